@@ -30,12 +30,12 @@ impl NeuralNetwork {
         self.status.parameters()
     }
 
-    fn forward<I, T, U>(&self, input: I) -> VarDiff<impl Data<Dim = Ix2>, impl Gradient<Dim = Ix2>>
+    fn forward<I, T, U>(&self, input: I) -> VarDiff<dyn Data<Dim = Ix2>, dyn Gradient<Dim = Ix2>>
     where
         I: MatMatMulT<Learnable<Ix2>>,
         I::Output: Into<VarDiff<T, U>>,
-        T: Data<Dim = Ix2>,
-        U: Gradient<Dim = Ix2>,
+        T: Data<Dim = Ix2> + 'static,
+        U: Gradient<Dim = Ix2> + 'static,
     {
         let out1 = self.lin1.forward(input).relu();
         let out2 = self.lin2.forward(out1).relu();
@@ -221,7 +221,7 @@ fn main() {
 
             let loss = loss::mse_loss(result.clone(), target.clone(), loss::Reduction::Mean);
             loss.forward();
-            total_loss += loss.data()[()];
+            total_loss += loss.data()[[]];
             loss.backward(1.0);
             optimizer.step();
         }
@@ -0,0 +1,409 @@
+//! Versioned on-disk container format.
+//!
+//! Everything neuronika persists on its own is stored in a [`Container`]: a small header followed
+//! by a sequence of named sections, each one carrying an arbitrary binary payload.
+//!
+//! # Layout
+//!
+//! All the integers are stored in little-endian byte order.
+//!
+//! ```text
+//! ┌──────────────────────────────────────────────────────────────┐
+//! │ magic bytes                                        8 bytes   │
+//! │ format major version                               u16       │
+//! │ format minor version                               u16       │
+//! │ header length                                      u32       │
+//! │ ┌──────────────────────────────────────────────────────────┐ │
+//! │ │ crate version                       u16 length + UTF-8   │ │
+//! │ │ payload kind                        u16 length + UTF-8   │ │
+//! │ └──────────────────────────────────────────────────────────┘ │
+//! ├──────────────────────────────────────────────────────────────┤
+//! │ section name                            u16 length + UTF-8   │
+//! │ section payload length                  u64                  │
+//! │ section CRC32                           u32                  │
+//! │ section payload                                              │
+//! ├──────────────────────────────────────────────────────────────┤
+//! │ ...                                                          │
+//! └──────────────────────────────────────────────────────────────┘
+//! ```
+//!
+//! The CRC32 of a section covers both its name and its payload.
+//!
+//! # Compatibility
+//!
+//! Files whose format major version differs from [`FORMAT_VERSION`] are rejected with
+//! [`ContainerError::UnsupportedVersion`]. Newer minor versions are read anyway: the fields that
+//! a minor version may append at the end of the header are skipped, thanks to the header length,
+//! and the sections that are unknown to the reader are simply never looked up.
+//!
+//! # Atomicity
+//!
+//! [`Container::write`] never leaves a partially written file behind: the container is written to
+//! a temporary file in the same directory as the destination, flushed to disk and then renamed
+//! over the destination.
+//!
+//! ```
+//! use neuronika::io::Container;
+//!
+//! let path = std::env::temp_dir().join("neuronika_io_example.nrk");
+//!
+//! let mut container = Container::new("example");
+//! container.push_section("weights", vec![1, 2, 3]);
+//! container.write(&path).unwrap();
+//!
+//! let container = Container::read(&path).unwrap();
+//! assert_eq!(container.kind(), "example");
+//! assert_eq!(container.section("weights"), Some(&[1, 2, 3][..]));
+//! # std::fs::remove_file(path).unwrap();
+//! ```
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    fs::{self, File},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+/// Magic bytes opening every container.
+pub const MAGIC: [u8; 8] = *b"NRKACONT";
+
+/// Format version written by this release as *(major, minor)*.
+pub const FORMAT_VERSION: (u16, u16) = (1, 0);
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ CRC32 ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Lookup table for the reflected IEEE 802.3 polynomial.
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+
+    table
+}
+
+/// Computes the CRC32 checksum of the concatenation of `chunks`.
+fn crc32(chunks: &[&[u8]]) -> u32 {
+    !chunks
+        .iter()
+        .flat_map(|chunk| chunk.iter())
+        .fold(!0, |crc, &byte| {
+            CRC32_TABLE[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+        })
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ ContainerError ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// The error type for reading and writing containers.
+#[derive(Debug)]
+pub enum ContainerError {
+    /// An I/O error occurred.
+    Io(io::Error),
+    /// The file doesn't start with the expected magic bytes.
+    InvalidMagic,
+    /// The file has been written with an incompatible major version of the format.
+    UnsupportedVersion { major: u16, minor: u16 },
+    /// The file ended in the middle of a header or of a section.
+    Truncated,
+    /// A string stored in the file isn't valid UTF-8.
+    InvalidUtf8,
+    /// The checksum of a section doesn't match its content.
+    ChecksumMismatch { section: String },
+}
+
+impl Display for ContainerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "error: {}", error),
+            Self::InvalidMagic => write!(f, "error: not a neuronika container."),
+            Self::UnsupportedVersion { major, minor } => write!(
+                f,
+                "error: unsupported container format version {}.{}, expected major version {}.",
+                major, minor, FORMAT_VERSION.0
+            ),
+            Self::Truncated => write!(f, "error: the container is truncated."),
+            Self::InvalidUtf8 => write!(f, "error: the container holds a non UTF-8 string."),
+            Self::ChecksumMismatch { section } => {
+                write!(f, "error: checksum mismatch in section {:?}.", section)
+            }
+        }
+    }
+}
+
+impl Error for ContainerError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ContainerError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Container ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// A named binary section of a [`Container`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
+/// A versioned collection of named binary sections.
+///
+/// See the [module-level documentation](self) for the on-disk layout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Container {
+    format_version: (u16, u16),
+    crate_version: String,
+    kind: String,
+    sections: Vec<Section>,
+}
+
+impl Container {
+    /// Creates an empty container holding a payload of the given kind.
+    ///
+    /// # Arguments
+    ///
+    /// `kind` - name identifying what the container stores, such as `"state_dict"`.
+    pub fn new(kind: &str) -> Self {
+        Self {
+            format_version: FORMAT_VERSION,
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            kind: kind.to_string(),
+            sections: Vec::new(),
+        }
+    }
+
+    /// Returns the kind of the payload.
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    /// Returns the format version the container has been written with as *(major, minor)*.
+    pub fn format_version(&self) -> (u16, u16) {
+        self.format_version
+    }
+
+    /// Returns the version of neuronika that wrote the container.
+    pub fn crate_version(&self) -> &str {
+        &self.crate_version
+    }
+
+    /// Returns the sections of the container in order.
+    pub fn sections(&self) -> &[Section] {
+        &self.sections
+    }
+
+    /// Returns the payload of the section named `name`, if any.
+    pub fn section(&self, name: &str) -> Option<&[u8]> {
+        self.sections
+            .iter()
+            .find(|section| section.name == name)
+            .map(|section| section.data.as_slice())
+    }
+
+    /// Appends a section to the container.
+    ///
+    /// # Panics
+    ///
+    /// If the container already has a section named `name`.
+    pub fn push_section(&mut self, name: &str, data: Vec<u8>) {
+        assert!(
+            self.section(name).is_none(),
+            "error: duplicated section {:?}.",
+            name
+        );
+
+        self.sections.push(Section {
+            name: name.to_string(),
+            data,
+        });
+    }
+
+    /// Serializes the container.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut header = Vec::new();
+        push_str(&mut header, &self.crate_version);
+        push_str(&mut header, &self.kind);
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&MAGIC);
+        bytes.extend_from_slice(&FORMAT_VERSION.0.to_le_bytes());
+        bytes.extend_from_slice(&FORMAT_VERSION.1.to_le_bytes());
+        bytes.extend_from_slice(&(header.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&header);
+
+        for section in &self.sections {
+            push_str(&mut bytes, &section.name);
+            bytes.extend_from_slice(&(section.data.len() as u64).to_le_bytes());
+            bytes
+                .extend_from_slice(&crc32(&[section.name.as_bytes(), &section.data]).to_le_bytes());
+            bytes.extend_from_slice(&section.data);
+        }
+
+        bytes
+    }
+
+    /// Deserializes a container, validating the checksum of every section.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ContainerError> {
+        let mut reader = Reader { bytes };
+
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(ContainerError::InvalidMagic);
+        }
+
+        let major = reader.u16()?;
+        let minor = reader.u16()?;
+        if major != FORMAT_VERSION.0 {
+            return Err(ContainerError::UnsupportedVersion { major, minor });
+        }
+
+        // Fields appended by newer minor versions are skipped.
+        let header_len = reader.u32()? as usize;
+        let mut header = Reader {
+            bytes: reader.take(header_len)?,
+        };
+        let crate_version = header.str()?;
+        let kind = header.str()?;
+
+        let mut sections = Vec::new();
+        while !reader.bytes.is_empty() {
+            let name = reader.str()?;
+            let len = usize::try_from(reader.u64()?).map_err(|_| ContainerError::Truncated)?;
+            let checksum = reader.u32()?;
+            let data = reader.take(len)?;
+
+            if crc32(&[name.as_bytes(), data]) != checksum {
+                return Err(ContainerError::ChecksumMismatch { section: name });
+            }
+
+            sections.push(Section {
+                name,
+                data: data.to_vec(),
+            });
+        }
+
+        Ok(Self {
+            format_version: (major, minor),
+            crate_version,
+            kind,
+            sections,
+        })
+    }
+
+    /// Atomically writes the container to `path`.
+    ///
+    /// The destination is either left untouched or entirely replaced.
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> Result<(), ContainerError> {
+        atomic_write(path.as_ref(), &self.to_bytes())
+    }
+
+    /// Reads the container stored at `path`.
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Self, ContainerError> {
+        Self::from_bytes(&fs::read(path)?)
+    }
+}
+
+/// Appends a length-prefixed string to `bytes`.
+///
+/// # Panics
+///
+/// If `string` is longer than `u16::MAX` bytes.
+fn push_str(bytes: &mut Vec<u8>, string: &str) {
+    let len = u16::try_from(string.len()).expect("error: string too long for a container.");
+    bytes.extend_from_slice(&len.to_le_bytes());
+    bytes.extend_from_slice(string.as_bytes());
+}
+
+/// Cursor over the bytes of a container.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], ContainerError> {
+        if self.bytes.len() < len {
+            return Err(ContainerError::Truncated);
+        }
+
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn u16(&mut self) -> Result<u16, ContainerError> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, ContainerError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, ContainerError> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn str(&mut self) -> Result<String, ContainerError> {
+        let len = self.u16()? as usize;
+        String::from_utf8(self.take(len)?.to_vec()).map_err(|_| ContainerError::InvalidUtf8)
+    }
+}
+
+/// Writes `bytes` to a temporary file next to `path`, flushes it and then renames it to `path`.
+pub(crate) fn atomic_write(path: &Path, bytes: &[u8]) -> Result<(), ContainerError> {
+    let tmp_path = tmp_path(path);
+
+    let result = File::create(&tmp_path)
+        .and_then(|mut file| {
+            file.write_all(bytes)?;
+            file.sync_all()
+        })
+        .and_then(|_| fs::rename(&tmp_path, path));
+
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp_path);
+    }
+
+    result.map_err(ContainerError::from)
+}
+
+/// Returns the path of the temporary file used to write `path`.
+fn tmp_path(path: &Path) -> PathBuf {
+    let mut file_name = std::ffi::OsString::from(".");
+    file_name.push(path.file_name().unwrap_or_default());
+    file_name.push(format!(".{}.tmp", std::process::id()));
+
+    path.with_file_name(file_name)
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
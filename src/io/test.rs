@@ -0,0 +1,193 @@
+use super::*;
+
+/// Returns a path in the temporary directory that is unique to the test named `name`.
+fn tmp_file(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("neuronika_{}_{}.nrk", name, std::process::id()))
+}
+
+fn new_container() -> Container {
+    let mut container = Container::new("state_dict");
+    container.push_section("weight", vec![1, 2, 3, 4]);
+    container.push_section("bias", vec![5, 6]);
+    container
+}
+
+/// Offset of the payload of the last section of `container` once serialized.
+fn last_payload_offset(container: &Container) -> usize {
+    container.to_bytes().len() - container.sections().last().unwrap().data.len()
+}
+
+#[test]
+fn crc32_check_value() {
+    assert_eq!(crc32(&[b"123456789"]), 0xCBF4_3926);
+    assert_eq!(crc32(&[b"1234", b"56789"]), 0xCBF4_3926);
+    assert_eq!(crc32(&[]), 0);
+}
+
+#[test]
+fn creation() {
+    let container = new_container();
+
+    assert_eq!(container.kind(), "state_dict");
+    assert_eq!(container.format_version(), FORMAT_VERSION);
+    assert_eq!(container.crate_version(), env!("CARGO_PKG_VERSION"));
+    assert_eq!(container.sections().len(), 2);
+    assert_eq!(container.section("weight"), Some(&[1, 2, 3, 4][..]));
+    assert_eq!(container.section("bias"), Some(&[5, 6][..]));
+    assert_eq!(container.section("running_mean"), None);
+}
+
+#[test]
+#[should_panic]
+fn duplicated_section() {
+    let mut container = new_container();
+    container.push_section("bias", Vec::new());
+}
+
+#[test]
+fn bytes_roundtrip() {
+    let container = new_container();
+    let bytes = container.to_bytes();
+
+    assert_eq!(&bytes[..MAGIC.len()], &MAGIC);
+    assert_eq!(Container::from_bytes(&bytes).unwrap(), container);
+}
+
+#[test]
+fn file_roundtrip() {
+    let path = tmp_file("file_roundtrip");
+    let container = new_container();
+
+    container.write(&path).unwrap();
+    assert_eq!(Container::read(&path).unwrap(), container);
+
+    // Writing again replaces the file and leaves no temporary file behind.
+    let mut other = Container::new("checkpoint");
+    other.push_section("epoch", 7_u64.to_le_bytes().to_vec());
+    other.write(&path).unwrap();
+    assert_eq!(Container::read(&path).unwrap(), other);
+    assert!(!tmp_path(&path).exists());
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn write_failure_leaves_destination() {
+    let path = tmp_file("write_failure");
+    new_container().write(&path).unwrap();
+
+    // The parent of the destination is a regular file, so the write fails without touching it.
+    let missing = path.join("missing").join("container.nrk");
+    assert!(matches!(
+        new_container().write(&missing),
+        Err(ContainerError::Io(_))
+    ));
+    assert_eq!(Container::read(&path).unwrap(), new_container());
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn checksum_mismatch() {
+    let path = tmp_file("checksum_mismatch");
+    let container = new_container();
+    container.write(&path).unwrap();
+
+    let mut bytes = fs::read(&path).unwrap();
+    bytes[last_payload_offset(&container)] ^= 0xFF;
+    fs::write(&path, bytes).unwrap();
+
+    let error = Container::read(&path).err().unwrap();
+    assert!(matches!(
+        &error,
+        ContainerError::ChecksumMismatch { section } if section == "bias"
+    ));
+    assert_eq!(
+        error.to_string(),
+        "error: checksum mismatch in section \"bias\"."
+    );
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn unknown_trailing_section() {
+    let path = tmp_file("unknown_trailing_section");
+    let mut container = new_container();
+    container.push_section("written_by_a_newer_release", vec![42; 16]);
+    container.write(&path).unwrap();
+
+    let container = Container::read(&path).unwrap();
+    assert_eq!(container.section("weight"), Some(&[1, 2, 3, 4][..]));
+    assert_eq!(container.section("bias"), Some(&[5, 6][..]));
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn newer_minor_version() {
+    let container = new_container();
+    let mut bytes = container.to_bytes();
+
+    // A newer minor version appends a field to the header.
+    let header_len_offset = MAGIC.len() + 4;
+    let header_len = u32::from_le_bytes(
+        bytes[header_len_offset..header_len_offset + 4]
+            .try_into()
+            .unwrap(),
+    );
+    let header_end = header_len_offset + 4 + header_len as usize;
+    bytes.splice(header_end..header_end, [0xAB; 5]);
+    bytes[header_len_offset..header_len_offset + 4]
+        .copy_from_slice(&(header_len + 5).to_le_bytes());
+    bytes[MAGIC.len() + 2..MAGIC.len() + 4].copy_from_slice(&(FORMAT_VERSION.1 + 1).to_le_bytes());
+
+    let read = Container::from_bytes(&bytes).unwrap();
+    assert_eq!(
+        read.format_version(),
+        (FORMAT_VERSION.0, FORMAT_VERSION.1 + 1)
+    );
+    assert_eq!(read.kind(), container.kind());
+    assert_eq!(read.sections(), container.sections());
+}
+
+#[test]
+fn unsupported_major_version() {
+    let mut bytes = new_container().to_bytes();
+    bytes[MAGIC.len()..MAGIC.len() + 2].copy_from_slice(&(FORMAT_VERSION.0 + 1).to_le_bytes());
+
+    let error = Container::from_bytes(&bytes).err().unwrap();
+    assert!(matches!(
+        error,
+        ContainerError::UnsupportedVersion { major, minor }
+            if major == FORMAT_VERSION.0 + 1 && minor == FORMAT_VERSION.1
+    ));
+    assert!(error
+        .to_string()
+        .starts_with("error: unsupported container format version"));
+}
+
+#[test]
+fn invalid_magic() {
+    let mut bytes = new_container().to_bytes();
+    bytes[0] = b'X';
+
+    assert!(matches!(
+        Container::from_bytes(&bytes),
+        Err(ContainerError::InvalidMagic)
+    ));
+}
+
+#[test]
+fn truncated() {
+    let bytes = new_container().to_bytes();
+
+    assert!(matches!(
+        Container::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(ContainerError::Truncated)
+    ));
+    assert!(matches!(
+        Container::from_bytes(&bytes[..MAGIC.len() + 1]),
+        Err(ContainerError::Truncated)
+    ));
+}
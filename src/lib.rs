@@ -194,15 +194,21 @@
 )]
 
 pub mod data;
+pub mod io;
 pub mod nn;
 pub mod optim;
+pub mod utils;
 mod variable;
 use ndarray::{Array, Array2, Dimension, Ix1, Ix2, ShapeBuilder};
 use ndarray_rand::rand_distr::Uniform;
 use ndarray_rand::RandomExt;
 pub use variable::{
-    Backward, Cache, Cat, Convolve, ConvolveWithGroups, Data, Eval, Forward, Gradient, MatMatMul,
-    MatMatMulT, MatVecMul, MaxPooling, Overwrite, Param, Stack, Var, VarDiff, VecMatMul, VecVecMul,
+    is_grad_enabled, no_grad, print_options, set_print_options, with_print_options,
+    AdaptiveAvgPooling, AdaptiveMaxPooling, AnyVar, AnyVarDiff, AvgPooling, Backward,
+    BatchedMatMatMul, Cache, Cat, Convolve, ConvolveTransposed, ConvolveWithGroups, Data, Eval,
+    Forward, Gradient, KroneckerProduct, MatMatMul, MatMatMulT, MatVecMul, MaxPoolIndices,
+    MaxPooling, NoGradGuard, OuterProduct, Overwrite, PadMode, Param, PrintOptions, Rank,
+    ScatterAdd, ShapedDisplay, Stack, TensorDot, Var, VarDiff, VecMatMul, VecVecMul, Where,
 };
 use variable::{Input, InputBackward};
 
@@ -442,6 +448,146 @@ where
     Stack::stack(lhs, rhs, axis)
 }
 
+/// Contracts the variables `lhs` and `rhs` over the axes `lhs_axes` of `lhs` and `rhs_axes` of
+/// `rhs`.
+///
+/// The result has the remaining axes of `lhs` followed by the remaining axes of `rhs`.
+///
+/// # Arguments
+///
+/// * `lhs` - variable.
+///
+/// * `rhs` - other variable.
+///
+/// * `lhs_axes` - axes of `lhs` to contract.
+///
+/// * `rhs_axes` - axes of `rhs` to contract, paired with `lhs_axes`.
+///
+/// # Panics
+///
+/// If `lhs_axes` and `rhs_axes` have different lengths, if an axis is out of bounds or repeated,
+/// or if two paired axes have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "blas")]
+/// # extern crate blas_src;
+/// let a = neuronika::ones((2, 3, 4));
+/// let b = neuronika::ones((4, 5));
+///
+/// let c = neuronika::tensordot(a, b, &[2], &[0]);
+/// c.forward();
+///
+/// assert_eq!(c.data().shape(), &[2, 3, 5]);
+/// assert!(c.data().iter().all(|el| *el == 4.));
+/// ```
+pub fn tensordot<Lhs, Rhs>(
+    lhs: Lhs,
+    rhs: Rhs,
+    lhs_axes: &[usize],
+    rhs_axes: &[usize],
+) -> <Lhs as TensorDot<Rhs>>::Output
+where
+    Lhs: TensorDot<Rhs>,
+{
+    TensorDot::tensordot(lhs, rhs, lhs_axes, rhs_axes)
+}
+
+/// Takes the elements of `lhs` where `condition` is non-zero and the ones of `rhs` elsewhere.
+///
+/// The shapes of `condition`, `lhs` and `rhs` are broadcast together. The gradient flows to `lhs`
+/// only where `condition` is non-zero and to `rhs` only where it is zero, so that the values of the
+/// branch that isn't selected, even if not finite, never affect the result nor the gradients.
+///
+/// # Arguments
+///
+/// * `condition` - variable whose non-zero elements select `lhs`.
+///
+/// * `lhs` - variable.
+///
+/// * `rhs` - other variable.
+///
+/// # Panics
+///
+/// If the shapes of `condition`, `lhs` and `rhs` cannot be broadcast together.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "blas")]
+/// # extern crate blas_src;
+/// let condition = neuronika::from_ndarray(ndarray::array![1., 0., 1.]);
+/// let lhs = neuronika::from_ndarray(ndarray::array![1., 2., 3.]);
+/// let rhs = neuronika::full(3, f32::NAN);
+///
+/// let selected = neuronika::where_(condition, lhs, rhs);
+/// selected.forward();
+///
+/// assert_eq!(selected.data()[0], 1.);
+/// assert!(selected.data()[1].is_nan());
+/// assert_eq!(selected.data()[2], 3.);
+/// ```
+pub fn where_<Cond, Lhs, Rhs>(
+    condition: Cond,
+    lhs: Lhs,
+    rhs: Rhs,
+) -> <Lhs as Where<Cond, Rhs>>::Output
+where
+    Lhs: Where<Cond, Rhs>,
+{
+    Where::where_(lhs, condition, rhs)
+}
+
+/// Adds the elements of `src` into a variable of shape `output_size` filled with zeros, along
+/// `axis`, at the positions specified by `index`.
+///
+/// Elements scattered to the same position add up, which makes this suitable for segment sums
+/// such as the aggregation of messages in graph neural networks. The gradient of `src` is the
+/// incoming gradient gathered at `index`.
+///
+/// # Arguments
+///
+/// * `src` - variable whose elements are scattered.
+///
+/// * `index` - positions along `axis` at which the elements of `src` are added, it must have the
+/// same shape as `src`.
+///
+/// * `output_size` - shape of the result.
+///
+/// * `axis` - axis along which the elements are scattered.
+///
+/// # Panics
+///
+/// If `axis` is out of bounds, if `index` and `src` have different shapes, if `index` is larger
+/// than the result along any other axis or if any of its elements is out of bounds for `axis`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "blas")]
+/// # extern crate blas_src;
+/// let src = neuronika::from_ndarray(ndarray::array![1., 2., 3.]);
+///
+/// let sum = neuronika::scatter_add(src, ndarray::array![0, 0, 1], 2, 0);
+/// sum.forward();
+///
+/// assert_eq!(*sum.data(), ndarray::array![3., 3.]);
+/// ```
+pub fn scatter_add<Src, D, Sh>(
+    src: Src,
+    index: Array<usize, D>,
+    output_size: Sh,
+    axis: usize,
+) -> <Var<Input<D>> as ScatterAdd<Src>>::Output
+where
+    D: Dimension,
+    Sh: ShapeBuilder<Dim = D>,
+    Var<Input<D>>: ScatterAdd<Src, Dim = D>,
+{
+    zeros(output_size).scatter_add(axis, index, src)
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -0,0 +1,371 @@
+use crate::variable::{Data, Gradient, Tensor, VarDiff};
+use ndarray::{Array2, Axis, Ix2, Ix3};
+
+/// Mask applied to the attention scores before the softmax.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttentionMask {
+    /// Additive mask of shape *(L, S)*, added to the scores of every batch. Setting an entry to
+    /// `f32::NEG_INFINITY` prevents the corresponding query from attending to the corresponding
+    /// key.
+    Additive(Tensor<Ix2>),
+    /// Key-padding mask of shape *(N, S)*, where `true` marks the keys of each batch that no
+    /// query may attend to.
+    KeyPadding(Array2<bool>),
+}
+
+impl AttentionMask {
+    /// Returns the mask as an additive tensor that can be broadcast to the scores of `batch_size`
+    /// batches over `src_len` keys.
+    ///
+    /// # Panics
+    ///
+    /// If the key-padding mask has not one row for each batch and one column for each key.
+    fn to_additive(&self, batch_size: usize, src_len: usize) -> Tensor<Ix3> {
+        match self {
+            Self::Additive(mask) => mask.clone().insert_axis(Axis(0)),
+            Self::KeyPadding(mask) => {
+                assert_eq!(
+                    mask.dim(),
+                    (batch_size, src_len),
+                    "error: key-padding mask of shape {:?} for {} batches of {} keys.",
+                    mask.shape(),
+                    batch_size,
+                    src_len
+                );
+                mask.mapv(|padding| if padding { f32::NEG_INFINITY } else { 0. })
+                    .insert_axis(Axis(1))
+            }
+        }
+    }
+
+    /// Repeats each row of a key-padding mask `times` times, so that it matches the batches of
+    /// the heads of a multi-head attention. An additive mask is shared by all the batches and is
+    /// left untouched.
+    pub(super) fn repeat_batches(self, times: usize) -> Self {
+        match self {
+            Self::Additive(mask) => Self::Additive(mask),
+            Self::KeyPadding(mask) => {
+                let (batch_size, src_len) = mask.dim();
+                Self::KeyPadding(Array2::from_shape_fn(
+                    (batch_size * times, src_len),
+                    |(batch, key)| mask[[batch / times, key]],
+                ))
+            }
+        }
+    }
+}
+
+impl From<Tensor<Ix2>> for AttentionMask {
+    fn from(mask: Tensor<Ix2>) -> Self {
+        Self::Additive(mask)
+    }
+}
+
+impl From<Array2<bool>> for AttentionMask {
+    fn from(mask: Array2<bool>) -> Self {
+        Self::KeyPadding(mask)
+    }
+}
+
+/// Computes the scaled dot-product attention of the queries `q` over the keys `k` and the values
+/// `v`.
+///
+/// ```text
+/// attention(Q, K, V) = softmax(Q K^T / sqrt(d_k) + mask) V
+/// ```
+///
+/// where *d_k* is the length of the last axis of `q`.
+///
+/// # Arguments
+///
+/// * `q` - queries of shape *(N, L, Dk)*.
+///
+/// * `k` - keys of shape *(N, S, Dk)*.
+///
+/// * `v` - values of shape *(N, S, Dv)*.
+///
+/// * `mask` - optional mask of the scores, either an additive mask of shape *(L, S)* or a
+/// key-padding mask of shape *(N, S)*. See [`AttentionMask`] for more details. A query whose keys
+/// are all masked results in NaNs.
+///
+/// The resulting output shape will be *(N, L, Dv)*.
+///
+/// # Panics
+///
+/// If the shapes of `q`, `k` and `v` are not compatible or if the mask doesn't fit the shape of
+/// the scores.
+///
+/// # Examples
+///
+/// A causal attention, where each position only attends to itself and to the previous ones.
+///
+/// ```
+/// use neuronika::nn;
+/// use ndarray::Array;
+///
+/// let q = neuronika::rand((2, 4, 8)).requires_grad();
+/// let k = neuronika::rand((2, 4, 8)).requires_grad();
+/// let v = neuronika::rand((2, 4, 3)).requires_grad();
+///
+/// let mask = Array::from_shape_fn((4, 4), |(i, j)| if j > i { f32::NEG_INFINITY } else { 0. });
+/// let attention = nn::scaled_dot_product_attention(q, k, v, Some(mask.into()));
+///
+/// attention.forward();
+/// assert_eq!(attention.data().shape(), &[2, 4, 3]);
+/// ```
+///
+/// A padded batch, where the last key of the first sequence must be ignored.
+///
+/// ```
+/// use neuronika::nn::functional;
+/// use ndarray::array;
+///
+/// let q = neuronika::rand((2, 4, 8)).requires_grad();
+/// let kv = neuronika::rand((2, 3, 8)).requires_grad();
+///
+/// let padding = array![[false, false, true], [false, false, false]];
+/// let attention =
+///     functional::scaled_dot_product_attention(q, kv.clone(), kv, Some(padding.into()));
+///
+/// attention.forward();
+/// assert_eq!(attention.data().shape(), &[2, 4, 8]);
+/// ```
+pub fn scaled_dot_product_attention<T1, U1, T2, U2, T3, U3>(
+    q: VarDiff<T1, U1>,
+    k: VarDiff<T2, U2>,
+    v: VarDiff<T3, U3>,
+    mask: Option<AttentionMask>,
+) -> VarDiff<impl Data<Dim = Ix3>, impl Gradient<Dim = Ix3>>
+where
+    T1: Data<Dim = Ix3> + 'static,
+    U1: Gradient<Dim = Ix3> + 'static,
+    T2: Data<Dim = Ix3> + 'static,
+    U2: Gradient<Dim = Ix3> + 'static,
+    T3: Data<Dim = Ix3> + 'static,
+    U3: Gradient<Dim = Ix3> + 'static,
+{
+    let d_k = q.data().shape()[2] as f32;
+    let (batch_size, src_len, _) = k.data().dim();
+    let scores = q.bmm(k.swap_axes(1, 2)) / d_k.sqrt();
+
+    let scores = match mask {
+        Some(mask) => {
+            (scores + crate::from_ndarray(mask.to_additive(batch_size, src_len))).into_dyn()
+        }
+        None => scores.into_dyn(),
+    };
+
+    scores.softmax(2).bmm(v)
+}
+
+#[cfg(test)]
+mod test {
+    use super::scaled_dot_product_attention;
+    use ndarray::{array, Array, Array3, Axis};
+
+    fn causal_mask(len: usize) -> ndarray::Array2<f32> {
+        Array::from_shape_fn(
+            (len, len),
+            |(i, j)| {
+                if j > i {
+                    f32::NEG_INFINITY
+                } else {
+                    0.
+                }
+            },
+        )
+    }
+
+    #[test]
+    fn forward() {
+        let q = crate::rand((2, 3, 4)).requires_grad();
+        let k = crate::rand((2, 5, 4)).requires_grad();
+        let v = crate::rand((2, 5, 6)).requires_grad();
+
+        let attention = scaled_dot_product_attention(q.clone(), k.clone(), v.clone(), None);
+        attention.forward();
+        assert_eq!(attention.data().shape(), &[2, 3, 6]);
+
+        // Computes the attention of each batch by hand.
+        let scores = q.data().clone();
+        for batch in 0..2 {
+            let scores = scores
+                .index_axis(Axis(0), batch)
+                .dot(&k.data().index_axis(Axis(0), batch).t())
+                / 2.;
+            let weights = scores.mapv(f32::exp);
+            let weights = &weights / &weights.sum_axis(Axis(1)).insert_axis(Axis(1));
+            let expected = weights.dot(&v.data().index_axis(Axis(0), batch));
+
+            let data = attention.data();
+            assert!(data
+                .index_axis(Axis(0), batch)
+                .iter()
+                .zip(expected.iter())
+                .all(|(actual, expected)| (actual - expected).abs() <= 1e-5));
+        }
+    }
+
+    #[test]
+    fn causal() {
+        let q = crate::rand((1, 4, 3)).requires_grad();
+        let k = crate::rand((1, 4, 3)).requires_grad();
+
+        // Attending over the identity yields the attention weights themselves.
+        let identity = crate::from_ndarray(Array::eye(4).insert_axis(Axis(0))).requires_grad();
+        let weights = scaled_dot_product_attention(q, k, identity, Some(causal_mask(4).into()));
+        weights.forward();
+
+        let weights = weights.data();
+        for i in 0..4 {
+            for j in 0..4 {
+                if j > i {
+                    assert_eq!(weights[[0, i, j]], 0.);
+                } else {
+                    assert!(weights[[0, i, j]] > 0.);
+                }
+            }
+            assert!((weights.index_axis(Axis(1), i).sum() - 1.).abs() <= 1e-6);
+        }
+    }
+
+    #[test]
+    fn backward() {
+        let q = crate::rand((2, 4, 3)).requires_grad();
+        let k = crate::rand((2, 4, 3)).requires_grad();
+        let v = crate::rand((2, 4, 5)).requires_grad();
+
+        let attention = scaled_dot_product_attention(
+            q.clone(),
+            k.clone(),
+            v.clone(),
+            Some(causal_mask(4).into()),
+        );
+        let loss = (attention * crate::rand((2, 4, 5))).sum();
+        loss.forward();
+        loss.backward(1.);
+
+        assert_eq!(loss.parameters().len(), 3);
+        for grad in [q.grad().clone(), k.grad().clone(), v.grad().clone()] {
+            assert!(grad.iter().all(|el| el.is_finite()));
+            assert!(grad.iter().any(|el| *el != 0.));
+        }
+    }
+
+    #[test]
+    fn key_padding() {
+        let q = crate::rand((2, 3, 4)).requires_grad();
+        let k = crate::rand((2, 5, 4)).requires_grad();
+        let padding = array![
+            [false, false, false, true, true],
+            [false, true, false, false, false]
+        ];
+
+        // Attending over the identity yields the attention weights themselves.
+        let identity = crate::from_ndarray(Array::from_shape_fn((2, 5, 5), |(_, i, j)| {
+            (i == j) as u8 as f32
+        }))
+        .requires_grad();
+        let weights = scaled_dot_product_attention(q, k, identity, Some(padding.clone().into()));
+        weights.forward();
+
+        let weights = weights.data();
+        for ((batch, i, j), weight) in weights.indexed_iter() {
+            if padding[[batch, j]] {
+                assert_eq!(*weight, 0.);
+            } else {
+                assert!(*weight > 0.);
+            }
+            if j == 0 {
+                let row = weights.index_axis(Axis(0), batch);
+                assert!((row.index_axis(Axis(0), i).sum() - 1.).abs() <= 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "error: key-padding mask of shape [2, 4] for 2 batches of 5 keys.")]
+    fn key_padding_wrong_shape() {
+        let q = crate::rand((2, 3, 4)).requires_grad();
+        let k = crate::rand((2, 5, 4)).requires_grad();
+        let padding = Array::from_elem((2, 4), false);
+
+        scaled_dot_product_attention(q, k.clone(), k, Some(padding.into()));
+    }
+
+    #[test]
+    fn backward_finite_differences() {
+        let (q, k, v) = (
+            crate::rand((2, 3, 4)).data().to_owned(),
+            crate::rand((2, 3, 4)).data().to_owned(),
+            crate::rand((2, 3, 4)).data().to_owned(),
+        );
+        let coefficients = crate::rand((2, 3, 4));
+        let padding = array![[false, false, true], [false, false, false]];
+        let loss = |q: &Array3<f32>, k: &Array3<f32>, v: &Array3<f32>| {
+            let attention = scaled_dot_product_attention(
+                crate::from_ndarray(q.clone()).requires_grad(),
+                crate::from_ndarray(k.clone()).requires_grad(),
+                crate::from_ndarray(v.clone()).requires_grad(),
+                Some(padding.clone().into()),
+            );
+            (attention * coefficients.clone()).sum()
+        };
+
+        let (q_var, k_var, v_var) = (
+            crate::from_ndarray(q.clone()).requires_grad(),
+            crate::from_ndarray(k.clone()).requires_grad(),
+            crate::from_ndarray(v.clone()).requires_grad(),
+        );
+        let output = (scaled_dot_product_attention(
+            q_var.clone(),
+            k_var.clone(),
+            v_var.clone(),
+            Some(padding.clone().into()),
+        ) * coefficients.clone())
+        .sum();
+        output.forward();
+        output.backward(1.);
+
+        let h = 1e-2;
+        let value = |q: &Array3<f32>, k: &Array3<f32>, v: &Array3<f32>| {
+            let loss = loss(q, k, v);
+            loss.forward();
+            let value = loss.data()[()];
+            value
+        };
+        for (operand, grad) in [(0, q_var.grad()), (1, k_var.grad()), (2, v_var.grad())] {
+            for (index, grad) in grad.indexed_iter() {
+                let (mut plus, mut minus) = (
+                    [&q, &k, &v].map(Clone::clone),
+                    [&q, &k, &v].map(Clone::clone),
+                );
+                plus[operand][index] += h;
+                minus[operand][index] -= h;
+
+                let numeric = (value(&plus[0], &plus[1], &plus[2])
+                    - value(&minus[0], &minus[1], &minus[2]))
+                    / (2. * h);
+                assert!(
+                    (grad - numeric).abs() <= 1e-2,
+                    "analytic: {}, numeric: {}",
+                    grad,
+                    numeric
+                );
+            }
+        }
+        // The padded keys and values receive no gradient.
+        assert!(k_var
+            .grad()
+            .index_axis(Axis(0), 0)
+            .row(2)
+            .iter()
+            .all(|el| *el == 0.));
+        assert!(v_var
+            .grad()
+            .index_axis(Axis(0), 0)
+            .row(2)
+            .iter()
+            .all(|el| *el == 0.));
+    }
+}
@@ -0,0 +1,313 @@
+use super::{named, Learnable, Register};
+use crate::variable::{
+    AnyVarDiff, Data, Eval, Gradient, Input, Rank, RawParam, Tensor, Var, VarDiff,
+};
+use ndarray::{Dimension, Ix1, Ix2, Ix4, RemoveAxis};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{cell::Cell, rc::Rc};
+
+/// Batch normalization input.
+///
+/// This trait is implemented by `Var` and `VarDiff`.
+pub trait BatchNormInput<D: Dimension> {
+    /// Normalizes each channel of `self` with the statistics computed over the batch by `layer`,
+    /// or with its running statistics in evaluation mode, then scales the result by the layer's
+    /// weight and shifts it by its bias.
+    fn batch_norm(self, layer: &BatchNorm<D>) -> VarDiff<dyn Data<Dim = D>, dyn Gradient<Dim = D>>;
+}
+
+impl<D, T: ?Sized, U: ?Sized> BatchNormInput<D> for VarDiff<T, U>
+where
+    D: RemoveAxis + 'static,
+    T: Data<Dim = D> + 'static,
+    U: Gradient<Dim = D> + 'static,
+{
+    fn batch_norm(self, layer: &BatchNorm<D>) -> VarDiff<dyn Data<Dim = D>, dyn Gradient<Dim = D>> {
+        let normalized = self.batch_norm_with_status(
+            &layer.running_mean,
+            &layer.running_var,
+            layer.momentum,
+            layer.eps,
+            layer.status.clone(),
+        );
+
+        (normalized * layer.weight.clone() + layer.bias.clone()).into_dyn()
+    }
+}
+
+impl<D, T: ?Sized> BatchNormInput<D> for Var<T>
+where
+    D: RemoveAxis + 'static,
+    T: Data<Dim = D> + 'static,
+{
+    fn batch_norm(self, layer: &BatchNorm<D>) -> VarDiff<dyn Data<Dim = D>, dyn Gradient<Dim = D>> {
+        let normalized = self.batch_norm_with_status(
+            &layer.running_mean,
+            &layer.running_var,
+            layer.momentum,
+            layer.eps,
+            layer.status.clone(),
+        );
+
+        (normalized * layer.weight.clone() + layer.bias.clone()).into_dyn()
+    }
+}
+
+/// Applies **batch normalization** over the channels of the incoming data as described in the
+/// paper [Batch Normalization: Accelerating Deep Network Training by Reducing Internal Covariate
+/// Shift](https://arxiv.org/abs/1502.03167).
+///
+/// ```text
+///       x - E[x]
+/// ʏ = ―――――――――――――――― * weight + bias
+///     √(Var[x] + eps)
+/// ```
+///
+/// The input has shape *(N, C, ...)*, the mean and the biased variance are computed for each of
+/// the *C* channels over the batch and the remaining axes. The type parameter `D` is the
+/// dimensionality of the input, see also [`BatchNorm1d`] and [`BatchNorm2d`].
+///
+/// In training mode the running statistics are updated at each evaluation as
+/// `running = (1 - momentum) * running + momentum * batch`, the running variance being updated
+/// with the unbiased estimator. In evaluation mode the input is normalized with the running
+/// statistics instead, so that the output of each sample doesn't depend on the others.
+///
+/// # Examples
+///
+/// ```
+/// use neuronika::nn::BatchNorm2d;
+///
+/// // A batch of 8 images with 3 channels.
+/// let input = neuronika::rand((8, 3, 5, 5));
+/// let batch_norm = BatchNorm2d::new(3, 0.1, 1e-5);
+///
+/// let output = batch_norm.forward(input);
+/// output.forward();
+/// assert_eq!(output.data().shape(), &[8, 3, 5, 5]);
+/// ```
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct BatchNorm<D: Dimension + 'static> {
+    pub weight: Learnable<D>,
+    pub bias: Learnable<D>,
+    pub running_mean: Var<Input<Ix1>>,
+    pub running_var: Var<Input<Ix1>>,
+    pub momentum: f32,
+    pub eps: f32,
+    #[cfg_attr(feature = "serialize", serde(skip, default = "train_status"))]
+    status: Rc<Cell<bool>>,
+}
+
+/// Batch normalization over an input of shape *(N, C)*.
+pub type BatchNorm1d = BatchNorm<Ix2>;
+
+/// Batch normalization over an input of shape *(N, C, H, W)*.
+pub type BatchNorm2d = BatchNorm<Ix4>;
+
+/// Returns a new status set to train.
+#[cfg(feature = "serialize")]
+fn train_status() -> Rc<Cell<bool>> {
+    Rc::new(Cell::new(true))
+}
+
+impl<D: Dimension + 'static> BatchNorm<D> {
+    /// Creates a batch normalization layer.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_features` - number of channels of the input.
+    ///
+    /// * `momentum` - weight of the batch statistics in the update of the running ones. A good
+    /// default value is *0.1*.
+    ///
+    /// * `eps` - value added to the variance for numerical stability.
+    ///
+    /// The learnable weight and bias of the layer have `num_features` elements along their
+    /// second axis, and axes of length one elsewhere so that they broadcast over the input. They
+    /// are respectively initialized with ones and zeros. The running mean and variance are
+    /// likewise initialized with zeros and ones.
+    ///
+    /// # Panics
+    ///
+    /// If `D` has less than 2 dimensions.
+    pub fn new(num_features: usize, momentum: f32, eps: f32) -> Self {
+        let ndim = D::NDIM.unwrap_or(2);
+        assert!(
+            ndim >= 2,
+            "error: batch normalization expects an input of shape (N, C, ...)."
+        );
+
+        let mut shape = D::zeros(ndim);
+        shape.slice_mut().iter_mut().for_each(|len| *len = 1);
+        shape[1] = num_features;
+
+        Self {
+            weight: Input::new(Tensor::ones(shape.clone())).requires_grad(),
+            bias: Input::new(Tensor::zeros(shape)).requires_grad(),
+            running_mean: Input::new(Tensor::zeros(num_features)),
+            running_var: Input::new(Tensor::ones(num_features)),
+            momentum,
+            eps,
+            status: Rc::new(Cell::new(true)),
+        }
+    }
+
+    /// Applies the batch normalization to the incoming data.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - a variable of shape *(N, num_features, ...)*, the output has the same shape as
+    /// the input.
+    pub fn forward<I: BatchNormInput<D>>(
+        &self,
+        input: I,
+    ) -> VarDiff<dyn Data<Dim = D>, dyn Gradient<Dim = D>> {
+        input.batch_norm(self)
+    }
+}
+
+impl<D: Rank> BatchNorm<D> {
+    /// Returns the weight and the bias of this `BatchNorm` instance, paired with their names.
+    pub fn named_parameters(&self) -> Vec<(String, AnyVarDiff)> {
+        vec![named("weight", &self.weight), named("bias", &self.bias)]
+    }
+}
+
+impl<D: Dimension + 'static> Eval for BatchNorm<D> {
+    fn eval(&self) {
+        self.status.set(false)
+    }
+
+    fn train(&self) {
+        self.status.set(true)
+    }
+}
+
+impl<D: Dimension + 'static> Register for BatchNorm<D> {
+    /// Registers the weight and the bias of this `BatchNorm` instance.
+    fn register_params(&self, params: &mut Vec<RawParam>) {
+        self.weight.register_params(params);
+        self.bias.register_params(params);
+    }
+
+    fn register_status(&mut self, status: Rc<Cell<bool>>) {
+        self.status = status;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{BatchNorm1d, BatchNorm2d};
+    use crate::Eval;
+    use ndarray::{Array, Axis, Ix4};
+
+    #[test]
+    fn creation() {
+        let batch_norm = BatchNorm2d::new(3, 0.1, 1e-5);
+
+        assert_eq!(batch_norm.weight.data().shape(), &[1, 3, 1, 1]);
+        assert_eq!(batch_norm.bias.data().shape(), &[1, 3, 1, 1]);
+        assert_eq!(*batch_norm.running_mean.data(), Array::<f32, _>::zeros(3));
+        assert_eq!(*batch_norm.running_var.data(), Array::<f32, _>::ones(3));
+        assert_eq!(batch_norm.named_parameters().len(), 2);
+    }
+
+    #[test]
+    fn train_statistics() {
+        let batch_norm = BatchNorm2d::new(3, 0.1, 1e-5);
+        let input = crate::rand((8, 3, 4, 4)) * 5. + 2.;
+
+        let output = batch_norm.forward(input);
+        output.forward();
+        for channel in output.data().axis_iter(Axis(1)) {
+            let mean = channel.mean().unwrap();
+            let var = channel.mapv(|el| (el - mean).powi(2)).mean().unwrap();
+            assert!(mean.abs() <= 1e-4);
+            assert!((var - 1.).abs() <= 1e-3);
+        }
+        assert!(batch_norm.running_mean.data().iter().all(|el| *el > 0.));
+    }
+
+    #[test]
+    fn eval_reproducibility() {
+        let batch_norm = BatchNorm1d::new(4, 0.1, 1e-5);
+        for _ in 0..5 {
+            let output = batch_norm.forward(crate::rand((16, 4)) * 3.);
+            output.forward();
+        }
+
+        batch_norm.eval();
+        let (running_mean, running_var) = (
+            batch_norm.running_mean.data().clone(),
+            batch_norm.running_var.data().clone(),
+        );
+        let input = crate::rand((2, 4));
+        let first = batch_norm.forward(input.clone());
+        first.forward();
+        let second = batch_norm.forward(input.clone());
+        second.forward();
+
+        // The running statistics are frozen and the output doesn't depend on the batch.
+        assert_eq!(*first.data(), *second.data());
+        assert_eq!(*batch_norm.running_mean.data(), running_mean);
+        assert_eq!(*batch_norm.running_var.data(), running_var);
+        let expected = (&*input.data() - &running_mean) / (&running_var + 1e-5).mapv(f32::sqrt);
+        assert!(first
+            .data()
+            .iter()
+            .zip(expected.iter())
+            .all(|(el, expected_el)| (el - expected_el).abs() <= 1e-5));
+
+        let single = batch_norm.forward(input.clone().narrow(0, 0, 1));
+        single.forward();
+        assert_eq!(single.data().row(0), first.data().row(0));
+    }
+
+    #[test]
+    fn gradient() {
+        // A tiny batch of 2 samples with 2 channels of 2 x 2 elements.
+        let data = Array::from_shape_vec(
+            (2, 2, 2, 2),
+            vec![
+                0.5, -1.2, 2.0, 0.1, 1.5, 0.3, -0.7, 0.9, -0.4, 1.1, 0.8, -1.6, 0.2, 2.2, -1.3, 0.6,
+            ],
+        )
+        .unwrap();
+        let coefficients = Array::from_shape_fn((2, 2, 2, 2), |(n, c, h, w)| {
+            ((n * 8 + c * 4 + h * 2 + w) as f32 * 0.37).sin()
+        });
+        let new_layer = || {
+            let batch_norm = BatchNorm2d::new(2, 0.1, 1e-3);
+            batch_norm.weight.data_mut()[[0, 1, 0, 0]] = -0.5;
+            batch_norm
+        };
+        let loss_of = |data: &Array<f32, Ix4>| {
+            let loss = (new_layer().forward(crate::from_ndarray(data.clone()))
+                * crate::from_ndarray(coefficients.clone()))
+            .sum();
+            loss.forward();
+            let value = loss.data()[()];
+            value
+        };
+
+        let batch_norm = new_layer();
+        let x = crate::from_ndarray(data.clone()).requires_grad();
+        let loss =
+            (batch_norm.forward(x.clone()) * crate::from_ndarray(coefficients.clone())).sum();
+        loss.forward();
+        loss.backward(1.);
+
+        let h = 1e-2;
+        for (index, grad_el) in x.grad().indexed_iter() {
+            let (mut plus, mut minus) = (data.clone(), data.clone());
+            plus[index] += h;
+            minus[index] -= h;
+            let numerical = (loss_of(&plus) - loss_of(&minus)) / (2. * h);
+            assert!((numerical - grad_el).abs() <= 1e-2);
+        }
+
+        // The weight and the bias receive their gradient as well.
+        assert!(batch_norm.weight.grad().iter().all(|el| el.abs() > 0.));
+        assert!(batch_norm.bias.grad().iter().all(|el| el.abs() > 0.));
+    }
+}
@@ -0,0 +1,204 @@
+use super::{init, named, Learnable, Register};
+use crate::variable::{AnyVarDiff, Data, Gradient, Input, RawParam, Tensor, VarDiff};
+use ndarray::{Ix1, Ix2, Ix3};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{cell::Cell, rc::Rc};
+
+/// Applies a **bilinear transformation** to a pair of incoming data.
+///
+/// Each output feature *i* is computed as *y_i = x1ᵀ W_i x2 + b_i*, where *W_i* is the *i*-th
+/// matrix of the learnable weight.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct Bilinear {
+    pub weight: Learnable<Ix3>,
+    pub bias: Learnable<Ix1>,
+}
+
+impl Bilinear {
+    /// Creates a bilinear layer.
+    ///
+    /// # Arguments
+    ///
+    /// * `in1_features` - size of each sample of the first input.
+    ///
+    /// * `in2_features` - size of each sample of the second input.
+    ///
+    /// * `out_features` - size of each output sample.
+    ///
+    /// The learnable weight of the layer is of shape `(out_features, in1_features, in2_features)`.
+    /// The learnable bias of the layer is of shape `out_features`.
+    ///
+    /// The values for both the weight and bias are initialized from *U(-k, k)* where
+    /// `k = (1. / in1_features as f32).sqrt()`.
+    pub fn new(in1_features: usize, in2_features: usize, out_features: usize) -> Self {
+        let k = (1. / (in1_features as f32)).sqrt();
+
+        let weight =
+            Input::new(Tensor::zeros((out_features, in1_features, in2_features))).requires_grad();
+        init::uniform(&weight, -k, k);
+
+        let bias = Input::new(Tensor::zeros(out_features)).requires_grad();
+        init::uniform(&bias, -k, k);
+
+        Self { weight, bias }
+    }
+
+    /// Applies the bilinear transformation *y = x1ᵀ A x2 + b* to the incoming data.
+    ///
+    /// # Arguments
+    ///
+    /// * `input1` - a variable of shape *(N, in1_features)*.
+    ///
+    /// * `input2` - a variable of shape *(N, in2_features)*.
+    ///
+    /// The output's shape will be *(N, out_features)*.
+    ///
+    /// # Panics
+    ///
+    /// If the inputs have a different number of samples or the wrong number of features.
+    pub fn forward<T1, U1, T2, U2>(
+        &self,
+        input1: VarDiff<T1, U1>,
+        input2: VarDiff<T2, U2>,
+    ) -> VarDiff<impl Data<Dim = Ix2>, impl Gradient<Dim = Ix2>>
+    where
+        T1: Data<Dim = Ix2> + 'static,
+        U1: Gradient<Dim = Ix2> + 'static,
+        T2: Data<Dim = Ix2> + 'static,
+        U2: Gradient<Dim = Ix2> + 'static,
+    {
+        let (out_features, in1_features, in2_features) = self.weight.data().dim();
+        let (batch, features1) = input1.data().dim();
+        let (batch2, features2) = input2.data().dim();
+        assert_eq!(
+            batch, batch2,
+            "error: the inputs have {} and {} samples.",
+            batch, batch2
+        );
+        assert!(
+            features1 == in1_features && features2 == in2_features,
+            "error: expected inputs with {} and {} features, found {} and {}.",
+            in1_features,
+            in2_features,
+            features1,
+            features2
+        );
+
+        // The first input is multiplied by all the matrices of the weight at once, giving an
+        // (N, out_features, in2_features) variable that is then multiplied by the second input.
+        let weight = self
+            .weight
+            .clone()
+            .permute((1, 0, 2))
+            .reshape((in1_features, out_features * in2_features));
+        let projected = input1
+            .mm(weight)
+            .reshape((batch, out_features, in2_features));
+        let output = projected
+            .bmm(input2.reshape((batch, in2_features, 1)))
+            .reshape((batch, out_features));
+
+        output + self.bias.clone()
+    }
+
+    /// Returns the weight and the bias of this `Bilinear` instance, paired with their names.
+    pub fn named_parameters(&self) -> Vec<(String, AnyVarDiff)> {
+        vec![named("weight", &self.weight), named("bias", &self.bias)]
+    }
+}
+
+impl Register for Bilinear {
+    /// Registers the weight and the bias of this `Bilinear` instance.
+    fn register_params(&self, params: &mut Vec<RawParam>) {
+        self.weight.register_params(params);
+        self.bias.register_params(params);
+    }
+
+    fn register_status(&mut self, _: Rc<Cell<bool>>) {}
+}
+
+#[cfg(test)]
+mod test {
+    use super::Bilinear;
+    use ndarray::{array, Array, Array2};
+
+    #[test]
+    fn creation() {
+        let bilinear = Bilinear::new(4, 3, 5);
+
+        assert_eq!(bilinear.weight.data().shape(), &[5, 4, 3]);
+        assert_eq!(bilinear.bias.data().shape(), &[5]);
+        assert_eq!(bilinear.named_parameters().len(), 2);
+    }
+
+    #[test]
+    fn forward() {
+        let bilinear = Bilinear::new(4, 3, 5);
+        let input1 = crate::rand((2, 4)).requires_grad();
+        let input2 = crate::rand((2, 3)).requires_grad();
+        let output = bilinear.forward(input1.clone(), input2.clone());
+        output.forward();
+
+        assert_eq!(output.data().shape(), &[2, 5]);
+
+        let (input1, input2) = (input1.data(), input2.data());
+        let weight = bilinear.weight.data();
+        let bias = bilinear.bias.data();
+        let expected = Array2::from_shape_fn((2, 5), |(n, i)| {
+            let matrix = weight.index_axis(ndarray::Axis(0), i);
+            input1.row(n).dot(&matrix.dot(&input2.row(n))) + bias[i]
+        });
+        assert!(output
+            .data()
+            .iter()
+            .zip(expected.iter())
+            .all(|(actual, expected)| (actual - expected).abs() <= 1e-5));
+    }
+
+    #[test]
+    fn identity() {
+        // With the i-th weight matrix being the unit matrix with a one at (i, i), each output
+        // feature is the product of the corresponding input features.
+        let bilinear = Bilinear::new(3, 3, 3);
+        *bilinear.weight.data_mut() =
+            Array::from_shape_fn((3, 3, 3), |(i, j, k)| (i == j && j == k) as u8 as f32);
+        bilinear.bias.data_mut().fill(0.);
+
+        let input1 = array![[1., 2., 3.], [-1., 0.5, 4.]];
+        let input2 = array![[4., -5., 6.], [2., 2., -0.5]];
+        let output = bilinear.forward(
+            crate::from_ndarray(input1.clone()).requires_grad(),
+            crate::from_ndarray(input2.clone()).requires_grad(),
+        );
+        output.forward();
+
+        assert_eq!(*output.data(), input1 * input2);
+    }
+
+    #[test]
+    fn backward() {
+        let bilinear = Bilinear::new(4, 3, 5);
+        let input1 = crate::rand((2, 4)).requires_grad();
+        let input2 = crate::rand((2, 3)).requires_grad();
+        let loss = bilinear.forward(input1.clone(), input2.clone()).sum();
+        loss.forward();
+        loss.backward(1.);
+
+        assert_eq!(loss.parameters().len(), 4);
+        assert!(bilinear.weight.grad().iter().any(|el| *el != 0.));
+        assert_eq!(*bilinear.bias.grad(), array![2., 2., 2., 2., 2.]);
+        assert!(input1.grad().iter().any(|el| *el != 0.));
+        assert!(input2.grad().iter().any(|el| *el != 0.));
+    }
+
+    #[test]
+    #[should_panic(expected = "error: expected inputs with 4 and 3 features, found 3 and 3.")]
+    fn wrong_features() {
+        let bilinear = Bilinear::new(4, 3, 5);
+        bilinear.forward(
+            crate::rand((2, 3)).requires_grad(),
+            crate::rand((2, 3)).requires_grad(),
+        );
+    }
+}
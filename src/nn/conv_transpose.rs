@@ -0,0 +1,278 @@
+use super::{init, named, Learnable, Register};
+use crate::variable::{
+    AnyVarDiff, ConvolveTransposed, Data, Gradient, Input, Overwrite, RawParam, Tensor, VarDiff,
+};
+use ndarray::{Ix3, Ix4};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{cell::Cell, rc::Rc};
+
+/// Applies a **spatial transposed convolution** over an input signal composed of several input
+/// planes.
+///
+/// The transposed convolution is the gradient of [`Conv2d`](super::Conv2d) with respect to its
+/// input, it is often used to upsample feature maps in decoders and generators. Each spatial
+/// axis of the output is of length
+///
+/// ```text
+/// (input - 1) * stride - 2 * padding + dilation * (kernel - 1) + output_padding + 1
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use neuronika::nn::ConvTranspose2d;
+///
+/// // Doubles the height and the width of the input.
+/// let deconv = ConvTranspose2d::new(4, 2, (3, 3), (2, 2), (1, 1), (1, 1), true);
+///
+/// let output = deconv.forward(neuronika::rand((8, 4, 5, 6)));
+/// output.forward();
+/// assert_eq!(output.data().shape(), &[8, 2, 10, 12]);
+/// ```
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct ConvTranspose2d {
+    pub stride: (usize, usize),
+    pub padding: (usize, usize),
+    pub output_padding: (usize, usize),
+    pub dilation: (usize, usize),
+    pub weight: Learnable<Ix4>,
+    pub bias: Option<Learnable<Ix3>>,
+}
+
+impl ConvTranspose2d {
+    /// Creates a new ConvTranspose2d.
+    ///
+    /// # Arguments
+    ///
+    /// * `in_channels` - number of planes in the input signal.
+    ///
+    /// * `out_channels` - number of planes in the output signal.
+    ///
+    /// * `kernel_size` - size of the kernel, a 2-tuple for this two-dimensional case.
+    ///
+    /// * `stride` - stride of the transposed convolution, a 2-tuple for this two-dimensional
+    /// case.
+    ///
+    /// * `padding` - padding stripped off each side of the output, a 2-tuple for this
+    /// two-dimensional case.
+    ///
+    /// * `output_padding` - additional size added at the end of each spatial axis of the output,
+    /// a 2-tuple for this two-dimensional case. Each component must be smaller than either the
+    /// stride or the dilation of the same axis.
+    ///
+    /// * `bias` - whether to add a learnable bias to the output.
+    ///
+    /// The weight is of shape *(in_channels, out_channels, kernel_h, kernel_w)*. The weight and
+    /// the bias are initialized from *U(-k, k)* where
+    /// `k = (1. /(out_channels * kernel_h * kernel_w) as f32).sqrt()`. The dilation is 1 along
+    /// both axes and can be changed afterwards.
+    pub fn new(
+        in_channels: usize,
+        out_channels: usize,
+        kernel_size: (usize, usize),
+        stride: (usize, usize),
+        padding: (usize, usize),
+        output_padding: (usize, usize),
+        bias: bool,
+    ) -> Self {
+        let (kernel_h, kernel_w) = kernel_size;
+        let k = (1. / (out_channels * kernel_h * kernel_w) as f32).sqrt();
+
+        let weight = Input::new(Tensor::zeros((
+            in_channels,
+            out_channels,
+            kernel_h,
+            kernel_w,
+        )))
+        .requires_grad();
+        init::uniform(&weight, -k, k);
+
+        let bias = bias.then(|| {
+            let bias = Input::new(Tensor::zeros((out_channels, 1, 1))).requires_grad();
+            init::uniform(&bias, -k, k);
+            bias
+        });
+
+        Self {
+            stride,
+            padding,
+            output_padding,
+            dilation: (1, 1),
+            weight,
+            bias,
+        }
+    }
+
+    /// Computes a 2-dimensional transposed convolution.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - the signal to convolve.
+    ///
+    /// The **input** must be of shape *(N, Cin, H, W)*
+    /// * **N** is the batch size
+    /// * **Cin** is the number of input channels
+    /// * **H** is the **height** of the input
+    /// * **W** is the **width** of the input
+    ///
+    /// The resulting output shape will be *(N, Cout, Hout, Wout)*
+    ///
+    /// # Panics
+    ///
+    /// If the number of channels of the input doesn't match the one of the layer, if a component
+    /// of the output padding is not smaller than either the stride or the dilation of the same
+    /// axis or if the padding leaves an empty output.
+    pub fn forward<I, T, U>(
+        &self,
+        input: I,
+    ) -> VarDiff<dyn Data<Dim = Ix4>, dyn Gradient<Dim = Ix4>>
+    where
+        I: ConvolveTransposed<I, Learnable<Ix4>>,
+        I::Output: Into<VarDiff<T, U>>,
+        T: Data<Dim = Ix4> + 'static,
+        U: Gradient<Dim = Ix4> + Overwrite + 'static,
+    {
+        let (stride_h, stride_w) = self.stride;
+        let (dilation_h, dilation_w) = self.dilation;
+        let (padding_h, padding_w) = self.padding;
+        let (output_padding_h, output_padding_w) = self.output_padding;
+
+        let output: VarDiff<T, U> = I::convolve_transposed(
+            input,
+            self.weight.clone(),
+            &[stride_h, stride_w],
+            &[dilation_h, dilation_w],
+            &[padding_h, padding_w],
+            &[output_padding_h, output_padding_w],
+        )
+        .into();
+
+        match &self.bias {
+            Some(bias) => (output + bias.clone()).into_dyn(),
+            None => output.into_dyn(),
+        }
+    }
+
+    /// Returns the weight and the bias, if any, of this `ConvTranspose2d` instance, paired with
+    /// their names.
+    pub fn named_parameters(&self) -> Vec<(String, AnyVarDiff)> {
+        let mut params = vec![named("weight", &self.weight)];
+        if let Some(bias) = &self.bias {
+            params.push(named("bias", bias));
+        }
+        params
+    }
+}
+
+impl Register for ConvTranspose2d {
+    /// Registers the weight and the bias, if any, of this `ConvTranspose2d` instance.
+    fn register_params(&self, params: &mut Vec<RawParam>) {
+        self.weight.register_params(params);
+        if let Some(bias) = &self.bias {
+            bias.register_params(params);
+        }
+    }
+
+    fn register_status(&mut self, _: Rc<Cell<bool>>) {}
+}
+
+#[cfg(test)]
+mod test {
+    use super::{super::Conv2d, ConvTranspose2d};
+    use crate::variable::{check_gradient, Zero};
+    use ndarray::{s, Array, Dimension};
+
+    #[test]
+    fn creation() {
+        let deconv = ConvTranspose2d::new(4, 3, (2, 5), (1, 1), (0, 0), (0, 0), true);
+
+        assert_eq!(deconv.weight.data().shape(), &[4, 3, 2, 5]);
+        assert_eq!(deconv.bias.as_ref().unwrap().data().shape(), &[3, 1, 1]);
+        assert_eq!(deconv.named_parameters().len(), 2);
+
+        let deconv = ConvTranspose2d::new(4, 3, (2, 5), (1, 1), (0, 0), (0, 0), false);
+        assert!(deconv.bias.is_none());
+        assert_eq!(deconv.named_parameters().len(), 1);
+    }
+
+    #[test]
+    fn output_shape() {
+        for (stride, padding, output_padding, dilation, expected) in [
+            ((1, 1), (0, 0), (0, 0), (1, 1), [7, 8]),
+            ((2, 2), (0, 0), (0, 0), (1, 1), [11, 13]),
+            ((2, 3), (1, 0), (1, 2), (1, 1), [10, 20]),
+            ((1, 1), (2, 1), (0, 0), (2, 2), [5, 8]),
+        ] {
+            let mut deconv =
+                ConvTranspose2d::new(2, 3, (3, 3), stride, padding, output_padding, true);
+            deconv.dilation = dilation;
+
+            let output = deconv.forward(crate::rand((1, 2, 5, 6)));
+            output.forward();
+            assert_eq!(output.data().shape()[2..], expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: output padding 1 must be smaller than either stride 1 or dilation 1."
+    )]
+    fn output_padding_too_large() {
+        ConvTranspose2d::new(2, 3, (3, 3), (1, 1), (0, 0), (1, 0), true)
+            .forward(crate::rand((1, 2, 5, 6)));
+    }
+
+    #[test]
+    fn stride_one_equals_convolution() {
+        let deconv = ConvTranspose2d::new(2, 3, (3, 2), (1, 1), (1, 0), (0, 0), true);
+        let input = crate::rand((2, 2, 4, 5));
+
+        // With unit stride the transposed convolution is a convolution of the input, padded so
+        // that the kernel slides past its borders, by the flipped kernel with swapped channels.
+        let mut conv = Conv2d::new(2, 3, (3, 2), (1, 1), (1, 1), Zero, (1, 1), 1, true);
+        conv.weight.data_mut().assign(
+            &deconv
+                .weight
+                .data()
+                .slice(s![.., .., ..;-1, ..;-1])
+                .permuted_axes([1, 0, 2, 3]),
+        );
+        conv.bias = deconv.bias.clone();
+
+        let (expected, output) = (conv.forward(input.clone()), deconv.forward(input));
+        expected.forward();
+        output.forward();
+
+        assert_eq!(output.data().shape(), &[2, 3, 4, 6]);
+        assert_eq!(output.data().shape(), expected.data().shape());
+        assert!(output
+            .data()
+            .iter()
+            .zip(expected.data().iter())
+            .all(|(actual, expected)| (actual - expected).abs() <= 1e-5));
+    }
+
+    #[test]
+    fn backward() {
+        let deconv = ConvTranspose2d::new(3, 2, (3, 2), (2, 1), (1, 1), (1, 0), true);
+        let input = crate::rand((2, 3, 3, 4)).requires_grad();
+
+        let output_shape = deconv.forward(input.clone()).data().raw_dim();
+        assert_eq!(output_shape.slice(), &[2, 2, 6, 3]);
+        let coefficients = Array::from_shape_fn(output_shape, |(n, c, h, w)| {
+            ((n + 2 * c + 3 * h + 5 * w) as f32 * 0.7).sin()
+        });
+
+        let loss = (deconv.forward(input.clone()) * crate::from_ndarray(coefficients)).sum();
+        check_gradient(&deconv.weight, &loss);
+        check_gradient(deconv.bias.as_ref().unwrap(), &loss);
+        check_gradient(&input, &loss);
+
+        // Only the kernel and the bias are differentiable.
+        let loss = deconv
+            .forward(crate::from_ndarray(input.data().clone()))
+            .sum();
+        assert_eq!(loss.parameters().len(), 2);
+    }
+}
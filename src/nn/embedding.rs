@@ -0,0 +1,190 @@
+use super::{init, named, Learnable, Register};
+use crate::variable::{
+    AnyVarDiff, Data, Embedding as EmbeddingNode, EmbeddingBackward, Gradient, Input, RawParam,
+    Tensor, Var, VarDiff,
+};
+use ndarray::{Array2, Axis, Ix2, Ix3};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{cell::Cell, rc::Rc};
+
+/// A lookup table storing **embeddings** of a fixed dictionary and size.
+///
+/// The input is a tensor of indices of shape *(N, L)*, such as a batch of tokenized sequences, and
+/// the output is the tensor of shape *(N, L, embedding_dim)* holding the corresponding rows of
+/// the table. Only the rows that were looked up receive a gradient, which is accumulated when an
+/// index appears more than once.
+///
+/// # Examples
+///
+/// ```
+/// use neuronika::nn::Embedding;
+///
+/// // A dictionary of 10 words, each represented by 3 features.
+/// let embedding = Embedding::new(10, 3, None);
+///
+/// let output = embedding.forward(ndarray::array![[1, 2, 4, 5], [4, 3, 2, 9]]);
+/// output.forward();
+/// assert_eq!(output.data().shape(), &[2, 4, 3]);
+/// ```
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct Embedding {
+    pub weight: Learnable<Ix2>,
+    pub padding_idx: Option<usize>,
+}
+
+impl Embedding {
+    /// Creates an embedding layer.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_embeddings` - size of the dictionary.
+    ///
+    /// * `embedding_dim` - size of each embedding.
+    ///
+    /// * `padding_idx` - optional index of the padding row, which never receives a gradient.
+    ///
+    /// The learnable weight of the layer is of shape `(num_embeddings, embedding_dim)` and is
+    /// initialized from *N(0, 1)*, except for the padding row which is filled with zeros.
+    ///
+    /// # Panics
+    ///
+    /// If `padding_idx` is out of bounds.
+    pub fn new(num_embeddings: usize, embedding_dim: usize, padding_idx: Option<usize>) -> Self {
+        let weight = Input::new(Tensor::zeros((num_embeddings, embedding_dim))).requires_grad();
+        init::normal(&weight, 0., 1.);
+        if let Some(index) = padding_idx {
+            assert!(
+                index < num_embeddings,
+                "error: padding index {} is out of bounds for an embedding table with {} rows.",
+                index,
+                num_embeddings
+            );
+            weight.data_mut().index_axis_mut(Axis(0), index).fill(0.);
+        }
+
+        Self {
+            weight,
+            padding_idx,
+        }
+    }
+
+    /// Looks up the embeddings of the incoming indices.
+    ///
+    /// # Arguments
+    ///
+    /// `indices` - indices of shape *(N, L)*, the output's shape will be
+    /// *(N, L, embedding_dim)*.
+    ///
+    /// # Panics
+    ///
+    /// If an index is out of bounds.
+    pub fn forward(
+        &self,
+        indices: Array2<usize>,
+    ) -> VarDiff<impl Data<Dim = Ix3>, impl Gradient<Dim = Ix3>> {
+        let weight = self.weight.clone();
+        let var = Var::from(
+            EmbeddingNode::new(weight.var.node, indices.clone()),
+            weight.var.past,
+        );
+
+        let backward_node = EmbeddingBackward::new(weight.node, indices, self.padding_idx);
+        VarDiff::from(backward_node, weight.past, var)
+    }
+
+    /// Returns the weight of this `Embedding` instance, paired with its name.
+    pub fn named_parameters(&self) -> Vec<(String, AnyVarDiff)> {
+        vec![named("weight", &self.weight)]
+    }
+}
+
+impl Register for Embedding {
+    /// Registers the weight of this `Embedding` instance.
+    fn register_params(&self, params: &mut Vec<RawParam>) {
+        self.weight.register_params(params);
+    }
+
+    fn register_status(&mut self, _: Rc<Cell<bool>>) {}
+}
+
+#[cfg(test)]
+mod test {
+    use super::Embedding;
+    use crate::optim::{L2, SGD};
+    use ndarray::Axis;
+
+    #[test]
+    fn creation() {
+        let embedding = Embedding::new(5, 3, Some(4));
+
+        assert_eq!(embedding.weight.data().shape(), &[5, 3]);
+        assert!(embedding
+            .weight
+            .data()
+            .index_axis(Axis(0), 4)
+            .iter()
+            .all(|el| el.abs() <= f32::EPSILON));
+    }
+
+    #[test]
+    #[should_panic(expected = "error: padding index 5 is out of bounds for an embedding table")]
+    fn creation_padding_out_of_bounds() {
+        Embedding::new(5, 3, Some(5));
+    }
+
+    #[test]
+    fn forward() {
+        let embedding = Embedding::new(5, 3, None);
+
+        let output = embedding.forward(ndarray::array![[4, 0], [1, 4]]);
+        output.forward();
+        assert_eq!(output.data().shape(), &[2, 2, 3]);
+
+        let weight = embedding.weight.data();
+        assert_eq!(output.data().slice(ndarray::s![0, 0, ..]), weight.row(4));
+        assert_eq!(output.data().slice(ndarray::s![0, 1, ..]), weight.row(0));
+        assert_eq!(output.data().slice(ndarray::s![1, 0, ..]), weight.row(1));
+        assert_eq!(output.data().slice(ndarray::s![1, 1, ..]), weight.row(4));
+    }
+
+    #[test]
+    fn backward_repeated_indices() {
+        let embedding = Embedding::new(5, 2, None);
+
+        let output = embedding.forward(ndarray::array![[1, 3, 1], [1, 0, 3]]);
+        let loss = output.sum();
+        loss.forward();
+        loss.backward(1.);
+
+        assert_eq!(loss.parameters().len(), 1);
+        assert_eq!(
+            *embedding.weight.grad(),
+            ndarray::array![[1., 1.], [3., 3.], [0., 0.], [2., 2.], [0., 0.]]
+        );
+    }
+
+    #[test]
+    fn training_padding_idx() {
+        let embedding = Embedding::new(4, 3, Some(0));
+
+        let output = embedding.forward(ndarray::array![[2, 0, 0], [1, 3, 0]]);
+        let loss = (output * crate::rand((2, 3, 3))).sum();
+        let optimizer = SGD::new(loss.parameters(), 0.1, L2::new(0.));
+        let initial = embedding.weight.data().clone();
+
+        loss.forward();
+        loss.backward(1.);
+        assert!(embedding
+            .weight
+            .grad()
+            .row(0)
+            .iter()
+            .all(|el| el.abs() <= f32::EPSILON));
+
+        optimizer.step();
+        let weight = embedding.weight.data();
+        assert!(weight.row(0).iter().all(|el| el.abs() <= f32::EPSILON));
+        assert_ne!(weight.row(2), initial.row(2));
+    }
+}
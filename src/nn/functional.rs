@@ -0,0 +1,14 @@
+//! # Functional interface.
+//!
+//! Stateless counterparts of some of the layers, operating directly on the variables they're
+//! given without holding any learnable parameter.
+//!
+//! * [`scaled_dot_product_attention`] - Computes the scaled dot-product attention of a batch of
+//! queries over a batch of keys and values, optionally masked by an [`AttentionMask`].
+//!
+//! * [`cosine_similarity`] - Computes the cosine similarity between the rows or the columns of
+//! two matrices.
+pub use super::{
+    attention::{scaled_dot_product_attention, AttentionMask},
+    similarity::cosine_similarity,
+};
@@ -0,0 +1,319 @@
+use super::{named, Learnable, Register};
+use crate::variable::{AnyVarDiff, Data, Gradient, Input, RawParam, Tensor, Var, VarDiff};
+use ndarray::{Ix1, Ix4};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{cell::Cell, rc::Rc};
+
+/// Group normalization input.
+///
+/// This trait is implemented by `Var` and `VarDiff`.
+pub trait GroupNormInput {
+    /// Normalizes `self`, of shape *(N, C, H, W)*, over `num_groups` groups of channels, then
+    /// scales each channel of the result by `weight` and shifts it by `bias`.
+    fn group_norm(
+        self,
+        num_groups: usize,
+        eps: f32,
+        weight: Learnable<Ix1>,
+        bias: Learnable<Ix1>,
+    ) -> VarDiff<dyn Data<Dim = Ix4>, dyn Gradient<Dim = Ix4>>;
+}
+
+/// Returns the shape in which an input of shape `(n, c, h, w)` is viewed when normalized over
+/// `num_groups` groups, one group of channels for each entry of the second axis.
+///
+/// # Panics
+///
+/// If the channels of the input are not the expected ones.
+fn grouped_shape(
+    (n, c, h, w): (usize, usize, usize, usize),
+    num_groups: usize,
+    num_channels: usize,
+) -> (usize, usize, usize, usize) {
+    assert_eq!(
+        c, num_channels,
+        "error: expected an input with {} channels, found {}.",
+        num_channels, c
+    );
+
+    (n, num_groups, c / num_groups, h * w)
+}
+
+impl<T: ?Sized, U: ?Sized> GroupNormInput for VarDiff<T, U>
+where
+    T: Data<Dim = Ix4> + 'static,
+    U: Gradient<Dim = Ix4> + 'static,
+{
+    fn group_norm(
+        self,
+        num_groups: usize,
+        eps: f32,
+        weight: Learnable<Ix1>,
+        bias: Learnable<Ix1>,
+    ) -> VarDiff<dyn Data<Dim = Ix4>, dyn Gradient<Dim = Ix4>> {
+        let shape = self.data().raw_dim();
+        let channels = (1, shape[1], 1, 1);
+        let grouped = grouped_shape(self.data().dim(), num_groups, weight.data().len());
+        let normalized = self.reshape(grouped).layer_norm(2, eps).reshape(shape);
+
+        (normalized * weight.reshape(channels) + bias.reshape(channels)).into_dyn()
+    }
+}
+
+impl<T: ?Sized> GroupNormInput for Var<T>
+where
+    T: Data<Dim = Ix4> + 'static,
+{
+    fn group_norm(
+        self,
+        num_groups: usize,
+        eps: f32,
+        weight: Learnable<Ix1>,
+        bias: Learnable<Ix1>,
+    ) -> VarDiff<dyn Data<Dim = Ix4>, dyn Gradient<Dim = Ix4>> {
+        let shape = self.data().raw_dim();
+        let channels = (1, shape[1], 1, 1);
+        let grouped = grouped_shape(self.data().dim(), num_groups, weight.data().len());
+        let normalized = self.reshape(grouped).layer_norm(2, eps).reshape(shape);
+
+        (normalized * weight.reshape(channels) + bias.reshape(channels)).into_dyn()
+    }
+}
+
+/// Applies **group normalization** over a batch of images as described in the paper
+/// [Group Normalization](https://arxiv.org/abs/1803.08494).
+///
+/// ```text
+///       x - E[x]
+/// ʏ = ―――――――――――――――― * weight + bias
+///     √(Var[x] + eps)
+/// ```
+///
+/// The channels are split in `num_groups` groups and the mean and the variance are computed
+/// separately for each sample and each group, so that the result doesn't depend on the size of
+/// the batch. The variance is the biased one. The weight and the bias are applied channel-wise.
+///
+/// With a single group this is the same as a layer normalization over the channels and the
+/// spatial dimensions, while with a group for each channel each image plane is normalized on its
+/// own.
+///
+/// # Examples
+///
+/// ```
+/// use neuronika::nn::GroupNorm;
+///
+/// // A batch of 4 images with 6 channels each.
+/// let input = neuronika::rand((4, 6, 5, 5));
+/// let group_norm = GroupNorm::new(3, 6, 1e-5);
+///
+/// let output = group_norm.forward(input);
+/// output.forward();
+/// assert_eq!(output.data().shape(), &[4, 6, 5, 5]);
+/// ```
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct GroupNorm {
+    pub weight: Learnable<Ix1>,
+    pub bias: Learnable<Ix1>,
+    pub num_groups: usize,
+    pub eps: f32,
+}
+
+impl GroupNorm {
+    /// Creates a group normalization layer.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_groups` - number of groups the channels are split in.
+    ///
+    /// * `num_channels` - number of channels of the input.
+    ///
+    /// * `eps` - value added to the variance for numerical stability.
+    ///
+    /// The learnable weight and bias of the layer have shape `num_channels` and are respectively
+    /// initialized with ones and zeros.
+    ///
+    /// # Panics
+    ///
+    /// If `num_groups` is zero or doesn't divide `num_channels`.
+    pub fn new(num_groups: usize, num_channels: usize, eps: f32) -> Self {
+        assert!(
+            num_groups > 0,
+            "error: the number of groups must be positive."
+        );
+        assert_eq!(
+            num_channels % num_groups,
+            0,
+            "error: {} channels cannot be split in {} groups.",
+            num_channels,
+            num_groups
+        );
+
+        let weight = Input::new(Tensor::ones(num_channels)).requires_grad();
+        let bias = Input::new(Tensor::zeros(num_channels)).requires_grad();
+
+        Self {
+            weight,
+            bias,
+            num_groups,
+            eps,
+        }
+    }
+
+    /// Applies the group normalization to the incoming data.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - a variable of shape *(N, C, H, W)*, the output has the same shape as the input.
+    ///
+    /// # Panics
+    ///
+    /// If the input hasn't `num_channels` channels.
+    pub fn forward<I: GroupNormInput>(
+        &self,
+        input: I,
+    ) -> VarDiff<dyn Data<Dim = Ix4>, dyn Gradient<Dim = Ix4>> {
+        input.group_norm(
+            self.num_groups,
+            self.eps,
+            self.weight.clone(),
+            self.bias.clone(),
+        )
+    }
+
+    /// Returns the weight and the bias of this `GroupNorm` instance, paired with their names.
+    pub fn named_parameters(&self) -> Vec<(String, AnyVarDiff)> {
+        vec![named("weight", &self.weight), named("bias", &self.bias)]
+    }
+}
+
+impl Register for GroupNorm {
+    /// Registers the weight and the bias of this `GroupNorm` instance.
+    fn register_params(&self, params: &mut Vec<RawParam>) {
+        self.weight.register_params(params);
+        self.bias.register_params(params);
+    }
+
+    fn register_status(&mut self, _: Rc<Cell<bool>>) {}
+}
+
+#[cfg(test)]
+mod test {
+    use super::{super::LayerNorm, GroupNorm};
+    use ndarray::{Array, Axis, Ix4};
+
+    #[test]
+    fn creation() {
+        let group_norm = GroupNorm::new(2, 6, 1e-5);
+
+        assert_eq!(group_norm.weight.data().shape(), &[6]);
+        assert_eq!(group_norm.bias.data().shape(), &[6]);
+        assert_eq!(group_norm.named_parameters().len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "error: 6 channels cannot be split in 4 groups.")]
+    fn creation_indivisible() {
+        GroupNorm::new(4, 6, 1e-5);
+    }
+
+    #[test]
+    #[should_panic(expected = "error: expected an input with 6 channels, found 4.")]
+    fn wrong_channels() {
+        GroupNorm::new(2, 6, 1e-5).forward(crate::rand((2, 4, 3, 3)));
+    }
+
+    #[test]
+    fn single_group() {
+        // A single group normalizes each sample as a whole, as the layer normalization does.
+        let input = crate::rand((3, 4, 5, 2)) * 10.;
+        let group_norm = GroupNorm::new(1, 4, 1e-5);
+        let layer_norm = LayerNorm::<Ix4>::new(&[4, 5, 2], 1e-5);
+
+        let output = group_norm.forward(input.clone());
+        let expected = layer_norm.forward(input);
+        output.forward();
+        expected.forward();
+
+        assert!(output
+            .data()
+            .iter()
+            .zip(expected.data().iter())
+            .all(|(actual, expected)| (actual - expected).abs() <= 1e-5));
+    }
+
+    #[test]
+    fn group_per_channel() {
+        // A group for each channel normalizes each image plane on its own, as the instance
+        // normalization does.
+        let input = crate::rand((3, 4, 5, 2)) * 10.;
+        let group_norm = GroupNorm::new(4, 4, 1e-5);
+        let output = group_norm.forward(input.clone());
+        output.forward();
+
+        let mut expected = input.data().clone();
+        for mut sample in expected.axis_iter_mut(Axis(0)) {
+            for mut plane in sample.axis_iter_mut(Axis(0)) {
+                let mean = plane.mean().unwrap();
+                let variance = plane.mapv(|el| (el - mean).powi(2)).mean().unwrap();
+                plane.mapv_inplace(|el| (el - mean) / (variance + 1e-5).sqrt());
+            }
+        }
+
+        assert!(output
+            .data()
+            .iter()
+            .zip(expected.iter())
+            .all(|(actual, expected)| (actual - expected).abs() <= 1e-4));
+    }
+
+    #[test]
+    fn backward_finite_differences() {
+        let group_norm = GroupNorm::new(2, 4, 1e-5);
+        *group_norm.weight.data_mut() = crate::rand(4).data().to_owned() + 0.5;
+        *group_norm.bias.data_mut() = crate::rand(4).data().to_owned();
+        let input = crate::rand((2, 4, 3, 2)).data().to_owned() * 4.;
+        let coefficients = crate::rand((2, 4, 3, 2));
+
+        let x = crate::from_ndarray(input.clone()).requires_grad();
+        let loss = (group_norm.forward(x.clone()) * coefficients.clone()).sum();
+        loss.forward();
+        loss.backward(1.);
+        assert_eq!(loss.parameters().len(), 3);
+
+        let objective = |input: Array<f32, Ix4>| {
+            let loss =
+                (group_norm.forward(crate::from_ndarray(input)) * coefficients.clone()).sum();
+            loss.forward();
+            let value = loss.data()[()];
+            value
+        };
+        let h = 1e-2;
+        for (i, grad) in x.grad().iter().enumerate() {
+            let (mut plus, mut minus) = (input.clone(), input.clone());
+            plus.as_slice_mut().unwrap()[i] += h;
+            minus.as_slice_mut().unwrap()[i] -= h;
+
+            let numeric = (objective(plus) - objective(minus)) / (2. * h);
+            assert!(
+                (grad - numeric).abs() <= 1e-2,
+                "analytic: {}, numeric: {}",
+                grad,
+                numeric
+            );
+        }
+
+        // The gradient of the bias of a channel is the sum of the coefficients of that channel.
+        let expected = coefficients
+            .data()
+            .sum_axis(Axis(3))
+            .sum_axis(Axis(2))
+            .sum_axis(Axis(0));
+        assert!(group_norm
+            .bias
+            .grad()
+            .iter()
+            .zip(expected.iter())
+            .all(|(actual, expected)| (actual - expected).abs() <= 1e-4));
+    }
+}
@@ -0,0 +1,309 @@
+use super::{
+    rnn::{initial_states, stack_steps, InitialState, RecurrentInput, RecurrentLayer, State, Step},
+    Register,
+};
+use crate::variable::{AnyVarDiff, Data, Gradient, RawParam, VarDiff};
+use ndarray::Ix3;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{cell::Cell, rc::Rc};
+
+/// A multi-layer **gated recurrent unit (GRU)** network.
+///
+/// For each time step *t* each layer computes
+///
+/// ```text
+/// rₜ = σ(Wᵢᵣxₜ + bᵢᵣ + Wₕᵣhₜ₋₁ + bₕᵣ)
+/// zₜ = σ(Wᵢzxₜ + bᵢz + Wₕzhₜ₋₁ + bₕz)
+/// nₜ = tanh(Wᵢₙxₜ + bᵢₙ + rₜ * (Wₕₙhₜ₋₁ + bₕₙ))
+/// hₜ = (1 - zₜ) * nₜ + zₜ * hₜ₋₁
+/// ```
+///
+/// where *xₜ* is the input of the first layer or the output of the previous layer and *h₀* is the
+/// initial state of the layer. The weights of the reset, update and new gates are stacked, in this
+/// order, as in [`GRUCell`](super::GRUCell). The computational graph is unrolled over the whole
+/// sequence at each call of [`.forward()`](GRU::forward()).
+///
+/// # Examples
+///
+/// ```
+/// use neuronika::nn::GRU;
+///
+/// // Sequences of 6 steps, in batches of 4, with 5 features each.
+/// let gru = GRU::new(5, 3, 2, true);
+/// let (output, hidden) = gru.forward(neuronika::rand((6, 4, 5)), None);
+///
+/// output.forward();
+/// hidden.forward();
+/// assert_eq!(output.data().shape(), &[6, 4, 3]);
+/// assert_eq!(hidden.data().shape(), &[2, 4, 3]);
+/// ```
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[allow(clippy::upper_case_acronyms)]
+pub struct GRU {
+    pub layers: Vec<RecurrentLayer>,
+    hidden_size: usize,
+}
+
+impl GRU {
+    /// Creates a new GRU.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_size` - number of expected features in the input.
+    ///
+    /// * `hidden_size` - number of features in the hidden state.
+    ///
+    /// * `num_layers` - number of stacked layers, each one fed with the output of the previous
+    /// one.
+    ///
+    /// * `bias` - whether the layers have biases.
+    ///
+    /// All the weight and biases are initialized from *U(-k, k)* where
+    /// `k = (1. / hidden_size as f32).sqrt()`.
+    ///
+    /// # Panics
+    ///
+    /// If `num_layers` is zero.
+    pub fn new(input_size: usize, hidden_size: usize, num_layers: usize, bias: bool) -> Self {
+        assert!(
+            num_layers > 0,
+            "error: the number of layers must be positive."
+        );
+
+        let layers = (0..num_layers)
+            .map(|layer| {
+                let input_size = if layer == 0 { input_size } else { hidden_size };
+                RecurrentLayer::new(input_size, hidden_size, 3, bias)
+            })
+            .collect();
+
+        Self {
+            layers,
+            hidden_size,
+        }
+    }
+
+    /// Runs the network over a sequence.
+    ///
+    /// * `input` - a variable of shape *(seq, batch, input_size)*.
+    ///
+    /// * `hidden` - an optional variable of shape *(num_layers, batch, hidden_size)* holding the
+    /// initial state of each layer, either differentiable or not. A null state is used when it's
+    /// `None`.
+    ///
+    /// Returns the output of the last layer at each time step, of shape
+    /// *(seq, batch, hidden_size)*, and the final state of each layer, of shape
+    /// *(num_layers, batch, hidden_size)*.
+    ///
+    /// # Panics
+    ///
+    /// If the input sequence is empty or the initial state has the wrong shape.
+    #[allow(clippy::type_complexity)]
+    pub fn forward<I: RecurrentInput>(
+        &self,
+        input: I,
+        hidden: Option<InitialState>,
+    ) -> (
+        VarDiff<impl Data<Dim = Ix3>, impl Gradient<Dim = Ix3>>,
+        VarDiff<impl Data<Dim = Ix3>, impl Gradient<Dim = Ix3>>,
+    ) {
+        let initial_states = initial_states(hidden, self.layers.len(), self.hidden_size);
+
+        let (mut input, mut steps) = (Some(input), Vec::new());
+        let mut final_states = Vec::with_capacity(self.layers.len());
+        for (layer, mut state) in self.layers.iter().zip(initial_states) {
+            // Each layer is fed with the output of the previous one.
+            let gates = match input.take() {
+                Some(input) => layer.input_gates(input),
+                None => layer.input_gates(steps),
+            };
+
+            steps = gates
+                .into_iter()
+                .map(|gates| {
+                    let next = self.step(layer, gates, state.take());
+                    state = Some(State::Differentiable(next.clone()));
+                    next
+                })
+                .collect();
+            final_states.extend(steps.last().cloned());
+        }
+
+        (stack_steps(&steps), stack_steps(&final_states))
+    }
+
+    /// Computes the next state of `layer` given the input contribution to its gates and its
+    /// current state.
+    fn step(&self, layer: &RecurrentLayer, gates: Step, state: Option<State>) -> Step {
+        let chunk = |gates: Step| {
+            let rows = gates.data().nrows();
+            gates.chunks((rows, self.hidden_size))
+        };
+
+        let igates = chunk(gates);
+        let (update_gate, new_gate) = match layer.hidden_gates(state.as_ref()).map(chunk) {
+            Some(hgates) => {
+                let reset_gate = (igates[0].clone() + hgates[0].clone()).sigmoid();
+                let update_gate = (igates[1].clone() + hgates[1].clone()).sigmoid();
+                let new_gate = (igates[2].clone() + hgates[2].clone() * reset_gate).tanh();
+                (update_gate.into_dyn(), new_gate.into_dyn())
+            }
+            // Without either a state or a bias the hidden contribution is null.
+            None => (
+                igates[1].clone().sigmoid().into_dyn(),
+                igates[2].clone().tanh().into_dyn(),
+            ),
+        };
+
+        // A missing state is a null one, so only the new gate contributes.
+        let next = (new_gate.clone() - new_gate * update_gate.clone()).into_dyn();
+        match state {
+            Some(state) => (next + state.mul(update_gate)).into_dyn(),
+            None => next,
+        }
+    }
+
+    /// Returns the weights and the biases of this `GRU` instance, paired with their names.
+    ///
+    /// The parameters of the `l`-th layer are suffixed by `_l{l}`, e.g. `weight_ih_l0`.
+    pub fn named_parameters(&self) -> Vec<(String, AnyVarDiff)> {
+        self.layers
+            .iter()
+            .enumerate()
+            .flat_map(|(index, layer)| layer.named_parameters(index))
+            .collect()
+    }
+}
+
+impl Register for GRU {
+    /// Registers the weights and the biases of this `GRU` instance.
+    fn register_params(&self, params: &mut Vec<RawParam>) {
+        self.layers
+            .iter()
+            .for_each(|layer| layer.register_params(params));
+    }
+
+    fn register_status(&mut self, _: Rc<Cell<bool>>) {}
+}
+
+#[cfg(test)]
+mod test {
+    use super::{super::GRUCell, GRU};
+    use crate::{
+        nn::loss::{mse_loss, Reduction},
+        optim::{Adam, L2},
+    };
+    use ndarray::{Array, Axis};
+
+    #[test]
+    fn creation() {
+        let gru = GRU::new(5, 3, 2, true);
+
+        assert_eq!(gru.layers.len(), 2);
+        assert_eq!(gru.layers[0].weight_ih.data().shape(), &[9, 5]);
+        assert_eq!(gru.layers[1].weight_ih.data().shape(), &[9, 3]);
+        assert_eq!(gru.layers[1].weight_hh.data().shape(), &[9, 3]);
+        assert_eq!(gru.named_parameters().len(), 8);
+
+        let gru = GRU::new(5, 3, 2, false);
+        assert_eq!(gru.named_parameters().len(), 4);
+    }
+
+    #[test]
+    fn single_step() {
+        // A single step of a single layer is a step of the GRU cell with the same parameters.
+        let gru = GRU::new(4, 3, 1, true);
+        let cell = GRUCell::new(4, 3);
+        let layer = &gru.layers[0];
+        *cell.weight_ih.data_mut() = layer.weight_ih.data().clone();
+        *cell.weight_hh.data_mut() = layer.weight_hh.data().clone();
+        *cell.bias_ih.data_mut() = layer.bias_ih.as_ref().unwrap().data().clone();
+        *cell.bias_hh.data_mut() = layer.bias_hh.as_ref().unwrap().data().clone();
+
+        let input = crate::rand((1, 2, 4));
+        let hidden = crate::rand((1, 2, 3)).requires_grad();
+        let (output, final_state) = gru.forward(input.clone(), Some(hidden.clone().into()));
+        output.forward();
+        final_state.forward();
+
+        let expected = cell.forward(
+            crate::from_ndarray(hidden.data().index_axis(Axis(0), 0).to_owned()).requires_grad(),
+            crate::from_ndarray(input.data().index_axis(Axis(0), 0).to_owned()),
+        );
+        expected.forward();
+
+        assert_eq!(*output.data(), *final_state.data());
+        assert!(output
+            .data()
+            .iter()
+            .zip(expected.data().iter())
+            .all(|(actual, expected)| (actual - expected).abs() <= 1e-6));
+
+        // A missing initial state is a null one.
+        let (output, _) = gru.forward(input.clone(), None);
+        let (expected, _) =
+            gru.forward(input, Some(crate::zeros((1, 2, 3)).requires_grad().into()));
+        output.forward();
+        expected.forward();
+        assert_eq!(*output.data(), *expected.data());
+    }
+
+    #[test]
+    fn backward() {
+        let gru = GRU::new(4, 3, 2, false);
+        let hidden = crate::zeros((2, 2, 3)).requires_grad();
+        let (output, final_state) =
+            gru.forward(crate::rand((5, 2, 4)), Some(hidden.clone().into()));
+        let loss = output.sum() + final_state.sum();
+        loss.forward();
+        loss.backward(1.);
+
+        // The weights and the initial state.
+        assert_eq!(loss.parameters().len(), 5);
+        assert!(hidden.grad().iter().all(|el| el.is_finite()));
+        assert!(hidden.grad().iter().any(|el| *el != 0.));
+    }
+
+    #[test]
+    fn copy_task() {
+        // The network learns to output, at each step, the input of the previous one.
+        let gru = GRU::new(1, 8, 2, true);
+        let sequences = Array::from_shape_vec(
+            (6, 4, 1),
+            vec![
+                1., 0., 1., 1., 0., 1., 0., 0., 1., 1., 0., 1., 0., 0., 1., 0., 1., 0., 1., 1., 0.,
+                1., 1., 0.,
+            ],
+        )
+        .unwrap();
+        let mut targets = Array::zeros((6, 4, 1));
+        for t in 1..6 {
+            for b in 0..4 {
+                targets[[t, b, 0]] = sequences[[t - 1, b, 0]];
+            }
+        }
+
+        let input = crate::from_ndarray(sequences);
+        let (output, _) = gru.forward(input, None);
+        // The first feature of the output is the prediction.
+        let loss = mse_loss(
+            output.narrow(2, 0, 1),
+            crate::from_ndarray(targets),
+            Reduction::Mean,
+        );
+        let optimizer = Adam::new(loss.parameters(), 0.01, (0.9, 0.999), L2::new(0.), 1e-8);
+
+        loss.forward();
+        let initial_loss = loss.data()[[]];
+        for _ in 0..300 {
+            loss.forward();
+            loss.backward(1.);
+            optimizer.step();
+            optimizer.zero_grad();
+        }
+        loss.forward();
+
+        assert!(loss.data()[[]] < initial_loss / 10.);
+    }
+}
@@ -0,0 +1,228 @@
+use super::{init, named, Learnable, Register};
+use crate::variable::{AnyVarDiff, Data, Gradient, Input, MatMatMulT, RawParam, Tensor, VarDiff};
+use ndarray::{Ix1, Ix2};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{cell::Cell, rc::Rc};
+
+/// A **gated recurrent unit (GRU)** cell.
+///
+/// Given the input *x* and the hidden state *h*, a single step computes
+///
+/// ```text
+/// r = σ(Wᵢᵣx + bᵢᵣ + Wₕᵣh + bₕᵣ)
+/// z = σ(Wᵢzx + bᵢz + Wₕzh + bₕz)
+/// n = tanh(Wᵢₙx + bᵢₙ + r * (Wₕₙh + bₕₙ))
+/// h' = (1 - z) * n + z * h
+/// ```
+///
+/// where the weights of the reset, update and new gates are stacked, in this order, in
+/// `weight_ih` and `weight_hh`. The computational graph is built anew at each call of
+/// [`.forward()`](GRUCell::forward()), so that the cell can be unrolled over a sequence.
+///
+/// # Examples
+///
+/// ```
+/// use neuronika::nn::GRUCell;
+///
+/// // A batch of 4 inputs with 5 features each.
+/// let gru = GRUCell::new(5, 3);
+/// let hidden = neuronika::zeros((4, 3)).requires_grad();
+///
+/// let hidden = gru.forward(hidden, neuronika::rand((4, 5)));
+/// hidden.forward();
+/// assert_eq!(hidden.data().shape(), &[4, 3]);
+/// ```
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[allow(clippy::upper_case_acronyms)]
+pub struct GRUCell {
+    pub weight_ih: Learnable<Ix2>,
+    pub weight_hh: Learnable<Ix2>,
+    pub bias_ih: Learnable<Ix1>,
+    pub bias_hh: Learnable<Ix1>,
+}
+
+impl GRUCell {
+    /// Creates a new GRUCell.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_size` - number of expected features in the input.
+    ///
+    /// * `hidden_size` - number of features in the hidden state.
+    ///
+    /// All the weight and biases are initialized from *U(-k, k)* where
+    /// `k = (1. / hidden_size as f32).sqrt()`.
+    pub fn new(input_size: usize, hidden_size: usize) -> Self {
+        let (weight_ih_shape, weight_hh_shape, bias_shape) = {
+            let xhidden_size = 3 * hidden_size;
+            (
+                (xhidden_size, input_size),
+                (xhidden_size, hidden_size),
+                xhidden_size,
+            )
+        };
+        let weight_ih = Input::new(Tensor::zeros(weight_ih_shape)).requires_grad();
+        let weight_hh = Input::new(Tensor::zeros(weight_hh_shape)).requires_grad();
+        let bias_ih = Input::new(Tensor::zeros(bias_shape)).requires_grad();
+        let bias_hh = Input::new(Tensor::zeros(bias_shape)).requires_grad();
+
+        let k = 1. / (hidden_size as f32).sqrt();
+        init::uniform(&weight_ih, -k, k);
+        init::uniform(&weight_hh, -k, k);
+        init::uniform(&bias_ih, -k, k);
+        init::uniform(&bias_hh, -k, k);
+
+        Self {
+            weight_ih,
+            weight_hh,
+            bias_ih,
+            bias_hh,
+        }
+    }
+
+    /// Computes a single **GRU step**.
+    ///
+    /// * `hidden` - a variable of shape *(batch, hidden_size)*, containing the initial hidden state
+    /// for each element in the batch.
+    ///
+    /// * `input` - a variable containing the input features of shape *(batch, input_size)*.
+    ///
+    /// The **output** is  a variable made of the next hidden state for each element in
+    /// the batch, of shape *(batch, hidden_size)*.
+    pub fn forward<Hf: ?Sized, Hb: ?Sized, I, T, U>(
+        &self,
+        hidden: VarDiff<Hf, Hb>,
+        input: I,
+    ) -> VarDiff<impl Data<Dim = Ix2>, impl Gradient<Dim = Ix2>>
+    where
+        Hf: Data<Dim = Ix2>,
+        Hb: Gradient<Dim = Ix2>,
+        I: MatMatMulT<Learnable<Ix2>>,
+        I::Output: Into<VarDiff<T, U>>,
+        T: Data<Dim = Ix2> + 'static,
+        U: Gradient<Dim = Ix2> + 'static,
+    {
+        let (igates, hgates) = {
+            (
+                input.mm_t(self.weight_ih.clone()).into() + self.bias_ih.clone(),
+                hidden.clone().mm_t(self.weight_hh.clone()) + self.bias_hh.clone(),
+            )
+        };
+        let gate_shape = {
+            let (gates_shape_rows, gates_shape_cols) = hgates.data().dim();
+            (gates_shape_rows, gates_shape_cols / 3)
+        };
+        let (chunked_igates, chunked_hgates) =
+            (igates.chunks(gate_shape), hgates.chunks(gate_shape));
+
+        let reset_gate = (chunked_hgates[0].clone() + chunked_igates[0].clone()).sigmoid();
+        let input_gate = (chunked_hgates[1].clone() + chunked_igates[1].clone()).sigmoid();
+        let new_gate =
+            (chunked_igates[2].clone() + (chunked_hgates[2].clone() * reset_gate)).tanh();
+        (hidden - new_gate.clone()) * input_gate + new_gate
+    }
+
+    /// Returns the weights and the biases of this `GRUCell` instance, paired with their names.
+    pub fn named_parameters(&self) -> Vec<(String, AnyVarDiff)> {
+        vec![
+            named("weight_ih", &self.weight_ih),
+            named("weight_hh", &self.weight_hh),
+            named("bias_ih", &self.bias_ih),
+            named("bias_hh", &self.bias_hh),
+        ]
+    }
+}
+
+impl Register for GRUCell {
+    /// Registers the weights and the biases of this `GRUCell` instance.
+    fn register_params(&self, params: &mut Vec<RawParam>) {
+        self.weight_hh.register_params(params);
+        self.weight_ih.register_params(params);
+        self.bias_hh.register_params(params);
+        self.bias_ih.register_params(params);
+    }
+
+    fn register_status(&mut self, _: Rc<Cell<bool>>) {}
+}
+
+#[cfg(test)]
+mod test {
+    use super::GRUCell;
+    use crate::variable::{check_gradient, Data, Gradient, VarDiff};
+    use ndarray::{Array, Ix0, Ix2};
+
+    /// Returns the loss obtained by unrolling `gru` over two steps, one for each input, starting
+    /// from a null hidden state.
+    fn unrolled_loss(
+        gru: &GRUCell,
+        inputs: &[Array<f32, Ix2>; 2],
+    ) -> VarDiff<impl Data<Dim = Ix0>, impl Gradient<Dim = Ix0>> {
+        let hidden = crate::zeros((2, 3)).requires_grad();
+        let hidden = gru.forward(hidden, crate::from_ndarray(inputs[0].clone()));
+        let hidden = gru.forward(hidden, crate::from_ndarray(inputs[1].clone()));
+
+        (hidden * crate::from_ndarray(ndarray::array![[1., -2., 3.], [0.5, 1., -1.]])).sum()
+    }
+
+    #[test]
+    fn creation() {
+        let gru = GRUCell::new(5, 3);
+
+        assert_eq!(gru.weight_ih.data().shape(), &[9, 5]);
+        assert_eq!(gru.weight_hh.data().shape(), &[9, 3]);
+        assert_eq!(gru.bias_ih.data().shape(), &[9]);
+        assert_eq!(gru.bias_hh.data().shape(), &[9]);
+        assert_eq!(gru.named_parameters().len(), 4);
+    }
+
+    #[test]
+    fn forward() {
+        let gru = GRUCell::new(5, 3);
+        let hidden = crate::zeros((4, 3)).requires_grad();
+
+        let new_hidden = gru.forward(hidden, crate::rand((4, 5)));
+        new_hidden.forward();
+        assert_eq!(new_hidden.data().shape(), &[4, 3]);
+        // Starting from a null state the output is a fraction of the new gate.
+        assert!(new_hidden.data().iter().all(|el| el.abs() < 1.));
+    }
+
+    #[test]
+    fn forward_null_weights() {
+        let gru = GRUCell::new(2, 3);
+        for weight in [&gru.weight_ih, &gru.weight_hh] {
+            weight.data_mut().fill(0.);
+        }
+        for bias in [&gru.bias_ih, &gru.bias_hh] {
+            bias.data_mut().fill(0.);
+        }
+
+        // The update gate is one half and the new gate is zero.
+        let hidden = crate::full((2, 3), 2.).requires_grad();
+        let new_hidden = gru.forward(hidden, crate::rand((2, 2)));
+        new_hidden.forward();
+        assert!(new_hidden
+            .data()
+            .iter()
+            .all(|el| (el - 1.).abs() <= f32::EPSILON));
+    }
+
+    #[test]
+    fn backward() {
+        let gru = GRUCell::new(2, 3);
+        let inputs = [
+            crate::rand((2, 2)).data().to_owned() * 2. - 1.,
+            crate::rand((2, 2)).data().to_owned() * 2. - 1.,
+        ];
+
+        // The null initial state is a parameter as well.
+        let loss = unrolled_loss(&gru, &inputs);
+        assert_eq!(loss.parameters().len(), 5);
+
+        check_gradient(&gru.weight_ih, &loss);
+        check_gradient(&gru.weight_hh, &loss);
+        check_gradient(&gru.bias_ih, &loss);
+        check_gradient(&gru.bias_hh, &loss);
+    }
+}
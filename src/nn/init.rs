@@ -18,9 +18,23 @@
 //!
 //! xavier_normal(&lin.weight, calculate_gain("relu"));
 //! ```
+//!
+//! Layers supporting it can also be built directly with the initializer of your choice, described
+//! by an [`Init`] value.
+//!
+//! ```
+//! use neuronika::nn;
+//! use neuronika::nn::init::{calculate_gain, Init};
+//!
+//! let lin = nn::Linear::new_with(10, 10, false, Init::KaimingNormal {
+//!     gain: calculate_gain("relu"),
+//! });
+//!
+//! assert!(lin.bias.is_none());
+//! ```
 use super::Learnable;
-use ndarray::{Dimension, Ix2};
-use rand::thread_rng;
+use ndarray::{Array2, Dimension, Ix2};
+use rand::{seq::index::sample, thread_rng};
 use rand_distr::{Distribution, Normal, Uniform};
 
 /// Returns the recommended gain value for the given non-linearity function.
@@ -53,22 +67,31 @@ pub fn calculate_gain(non_linearity: &str) -> f32 {
 ///
 /// For *MLPs* *fan_in* and *fan_out* are respectively the number of inputs and outputs to an
 /// hidden unit of the layer. For *CNNs* however, the number of input feature maps and the size
-/// of the receptive field must be taken into account .
+/// of the receptive field must be taken into account, e.g. a kernel of shape *(64, 3, 3, 3)* has
+/// a *fan_in* of *3 * 3 * 3* and a *fan_out* of *64 * 3 * 3*.
 ///
 /// # Arguments
 ///
 /// `param` - differentiable variable for which the *fan in* and the *fan out* must be
 /// calculated.
+///
+/// # Panics
+///
+/// If `param` has less than 2 dimensions.
 pub fn calculate_fan_in_fan_out<D: Dimension>(param: &Learnable<D>) -> (f32, f32) {
     let data = param.data();
     let shape = data.shape();
+    assert!(
+        shape.len() >= 2,
+        "error: fan in and fan out can not be computed for parameters with less than 2 dimensions."
+    );
 
     let num_input_fmaps = shape[1];
     let num_output_fmaps = shape[0];
 
     let (fan_in, fan_out) = {
         if shape.len() > 2 {
-            let numel = shape.iter().skip(2).sum::<usize>();
+            let numel = shape.iter().skip(2).product::<usize>();
             (num_input_fmaps * numel, num_output_fmaps * numel)
         } else {
             (num_input_fmaps, num_output_fmaps)
@@ -256,3 +279,366 @@ pub fn xavier_normal<D: Dimension>(param: &Learnable<D>, gain: f32) {
         .data_mut()
         .map_inplace(|el| *el = norm_distr.sample(&mut t_rng));
 }
+
+/// Fills the differentiable leaf variable with values according to the method described in
+/// [Delving deep into rectifiers: Surpassing human-level performance on ImageNet
+/// classification](https://arxiv.org/abs/1502.01852) - He, K. et al. (2015), using a uniform
+/// distribution.
+///
+/// Also known as **He initialization**.
+///
+/// # Arguments
+///
+/// * `param` - differentiable variable to initialize.
+///
+/// * `gain` - optional scaling factor. See also [`calculate_gain`](function@calculate_gain).
+pub fn kaiming_uniform<D: Dimension>(param: &Learnable<D>, gain: f32) {
+    let (fan_in, _) = calculate_fan_in_fan_out(param);
+    let std = gain / fan_in.sqrt();
+    let a = 3.0_f32.sqrt() * std;
+    let unif_distr = Uniform::new(-a, a);
+    let mut t_rng = thread_rng();
+    param
+        .data_mut()
+        .map_inplace(|el| *el = unif_distr.sample(&mut t_rng));
+}
+
+/// Fills the differentiable leaf variable with values according to the method described in
+/// [Delving deep into rectifiers: Surpassing human-level performance on ImageNet
+/// classification](https://arxiv.org/abs/1502.01852) - He, K. et al. (2015), using a normal
+/// distribution.
+///
+/// Also known as **He initialization**.
+///
+/// # Arguments
+///
+/// * `param` - differentiable variable to initialize.
+///
+/// * `gain` - optional scaling factor. See also [`calculate_gain`](function@calculate_gain).
+pub fn kaiming_normal<D: Dimension>(param: &Learnable<D>, gain: f32) {
+    let (fan_in, _) = calculate_fan_in_fan_out(param);
+    let std = gain / fan_in.sqrt();
+    let norm_distr = Normal::new(0., std).unwrap();
+    let mut t_rng = thread_rng();
+    param
+        .data_mut()
+        .map_inplace(|el| *el = norm_distr.sample(&mut t_rng));
+}
+
+/// Fills the differentiable leaf variable with a (semi) orthogonal matrix, as described in
+/// [Exact solutions to the nonlinear dynamics of learning in deep linear neural
+/// networks](https://arxiv.org/abs/1312.6120) - Saxe, A. et al. (2013).
+///
+/// The parameter is viewed as a matrix with as many rows as the length of its first axis, the
+/// remaining axes being flattened. Its rows, or its columns if they are fewer, are orthonormal.
+///
+/// # Arguments
+///
+/// * `param` - differentiable variable to initialize.
+///
+/// * `gain` - optional scaling factor. See also [`calculate_gain`](function@calculate_gain).
+///
+/// # Panics
+///
+/// If `param` has less than 2 dimensions.
+pub fn orthogonal<D: Dimension>(param: &Learnable<D>, gain: f32) {
+    let mut data = param.data_mut();
+    assert!(
+        data.ndim() >= 2,
+        "error: only parameters with at least 2 dimensions are supported."
+    );
+    let rows = data.shape()[0];
+    let cols = data.len() / rows;
+
+    // The orthonormal vectors are the columns of a tall matrix, transposed back when needed.
+    let (tall_rows, tall_cols) = (rows.max(cols), rows.min(cols));
+    let norm_distr = Normal::new(0., 1.).unwrap();
+    let mut t_rng = thread_rng();
+    let mut tall =
+        Array2::from_shape_simple_fn((tall_rows, tall_cols), || norm_distr.sample(&mut t_rng));
+    gram_schmidt(&mut tall);
+
+    let matrix = if rows < cols {
+        tall.reversed_axes()
+    } else {
+        tall
+    };
+    data.iter_mut()
+        .zip(matrix.iter())
+        .for_each(|(el, value)| *el = gain * value);
+}
+
+/// Orthonormalizes the columns of `matrix` in place with the modified Gram-Schmidt process.
+fn gram_schmidt(matrix: &mut Array2<f32>) {
+    for j in 0..matrix.ncols() {
+        for k in 0..j {
+            let projection = matrix.column(j).dot(&matrix.column(k));
+            let previous = matrix.column(k).to_owned();
+            matrix
+                .column_mut(j)
+                .zip_mut_with(&previous, |el, prev| *el -= projection * prev);
+        }
+        let norm = matrix.column(j).dot(&matrix.column(j)).sqrt();
+        matrix.column_mut(j).mapv_inplace(|el| el / norm);
+    }
+}
+
+/// Fills the 2-dimensional differentiable leaf variable as a sparse matrix, as described in
+/// [Deep learning via Hessian-free
+/// optimization](https://dl.acm.org/doi/10.5555/3104322.3104416) - Martens, J. (2010).
+///
+/// In each column a fraction `sparsity` of the elements is set to zero, the others are drawn from
+/// the normal distribution *N(0, std^2)*.
+///
+/// # Arguments
+///
+/// * `param` - differentiable variable to initialize.
+///
+/// * `sparsity` - fraction of the elements of each column to be set to zero.
+///
+/// * `std` - standard deviation of the normal distribution.
+///
+/// # Panics
+///
+/// If `sparsity` is not in *[0, 1]*.
+pub fn sparse(param: &Learnable<Ix2>, sparsity: f32, std: f32) {
+    assert!(
+        (0. ..=1.).contains(&sparsity),
+        "error: the sparsity must be in [0, 1], got {}.",
+        sparsity
+    );
+    let mut data = param.data_mut();
+    let rows = data.nrows();
+    let zeros = (sparsity * rows as f32).ceil() as usize;
+
+    let norm_distr = Normal::new(0., std).unwrap();
+    let mut t_rng = thread_rng();
+    for mut column in data.columns_mut() {
+        column.map_inplace(|el| *el = norm_distr.sample(&mut t_rng));
+        for row in sample(&mut t_rng, rows, zeros) {
+            column[row] = 0.;
+        }
+    }
+}
+
+/// An initialization scheme, dispatching to the function of this module with the same name.
+///
+/// The variants based on the *fan_in* and the *fan_out* can only be applied to parameters that
+/// are at least 2-dimensional.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Init {
+    /// See [`uniform`](function@uniform).
+    Uniform { low: f32, high: f32 },
+    /// See [`normal`](function@normal).
+    Normal { mean: f32, std: f32 },
+    /// See [`xavier_uniform`](function@xavier_uniform).
+    XavierUniform { gain: f32 },
+    /// See [`xavier_normal`](function@xavier_normal).
+    XavierNormal { gain: f32 },
+    /// See [`kaiming_uniform`](function@kaiming_uniform).
+    KaimingUniform { gain: f32 },
+    /// See [`kaiming_normal`](function@kaiming_normal).
+    KaimingNormal { gain: f32 },
+    /// See [`constant`](function@constant).
+    Constant(f32),
+    /// See [`zeros`](function@zeros).
+    Zeros,
+}
+
+impl Init {
+    /// Fills the differentiable leaf variable in place according to this scheme.
+    ///
+    /// # Arguments
+    ///
+    /// `param` - differentiable variable to initialize.
+    pub fn apply<D: Dimension>(self, param: &Learnable<D>) {
+        match self {
+            Init::Uniform { low, high } => uniform(param, low, high),
+            Init::Normal { mean, std } => normal(param, mean, std),
+            Init::XavierUniform { gain } => xavier_uniform(param, gain),
+            Init::XavierNormal { gain } => xavier_normal(param, gain),
+            Init::KaimingUniform { gain } => kaiming_uniform(param, gain),
+            Init::KaimingNormal { gain } => kaiming_normal(param, gain),
+            Init::Constant(value) => constant(param, value),
+            Init::Zeros => zeros(param),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{calculate_fan_in_fan_out, calculate_gain, orthogonal, sparse, Init};
+    use crate::{nn::Learnable, variable::Input};
+    use ndarray::{Array, Array2, Ix2, Ix4};
+
+    /// Creates a differentiable leaf of shape (fan_out, fan_in) filled by `init`.
+    fn initialized(fan_out: usize, fan_in: usize, init: Init) -> Learnable<Ix2> {
+        let param = Input::new(ndarray::Array::zeros((fan_out, fan_in))).requires_grad();
+        init.apply(&param);
+        param
+    }
+
+    /// Returns the sample mean and the sample standard deviation of the parameter's values.
+    fn moments(param: &Learnable<Ix2>) -> (f32, f32) {
+        let data = param.data();
+        let mean = data.mean().unwrap();
+        let var = data.iter().map(|el| (el - mean).powi(2)).sum::<f32>() / data.len() as f32;
+
+        (mean, var.sqrt())
+    }
+
+    #[test]
+    fn uniform() {
+        let param = initialized(200, 300, Init::Uniform { low: -2., high: 3. });
+        assert!(param.data().iter().all(|el| (-2. ..3.).contains(el)));
+
+        let (mean, std) = moments(&param);
+        assert!((mean - 0.5).abs() <= 0.05);
+        assert!((std - 5. / 12_f32.sqrt()).abs() <= 0.05);
+    }
+
+    #[test]
+    fn normal() {
+        let param = initialized(200, 300, Init::Normal { mean: 1., std: 2. });
+
+        let (mean, std) = moments(&param);
+        assert!((mean - 1.).abs() <= 0.05);
+        assert!((std - 2.).abs() <= 0.05);
+    }
+
+    #[test]
+    fn xavier_uniform() {
+        let gain = calculate_gain("tanh");
+        let param = initialized(200, 300, Init::XavierUniform { gain });
+
+        let expected_std = gain * (2. / 500_f32).sqrt();
+        let bound = 3_f32.sqrt() * expected_std;
+        assert!(param.data().iter().all(|el| el.abs() <= bound));
+
+        let (mean, std) = moments(&param);
+        assert!(mean.abs() <= 0.05 * expected_std);
+        assert!((std - expected_std).abs() <= 0.05 * expected_std);
+    }
+
+    #[test]
+    fn xavier_normal() {
+        let param = initialized(200, 300, Init::XavierNormal { gain: 1. });
+
+        let expected_std = (2. / 500_f32).sqrt();
+        let (mean, std) = moments(&param);
+        assert!(mean.abs() <= 0.05 * expected_std);
+        assert!((std - expected_std).abs() <= 0.05 * expected_std);
+    }
+
+    #[test]
+    fn kaiming_uniform() {
+        let gain = calculate_gain("relu");
+        let param = initialized(200, 300, Init::KaimingUniform { gain });
+
+        let expected_std = gain / 300_f32.sqrt();
+        let bound = 3_f32.sqrt() * expected_std;
+        assert!(param.data().iter().all(|el| el.abs() <= bound));
+
+        let (mean, std) = moments(&param);
+        assert!(mean.abs() <= 0.05 * expected_std);
+        assert!((std - expected_std).abs() <= 0.05 * expected_std);
+    }
+
+    #[test]
+    fn kaiming_normal() {
+        let gain = calculate_gain("relu");
+        let param = initialized(200, 300, Init::KaimingNormal { gain });
+
+        let expected_std = gain / 300_f32.sqrt();
+        let (mean, std) = moments(&param);
+        assert!(mean.abs() <= 0.05 * expected_std);
+        assert!((std - expected_std).abs() <= 0.05 * expected_std);
+    }
+
+    #[test]
+    fn constant() {
+        let param = initialized(3, 4, Init::Constant(0.25));
+        assert!(param.data().iter().all(|el| *el == 0.25));
+
+        Init::Zeros.apply(&param);
+        assert!(param.data().iter().all(|el| *el == 0.));
+    }
+
+    #[test]
+    fn fan_in_fan_out() {
+        let kernel: Learnable<Ix4> = Input::new(Array::zeros((64, 3, 3, 3))).requires_grad();
+        assert_eq!(calculate_fan_in_fan_out(&kernel), (27., 576.));
+
+        let weight = Input::new(Array::zeros((5, 4))).requires_grad();
+        assert_eq!(calculate_fan_in_fan_out(&weight), (4., 5.));
+    }
+
+    #[test]
+    #[should_panic(expected = "error: fan in and fan out can not be computed")]
+    fn fan_in_fan_out_vector() {
+        calculate_fan_in_fan_out(&Input::new(Array::zeros(3)).requires_grad());
+    }
+
+    #[test]
+    fn kaiming_normal_kernel() {
+        let kernel = Input::new(Array::zeros((64, 16, 3, 3))).requires_grad();
+        super::kaiming_normal(&kernel, calculate_gain("relu"));
+
+        let data = kernel.data();
+        let mean = data.mean().unwrap();
+        let var = data.iter().map(|el| (el - mean).powi(2)).sum::<f32>() / data.len() as f32;
+        // Var(w) = gain^2 / fan_in.
+        let expected_var = 2. / 144.;
+        assert!((var - expected_var).abs() <= 0.1 * expected_var);
+    }
+
+    /// Returns `true` if `matrix` is the identity matrix within tolerance.
+    fn is_identity(matrix: &Array2<f32>) -> bool {
+        matrix
+            .indexed_iter()
+            .all(|((i, j), el)| (el - if i == j { 1. } else { 0. }).abs() <= 1e-4)
+    }
+
+    #[test]
+    fn orthogonal_wide() {
+        let param = Input::new(Array::zeros((6, 10))).requires_grad();
+        orthogonal(&param, 1.);
+
+        let data = param.data();
+        assert!(is_identity(&data.dot(&data.t())));
+    }
+
+    #[test]
+    fn orthogonal_tall() {
+        let param = Input::new(Array::zeros((10, 6))).requires_grad();
+        orthogonal(&param, 2.);
+
+        let data = param.data().mapv(|el| el / 2.);
+        assert!(is_identity(&data.t().dot(&data)));
+    }
+
+    #[test]
+    fn orthogonal_kernel() {
+        let kernel = Input::new(Array::zeros((4, 2, 3, 3))).requires_grad();
+        orthogonal(&kernel, 1.);
+
+        let matrix = kernel.data().to_owned().into_shape((4, 18)).unwrap();
+        assert!(is_identity(&matrix.dot(&matrix.t())));
+    }
+
+    #[test]
+    fn sparse_columns() {
+        let param = Input::new(Array::zeros((10, 20))).requires_grad();
+        sparse(&param, 0.25, 0.01);
+
+        for column in param.data().columns() {
+            assert_eq!(column.iter().filter(|el| **el == 0.).count(), 3);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "error: the sparsity must be in [0, 1], got 1.5.")]
+    fn sparse_fail() {
+        sparse(&Input::new(Array::zeros((3, 3))).requires_grad(), 1.5, 0.01);
+    }
+}
@@ -0,0 +1,222 @@
+use super::{named, Learnable, Register};
+use crate::variable::{AnyVarDiff, Data, Gradient, Input, RawParam, Tensor, VarDiff};
+use ndarray::{Ix1, Ix4};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{cell::Cell, rc::Rc};
+
+/// Applies **instance normalization** over a batch of images as described in the paper
+/// [Instance Normalization: The Missing Ingredient for Fast Stylization](https://arxiv.org/abs/1607.08022).
+///
+/// ```text
+///       x - E[x]
+/// ʏ = ―――――――――――――――― * weight + bias
+///     √(Var[x] + eps)
+/// ```
+///
+/// The mean and the variance are computed separately for each channel of each sample over the
+/// spatial dimensions. The variance is the biased one. When the layer is affine the weight and
+/// the bias are applied channel-wise, otherwise the output is just normalized.
+///
+/// # Examples
+///
+/// ```
+/// use neuronika::nn::InstanceNorm;
+///
+/// // A batch of 4 images with 3 channels each.
+/// let input = neuronika::rand((4, 3, 5, 5)).requires_grad();
+/// let instance_norm = InstanceNorm::new(3, 1e-5, true);
+///
+/// let output = instance_norm.forward(input);
+/// output.forward();
+/// assert_eq!(output.data().shape(), &[4, 3, 5, 5]);
+/// ```
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct InstanceNorm {
+    pub weight: Option<Learnable<Ix1>>,
+    pub bias: Option<Learnable<Ix1>>,
+    pub eps: f32,
+    num_features: usize,
+}
+
+impl InstanceNorm {
+    /// Creates an instance normalization layer.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_features` - number of channels of the input.
+    ///
+    /// * `eps` - value added to the variance for numerical stability.
+    ///
+    /// * `affine` - whether the layer has a learnable weight and bias.
+    ///
+    /// The learnable weight and bias of the layer, if any, have shape `num_features` and are
+    /// respectively initialized with ones and zeros.
+    pub fn new(num_features: usize, eps: f32, affine: bool) -> Self {
+        let weight = affine.then(|| Input::new(Tensor::ones(num_features)).requires_grad());
+        let bias = affine.then(|| Input::new(Tensor::zeros(num_features)).requires_grad());
+
+        Self {
+            weight,
+            bias,
+            eps,
+            num_features,
+        }
+    }
+
+    /// Applies the instance normalization to the incoming data.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - a variable of shape *(N, C, H, W)*, the output has the same shape as the input.
+    ///
+    /// # Panics
+    ///
+    /// If the input hasn't `num_features` channels.
+    pub fn forward<T, U>(
+        &self,
+        input: VarDiff<T, U>,
+    ) -> VarDiff<dyn Data<Dim = Ix4>, dyn Gradient<Dim = Ix4>>
+    where
+        T: Data<Dim = Ix4> + 'static,
+        U: Gradient<Dim = Ix4> + 'static,
+    {
+        let channels = input.data().shape()[1];
+        assert_eq!(
+            channels, self.num_features,
+            "error: expected an input with {} channels, found {}.",
+            self.num_features, channels
+        );
+
+        let mean = input.clone().mean_axes(&[2, 3]);
+        let centered = input - mean;
+        let std = (centered.clone().pow(2).mean_axes(&[2, 3]) + self.eps).sqrt();
+        let normalized = centered / std;
+
+        let shape = (1, channels, 1, 1);
+        match (&self.weight, &self.bias) {
+            (Some(weight), Some(bias)) => (normalized * weight.clone().reshape(shape)
+                + bias.clone().reshape(shape))
+            .into_dyn(),
+            _ => normalized.into_dyn(),
+        }
+    }
+
+    /// Returns the weight and the bias, if any, of this `InstanceNorm` instance, paired with
+    /// their names.
+    pub fn named_parameters(&self) -> Vec<(String, AnyVarDiff)> {
+        let mut params = Vec::new();
+        if let Some(weight) = &self.weight {
+            params.push(named("weight", weight));
+        }
+        if let Some(bias) = &self.bias {
+            params.push(named("bias", bias));
+        }
+        params
+    }
+}
+
+impl Register for InstanceNorm {
+    /// Registers the weight and the bias, if any, of this `InstanceNorm` instance.
+    fn register_params(&self, params: &mut Vec<RawParam>) {
+        if let Some(weight) = &self.weight {
+            weight.register_params(params);
+        }
+        if let Some(bias) = &self.bias {
+            bias.register_params(params);
+        }
+    }
+
+    fn register_status(&mut self, _: Rc<Cell<bool>>) {}
+}
+
+#[cfg(test)]
+mod test {
+    use super::{super::GroupNorm, InstanceNorm};
+    use ndarray::Axis;
+
+    #[test]
+    fn creation() {
+        let instance_norm = InstanceNorm::new(3, 1e-5, true);
+
+        assert_eq!(instance_norm.weight.as_ref().unwrap().data().shape(), &[3]);
+        assert_eq!(instance_norm.bias.as_ref().unwrap().data().shape(), &[3]);
+        assert_eq!(instance_norm.named_parameters().len(), 2);
+
+        let instance_norm = InstanceNorm::new(3, 1e-5, false);
+        assert!(instance_norm.weight.is_none() && instance_norm.bias.is_none());
+        assert!(instance_norm.named_parameters().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "error: expected an input with 3 channels, found 2.")]
+    fn wrong_channels() {
+        InstanceNorm::new(3, 1e-5, false).forward(crate::rand((2, 2, 4, 4)).requires_grad());
+    }
+
+    #[test]
+    fn forward() {
+        let input = (crate::rand((2, 3, 4, 5)) * 10. + 3.).requires_grad();
+        let instance_norm = InstanceNorm::new(3, 1e-5, true);
+
+        let output = instance_norm.forward(input);
+        output.forward();
+        assert_eq!(output.data().shape(), &[2, 3, 4, 5]);
+
+        // Every channel of every sample has zero mean and unit variance.
+        for sample in output.data().axis_iter(Axis(0)) {
+            for plane in sample.axis_iter(Axis(0)) {
+                let mean = plane.mean().unwrap();
+                let variance = plane.mapv(|el| (el - mean).powi(2)).mean().unwrap();
+                assert!(mean.abs() <= 1e-4);
+                assert!((variance - 1.).abs() <= 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn backward() {
+        let input = crate::rand((2, 3, 4, 4)).requires_grad();
+        let instance_norm = InstanceNorm::new(3, 1e-5, true);
+
+        let output = instance_norm.forward(input.clone());
+        let loss = (output * crate::rand((2, 3, 4, 4))).sum();
+        loss.forward();
+        loss.backward(1.);
+
+        assert_eq!(loss.parameters().len(), 3);
+        let (weight, bias) = (
+            instance_norm.weight.as_ref().unwrap(),
+            instance_norm.bias.as_ref().unwrap(),
+        );
+        assert!(weight.grad().iter().all(|el| el.abs() > 0.));
+        assert!(bias.grad().iter().all(|el| el.abs() > 0.));
+        assert!(input.grad().iter().all(|el| el.is_finite()));
+        // The gradient of the normalization sums to zero over the spatial dimensions.
+        for sample in input.grad().axis_iter(Axis(0)) {
+            for plane in sample.axis_iter(Axis(0)) {
+                assert!(plane.sum().abs() <= 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn group_norm() {
+        // Without the affine transformation, it's a group normalization with a group for each
+        // channel.
+        let input = crate::rand((2, 4, 3, 5)) * 10.;
+        let instance_norm = InstanceNorm::new(4, 1e-5, false);
+        let group_norm = GroupNorm::new(4, 4, 1e-5);
+
+        let output = instance_norm.forward(input.clone().requires_grad());
+        let expected = group_norm.forward(input);
+        output.forward();
+        expected.forward();
+
+        assert!(output
+            .data()
+            .iter()
+            .zip(expected.data().iter())
+            .all(|(actual, expected)| (actual - expected).abs() <= 1e-5));
+    }
+}
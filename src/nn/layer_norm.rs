@@ -0,0 +1,468 @@
+use super::{named, Learnable, Mask2d, Register};
+use crate::variable::{AnyVarDiff, Data, Gradient, Input, Rank, RawParam, Tensor, Var, VarDiff};
+use ndarray::{Dimension, Ix4};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{cell::Cell, rc::Rc};
+
+/// Layer normalization input.
+///
+/// This trait is implemented by `Var` and `VarDiff`.
+pub trait LayerNormInput<D: Dimension> {
+    /// Normalizes `self` over its last `normalized_ndim` axes, then scales the result by `weight`
+    /// and shifts it by `bias`.
+    fn layer_norm(
+        self,
+        normalized_ndim: usize,
+        eps: f32,
+        weight: Learnable<D>,
+        bias: Learnable<D>,
+    ) -> VarDiff<dyn Data<Dim = D>, dyn Gradient<Dim = D>>;
+
+    /// Same as [`.layer_norm()`](LayerNormInput::layer_norm()), but the statistics are computed
+    /// only over the positions where `mask` is one, and the result is zeroed elsewhere. The mask
+    /// holds ones and zeros and must be broadcastable to the shape of `self`.
+    fn masked_layer_norm(
+        self,
+        normalized_ndim: usize,
+        eps: f32,
+        weight: Learnable<D>,
+        bias: Learnable<D>,
+        mask: Var<Input<D>>,
+    ) -> VarDiff<dyn Data<Dim = D>, dyn Gradient<Dim = D>>;
+}
+
+/// Returns the last `normalized_ndim` axes of an array with `ndim` dimensions.
+fn normalized_axes(ndim: usize, normalized_ndim: usize) -> Vec<usize> {
+    assert!(
+        normalized_ndim <= ndim,
+        "error: the input has less dimensions than the normalized shape."
+    );
+
+    (ndim - normalized_ndim..ndim).collect()
+}
+
+/// Lower bound of the fraction of valid positions of a sample, it keeps the statistics of a fully
+/// masked sample finite.
+const MIN_VALID_FRACTION: f32 = 1e-6;
+
+/// Returns the fraction of the positions along `axes` where `mask` is one, that is the ratio
+/// between the mean over the valid positions and the mean over all the positions.
+///
+/// The fraction is bounded from below by `MIN_VALID_FRACTION`, so that a fully masked sample is
+/// normalized to zeros instead of dividing zero by zero.
+fn valid_fraction<D>(mask: Var<Input<D>>, axes: &[usize]) -> Var<dyn Data<Dim = D>>
+where
+    D: Dimension + 'static,
+{
+    ((mask.mean_axes(axes) - MIN_VALID_FRACTION).relu() + MIN_VALID_FRACTION).into_dyn()
+}
+
+impl<D, T: ?Sized, U: ?Sized> LayerNormInput<D> for VarDiff<T, U>
+where
+    D: Dimension + 'static,
+    T: Data<Dim = D> + 'static,
+    U: Gradient<Dim = D> + 'static,
+{
+    fn layer_norm(
+        self,
+        normalized_ndim: usize,
+        eps: f32,
+        weight: Learnable<D>,
+        bias: Learnable<D>,
+    ) -> VarDiff<dyn Data<Dim = D>, dyn Gradient<Dim = D>> {
+        (self.layer_norm(normalized_ndim, eps) * weight + bias).into_dyn()
+    }
+
+    fn masked_layer_norm(
+        self,
+        normalized_ndim: usize,
+        eps: f32,
+        weight: Learnable<D>,
+        bias: Learnable<D>,
+        mask: Var<Input<D>>,
+    ) -> VarDiff<dyn Data<Dim = D>, dyn Gradient<Dim = D>> {
+        let axes = normalized_axes(self.data().ndim(), normalized_ndim);
+        let valid = valid_fraction(mask.clone(), &axes);
+        let mean = (self.clone() * mask.clone()).mean_axes(&axes) / valid.clone();
+        let centered = (self - mean) * mask.clone();
+        let std = (centered.clone().pow(2).mean_axes(&axes) / valid + eps).sqrt();
+
+        ((centered / std * weight + bias) * mask).into_dyn()
+    }
+}
+
+impl<D, T: ?Sized> LayerNormInput<D> for Var<T>
+where
+    D: Dimension + 'static,
+    T: Data<Dim = D> + 'static,
+{
+    fn layer_norm(
+        self,
+        normalized_ndim: usize,
+        eps: f32,
+        weight: Learnable<D>,
+        bias: Learnable<D>,
+    ) -> VarDiff<dyn Data<Dim = D>, dyn Gradient<Dim = D>> {
+        (self.layer_norm(normalized_ndim, eps) * weight + bias).into_dyn()
+    }
+
+    fn masked_layer_norm(
+        self,
+        normalized_ndim: usize,
+        eps: f32,
+        weight: Learnable<D>,
+        bias: Learnable<D>,
+        mask: Var<Input<D>>,
+    ) -> VarDiff<dyn Data<Dim = D>, dyn Gradient<Dim = D>> {
+        let axes = normalized_axes(self.data().ndim(), normalized_ndim);
+        let valid = valid_fraction(mask.clone(), &axes);
+        let mean = (self.clone() * mask.clone()).mean_axes(&axes) / valid.clone();
+        let centered = (self - mean) * mask.clone();
+        let std = (centered.clone().pow(2).mean_axes(&axes) / valid + eps).sqrt();
+
+        ((centered / std * weight + bias) * mask).into_dyn()
+    }
+}
+
+/// Applies **layer normalization** over the trailing dimensions of the incoming data as
+/// described in the paper [Layer Normalization](https://arxiv.org/abs/1607.06450).
+///
+/// ```text
+///       x - E[x]
+/// ʏ = ―――――――――――――――― * weight + bias
+///     √(Var[x] + eps)
+/// ```
+///
+/// Differently from batch normalization, the mean and the variance are computed separately for
+/// each sample over the last dimensions, whose lengths are given by the `normalized_shape`
+/// passed to the constructor. The variance is the biased one.
+///
+/// The type parameter `D` is the dimensionality of the input.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::Ix3;
+/// use neuronika::nn::LayerNorm;
+///
+/// // A batch of 4 sequences of length 6 with 10 features each.
+/// let input = neuronika::rand((4, 6, 10));
+/// let layer_norm = LayerNorm::<Ix3>::new(&[10], 1e-5);
+///
+/// let output = layer_norm.forward(input);
+/// output.forward();
+/// assert_eq!(output.data().shape(), &[4, 6, 10]);
+/// ```
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct LayerNorm<D: Dimension + 'static> {
+    pub weight: Learnable<D>,
+    pub bias: Learnable<D>,
+    pub eps: f32,
+    normalized_ndim: usize,
+}
+
+impl<D: Dimension + 'static> LayerNorm<D> {
+    /// Creates a layer normalization layer.
+    ///
+    /// # Arguments
+    ///
+    /// * `normalized_shape` - shape of the trailing dimensions of the input over which the
+    /// normalization is performed.
+    ///
+    /// * `eps` - value added to the variance for numerical stability.
+    ///
+    /// The learnable weight and bias of the layer have shape `normalized_shape`, with leading axes
+    /// of length one added as needed to match the dimensionality `D`. They are respectively
+    /// initialized with ones and zeros.
+    ///
+    /// # Panics
+    ///
+    /// If `normalized_shape` has more dimensions than `D`.
+    pub fn new(normalized_shape: &[usize], eps: f32) -> Self {
+        let normalized_ndim = normalized_shape.len();
+        let ndim = D::NDIM.unwrap_or(normalized_ndim);
+        assert!(
+            normalized_ndim <= ndim,
+            "error: the normalized shape has more dimensions than the input."
+        );
+
+        let mut shape = D::zeros(ndim);
+        shape.slice_mut().iter_mut().for_each(|len| *len = 1);
+        shape.slice_mut()[ndim - normalized_ndim..].copy_from_slice(normalized_shape);
+
+        let weight = Input::new(Tensor::ones(shape.clone())).requires_grad();
+        let bias = Input::new(Tensor::zeros(shape)).requires_grad();
+
+        Self {
+            weight,
+            bias,
+            eps,
+            normalized_ndim,
+        }
+    }
+
+    /// Applies the layer normalization to the incoming data.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - a variable whose trailing dimensions match the normalized shape, the output has
+    /// the same shape as the input.
+    pub fn forward<I: LayerNormInput<D>>(
+        &self,
+        input: I,
+    ) -> VarDiff<dyn Data<Dim = D>, dyn Gradient<Dim = D>> {
+        input.layer_norm(
+            self.normalized_ndim,
+            self.eps,
+            self.weight.clone(),
+            self.bias.clone(),
+        )
+    }
+}
+
+impl LayerNorm<Ix4> {
+    /// Applies the layer normalization to a batch of padded inputs.
+    ///
+    /// The mean and the variance of each sample are computed only over the positions marked as
+    /// valid by `mask`, the other positions of the output are zeroed and receive no gradient. A
+    /// sample without valid positions is entirely zeroed.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - a variable of shape *(N, C, H, W)*.
+    ///
+    /// * `mask` - validity mask of the input.
+    pub fn forward_masked<I: LayerNormInput<Ix4>>(
+        &self,
+        input: I,
+        mask: &Mask2d,
+    ) -> VarDiff<dyn Data<Dim = Ix4>, dyn Gradient<Dim = Ix4>> {
+        input.masked_layer_norm(
+            self.normalized_ndim,
+            self.eps,
+            self.weight.clone(),
+            self.bias.clone(),
+            mask.to_var(),
+        )
+    }
+}
+
+impl<D: Rank> LayerNorm<D> {
+    /// Returns the weight and the bias of this `LayerNorm` instance, paired with their names.
+    pub fn named_parameters(&self) -> Vec<(String, AnyVarDiff)> {
+        vec![named("weight", &self.weight), named("bias", &self.bias)]
+    }
+}
+
+impl<D: Dimension + 'static> Register for LayerNorm<D> {
+    /// Registers the weight and the bias of this `LayerNorm` instance.
+    fn register_params(&self, params: &mut Vec<RawParam>) {
+        self.weight.register_params(params);
+        self.bias.register_params(params);
+    }
+
+    fn register_status(&mut self, _: Rc<Cell<bool>>) {}
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        super::loss::{mse_loss, Reduction},
+        LayerNorm, Mask2d,
+    };
+    use crate::optim::{L2, SGD};
+    use ndarray::{s, Array, Axis, Dimension, Ix2, Ix3, Ix4};
+
+    #[test]
+    fn creation() {
+        let layer_norm = LayerNorm::<Ix3>::new(&[4, 5], 1e-5);
+
+        assert_eq!(layer_norm.weight.data().shape(), &[1, 4, 5]);
+        assert_eq!(layer_norm.bias.data().shape(), &[1, 4, 5]);
+        assert!(layer_norm
+            .weight
+            .data()
+            .iter()
+            .all(|el| (*el - 1.).abs() <= f32::EPSILON));
+        assert!(layer_norm
+            .bias
+            .data()
+            .iter()
+            .all(|el| el.abs() <= f32::EPSILON));
+    }
+
+    #[test]
+    #[should_panic]
+    fn creation_too_many_dimensions() {
+        LayerNorm::<Ix2>::new(&[2, 3, 4], 1e-5);
+    }
+
+    #[test]
+    fn forward() {
+        let input = crate::rand((3, 4, 5)) * 10.;
+        let layer_norm = LayerNorm::<Ix3>::new(&[4, 5], 1e-5);
+
+        let output = layer_norm.forward(input);
+        output.forward();
+        assert_eq!(output.data().shape(), &[3, 4, 5]);
+
+        // Every sample has zero mean and unit variance.
+        for sample in output.data().axis_iter(Axis(0)) {
+            let mean = sample.mean().unwrap();
+            let variance = sample.mapv(|el| (el - mean).powi(2)).mean().unwrap();
+            assert!(mean.abs() <= 1e-4);
+            assert!((variance - 1.).abs() <= 1e-3);
+        }
+    }
+
+    #[test]
+    fn backward() {
+        let input = crate::rand((3, 5)).requires_grad();
+        let layer_norm = LayerNorm::<Ix2>::new(&[5], 1e-5);
+
+        let output = layer_norm.forward(input.clone());
+        let loss = (output * crate::rand((3, 5))).sum();
+        loss.forward();
+        loss.backward(1.);
+
+        assert_eq!(loss.parameters().len(), 3);
+        assert!(layer_norm.weight.grad().iter().any(|el| el.abs() > 0.));
+        assert!(layer_norm.bias.grad().iter().any(|el| el.abs() > 0.));
+        assert!(input.grad().iter().all(|el| el.is_finite()));
+        // The gradient of the normalization sums to zero over the normalized axes.
+        for row in input.grad().axis_iter(Axis(0)) {
+            assert!(row.sum().abs() <= 1e-4);
+        }
+    }
+
+    /// Checks the gradient of the input of `layer_norm` against the one computed by means of
+    /// central finite differences.
+    fn check_input_gradient<D: Dimension + 'static>(
+        layer_norm: &LayerNorm<D>,
+        input: Array<f32, D>,
+    ) {
+        let coefficients = crate::rand(input.raw_dim());
+        let loss = |input: Array<f32, D>| {
+            let loss =
+                (layer_norm.forward(crate::from_ndarray(input)) * coefficients.clone()).sum();
+            loss.forward();
+            let value = loss.data()[()];
+            value
+        };
+
+        let x = crate::from_ndarray(input.clone()).requires_grad();
+        let output = (layer_norm.forward(x.clone()) * coefficients.clone()).sum();
+        output.forward();
+        output.backward(1.);
+
+        let h = 1e-2;
+        for (i, grad) in x.grad().iter().enumerate() {
+            let (mut plus, mut minus) = (input.clone(), input.clone());
+            plus.as_slice_mut().unwrap()[i] += h;
+            minus.as_slice_mut().unwrap()[i] -= h;
+
+            let numeric = (loss(plus) - loss(minus)) / (2. * h);
+            assert!(
+                (grad - numeric).abs() <= 1e-2,
+                "analytic: {}, numeric: {}",
+                grad,
+                numeric
+            );
+        }
+    }
+
+    #[test]
+    fn backward_finite_differences() {
+        // 2-dimensional input normalized over its last axis.
+        let layer_norm = LayerNorm::<Ix2>::new(&[5], 1e-5);
+        *layer_norm.weight.data_mut() = crate::rand((1, 5)).data().to_owned() + 0.5;
+        check_input_gradient(&layer_norm, crate::rand((3, 5)).data().to_owned() * 4.);
+
+        // 3-dimensional input normalized over its last axis.
+        let layer_norm = LayerNorm::<Ix3>::new(&[4], 1e-5);
+        check_input_gradient(&layer_norm, crate::rand((2, 3, 4)).data().to_owned() * 4.);
+
+        // 3-dimensional input normalized over its last two axes.
+        let layer_norm = LayerNorm::<Ix3>::new(&[3, 4], 1e-5);
+        *layer_norm.weight.data_mut() = crate::rand((1, 3, 4)).data().to_owned() + 0.5;
+        check_input_gradient(&layer_norm, crate::rand((2, 3, 4)).data().to_owned() * 4.);
+    }
+
+    #[test]
+    fn constant_row() {
+        let input = crate::full((2, 4), 3.).requires_grad();
+        let layer_norm = LayerNorm::<Ix2>::new(&[4], 0.25);
+
+        let output = layer_norm.forward(input.clone());
+        let loss = (output.clone() * crate::range(0., 8., 1.).reshape((2, 4))).sum();
+        loss.forward();
+        loss.backward(1.);
+
+        // The variance is zero, so that the gradient is only scaled by 1 / sqrt(eps).
+        assert!(output.data().iter().all(|el| el.abs() <= f32::EPSILON));
+        assert!(input.grad().rows().into_iter().all(|row| row
+            .iter()
+            .zip([-3., -1., 1., 3.])
+            .all(|(grad, expected)| { (grad - expected).abs() <= 1e-5 })));
+    }
+
+    #[test]
+    fn fully_masked_sample() {
+        let mask = Mask2d::from_sizes(&[(3, 4), (0, 0)], (3, 4));
+        let data = crate::rand((2, 2, 3, 4)).data().to_owned() * 4.;
+        let input = crate::from_ndarray(data.clone()).requires_grad();
+        let layer_norm = LayerNorm::<Ix4>::new(&[2, 3, 4], 1e-5);
+
+        let output = layer_norm.forward_masked(input.clone(), &mask);
+        let loss = (output.clone() * crate::rand((2, 2, 3, 4))).sum();
+        loss.forward();
+        loss.backward(1.);
+
+        // The fully masked sample is normalized to zeros and receives no gradient.
+        assert!(output
+            .data()
+            .index_axis(Axis(0), 1)
+            .iter()
+            .all(|el| *el == 0.));
+        assert!(input
+            .grad()
+            .index_axis(Axis(0), 1)
+            .iter()
+            .all(|el| *el == 0.));
+        assert!(input.grad().iter().all(|el| el.is_finite()));
+        assert!(layer_norm.weight.grad().iter().all(|el| el.is_finite()));
+
+        // The valid sample is normalized as it would be alone.
+        let alone = layer_norm.forward(crate::from_ndarray(
+            data.slice(s![0..1, .., .., ..]).to_owned(),
+        ));
+        alone.forward();
+        assert!(output
+            .data()
+            .index_axis(Axis(0), 0)
+            .iter()
+            .zip(alone.data().iter())
+            .all(|(masked, alone)| (masked - alone).abs() <= 1e-4));
+    }
+
+    #[test]
+    fn training() {
+        let input = crate::rand((8, 6)) * 4. + 2.;
+        let target = crate::full((8, 6), 0.5);
+        let layer_norm = LayerNorm::<Ix2>::new(&[6], 1e-5);
+
+        let loss = mse_loss(layer_norm.forward(input), target, Reduction::Mean);
+        let optimizer = SGD::new(loss.parameters(), 0.1, L2::new(0.));
+        let mut losses = Vec::new();
+        for _ in 0..50 {
+            loss.forward();
+            losses.push(loss.data()[[]]);
+            loss.backward(1.);
+            optimizer.step();
+            optimizer.zero_grad();
+        }
+
+        assert!(losses.last().unwrap() < &(losses[0] * 0.1));
+    }
+}
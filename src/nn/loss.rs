@@ -13,6 +13,9 @@
 //! * [`mae_loss`] - Measures the mean absolute error between each element in the input and the
 //! target.
 //!
+//! * [`huber_loss`] - Measures the huber loss between each element in the input and the target,
+//! quadratic for small errors and linear for large ones.
+//!
 //! ## Probabilistic losses
 //!
 //! * [`bce_loss`] - Measures the binary cross entropy between the target and the input.
@@ -22,25 +25,71 @@
 //!
 //! * [`nll_loss`] -  Measures the negative log likelihood between the target and the input.
 //!
+//! * [`focal_loss`] -  Measures the focal loss between the target and the input, a cross entropy
+//! that down-weights well classified samples.
+//!
+//! * [`sparse_cross_entropy_loss`] - Measures the cross entropy between the logits and the class
+//! indices of the target.
+//!
 //! * [`kldiv_loss`] -  Measures the Kullback-Leibler divergence between the target and the input.
+//!
+//! * [`kldiv_loss_from_logits`] -  Measures the Kullback-Leibler divergence between the target
+//! and the log-softmax of the input.
+//!
+//! * [`jsdiv_loss`] -  Measures the Jensen-Shannon divergence between the target and the input.
+//!
+//! * [`ctc_loss`] -  Measures the connectionist temporal classification loss between a sequence
+//! of log-probabilities and a shorter target sequence.
+//!
+//! ## Margin losses
+//!
+//! * [`hinge_loss`] - Measures the hinge loss between the target and the input, used for binary
+//! maximum-margin classification.
+//!
+//! * [`multi_margin_loss`] - Measures the multi-class margin loss between the target and the
+//! input, used for maximum-margin classification over multiple classes.
+//!
+//! ## Metric learning losses
+//!
+//! * [`cosine_embedding_loss`] - Measures whether two embeddings are similar or dissimilar using
+//! their cosine similarity.
+//!
+//! * [`triplet_margin_loss`] - Measures the relative similarity between an anchor, a positive and
+//! a negative embedding.
 use super::{
     variable::{
-        BCELoss, BCELossBackward, BCEWithLogitsLoss, BCEWithLogitsLossBackward, KLDivLoss,
-        KLDivLossBackward, MAELoss, MAELossBackward, MSELoss, MSELossBackward, NLLLoss,
-        NLLLossBackward,
+        BCELoss, BCELossBackward, BCEWithLogitsLoss, BCEWithLogitsLossBackward, CTCLoss,
+        CTCLossBackward, FocalLoss, FocalLossBackward, HingeLoss, HingeLossBackward, HuberLoss,
+        HuberLossBackward, JSDivLoss, JSDivLossBackward, KLDivLoss, KLDivLossBackward, LogSoftmax,
+        LogSoftmaxBackward, MAELoss, MAELossBackward, MSELoss, MSELossBackward, MultiMarginLoss,
+        MultiMarginLossBackward, NLLLoss, NLLLossBackward, SparseCrossEntropyLoss,
+        SparseCrossEntropyLossBackward,
     },
     Data, Gradient, Var, VarDiff,
 };
-use ndarray::Dimension;
+use ndarray::{ArrayD, Axis, Dimension, Ix1, Ix2, Ix3, IxDyn};
 use std::fmt::Debug;
 
 /// Specifies the reduction to apply to the *loss* output.
+///
+/// Losses output a dynamically dimensioned variable, which is zero-dimensional when the loss is
+/// either summed or averaged.
 #[derive(Clone, Debug)]
 pub enum Reduction {
+    /// The output won't be reduced. It has the shape of the input for the losses that are averaged
+    /// over the number of elements, the shape of the target for the [`nll_loss`] and one entry for
+    /// each sample, sequence, pair or triplet for all the others. Summing it gives the
+    /// [`Reduction::Sum`] of the loss.
+    None,
     /// The output will be summed.
     Sum,
-    /// The sum of the output will be divided by the batch size for the [`kldiv_loss`] and the
-    /// [`nll_loss`]. For all other losses the output will be divided by the number of elements.
+    /// The sum of the output will be divided by the batch size for the [`kldiv_loss`], the
+    /// [`kldiv_loss_from_logits`], the [`jsdiv_loss`], the [`focal_loss`], the
+    /// [`sparse_cross_entropy_loss`] and the [`multi_margin_loss`], by the number of pairs or
+    /// triplets for the [`cosine_embedding_loss`] and the [`triplet_margin_loss`] and by the total
+    /// weight of the targets for the [`nll_loss`]. The [`ctc_loss`] divides the loss of each
+    /// sequence by the length of its target before averaging over the batch. For all other losses
+    /// the output will be divided by the number of elements.
     Mean,
 }
 
@@ -103,6 +152,92 @@ where
     VarDiff::from(backward_node, input.past, var)
 }
 
+/// Huber loss input.
+///
+/// This trait is implemented by `Var` and `VarDiff`.
+pub trait HuberLossInput<V: ?Sized>
+where
+    V: Data + 'static,
+{
+    type Output;
+
+    fn huber_loss(self, target: Var<V>, delta: f32, reduction: Reduction) -> Self::Output;
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> HuberLossInput<V> for VarDiff<T, U>
+where
+    T: Data,
+    U: Gradient<Dim = T::Dim>,
+    V: Data<Dim = T::Dim> + 'static,
+{
+    type Output = VarDiff<HuberLoss<T, V>, HuberLossBackward<U, T, V>>;
+
+    fn huber_loss(self, target: Var<V>, delta: f32, reduction: Reduction) -> Self::Output {
+        let backward_node = HuberLossBackward::new(
+            self.node,
+            self.var.node.clone(),
+            target.node.clone(),
+            delta,
+            reduction.clone(),
+        );
+        VarDiff::from(
+            backward_node,
+            self.past,
+            self.var.huber_loss(target, delta, reduction),
+        )
+    }
+}
+
+impl<T: ?Sized, V: ?Sized> HuberLossInput<V> for Var<T>
+where
+    T: Data,
+    V: Data<Dim = T::Dim> + 'static,
+{
+    type Output = Var<HuberLoss<T, V>>;
+
+    fn huber_loss(mut self, target: Var<V>, delta: f32, reduction: Reduction) -> Self::Output {
+        self.past.merge(target.past);
+        Var::from(
+            HuberLoss::new(self.node, target.node, delta, reduction),
+            self.past,
+        )
+    }
+}
+
+/// Computes the **huber loss** between each element in the input x and target y.
+///
+/// ```text
+///        1   n
+/// Lᴏss = ―   ∑ lᵢ
+///        n  i=1
+///
+///      ⎧ 0.5 * (xᵢ- ʏᵢ)²            if |xᵢ- ʏᵢ| ≤ δ
+/// lᵢ = ⎨
+///      ⎩ δ * (|xᵢ- ʏᵢ| - 0.5 * δ)   otherwise
+/// ```
+///
+/// The loss is quadratic for errors smaller than `delta` and linear for larger ones, which makes
+/// it less sensitive to outliers than the [`mse_loss`]. With a `delta` of one it's also known as
+/// the *smooth L1 loss*.
+///
+/// The input can be either a `Var` or a `VarDiff`, the target is never differentiated.
+///
+/// # Panics
+///
+/// If `delta` is not positive.
+pub fn huber_loss<I, V: ?Sized>(
+    input: I,
+    target: Var<V>,
+    delta: f32,
+    reduction: Reduction,
+) -> I::Output
+where
+    I: HuberLossInput<V>,
+    V: Data + 'static,
+{
+    input.huber_loss(target, delta, reduction)
+}
+
 /// Computes the **binary cross entropy** between the target y and input x.
 ///
 /// ```text
@@ -143,7 +278,7 @@ where
 ///
 /// ```text
 ///        1   n
-/// Lᴏss = ―   ∑  - [ʏᵢ * ln(σ(xᵢ)) + (1 - ʏᵢ) * ln(1 - σ(xᵢ))]
+/// Lᴏss = ―   ∑  - [pᵢ * ʏᵢ * ln(σ(xᵢ)) + (1 - ʏᵢ) * ln(1 - σ(xᵢ))]
 ///        n  i=1
 /// ```
 /// This loss combines a sigmoid and a binary cross entropy.
@@ -152,9 +287,19 @@ where
 /// advantage of the log-sum-exp trick for numerical stability.
 /// Note that the target y should be numbers between 0 and 1 and the
 /// input x should be raw unnormalized scores.
+///
+/// The optional `pos_weight` p weights the positive examples and must be broadcastable to the
+/// shape of the input, e.g. it can hold a weight for each class of a multi-label problem. A
+/// positive weight greater than one increases the recall, while one smaller than one increases
+/// the precision. When it's not given every positive example has weight one.
+///
+/// # Panics
+///
+/// If `pos_weight` is not broadcastable to the shape of the input.
 pub fn bce_with_logits_loss<T: ?Sized, U: ?Sized, V: ?Sized>(
     mut input: VarDiff<T, U>,
     target: Var<V>,
+    pos_weight: Option<ArrayD<f32>>,
     reduction: Reduction,
 ) -> VarDiff<BCEWithLogitsLoss<T, V>, BCEWithLogitsLossBackward<U, T, V>>
 where
@@ -166,29 +311,47 @@ where
     let forward_node = BCEWithLogitsLoss::new(
         input.var.node.clone(),
         target.node.clone(),
+        pos_weight.clone(),
         reduction.clone(),
     );
     let var = Var::from(forward_node, input.var.past);
 
-    let backward_node =
-        BCEWithLogitsLossBackward::new(input.node, input.var.node, target.node, reduction);
+    let backward_node = BCEWithLogitsLossBackward::new(
+        input.node,
+        input.var.node,
+        target.node,
+        pos_weight,
+        reduction,
+    );
     VarDiff::from(backward_node, input.past, var)
 }
 
 /// Computes the **negative log likelihood** between the target y and input x.
 ///
 /// ```text
-///         1   n
-/// Lᴏss =  ―   ∑  - xₙ,ᵧₙ
-///         n  i=1
+///           1     n
+/// Lᴏss =  ―――――   ∑  - wᵧₙ * xₙ,ᵧₙ
+///         ∑ wᵧₙ  i=1
 /// ```
 ///
 /// The input x given is expected to contain log-probabilities for each class,
 /// this is typically achieved by using [`.log_softmax()`]. input has to be a of size either
 /// (minibatch, C) or (minibatch, C, d1, d2, ..., dk) with k >= 1 for the K-dimensional
 /// case. The target that this loss expects should be a class index in the range [0, C) where
-/// C = number of classes. When the given reduction is equal to [`Reduction::Mean`] the total
-/// loss is divided by the batch size.
+/// C = number of classes.
+///
+/// The optional `weight` assigns a weight to each of the C classes, which is useful when
+/// training on an unbalanced dataset, while the targets equal to the optional `ignore_index`
+/// contribute neither to the loss nor to the gradient. When the given reduction is equal to
+/// [`Reduction::Mean`] the total loss is divided by the sum of the weights of the targets that
+/// are not ignored, which is the number of such targets when no weight is given. If every target
+/// is ignored the loss is zero.
+///
+/// A `label_smoothing` factor *s* greater than zero replaces the one-hot target of each sample
+/// with a smoothed distribution, that is *1 - s* on the target class and *s / (C - 1)* on each of
+/// the others, which is weighted as a whole by the weight of the target class. Combined with a
+/// [`.log_softmax()`] this yields the label smoothed cross entropy, whose gradient wrt the logits
+/// is the softmax minus the smoothed target. A factor of zero gives the plain loss.
 ///
 /// As mentioned before, this loss can also be used for higher dimensional inputs, such as 2D
 /// images, by providing an input of size (minibatch, C, d1, d2, ..., dk) with k >= 1 where
@@ -197,10 +360,17 @@ where
 /// In the K-dimensional case this loss expects a target of shape
 /// (minibatch, d1, d2, ..., dk).
 ///
+/// # Panics
+///
+/// If `weight` doesn't have exactly C elements or if `label_smoothing` is not in *[0, 1]*.
+///
 /// [`.log_softmax()`]: VarDiff::log_softmax()
 pub fn nll_loss<T: ?Sized, U: ?Sized, V: ?Sized>(
     mut input: VarDiff<T, U>,
     target: Var<V>,
+    weight: Option<&[f32]>,
+    ignore_index: Option<usize>,
+    label_smoothing: f32,
     reduction: Reduction,
 ) -> VarDiff<NLLLoss<T, V>, NLLLossBackward<U, V>>
 where
@@ -210,14 +380,25 @@ where
     T::Dim: Copy,
 {
     input.var.past.merge(target.past);
+    let weight = weight.map(<[f32]>::to_vec);
     let forward_node = NLLLoss::new(
         input.var.node.clone(),
         target.node.clone(),
+        weight.clone(),
+        ignore_index,
+        label_smoothing,
         reduction.clone(),
     );
     let var = Var::from(forward_node, input.var.past);
 
-    let backward_node = NLLLossBackward::new(input.node, target.node, reduction);
+    let backward_node = NLLLossBackward::new(
+        input.node,
+        target.node,
+        weight,
+        ignore_index,
+        label_smoothing,
+        reduction,
+    );
     VarDiff::from(backward_node, input.past, var)
 }
 
@@ -234,13 +415,16 @@ where
 /// direct regression over the space of (discretely sampled) continuous output distributions.
 ///
 /// The input given is expected to contain log-probabilities and is not restricted to a 2D Tensor,
-/// while the targets are interpreted as probabilities. When the given reduction is equal
-/// to [`Reduction::Mean`] the total loss is divided by the batch size.
+/// while the targets are interpreted as probabilities, or as log-probabilities when `log_target`
+/// is `true`. Targets equal to zero contribute nothing to the loss, as *0 * ln(0)* is taken to be
+/// zero. When the given reduction is equal to [`Reduction::Mean`] the total loss is divided by the
+/// batch size.
 ///
 /// This criterion expects a target variable of the same size as the input variable.
 pub fn kldiv_loss<T: ?Sized, U: ?Sized, V: ?Sized>(
     mut input: VarDiff<T, U>,
     target: Var<V>,
+    log_target: bool,
     reduction: Reduction,
 ) -> VarDiff<KLDivLoss<T, V>, KLDivLossBackward<U, V>>
 where
@@ -250,12 +434,1207 @@ where
 {
     input.var.past.merge(target.past);
     let forward_node = KLDivLoss::new(
+        input.var.node.clone(),
+        target.node.clone(),
+        log_target,
+        reduction.clone(),
+    );
+    let var = Var::from(forward_node, input.var.past);
+
+    let backward_node = KLDivLossBackward::new(input.node, target.node, log_target, reduction);
+    VarDiff::from(backward_node, input.past, var)
+}
+
+/// The variable returned by [`kldiv_loss_from_logits`].
+type KLDivLossFromLogits<T, U, V> = VarDiff<
+    KLDivLoss<LogSoftmax<T>, V>,
+    KLDivLossBackward<LogSoftmaxBackward<U, LogSoftmax<T>>, V>,
+>;
+
+/// Computes the **Kullback-Leibler** divergence between the target and the log-softmax of the
+/// input along `axis`.
+///
+/// This is the same as the [`kldiv_loss`] of [`.log_softmax(axis)`] and the target, so that the
+/// input can hold raw, unnormalized scores. The gradient wrt the input is the softmax of the
+/// input minus the target, scaled by the total mass of the target along `axis`.
+///
+/// # Panics
+///
+/// If `axis` is out of bounds for the input.
+///
+/// [`.log_softmax(axis)`]: VarDiff::log_softmax()
+pub fn kldiv_loss_from_logits<T: ?Sized, U: ?Sized, V: ?Sized>(
+    input: VarDiff<T, U>,
+    target: Var<V>,
+    axis: usize,
+    log_target: bool,
+    reduction: Reduction,
+) -> KLDivLossFromLogits<T, U, V>
+where
+    T: Data,
+    U: Gradient<Dim = T::Dim>,
+    V: Data<Dim = T::Dim>,
+{
+    kldiv_loss(input.log_softmax(axis), target, log_target, reduction)
+}
+
+/// Computes the **Jensen-Shannon** divergence between the target and the input.
+///
+/// ```text
+///         n
+/// Lᴏss =  ∑  0.5 * [ʏₙ * ln(ʏₙ / mₙ) + qₙ * ln(qₙ / mₙ)]
+///        i=1
+///
+/// qₙ = exp(xₙ),  mₙ = 0.5 * (ʏₙ + qₙ)
+/// ```
+///
+/// The [Jensen-Shannon divergence](https://en.wikipedia.org/wiki/Jensen–Shannon_divergence) is a
+/// symmetrized and bounded version of the Kullback-Leibler divergence, it's the average of the
+/// [`kldiv_loss`] of both distributions from their mixture.
+///
+/// As for the [`kldiv_loss`] the input is expected to contain log-probabilities, while the targets
+/// are interpreted as probabilities. Zero probabilities in either of them contribute nothing to
+/// the loss. When the given reduction is equal to [`Reduction::Mean`] the total loss is divided by
+/// the batch size.
+///
+/// This criterion expects a target variable of the same size as the input variable.
+pub fn jsdiv_loss<T: ?Sized, U: ?Sized, V: ?Sized>(
+    mut input: VarDiff<T, U>,
+    target: Var<V>,
+    reduction: Reduction,
+) -> VarDiff<JSDivLoss<T, V>, JSDivLossBackward<U, T, V>>
+where
+    T: Data,
+    U: Gradient<Dim = T::Dim>,
+    V: Data<Dim = T::Dim>,
+{
+    input.var.past.merge(target.past);
+    let forward_node = JSDivLoss::new(
+        input.var.node.clone(),
+        target.node.clone(),
+        reduction.clone(),
+    );
+    let var = Var::from(forward_node, input.var.past);
+
+    let backward_node = JSDivLossBackward::new(input.node, input.var.node, target.node, reduction);
+    VarDiff::from(backward_node, input.past, var)
+}
+
+/// Balancing factor of the [`focal_loss`].
+#[derive(Clone, Copy, Debug)]
+pub enum FocalAlpha<'a> {
+    /// The same factor is applied to every class.
+    Scalar(f32),
+    /// One factor for each class.
+    PerClass(&'a [f32]),
+}
+
+/// Computes the **focal loss** between the input and the target.
+///
+/// ```text
+/// Lᴏssₙ = - αʏₙ * (1 - pₙ)ᵞ * ln(pₙ)
+///
+/// pₙ = softmax(xₙ)[ʏₙ]
+/// ```
+///
+/// The [focal loss](https://arxiv.org/abs/1708.02002) is a cross entropy whose terms are
+/// modulated by *(1 - pₙ)ᵞ*, so that well classified samples contribute less to the total loss
+/// and training focuses on the hard ones. It is thus suited to heavily class-imbalanced problems.
+/// When `gamma` is zero and `alpha` is one it is equal to the cross entropy.
+///
+/// The input given is expected to contain the raw, unnormalized scores of each class, the
+/// log-softmax is computed internally in a numerically stable way. It must be a 2D Tensor of shape
+/// *(N, C)*, while the target must be a 1D Tensor of size *N* containing the class index of each
+/// sample, as for the [`nll_loss`]. When the given reduction is equal to [`Reduction::Mean`] the
+/// total loss is divided by the batch size.
+///
+/// # Panics
+///
+/// If `alpha` is [`FocalAlpha::PerClass`] and it doesn't contain exactly one factor per class.
+pub fn focal_loss<T: ?Sized, U: ?Sized, V: ?Sized>(
+    mut input: VarDiff<T, U>,
+    target: Var<V>,
+    alpha: FocalAlpha,
+    gamma: f32,
+    reduction: Reduction,
+) -> VarDiff<FocalLoss<T, V>, FocalLossBackward<U, T, V>>
+where
+    T: Data<Dim = Ix2>,
+    U: Gradient<Dim = Ix2>,
+    V: Data<Dim = Ix1>,
+{
+    input.var.past.merge(target.past);
+    let alpha = match alpha {
+        FocalAlpha::Scalar(alpha) => vec![alpha; input.var.node.data().len_of(Axis(1))],
+        FocalAlpha::PerClass(alpha) => alpha.to_vec(),
+    };
+    let forward_node = FocalLoss::new(
+        input.var.node.clone(),
+        target.node.clone(),
+        alpha.clone(),
+        gamma,
+        reduction.clone(),
+    );
+    let var = Var::from(forward_node, input.var.past);
+
+    let backward_node = FocalLossBackward::new(
+        input.node,
+        input.var.node,
+        target.node,
+        alpha,
+        gamma,
+        reduction,
+    );
+    VarDiff::from(backward_node, input.past, var)
+}
+
+/// Computes the **cross entropy** between the logits x and the class indices y.
+///
+/// ```text
+/// Lᴏssₙ = - ln(softmax(xₙ)[ʏₙ])
+/// ```
+///
+/// The input given is expected to contain the raw, unnormalized scores of each class and must be
+/// a 2D Tensor of shape *(N, C)*, while the target holds the class index of each of the N
+/// samples. The log-softmax is computed internally in a numerically stable way and only the
+/// entry of the target class is picked, so that no one-hot target is ever built. For the same
+/// reason the gradient wrt the logits, the softmax minus the one-hot target, is obtained by
+/// subtracting one from the target class alone. When the given reduction is equal to
+/// [`Reduction::Mean`] the total loss is divided by the batch size.
+///
+/// This is equivalent to a [`nll_loss`] over the [`.log_softmax(1)`] of the input.
+///
+/// # Panics
+///
+/// If the number of targets differs from the batch size or if any of them is not a class.
+///
+/// [`.log_softmax(1)`]: VarDiff::log_softmax()
+pub fn sparse_cross_entropy_loss<T: ?Sized, U: ?Sized>(
+    input: VarDiff<T, U>,
+    target: &[usize],
+    reduction: Reduction,
+) -> VarDiff<SparseCrossEntropyLoss<T>, SparseCrossEntropyLossBackward<U, T>>
+where
+    T: Data<Dim = Ix2>,
+    U: Gradient<Dim = Ix2>,
+{
+    let forward_node =
+        SparseCrossEntropyLoss::new(input.var.node.clone(), target.to_vec(), reduction.clone());
+    let var = Var::from(forward_node, input.var.past);
+
+    let backward_node =
+        SparseCrossEntropyLossBackward::new(input.node, input.var.node, target.to_vec(), reduction);
+    VarDiff::from(backward_node, input.past, var)
+}
+
+/// Computes the **connectionist temporal classification loss** between the log-probabilities x
+/// and the target sequences y.
+///
+/// ```text
+///          N
+/// Lᴏss = - ∑ ln ∑ ∏ xₜₙ[πₜ]
+///         n=1  π  t
+/// ```
+///
+/// The inner sum ranges over all the alignments π of the n-th target, i.e. the paths of
+/// `input_lengths[n]` classes that collapse to it once repeated classes are merged and the blanks
+/// are removed. It's computed by the forward-backward algorithm in log space.
+///
+/// The input must be a 3D Tensor of shape *(T, N, C)* holding the log-probabilities of the classes
+/// at each time step, as given for example by a `.log_softmax(2)`, while the target must be a 2D
+/// Tensor of shape *(N, S)* holding the class indices of each sequence, padded to the length of the
+/// longest one. The first `input_lengths[n]` time steps and the first `target_lengths[n]` labels
+/// of each sequence are considered, the blank must not appear among the labels.
+///
+/// The gradient is computed wrt the log-probabilities themselves. Targets that cannot be aligned
+/// with their input, e.g. because it's too short, yield an infinite loss and no gradient.
+///
+/// # Panics
+///
+/// If the lengths don't match the batch size, if they exceed the shape of the input or of the
+/// target or if `blank` is not a class.
+pub fn ctc_loss<T: ?Sized, U: ?Sized, V: ?Sized>(
+    mut input: VarDiff<T, U>,
+    target: Var<V>,
+    input_lengths: &[usize],
+    target_lengths: &[usize],
+    blank: usize,
+    reduction: Reduction,
+) -> VarDiff<CTCLoss<T, V>, CTCLossBackward<U, T, V>>
+where
+    T: Data<Dim = Ix3>,
+    U: Gradient<Dim = Ix3>,
+    V: Data<Dim = Ix2>,
+{
+    input.var.past.merge(target.past);
+    let forward_node = CTCLoss::new(
+        input.var.node.clone(),
+        target.node.clone(),
+        input_lengths.to_vec(),
+        target_lengths.to_vec(),
+        blank,
+        reduction.clone(),
+    );
+    let var = Var::from(forward_node, input.var.past);
+
+    let backward_node = CTCLossBackward::new(
+        input.node,
+        input.var.node,
+        target.node,
+        input_lengths.to_vec(),
+        target_lengths.to_vec(),
+        blank,
+        reduction,
+    );
+    VarDiff::from(backward_node, input.past, var)
+}
+
+/// Computes the **hinge loss** between the input x and the target y.
+///
+/// ```text
+///        1   n
+/// Lᴏss = ―   ∑ max(0, 1 - ʏᵢ * xᵢ)
+///        n  i=1
+/// ```
+///
+/// The targets are expected to be either *1* or *-1*. Only the elements whose margin is strictly
+/// positive contribute to the gradient, so that samples lying exactly on the boundary
+/// *ʏᵢ * xᵢ = 1* are left untouched.
+///
+/// This criterion expects a target variable of the same size as the input variable.
+pub fn hinge_loss<T: ?Sized, U: ?Sized, V: ?Sized>(
+    mut input: VarDiff<T, U>,
+    target: Var<V>,
+    reduction: Reduction,
+) -> VarDiff<HingeLoss<T, V>, HingeLossBackward<U, T, V>>
+where
+    T: Data,
+    U: Gradient<Dim = T::Dim>,
+    V: Data<Dim = T::Dim>,
+{
+    input.var.past.merge(target.past);
+    let forward_node = HingeLoss::new(
         input.var.node.clone(),
         target.node.clone(),
         reduction.clone(),
     );
     let var = Var::from(forward_node, input.var.past);
 
-    let backward_node = KLDivLossBackward::new(input.node, target.node, reduction);
+    let backward_node = HingeLossBackward::new(input.node, input.var.node, target.node, reduction);
     VarDiff::from(backward_node, input.past, var)
 }
+
+/// Computes the **multi-class margin loss** between the input x and the target y.
+///
+/// ```text
+///         1   C
+/// Lᴏssₙ = ―   ∑  max(0, margin - xₙ[ʏₙ] + xₙ[i])ᵖ
+///         C  i≠ʏₙ
+/// ```
+///
+/// The input given is expected to contain the raw, unnormalized scores of each class and must be
+/// a 2D Tensor of shape *(N, C)*, while the target must be a 1D Tensor of size *N* containing the
+/// class index of each sample. The exponent `p` must be either *1* or *2*.
+///
+/// The gradient of each violated margin is propagated to its class and, with opposite sign, to the
+/// target class. Margins lying exactly on the boundary are not considered violated.
+///
+/// # Panics
+///
+/// If `p` is neither *1* nor *2*.
+pub fn multi_margin_loss<T: ?Sized, U: ?Sized, V: ?Sized>(
+    mut input: VarDiff<T, U>,
+    target: Var<V>,
+    margin: f32,
+    p: i32,
+    reduction: Reduction,
+) -> VarDiff<MultiMarginLoss<T, V>, MultiMarginLossBackward<U, T, V>>
+where
+    T: Data<Dim = Ix2>,
+    U: Gradient<Dim = Ix2>,
+    V: Data<Dim = Ix1>,
+{
+    input.var.past.merge(target.past);
+    let forward_node = MultiMarginLoss::new(
+        input.var.node.clone(),
+        target.node.clone(),
+        margin,
+        p,
+        reduction.clone(),
+    );
+    let var = Var::from(forward_node, input.var.past);
+
+    let backward_node = MultiMarginLossBackward::new(
+        input.node,
+        input.var.node,
+        target.node,
+        margin,
+        p,
+        reduction,
+    );
+    VarDiff::from(backward_node, input.past, var)
+}
+
+/// Lower bound for the norms of the embeddings compared by the [`cosine_embedding_loss`].
+const COSINE_EPS: f32 = 1e-8;
+
+/// Offset added to the differences of the embeddings compared by the [`triplet_margin_loss`].
+const DISTANCE_EPS: f32 = 1e-6;
+
+/// Applies `reduction` to a loss computed for each pair or triplet of embeddings.
+fn reduce<T: ?Sized, U: ?Sized>(
+    loss: VarDiff<T, U>,
+    reduction: Reduction,
+) -> VarDiff<dyn Data<Dim = IxDyn>, dyn Gradient<Dim = IxDyn>>
+where
+    T: Data + 'static,
+    U: Gradient<Dim = T::Dim> + 'static,
+{
+    match reduction {
+        Reduction::None => {
+            let shape = loss.data().raw_dim().into_dyn();
+            loss.reshape(shape).into_dyn()
+        }
+        Reduction::Sum => loss.sum().reshape(IxDyn(&[])).into_dyn(),
+        Reduction::Mean => loss.mean().reshape(IxDyn(&[])).into_dyn(),
+    }
+}
+
+/// Returns the axis holding the features of embeddings with `ndim` dimensions.
+fn feature_axis(ndim: usize) -> usize {
+    assert!(
+        ndim > 0,
+        "error: embeddings must have at least one dimension."
+    );
+
+    ndim - 1
+}
+
+/// Computes the **cosine embedding loss** between the embeddings x1 and x2, given a target y
+/// telling whether they are similar, with y = 1, or dissimilar, with y = -1.
+///
+/// ```text
+///        1   n
+/// Lᴏss = ―   ∑ lᵢ
+///        n  i=1
+///
+///      ⎧ 1 - cos(x1ᵢ, x2ᵢ)                if ʏᵢ = 1
+/// lᵢ = ⎨
+///      ⎩ max(0, cos(x1ᵢ, x2ᵢ) - margin)   if ʏᵢ = -1
+/// ```
+///
+/// The embeddings lie along the last axis of x1 and x2, the target must be broadcastable to the
+/// shape of the inputs with their last axis of length one, e.g. (N, 1) for two batches of N
+/// embeddings of shape (N, D). The margin should be between -1 and 1, dissimilar pairs whose
+/// cosine similarity is already below it contribute neither to the loss nor to the gradient.
+///
+/// The norms of the embeddings are clamped to be at least `1e-8`, so that zero embeddings don't
+/// cause divisions by zero.
+///
+/// # Panics
+///
+/// If the inputs are zero-dimensional.
+pub fn cosine_embedding_loss<D, F1: ?Sized, B1: ?Sized, F2: ?Sized, B2: ?Sized, F3: ?Sized>(
+    x1: VarDiff<F1, B1>,
+    x2: VarDiff<F2, B2>,
+    target: Var<F3>,
+    margin: f32,
+    reduction: Reduction,
+) -> VarDiff<dyn Data<Dim = IxDyn>, dyn Gradient<Dim = IxDyn>>
+where
+    D: Dimension + 'static,
+    F1: Data<Dim = D> + 'static,
+    B1: Gradient<Dim = D> + 'static,
+    F2: Data<Dim = D> + 'static,
+    B2: Gradient<Dim = D> + 'static,
+    F3: Data<Dim = D> + 'static,
+{
+    let axis = feature_axis(x1.data().ndim());
+    let features = x1.data().len_of(Axis(axis)) as f32;
+    let cosine = (x1.normalize(2., &[axis], COSINE_EPS) * x2.normalize(2., &[axis], COSINE_EPS))
+        .mean_axes(&[axis])
+        * features;
+
+    // Masks selecting the similar and the dissimilar pairs.
+    let similar = (target.clone() + 1.) / 2.;
+    let dissimilar = (target - 1.) / -2.;
+    let loss = (-cosine.clone() + 1.) * similar + (cosine - margin).relu() * dissimilar;
+
+    reduce(loss, reduction)
+}
+
+/// Computes the **triplet margin loss** between the anchor a, the positive example p and the
+/// negative example n.
+///
+/// ```text
+///        1   N
+/// Lᴏss = ―   ∑ max(0, d(aᵢ, pᵢ) - d(aᵢ, nᵢ) + margin)
+///        N  i=1
+///
+/// d(x, ʏ) = ‖x - ʏ + eps‖ₚ
+/// ```
+///
+/// The loss pulls the anchor towards the positive example while pushing it away from the
+/// negative one, it's used to learn embeddings in which the distance reflects the similarity of
+/// the samples. Triplets whose negative example is already farther than the positive one by at
+/// least `margin` contribute neither to the loss nor to the gradient.
+///
+/// The embeddings lie along the last axis of the inputs and their distance is measured by the
+/// *Lp norm* of their difference, which is offset by `1e-6` so that the distance of two equal
+/// embeddings is differentiable.
+///
+/// # Panics
+///
+/// If the inputs are zero-dimensional.
+pub fn triplet_margin_loss<
+    D,
+    F1: ?Sized,
+    B1: ?Sized,
+    F2: ?Sized,
+    B2: ?Sized,
+    F3: ?Sized,
+    B3: ?Sized,
+>(
+    anchor: VarDiff<F1, B1>,
+    positive: VarDiff<F2, B2>,
+    negative: VarDiff<F3, B3>,
+    margin: f32,
+    p: f32,
+    reduction: Reduction,
+) -> VarDiff<dyn Data<Dim = IxDyn>, dyn Gradient<Dim = IxDyn>>
+where
+    D: Dimension + 'static,
+    F1: Data<Dim = D> + 'static,
+    B1: Gradient<Dim = D> + 'static,
+    F2: Data<Dim = D> + 'static,
+    B2: Gradient<Dim = D> + 'static,
+    F3: Data<Dim = D> + 'static,
+    B3: Gradient<Dim = D> + 'static,
+{
+    let axis = feature_axis(anchor.data().ndim());
+    let positive_distance = (anchor.clone() - positive + DISTANCE_EPS).norm(p, &[axis]);
+    let negative_distance = (anchor - negative + DISTANCE_EPS).norm(p, &[axis]);
+    let loss = (positive_distance - negative_distance + margin).relu();
+
+    reduce(loss, reduction)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        bce_loss, bce_with_logits_loss, cosine_embedding_loss, ctc_loss, focal_loss, hinge_loss,
+        huber_loss, jsdiv_loss, kldiv_loss, kldiv_loss_from_logits, mae_loss, mse_loss,
+        multi_margin_loss, nll_loss, sparse_cross_entropy_loss, triplet_margin_loss, Data,
+        FocalAlpha, Gradient, Reduction, VarDiff,
+    };
+    use crate::variable::{check_gradient, Input, InputBackward};
+    use ndarray::{array, Array, Array2, Axis, Dimension, IxDyn};
+
+    /// Checks element-wise whether `array` is within `1e-3` of `target`.
+    fn assert_close(array: &Array2<f32>, target: &Array2<f32>) {
+        assert!(
+            array
+                .iter()
+                .zip(target.iter())
+                .all(|(l, r)| (l - r).abs() < 1e-3),
+            "\nLeft:\n{}\nRight:\n{}",
+            array,
+            target
+        );
+    }
+
+    /// Checks that averaging the unreduced output of `loss` gives back both the value and the
+    /// gradient of its mean reduction, for an input with elements `input`.
+    fn assert_none_then_mean<D, F, T: ?Sized, U: ?Sized>(input: Array<f32, D>, loss: F)
+    where
+        D: Dimension + 'static,
+        F: Fn(VarDiff<Input<D>, InputBackward<D>>, Reduction) -> VarDiff<T, U>,
+        T: Data<Dim = IxDyn> + 'static,
+        U: Gradient<Dim = IxDyn> + 'static,
+    {
+        let unreduced_input = crate::from_ndarray(input.clone()).requires_grad();
+        let unreduced = loss(unreduced_input.clone(), Reduction::None);
+        unreduced.forward();
+        assert!(unreduced.data().ndim() > 0);
+
+        let averaged = unreduced.mean();
+        averaged.forward();
+        averaged.backward(1.);
+
+        let mean_input = crate::from_ndarray(input).requires_grad();
+        let mean = loss(mean_input.clone(), Reduction::Mean);
+        mean.forward();
+        mean.backward(1.);
+
+        assert!((averaged.data()[()] - mean.data()[[]]).abs() < 1e-5);
+        assert!(unreduced_input
+            .grad()
+            .iter()
+            .zip(mean_input.grad().iter())
+            .all(|(l, r)| (l - r).abs() < 1e-5));
+    }
+
+    #[test]
+    fn none_then_mean() {
+        let input = array![[0.5, -1., 2.], [-3., 0.2, 0.7]];
+        let target = crate::from_ndarray(array![[1., 0., 2.5], [0., -1., 0.7]]);
+        assert_none_then_mean(input.clone(), |x, reduction| {
+            mse_loss(x, target.clone(), reduction)
+        });
+        assert_none_then_mean(input.clone(), |x, reduction| {
+            mae_loss(x, target.clone(), reduction)
+        });
+        assert_none_then_mean(input.clone(), |x, reduction| {
+            huber_loss(x, target.clone(), 1., reduction)
+        });
+
+        let signs = crate::from_ndarray(array![[1., -1., 1.], [-1., -1., 1.]]);
+        assert_none_then_mean(input.clone(), |x, reduction| {
+            hinge_loss(x, signs.clone(), reduction)
+        });
+
+        let labels = crate::from_ndarray(array![[1., 0., 1.], [0., 0., 1.]]);
+        assert_none_then_mean(input.clone(), |x, reduction| {
+            bce_with_logits_loss(x, labels.clone(), None, reduction)
+        });
+        assert_none_then_mean(input.mapv(|el| 1. / (1. + (-el).exp())), |x, reduction| {
+            bce_loss(x, labels.clone(), reduction)
+        });
+
+        // The divergences and the classification losses output the loss of each sample.
+        let distribution = crate::from_ndarray(array![[0.2, 0.5, 0.3], [0.6, 0., 0.4]]);
+        assert_none_then_mean(input.clone(), |x, reduction| {
+            kldiv_loss(x.log_softmax(1), distribution.clone(), false, reduction)
+        });
+        assert_none_then_mean(input.clone(), |x, reduction| {
+            kldiv_loss_from_logits(x, distribution.clone(), 1, false, reduction)
+        });
+        assert_none_then_mean(input.clone(), |x, reduction| {
+            jsdiv_loss(x.log_softmax(1), distribution.clone(), reduction)
+        });
+
+        let classes = crate::from_ndarray(array![2., 0.]);
+        assert_none_then_mean(input.clone(), |x, reduction| {
+            nll_loss(
+                x.log_softmax(1),
+                classes.clone(),
+                None,
+                None,
+                0.1,
+                reduction,
+            )
+        });
+        assert_none_then_mean(input.clone(), |x, reduction| {
+            focal_loss(x, classes.clone(), FocalAlpha::Scalar(0.5), 2., reduction)
+        });
+        assert_none_then_mean(input.clone(), |x, reduction| {
+            sparse_cross_entropy_loss(x, &[2, 0], reduction)
+        });
+        assert_none_then_mean(input.clone(), |x, reduction| {
+            multi_margin_loss(x, classes.clone(), 1., 2, reduction)
+        });
+
+        let other = array![[1., 2., -1.], [0.5, -1., 2.]];
+        let pairs = crate::from_ndarray(array![[1.], [-1.]]);
+        assert_none_then_mean(input.clone(), |x, reduction| {
+            let other = crate::from_ndarray(other.clone()).requires_grad();
+            cosine_embedding_loss(x, other, pairs.clone(), 0.1, reduction)
+        });
+        assert_none_then_mean(input, |x, reduction| {
+            let positive = crate::from_ndarray(other.clone()).requires_grad();
+            let negative = crate::from_ndarray(-other.clone()).requires_grad();
+            triplet_margin_loss(x, positive, negative, 1., 2., reduction)
+        });
+
+        // The mean of the connectionist temporal classification divides the loss of each
+        // sequence by the length of its target, which is one here.
+        let logits = Array::from_shape_fn((3, 2, 3), |(t, n, c)| ((t + 2 * n + c) as f32).sin());
+        let labels = crate::from_ndarray(array![[1.], [2.]]);
+        assert_none_then_mean(logits, |x, reduction| {
+            ctc_loss(
+                x.log_softmax(2),
+                labels.clone(),
+                &[3, 2],
+                &[1, 1],
+                0,
+                reduction,
+            )
+        });
+    }
+
+    #[test]
+    fn huber_loss_var() {
+        let input = crate::from_ndarray(ndarray::array![0.5, -1., 2., -3.]);
+        let loss = huber_loss(input, crate::zeros(4), 1., Reduction::Sum);
+
+        loss.forward();
+        assert!((loss.data()[[]] - 4.625).abs() <= f32::EPSILON);
+    }
+
+    #[test]
+    fn huber_loss_transition() {
+        // The gradient is continuous at |e| = delta and clipped beyond it.
+        let input = crate::from_ndarray(ndarray::array![1.5, 2., 2.5, -2.]).requires_grad();
+        let loss = huber_loss(input.clone(), crate::zeros(4), 2., Reduction::Sum);
+
+        loss.forward();
+        loss.backward(1.);
+        assert_eq!(*input.grad(), ndarray::array![1.5, 2., 2., -2.]);
+    }
+
+    #[test]
+    fn huber_loss_reductions() {
+        let input = crate::from_ndarray(ndarray::array![[0.5, -1.], [2., -3.]]).requires_grad();
+        let target = crate::from_ndarray(ndarray::array![[0., 0.], [0., 0.]]);
+
+        let sum = huber_loss(input.clone(), target.clone(), 1., Reduction::Sum);
+        sum.forward();
+        sum.backward(1.);
+        assert!((sum.data()[[]] - 4.625).abs() <= f32::EPSILON);
+        assert_eq!(*input.grad(), ndarray::array![[0.5, -1.], [1., -1.]]);
+
+        let input = crate::from_ndarray(ndarray::array![[0.5, -1.], [2., -3.]]).requires_grad();
+        let mean = huber_loss(input.clone(), target, 1., Reduction::Mean);
+        mean.forward();
+        mean.backward(1.);
+        assert!((mean.data()[[]] - 1.15625).abs() <= f32::EPSILON);
+        assert_eq!(
+            *input.grad(),
+            ndarray::array![[0.125, -0.25], [0.25, -0.25]]
+        );
+    }
+
+    #[test]
+    fn mse_loss_unreduced() {
+        // Averaging the per-element loss must give back both the value and the gradient of the
+        // mean reduction.
+        let input = crate::from_ndarray(ndarray::array![[0.5, -1.], [2., -3.]]).requires_grad();
+        let target = crate::from_ndarray(ndarray::array![[1., 0.], [0., -1.]]);
+
+        let unreduced = mse_loss(input.clone(), target.clone(), Reduction::None);
+        unreduced.forward();
+        assert_eq!(
+            *unreduced.data(),
+            ndarray::array![[0.25, 1.], [4., 4.]].into_dyn()
+        );
+
+        let manual = unreduced.mean();
+        manual.forward();
+        manual.backward(1.);
+        let manual_grad = input.grad().clone();
+
+        let input = crate::from_ndarray(ndarray::array![[0.5, -1.], [2., -3.]]).requires_grad();
+        let mean = mse_loss(input.clone(), target, Reduction::Mean);
+        mean.forward();
+        mean.backward(1.);
+
+        assert!((manual.data()[[]] - mean.data()[[]]).abs() <= f32::EPSILON);
+        assert!(manual_grad
+            .iter()
+            .zip(input.grad().iter())
+            .all(|(l, r)| (l - r).abs() <= f32::EPSILON));
+    }
+
+    #[test]
+    fn bce_with_logits_loss_composed() {
+        let logits = ndarray::array![[-3., -0.5, 0.], [0.25, 1.5, 4.]];
+        let target = crate::from_ndarray(ndarray::array![[0., 1., 0.5], [1., 0., 1.]]);
+
+        let input = crate::from_ndarray(logits.clone()).requires_grad();
+        let fused = bce_with_logits_loss(input.clone(), target.clone(), None, Reduction::Mean);
+        fused.forward();
+        fused.backward(1.);
+        let fused_grad = input.grad().clone();
+
+        let input = crate::from_ndarray(logits).requires_grad();
+        let composed = bce_loss(input.clone().sigmoid(), target, Reduction::Mean);
+        composed.forward();
+        composed.backward(1.);
+
+        assert!((fused.data()[[]] - composed.data()[[]]).abs() < 1e-6);
+        assert!(fused_grad
+            .iter()
+            .zip(input.grad().iter())
+            .all(|(l, r)| (l - r).abs() < 1e-6));
+    }
+
+    #[test]
+    fn kldiv_loss_zero_targets() {
+        // The second row of the target holds an exact zero, which must not produce a NaN.
+        let log_probs = ndarray::array![[0.4_f32, 0.5, 0.1], [0.6, 0.1, 0.3]].mapv(f32::ln);
+        let target = ndarray::array![[0.2_f32, 0.5, 0.3], [0.6, 0., 0.4]];
+
+        let input = crate::from_ndarray(log_probs.clone()).requires_grad();
+        let loss = kldiv_loss(
+            input.clone(),
+            crate::from_ndarray(target.clone()),
+            false,
+            Reduction::Sum,
+        );
+        loss.forward();
+        loss.backward(1.);
+        assert!((loss.data()[[]] - 0.3060).abs() < 1e-4);
+        assert_eq!(*input.grad(), -target.clone());
+
+        let input = crate::from_ndarray(log_probs).requires_grad();
+        let loss = kldiv_loss(
+            input.clone(),
+            crate::from_ndarray(target.mapv(f32::ln)),
+            true,
+            Reduction::Mean,
+        );
+        loss.forward();
+        loss.backward(1.);
+        assert!((loss.data()[[]] - 0.1530).abs() < 1e-4);
+        assert!(input
+            .grad()
+            .iter()
+            .zip(target.iter())
+            .all(|(grad, target)| (grad + target / 2.).abs() < 1e-6));
+    }
+
+    #[test]
+    fn kldiv_loss_asymmetric() {
+        let p = ndarray::array![[0.2_f32, 0.5, 0.3], [0.6, 0.1, 0.3]];
+        let q = ndarray::array![[0.4_f32, 0.5, 0.1], [0.1, 0.1, 0.8]];
+
+        let p_q = kldiv_loss(
+            crate::from_ndarray(q.mapv(f32::ln)).requires_grad(),
+            crate::from_ndarray(p.clone()),
+            false,
+            Reduction::Sum,
+        );
+        p_q.forward();
+        let q_p = kldiv_loss(
+            crate::from_ndarray(p.mapv(f32::ln)).requires_grad(),
+            crate::from_ndarray(q),
+            false,
+            Reduction::Sum,
+        );
+        q_p.forward();
+
+        assert!((p_q.data()[[]] - q_p.data()[[]]).abs() > 1e-2);
+    }
+
+    #[test]
+    fn kldiv_loss_from_logits_log_softmax() {
+        let logits = ndarray::array![[1_f32, 2., 0.5], [0.3, -1., 2.]];
+        let target = ndarray::array![[0.2_f32, 0.5, 0.3], [0.6, 0.1, 0.3]];
+        for reduction in [Reduction::Sum, Reduction::Mean] {
+            let input = crate::from_ndarray(logits.clone()).requires_grad();
+            let loss = kldiv_loss_from_logits(
+                input.clone(),
+                crate::from_ndarray(target.clone()),
+                1,
+                false,
+                reduction.clone(),
+            );
+            loss.forward();
+            loss.backward(1.);
+
+            let other = crate::from_ndarray(logits.clone()).requires_grad();
+            let expected = kldiv_loss(
+                other.clone().log_softmax(1),
+                crate::from_ndarray(target.clone()),
+                false,
+                reduction.clone(),
+            );
+            expected.forward();
+            expected.backward(1.);
+
+            assert_eq!(loss.data()[[]], expected.data()[[]]);
+            assert_eq!(*input.grad(), *other.grad());
+
+            // Each row of the target sums to one, so the gradient is the softmax minus the target.
+            let softmax = logits.mapv(f32::exp)
+                / logits.mapv(f32::exp).sum_axis(Axis(1)).insert_axis(Axis(1));
+            let scale = match reduction {
+                Reduction::None | Reduction::Sum => 1.,
+                Reduction::Mean => 0.5,
+            };
+            assert_close(&input.grad(), &((softmax - &target) * scale));
+        }
+    }
+
+    #[test]
+    fn jsdiv_loss_symmetric() {
+        let p = ndarray::array![[0.2_f32, 0.5, 0.3], [0.6, 0., 0.4]];
+        let q = ndarray::array![[0.4_f32, 0.5, 0.1], [0., 0.5, 0.5]];
+
+        let forward = jsdiv_loss(
+            crate::from_ndarray(q.mapv(f32::ln)).requires_grad(),
+            crate::from_ndarray(p.clone()),
+            Reduction::Sum,
+        );
+        forward.forward();
+
+        let backward = jsdiv_loss(
+            crate::from_ndarray(p.mapv(f32::ln)).requires_grad(),
+            crate::from_ndarray(q),
+            Reduction::Sum,
+        );
+        backward.forward();
+
+        assert!((forward.data()[[]] - 0.4272).abs() < 1e-4);
+        assert!((forward.data()[[]] - backward.data()[[]]).abs() < 1e-6);
+    }
+
+    #[test]
+    fn nll_loss_label_smoothing() {
+        let logits = array![[0.2, -1., 0.5], [1.5, 0.3, -0.4]];
+        let target = crate::from_ndarray(array![2., 0.]);
+        let weight = [1., 2., 0.5];
+
+        // Without smoothing the loss is unchanged, bit for bit.
+        let input = crate::from_ndarray(logits.clone()).requires_grad();
+        let log_probs = input.log_softmax(1);
+        let loss = nll_loss(
+            log_probs.clone(),
+            target.clone(),
+            Some(&weight),
+            None,
+            0.,
+            Reduction::Mean,
+        );
+        loss.forward();
+        loss.backward(1.);
+
+        let expected = {
+            let log_probs = log_probs.data();
+            ((0. - log_probs[[0, 2]] * 0.5) - log_probs[[1, 0]] * 1.) / 1.5
+        };
+        assert_eq!(loss.data()[[]], expected);
+        assert_eq!(
+            *log_probs.grad(),
+            array![[0., 0., -0.5 / 1.5], [-1. / 1.5, 0., 0.]]
+        );
+
+        // The gradient wrt the logits is the softmax minus the smoothed target.
+        let input = crate::from_ndarray(logits.clone()).requires_grad();
+        let loss = nll_loss(
+            input.clone().log_softmax(1),
+            target.clone(),
+            None,
+            None,
+            0.1,
+            Reduction::Mean,
+        );
+        loss.forward();
+        loss.backward(1.);
+
+        assert!((loss.data()[[]] - 0.646023).abs() < 1e-5);
+        assert_close(
+            &input.grad(),
+            &array![
+                [0.163604, 0.031807, -0.195411],
+                [-0.105354, 0.078805, 0.026548]
+            ],
+        );
+
+        // The smoothed target of each sample is weighted by the weight of its class.
+        let loss = nll_loss(
+            crate::from_ndarray(logits).requires_grad().log_softmax(1),
+            target,
+            Some(&weight),
+            Some(0),
+            0.1,
+            Reduction::Sum,
+        );
+        loss.forward();
+        assert!((loss.data()[[]] - 0.382478).abs() < 1e-5);
+    }
+
+    #[test]
+    fn focal_loss_cross_entropy() {
+        // Without focusing the focal loss is the cross entropy.
+        let logits = array![[1., 2., 0.5], [0.3, -1., 2.], [-0.5, 0.2, 0.1]];
+        for reduction in [Reduction::Sum, Reduction::Mean] {
+            let input = crate::from_ndarray(logits.clone()).requires_grad();
+            let target = crate::from_ndarray(array![1., 0., 2.]);
+            let focal = focal_loss(
+                input.clone(),
+                target.clone(),
+                FocalAlpha::Scalar(1.),
+                0.,
+                reduction.clone(),
+            );
+            focal.forward();
+            focal.backward(1.);
+
+            let other = crate::from_ndarray(logits.clone()).requires_grad();
+            let cross_entropy = nll_loss(
+                other.clone().log_softmax(1),
+                target,
+                None,
+                None,
+                0.,
+                reduction,
+            );
+            cross_entropy.forward();
+            cross_entropy.backward(1.);
+
+            assert_eq!(focal.data()[[]], cross_entropy.data()[[]]);
+            assert_close(&input.grad(), &other.grad());
+        }
+    }
+
+    #[test]
+    fn focal_loss_focusing() {
+        let input = crate::from_ndarray(array![[1., 2., 0.5], [0.3, -1., 2.]]).requires_grad();
+        let target = crate::from_ndarray(array![1., 0.]);
+        let loss = focal_loss(
+            input.clone(),
+            target,
+            FocalAlpha::PerClass(&[0.25, 0.5, 0.75]),
+            2.,
+            Reduction::Sum,
+        );
+        loss.forward();
+        assert!((loss.data()[[]] - 0.378297).abs() < 1e-5);
+    }
+
+    #[test]
+    fn focal_loss_confident() {
+        // A confidently correct sample weighs much less than in the cross entropy.
+        let logits = array![[6., 0., 0.], [0., 0., 6.]];
+        let target = crate::from_ndarray(array![0., 2.]);
+        let focal = focal_loss(
+            crate::from_ndarray(logits.clone()).requires_grad(),
+            target.clone(),
+            FocalAlpha::Scalar(1.),
+            2.,
+            Reduction::Mean,
+        );
+        focal.forward();
+        let cross_entropy = focal_loss(
+            crate::from_ndarray(logits).requires_grad(),
+            target,
+            FocalAlpha::Scalar(1.),
+            0.,
+            Reduction::Mean,
+        );
+        cross_entropy.forward();
+
+        assert!(focal.data()[[]] < 1e-6);
+        assert!(focal.data()[[]] < cross_entropy.data()[[]] * 1e-3);
+    }
+
+    #[test]
+    fn focal_loss_gradient() {
+        let logits = array![[1., 2., 0.5], [0.3, -1., 2.], [-0.5, 0.2, 0.1]];
+        let alpha = [0.25, 0.5, 0.75];
+        for reduction in [Reduction::Sum, Reduction::Mean] {
+            let input = crate::from_ndarray(logits.clone()).requires_grad();
+            let target = crate::from_ndarray(array![1., 0., 2.]);
+            let loss = focal_loss(
+                input.clone(),
+                target,
+                FocalAlpha::PerClass(&alpha),
+                2.,
+                reduction,
+            );
+            check_gradient(&input, &loss);
+        }
+    }
+
+    #[test]
+    fn sparse_cross_entropy_loss_dense() {
+        let logits = Array::from_shape_vec(
+            (8, 5),
+            (0..40).map(|el| ((el * 7 % 11) as f32 - 5.) / 2.).collect(),
+        )
+        .unwrap();
+        let target = [0, 4, 2, 1, 3, 3, 0, 2];
+        for reduction in [Reduction::Sum, Reduction::Mean] {
+            let input = crate::from_ndarray(logits.clone()).requires_grad();
+            let sparse = sparse_cross_entropy_loss(input.clone(), &target, reduction.clone());
+            sparse.forward();
+            sparse.backward(1.);
+
+            let other = crate::from_ndarray(logits.clone()).requires_grad();
+            let dense = nll_loss(
+                other.clone().log_softmax(1),
+                crate::from_ndarray(Array::from_iter(target.iter().map(|el| *el as f32))),
+                None,
+                None,
+                0.,
+                reduction,
+            );
+            dense.forward();
+            dense.backward(1.);
+
+            assert!((sparse.data()[[]] - dense.data()[[]]).abs() < 1e-5);
+            assert_eq!(input.grad().shape(), &[8, 5]);
+            assert_close(&input.grad(), &other.grad());
+        }
+    }
+
+    #[test]
+    fn ctc_loss_gradient() {
+        // Log-probabilities of shape (4, 2, 3), the second sequence lasts three steps.
+        let logits = Array::from_shape_vec(
+            (4, 2, 3),
+            vec![
+                0.2, -0.4, 0.9, -1.1, 0.5, 0.3, 0.7, 0.1, -0.6, 0.4, -0.2, 1.2, -0.3, 0.8, 0.6,
+                0.9, -0.7, 0.1, 1.0, 0.2, -0.5, 0.3, 0.3, 0.3,
+            ],
+        )
+        .unwrap();
+        let log_probs = &logits
+            - &logits
+                .mapv(f32::exp)
+                .sum_axis(Axis(2))
+                .mapv(f32::ln)
+                .insert_axis(Axis(2));
+        let targets = array![[1., 1.], [2., 0.]];
+        for reduction in [Reduction::Sum, Reduction::Mean] {
+            let input = crate::from_ndarray(log_probs.clone()).requires_grad();
+            let loss = ctc_loss(
+                input.clone(),
+                crate::from_ndarray(targets.clone()),
+                &[4, 3],
+                &[2, 1],
+                0,
+                reduction.clone(),
+            );
+            check_gradient(&input, &loss);
+
+            // The time step after the end of the second sequence must receive no gradient.
+            assert!(input
+                .grad()
+                .index_axis(Axis(0), 3)
+                .row(1)
+                .iter()
+                .all(|el| *el == 0.));
+        }
+    }
+
+    #[test]
+    fn hinge_loss_reductions() {
+        let input = crate::from_ndarray(array![[2., 1., 0.5], [-1., 0.3, -2.]]).requires_grad();
+        let target = crate::from_ndarray(array![[1., 1., 1.], [-1., -1., 1.]]);
+
+        let unreduced = hinge_loss(input.clone(), target.clone(), Reduction::None);
+        unreduced.forward();
+        assert_close(
+            &unreduced.data().clone().into_dimensionality().unwrap(),
+            &array![[0., 0., 0.5], [0., 1.3, 3.]],
+        );
+
+        let sum = hinge_loss(input.clone(), target.clone(), Reduction::Sum);
+        sum.forward();
+        assert!((sum.data()[[]] - 4.8).abs() < 1e-6);
+
+        let mean = hinge_loss(input, target, Reduction::Mean);
+        mean.forward();
+        assert!((mean.data()[[]] - 0.8).abs() < 1e-6);
+    }
+
+    #[test]
+    fn multi_margin_loss_gradient() {
+        let logits = array![[0.1, 0.2, 0.4], [0.8, 0.3, -0.2], [-0.5, 1.5, 0.9]];
+        for p in [1, 2] {
+            for reduction in [Reduction::Sum, Reduction::Mean] {
+                let input = crate::from_ndarray(logits.clone()).requires_grad();
+                let target = crate::from_ndarray(array![2., 0., 1.]);
+                let loss = multi_margin_loss(input.clone(), target, 0.8, p, reduction);
+                check_gradient(&input, &loss);
+            }
+        }
+    }
+
+    #[test]
+    fn cosine_embedding_loss_margin() {
+        // The first pair is dissimilar and already below the margin, the second one is not.
+        let x1 = crate::from_ndarray(array![[1., 0.], [0., 1.]]).requires_grad();
+        let x2 = crate::from_ndarray(array![[-1., 0.], [1., 1.]]).requires_grad();
+        let target = crate::from_ndarray(array![[-1.], [-1.]]);
+
+        let loss = cosine_embedding_loss(x1.clone(), x2.clone(), target, 0.5, Reduction::Sum);
+        loss.forward();
+        loss.backward(1.);
+
+        assert!((loss.data()[[]] - (0.5_f32.sqrt() - 0.5)).abs() < 1e-6);
+        assert_eq!(x1.grad().row(0), array![0., 0.]);
+        assert_eq!(x2.grad().row(0), array![0., 0.]);
+        assert!(x1.grad().row(1).iter().any(|el| el.abs() > 0.));
+        assert!(x2.grad().row(1).iter().any(|el| el.abs() > 0.));
+    }
+
+    #[test]
+    fn cosine_embedding_loss_gradient() {
+        let first = array![[1., 2., -1.], [0.5, -1., 2.], [3., 1., 1.]];
+        let second = array![[2., -1., 0.5], [1., 1., 1.], [-2., 0.5, 1.]];
+        let target = array![[1.], [-1.], [1.]];
+
+        let x1 = crate::from_ndarray(first).requires_grad();
+        let x2 = crate::from_ndarray(second).requires_grad();
+        let output = cosine_embedding_loss(
+            x1.clone(),
+            x2.clone(),
+            crate::from_ndarray(target),
+            -0.5,
+            Reduction::Mean,
+        );
+        check_gradient(&x1, &output);
+        check_gradient(&x2, &output);
+    }
+
+    #[test]
+    fn cosine_embedding_loss_zero_embedding() {
+        let x1 = crate::from_ndarray(array![[0., 0.]]).requires_grad();
+        let x2 = crate::from_ndarray(array![[1., 1.]]).requires_grad();
+        let target = crate::from_ndarray(array![[1.]]);
+
+        let loss = cosine_embedding_loss(x1.clone(), x2.clone(), target, 0., Reduction::Mean);
+        loss.forward();
+        loss.backward(1.);
+
+        assert!((loss.data()[[]] - 1.).abs() < 1e-6);
+        assert!(x1
+            .grad()
+            .iter()
+            .chain(x2.grad().iter())
+            .all(|el| el.is_finite()));
+    }
+
+    #[test]
+    fn triplet_margin_loss_margin() {
+        // The negative example of the first triplet is already far enough from the anchor.
+        let anchor = crate::from_ndarray(array![[0., 0.], [0., 0.]]).requires_grad();
+        let positive = crate::from_ndarray(array![[1., 0.], [1., 0.]]).requires_grad();
+        let negative = crate::from_ndarray(array![[3., 0.], [0., 1.5]]).requires_grad();
+
+        let loss = triplet_margin_loss(
+            anchor.clone(),
+            positive.clone(),
+            negative.clone(),
+            1.,
+            2.,
+            Reduction::Sum,
+        );
+        loss.forward();
+        loss.backward(1.);
+
+        assert!((loss.data()[[]] - 0.5).abs() < 1e-4);
+        for grad in [anchor.grad(), positive.grad(), negative.grad()] {
+            assert_eq!(grad.row(0), array![0., 0.]);
+            assert!(grad.row(1).iter().any(|el| el.abs() > 0.));
+        }
+    }
+
+    #[test]
+    fn triplet_margin_loss_gradient() {
+        let anchor = array![[1., 2., -1.], [0.5, -1., 2.]];
+        let positive = array![[1.5, 1., -0.5], [1., -1., 1.]];
+        let negative = array![[2., 2.5, -1.5], [0., 0., 2.5]];
+
+        let a = crate::from_ndarray(anchor).requires_grad();
+        let p = crate::from_ndarray(positive).requires_grad();
+        let n = crate::from_ndarray(negative).requires_grad();
+        let output = triplet_margin_loss(a.clone(), p.clone(), n.clone(), 2., 2., Reduction::Mean);
+        check_gradient(&a, &output);
+        check_gradient(&p, &output);
+        check_gradient(&n, &output);
+    }
+}
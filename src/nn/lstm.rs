@@ -0,0 +1,339 @@
+use super::{
+    rnn::{initial_states, stack_steps, InitialState, RecurrentInput, RecurrentLayer, State, Step},
+    Register,
+};
+use crate::variable::{AnyVarDiff, Data, Gradient, RawParam, VarDiff};
+use ndarray::Ix3;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{cell::Cell, rc::Rc};
+
+/// A multi-layer **long short-term memory (LSTM)** network.
+///
+/// For each time step *t* each layer computes
+///
+/// ```text
+/// iₜ = σ(Wᵢᵢxₜ + bᵢᵢ + Wₕᵢhₜ₋₁ + bₕᵢ)
+/// fₜ = σ(Wᵢ𝒻xₜ + bᵢ𝒻 + Wₕ𝒻hₜ₋₁ + bₕ𝒻)
+/// gₜ = tanh(Wᵢɢxₜ + bᵢɢ + Wₕɢhₜ₋₁ + bₕɢ)
+/// oₜ = σ(Wᵢₒxₜ + bᵢₒ + Wₕₒhₜ₋₁ + bₕₒ)
+/// cₜ = fₜ * cₜ₋₁ + iₜ * gₜ
+/// hₜ = oₜ * tanh(cₜ)
+/// ```
+///
+/// where *xₜ* is the input of the first layer or the output of the previous layer and *h₀* and
+/// *c₀* are the initial states of the layer. The weights of the four gates are stacked, in this
+/// order, as in [`LSTMCell`](super::LSTMCell), so that each step takes a single matrix
+/// multiplication per weight. The computational graph is unrolled over the whole sequence at each
+/// call of [`.forward()`](LSTM::forward()).
+///
+/// # Examples
+///
+/// ```
+/// use neuronika::nn::LSTM;
+///
+/// // Sequences of 6 steps, in batches of 4, with 5 features each.
+/// let lstm = LSTM::new(5, 3, 2, true);
+/// let h0 = neuronika::zeros((2, 4, 3));
+/// let c0 = neuronika::zeros((2, 4, 3)).requires_grad();
+/// let (output, (hidden, cell_state)) =
+///     lstm.forward(neuronika::rand((6, 4, 5)), Some((h0.into(), c0.into())));
+///
+/// output.forward();
+/// hidden.forward();
+/// cell_state.forward();
+/// assert_eq!(output.data().shape(), &[6, 4, 3]);
+/// assert_eq!(hidden.data().shape(), &[2, 4, 3]);
+/// assert_eq!(cell_state.data().shape(), &[2, 4, 3]);
+/// ```
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[allow(clippy::upper_case_acronyms)]
+pub struct LSTM {
+    pub layers: Vec<RecurrentLayer>,
+    hidden_size: usize,
+}
+
+impl LSTM {
+    /// Creates a new LSTM.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_size` - number of expected features in the input.
+    ///
+    /// * `hidden_size` - number of features in the hidden state.
+    ///
+    /// * `num_layers` - number of stacked layers, each one fed with the output of the previous
+    /// one.
+    ///
+    /// * `bias` - whether the layers have biases.
+    ///
+    /// All the weight and biases are initialized from *U(-k, k)* where
+    /// `k = (1. / hidden_size as f32).sqrt()`.
+    ///
+    /// # Panics
+    ///
+    /// If `num_layers` is zero.
+    pub fn new(input_size: usize, hidden_size: usize, num_layers: usize, bias: bool) -> Self {
+        assert!(
+            num_layers > 0,
+            "error: the number of layers must be positive."
+        );
+
+        let layers = (0..num_layers)
+            .map(|layer| {
+                let input_size = if layer == 0 { input_size } else { hidden_size };
+                RecurrentLayer::new(input_size, hidden_size, 4, bias)
+            })
+            .collect();
+
+        Self {
+            layers,
+            hidden_size,
+        }
+    }
+
+    /// Runs the network over a sequence.
+    ///
+    /// * `input` - a variable of shape *(seq, batch, input_size)*.
+    ///
+    /// * `state` - an optional tuple of variables, both of shape
+    /// *(num_layers, batch, hidden_size)*, holding the initial hidden state and the initial
+    /// cell's state of each layer. Each of them can be either differentiable or not. Null states
+    /// are used when it's `None`.
+    ///
+    /// Returns the output of the last layer at each time step, of shape
+    /// *(seq, batch, hidden_size)*, and a tuple with the final hidden state and the final cell's
+    /// state of each layer, both of shape *(num_layers, batch, hidden_size)*.
+    ///
+    /// # Panics
+    ///
+    /// If the input sequence is empty or the initial states have the wrong shape.
+    #[allow(clippy::type_complexity)]
+    pub fn forward<I: RecurrentInput>(
+        &self,
+        input: I,
+        state: Option<(InitialState, InitialState)>,
+    ) -> (
+        VarDiff<impl Data<Dim = Ix3>, impl Gradient<Dim = Ix3>>,
+        (
+            VarDiff<impl Data<Dim = Ix3>, impl Gradient<Dim = Ix3>>,
+            VarDiff<impl Data<Dim = Ix3>, impl Gradient<Dim = Ix3>>,
+        ),
+    ) {
+        let num_layers = self.layers.len();
+        let (hidden, cell_state) = state.unzip();
+        let initial_states = initial_states(hidden, num_layers, self.hidden_size)
+            .into_iter()
+            .zip(initial_states(cell_state, num_layers, self.hidden_size));
+
+        let (mut input, mut steps) = (Some(input), Vec::new());
+        let mut final_hidden = Vec::with_capacity(num_layers);
+        let mut final_cell_states = Vec::with_capacity(num_layers);
+        for (layer, (mut hidden, mut cell_state)) in self.layers.iter().zip(initial_states) {
+            // Each layer is fed with the output of the previous one.
+            let gates = match input.take() {
+                Some(input) => layer.input_gates(input),
+                None => layer.input_gates(steps),
+            };
+
+            steps = gates
+                .into_iter()
+                .map(|gates| {
+                    let (next_hidden, next_cell_state) =
+                        self.step(layer, gates, hidden.take(), cell_state.take());
+                    hidden = Some(State::Differentiable(next_hidden.clone()));
+                    cell_state = Some(State::Differentiable(next_cell_state));
+                    next_hidden
+                })
+                .collect();
+            final_hidden.extend(steps.last().cloned());
+            if let Some(State::Differentiable(cell_state)) = cell_state {
+                final_cell_states.push(cell_state);
+            }
+        }
+
+        (
+            stack_steps(&steps),
+            (stack_steps(&final_hidden), stack_steps(&final_cell_states)),
+        )
+    }
+
+    /// Computes the next hidden state and the next cell's state of `layer` given the input
+    /// contribution to its gates and its current states.
+    fn step(
+        &self,
+        layer: &RecurrentLayer,
+        gates: Step,
+        hidden: Option<State>,
+        cell_state: Option<State>,
+    ) -> (Step, Step) {
+        // The four gates are computed by a single matrix multiplication and then split.
+        let gates = match layer.hidden_gates(hidden.as_ref()) {
+            Some(hidden_gates) => (gates + hidden_gates).into_dyn(),
+            None => gates,
+        };
+        let rows = gates.data().nrows();
+        let gates = gates.chunks((rows, self.hidden_size));
+        let (input_gate, forget_gate, cell_state_gate, output_gate) = (
+            gates[0].clone().sigmoid(),
+            gates[1].clone().sigmoid(),
+            gates[2].clone().tanh(),
+            gates[3].clone().sigmoid(),
+        );
+
+        // A missing cell's state is a null one, so the forget gate has no effect.
+        let next_cell_state = (input_gate * cell_state_gate).into_dyn();
+        let next_cell_state = match cell_state {
+            Some(cell_state) => {
+                (cell_state.mul(forget_gate.into_dyn()) + next_cell_state).into_dyn()
+            }
+            None => next_cell_state,
+        };
+        let next_hidden = (output_gate * next_cell_state.clone().tanh()).into_dyn();
+
+        (next_hidden, next_cell_state)
+    }
+
+    /// Returns the weights and the biases of this `LSTM` instance, paired with their names.
+    ///
+    /// The parameters of the `l`-th layer are suffixed by `_l{l}`, e.g. `weight_ih_l0`.
+    pub fn named_parameters(&self) -> Vec<(String, AnyVarDiff)> {
+        self.layers
+            .iter()
+            .enumerate()
+            .flat_map(|(index, layer)| layer.named_parameters(index))
+            .collect()
+    }
+}
+
+impl Register for LSTM {
+    /// Registers the weights and the biases of this `LSTM` instance.
+    fn register_params(&self, params: &mut Vec<RawParam>) {
+        self.layers
+            .iter()
+            .for_each(|layer| layer.register_params(params));
+    }
+
+    fn register_status(&mut self, _: Rc<Cell<bool>>) {}
+}
+
+#[cfg(test)]
+mod test {
+    use super::LSTM;
+    use crate::variable::check_gradient;
+    use ndarray::{Array, Array2, Axis};
+
+    fn sigmoid(x: f32) -> f32 {
+        1. / (1. + (-x).exp())
+    }
+
+    #[test]
+    fn creation() {
+        let lstm = LSTM::new(5, 3, 2, true);
+
+        assert_eq!(lstm.layers.len(), 2);
+        assert_eq!(lstm.layers[0].weight_ih.data().shape(), &[12, 5]);
+        assert_eq!(lstm.layers[1].weight_ih.data().shape(), &[12, 3]);
+        assert_eq!(lstm.layers[1].weight_hh.data().shape(), &[12, 3]);
+        assert_eq!(lstm.named_parameters().len(), 8);
+
+        let lstm = LSTM::new(5, 3, 2, false);
+        assert_eq!(lstm.named_parameters().len(), 4);
+    }
+
+    #[test]
+    fn single_step() {
+        let lstm = LSTM::new(4, 3, 1, true);
+        let input = crate::rand((1, 2, 4));
+        let hidden = crate::rand((1, 2, 3));
+        let cell_state = crate::rand((1, 2, 3)).requires_grad();
+
+        let (output, (final_hidden, final_cell_state)) = lstm.forward(
+            input.clone(),
+            Some((hidden.clone().into(), cell_state.clone().into())),
+        );
+        output.forward();
+        final_hidden.forward();
+        final_cell_state.forward();
+
+        let layer = &lstm.layers[0];
+        let gates = input
+            .data()
+            .index_axis(Axis(0), 0)
+            .dot(&layer.weight_ih.data().t())
+            + hidden
+                .data()
+                .index_axis(Axis(0), 0)
+                .dot(&layer.weight_hh.data().t())
+            + &*layer.bias_ih.as_ref().unwrap().data()
+            + &*layer.bias_hh.as_ref().unwrap().data();
+        let gate = |index: usize| gates.slice(ndarray::s![.., index * 3..(index + 1) * 3]);
+        let expected_cell_state = gate(1).mapv(sigmoid) * cell_state.data().index_axis(Axis(0), 0)
+            + gate(0).mapv(sigmoid) * gate(2).mapv(f32::tanh);
+        let expected_hidden = gate(3).mapv(sigmoid) * expected_cell_state.mapv(f32::tanh);
+
+        let close = |actual: &Array<f32, _>, expected: &Array2<f32>| {
+            actual
+                .iter()
+                .zip(expected.iter())
+                .all(|(actual, expected)| (actual - expected).abs() <= 1e-6)
+        };
+        assert_eq!(*output.data(), *final_hidden.data());
+        assert!(close(&*output.data(), &expected_hidden));
+        assert!(close(&*final_cell_state.data(), &expected_cell_state));
+
+        // Missing initial states are null ones.
+        let (output, (_, final_cell_state)) = lstm.forward(input.clone(), None);
+        let (expected, (_, expected_cell_state)) = lstm.forward(
+            input,
+            Some((
+                crate::zeros((1, 2, 3)).into(),
+                crate::zeros((1, 2, 3)).into(),
+            )),
+        );
+        output.forward();
+        final_cell_state.forward();
+        expected.forward();
+        expected_cell_state.forward();
+        assert_eq!(*output.data(), *expected.data());
+        assert_eq!(*final_cell_state.data(), *expected_cell_state.data());
+    }
+
+    #[test]
+    fn multi_layer() {
+        let lstm = LSTM::new(4, 3, 2, false);
+        let (output, (hidden, cell_state)) = lstm.forward(crate::rand((5, 2, 4)), None);
+        output.forward();
+        hidden.forward();
+        cell_state.forward();
+
+        assert_eq!(output.data().shape(), &[5, 2, 3]);
+        assert_eq!(hidden.data().shape(), &[2, 2, 3]);
+        assert_eq!(cell_state.data().shape(), &[2, 2, 3]);
+        // The final hidden state of the last layer is its output at the last step.
+        assert_eq!(
+            output.data().index_axis(Axis(0), 4),
+            hidden.data().index_axis(Axis(0), 1)
+        );
+    }
+
+    #[test]
+    fn gradient() {
+        // The gradients through 10 steps are checked against the finite differences.
+        let lstm = LSTM::new(2, 2, 1, true);
+        let input = crate::rand((10, 1, 2)) * 2. - 1.;
+        let hidden = (crate::rand((1, 1, 2)) * 2. - 1.).requires_grad();
+        let cell_state = (crate::rand((1, 1, 2)) * 2. - 1.).requires_grad();
+        let (output, (_, final_cell_state)) = lstm.forward(
+            input,
+            Some((hidden.clone().into(), cell_state.clone().into())),
+        );
+        let loss = output.sum() + final_cell_state.sum();
+        let layer = &lstm.layers[0];
+        check_gradient(&layer.weight_ih, &loss);
+        check_gradient(&layer.weight_hh, &loss);
+        check_gradient(layer.bias_hh.as_ref().unwrap(), &loss);
+        check_gradient(&hidden, &loss);
+        check_gradient(&cell_state, &loss);
+    }
+}
@@ -0,0 +1,267 @@
+use super::{init, named, Learnable, Register};
+use crate::variable::{AnyVarDiff, Data, Gradient, Input, MatMatMulT, RawParam, Tensor, VarDiff};
+use ndarray::{Ix1, Ix2};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{cell::Cell, rc::Rc};
+
+/// A **long short-term memory (LSTM)** cell.
+///
+/// Given the input *x*, the hidden state *h* and the cell's state *c*, a single step computes
+///
+/// ```text
+/// i = σ(Wᵢᵢx + bᵢᵢ + Wₕᵢh + bₕᵢ)
+/// f = σ(Wᵢ𝒻x + bᵢ𝒻 + Wₕ𝒻h + bₕ𝒻)
+/// g = tanh(Wᵢɢx + bᵢɢ + Wₕɢh + bₕɢ)
+/// o = σ(Wᵢₒx + bᵢₒ + Wₕₒh + bₕₒ)
+/// c' = f * c + i * g
+/// h' = o * tanh(c')
+/// ```
+///
+/// where the weights of the four gates are stacked, in this order, in `weight_ih` and
+/// `weight_hh`. The computational graph is built anew at each call of
+/// [`.forward()`](LSTMCell::forward()), so that the cell can be unrolled over a sequence.
+///
+/// # Examples
+///
+/// ```
+/// use neuronika::nn::LSTMCell;
+///
+/// // A batch of 4 inputs with 5 features each.
+/// let lstm = LSTMCell::new(5, 3);
+/// let (cell_state, hidden) = (
+///     neuronika::zeros((4, 3)).requires_grad(),
+///     neuronika::zeros((4, 3)).requires_grad(),
+/// );
+///
+/// let (cell_state, hidden) = lstm.forward((cell_state, hidden), neuronika::rand((4, 5)));
+/// hidden.forward();
+/// assert_eq!(hidden.data().shape(), &[4, 3]);
+/// assert_eq!(cell_state.data().shape(), &[4, 3]);
+/// ```
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[allow(clippy::upper_case_acronyms)]
+pub struct LSTMCell {
+    pub weight_ih: Learnable<Ix2>,
+    pub weight_hh: Learnable<Ix2>,
+    pub bias_ih: Learnable<Ix1>,
+    pub bias_hh: Learnable<Ix1>,
+}
+
+impl LSTMCell {
+    /// Creates a new LSTMCell.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_size` - number of expected features in the input.
+    ///
+    /// * `hidden_size` - number of features in the hidden state.
+    ///
+    /// All the weight and biases are initialized from *U(-k, k)* where
+    /// `k = (1. / hidden_size as f32).sqrt()`.
+    pub fn new(input_size: usize, hidden_size: usize) -> Self {
+        let (weight_ih_shape, weight_hh_shape, bias_shape) = {
+            let xhidden_size = 4 * hidden_size;
+            (
+                (xhidden_size, input_size),
+                (xhidden_size, hidden_size),
+                xhidden_size,
+            )
+        };
+        let weight_ih = Input::new(Tensor::zeros(weight_ih_shape)).requires_grad();
+        let weight_hh = Input::new(Tensor::zeros(weight_hh_shape)).requires_grad();
+        let bias_ih = Input::new(Tensor::zeros(bias_shape)).requires_grad();
+        let bias_hh = Input::new(Tensor::zeros(bias_shape)).requires_grad();
+
+        let k = 1. / (hidden_size as f32).sqrt();
+        init::uniform(&weight_ih, -k, k);
+        init::uniform(&weight_hh, -k, k);
+        init::uniform(&bias_ih, -k, k);
+        init::uniform(&bias_hh, -k, k);
+
+        Self {
+            weight_ih,
+            weight_hh,
+            bias_ih,
+            bias_hh,
+        }
+    }
+
+    /// Computes a single **LSTM step**.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - a tuple of tensors, both of shape *(batch, hidden_size)*, containing the
+    /// initial hidden state for each element in the batch and the initial cell's state for
+    /// each element in the batch.
+    ///
+    /// * `input` - a variable containing the input features of shape *(batch, input_size)*.
+    ///
+    /// The **output** is a tuple of tensors made of the next hidden state for each element in
+    /// the batch, of shape *(batch, hidden_size)* and the next cell's state for each element in
+    /// the batch, of shape *(batch, hidden_size)*.
+    pub fn forward<Cf: ?Sized, Cb: ?Sized, Hf: ?Sized, Hb: ?Sized, I, T, U>(
+        &self,
+        state: (VarDiff<Cf, Cb>, VarDiff<Hf, Hb>),
+        input: I,
+    ) -> (
+        VarDiff<impl Data<Dim = Ix2>, impl Gradient<Dim = Ix2>>,
+        VarDiff<impl Data<Dim = Ix2>, impl Gradient<Dim = Ix2>>,
+    )
+    where
+        Cf: Data<Dim = Ix2>,
+        Cb: Gradient<Dim = Ix2>,
+        Hf: Data<Dim = Ix2>,
+        Hb: Gradient<Dim = Ix2>,
+        I: MatMatMulT<Learnable<Ix2>>,
+        I::Output: Into<VarDiff<T, U>>,
+        T: Data<Dim = Ix2> + 'static,
+        U: Gradient<Dim = Ix2> + 'static,
+    {
+        let (cell_state, hidden) = state;
+        let gates = hidden.mm_t(self.weight_hh.clone())
+            + self.bias_hh.clone()
+            + input.mm_t(self.weight_ih.clone()).into()
+            + self.bias_ih.clone();
+        let gate_shape = {
+            let (gates_shape_rows, gates_shape_cols) = gates.data().dim();
+            (gates_shape_rows, gates_shape_cols / 4)
+        };
+        let chunked_gates = gates.chunks(gate_shape);
+        let (input_gate, forget_gate, cell_state_gate, output_gate) = (
+            chunked_gates[0].clone().sigmoid(),
+            chunked_gates[1].clone().sigmoid(),
+            chunked_gates[2].clone().tanh(),
+            chunked_gates[3].clone().sigmoid(),
+        );
+        let new_cell_state = forget_gate * cell_state + (input_gate * cell_state_gate);
+        let new_hidden = output_gate * new_cell_state.clone().tanh();
+
+        (new_cell_state, new_hidden)
+    }
+
+    /// Returns the weights and the biases of this `LSTMCell` instance, paired with their names.
+    pub fn named_parameters(&self) -> Vec<(String, AnyVarDiff)> {
+        vec![
+            named("weight_ih", &self.weight_ih),
+            named("weight_hh", &self.weight_hh),
+            named("bias_ih", &self.bias_ih),
+            named("bias_hh", &self.bias_hh),
+        ]
+    }
+}
+
+impl Register for LSTMCell {
+    /// Registers the weights and the biases of this LSTMCell instance.
+    fn register_params(&self, params: &mut Vec<RawParam>) {
+        self.weight_hh.register_params(params);
+        self.weight_ih.register_params(params);
+        self.bias_hh.register_params(params);
+        self.bias_ih.register_params(params);
+    }
+
+    fn register_status(&mut self, _: Rc<Cell<bool>>) {}
+}
+
+#[cfg(test)]
+mod test {
+    use super::LSTMCell;
+    use crate::variable::{check_gradient, Data, Gradient, VarDiff};
+    use ndarray::{Array, Ix0, Ix2};
+
+    /// Returns the loss obtained by unrolling `lstm` over two steps, one for each input, starting
+    /// from a null state.
+    fn unrolled_loss(
+        lstm: &LSTMCell,
+        inputs: &[Array<f32, Ix2>; 2],
+    ) -> VarDiff<impl Data<Dim = Ix0>, impl Gradient<Dim = Ix0>> {
+        let state = (
+            crate::zeros((2, 3)).requires_grad(),
+            crate::zeros((2, 3)).requires_grad(),
+        );
+        let state = lstm.forward(state, crate::from_ndarray(inputs[0].clone()));
+        let (cell_state, hidden) = lstm.forward(state, crate::from_ndarray(inputs[1].clone()));
+
+        (cell_state + hidden * 2.).sum()
+    }
+
+    #[test]
+    fn creation() {
+        let lstm = LSTMCell::new(5, 3);
+
+        assert_eq!(lstm.weight_ih.data().shape(), &[12, 5]);
+        assert_eq!(lstm.weight_hh.data().shape(), &[12, 3]);
+        assert_eq!(lstm.bias_ih.data().shape(), &[12]);
+        assert_eq!(lstm.bias_hh.data().shape(), &[12]);
+        assert_eq!(lstm.named_parameters().len(), 4);
+    }
+
+    #[test]
+    fn forward() {
+        let lstm = LSTMCell::new(5, 3);
+        let (cell_state, hidden) = (
+            crate::full((4, 3), 10.).requires_grad(),
+            crate::zeros((4, 3)).requires_grad(),
+        );
+
+        let (new_cell_state, new_hidden) =
+            lstm.forward((cell_state, hidden), crate::rand((4, 5)) * 4.);
+        new_hidden.forward();
+        assert_eq!(new_cell_state.data().shape(), &[4, 3]);
+        assert_eq!(new_hidden.data().shape(), &[4, 3]);
+
+        // The forget and the input gates are in (0, 1) and the candidate cell's state is in
+        // (-1, 1), while the hidden state is bounded by the hyperbolic tangent of the cell's one.
+        assert!(new_cell_state.data().iter().all(|el| el.abs() <= 11.));
+        assert!(new_hidden
+            .data()
+            .iter()
+            .zip(new_cell_state.data().iter())
+            .all(|(hidden, cell_state)| hidden.abs() <= cell_state.tanh().abs()));
+    }
+
+    #[test]
+    fn forward_null_weights() {
+        let lstm = LSTMCell::new(2, 3);
+        for weight in [&lstm.weight_ih, &lstm.weight_hh] {
+            weight.data_mut().fill(0.);
+        }
+        for bias in [&lstm.bias_ih, &lstm.bias_hh] {
+            bias.data_mut().fill(0.);
+        }
+
+        // All the sigmoid gates are one half and the candidate cell's state is zero.
+        let cell_state = crate::full((2, 3), 2.).requires_grad();
+        let (new_cell_state, new_hidden) = lstm.forward(
+            (cell_state, crate::zeros((2, 3)).requires_grad()),
+            crate::rand((2, 2)),
+        );
+        new_hidden.forward();
+        assert!(new_cell_state
+            .data()
+            .iter()
+            .all(|el| (el - 1.).abs() <= f32::EPSILON));
+        assert!(new_hidden
+            .data()
+            .iter()
+            .all(|el| (el - 0.5 * 1_f32.tanh()).abs() <= f32::EPSILON));
+    }
+
+    #[test]
+    fn backward() {
+        let lstm = LSTMCell::new(2, 3);
+        let inputs = [
+            crate::rand((2, 2)).data().to_owned() * 2. - 1.,
+            crate::rand((2, 2)).data().to_owned() * 2. - 1.,
+        ];
+
+        // The null initial states are parameters as well.
+        let loss = unrolled_loss(&lstm, &inputs);
+        assert_eq!(loss.parameters().len(), 6);
+
+        check_gradient(&lstm.weight_ih, &loss);
+        check_gradient(&lstm.weight_hh, &loss);
+        check_gradient(&lstm.bias_ih, &loss);
+        check_gradient(&lstm.bias_hh, &loss);
+    }
+}
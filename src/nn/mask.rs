@@ -0,0 +1,258 @@
+use super::{Input, Var};
+use ndarray::{Array4, Axis, Ix4};
+
+/// How the validity of an output position is derived from the input positions in its window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaskMode {
+    /// An output position is valid if at least one of the positions in its window is valid.
+    AnyValid,
+    /// An output position is valid if all the positions in its window are valid.
+    AllValid,
+}
+
+/// Validity mask of a batch of padded 2-dimensional inputs.
+///
+/// The mask has shape *(N, 1, H, W)*, where *N* is the batch size and *H* and *W* are the height
+/// and the width of the padded inputs, a `true` entry marks a valid position. The same mask is
+/// shared by all the channels of a sample.
+///
+/// Masks are propagated through the layers that support them, see for instance
+/// [`Conv2d::forward_masked`](super::Conv2d::forward_masked),
+/// [`MaxPool2d::forward_masked`](super::MaxPool2d::forward_masked) and
+/// [`LayerNorm::forward_masked`](super::LayerNorm::forward_masked).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask2d {
+    data: Array4<bool>,
+}
+
+impl Mask2d {
+    /// Creates a mask from an array of shape *(N, 1, H, W)*.
+    ///
+    /// # Panics
+    ///
+    /// If the second axis of `data` has length different from one.
+    pub fn new(data: Array4<bool>) -> Self {
+        assert_eq!(
+            data.len_of(Axis(1)),
+            1,
+            "error: the mask must have a single channel."
+        );
+
+        Self { data }
+    }
+
+    /// Creates the mask of a batch of inputs padded to `shape` *(H, W)* on the bottom and on the
+    /// right.
+    ///
+    /// # Arguments
+    ///
+    /// `sizes` - true *(height, width)* of each sample of the batch.
+    ///
+    /// # Panics
+    ///
+    /// If any of the sizes exceeds `shape`.
+    pub fn from_sizes(sizes: &[(usize, usize)], shape: (usize, usize)) -> Self {
+        let (height, width) = shape;
+        assert!(
+            sizes.iter().all(|&(h, w)| h <= height && w <= width),
+            "error: sample size exceeds the padded shape."
+        );
+
+        Self::new(Array4::from_shape_fn(
+            (sizes.len(), 1, height, width),
+            |(n, _, i, j)| i < sizes[n].0 && j < sizes[n].1,
+        ))
+    }
+
+    /// Returns the underlying boolean array.
+    pub fn data(&self) -> &Array4<bool> {
+        &self.data
+    }
+
+    /// Computes the mask of the output of a sliding window operation, such as a convolution or a
+    /// pooling, applied to the inputs described by `self`.
+    ///
+    /// Positions falling in the zero padding added by the operation itself are ignored, so that
+    /// the edges of a sample are treated as they would be if the sample were processed alone.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel_size` - size of the window.
+    ///
+    /// * `padding` - padding added by the operation on both sides of each spatial axis.
+    ///
+    /// * `stride` - stride of the window.
+    ///
+    /// * `dilation` - spacing between the positions of the window.
+    ///
+    /// * `mode` - whether an output position needs any or all of its window to be valid.
+    pub fn downsample(
+        &self,
+        kernel_size: (usize, usize),
+        padding: (usize, usize),
+        stride: (usize, usize),
+        dilation: (usize, usize),
+        mode: MaskMode,
+    ) -> Self {
+        let (batch, _, height, width) = self.data.dim();
+        let out_len =
+            |len: usize, kernel: usize, padding: usize, stride: usize, dilation: usize| {
+                (len + 2 * padding - dilation * (kernel - 1) - 1) / stride + 1
+            };
+        let out_height = out_len(height, kernel_size.0, padding.0, stride.0, dilation.0);
+        let out_width = out_len(width, kernel_size.1, padding.1, stride.1, dilation.1);
+
+        let data = Array4::from_shape_fn((batch, 1, out_height, out_width), |(n, _, i, j)| {
+            let rows = (0..kernel_size.0)
+                .map(|k| (i * stride.0 + k * dilation.0).checked_sub(padding.0))
+                .filter_map(|row| row.filter(|&row| row < height));
+            let mut window = rows
+                .flat_map(|row| {
+                    (0..kernel_size.1)
+                        .map(|k| (j * stride.1 + k * dilation.1).checked_sub(padding.1))
+                        .filter_map(|col| col.filter(|&col| col < width))
+                        .map(move |col| self.data[[n, 0, row, col]])
+                })
+                .peekable();
+
+            match mode {
+                MaskMode::AnyValid => window.any(|valid| valid),
+                MaskMode::AllValid => window.peek().is_some() && window.all(|valid| valid),
+            }
+        });
+
+        Self { data }
+    }
+
+    /// Returns a variable of shape *(N, 1, H, W)* holding ones in the valid positions and zeros
+    /// elsewhere.
+    pub fn to_var(&self) -> Var<Input<Ix4>> {
+        self.to_var_with(1., 0.)
+    }
+
+    /// Returns a variable of shape *(N, 1, H, W)* holding `valid` in the valid positions and
+    /// `invalid` elsewhere.
+    pub(crate) fn to_var_with(&self, valid: f32, invalid: f32) -> Var<Input<Ix4>> {
+        Input::new(self.data.mapv(|el| if el { valid } else { invalid }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Mask2d, MaskMode};
+    use crate::nn::{Conv2d, LayerNorm, MaxPool2d, Zero};
+    use ndarray::{s, Array, Array4, Axis, Ix4};
+
+    #[test]
+    fn from_sizes() {
+        let mask = Mask2d::from_sizes(&[(2, 3), (1, 1)], (2, 3));
+
+        assert_eq!(
+            mask.data().iter().copied().collect::<Vec<_>>(),
+            vec![true, true, true, true, true, true, true, false, false, false, false, false]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_many_channels() {
+        Mask2d::new(Array4::from_elem((1, 2, 3, 3), true));
+    }
+
+    #[test]
+    fn downsample() {
+        let mask = Mask2d::from_sizes(&[(4, 4), (3, 2)], (4, 4));
+
+        let all_valid = mask.downsample((2, 2), (0, 0), (1, 1), (1, 1), MaskMode::AllValid);
+        assert_eq!(all_valid.data().dim(), (2, 1, 3, 3));
+        assert!(all_valid.data().index_axis(Axis(0), 0).iter().all(|el| *el));
+        assert_eq!(
+            all_valid.data().index_axis(Axis(0), 1).mapv(u8::from),
+            ndarray::array![[[1, 0, 0], [1, 0, 0], [0, 0, 0]]]
+        );
+
+        let any_valid = mask.downsample((2, 2), (0, 0), (1, 1), (1, 1), MaskMode::AnyValid);
+        assert_eq!(
+            any_valid.data().index_axis(Axis(0), 1).mapv(u8::from),
+            ndarray::array![[[1, 1, 0], [1, 1, 0], [1, 1, 0]]]
+        );
+
+        // The padding added by the operation doesn't invalidate the edges.
+        let padded = mask.downsample((3, 3), (1, 1), (2, 2), (1, 1), MaskMode::AllValid);
+        assert_eq!(
+            padded.data().index_axis(Axis(0), 1).mapv(u8::from),
+            ndarray::array![[[1, 0], [0, 0]]]
+        );
+    }
+
+    #[test]
+    fn masked_batch_matches_unpadded_samples() {
+        let sizes = [(8, 8), (6, 5)];
+        let mask = Mask2d::from_sizes(&sizes, (8, 8));
+
+        // The padding is filled with large values that would spoil any statistic.
+        let mut data = crate::rand((2, 2, 8, 8)).data().clone();
+        data.indexed_iter_mut()
+            .filter(|((n, _, i, j), _)| *i >= sizes[*n].0 || *j >= sizes[*n].1)
+            .for_each(|(_, el)| *el = 100.);
+
+        let conv = Conv2d::new(2, 3, (3, 3), (1, 1), (0, 0), Zero, (1, 1), 1, true);
+        let pool = MaxPool2d::new((2, 2), (2, 2), (0, 0));
+
+        let input = crate::from_ndarray(data.clone()).requires_grad();
+        let (output, output_mask) = conv.forward_masked(input.clone(), &mask, MaskMode::AllValid);
+        let (output, output_mask) = pool.forward_masked(output, &output_mask, MaskMode::AllValid);
+        let output = LayerNorm::<Ix4>::new(&[3, 3, 3], 1e-5).forward_masked(output, &output_mask);
+        let loss = (output.clone() * output.clone()).sum();
+        loss.forward();
+        loss.backward(1.);
+
+        // The same computation carried out on each sample alone, sharing the convolution's
+        // parameters with the batched one.
+        let sample_conv = Conv2d::new(2, 3, (3, 3), (1, 1), (0, 0), Zero, (1, 1), 1, true);
+        sample_conv.weight.data_mut().assign(&conv.weight.data());
+        let sample_bias = sample_conv.bias.as_ref().unwrap();
+        sample_bias
+            .data_mut()
+            .assign(&conv.bias.as_ref().unwrap().data());
+
+        for (n, &(h, w)) in sizes.iter().enumerate() {
+            let (out_h, out_w) = ((h - 2) / 2, (w - 2) / 2);
+            let sample = crate::from_ndarray(data.slice(s![n..=n, .., ..h, ..w]).to_owned());
+            let sample_output = LayerNorm::<Ix4>::new(&[3, out_h, out_w], 1e-5)
+                .forward(pool.forward(sample_conv.forward(sample)));
+            let sample_loss = (sample_output.clone() * sample_output.clone()).sum();
+            sample_loss.forward();
+            sample_loss.backward(1.);
+
+            let valid = output
+                .data()
+                .slice(s![n..=n, .., ..out_h, ..out_w])
+                .to_owned();
+            assert!(valid
+                .iter()
+                .zip(sample_output.data().iter())
+                .all(|(batched, alone)| (batched - alone).abs() < 1e-4));
+
+            let mut invalid = output.data().index_axis(Axis(0), n).to_owned();
+            invalid.slice_mut(s![.., ..out_h, ..out_w]).fill(0.);
+            assert!(invalid.iter().all(|el| *el == 0.));
+
+            let expected_mask = Array::from_shape_fn((1, 3, 3), |(_, i, j)| i < out_h && j < out_w);
+            assert_eq!(output_mask.data().index_axis(Axis(0), n), expected_mask);
+        }
+
+        // The padding contributes nothing to the gradients.
+        assert!(input
+            .grad()
+            .indexed_iter()
+            .filter(|((n, _, i, j), _)| *i >= sizes[*n].0 || *j >= sizes[*n].1)
+            .all(|(_, el)| *el == 0.));
+        assert!(conv
+            .weight
+            .grad()
+            .iter()
+            .zip(sample_conv.weight.grad().iter())
+            .all(|(batched, alone)| (batched - alone).abs() < 1e-3));
+    }
+}
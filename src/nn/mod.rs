@@ -73,12 +73,12 @@
 //!     fn forward<I, T, U>(
 //!         &self,
 //!         input: I,
-//!     ) -> VarDiff<impl Data<Dim = Ix2>, impl Gradient<Dim = Ix2>>
+//!     ) -> VarDiff<dyn Data<Dim = Ix2>, dyn Gradient<Dim = Ix2>>
 //!     where
 //!         I: MatMatMulT<Learnable<Ix2>>,
 //!         I::Output: Into<VarDiff<T, U>>,
-//!         T: Data<Dim = Ix2> + Forward,
-//!         U: Gradient<Dim = Ix2>,
+//!         T: Data<Dim = Ix2> + Forward + 'static,
+//!         U: Gradient<Dim = Ix2> + 'static,
 //!     {
 //!         let out1 = self.lin1.forward(input).relu();
 //!         let out2 = self.lin2.forward(out1).relu();
@@ -117,12 +117,12 @@
 //! #     fn forward<I, T, U>(
 //! #         &self,
 //! #         input: I,
-//! #     ) -> VarDiff<impl Data<Dim = Ix2>, impl Gradient<Dim = Ix2>>
+//! #     ) -> VarDiff<dyn Data<Dim = Ix2>, dyn Gradient<Dim = Ix2>>
 //! #     where
 //! #         I: MatMatMulT<Learnable<Ix2>>,
 //! #         I::Output: Into<VarDiff<T, U>>,
-//! #         T: Data<Dim = Ix2> + Forward,
-//! #         U: Gradient<Dim = Ix2>,
+//! #         T: Data<Dim = Ix2> + Forward + 'static,
+//! #         U: Gradient<Dim = Ix2> + 'static,
 //! #     {
 //! #         let out1 = self.lin1.forward(input).relu();
 //! #         let out2 = self.lin2.forward(out1).relu();
@@ -171,12 +171,12 @@
 //! #     fn forward<I, T, U>(
 //! #         &self,
 //! #         input: I,
-//! #     ) -> VarDiff<impl Data<Dim = Ix2>, impl Gradient<Dim = Ix2>>
+//! #     ) -> VarDiff<dyn Data<Dim = Ix2>, dyn Gradient<Dim = Ix2>>
 //! #     where
 //! #         I: MatMatMulT<Learnable<Ix2>>,
 //! #         I::Output: Into<VarDiff<T, U>>,
-//! #         T: Data<Dim = Ix2>,
-//! #         U: Gradient<Dim = Ix2>,
+//! #         T: Data<Dim = Ix2> + 'static,
+//! #         U: Gradient<Dim = Ix2> + 'static,
 //! #     {
 //! #         let out1 = self.lin1.forward(input).relu();
 //! #         let out2 = self.lin2.forward(out1).relu();
@@ -355,12 +355,22 @@
 //!
 //! * [`nn::Linear`](struct@Linear) - Applies a linear transformation to the incoming data.
 //!
+//! * [`nn::Bilinear`](struct@Bilinear) - Applies a bilinear transformation to a pair of incoming
+//! data.
+//!
 //! ## Recurrent Layers
 //!
 //! * [`nn::GRUCell`](struct@GRUCell) - A gated recurrent unit cell.
 //!
 //! * [`nn::LSTMCell`](struct@LSTMCell) - A long short term memory cell.
 //!
+//! * [`nn::RNN`](struct@RNN) - A multi-layer Elman recurrent network, with a tanh or ReLU
+//! non-linearity.
+//!
+//! * [`nn::GRU`](struct@GRU) - A multi-layer gated recurrent unit network.
+//!
+//! * [`nn::LSTM`](struct@LSTM) - A multi-layer long short term memory network.
+//!
 //! ## Convolution Layers
 //!
 //! * [`nn::Conv1d`](struct@Conv1d) - Applies a temporal convolution over an input signal composed
@@ -369,12 +379,12 @@
 //! * [`nn::GroupedConv1d`](struct@GroupedConv1d) - Applies a grouped temporal convolution over an
 //! input signal composed of several input planes.
 //!
-//! * [`nn::Conv2d`](struct@Conv2d) - Applies a spatial convolution over an input signal composed
-//! of several input planes.
-//!
-//! * [`nn::GroupedConv2d`](struct@GroupedConv2d) - Applies a grouped spatial convolution over an
+//! * [`nn::Conv2d`](struct@Conv2d) - Applies a spatial convolution, optionally grouped, over an
 //! input signal composed of several input planes.
 //!
+//! * [`nn::ConvTranspose2d`](struct@ConvTranspose2d) - Applies a spatial transposed convolution
+//! over an input signal composed of several input planes.
+//!
 //! * [`nn::Conv3d`](struct@Conv3d) - Applies a volumetric convolution over an input signal composed
 //! of several input planes.
 //!
@@ -390,23 +400,150 @@
 //! * [`nn::MaxPool3d`](struct@MaxPool3d) - Max pooling operation for 3D data (spatial or
 //! spatio-temporal).
 //!
+//! ## Average Pooling Layers
+//!
+//! * [`nn::AvgPool2d`](struct@AvgPool2d) - Average pooling operation for 2D spatial data.
+//!
+//! ## Adaptive Pooling Layers
+//!
+//! * [`nn::AdaptiveAvgPool2d`](struct@AdaptiveAvgPool2d) - Average pooling operation for 2D
+//! spatial data with a fixed output shape.
+//!
+//! * [`nn::AdaptiveMaxPool2d`](struct@AdaptiveMaxPool2d) - Max pooling operation for 2D spatial
+//! data with a fixed output shape.
+//!
+//! * [`nn::GlobalAvgPool`](struct@GlobalAvgPool) - Average over the whole spatial extent of each
+//! channel.
+//!
+//! * [`nn::GlobalMaxPool`](struct@GlobalMaxPool) - Maximum over the whole spatial extent of each
+//! channel.
+//!
 //! ## Dropout Layers
 //!
 //! * [`nn::Dropout`](struct@Dropout) - During training, randomly zeroes some of the elements of
 //! the input variable with probability *p* using samples from a Bernoulli distribution.
+//!
+//! ## Normalization Layers
+//!
+//! * [`nn::BatchNorm`](struct@BatchNorm) - Applies batch normalization over the channels of the
+//! input variable, see also [`nn::BatchNorm1d`](type@BatchNorm1d) and
+//! [`nn::BatchNorm2d`](type@BatchNorm2d).
+//!
+//! * [`nn::LayerNorm`](struct@LayerNorm) - Applies layer normalization over the trailing
+//! dimensions of the input variable.
+//!
+//! * [`nn::GroupNorm`](struct@GroupNorm) - Applies group normalization over groups of channels of
+//! the input variable.
+//!
+//! * [`nn::InstanceNorm`](struct@InstanceNorm) - Applies instance normalization over each channel
+//! of each sample of the input variable.
+//!
+//! ## Sparse Layers
+//!
+//! * [`nn::Embedding`](struct@Embedding) - A lookup table storing embeddings of a fixed dictionary
+//! and size.
+//!
+//! ## Attention
+//!
+//! * [`nn::scaled_dot_product_attention`](fn@scaled_dot_product_attention) - Computes the scaled
+//! dot-product attention of a batch of queries over a batch of keys and values, optionally masked
+//! by an additive or a key-padding [`nn::AttentionMask`](enum@AttentionMask). It's also available
+//! as [`nn::functional::scaled_dot_product_attention`](fn@functional::scaled_dot_product_attention).
+//!
+//! * [`nn::MultiHeadAttention`](struct@MultiHeadAttention) - Applies a multi-head attention,
+//! projecting the queries, the keys and the values before attending with several heads in
+//! parallel.
+//!
+//! * [`nn::PositionalEncoding`](struct@PositionalEncoding) - Adds the sinusoidal positional
+//! encoding to a batch of sequences of embeddings.
+//!
+//! ## Distance Functions
+//!
+//! * [`nn::cosine_similarity`](fn@cosine_similarity) - Computes the cosine similarity between the
+//! rows or the columns of two matrices.
 use super::{Input, InputBackward, Param};
 use crate::variable::{
-    self, Convolve, ConvolveWithGroups, Data, Dropout as DropoutNode,
-    DropoutBackward as DropoutBackwardNode, Eval, Gradient, MatMatMulT, MaxPooling, Overwrite,
-    RawParam, Tensor, Var, VarDiff,
+    self, AdaptiveAvgPooling, AdaptiveMaxPooling, AnyVarDiff, AvgPooling, Convolve,
+    ConvolveWithGroups, Data, Dropout as DropoutNode, DropoutBackward as DropoutBackwardNode, Eval,
+    Gradient, MatMatMulT, MaxPoolIndices, MaxPooling, Overwrite, Rank, RawParam, Tensor, Var,
+    VarDiff,
 };
 pub use crate::variable::{Constant, PaddingMode, Reflective, Replicative, Zero};
+use init::Init;
 use ndarray::{Ix1, Ix2, Ix3, Ix4, Ix5};
-use std::{cell::Cell, rc::Rc};
+use std::{
+    cell::Cell,
+    ops::{Add, Mul},
+    rc::Rc,
+};
 
+pub mod functional;
 pub mod init;
 pub mod loss;
 
+mod attention;
+mod batch_norm;
+mod bilinear;
+mod conv_transpose;
+mod embedding;
+mod group_norm;
+mod gru;
+mod gru_cell;
+mod instance_norm;
+mod layer_norm;
+mod lstm;
+mod lstm_cell;
+mod mask;
+mod multi_head_attention;
+mod positional_encoding;
+mod rnn;
+mod similarity;
+pub use attention::{scaled_dot_product_attention, AttentionMask};
+pub use batch_norm::{BatchNorm, BatchNorm1d, BatchNorm2d, BatchNormInput};
+pub use bilinear::Bilinear;
+pub use conv_transpose::ConvTranspose2d;
+pub use embedding::Embedding;
+pub use group_norm::{GroupNorm, GroupNormInput};
+pub use gru::GRU;
+pub use gru_cell::GRUCell;
+pub use instance_norm::InstanceNorm;
+pub use layer_norm::{LayerNorm, LayerNormInput};
+pub use lstm::LSTM;
+pub use lstm_cell::LSTMCell;
+pub use mask::{Mask2d, MaskMode};
+pub use multi_head_attention::MultiHeadAttention;
+pub use positional_encoding::PositionalEncoding;
+pub use rnn::{InitialState, Nonlinearity, RecurrentInput, RecurrentLayer, RNN};
+pub use similarity::cosine_similarity;
+
+/// Value added to the invalid positions of a padded input before a max pooling.
+const MASKED_VALUE: f32 = -1e30;
+
+/// Pairs the learnable parameter `param` with its `name`, erasing its dimensionality.
+fn named<D: Rank>(name: &str, param: &Learnable<D>) -> (String, AnyVarDiff) {
+    (name.to_string(), param.clone().into())
+}
+
+/// Checks that both the input and the output channels of a grouped convolution can be split in
+/// `groups` groups.
+fn check_groups(in_channels: usize, out_channels: usize, groups: usize) {
+    assert!(groups > 0, "error: the number of groups must be positive.");
+    assert_eq!(
+        in_channels % groups,
+        0,
+        "error: in channels {} is not divisible by groups {}.",
+        in_channels,
+        groups
+    );
+    assert_eq!(
+        out_channels % groups,
+        0,
+        "error: out channels {} is not divisible by groups {}.",
+        out_channels,
+        groups
+    );
+}
+
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
 
@@ -593,7 +730,7 @@ impl Register for Dropout {
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 pub struct Linear {
     pub weight: Learnable<Ix2>,
-    pub bias: Learnable<Ix1>,
+    pub bias: Option<Learnable<Ix1>>,
 }
 
 impl Linear {
@@ -611,259 +748,86 @@ impl Linear {
     /// The values for both the weight and bias are initialized from *U(-k, k)* where
     /// `k = (1. / in_features as f32).sqrt()`.
     pub fn new(in_features: usize, out_features: usize) -> Self {
-        let weight = Input::new(Tensor::zeros((out_features, in_features))).requires_grad();
-        let bias = Input::new(Tensor::zeros(out_features)).requires_grad();
         let k = (1. / (in_features as f32)).sqrt();
-        init::uniform(&weight, -k, k);
-        init::uniform(&bias, -k, k);
 
-        Self { weight, bias }
+        Self::new_with(
+            in_features,
+            out_features,
+            true,
+            Init::Uniform { low: -k, high: k },
+        )
     }
 
-    /// Applies the linear transformation *y = xA^T + b* to the incoming data.
+    /// Creates a linear layer, optionally without bias, whose weight is filled by `init`.
     ///
     /// # Arguments
     ///
-    /// `data` - a variable of shape *(N, in_features)*, the output's shape will be
-    /// *(N, out_features)*.
-    pub fn forward<I, T, U>(
-        &self,
-        input: I,
-    ) -> VarDiff<impl Data<Dim = Ix2>, impl Gradient<Dim = Ix2>>
-    where
-        I: MatMatMulT<Learnable<Ix2>>,
-        I::Output: Into<VarDiff<T, U>>,
-        T: Data<Dim = Ix2>,
-        U: Gradient<Dim = Ix2>,
-    {
-        input.mm_t(self.weight.clone()).into() + self.bias.clone()
-    }
-}
-
-impl Register for Linear {
-    /// Registers the weight and the bias of this `Linear` instance.
-    fn register_params(&self, params: &mut Vec<RawParam>) {
-        self.weight.register_params(params);
-        self.bias.register_params(params);
-    }
-
-    fn register_status(&mut self, _: Rc<Cell<bool>>) {}
-}
-
-/// A **long short-term memory (LSTM)** cell.
-#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
-#[allow(clippy::upper_case_acronyms)]
-pub struct LSTMCell {
-    pub weight_ih: Learnable<Ix2>,
-    pub weight_hh: Learnable<Ix2>,
-    pub bias_ih: Learnable<Ix1>,
-    pub bias_hh: Learnable<Ix1>,
-}
-
-impl LSTMCell {
-    /// Creates a new LSTMCell.
+    /// * `in_features` – size of each input sample.
     ///
-    /// # Arguments
+    /// * `out_features` – size of each output sample.
     ///
-    /// * `input_size` - number of expected features in the input.
+    /// * `bias` - whether the layer has a learnable bias.
     ///
-    /// * `hidden_size` - number of features in the hidden state.
+    /// * `init` - initializer of the weight.
     ///
-    /// All the weight and biases are initialized from *U(-k, k)* where
-    /// `k = (1. / hidden_size as f32).sqrt()`.
-    pub fn new(input_size: usize, hidden_size: usize) -> Self {
-        let (weight_ih_shape, weight_hh_shape, bias_shape) = {
-            let xhidden_size = 4 * hidden_size;
-            (
-                (xhidden_size, input_size),
-                (xhidden_size, hidden_size),
-                xhidden_size,
-            )
-        };
-        let weight_ih = Input::new(Tensor::zeros(weight_ih_shape)).requires_grad();
-        let weight_hh = Input::new(Tensor::zeros(weight_hh_shape)).requires_grad();
-        let bias_ih = Input::new(Tensor::zeros(bias_shape)).requires_grad();
-        let bias_hh = Input::new(Tensor::zeros(bias_shape)).requires_grad();
+    /// The bias, if any, is initialized as in [`Linear::new()`].
+    pub fn new_with(in_features: usize, out_features: usize, bias: bool, init: Init) -> Self {
+        let weight = Input::new(Tensor::zeros((out_features, in_features))).requires_grad();
+        init.apply(&weight);
 
-        let k = 1. / (hidden_size as f32).sqrt();
-        init::uniform(&weight_ih, -k, k);
-        init::uniform(&weight_hh, -k, k);
-        init::uniform(&bias_ih, -k, k);
-        init::uniform(&bias_hh, -k, k);
+        let bias = bias.then(|| {
+            let bias = Input::new(Tensor::zeros(out_features)).requires_grad();
+            let k = (1. / (in_features as f32)).sqrt();
+            init::uniform(&bias, -k, k);
+            bias
+        });
 
-        Self {
-            weight_ih,
-            weight_hh,
-            bias_ih,
-            bias_hh,
-        }
+        Self { weight, bias }
     }
 
-    /// Computes a single **LSTM step**.
+    /// Applies the linear transformation *y = xA^T + b* to the incoming data. When the layer has
+    /// no bias the addition is skipped altogether.
     ///
     /// # Arguments
     ///
-    /// * `state` - a tuple of tensors, both of shape *(batch, hidden_size)*, containing the
-    /// initial hidden state for each element in the batch and the initial cell's state for
-    /// each element in the batch.
-    ///
-    /// * `input` - a variable containing the input features of shape *(batch, input_size)*.
-    ///
-    /// The **output** is a tuple of tensors made of the next hidden state for each element in
-    /// the batch, of shape *(batch, hidden_size)* and the next cell's state for each element in
-    /// the batch, of shape *(batch, hidden_size)*.
-    pub fn forward<Cf: ?Sized, Cb: ?Sized, Hf: ?Sized, Hb: ?Sized, I, T, U>(
+    /// `data` - a variable of shape *(N, in_features)*, the output's shape will be
+    /// *(N, out_features)*.
+    pub fn forward<I, T, U>(
         &self,
-        state: (VarDiff<Cf, Cb>, VarDiff<Hf, Hb>),
         input: I,
-    ) -> (
-        VarDiff<impl Data<Dim = Ix2>, impl Gradient<Dim = Ix2>>,
-        VarDiff<impl Data<Dim = Ix2>, impl Gradient<Dim = Ix2>>,
-    )
+    ) -> VarDiff<dyn Data<Dim = Ix2>, dyn Gradient<Dim = Ix2>>
     where
-        Cf: Data<Dim = Ix2>,
-        Cb: Gradient<Dim = Ix2>,
-        Hf: Data<Dim = Ix2>,
-        Hb: Gradient<Dim = Ix2>,
         I: MatMatMulT<Learnable<Ix2>>,
         I::Output: Into<VarDiff<T, U>>,
-        T: Data<Dim = Ix2>,
-        U: Gradient<Dim = Ix2>,
+        T: Data<Dim = Ix2> + 'static,
+        U: Gradient<Dim = Ix2> + 'static,
     {
-        let (cell_state, hidden) = state;
-        let gates = hidden.mm_t(self.weight_hh.clone())
-            + self.bias_hh.clone()
-            + input.mm_t(self.weight_ih.clone()).into()
-            + self.bias_ih.clone();
-        let gate_shape = {
-            let (gates_shape_rows, gates_shape_cols) = gates.data().dim();
-            (gates_shape_rows, gates_shape_cols / 4)
-        };
-        let chunked_gates = gates.chunks(gate_shape);
-        let (input_gate, forget_gate, cell_state_gate, output_gate) = (
-            chunked_gates[0].clone().sigmoid(),
-            chunked_gates[1].clone().tanh(),
-            chunked_gates[2].clone().sigmoid(),
-            chunked_gates[3].clone().sigmoid(),
-        );
-        let new_cell_state = forget_gate * cell_state + (input_gate * cell_state_gate);
-        let new_hidden = output_gate * new_cell_state.clone().tanh();
-
-        (new_cell_state, new_hidden)
-    }
-}
-
-impl Register for LSTMCell {
-    /// Registers the weights and the biases of this LSTMCell instance.
-    fn register_params(&self, params: &mut Vec<RawParam>) {
-        self.weight_hh.register_params(params);
-        self.weight_ih.register_params(params);
-        self.bias_hh.register_params(params);
-        self.bias_ih.register_params(params);
-    }
-
-    fn register_status(&mut self, _: Rc<Cell<bool>>) {}
-}
-
-/// A **gated recurrent unit (GRU)** cell.
-#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
-#[allow(clippy::upper_case_acronyms)]
-pub struct GRUCell {
-    pub weight_ih: Learnable<Ix2>,
-    pub weight_hh: Learnable<Ix2>,
-    pub bias_ih: Learnable<Ix1>,
-    pub bias_hh: Learnable<Ix1>,
-}
+        let output: VarDiff<T, U> = input.mm_t(self.weight.clone()).into();
 
-impl GRUCell {
-    /// Creates a new GRUCell.
-    ///
-    /// # Arguments
-    ///
-    /// * `input_size` - number of expected features in the input.
-    ///
-    /// * `hidden_size` - number of features in the hidden state.
-    ///
-    /// All the weight and biases are initialized from *U(-k, k)* where
-    /// `k = (1. / hidden_size as f32).sqrt()`.
-    pub fn new(input_size: usize, hidden_size: usize) -> Self {
-        let (weight_ih_shape, weight_hh_shape, bias_shape) = {
-            let xhidden_size = 3 * hidden_size;
-            (
-                (xhidden_size, input_size),
-                (xhidden_size, hidden_size),
-                xhidden_size,
-            )
-        };
-        let weight_ih = Input::new(Tensor::zeros(weight_ih_shape)).requires_grad();
-        let weight_hh = Input::new(Tensor::zeros(weight_hh_shape)).requires_grad();
-        let bias_ih = Input::new(Tensor::zeros(bias_shape)).requires_grad();
-        let bias_hh = Input::new(Tensor::zeros(bias_shape)).requires_grad();
-
-        let k = 1. / (hidden_size as f32).sqrt();
-        init::uniform(&weight_ih, -k, k);
-        init::uniform(&weight_hh, -k, k);
-        init::uniform(&bias_ih, -k, k);
-        init::uniform(&bias_hh, -k, k);
-
-        Self {
-            weight_ih,
-            weight_hh,
-            bias_ih,
-            bias_hh,
+        match &self.bias {
+            Some(bias) => (output + bias.clone()).into_dyn(),
+            None => output.into_dyn(),
         }
     }
 
-    /// Computes a single **GRU step**.
-    ///
-    /// * `hidden` - a variable of shape *(batch, hidden_size)*, containing the initial hidden state
-    /// for each element in the batch.
-    ///
-    /// * `input` - a variable containing the input features of shape *(batch, input_size)*.
-    ///
-    /// The **output** is  a variable made of the next hidden state for each element in
-    /// the batch, of shape *(batch, hidden_size)*.
-    pub fn forward<Hf: ?Sized, Hb: ?Sized, I, T, U>(
-        &self,
-        hidden: VarDiff<Hf, Hb>,
-        input: I,
-    ) -> VarDiff<impl Data<Dim = Ix2>, impl Gradient<Dim = Ix2>>
-    where
-        Hf: Data<Dim = Ix2>,
-        Hb: Gradient<Dim = Ix2>,
-        I: MatMatMulT<Learnable<Ix2>>,
-        I::Output: Into<VarDiff<T, U>>,
-        T: Data<Dim = Ix2>,
-        U: Gradient<Dim = Ix2>,
-    {
-        let (igates, hgates) = {
-            (
-                input.mm_t(self.weight_ih.clone()).into() + self.bias_ih.clone(),
-                hidden.clone().mm_t(self.weight_hh.clone()) + self.bias_hh.clone(),
-            )
-        };
-        let gate_shape = {
-            let (gates_shape_rows, gates_shape_cols) = hgates.data().dim();
-            (gates_shape_rows, gates_shape_cols / 3)
-        };
-        let (chunked_igates, chunked_hgates) =
-            (igates.chunks(gate_shape), hgates.chunks(gate_shape));
-
-        let reset_gate = (chunked_hgates[0].clone() + chunked_igates[0].clone()).sigmoid();
-        let input_gate = (chunked_hgates[1].clone() + chunked_igates[1].clone()).sigmoid();
-        let new_gate =
-            (chunked_igates[2].clone() + (chunked_hgates[2].clone() * reset_gate)).tanh();
-        (hidden - new_gate.clone()) * input_gate + new_gate
+    /// Returns the weight and the bias, if any, of this `Linear` instance, paired with their
+    /// names.
+    pub fn named_parameters(&self) -> Vec<(String, AnyVarDiff)> {
+        let mut params = vec![named("weight", &self.weight)];
+        if let Some(bias) = &self.bias {
+            params.push(named("bias", bias));
+        }
+        params
     }
 }
 
-impl Register for GRUCell {
-    /// Registers the weights and the biases of this `GRUCell` instance.
+impl Register for Linear {
+    /// Registers the weight and the bias, if any, of this `Linear` instance.
     fn register_params(&self, params: &mut Vec<RawParam>) {
-        self.weight_hh.register_params(params);
-        self.weight_ih.register_params(params);
-        self.bias_hh.register_params(params);
-        self.bias_ih.register_params(params);
+        self.weight.register_params(params);
+        if let Some(bias) = &self.bias {
+            bias.register_params(params);
+        }
     }
 
     fn register_status(&mut self, _: Rc<Cell<bool>>) {}
@@ -893,13 +857,13 @@ impl<Pad: PaddingMode> Conv1d<Pad> {
     ///
     /// * `kernel_size` - size of the kernel, a number for this one-dimensional case.
     ///
+    /// * `stride` - stride of the convolution, a number for this one-dimensional case.
+    ///
     /// * `padding` - padding to be applied to the input, a number for this one-dimensional case.
     ///
     /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`] or
     /// [`Replicative`].
     ///
-    /// * `stride` - stride of the convolution, a number for this one-dimensional case.
-    ///
     /// * `dilation` - controls the spacing between the kernel points, a number for this
     /// one-dimensional case.
     ///
@@ -909,9 +873,9 @@ impl<Pad: PaddingMode> Conv1d<Pad> {
         in_channels: usize,
         out_channels: usize,
         kernel_size: usize,
+        stride: usize,
         padding: usize,
         padding_mode: Pad,
-        stride: usize,
         dilation: usize,
     ) -> Self {
         let weight =
@@ -956,8 +920,8 @@ impl<Pad: PaddingMode> Conv1d<Pad> {
     where
         I: Convolve<I, Learnable<Ix3>, Pad>,
         I::Output: Into<VarDiff<T, U>>,
-        T: Data<Dim = Ix3>,
-        U: Gradient<Dim = Ix3>,
+        T: Data<Dim = Ix3> + 'static,
+        U: Gradient<Dim = Ix3> + 'static,
     {
         I::convolve(
             input,
@@ -970,6 +934,11 @@ impl<Pad: PaddingMode> Conv1d<Pad> {
         .into()
             + self.bias.clone()
     }
+
+    /// Returns the weight and the bias of this `Conv1d` instance, paired with their names.
+    pub fn named_parameters(&self) -> Vec<(String, AnyVarDiff)> {
+        vec![named("weight", &self.weight), named("bias", &self.bias)]
+    }
 }
 
 impl<Pad: PaddingMode> Register for Conv1d<Pad> {
@@ -1006,13 +975,13 @@ impl<Pad: PaddingMode> GroupedConv1d<Pad> {
     ///
     /// * `kernel_size` - size of the kernel, a number for this one-dimensional case.
     ///
+    /// * `stride` - stride of the convolution, a number for this one-dimensional case.
+    ///
     /// * `padding` - padding to be applied to the input, a number for this one-dimensional case.
     ///
     /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`] or
     /// [`Replicative`].
     ///
-    /// * `stride` - stride of the convolution, a number for this one-dimensional case.
-    ///
     /// * `dilation` - controls the spacing between the kernel points, a number for this
     /// one-dimensional case.
     ///
@@ -1033,12 +1002,14 @@ impl<Pad: PaddingMode> GroupedConv1d<Pad> {
         in_channels: usize,
         out_channels: usize,
         kernel_size: usize,
+        stride: usize,
         padding: usize,
         padding_mode: Pad,
-        stride: usize,
         dilation: usize,
         groups: usize,
     ) -> Self {
+        check_groups(in_channels, out_channels, groups);
+
         let weight = Input::new(Tensor::zeros((
             out_channels,
             in_channels / groups,
@@ -1073,9 +1044,9 @@ impl<Pad: PaddingMode> GroupedConv1d<Pad> {
     /// * **Cin** is the number of input channels
     /// * **L** is the **length** of the input
     ///
-    /// The **kernel** must be of shape *(Cout, Cin, Lk)*
+    /// The **kernel** must be of shape *(Cout, Cin / groups, Lk)*
     /// * **Cout** is the number of output channels
-    /// * **Cin** is the number of input channels
+    /// * **Cin** is the number of input channels, split in `groups` groups
     /// * **Lk** is the **length** of the kernel
     ///
     /// The resulting output shape will be *(N, Cout, Lout)*
@@ -1086,8 +1057,8 @@ impl<Pad: PaddingMode> GroupedConv1d<Pad> {
     where
         I: ConvolveWithGroups<I, Learnable<Ix3>, Pad>,
         I::Output: Into<VarDiff<T, U>>,
-        T: Data<Dim = Ix3>,
-        U: Gradient<Dim = Ix3>,
+        T: Data<Dim = Ix3> + 'static,
+        U: Gradient<Dim = Ix3> + 'static,
     {
         I::convolve_with_groups(
             input,
@@ -1101,6 +1072,11 @@ impl<Pad: PaddingMode> GroupedConv1d<Pad> {
         .into()
             + self.bias.clone()
     }
+
+    /// Returns the weight and the bias of this `GroupedConv1d` instance, paired with their names.
+    pub fn named_parameters(&self) -> Vec<(String, AnyVarDiff)> {
+        vec![named("weight", &self.weight), named("bias", &self.bias)]
+    }
 }
 
 impl<Pad: PaddingMode> Register for GroupedConv1d<Pad> {
@@ -1113,17 +1089,17 @@ impl<Pad: PaddingMode> Register for GroupedConv1d<Pad> {
     fn register_status(&mut self, _: Rc<Cell<bool>>) {}
 }
 
-/// Applies a **spatial convolution** over an input signal composed of several input planes.
-///
-/// See also [`GroupedConv2d`].
+/// Applies a **spatial convolution** over an input signal composed of several input planes,
+/// optionally split in groups of channels.
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 pub struct Conv2d<Pad: PaddingMode> {
     pub padding: (usize, usize),
     pub padding_mode: Pad,
     pub stride: (usize, usize),
     pub dilation: (usize, usize),
+    pub groups: usize,
     pub weight: Learnable<Ix4>,
-    pub bias: Learnable<Ix3>,
+    pub bias: Option<Learnable<Ix3>>,
 }
 
 impl<Pad: PaddingMode> Conv2d<Pad> {
@@ -1131,58 +1107,78 @@ impl<Pad: PaddingMode> Conv2d<Pad> {
     ///
     /// # Arguments
     ///
-    /// * `in_channels` - number of planes in the input signal.
+    /// * `in_channels` - number of planes in the input signal.
     ///
-    /// * `out_channels` - number of planes in the output signal.
+    /// * `out_channels` - number of planes in the output signal.
     ///
     /// * `kernel_size` - size of the kernel, a 2-tuple for this two-dimensional case.
     ///
+    /// * `stride` - stride of the convolution, a 2-tuple for this two-dimensional case.
+    ///
     /// * `padding` - padding to be applied to the input, a 2-tuple for this two-dimensional case.
     ///
     /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`] or
     /// [`Replicative`].
     ///
-    /// * `stride` - stride of the convolution, a 2-tuple for this two-dimensional case.
-    ///
     /// * `dilation` - controls the spacing between the kernel points, a 2-tuple for this
     /// two-dimensional case.
     ///
+    /// * `groups` - controls the connections between inputs and outputs. `in_channels` and
+    /// `out_channels` must both be divisible by groups. With `groups` equal to `in_channels` each
+    /// input channel is convolved with its own set of filters, that is a depthwise convolution.
+    ///
+    /// * `bias` - whether the layer has a learnable bias.
+    ///
     /// The weight and the bias are initialized from *U(-k, k)* where
-    /// `k = (1. /(in_channels * kernel_w * kernel_h) as f32).sqrt()`.
+    /// `k = (groups /(in_channels * kernel_w * kernel_h) as f32).sqrt()`.
+    ///
+    /// # Panics
+    ///
+    /// If either `in_channels` or `out_channels` is not divisible by `groups`.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         in_channels: usize,
         out_channels: usize,
         kernel_size: (usize, usize),
+        stride: (usize, usize),
         padding: (usize, usize),
         padding_mode: Pad,
-        stride: (usize, usize),
         dilation: (usize, usize),
+        groups: usize,
+        bias: bool,
     ) -> Self {
+        check_groups(in_channels, out_channels, groups);
+
         let (kernel_h, kernel_w) = kernel_size;
         let weight = Input::new(Tensor::zeros((
             out_channels,
-            in_channels,
+            in_channels / groups,
             kernel_h,
             kernel_w,
         )))
         .requires_grad();
-        let bias = Input::new(Tensor::zeros((out_channels, 1, 1))).requires_grad();
 
-        let k = (1. / (in_channels * kernel_h * kernel_w) as f32).sqrt();
+        let k = (groups as f32 / (in_channels * kernel_h * kernel_w) as f32).sqrt();
         init::uniform(&weight, -k, k);
-        init::uniform(&bias, -k, k);
+        let bias = bias.then(|| {
+            let bias = Input::new(Tensor::zeros((out_channels, 1, 1))).requires_grad();
+            init::uniform(&bias, -k, k);
+            bias
+        });
 
         Self {
             padding,
             padding_mode,
             stride,
             dilation,
+            groups,
             weight,
             bias,
         }
     }
 
-    /// Computes a 2-dimensional convolution *(cross correlation)*.
+    /// Computes a 2-dimensional convolution *(cross correlation)*, grouped if the layer has more
+    /// than one group.
     ///
     /// # Arguments
     ///
@@ -1194,9 +1190,9 @@ impl<Pad: PaddingMode> Conv2d<Pad> {
     /// * **H** is the **height** of the input
     /// * **W** is the **width** of the input
     ///
-    /// The **kernel** must be of shape *(Cout, Cin, Hk, Wk)*
+    /// The **kernel** must be of shape *(Cout, Cin / groups, Hk, Wk)*
     /// * **Cout** is the number of output channels
-    /// * **Cin** is the number of input channels
+    /// * **Cin** is the number of input channels, split in `groups` groups
     /// * **Hk** is the **height** of the kernel
     /// * **Wk** is the **width** of the kernel
     ///
@@ -1204,41 +1200,104 @@ impl<Pad: PaddingMode> Conv2d<Pad> {
     pub fn forward<I, T, U>(
         &self,
         input: I,
-    ) -> VarDiff<impl Data<Dim = Ix4>, impl Gradient<Dim = Ix4>>
+    ) -> VarDiff<dyn Data<Dim = Ix4>, dyn Gradient<Dim = Ix4>>
     where
-        I: Convolve<I, Learnable<Ix4>, Pad>,
+        I: ConvolveWithGroups<I, Learnable<Ix4>, Pad>,
         I::Output: Into<VarDiff<T, U>>,
-        T: Data<Dim = Ix4>,
-        U: Gradient<Dim = Ix4> + Overwrite,
+        T: Data<Dim = Ix4> + 'static,
+        U: Gradient<Dim = Ix4> + Overwrite + 'static,
     {
         let (stride_h, stride_w) = self.stride;
         let (padding_h, padding_w) = self.padding;
         let (dilation_h, dilation_w) = self.dilation;
 
-        I::convolve(
+        let output: VarDiff<T, U> = I::convolve_with_groups(
             input,
             self.weight.clone(),
             &[stride_h, stride_w],
             &[dilation_h, dilation_w],
             &[padding_h, padding_w],
             self.padding_mode,
+            self.groups,
         )
-        .into()
-            + self.bias.clone()
+        .into();
+
+        match &self.bias {
+            Some(bias) => (output + bias.clone()).into_dyn(),
+            None => output.into_dyn(),
+        }
+    }
+
+    /// Computes a 2-dimensional convolution of a batch of padded inputs and propagates their
+    /// validity mask.
+    ///
+    /// The invalid positions of the input are zeroed before the convolution, the output
+    /// positions that are invalid according to the downsampled mask are zeroed afterwards and
+    /// receive no gradient.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - the signal to convolve, of shape *(N, Cin, H, W)*.
+    ///
+    /// * `mask` - validity mask of the input.
+    ///
+    /// * `mode` - whether an output position needs any or all of its receptive field to be valid.
+    ///
+    /// Returns the output together with its validity mask.
+    pub fn forward_masked<I, T, U>(
+        &self,
+        input: I,
+        mask: &Mask2d,
+        mode: MaskMode,
+    ) -> (
+        VarDiff<dyn Data<Dim = Ix4>, dyn Gradient<Dim = Ix4>>,
+        Mask2d,
+    )
+    where
+        I: Mul<Var<Input<Ix4>>>,
+        I::Output: ConvolveWithGroups<I::Output, Learnable<Ix4>, Pad>,
+        <I::Output as ConvolveWithGroups<I::Output, Learnable<Ix4>, Pad>>::Output:
+            Into<VarDiff<T, U>>,
+        T: Data<Dim = Ix4> + 'static,
+        U: Gradient<Dim = Ix4> + Overwrite + 'static,
+    {
+        let kernel_size = {
+            let weight = self.weight.data();
+            (weight.shape()[2], weight.shape()[3])
+        };
+        let output_mask =
+            mask.downsample(kernel_size, self.padding, self.stride, self.dilation, mode);
+        let output = self.forward(input * mask.to_var());
+
+        ((output * output_mask.to_var()).into_dyn(), output_mask)
+    }
+
+    /// Returns the weight and the bias, if any, of this `Conv2d` instance, paired with their
+    /// names.
+    pub fn named_parameters(&self) -> Vec<(String, AnyVarDiff)> {
+        let mut params = vec![named("weight", &self.weight)];
+        if let Some(bias) = &self.bias {
+            params.push(named("bias", bias));
+        }
+        params
     }
 }
 
 impl<Pad: PaddingMode> Register for Conv2d<Pad> {
-    /// Registers the weight and the bias of this `Conv2d` instance.
+    /// Registers the weight and the bias, if any, of this `Conv2d` instance.
     fn register_params(&self, params: &mut Vec<RawParam>) {
         self.weight.register_params(params);
-        self.bias.register_params(params);
+        if let Some(bias) = &self.bias {
+            bias.register_params(params);
+        }
     }
 
     fn register_status(&mut self, _: Rc<Cell<bool>>) {}
 }
 
 /// Applies a **spatial grouped convolution** over an input signal composed of several input planes.
+///
+/// Superseded by [`Conv2d`], which takes the number of groups as well.
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 pub struct GroupedConv2d<Pad: PaddingMode> {
     pub padding: (usize, usize),
@@ -1283,6 +1342,7 @@ impl<Pad: PaddingMode> GroupedConv2d<Pad> {
     ///
     /// The weight and the bias of the layer are initialized from *U(-k, k)* where
     /// `k = (groups /(in_channels * kernel_h * kernel_w) as f32).sqrt()`.
+    #[deprecated(note = "use `Conv2d::new`, which takes the number of groups as well")]
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         in_channels: usize,
@@ -1294,10 +1354,12 @@ impl<Pad: PaddingMode> GroupedConv2d<Pad> {
         dilation: (usize, usize),
         groups: usize,
     ) -> Self {
+        check_groups(in_channels, out_channels, groups);
+
         let (kernel_h, kernel_w) = kernel_size;
         let weight = Input::new(Tensor::zeros((
             out_channels,
-            in_channels,
+            in_channels / groups,
             kernel_h,
             kernel_w,
         )))
@@ -1331,9 +1393,9 @@ impl<Pad: PaddingMode> GroupedConv2d<Pad> {
     /// * **H** is the **height** of the input
     /// * **W** is the **width** of the input
     ///
-    /// The **kernel** must be of shape *(Cout, Cin, Hk, Wk)*
+    /// The **kernel** must be of shape *(Cout, Cin / groups, Hk, Wk)*
     /// * **Cout** is the number of output channels
-    /// * **Cin** is the number of input channels
+    /// * **Cin** is the number of input channels, split in `groups` groups
     /// * **Hk** is the **height** of the kernel
     /// * **Wk** is the **width** of the kernel
     ///
@@ -1345,8 +1407,8 @@ impl<Pad: PaddingMode> GroupedConv2d<Pad> {
     where
         I: ConvolveWithGroups<I, Learnable<Ix4>, Pad>,
         I::Output: Into<VarDiff<T, U>>,
-        T: Data<Dim = Ix4>,
-        U: Gradient<Dim = Ix4>,
+        T: Data<Dim = Ix4> + 'static,
+        U: Gradient<Dim = Ix4> + 'static,
     {
         let (stride_h, stride_w) = self.stride;
         let (padding_h, padding_w) = self.padding;
@@ -1364,6 +1426,11 @@ impl<Pad: PaddingMode> GroupedConv2d<Pad> {
         .into()
             + self.bias.clone()
     }
+
+    /// Returns the weight and the bias of this `GroupedConv2d` instance, paired with their names.
+    pub fn named_parameters(&self) -> Vec<(String, AnyVarDiff)> {
+        vec![named("weight", &self.weight), named("bias", &self.bias)]
+    }
 }
 
 impl<Pad: PaddingMode> Register for GroupedConv2d<Pad> {
@@ -1400,13 +1467,13 @@ impl<Pad: PaddingMode> Conv3d<Pad> {
     ///
     /// * `kernel_size` - size of the kernel, a 3-tuple for this three-dimensional case.
     ///
+    /// * `stride` - stride of the convolution, a 3-tuple for this three-dimensional case.
+    ///
     /// * `padding` - padding to be applied to the input, a 3-tuple for this three-dimensional case.
     ///
     /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`] or
     /// [`Replicative`].
     ///
-    /// * `stride` - stride of the convolution, a 3-tuple for this three-dimensional case.
-    ///
     /// * `dilation` - controls the spacing between the kernel points, a 3-tuple for this
     /// three-dimensional case.
     ///
@@ -1416,9 +1483,9 @@ impl<Pad: PaddingMode> Conv3d<Pad> {
         in_channels: usize,
         out_channels: usize,
         kernel_size: (usize, usize, usize),
+        stride: (usize, usize, usize),
         padding: (usize, usize, usize),
         padding_mode: Pad,
-        stride: (usize, usize, usize),
         dilation: (usize, usize, usize),
     ) -> Self {
         let (kernel_d, kernel_h, kernel_w) = kernel_size;
@@ -1474,8 +1541,8 @@ impl<Pad: PaddingMode> Conv3d<Pad> {
     where
         I: Convolve<I, Learnable<Ix5>, Pad>,
         I::Output: Into<VarDiff<T, U>>,
-        T: Data<Dim = Ix5>,
-        U: Gradient<Dim = Ix5>,
+        T: Data<Dim = Ix5> + 'static,
+        U: Gradient<Dim = Ix5> + 'static,
     {
         let (stride_d, stride_h, stride_w) = self.stride;
         let (padding_d, padding_h, padding_w) = self.padding;
@@ -1492,6 +1559,11 @@ impl<Pad: PaddingMode> Conv3d<Pad> {
         .into()
             + self.bias.clone()
     }
+
+    /// Returns the weight and the bias of this `Conv3d` instance, paired with their names.
+    pub fn named_parameters(&self) -> Vec<(String, AnyVarDiff)> {
+        vec![named("weight", &self.weight), named("bias", &self.bias)]
+    }
 }
 
 impl<Pad: PaddingMode> Register for Conv3d<Pad> {
@@ -1528,13 +1600,13 @@ impl<Pad: PaddingMode> GroupedConv3d<Pad> {
     ///
     /// * `kernel_size` - size of the kernel, a 3-tuple  for this three-dimensional case.
     ///
+    /// * `stride` - stride of the convolution, a 3-tuple  for this three-dimensional case.
+    ///
     /// * `padding` - padding to be applied to the input, a 3-tuple  for this three-dimensional case.
     ///
     /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`] or
     /// [`Replicative`].
     ///
-    /// * `stride` - stride of the convolution, a 3-tuple  for this three-dimensional case.
-    ///
     /// * `dilation` - controls the spacing between the kernel points, a 3-tuple  for this
     /// three-dimensional case.
     ///
@@ -1555,16 +1627,18 @@ impl<Pad: PaddingMode> GroupedConv3d<Pad> {
         in_channels: usize,
         out_channels: usize,
         kernel_size: (usize, usize, usize),
+        stride: (usize, usize, usize),
         padding: (usize, usize, usize),
         padding_mode: Pad,
-        stride: (usize, usize, usize),
         dilation: (usize, usize, usize),
         groups: usize,
     ) -> Self {
+        check_groups(in_channels, out_channels, groups);
+
         let (kernel_d, kernel_h, kernel_w) = kernel_size;
         let weight = Input::new(Tensor::zeros((
             out_channels,
-            in_channels,
+            in_channels / groups,
             kernel_d,
             kernel_h,
             kernel_w,
@@ -1572,7 +1646,7 @@ impl<Pad: PaddingMode> GroupedConv3d<Pad> {
         .requires_grad();
         let bias = Input::new(Tensor::zeros((out_channels, 1, 1, 1))).requires_grad();
 
-        let k = (1. / (in_channels * kernel_d * kernel_h * kernel_w) as f32).sqrt();
+        let k = (groups as f32 / (in_channels * kernel_d * kernel_h * kernel_w) as f32).sqrt();
         init::uniform(&weight, -k, k);
         init::uniform(&bias, -k, k);
 
@@ -1598,9 +1672,9 @@ impl<Pad: PaddingMode> GroupedConv3d<Pad> {
     /// * **H** is the **height** of the input
     /// * **W** is the **width** of the input
     ///
-    /// The **kernel** must be of shape *(Cout, Cin, Dk,  Hk, Wk)*
+    /// The **kernel** must be of shape *(Cout, Cin / groups, Dk,  Hk, Wk)*
     /// * **Cout** is the number of output channels
-    /// * **Cin** is the number of input channels
+    /// * **Cin** is the number of input channels, split in `groups` groups
     /// * **Dk** is the **depth** of the kernel
     /// * **Hk** is the **height** of the kernel
     /// * **Wk** is the **width** of the kernel
@@ -1613,8 +1687,8 @@ impl<Pad: PaddingMode> GroupedConv3d<Pad> {
     where
         I: ConvolveWithGroups<I, Learnable<Ix5>, Pad>,
         I::Output: Into<VarDiff<T, U>>,
-        T: Data<Dim = Ix5>,
-        U: Gradient<Dim = Ix5>,
+        T: Data<Dim = Ix5> + 'static,
+        U: Gradient<Dim = Ix5> + 'static,
     {
         let (stride_d, stride_h, stride_w) = self.stride;
         let (padding_d, padding_h, padding_w) = self.padding;
@@ -1632,6 +1706,11 @@ impl<Pad: PaddingMode> GroupedConv3d<Pad> {
         .into()
             + self.bias.clone()
     }
+
+    /// Returns the weight and the bias of this `GroupedConv3d` instance, paired with their names.
+    pub fn named_parameters(&self) -> Vec<(String, AnyVarDiff)> {
+        vec![named("weight", &self.weight), named("bias", &self.bias)]
+    }
 }
 
 impl<Pad: PaddingMode> Register for GroupedConv3d<Pad> {
@@ -1680,21 +1759,26 @@ impl MaxPool1d {
         where
             I: MaxPooling<I>,
             I::Output: Into<VarDiff<T, U>>,
-            T: Data<Dim=Ix3>,
-            U: Gradient<Dim=Ix3>,
+            T: Data<Dim=Ix3> + 'static,
+            U: Gradient<Dim=Ix3> + 'static,
     {
         I::max_pool(
             input,
             &[self.pool_shape],
             &[self.stride],
+            &[0],
         ).into()
     }
 }
 
 /// Max pooling operation for 2D spatial data.
+///
+/// The gradient of each pool flows only to its maximum, the first one in case of ties. An element
+/// that is the maximum of several overlapping pools gathers the gradients of all of them.
 pub struct MaxPool2d {
     pub pool_shape: (usize, usize),
     pub stride: (usize, usize),
+    pub padding: (usize, usize),
 }
 
 impl MaxPool2d {
@@ -1705,13 +1789,18 @@ impl MaxPool2d {
     /// * `pool_shape` - shape of the pool, a 2-tuple for this two-dimensional case.
     ///
     /// * `stride` - stride of the pooling, a 2-tuple for this two-dimensional case.
+    ///
+    /// * `padding` - implicit negative infinity padding added on both sides of the input, a
+    /// 2-tuple for this two-dimensional case. It must be at most half of the pool shape.
     pub fn new(
         pool_shape: (usize, usize),
         stride: (usize, usize),
+        padding: (usize, usize),
     ) -> Self {
         Self {
             pool_shape,
             stride,
+            padding,
         }
     }
 
@@ -1727,15 +1816,91 @@ impl MaxPool2d {
         where
             I: MaxPooling<I>,
             I::Output: Into<VarDiff<T, U>>,
-            T: Data<Dim=Ix4>,
-            U: Gradient<Dim=Ix4>,
+            T: Data<Dim=Ix4> + 'static,
+            U: Gradient<Dim=Ix4> + 'static,
     {
         I::max_pool(
             input,
             &[self.pool_shape.0, self.pool_shape.1],
             &[self.stride.0, self.stride.1],
+            &[self.padding.0, self.padding.1],
         ).into()
     }
+
+    /// Applies the pooling to the variable in input and returns the positions of the maxima.
+    ///
+    /// The indices are flattened over the height and the width of the input and are available
+    /// once the output has been computed, see [`MaxPoolIndices`] for more details.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - variable in input to the layer.
+    pub fn forward_with_indices<I, T, U>(
+        &self,
+        input: I,
+    ) -> (
+        VarDiff<impl Data<Dim = Ix4>, impl Gradient<Dim = Ix4>>,
+        MaxPoolIndices<Ix4>,
+    )
+    where
+        I: MaxPooling<I, Indices = MaxPoolIndices<Ix4>>,
+        I::Output: Into<VarDiff<T, U>>,
+        T: Data<Dim = Ix4> + 'static,
+        U: Gradient<Dim = Ix4> + 'static,
+    {
+        let (output, indices) = I::max_pool_with_indices(
+            input,
+            &[self.pool_shape.0, self.pool_shape.1],
+            &[self.stride.0, self.stride.1],
+            &[self.padding.0, self.padding.1],
+        );
+
+        (output.into(), indices)
+    }
+
+    /// Applies the pooling to a batch of padded inputs and propagates their validity mask.
+    ///
+    /// The invalid positions of the input never win the maximum of a window, the output
+    /// positions that are invalid according to the downsampled mask are zeroed and receive no
+    /// gradient.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - variable in input to the layer, of shape *(N, C, H, W)*.
+    ///
+    /// * `mask` - validity mask of the input.
+    ///
+    /// * `mode` - whether an output position needs any or all of its pool to be valid.
+    ///
+    /// Returns the output together with its validity mask.
+    pub fn forward_masked<I, T, U>(
+        &self,
+        input: I,
+        mask: &Mask2d,
+        mode: MaskMode,
+    ) -> (
+        VarDiff<dyn Data<Dim = Ix4>, dyn Gradient<Dim = Ix4>>,
+        Mask2d,
+    )
+    where
+        I: Add<Var<Input<Ix4>>>,
+        I::Output: MaxPooling<I::Output>,
+        <I::Output as MaxPooling<I::Output>>::Output: Into<VarDiff<T, U>>,
+        T: Data<Dim = Ix4> + 'static,
+        U: Gradient<Dim = Ix4> + 'static,
+    {
+        let output_mask = mask.downsample(self.pool_shape, self.padding, self.stride, (1, 1), mode);
+        let input = input + mask.to_var_with(0., MASKED_VALUE);
+        let output: VarDiff<T, U> = I::Output::max_pool(
+            input,
+            &[self.pool_shape.0, self.pool_shape.1],
+            &[self.stride.0, self.stride.1],
+            &[self.padding.0, self.padding.1],
+        )
+        .into();
+
+        ((output * output_mask.to_var()).into_dyn(), output_mask)
+    }
 }
 
 /// Max pooling operation for 3D data (spatial or spatio-temporal).
@@ -1774,13 +1939,585 @@ impl MaxPool3d {
         where
             I: MaxPooling<I>,
             I::Output: Into<VarDiff<T, U>>,
-            T: Data<Dim=Ix5>,
-            U: Gradient<Dim=Ix5>,
+            T: Data<Dim=Ix5> + 'static,
+            U: Gradient<Dim=Ix5> + 'static,
     {
         I::max_pool(
             input,
             &[self.pool_shape.0, self.pool_shape.1, self.pool_shape.2],
             &[self.stride.0, self.stride.1, self.stride.2],
+            &[0, 0, 0],
         ).into()
     }
 }
+
+/// Average pooling operation for 2D spatial data.
+///
+/// The gradient of each pool is spread uniformly over its elements.
+pub struct AvgPool2d {
+    pub pool_shape: (usize, usize),
+    pub stride: (usize, usize),
+    pub padding: (usize, usize),
+    pub count_include_pad: bool,
+}
+
+impl AvgPool2d {
+    /// Creates an AvgPool2d layer.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool_shape` - shape of the pool, a 2-tuple for this two-dimensional case.
+    ///
+    /// * `stride` - stride of the pooling, a 2-tuple for this two-dimensional case.
+    ///
+    /// * `padding` - implicit zero padding added on both sides of the input, a 2-tuple for this
+    /// two-dimensional case. It must be at most half of the pool shape.
+    ///
+    /// * `count_include_pad` - whether the padded zeros are counted when averaging a pool. If
+    /// not, each pool is averaged over the elements of the input it covers.
+    pub fn new(
+        pool_shape: (usize, usize),
+        stride: (usize, usize),
+        padding: (usize, usize),
+        count_include_pad: bool,
+    ) -> Self {
+        Self {
+            pool_shape,
+            stride,
+            padding,
+            count_include_pad,
+        }
+    }
+
+    /// Applies the pooling to the variable in input.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - variable in input to the layer.
+    pub fn forward<I, T, U>(
+        &self,
+        input: I,
+    ) -> VarDiff<impl Data<Dim = Ix4>, impl Gradient<Dim = Ix4>>
+    where
+        I: AvgPooling<I>,
+        I::Output: Into<VarDiff<T, U>>,
+        T: Data<Dim = Ix4> + 'static,
+        U: Gradient<Dim = Ix4> + 'static,
+    {
+        I::avg_pool(
+            input,
+            &[self.pool_shape.0, self.pool_shape.1],
+            &[self.stride.0, self.stride.1],
+            &[self.padding.0, self.padding.1],
+            self.count_include_pad,
+        )
+        .into()
+    }
+}
+
+/// Average pooling operation for 2D spatial data with a fixed output shape.
+///
+/// The `i`-th pool along an axis of length `len` pooled to `output` spans the elements from
+/// `floor(i * len / output)` up to `ceil((i + 1) * len / output)`, so pools may overlap when the
+/// input is not a multiple of the output. The gradient of each pool is spread uniformly over its
+/// elements.
+pub struct AdaptiveAvgPool2d {
+    pub output_shape: (usize, usize),
+}
+
+impl AdaptiveAvgPool2d {
+    /// Creates an AdaptiveAvgPool2d layer.
+    ///
+    /// # Arguments
+    ///
+    /// `output_shape` - height and width of the output, a 2-tuple for this two-dimensional case.
+    pub fn new(output_shape: (usize, usize)) -> Self {
+        Self { output_shape }
+    }
+
+    /// Applies the pooling to the variable in input.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - variable in input to the layer.
+    pub fn forward<I, T, U>(
+        &self,
+        input: I,
+    ) -> VarDiff<impl Data<Dim = Ix4>, impl Gradient<Dim = Ix4>>
+    where
+        I: AdaptiveAvgPooling<I>,
+        I::Output: Into<VarDiff<T, U>>,
+        T: Data<Dim = Ix4> + 'static,
+        U: Gradient<Dim = Ix4> + 'static,
+    {
+        I::adaptive_avg_pool(input, &[self.output_shape.0, self.output_shape.1]).into()
+    }
+}
+
+/// Max pooling operation for 2D spatial data with a fixed output shape.
+///
+/// Pools are computed as in [`AdaptiveAvgPool2d`]. The gradient of each pool flows to its
+/// maximum, accumulating over overlapping pools.
+pub struct AdaptiveMaxPool2d {
+    pub output_shape: (usize, usize),
+}
+
+impl AdaptiveMaxPool2d {
+    /// Creates an AdaptiveMaxPool2d layer.
+    ///
+    /// # Arguments
+    ///
+    /// `output_shape` - height and width of the output, a 2-tuple for this two-dimensional case.
+    pub fn new(output_shape: (usize, usize)) -> Self {
+        Self { output_shape }
+    }
+
+    /// Applies the pooling to the variable in input.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - variable in input to the layer.
+    pub fn forward<I, T, U>(
+        &self,
+        input: I,
+    ) -> VarDiff<impl Data<Dim = Ix4>, impl Gradient<Dim = Ix4>>
+    where
+        I: AdaptiveMaxPooling<I>,
+        I::Output: Into<VarDiff<T, U>>,
+        T: Data<Dim = Ix4> + 'static,
+        U: Gradient<Dim = Ix4> + 'static,
+    {
+        I::adaptive_max_pool(input, &[self.output_shape.0, self.output_shape.1]).into()
+    }
+}
+
+/// Averages each channel of a 2D spatial input over its whole extent.
+///
+/// An input of shape *(N, C, H, W)* results in an output of shape *(N, C)*.
+#[derive(Default)]
+pub struct GlobalAvgPool;
+
+impl GlobalAvgPool {
+    /// Creates a GlobalAvgPool layer.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Applies the pooling to the variable in input.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - variable in input to the layer.
+    pub fn forward<I, T, U>(
+        &self,
+        input: I,
+    ) -> VarDiff<impl Data<Dim = Ix2>, impl Gradient<Dim = Ix2>>
+    where
+        I: AdaptiveAvgPooling<I>,
+        I::Output: Into<VarDiff<T, U>>,
+        T: Data<Dim = Ix4> + 'static,
+        U: Gradient<Dim = Ix4> + 'static,
+    {
+        let output = I::adaptive_avg_pool(input, &[1, 1]).into();
+        let shape = (output.data().shape()[0], output.data().shape()[1]);
+        output.reshape(shape)
+    }
+}
+
+/// Takes the maximum of each channel of a 2D spatial input over its whole extent.
+///
+/// An input of shape *(N, C, H, W)* results in an output of shape *(N, C)*.
+#[derive(Default)]
+pub struct GlobalMaxPool;
+
+impl GlobalMaxPool {
+    /// Creates a GlobalMaxPool layer.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Applies the pooling to the variable in input.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - variable in input to the layer.
+    pub fn forward<I, T, U>(
+        &self,
+        input: I,
+    ) -> VarDiff<impl Data<Dim = Ix2>, impl Gradient<Dim = Ix2>>
+    where
+        I: AdaptiveMaxPooling<I>,
+        I::Output: Into<VarDiff<T, U>>,
+        T: Data<Dim = Ix4> + 'static,
+        U: Gradient<Dim = Ix4> + 'static,
+    {
+        let output = I::adaptive_max_pool(input, &[1, 1]).into();
+        let shape = (output.data().shape()[0], output.data().shape()[1]);
+        output.reshape(shape)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        init::Init, AdaptiveAvgPool2d, AdaptiveMaxPool2d, AvgPool2d, Constant, Conv2d,
+        GlobalAvgPool, GlobalMaxPool, GroupedConv2d, Linear, MaxPool2d, PaddingMode, Reflective,
+        Replicative, Zero,
+    };
+    use crate::variable::check_gradient;
+    use ndarray::{s, Array};
+
+    #[test]
+    fn linear_new_with() {
+        let linear = Linear::new_with(3, 2, true, Init::Constant(0.5));
+        assert!(linear.weight.data().iter().all(|el| *el == 0.5));
+        assert_eq!(linear.bias.as_ref().unwrap().data().shape(), &[2]);
+        assert_eq!(linear.named_parameters().len(), 2);
+
+        let linear = Linear::new_with(3, 2, false, Init::Zeros);
+        assert!(linear.weight.data().iter().all(|el| *el == 0.));
+        assert!(linear.bias.is_none());
+        assert_eq!(linear.named_parameters().len(), 1);
+    }
+
+    #[test]
+    fn linear_without_bias() {
+        let linear = Linear::new_with(3, 2, false, Init::Normal { mean: 0., std: 1. });
+        let input = crate::rand((4, 3));
+
+        let output = linear.forward(input.clone());
+        output.forward();
+        // Without bias the output is exactly the matrix multiplication.
+        let expected = input.data().dot(&linear.weight.data().t());
+        assert_eq!(*output.data(), expected);
+
+        let loss = output.sum();
+        loss.forward();
+        loss.backward(1.);
+        assert_eq!(loss.parameters().len(), 1);
+        assert_eq!(
+            *linear.weight.grad(),
+            ndarray::Array::ones((2, 4)).dot(&*input.data())
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn grouped_conv2d_weight_shape() {
+        let conv = GroupedConv2d::new(6, 4, (3, 2), (0, 0), Zero, (1, 1), (1, 1), 2);
+        assert_eq!(conv.weight.data().shape(), &[4, 3, 3, 2]);
+        assert_eq!(conv.bias.data().shape(), &[4, 1, 1]);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    #[should_panic(expected = "error: in channels 3 is not divisible by groups 2.")]
+    fn grouped_conv2d_in_channels_not_divisible() {
+        GroupedConv2d::new(3, 4, (3, 3), (0, 0), Zero, (1, 1), (1, 1), 2);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    #[should_panic(expected = "error: out channels 3 is not divisible by groups 2.")]
+    fn grouped_conv2d_out_channels_not_divisible() {
+        GroupedConv2d::new(4, 3, (3, 3), (0, 0), Zero, (1, 1), (1, 1), 2);
+    }
+
+    #[test]
+    fn conv2d_groups() {
+        let conv = Conv2d::new(6, 4, (3, 2), (1, 1), (0, 0), Zero, (1, 1), 2, false);
+        assert_eq!(conv.weight.data().shape(), &[4, 3, 3, 2]);
+        assert!(conv.bias.is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "error: out channels 3 is not divisible by groups 2.")]
+    fn conv2d_out_channels_not_divisible() {
+        Conv2d::new(4, 3, (3, 3), (1, 1), (0, 0), Zero, (1, 1), 2, true);
+    }
+
+    #[test]
+    fn depthwise_conv2d() {
+        let depthwise = Conv2d::new(3, 6, (3, 3), (2, 1), (1, 1), Zero, (1, 1), 3, true);
+        let input = crate::rand((2, 3, 6, 5));
+
+        let output = depthwise.forward(input.clone());
+        output.forward();
+        assert_eq!(output.data().shape(), &[2, 6, 3, 5]);
+
+        // Each channel is convolved independently with its own pair of filters.
+        for channel in 0..3 {
+            let filters = 2 * channel..2 * channel + 2;
+            let conv = Conv2d::new(1, 2, (3, 3), (2, 1), (1, 1), Zero, (1, 1), 1, true);
+            conv.weight
+                .data_mut()
+                .assign(
+                    &depthwise
+                        .weight
+                        .data()
+                        .slice(s![filters.clone(), .., .., ..]),
+                );
+            conv.bias.as_ref().unwrap().data_mut().assign(
+                &depthwise
+                    .bias
+                    .as_ref()
+                    .unwrap()
+                    .data()
+                    .slice(s![filters.clone(), .., ..]),
+            );
+
+            let channel_input = crate::from_ndarray(
+                input
+                    .data()
+                    .slice(s![.., channel..channel + 1, .., ..])
+                    .to_owned(),
+            );
+            let channel_output = conv.forward(channel_input);
+            channel_output.forward();
+
+            let expected = channel_output.data();
+            let actual = output.data();
+            let actual = actual.slice(s![.., filters, .., ..]);
+            assert!(expected
+                .iter()
+                .zip(actual.iter())
+                .all(|(expected, actual)| (expected - actual).abs() <= 1e-6));
+        }
+    }
+
+    fn check_padding_gradient<Pad: PaddingMode + Copy + 'static>(padding_mode: Pad) {
+        for &(padding, groups) in &[((1, 1), 1), ((0, 2), 1), ((2, 0), 2)] {
+            let conv = Conv2d::new(
+                2,
+                2,
+                (3, 2),
+                (1, 1),
+                padding,
+                padding_mode,
+                (1, 1),
+                groups,
+                true,
+            );
+            let input = crate::rand((2, 2, 4, 5)).requires_grad();
+
+            let output_shape = conv.forward(input.clone()).data().raw_dim();
+            let coefficients = Array::from_shape_fn(output_shape, |(n, c, h, w)| {
+                ((n + 2 * c + 3 * h + 5 * w) as f32 * 0.7).sin()
+            });
+
+            let loss = (conv.forward(input.clone()) * crate::from_ndarray(coefficients)).sum();
+            check_gradient(&conv.weight, &loss);
+            check_gradient(&input, &loss);
+        }
+    }
+
+    #[test]
+    fn conv2d_zero_padding_backward() {
+        check_padding_gradient(Zero);
+    }
+
+    #[test]
+    fn conv2d_constant_padding_backward() {
+        check_padding_gradient(Constant::new(0.5));
+    }
+
+    #[test]
+    fn conv2d_reflective_padding_backward() {
+        check_padding_gradient(Reflective);
+    }
+
+    #[test]
+    fn conv2d_replicative_padding_backward() {
+        check_padding_gradient(Replicative);
+    }
+
+    #[test]
+    fn dilated_conv2d() {
+        let conv = Conv2d::new(1, 1, (3, 3), (1, 1), (0, 0), Zero, (2, 2), 1, false);
+        conv.weight
+            .data_mut()
+            .assign(&Array::range(1., 10., 1.).into_shape((1, 1, 3, 3)).unwrap());
+
+        // The dilated kernel spans the whole 5x5 input, touching only the even rows and columns:
+        // 1 * 0 + 2 * 2 + 3 * 4 + 4 * 10 + 5 * 12 + 6 * 14 + 7 * 20 + 8 * 22 + 9 * 24 = 732.
+        let input = Array::range(0., 25., 1.).into_shape((1, 1, 5, 5)).unwrap();
+        let output = conv.forward(crate::from_ndarray(input));
+        output.forward();
+
+        assert_eq!(*output.data(), Array::from_elem((1, 1, 1, 1), 732.));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn grouped_conv2d_backward() {
+        let conv = GroupedConv2d::new(4, 6, (2, 3), (1, 0), Zero, (1, 2), (2, 1), 2);
+        let input = crate::rand((2, 4, 5, 6)).requires_grad();
+        let coefficients = Array::range(0., 120., 1.)
+            .mapv(|el: f32| (el * 0.3).sin())
+            .into_shape((2, 6, 5, 2))
+            .unwrap();
+
+        let loss = (conv.forward(input.clone()) * crate::from_ndarray(coefficients)).sum();
+        check_gradient(&conv.weight, &loss);
+        check_gradient(&conv.bias, &loss);
+        check_gradient(&input, &loss);
+    }
+
+    #[test]
+    fn max_pool2d_overlapping() {
+        let pool = MaxPool2d::new((2, 2), (1, 1), (0, 0));
+        let input = crate::from_ndarray(
+            Array::from_shape_vec((1, 1, 3, 3), vec![1., 2., 3., 4., 9., 5., 6., 7., 8.]).unwrap(),
+        )
+        .requires_grad();
+
+        let output = pool.forward(input.clone());
+        let loss = (output.clone()
+            * crate::from_ndarray(
+                Array::from_shape_vec((1, 1, 2, 2), vec![1., 2., 3., 4.]).unwrap(),
+            ))
+        .sum();
+        loss.forward();
+        loss.backward(1.);
+
+        // The center wins all the pools and gathers all of their gradients.
+        assert_eq!(*output.data(), Array::from_elem((1, 1, 2, 2), 9.));
+        assert_eq!(
+            *input.grad(),
+            Array::from_shape_vec((1, 1, 3, 3), vec![0., 0., 0., 0., 10., 0., 0., 0., 0.]).unwrap()
+        );
+    }
+
+    #[test]
+    fn max_pool2d_with_indices() {
+        let pool = MaxPool2d::new((2, 2), (2, 2), (1, 1));
+        let input = Array::from_shape_vec(
+            (1, 1, 3, 4),
+            vec![-1., -5., 3., 0., 7., -2., -3., 4., 1., 8., 2., -6.],
+        )
+        .unwrap();
+
+        let (output, indices) =
+            pool.forward_with_indices(crate::from_ndarray(input).requires_grad());
+        output.forward();
+
+        assert_eq!(
+            *output.data(),
+            Array::from_shape_vec((1, 1, 2, 3), vec![-1., 3., 0., 7., 8., 4.]).unwrap()
+        );
+        assert_eq!(
+            *indices.indices(),
+            Array::from_shape_vec((1, 1, 2, 3), vec![0, 2, 3, 4, 9, 7]).unwrap()
+        );
+    }
+
+    #[test]
+    fn avg_pool2d() {
+        let input =
+            crate::from_ndarray(Array::from_shape_vec((1, 1, 2, 2), vec![1., 2., 3., 4.]).unwrap())
+                .requires_grad();
+
+        // Each pool covers a single element of the input and three padded zeros.
+        for (count_include_pad, expected, expected_grad) in [
+            (true, [0.25, 0.5, 0.75, 1.], 0.25),
+            (false, [1., 2., 3., 4.], 1.),
+        ] {
+            let pool = AvgPool2d::new((2, 2), (2, 2), (1, 1), count_include_pad);
+            let output = pool.forward(input.clone());
+            let loss = output.clone().sum();
+            loss.forward();
+            input.zero_grad();
+            loss.backward(1.);
+
+            assert_eq!(
+                *output.data(),
+                Array::from_shape_vec((1, 1, 2, 2), expected.to_vec()).unwrap()
+            );
+            assert_eq!(*input.grad(), Array::from_elem((1, 1, 2, 2), expected_grad));
+        }
+    }
+
+    #[test]
+    fn adaptive_pool2d() {
+        let input = crate::from_ndarray(
+            Array::from_shape_vec((1, 1, 7, 7), (0..49).map(|el| el as f32).collect()).unwrap(),
+        )
+        .requires_grad();
+
+        // Pools span rows and columns [0, 3), [2, 5) and [4, 7).
+        let output = AdaptiveAvgPool2d::new((3, 3)).forward(input.clone());
+        let loss = output.clone().sum();
+        loss.forward();
+        // Seeding with 9 gives a unit gradient to each element of a 3 x 3 pool.
+        loss.backward(9.);
+
+        assert_eq!(
+            *output.data(),
+            Array::from_shape_vec(
+                (1, 1, 3, 3),
+                vec![8., 10., 12., 22., 24., 26., 36., 38., 40.]
+            )
+            .unwrap()
+        );
+
+        // Rows and columns 2 and 4 belong to two pools each.
+        let row = Array::from_shape_vec((7, 1), vec![1., 1., 2., 1., 2., 1., 1.]).unwrap();
+        let expected = &row * &row.t();
+        assert_eq!(*input.grad(), expected.into_shape((1, 1, 7, 7)).unwrap());
+
+        let output = AdaptiveMaxPool2d::new((3, 3)).forward(input.clone());
+        let loss = output.clone().sum();
+        loss.forward();
+        input.zero_grad();
+        loss.backward(1.);
+
+        assert_eq!(
+            *output.data(),
+            Array::from_shape_vec(
+                (1, 1, 3, 3),
+                vec![16., 18., 20., 30., 32., 34., 44., 46., 48.]
+            )
+            .unwrap()
+        );
+        let mut expected = Array::zeros((1, 1, 7, 7));
+        for r in [2, 4, 6] {
+            for c in [2, 4, 6] {
+                expected[[0, 0, r, c]] = 1.;
+            }
+        }
+        assert_eq!(*input.grad(), expected);
+    }
+
+    #[test]
+    fn global_pool() {
+        let input = crate::from_ndarray(
+            Array::from_shape_vec((2, 3, 2, 2), (0..24).map(|el| el as f32).collect()).unwrap(),
+        )
+        .requires_grad();
+
+        let output = GlobalAvgPool::new().forward(input.clone());
+        let loss = output.clone().sum();
+        loss.forward();
+        loss.backward(1.);
+
+        assert_eq!(
+            *output.data(),
+            Array::from_shape_vec((2, 3), vec![1.5, 5.5, 9.5, 13.5, 17.5, 21.5]).unwrap()
+        );
+        assert_eq!(*input.grad(), Array::from_elem((2, 3, 2, 2), 0.25));
+
+        let output = GlobalMaxPool::new().forward(input.clone());
+        let loss = output.clone().sum();
+        loss.forward();
+        input.zero_grad();
+        loss.backward(1.);
+
+        assert_eq!(
+            *output.data(),
+            Array::from_shape_vec((2, 3), vec![3., 7., 11., 15., 19., 23.]).unwrap()
+        );
+        let mut expected = Array::zeros((2, 3, 2, 2));
+        expected.slice_mut(ndarray::s![.., .., 1, 1]).fill(1.);
+        assert_eq!(*input.grad(), expected);
+    }
+}
@@ -0,0 +1,390 @@
+use super::{init, named, scaled_dot_product_attention, AttentionMask, Learnable, Register};
+use crate::variable::{AnyVarDiff, Data, Gradient, Input, RawParam, Tensor, VarDiff};
+use ndarray::{Ix2, Ix3};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{cell::Cell, rc::Rc};
+
+/// Applies a **multi-head attention** of a batch of queries over a batch of keys and values.
+///
+/// The queries, the keys and the values are linearly projected by `weight_q`, `weight_k` and
+/// `weight_v`, then split in `num_heads` heads of `embed_dim / num_heads` features each. Every
+/// head attends independently by means of [`scaled_dot_product_attention`], the results are
+/// concatenated back and projected by `weight_o`.
+///
+/// ```text
+/// head_i = attention(Q Wqᵢᵀ, K Wkᵢᵀ, V Wvᵢᵀ)
+/// output = concat(head_1, ..., head_h) Woᵀ
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use neuronika::nn::MultiHeadAttention;
+///
+/// let attention = MultiHeadAttention::new(8, 2);
+/// let input = neuronika::rand((3, 5, 8)).requires_grad();
+///
+/// // Self-attention.
+/// let output = attention.forward(input.clone(), input.clone(), input, None);
+/// output.forward();
+/// assert_eq!(output.data().shape(), &[3, 5, 8]);
+/// ```
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct MultiHeadAttention {
+    pub num_heads: usize,
+    pub weight_q: Learnable<Ix2>,
+    pub weight_k: Learnable<Ix2>,
+    pub weight_v: Learnable<Ix2>,
+    pub weight_o: Learnable<Ix2>,
+}
+
+impl MultiHeadAttention {
+    /// Creates a new MultiHeadAttention.
+    ///
+    /// # Arguments
+    ///
+    /// * `embed_dim` - number of features of the queries, the keys, the values and the output.
+    ///
+    /// * `num_heads` - number of parallel attention heads, `embed_dim` must be divisible by it.
+    ///
+    /// All the projection matrices are of shape *(embed_dim, embed_dim)* and are initialized from
+    /// *U(-k, k)* where `k = (1. / embed_dim as f32).sqrt()`.
+    ///
+    /// # Panics
+    ///
+    /// If `num_heads` is zero or if `embed_dim` is not divisible by `num_heads`.
+    pub fn new(embed_dim: usize, num_heads: usize) -> Self {
+        assert!(
+            num_heads > 0,
+            "error: the number of heads must be positive."
+        );
+        assert_eq!(
+            embed_dim % num_heads,
+            0,
+            "error: embed_dim {} is not divisible by num_heads {}.",
+            embed_dim,
+            num_heads
+        );
+
+        let k = (1. / embed_dim as f32).sqrt();
+        let projection = || {
+            let weight = Input::new(Tensor::zeros((embed_dim, embed_dim))).requires_grad();
+            init::uniform(&weight, -k, k);
+            weight
+        };
+
+        Self {
+            num_heads,
+            weight_q: projection(),
+            weight_k: projection(),
+            weight_v: projection(),
+            weight_o: projection(),
+        }
+    }
+
+    /// Computes the multi-head attention of `query` over `key` and `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - queries of shape *(N, L, E)*.
+    ///
+    /// * `key` - keys of shape *(N, S, E)*.
+    ///
+    /// * `value` - values of shape *(N, S, E)*.
+    ///
+    /// * `mask` - optional mask shared by all the heads, either an additive mask of shape
+    /// *(L, S)* or a key-padding mask of shape *(N, S)*. See [`AttentionMask`] for more details.
+    ///
+    /// The resulting output shape will be *(N, L, E)*, the same as `query`.
+    pub fn forward<T1, U1, T2, U2, T3, U3>(
+        &self,
+        query: VarDiff<T1, U1>,
+        key: VarDiff<T2, U2>,
+        value: VarDiff<T3, U3>,
+        mask: Option<AttentionMask>,
+    ) -> VarDiff<impl Data<Dim = Ix3>, impl Gradient<Dim = Ix3>>
+    where
+        T1: Data<Dim = Ix3> + 'static,
+        U1: Gradient<Dim = Ix3> + 'static,
+        T2: Data<Dim = Ix3> + 'static,
+        U2: Gradient<Dim = Ix3> + 'static,
+        T3: Data<Dim = Ix3> + 'static,
+        U3: Gradient<Dim = Ix3> + 'static,
+    {
+        let (batch_size, target_len, embed_dim) = query.data().dim();
+
+        let query = self.split_heads(project(query, &self.weight_q));
+        let key = self.split_heads(project(key, &self.weight_k));
+        let value = self.split_heads(project(value, &self.weight_v));
+
+        // The heads of each batch are laid out contiguously, as many batches of the attention.
+        let mask = mask.map(|mask| mask.repeat_batches(self.num_heads));
+        let heads = scaled_dot_product_attention(query, key, value, mask)
+            .reshape((
+                batch_size,
+                self.num_heads,
+                target_len,
+                embed_dim / self.num_heads,
+            ))
+            .permute((0, 2, 1, 3))
+            .reshape((batch_size, target_len, embed_dim));
+
+        project(heads, &self.weight_o)
+    }
+
+    /// Splits the features of `input`, of shape *(N, L, E)*, in `num_heads` heads, returning a
+    /// variable of shape *(N · num_heads, L, E / num_heads)*.
+    fn split_heads<T, U>(
+        &self,
+        input: VarDiff<T, U>,
+    ) -> VarDiff<impl Data<Dim = Ix3>, impl Gradient<Dim = Ix3>>
+    where
+        T: Data<Dim = Ix3> + 'static,
+        U: Gradient<Dim = Ix3> + 'static,
+    {
+        let (batch_size, len, embed_dim) = input.data().dim();
+        let head_dim = embed_dim / self.num_heads;
+
+        input
+            .reshape((batch_size, len, self.num_heads, head_dim))
+            .permute((0, 2, 1, 3))
+            .reshape((batch_size * self.num_heads, len, head_dim))
+    }
+
+    /// Returns the projection matrices of this `MultiHeadAttention` instance, paired with their
+    /// names.
+    pub fn named_parameters(&self) -> Vec<(String, AnyVarDiff)> {
+        vec![
+            named("weight_q", &self.weight_q),
+            named("weight_k", &self.weight_k),
+            named("weight_v", &self.weight_v),
+            named("weight_o", &self.weight_o),
+        ]
+    }
+}
+
+/// Multiplies each of the feature vectors of `input`, of shape *(N, L, E)*, by the transpose of
+/// `weight`.
+fn project<T, U>(
+    input: VarDiff<T, U>,
+    weight: &Learnable<Ix2>,
+) -> VarDiff<impl Data<Dim = Ix3>, impl Gradient<Dim = Ix3>>
+where
+    T: Data<Dim = Ix3> + 'static,
+    U: Gradient<Dim = Ix3> + 'static,
+{
+    let (batch_size, len, in_features) = input.data().dim();
+    let out_features = weight.data().shape()[0];
+
+    input
+        .reshape((batch_size * len, in_features))
+        .mm_t(weight.clone())
+        .reshape((batch_size, len, out_features))
+}
+
+impl Register for MultiHeadAttention {
+    /// Registers the projection matrices of this `MultiHeadAttention` instance.
+    fn register_params(&self, params: &mut Vec<RawParam>) {
+        self.weight_q.register_params(params);
+        self.weight_k.register_params(params);
+        self.weight_v.register_params(params);
+        self.weight_o.register_params(params);
+    }
+
+    fn register_status(&mut self, _: Rc<Cell<bool>>) {}
+}
+
+#[cfg(test)]
+mod test {
+    use super::{super::scaled_dot_product_attention, MultiHeadAttention};
+    use ndarray::{array, s, Array};
+
+    #[test]
+    fn creation() {
+        let attention = MultiHeadAttention::new(6, 3);
+
+        assert_eq!(attention.num_heads, 3);
+        assert_eq!(attention.weight_q.data().shape(), &[6, 6]);
+        assert_eq!(attention.weight_o.data().shape(), &[6, 6]);
+        assert_eq!(attention.named_parameters().len(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "error: embed_dim 6 is not divisible by num_heads 4.")]
+    fn creation_not_divisible() {
+        MultiHeadAttention::new(6, 4);
+    }
+
+    #[test]
+    fn forward() {
+        let attention = MultiHeadAttention::new(8, 4);
+        let query = crate::rand((2, 3, 8)).requires_grad();
+        let key = crate::rand((2, 5, 8)).requires_grad();
+
+        let output = attention.forward(query, key.clone(), key, None);
+        output.forward();
+        assert_eq!(output.data().shape(), &[2, 3, 8]);
+    }
+
+    #[test]
+    fn single_head() {
+        let attention = MultiHeadAttention::new(4, 1);
+        let eye = Array::eye(4);
+        attention.weight_q.data_mut().assign(&eye);
+        attention.weight_k.data_mut().assign(&eye);
+        attention.weight_v.data_mut().assign(&eye);
+        attention.weight_o.data_mut().assign(&eye);
+
+        let (query, key) = (
+            crate::rand((2, 3, 4)).requires_grad(),
+            crate::rand((2, 5, 4)).requires_grad(),
+        );
+        let mask = Array::from_shape_fn((3, 5), |(i, j)| if j > i { -1e9 } else { 0. });
+
+        // With identity projections a single head reduces to the plain attention.
+        let output = attention.forward(
+            query.clone(),
+            key.clone(),
+            key.clone(),
+            Some(mask.clone().into()),
+        );
+        output.forward();
+        let expected = scaled_dot_product_attention(
+            query.clone(),
+            key.clone(),
+            key.clone(),
+            Some(mask.into()),
+        );
+        expected.forward();
+
+        assert!(output
+            .data()
+            .iter()
+            .zip(expected.data().iter())
+            .all(|(actual, expected)| (actual - expected).abs() <= 1e-6));
+
+        // The same holds for a key-padding mask.
+        let padding = array![
+            [false, false, false, true, true],
+            [false, true, false, false, false]
+        ];
+        let output = attention.forward(
+            query.clone(),
+            key.clone(),
+            key.clone(),
+            Some(padding.clone().into()),
+        );
+        output.forward();
+        let expected = scaled_dot_product_attention(query, key.clone(), key, Some(padding.into()));
+        expected.forward();
+
+        assert!(output
+            .data()
+            .iter()
+            .zip(expected.data().iter())
+            .all(|(actual, expected)| (actual - expected).abs() <= 1e-6));
+    }
+
+    #[test]
+    fn key_padding() {
+        let attention = MultiHeadAttention::new(6, 3);
+        let query = crate::rand((2, 3, 6)).requires_grad();
+        let (key, other_key) = (crate::rand((2, 4, 6)), crate::rand((2, 4, 6)));
+        let padding = array![[false, false, true, true], [false, false, false, true]];
+
+        // Changing the padded keys and values doesn't change the output of any head.
+        let mut other_key = other_key.data().to_owned();
+        for (batch, mut sample) in other_key.outer_iter_mut().enumerate() {
+            for (position, mut features) in sample.outer_iter_mut().enumerate() {
+                if !padding[[batch, position]] {
+                    features.assign(&key.data().slice(s![batch, position, ..]));
+                }
+            }
+        }
+
+        let output = attention.forward(
+            query.clone(),
+            key.clone().requires_grad(),
+            key.requires_grad(),
+            Some(padding.clone().into()),
+        );
+        let other_key = crate::from_ndarray(other_key);
+        let other_output = attention.forward(
+            query,
+            other_key.clone().requires_grad(),
+            other_key.requires_grad(),
+            Some(padding.into()),
+        );
+        output.forward();
+        other_output.forward();
+
+        assert!(output
+            .data()
+            .iter()
+            .zip(other_output.data().iter())
+            .all(|(actual, expected)| (actual - expected).abs() <= 1e-6));
+    }
+
+    #[test]
+    fn split_heads() {
+        let attention = MultiHeadAttention::new(4, 2);
+        let eye = Array::eye(4);
+        attention.weight_q.data_mut().assign(&eye);
+        attention.weight_k.data_mut().assign(&eye);
+        attention.weight_v.data_mut().assign(&eye);
+        attention.weight_o.data_mut().assign(&eye);
+
+        let (query, key) = (crate::rand((2, 3, 4)), crate::rand((2, 5, 4)));
+        let output = attention.forward(
+            query.clone().requires_grad(),
+            key.clone().requires_grad(),
+            key.clone().requires_grad(),
+            None,
+        );
+        output.forward();
+
+        // Each head attends over its own slice of the features.
+        for head in 0..2 {
+            let features = s![.., .., 2 * head..2 * head + 2];
+            let head_query = crate::from_ndarray(query.data().slice(features).to_owned());
+            let head_key = crate::from_ndarray(key.data().slice(features).to_owned());
+            let expected = scaled_dot_product_attention(
+                head_query.requires_grad(),
+                head_key.clone().requires_grad(),
+                head_key.requires_grad(),
+                None,
+            );
+            expected.forward();
+
+            assert!(output
+                .data()
+                .slice(features)
+                .iter()
+                .zip(expected.data().iter())
+                .all(|(actual, expected)| (actual - expected).abs() <= 1e-6));
+        }
+    }
+
+    #[test]
+    fn backward() {
+        let attention = MultiHeadAttention::new(6, 2);
+        let input = crate::rand((2, 4, 6)).requires_grad();
+
+        let output = attention.forward(input.clone(), input.clone(), input, None);
+        let loss = (output * crate::rand((2, 4, 6))).sum();
+        loss.forward();
+        loss.backward(1.);
+
+        assert_eq!(loss.parameters().len(), 5);
+        for weight in [
+            &attention.weight_q,
+            &attention.weight_k,
+            &attention.weight_v,
+            &attention.weight_o,
+        ] {
+            assert_eq!(weight.grad().shape(), &[6, 6]);
+            assert!(weight.grad().iter().any(|el| *el != 0.));
+        }
+    }
+}
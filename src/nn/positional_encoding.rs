@@ -0,0 +1,185 @@
+use super::Register;
+use crate::variable::{Data, Gradient, Input, RawParam, Tensor, Var, VarDiff};
+use ndarray::{s, Ix2, Ix3};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{cell::Cell, rc::Rc};
+
+/// Adds the **sinusoidal positional encoding** to a batch of sequences of embeddings.
+///
+/// The encoding of the position *pos* along the feature *j* is
+///
+/// ```text
+/// PE(pos, 2i)     = sin(pos / 10000^(2i / embed_dim))
+/// PE(pos, 2i + 1) = cos(pos / 10000^(2i / embed_dim))
+/// ```
+///
+/// The table is computed once, at construction, for all the positions up to `max_seq_len` and
+/// is not learnable.
+///
+/// # Examples
+///
+/// ```
+/// use neuronika::nn::PositionalEncoding;
+///
+/// let encoding = PositionalEncoding::new(100, 8);
+///
+/// // A batch of 4 sequences of 10 embeddings each.
+/// let output = encoding.forward(neuronika::rand((4, 10, 8)).requires_grad());
+/// output.forward();
+/// assert_eq!(output.data().shape(), &[4, 10, 8]);
+/// ```
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct PositionalEncoding {
+    pub encoding: Var<Input<Ix2>>,
+}
+
+impl PositionalEncoding {
+    /// Creates a new PositionalEncoding.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_seq_len` - maximum length of the sequences the encoding can be added to.
+    ///
+    /// * `embed_dim` - number of features of each embedding.
+    ///
+    /// The encoding table is of shape *(max_seq_len, embed_dim)*.
+    pub fn new(max_seq_len: usize, embed_dim: usize) -> Self {
+        let encoding = Tensor::from_shape_fn((max_seq_len, embed_dim), |(pos, j)| {
+            let exponent = (2 * (j / 2)) as f32 / embed_dim as f32;
+            let angle = pos as f32 / 10_000_f32.powf(exponent);
+
+            if j % 2 == 0 {
+                angle.sin()
+            } else {
+                angle.cos()
+            }
+        });
+
+        Self {
+            encoding: Input::new(encoding),
+        }
+    }
+
+    /// Adds the encoding of the first *L* positions to the incoming embeddings.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - embeddings of shape *(N, L, embed_dim)*, the output has the same shape.
+    ///
+    /// # Panics
+    ///
+    /// If *L* exceeds `max_seq_len` or if the number of features of the input doesn't match
+    /// `embed_dim`.
+    pub fn forward<T, U>(
+        &self,
+        input: VarDiff<T, U>,
+    ) -> VarDiff<impl Data<Dim = Ix3>, impl Gradient<Dim = Ix3>>
+    where
+        T: Data<Dim = Ix3> + 'static,
+        U: Gradient<Dim = Ix3> + 'static,
+    {
+        let (_, seq_len, embed_dim) = input.data().dim();
+        let (max_seq_len, features) = self.encoding.data().dim();
+        assert!(
+            seq_len <= max_seq_len,
+            "error: sequence length {} exceeds the maximum length {}.",
+            seq_len,
+            max_seq_len
+        );
+        assert_eq!(
+            embed_dim, features,
+            "error: the input has {} features but the encoding has {}.",
+            embed_dim, features
+        );
+
+        let encoding = self.encoding.data().slice(s![..seq_len, ..]).to_owned();
+        input + crate::from_ndarray(encoding)
+    }
+}
+
+impl Register for PositionalEncoding {
+    fn register_params(&self, _: &mut Vec<RawParam>) {}
+
+    fn register_status(&mut self, _: Rc<Cell<bool>>) {}
+}
+
+#[cfg(test)]
+mod test {
+    use super::PositionalEncoding;
+    use ndarray::{s, Axis};
+
+    #[test]
+    fn creation() {
+        let encoding = PositionalEncoding::new(50, 6);
+
+        assert_eq!(encoding.encoding.data().shape(), &[50, 6]);
+        assert!(encoding
+            .encoding
+            .data()
+            .iter()
+            .all(|el| (-1. ..=1.).contains(el)));
+    }
+
+    #[test]
+    fn first_position() {
+        let encoding = PositionalEncoding::new(10, 8);
+        let data = encoding.encoding.data();
+        let first = data.index_axis(Axis(0), 0);
+
+        // The sine terms vanish and the cosine ones are one.
+        assert!(first.slice(s![..;2]).iter().all(|el| *el == 0.));
+        assert!(first.slice(s![1..;2]).iter().all(|el| *el == 1.));
+    }
+
+    #[test]
+    fn formula() {
+        let encoding = PositionalEncoding::new(10, 4);
+        let data = encoding.encoding.data();
+
+        for pos in 1..4 {
+            let pos_f = pos as f32;
+            let expected = [
+                pos_f.sin(),
+                pos_f.cos(),
+                (pos_f / 100.).sin(),
+                (pos_f / 100.).cos(),
+            ];
+            assert!(data
+                .index_axis(Axis(0), pos)
+                .iter()
+                .zip(expected.iter())
+                .all(|(actual, expected)| (actual - expected).abs() <= 1e-6));
+        }
+    }
+
+    #[test]
+    fn forward() {
+        let encoding = PositionalEncoding::new(10, 4);
+        let input = crate::rand((3, 6, 4)).requires_grad();
+
+        let output = encoding.forward(input.clone());
+        output.forward();
+        assert_eq!(output.data().shape(), &[3, 6, 4]);
+
+        let expected = &*input.data() + &encoding.encoding.data().slice(s![..6, ..]);
+        assert!(output
+            .data()
+            .iter()
+            .zip(expected.iter())
+            .all(|(actual, expected)| (actual - expected).abs() <= 1e-6));
+
+        // The encoding is not learnable.
+        let loss = output.sum();
+        loss.forward();
+        loss.backward(1.);
+        assert_eq!(loss.parameters().len(), 1);
+        assert!(input.grad().iter().all(|el| *el == 1.));
+    }
+
+    #[test]
+    #[should_panic(expected = "error: sequence length 11 exceeds the maximum length 10.")]
+    fn sequence_too_long() {
+        PositionalEncoding::new(10, 4).forward(crate::rand((1, 11, 4)).requires_grad());
+    }
+}
@@ -0,0 +1,582 @@
+use super::{init, named, Learnable, Register};
+use crate::variable::{
+    AnyVarDiff, Data, Gradient, Input, MatMatMulT, RawParam, Tensor, Var, VarDiff,
+};
+use ndarray::{Ix1, Ix2, Ix3};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{cell::Cell, rc::Rc};
+
+/// A differentiable matrix of unknown type, such as the state of a recurrent layer at some
+/// time step.
+pub(super) type Step = VarDiff<dyn Data<Dim = Ix2>, dyn Gradient<Dim = Ix2>>;
+
+/// Sequences that can be fed to a recurrent layer, either differentiable or not.
+pub trait RecurrentInput {
+    /// Splits `self`, of shape *(seq, batch, features)*, in its time steps and multiplies each of
+    /// them by the transpose of `weight`.
+    fn project_steps(self, weight: &Learnable<Ix2>) -> Vec<Step>;
+}
+
+impl<T: ?Sized> RecurrentInput for Var<T>
+where
+    T: Data<Dim = Ix3> + 'static,
+{
+    fn project_steps(self, weight: &Learnable<Ix2>) -> Vec<Step> {
+        let seq_len = self.data().len_of(ndarray::Axis(0));
+        (0..seq_len)
+            .map(|t| {
+                self.clone()
+                    .narrow(0, t, 1)
+                    .squeeze(0)
+                    .mm_t(weight.clone())
+                    .into_dyn()
+            })
+            .collect()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> RecurrentInput for VarDiff<T, U>
+where
+    T: Data<Dim = Ix3> + 'static,
+    U: Gradient<Dim = Ix3> + 'static,
+{
+    fn project_steps(self, weight: &Learnable<Ix2>) -> Vec<Step> {
+        let seq_len = self.data().len_of(ndarray::Axis(0));
+        (0..seq_len)
+            .map(|t| {
+                self.clone()
+                    .narrow(0, t, 1)
+                    .squeeze(0)
+                    .mm_t(weight.clone())
+                    .into_dyn()
+            })
+            .collect()
+    }
+}
+
+impl RecurrentInput for Vec<Step> {
+    fn project_steps(self, weight: &Learnable<Ix2>) -> Vec<Step> {
+        self.into_iter()
+            .map(|step| step.mm_t(weight.clone()).into_dyn())
+            .collect()
+    }
+}
+
+/// The weights and the biases of a single layer of a recurrent network.
+///
+/// The weights of the gates of the layer are stacked along the first axis of `weight_ih` and
+/// `weight_hh`, and so are their biases.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct RecurrentLayer {
+    pub weight_ih: Learnable<Ix2>,
+    pub weight_hh: Learnable<Ix2>,
+    pub bias_ih: Option<Learnable<Ix1>>,
+    pub bias_hh: Option<Learnable<Ix1>>,
+}
+
+impl RecurrentLayer {
+    /// Creates the weights and, if `bias` is `true`, the biases of a layer with `gates` gates.
+    ///
+    /// All of them are initialized from *U(-k, k)* where `k = (1. / hidden_size as f32).sqrt()`.
+    pub(super) fn new(input_size: usize, hidden_size: usize, gates: usize, bias: bool) -> Self {
+        let k = 1. / (hidden_size as f32).sqrt();
+        let new_param = |shape| {
+            let param = Input::new(Tensor::zeros(shape)).requires_grad();
+            init::uniform(&param, -k, k);
+            param
+        };
+        let new_bias = || {
+            let bias = Input::new(Tensor::zeros(gates * hidden_size)).requires_grad();
+            init::uniform(&bias, -k, k);
+            bias
+        };
+
+        Self {
+            weight_ih: new_param((gates * hidden_size, input_size)),
+            weight_hh: new_param((gates * hidden_size, hidden_size)),
+            bias_ih: bias.then(new_bias),
+            bias_hh: bias.then(new_bias),
+        }
+    }
+
+    /// Returns the input contribution to the gates at each time step, bias included.
+    pub(super) fn input_gates<I: RecurrentInput>(&self, input: I) -> Vec<Step> {
+        let steps = input.project_steps(&self.weight_ih);
+        match &self.bias_ih {
+            Some(bias) => steps
+                .into_iter()
+                .map(|step| (step + bias.clone()).into_dyn())
+                .collect(),
+            None => steps,
+        }
+    }
+
+    /// Returns the contribution of the hidden state to the gates, bias included.
+    ///
+    /// A missing hidden state stands for a null one, in which case only the bias, if any, is
+    /// returned as a row vector.
+    pub(super) fn hidden_gates(&self, hidden: Option<&State>) -> Option<Step> {
+        match (hidden, &self.bias_hh) {
+            (Some(hidden), Some(bias)) => {
+                Some((hidden.mm_t(&self.weight_hh) + bias.clone()).into_dyn())
+            }
+            (Some(hidden), None) => Some(hidden.mm_t(&self.weight_hh)),
+            (None, Some(bias)) => Some(bias.clone().unsqueeze(0).into_dyn()),
+            (None, None) => None,
+        }
+    }
+
+    /// Returns the weights and the biases of the `index`-th layer, paired with their names.
+    pub(super) fn named_parameters(&self, index: usize) -> Vec<(String, AnyVarDiff)> {
+        let mut params = vec![
+            named(&format!("weight_ih_l{}", index), &self.weight_ih),
+            named(&format!("weight_hh_l{}", index), &self.weight_hh),
+        ];
+        if let Some(bias) = &self.bias_ih {
+            params.push(named(&format!("bias_ih_l{}", index), bias));
+        }
+        if let Some(bias) = &self.bias_hh {
+            params.push(named(&format!("bias_hh_l{}", index), bias));
+        }
+        params
+    }
+}
+
+impl Register for RecurrentLayer {
+    /// Registers the weights and the biases, if any, of this `RecurrentLayer` instance.
+    fn register_params(&self, params: &mut Vec<RawParam>) {
+        self.weight_ih.register_params(params);
+        self.weight_hh.register_params(params);
+        if let Some(bias) = &self.bias_ih {
+            bias.register_params(params);
+        }
+        if let Some(bias) = &self.bias_hh {
+            bias.register_params(params);
+        }
+    }
+
+    fn register_status(&mut self, _: Rc<Cell<bool>>) {}
+}
+
+/// The initial state of the layers of a recurrent network, of shape
+/// *(num_layers, batch, hidden_size)*.
+///
+/// It's built from either a variable or a differentiable variable, in which case the gradient
+/// flows back to it.
+pub enum InitialState {
+    Constant(Var<dyn Data<Dim = Ix3>>),
+    Differentiable(VarDiff<dyn Data<Dim = Ix3>, dyn Gradient<Dim = Ix3>>),
+}
+
+impl InitialState {
+    fn shape(&self) -> Vec<usize> {
+        match self {
+            Self::Constant(state) => state.data().shape().to_vec(),
+            Self::Differentiable(state) => state.data().shape().to_vec(),
+        }
+    }
+
+    /// Returns the state of the `layer`-th layer.
+    fn layer(&self, layer: usize) -> State {
+        match self {
+            Self::Constant(state) => {
+                State::Constant(state.clone().narrow(0, layer, 1).squeeze(0).into_dyn())
+            }
+            Self::Differentiable(state) => {
+                State::Differentiable(state.clone().narrow(0, layer, 1).squeeze(0).into_dyn())
+            }
+        }
+    }
+}
+
+impl<T> From<Var<T>> for InitialState
+where
+    T: Data<Dim = Ix3> + 'static,
+{
+    fn from(state: Var<T>) -> Self {
+        Self::Constant(state.into_dyn())
+    }
+}
+
+impl From<Var<dyn Data<Dim = Ix3>>> for InitialState {
+    fn from(state: Var<dyn Data<Dim = Ix3>>) -> Self {
+        Self::Constant(state)
+    }
+}
+
+impl<T, U> From<VarDiff<T, U>> for InitialState
+where
+    T: Data<Dim = Ix3> + 'static,
+    U: Gradient<Dim = Ix3> + 'static,
+{
+    fn from(state: VarDiff<T, U>) -> Self {
+        Self::Differentiable(state.into_dyn())
+    }
+}
+
+impl From<VarDiff<dyn Data<Dim = Ix3>, dyn Gradient<Dim = Ix3>>> for InitialState {
+    fn from(state: VarDiff<dyn Data<Dim = Ix3>, dyn Gradient<Dim = Ix3>>) -> Self {
+        Self::Differentiable(state)
+    }
+}
+
+/// The state of a layer of a recurrent network at some time step. Only an initial state can be
+/// constant.
+#[derive(Clone)]
+pub(super) enum State {
+    Constant(Var<dyn Data<Dim = Ix2>>),
+    Differentiable(Step),
+}
+
+impl State {
+    /// Multiplies the state by the transpose of `weight`.
+    fn mm_t(&self, weight: &Learnable<Ix2>) -> Step {
+        match self {
+            Self::Constant(state) => MatMatMulT::mm_t(state.clone(), weight.clone()).into_dyn(),
+            Self::Differentiable(state) => state.clone().mm_t(weight.clone()).into_dyn(),
+        }
+    }
+
+    /// Multiplies the state element-wise by `rhs`.
+    pub(super) fn mul(&self, rhs: Step) -> Step {
+        match self {
+            Self::Constant(state) => (state.clone() * rhs).into_dyn(),
+            Self::Differentiable(state) => (state.clone() * rhs).into_dyn(),
+        }
+    }
+}
+
+/// Splits the initial state of a recurrent network, of shape *(num_layers, batch, hidden_size)*,
+/// in the initial states of its layers.
+///
+/// # Panics
+///
+/// If the state has not one entry per layer or doesn't have `hidden_size` features.
+pub(super) fn initial_states(
+    hidden: Option<InitialState>,
+    num_layers: usize,
+    hidden_size: usize,
+) -> Vec<Option<State>> {
+    match hidden {
+        Some(hidden) => {
+            let shape = hidden.shape();
+            assert!(
+                shape[0] == num_layers && shape[2] == hidden_size,
+                "error: initial state of shape {:?} for {} layers with {} features.",
+                shape,
+                num_layers,
+                hidden_size
+            );
+            (0..num_layers)
+                .map(|layer| Some(hidden.layer(layer)))
+                .collect()
+        }
+        None => vec![None; num_layers],
+    }
+}
+
+/// Stacks the states in a single variable, along a new first axis.
+pub(super) fn stack_steps(
+    steps: &[Step],
+) -> VarDiff<impl Data<Dim = Ix3>, impl Gradient<Dim = Ix3>> {
+    assert!(!steps.is_empty(), "error: the input sequence is empty.");
+    VarDiff::stack(steps, 0)
+}
+
+/// Non-linearity applied by the [`RNN`] layer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub enum Nonlinearity {
+    /// The hyperbolic tangent.
+    Tanh,
+    /// The rectified linear unit.
+    ReLU,
+}
+
+/// A multi-layer **Elman recurrent neural network (RNN)**.
+///
+/// For each time step *t* each layer computes
+///
+/// ```text
+/// hₜ = σ(Wᵢₕxₜ + bᵢₕ + Wₕₕhₜ₋₁ + bₕₕ)
+/// ```
+///
+/// where *σ* is either the hyperbolic tangent or the ReLU, *xₜ* is the input of the first layer
+/// or the output of the previous layer and *h₀* is the initial state of the layer. The
+/// computational graph is unrolled over the whole sequence at each call of
+/// [`.forward()`](RNN::forward()).
+///
+/// # Examples
+///
+/// ```
+/// use neuronika::nn::{Nonlinearity, RNN};
+///
+/// // Sequences of 6 steps, in batches of 4, with 5 features each.
+/// let rnn = RNN::new(5, 3, 2, Nonlinearity::Tanh, true);
+/// let (output, hidden) = rnn.forward(neuronika::rand((6, 4, 5)), None);
+///
+/// output.forward();
+/// hidden.forward();
+/// assert_eq!(output.data().shape(), &[6, 4, 3]);
+/// assert_eq!(hidden.data().shape(), &[2, 4, 3]);
+/// ```
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[allow(clippy::upper_case_acronyms)]
+pub struct RNN {
+    pub layers: Vec<RecurrentLayer>,
+    pub nonlinearity: Nonlinearity,
+    hidden_size: usize,
+}
+
+impl RNN {
+    /// Creates a new RNN.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_size` - number of expected features in the input.
+    ///
+    /// * `hidden_size` - number of features in the hidden state.
+    ///
+    /// * `num_layers` - number of stacked layers, each one fed with the output of the previous
+    /// one.
+    ///
+    /// * `nonlinearity` - non-linearity applied at each step.
+    ///
+    /// * `bias` - whether the layers have biases.
+    ///
+    /// All the weight and biases are initialized from *U(-k, k)* where
+    /// `k = (1. / hidden_size as f32).sqrt()`.
+    ///
+    /// # Panics
+    ///
+    /// If `num_layers` is zero.
+    pub fn new(
+        input_size: usize,
+        hidden_size: usize,
+        num_layers: usize,
+        nonlinearity: Nonlinearity,
+        bias: bool,
+    ) -> Self {
+        assert!(
+            num_layers > 0,
+            "error: the number of layers must be positive."
+        );
+
+        let layers = (0..num_layers)
+            .map(|layer| {
+                let input_size = if layer == 0 { input_size } else { hidden_size };
+                RecurrentLayer::new(input_size, hidden_size, 1, bias)
+            })
+            .collect();
+
+        Self {
+            layers,
+            nonlinearity,
+            hidden_size,
+        }
+    }
+
+    /// Runs the network over a sequence.
+    ///
+    /// * `input` - a variable of shape *(seq, batch, input_size)*.
+    ///
+    /// * `hidden` - an optional variable of shape *(num_layers, batch, hidden_size)* holding the
+    /// initial state of each layer, either differentiable or not. A null state is used when it's
+    /// `None`.
+    ///
+    /// Returns the output of the last layer at each time step, of shape
+    /// *(seq, batch, hidden_size)*, and the final state of each layer, of shape
+    /// *(num_layers, batch, hidden_size)*.
+    ///
+    /// # Panics
+    ///
+    /// If the input sequence is empty or the initial state has the wrong shape.
+    #[allow(clippy::type_complexity)]
+    pub fn forward<I: RecurrentInput>(
+        &self,
+        input: I,
+        hidden: Option<InitialState>,
+    ) -> (
+        VarDiff<impl Data<Dim = Ix3>, impl Gradient<Dim = Ix3>>,
+        VarDiff<impl Data<Dim = Ix3>, impl Gradient<Dim = Ix3>>,
+    ) {
+        let initial_states = initial_states(hidden, self.layers.len(), self.hidden_size);
+
+        let (mut input, mut steps) = (Some(input), Vec::new());
+        let mut final_states = Vec::with_capacity(self.layers.len());
+        for (layer, mut state) in self.layers.iter().zip(initial_states) {
+            // Each layer is fed with the output of the previous one.
+            let gates = match input.take() {
+                Some(input) => layer.input_gates(input),
+                None => layer.input_gates(steps),
+            };
+
+            steps = gates
+                .into_iter()
+                .map(|gates| {
+                    let gates = match layer.hidden_gates(state.as_ref()) {
+                        Some(hidden_gates) => (gates + hidden_gates).into_dyn(),
+                        None => gates,
+                    };
+                    let next = match self.nonlinearity {
+                        Nonlinearity::Tanh => gates.tanh().into_dyn(),
+                        Nonlinearity::ReLU => gates.relu().into_dyn(),
+                    };
+                    state = Some(State::Differentiable(next.clone()));
+                    next
+                })
+                .collect();
+            final_states.extend(steps.last().cloned());
+        }
+
+        (stack_steps(&steps), stack_steps(&final_states))
+    }
+
+    /// Returns the weights and the biases of this `RNN` instance, paired with their names.
+    ///
+    /// The parameters of the `l`-th layer are suffixed by `_l{l}`, e.g. `weight_ih_l0`.
+    pub fn named_parameters(&self) -> Vec<(String, AnyVarDiff)> {
+        self.layers
+            .iter()
+            .enumerate()
+            .flat_map(|(index, layer)| layer.named_parameters(index))
+            .collect()
+    }
+}
+
+impl Register for RNN {
+    /// Registers the weights and the biases of this `RNN` instance.
+    fn register_params(&self, params: &mut Vec<RawParam>) {
+        self.layers
+            .iter()
+            .for_each(|layer| layer.register_params(params));
+    }
+
+    fn register_status(&mut self, _: Rc<Cell<bool>>) {}
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Nonlinearity, RNN};
+    use crate::variable::{Data, Gradient, VarDiff};
+    use ndarray::{Array, Axis, Ix3};
+
+    #[test]
+    fn creation() {
+        let rnn = RNN::new(5, 3, 2, Nonlinearity::Tanh, true);
+
+        assert_eq!(rnn.layers.len(), 2);
+        assert_eq!(rnn.layers[0].weight_ih.data().shape(), &[3, 5]);
+        assert_eq!(rnn.layers[1].weight_ih.data().shape(), &[3, 3]);
+        assert_eq!(rnn.layers[1].weight_hh.data().shape(), &[3, 3]);
+        assert_eq!(rnn.layers[1].bias_hh.as_ref().unwrap().data().shape(), &[3]);
+        assert_eq!(rnn.named_parameters().len(), 8);
+        assert_eq!(rnn.named_parameters()[6].0, "bias_ih_l1");
+
+        let rnn = RNN::new(5, 3, 2, Nonlinearity::ReLU, false);
+        assert_eq!(rnn.named_parameters().len(), 4);
+    }
+
+    #[test]
+    fn single_step() {
+        for (nonlinearity, bias) in [(Nonlinearity::Tanh, true), (Nonlinearity::ReLU, false)] {
+            let rnn = RNN::new(4, 3, 1, nonlinearity, bias);
+            let input = crate::rand((1, 2, 4));
+            let hidden = (crate::rand((1, 2, 3)).requires_grad() * 2. - 1.).into_dyn();
+
+            let (output, final_state) = rnn.forward(input.clone(), Some(hidden.clone().into()));
+            output.forward();
+            final_state.forward();
+
+            let layer = &rnn.layers[0];
+            let mut expected = input
+                .data()
+                .index_axis(Axis(0), 0)
+                .dot(&layer.weight_ih.data().t())
+                + hidden
+                    .data()
+                    .index_axis(Axis(0), 0)
+                    .dot(&layer.weight_hh.data().t());
+            if bias {
+                expected = expected
+                    + &*layer.bias_ih.as_ref().unwrap().data()
+                    + &*layer.bias_hh.as_ref().unwrap().data();
+            }
+            let expected = match nonlinearity {
+                Nonlinearity::Tanh => expected.mapv(f32::tanh),
+                Nonlinearity::ReLU => expected.mapv(|el| el.max(0.)),
+            };
+
+            assert_eq!(output.data().shape(), &[1, 2, 3]);
+            assert_eq!(*output.data(), *final_state.data());
+            assert!(output
+                .data()
+                .iter()
+                .zip(expected.iter())
+                .all(|(actual, expected)| (actual - expected).abs() <= 1e-6));
+        }
+    }
+
+    #[test]
+    fn multi_layer() {
+        let rnn = RNN::new(4, 3, 2, Nonlinearity::Tanh, true);
+        let (output, final_state) = rnn.forward(crate::rand((5, 2, 4)).requires_grad(), None);
+        output.forward();
+        final_state.forward();
+
+        assert_eq!(output.data().shape(), &[5, 2, 3]);
+        assert_eq!(final_state.data().shape(), &[2, 2, 3]);
+        // The final state of the last layer is its output at the last step.
+        assert_eq!(
+            output.data().index_axis(Axis(0), 4),
+            final_state.data().index_axis(Axis(0), 1)
+        );
+    }
+
+    #[test]
+    fn backward() {
+        let rnn = RNN::new(4, 3, 2, Nonlinearity::Tanh, true);
+        let hidden = crate::zeros((2, 2, 3)).requires_grad();
+        let (output, _) = rnn.forward(crate::rand((5, 2, 4)), Some(hidden.clone().into()));
+        let loss = output.sum();
+        loss.forward();
+        loss.backward(1.);
+
+        // The weights, the biases and the initial state.
+        assert_eq!(loss.parameters().len(), 9);
+        assert!(hidden.grad().iter().any(|el| *el != 0.));
+        assert!(rnn.layers[0].weight_ih.grad().iter().any(|el| *el != 0.));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: initial state of shape [1, 2, 3] for 2 layers with 3 features."
+    )]
+    fn wrong_initial_state() {
+        let rnn = RNN::new(4, 3, 2, Nonlinearity::Tanh, true);
+        let hidden: VarDiff<dyn Data<Dim = Ix3>, dyn Gradient<Dim = Ix3>> =
+            crate::from_ndarray(Array::zeros((1, 2, 3)))
+                .requires_grad()
+                .into_dyn();
+        rnn.forward(crate::rand((5, 2, 4)), Some(hidden.into()));
+    }
+
+    #[test]
+    fn constant_initial_state() {
+        // A constant initial state gives the same output as a differentiable one, but it's not a
+        // parameter.
+        let rnn = RNN::new(4, 3, 2, Nonlinearity::Tanh, true);
+        let input = crate::rand((5, 2, 4));
+        let hidden = crate::rand((2, 2, 3));
+
+        let (output, _) = rnn.forward(input.clone(), Some(hidden.clone().into()));
+        let (expected, _) = rnn.forward(input, Some(hidden.requires_grad().into()));
+        output.forward();
+        expected.forward();
+
+        assert_eq!(*output.data(), *expected.data());
+        assert_eq!(output.parameters().len(), 8);
+        assert_eq!(expected.parameters().len(), 9);
+    }
+}
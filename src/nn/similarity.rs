@@ -0,0 +1,157 @@
+use crate::variable::{Data, Gradient, VarDiff};
+use ndarray::{Axis, Ix1, Ix2};
+
+/// Computes the cosine similarity between the rows or the columns of `a` and `b`.
+///
+/// ```text
+///                      a · b
+/// similarity = ―――――――――――――――――――
+///               ‖a‖₂ * ‖b‖₂ + eps
+/// ```
+///
+/// The dot products and the norms are computed along `axis`, so that an `axis` of 1 compares
+/// the rows of two batches of embeddings of shape *(N, D)* and results in a vector of length
+/// *N*. The small `eps` prevents the division by zero when either vector is null.
+///
+/// # Panics
+///
+/// If `a` and `b` have different shapes or if `axis` is not 0 or 1.
+///
+/// # Examples
+///
+/// ```
+/// use neuronika::nn;
+/// use ndarray::array;
+///
+/// let a = neuronika::from_ndarray(array![[1., 0.], [1., 1.]]).requires_grad();
+/// let b = neuronika::from_ndarray(array![[0., 1.], [2., 2.]]).requires_grad();
+///
+/// let similarity = nn::cosine_similarity(a, b, 1, 1e-8);
+/// similarity.forward();
+///
+/// assert!(similarity.data()[0].abs() < 1e-6);
+/// assert!((similarity.data()[1] - 1.).abs() < 1e-6);
+/// ```
+pub fn cosine_similarity<T1, U1, T2, U2>(
+    a: VarDiff<T1, U1>,
+    b: VarDiff<T2, U2>,
+    axis: usize,
+    eps: f32,
+) -> VarDiff<impl Data<Dim = Ix1>, impl Gradient<Dim = Ix1>>
+where
+    T1: Data<Dim = Ix2> + 'static,
+    U1: Gradient<Dim = Ix2> + 'static,
+    T2: Data<Dim = Ix2> + 'static,
+    U2: Gradient<Dim = Ix2> + 'static,
+{
+    assert!(
+        axis < 2,
+        "error: axis {} is out of bounds for 2d inputs.",
+        axis
+    );
+    assert_eq!(
+        a.data().shape(),
+        b.data().shape(),
+        "error: cannot compare inputs of shapes {:?} and {:?}.",
+        a.data().shape(),
+        b.data().shape()
+    );
+
+    let features = a.data().len_of(Axis(axis)) as f32;
+    let dot = (a.clone() * b.clone()).mean_axes(&[axis]) * features;
+    let norms = a.norm(2., &[axis]) * b.norm(2., &[axis]) + eps;
+
+    (dot / norms).squeeze(axis)
+}
+
+#[cfg(test)]
+mod test {
+    use super::cosine_similarity;
+    use ndarray::{array, Array2, Axis};
+
+    #[test]
+    fn identical() {
+        let a = array![[1., 2., 3.], [-0.5, 0.2, 4.]];
+        let similarity = cosine_similarity(
+            crate::from_ndarray(a.clone()).requires_grad(),
+            crate::from_ndarray(a).requires_grad(),
+            1,
+            1e-8,
+        );
+        similarity.forward();
+
+        assert_eq!(similarity.data().shape(), &[2]);
+        assert!(similarity.data().iter().all(|el| (el - 1.).abs() < 1e-6));
+    }
+
+    #[test]
+    fn orthogonal() {
+        let similarity = cosine_similarity(
+            crate::from_ndarray(array![[1., 0.], [3., 0.]]).requires_grad(),
+            crate::from_ndarray(array![[0., 2.], [0., -1.]]).requires_grad(),
+            0,
+            1e-8,
+        );
+        similarity.forward();
+
+        assert_eq!(*similarity.data(), array![0., 0.]);
+    }
+
+    #[test]
+    fn gradient() {
+        let a = array![
+            [0.3, -1.2, 0.8],
+            [1.5, 0.4, -0.6],
+            [-0.7, 0.9, 0.2],
+            [0.1, 0.5, 1.1]
+        ];
+        let b = array![
+            [1.1, 0.2, -0.4],
+            [0.3, -0.8, 0.6],
+            [0.5, 0.5, 1.3],
+            [-1., 0.7, 0.4]
+        ];
+        let weights = array![0.5, -1., 2., 1.5];
+
+        let a_var = crate::from_ndarray(a.clone()).requires_grad();
+        let b_var = crate::from_ndarray(b.clone()).requires_grad();
+        let loss = (cosine_similarity(a_var.clone(), b_var, 1, 1e-8)
+            * crate::from_ndarray(weights.clone()))
+        .sum();
+        loss.forward();
+        loss.backward(1.);
+
+        let objective = |a: &Array2<f32>| {
+            let dot = (a * &b).sum_axis(Axis(1));
+            let norms = a.mapv(|el| el * el).sum_axis(Axis(1)).mapv(f32::sqrt)
+                * b.mapv(|el| el * el).sum_axis(Axis(1)).mapv(f32::sqrt);
+            (dot / norms * &weights).sum()
+        };
+
+        let h = 1e-2;
+        let mut expected = Array2::zeros(a.raw_dim());
+        for (idx, el) in expected.indexed_iter_mut() {
+            let (mut plus, mut minus) = (a.clone(), a.clone());
+            plus[idx] += h;
+            minus[idx] -= h;
+            *el = (objective(&plus) - objective(&minus)) / (2. * h);
+        }
+
+        assert!(a_var
+            .grad()
+            .iter()
+            .zip(expected.iter())
+            .all(|(actual, expected)| (actual - expected).abs() < 1e-3));
+    }
+
+    #[test]
+    #[should_panic(expected = "error: cannot compare inputs of shapes [2, 3] and [2, 2].")]
+    fn shape_mismatch() {
+        cosine_similarity(
+            crate::from_ndarray(Array2::<f32>::zeros((2, 3))).requires_grad(),
+            crate::from_ndarray(Array2::<f32>::zeros((2, 2))).requires_grad(),
+            1,
+            1e-8,
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use super::{accumulation_factor, Optimizer, Param, Penalty};
+use ndarray::{ArrayD, ArrayViewMutD, Zip};
+use rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
+use std::cell::{Cell, RefCell};
+
+/// **AdaDelta** optimizer.
+///
+/// The algorithm has been proposed in [this paper](https://arxiv.org/abs/1212.5701).
+///
+/// The step of each parameter is the ratio between the root mean square of its past updates and
+/// that of its gradients, both computed as exponentially decaying averages, so that no learning
+/// rate needs to be set. The learning rate of this optimizer only scales the step, it starts at
+/// *1.0* and can be changed with [`.set_lr()`](AdaDelta::set_lr()), for instance by a learning
+/// rate scheduler.
+pub struct AdaDelta<'a, T: Penalty> {
+    params: RefCell<Vec<AdaDeltaParam<'a>>>,
+    lr: Cell<f32>,
+    rho: Cell<f32>,
+    eps: Cell<f32>,
+    penalty: T,
+}
+
+impl<'a, T: Penalty> AdaDelta<'a, T> {
+    /// Creates a new *AdaDelta* optimizer.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - vector of [`Param`] to optimize.
+    ///
+    /// * `rho` - decay rate of the running averages. A good default value is *0.9*.
+    ///
+    /// * `eps` - small constant for numerical stability. A good default value is *1e-6*.
+    ///
+    /// * `penalty` - penalty regularization.
+    pub fn new(params: Vec<Param<'a>>, rho: f32, eps: f32, penalty: T) -> Self {
+        let params = RefCell::new(Self::build_params(params));
+
+        Self {
+            params,
+            lr: Cell::new(1.),
+            rho: Cell::new(rho),
+            eps: Cell::new(eps),
+            penalty,
+        }
+    }
+
+    /// Return the current learning rate.
+    pub fn get_lr(&self) -> f32 {
+        Optimizer::get_lr(self)
+    }
+
+    /// Sets `lr` as the  new value for the learning rate.
+    pub fn set_lr(&self, lr: f32) {
+        Optimizer::set_lr(self, lr);
+    }
+
+    /// Return the current decay rate of the running averages.
+    pub fn get_rho(&self) -> f32 {
+        self.rho.get()
+    }
+
+    /// Sets `rho` as the new value for the decay rate of the running averages.
+    pub fn set_rho(&self, rho: f32) {
+        self.rho.set(rho)
+    }
+
+    /// Return the current *eps* constant.
+    pub fn get_eps(&self) -> f32 {
+        self.eps.get()
+    }
+
+    /// Sets `eps` as the  new value for the *eps* constant.
+    pub fn set_eps(&self, eps: f32) {
+        self.eps.set(eps)
+    }
+
+    /// Performs a single AdaDelta optimization step.
+    pub fn step(&self) {
+        Optimizer::step(self);
+    }
+
+    /// Zeroes the gradient of this optimizer's parameters.
+    pub fn zero_grad(&self) {
+        Optimizer::zero_grad(self);
+    }
+
+    /// Performs a single optimization step with the gradients accumulated over `n_steps`
+    /// backward passes, averaging them first.
+    ///
+    /// # Panics
+    ///
+    /// If `n_steps` is zero.
+    pub fn step_accumulated(&self, n_steps: usize) {
+        Optimizer::step_accumulated(self, n_steps);
+    }
+}
+
+/// A parameter used by the *AdaDelta* optimizer.
+pub struct AdaDeltaParam<'a> {
+    data: ArrayViewMutD<'a, f32>,
+    grad: ArrayViewMutD<'a, f32>,
+    square_avg: ArrayD<f32>,
+    acc_delta: ArrayD<f32>,
+}
+
+impl<'a> From<Param<'a>> for AdaDeltaParam<'a> {
+    fn from(param: Param<'a>) -> Self {
+        let Param { data, grad } = param;
+        let (square_avg, acc_delta) =
+            (ArrayD::zeros(grad.raw_dim()), ArrayD::zeros(grad.raw_dim()));
+
+        Self {
+            data,
+            grad,
+            square_avg,
+            acc_delta,
+        }
+    }
+}
+
+impl<'a, T: Penalty> Optimizer<'a> for AdaDelta<'a, T> {
+    type ParamRepr = AdaDeltaParam<'a>;
+
+    fn step(&self) {
+        let (mut params, lr, rho, eps, penalty) = (
+            self.params.borrow_mut(),
+            self.lr.get(),
+            &self.rho.get(),
+            &self.eps.get(),
+            &self.penalty,
+        );
+
+        params.par_iter_mut().for_each(|param| {
+            let mut p_grad = param.grad.to_owned();
+            Zip::from(&mut p_grad)
+                .and(&param.data)
+                .for_each(|p_grad_el, data_el| *p_grad_el += penalty.penalize(data_el));
+
+            Zip::from(&mut param.square_avg)
+                .and(&p_grad)
+                .for_each(|square_avg_el, p_grad_el| {
+                    *square_avg_el = *square_avg_el * rho + p_grad_el * p_grad_el * (1. - rho)
+                });
+
+            Zip::from(&mut param.data)
+                .and(&mut param.acc_delta)
+                .and(&p_grad)
+                .and(&param.square_avg)
+                .for_each(|data_el, acc_delta_el, p_grad_el, square_avg_el| {
+                    let delta =
+                        (*acc_delta_el + eps).sqrt() / (square_avg_el + eps).sqrt() * p_grad_el;
+
+                    *acc_delta_el = *acc_delta_el * rho + delta * delta * (1. - rho);
+                    *data_el += -delta * lr
+                });
+        });
+    }
+
+    fn zero_grad(&self) {
+        self.params.borrow_mut().par_iter_mut().for_each(|param| {
+            let grad = &mut param.grad;
+            Zip::from(grad).for_each(|grad_el| *grad_el = 0.);
+        });
+    }
+
+    fn step_accumulated(&self, n_steps: usize) {
+        let factor = accumulation_factor(n_steps);
+        self.params.borrow_mut().par_iter_mut().for_each(|param| {
+            let grad = &mut param.grad;
+            Zip::from(grad).for_each(|grad_el| *grad_el *= factor);
+        });
+        self.step();
+    }
+
+    fn get_lr(&self) -> f32 {
+        self.lr.get()
+    }
+
+    fn set_lr(&self, lr: f32) {
+        self.lr.set(lr)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,127 @@
+use super::{super::L2, AdaDelta};
+use ndarray::array;
+
+#[test]
+fn creation() {
+    let optim = AdaDelta::new(Vec::new(), 0.9, 1e-6, L2::new(1e-2));
+
+    assert_eq!(optim.params.borrow().len(), 0);
+    assert!((optim.get_lr() - 1.).abs() <= f32::EPSILON);
+    assert!((optim.get_rho() - 0.9).abs() <= f32::EPSILON);
+    assert!((optim.get_eps() - 1e-6).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_lr() {
+    let optim = AdaDelta::new(Vec::new(), 0.9, 1e-6, L2::new(1e-2));
+
+    optim.set_lr(0.5);
+    assert!((optim.get_lr() - 0.5).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_rho() {
+    let optim = AdaDelta::new(Vec::new(), 0.9, 1e-6, L2::new(1e-2));
+
+    optim.set_rho(0.95);
+    assert!((optim.get_rho() - 0.95).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_eps() {
+    let optim = AdaDelta::new(Vec::new(), 0.9, 1e-6, L2::new(1e-2));
+
+    optim.set_eps(1e-8);
+    assert!((optim.get_eps() - 1e-8).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn adaptive_step() {
+    let x = crate::zeros(2).requires_grad();
+    let optim = AdaDelta::new(x.parameters(), 0.9, 1e-6, L2::new(0.));
+
+    // Gradients three orders of magnitude apart yield nearly the same step.
+    x.grad_mut().assign(&array![1., 1000.]);
+    optim.step();
+
+    let first = x.data().clone();
+    assert!(first.iter().all(|el| *el < 0.));
+    assert!((first[0] / first[1] - 1.).abs() <= 1e-4);
+
+    // With a constant gradient the step grows as the updates accumulate.
+    optim.step();
+
+    let second = &*x.data() - &first;
+    assert!(second[0] < first[0]);
+    assert!(second[1] < first[1]);
+}
+
+/// Reference AdaDelta minimizing `(w - target)^2` element-wise.
+fn reference(mut w: f32, target: f32, rho: f32, eps: f32, steps: usize) -> f32 {
+    let (mut square_avg, mut acc_delta) = (0., 0.);
+
+    for _ in 0..steps {
+        let grad = 2. * (w - target);
+        square_avg = rho * square_avg + (1. - rho) * grad * grad;
+        let delta = (acc_delta + eps).sqrt() / (square_avg + eps).sqrt() * grad;
+        acc_delta = rho * acc_delta + (1. - rho) * delta * delta;
+        w -= delta;
+    }
+
+    w
+}
+
+const EPOCHS: usize = 200;
+
+#[test]
+fn step() {
+    let x = crate::rand((3, 3));
+    let y = crate::rand((3, 3));
+    let z = x.clone().mm(y);
+
+    let w = crate::rand((3, 3)).requires_grad();
+    let loss = (x.mm(w) - z).pow(2).sum();
+    loss.forward();
+
+    let first_value = loss.data().clone().into_scalar();
+    let optim = AdaDelta::new(loss.parameters(), 0.9, 1e-6, L2::new(0.0));
+
+    for _ in 0..EPOCHS {
+        loss.forward();
+        loss.backward(1.0);
+
+        optim.step();
+        optim.zero_grad();
+    }
+    assert!(loss.data().clone().into_scalar() < first_value);
+}
+
+#[test]
+fn quadratic() {
+    let target = crate::from_ndarray(array![3., -2., 0.5]);
+    let w = crate::zeros(3).requires_grad();
+    let loss = (w.clone() - target).pow(2).sum();
+    let optim = AdaDelta::new(loss.parameters(), 0.9, 1e-2, L2::new(0.));
+
+    for _ in 0..EPOCHS {
+        loss.forward();
+        loss.backward(1.);
+
+        optim.step();
+        optim.zero_grad();
+    }
+
+    let expected = [3., -2., 0.5].map(|target| reference(0., target, 0.9, 1e-2, EPOCHS));
+    assert!(w
+        .data()
+        .iter()
+        .zip(&expected)
+        .all(|(w_el, expected_el)| (w_el - expected_el).abs() <= 1e-4));
+
+    // The minimum is being approached.
+    assert!(w
+        .data()
+        .iter()
+        .zip(&[3., -2., 0.5])
+        .all(|(w_el, target)| (w_el - target).abs() < target.abs()));
+}
@@ -1,4 +1,4 @@
-use super::{Optimizer, Param, Penalty};
+use super::{accumulation_factor, Optimizer, Param, Penalty};
 use ndarray::{ArrayD, ArrayViewMutD, Zip};
 use rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
 use std::cell::{Cell, RefCell};
@@ -80,6 +80,16 @@ impl<'a, T: Penalty> Adagrad<'a, T> {
     pub fn zero_grad(&self) {
         Optimizer::zero_grad(self);
     }
+
+    /// Performs a single optimization step with the gradients accumulated over `n_steps`
+    /// backward passes, averaging them first.
+    ///
+    /// # Panics
+    ///
+    /// If `n_steps` is zero.
+    pub fn step_accumulated(&self, n_steps: usize) {
+        Optimizer::step_accumulated(self, n_steps);
+    }
 }
 
 /// A parameter used by the *Adagrad* optimizer.
@@ -147,6 +157,15 @@ impl<'a, T: Penalty> Optimizer<'a> for Adagrad<'a, T> {
         });
     }
 
+    fn step_accumulated(&self, n_steps: usize) {
+        let factor = accumulation_factor(n_steps);
+        self.params.borrow_mut().par_iter_mut().for_each(|param| {
+            let grad = &mut param.grad;
+            Zip::from(grad).for_each(|grad_el| *grad_el *= factor);
+        });
+        self.step();
+    }
+
     fn get_lr(&self) -> f32 {
         self.lr.get()
     }
@@ -0,0 +1,213 @@
+use super::{accumulation_factor, Optimizer, Param};
+use ndarray::{ArrayD, ArrayViewMutD, Zip};
+use rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
+use std::cell::{Cell, RefCell};
+
+/// **AdamW** optimizer.
+///
+/// It has been proposed in
+/// [Decoupled Weight Decay Regularization](https://arxiv.org/abs/1711.05101).
+///
+/// Differently from [`Adam`](super::Adam) with an [`L2`](super::L2) penalty, whose regularization
+/// term is rescaled along with the gradient by the adaptive learning rate, the weight decay is
+/// applied directly to the parameters before each step.
+pub struct AdamW<'a> {
+    params: RefCell<Vec<AdamWParam<'a>>>,
+    lr: Cell<f32>,
+    betas: Cell<(f32, f32)>,
+    weight_decay: Cell<f32>,
+    eps: Cell<f32>,
+}
+
+impl<'a> AdamW<'a> {
+    /// Creates a new *AdamW* optimizer.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - vector of [`Param`] to optimize.
+    ///
+    /// * `lr` - learning rate.
+    ///
+    /// * `betas` - a 2-tuple of coefficients used for computing running averages of the gradient
+    /// and its square. Good default is: *(0.9, 0.999)*.
+    ///
+    /// * `weight_decay` - weight decay coefficient, each parameter is multiplied by
+    /// *(1 - lr * weight_decay)* before the step. A good default value is *1e-2*.
+    ///
+    /// * `eps` - small constant for numerical stability. A good default value is *1e-8*.
+    pub fn new(
+        params: Vec<Param<'a>>,
+        lr: f32,
+        betas: (f32, f32),
+        weight_decay: f32,
+        eps: f32,
+    ) -> Self {
+        let params = RefCell::new(Self::build_params(params));
+        let lr = Cell::new(lr);
+
+        Self {
+            params,
+            lr,
+            betas: Cell::new(betas),
+            weight_decay: Cell::new(weight_decay),
+            eps: Cell::new(eps),
+        }
+    }
+
+    /// Return the current learning rate.
+    pub fn get_lr(&self) -> f32 {
+        Optimizer::get_lr(self)
+    }
+
+    /// Sets `lr` as the  new value for the learning rate.
+    pub fn set_lr(&self, lr: f32) {
+        Optimizer::set_lr(self, lr);
+    }
+
+    /// Return the current values for the exponential decay rates.
+    pub fn get_betas(&self) -> (f32, f32) {
+        self.betas.get()
+    }
+
+    /// Sets `betas` as the  new value for the exponential decay rates.
+    pub fn set_betas(&self, betas: (f32, f32)) {
+        self.betas.set(betas)
+    }
+
+    /// Return the current weight decay coefficient.
+    pub fn get_weight_decay(&self) -> f32 {
+        self.weight_decay.get()
+    }
+
+    /// Sets `weight_decay` as the new value for the weight decay coefficient.
+    pub fn set_weight_decay(&self, weight_decay: f32) {
+        self.weight_decay.set(weight_decay)
+    }
+
+    /// Return the current *eps* constant.
+    pub fn get_eps(&self) -> f32 {
+        self.eps.get()
+    }
+
+    /// Sets `eps` as the  new value for the *eps* constant.
+    pub fn set_eps(&self, eps: f32) {
+        self.eps.set(eps)
+    }
+
+    /// Performs a single AdamW optimization step.
+    pub fn step(&self) {
+        Optimizer::step(self);
+    }
+
+    /// Zeroes the gradient of this optimizer's parameters.
+    pub fn zero_grad(&self) {
+        Optimizer::zero_grad(self);
+    }
+
+    /// Performs a single optimization step with the gradients accumulated over `n_steps`
+    /// backward passes, averaging them first.
+    ///
+    /// # Panics
+    ///
+    /// If `n_steps` is zero.
+    pub fn step_accumulated(&self, n_steps: usize) {
+        Optimizer::step_accumulated(self, n_steps);
+    }
+}
+
+/// A Parameter used by the *AdamW* optimizer.
+pub struct AdamWParam<'a> {
+    data: ArrayViewMutD<'a, f32>,
+    grad: ArrayViewMutD<'a, f32>,
+    step: usize,
+    exp_avg: ArrayD<f32>,
+    exp_avg_sq: ArrayD<f32>,
+}
+
+impl<'a> From<Param<'a>> for AdamWParam<'a> {
+    fn from(param: Param<'a>) -> Self {
+        let Param { data, grad } = param;
+        let step = 0;
+        let (exp_avg, exp_avg_sq) =
+            { (ArrayD::zeros(grad.raw_dim()), ArrayD::zeros(grad.raw_dim())) };
+        Self {
+            data,
+            grad,
+            step,
+            exp_avg,
+            exp_avg_sq,
+        }
+    }
+}
+
+impl<'a> Optimizer<'a> for AdamW<'a> {
+    type ParamRepr = AdamWParam<'a>;
+
+    fn step(&self) {
+        let (lr, mut params, (beta1, beta2), weight_decay, eps) = (
+            self.lr.get(),
+            self.params.borrow_mut(),
+            &self.betas.get(),
+            self.weight_decay.get(),
+            &self.eps.get(),
+        );
+
+        params.par_iter_mut().for_each(|param| {
+            let (step, exp_avg, exp_avg_sq) =
+                (&mut param.step, &mut param.exp_avg, &mut param.exp_avg_sq);
+
+            *step += 1;
+            let bias_correction1 = 1. - beta1.powi(*step as i32);
+            let bias_correction2 = 1. - beta2.powi(*step as i32);
+
+            Zip::from(exp_avg)
+                .and(&param.grad)
+                .for_each(|exp_avg_el, grad_el| {
+                    *exp_avg_el = *exp_avg_el * beta1 + grad_el * (1. - beta1)
+                });
+
+            Zip::from(exp_avg_sq)
+                .and(&param.grad)
+                .for_each(|exp_avg_sq_el, grad_el| {
+                    *exp_avg_sq_el = *exp_avg_sq_el * beta2 + grad_el * grad_el * (1. - beta2)
+                });
+
+            Zip::from(&mut param.data)
+                .and(&param.exp_avg)
+                .and(&param.exp_avg_sq)
+                .for_each(|data_el, exp_avg_el, exp_avg_sq_el| {
+                    *data_el *= 1. - lr * weight_decay;
+                    *data_el += exp_avg_el
+                        / ((exp_avg_sq_el.sqrt() / bias_correction2.sqrt()) + *eps)
+                        * (-lr / bias_correction1)
+                })
+        });
+    }
+
+    fn zero_grad(&self) {
+        self.params.borrow_mut().par_iter_mut().for_each(|param| {
+            let grad = &mut param.grad;
+            Zip::from(grad).for_each(|grad_el| *grad_el = 0.);
+        });
+    }
+
+    fn step_accumulated(&self, n_steps: usize) {
+        let factor = accumulation_factor(n_steps);
+        self.params.borrow_mut().par_iter_mut().for_each(|param| {
+            let grad = &mut param.grad;
+            Zip::from(grad).for_each(|grad_el| *grad_el *= factor);
+        });
+        self.step();
+    }
+
+    fn get_lr(&self) -> f32 {
+        self.lr.get()
+    }
+
+    fn set_lr(&self, lr: f32) {
+        self.lr.set(lr)
+    }
+}
+
+#[cfg(test)]
+mod test;
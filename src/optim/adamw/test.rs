@@ -0,0 +1,180 @@
+use super::{
+    super::{Adam, L2},
+    AdamW,
+};
+use ndarray::array;
+
+#[test]
+fn creation() {
+    let optim = AdamW::new(Vec::new(), 1e-2, (0.9, 0.999), 1e-2, 1e-8);
+
+    assert_eq!(optim.params.borrow().len(), 0);
+    assert!((optim.get_lr() - 1e-2).abs() <= f32::EPSILON);
+    assert_eq!(optim.get_betas(), (0.9, 0.999));
+    assert!((optim.get_weight_decay() - 1e-2).abs() <= f32::EPSILON);
+    assert!((optim.get_eps() - 1e-8).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_lr() {
+    let optim = AdamW::new(Vec::new(), 1e-2, (0.9, 0.999), 1e-2, 1e-8);
+
+    optim.set_lr(1e-3);
+    assert!((optim.get_lr() - 1e-3).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_betas() {
+    let optim = AdamW::new(Vec::new(), 1e-2, (0.9, 0.999), 1e-2, 1e-8);
+
+    optim.set_betas((0.91, 0.9991));
+    assert_eq!(optim.get_betas(), (0.91, 0.9991));
+}
+
+#[test]
+fn set_weight_decay() {
+    let optim = AdamW::new(Vec::new(), 1e-2, (0.9, 0.999), 1e-2, 1e-8);
+
+    optim.set_weight_decay(1e-1);
+    assert!((optim.get_weight_decay() - 1e-1).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_eps() {
+    let optim = AdamW::new(Vec::new(), 1e-2, (0.9, 0.999), 1e-2, 1e-8);
+
+    optim.set_eps(1e-9);
+    assert!((optim.get_eps() - 1e-9).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn decoupled_weight_decay() {
+    let (lr, (beta1, beta2), weight_decay, eps) = (0.1, (0.9, 0.999), 0.5, 1e-8);
+    let grads = [array![0.5, 0.5], array![-0.2, 1.5], array![0.1, -0.3]];
+
+    let x = crate::from_ndarray(array![1., -2.]).requires_grad();
+    let adamw = AdamW::new(x.parameters(), lr, (beta1, beta2), weight_decay, eps);
+
+    // The L2 penalty adds 2 * lambda * w to the gradient.
+    let y = crate::from_ndarray(array![1., -2.]).requires_grad();
+    let adam = Adam::new(
+        y.parameters(),
+        lr,
+        (beta1, beta2),
+        L2::new(weight_decay / 2.),
+        eps,
+    );
+
+    // Reference implementation of the update rule, element by element.
+    let (mut w, mut m, mut v) = ([1_f32, -2.], [0_f32; 2], [0_f32; 2]);
+    for (step, grad) in grads.iter().enumerate() {
+        x.grad_mut().assign(grad);
+        y.grad_mut().assign(grad);
+        adamw.step();
+        adam.step();
+
+        let (bias_correction1, bias_correction2) = (
+            1. - beta1.powi(step as i32 + 1),
+            1. - beta2.powi(step as i32 + 1),
+        );
+        for i in 0..2 {
+            m[i] = beta1 * m[i] + (1. - beta1) * grad[i];
+            v[i] = beta2 * v[i] + (1. - beta2) * grad[i] * grad[i];
+            w[i] = w[i] * (1. - lr * weight_decay)
+                - lr * (m[i] / bias_correction1) / ((v[i] / bias_correction2).sqrt() + eps);
+        }
+        assert!(x
+            .data()
+            .iter()
+            .zip(w.iter())
+            .all(|(x_el, w_el)| (x_el - w_el).abs() <= 1e-6));
+    }
+
+    // After the first step of Adam the regularized gradient is rescaled to unit magnitude, so the
+    // penalty only flips the direction of the second parameter. AdamW shrinks both instead.
+    let x = crate::from_ndarray(array![1., -2.]).requires_grad();
+    x.grad_mut().assign(&array![0.5, 0.5]);
+    AdamW::new(x.parameters(), lr, (beta1, beta2), weight_decay, eps).step();
+
+    let y = crate::from_ndarray(array![1., -2.]).requires_grad();
+    y.grad_mut().assign(&array![0.5, 0.5]);
+    Adam::new(
+        y.parameters(),
+        lr,
+        (beta1, beta2),
+        L2::new(weight_decay / 2.),
+        eps,
+    )
+    .step();
+
+    assert!(x
+        .data()
+        .iter()
+        .zip([0.85, -2.].iter())
+        .all(|(x_el, target)| (x_el - target).abs() <= 1e-6));
+    assert!(y
+        .data()
+        .iter()
+        .zip([0.9, -1.9].iter())
+        .all(|(y_el, target)| (y_el - target).abs() <= 1e-6));
+}
+
+#[test]
+fn smaller_norm_than_l2() {
+    // The minimum of the loss is at (1, 10), the decay pulls the parameters towards the origin.
+    let target = crate::from_ndarray(array![1., 10.]);
+    let x = crate::zeros(2).requires_grad();
+    let x_loss = (x.clone() - target.clone()).pow(2).sum();
+    let adamw = AdamW::new(x_loss.parameters(), 0.05, (0.9, 0.999), 0.5, 1e-8);
+
+    let y = crate::zeros(2).requires_grad();
+    let y_loss = (y.clone() - target).pow(2).sum();
+    let adam = Adam::new(y_loss.parameters(), 0.05, (0.9, 0.999), L2::new(0.25), 1e-8);
+
+    for _ in 0..EPOCHS * 10 {
+        x_loss.forward();
+        x_loss.backward(1.0);
+        adamw.step();
+        adamw.zero_grad();
+
+        y_loss.forward();
+        y_loss.backward(1.0);
+        adam.step();
+        adam.zero_grad();
+    }
+
+    // Adam with L2 converges to the minimum of the regularized loss, that is target / 1.25, while
+    // the decay of AdamW balances its normalized steps as soon as the parameter reaches 1 / 0.5.
+    assert!((y.data()[1] - 10. / 1.25).abs() <= 1e-2);
+    assert!((x.data()[1] - 2.).abs() <= 5e-2);
+    let (x_norm, y_norm) = (
+        x.data().iter().map(|el| el * el).sum::<f32>().sqrt(),
+        y.data().iter().map(|el| el * el).sum::<f32>().sqrt(),
+    );
+    assert!(x_norm < y_norm);
+}
+
+const EPOCHS: usize = 200;
+
+#[test]
+fn step() {
+    let x = crate::rand((3, 3));
+    let y = crate::rand((3, 3));
+    let z = x.clone().mm(y);
+
+    let w = crate::rand((3, 3)).requires_grad();
+    let loss = (x.mm(w) - z).pow(2).sum();
+    loss.forward();
+
+    let first_value = loss.data().clone().into_scalar();
+    let optim = AdamW::new(loss.parameters(), 0.01, (0.9, 0.999), 0.0, 1e-8);
+
+    for _ in 0..EPOCHS {
+        loss.forward();
+        loss.backward(1.0);
+
+        optim.step();
+        optim.zero_grad();
+    }
+    assert!(loss.data().clone().into_scalar() < first_value);
+}
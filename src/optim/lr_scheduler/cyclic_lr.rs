@@ -0,0 +1,254 @@
+use super::{
+    super::Optimizer, prepare_iteration_step, LRBounds, LRScheduler, StepCounter, StepMode,
+};
+use std::cell::Cell;
+
+/// Policy by which the amplitude of the cycles of a [`CyclicLR`] scheduler changes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CyclicMode {
+    /// Every cycle has the same amplitude.
+    Triangular,
+    /// The amplitude is halved at each cycle.
+    Triangular2,
+    /// The amplitude is scaled by `gamma` to the power of the current epoch.
+    ExpRange {
+        /// Multiplicative factor of the amplitude.
+        gamma: f32,
+    },
+}
+
+/// Cycles the learning rate between `base_lr` and `max_lr`, as described in
+/// [Cyclical Learning Rates for Training Neural Networks](https://arxiv.org/abs/1506.01186).
+///
+/// Each cycle linearly increases the learning rate from `base_lr` for `step_size_up` epochs, then
+/// linearly decreases it back to `base_lr` for `step_size_down` epochs. The peak of the cycle
+/// depends on the [`CyclicMode`].
+///
+///```text
+/// lrₜ = base_lr + (max_lr - base_lr) * position(t) * scale(t)
+///```
+///
+/// where `position(t)` goes from 0 to 1 and back to 0 within each cycle, while `scale(t)` is
+/// 1 for [`CyclicMode::Triangular`], 1 / 2ᶜ for [`CyclicMode::Triangular2`], where `c` is the
+/// number of cycles completed so far, and gammaᵗ for [`CyclicMode::ExpRange`].
+///
+/// The paper cycles the learning rate once per iteration: if that's the desired behaviour set
+/// [`StepMode::Iteration`] with `.set_step_mode()` and call `.step()` after each optimizer's
+/// update, the step sizes are still counted in epochs.
+pub struct CyclicLR<'a, T: Optimizer<'a>> {
+    optimizer: &'a T,
+    base_lr: f32,
+    max_lr: f32,
+    step_size_up: usize,
+    step_size_down: usize,
+    mode: CyclicMode,
+    cycle: Cell<f32>,
+    cycle_position: Cell<f32>,
+    steps: StepCounter,
+    current_lr: Cell<f32>,
+    last_lr: Cell<f32>,
+    bounds: LRBounds,
+}
+
+impl<'a, T: Optimizer<'a>> CyclicLR<'a, T> {
+    /// Creates a new CyclicLR scheduler.
+    ///
+    /// The optimizer's learning rate is immediately set to `base_lr`.
+    ///
+    /// # Arguments
+    ///
+    /// * `optimizer` - wrapped optimizer.
+    ///
+    /// * `base_lr` - lower boundary of the cycle.
+    ///
+    /// * `max_lr` - upper boundary of the cycle, it may not be reached depending on `mode`.
+    ///
+    /// * `step_size_up` - number of epochs in the increasing half of the cycle.
+    ///
+    /// * `step_size_down` - number of epochs in the decreasing half of the cycle.
+    ///
+    /// * `mode` - policy by which the amplitude of the cycles changes.
+    ///
+    /// # Panics
+    ///
+    /// If `base_lr` is greater than `max_lr` or if `step_size_up` is zero.
+    pub fn new(
+        optimizer: &'a T,
+        base_lr: f32,
+        max_lr: f32,
+        step_size_up: usize,
+        step_size_down: usize,
+        mode: CyclicMode,
+    ) -> Self {
+        assert!(
+            base_lr <= max_lr,
+            "error: base_lr must not be greater than max_lr, got {} and {}.",
+            base_lr,
+            max_lr
+        );
+        assert!(step_size_up > 0, "error: step_size_up must be positive.");
+        optimizer.set_lr(base_lr);
+
+        Self {
+            optimizer,
+            base_lr,
+            max_lr,
+            step_size_up,
+            step_size_down,
+            mode,
+            cycle: Cell::new(0.),
+            cycle_position: Cell::new(0.),
+            steps: StepCounter::default(),
+            current_lr: Cell::new(base_lr),
+            last_lr: Cell::new(0.0),
+            bounds: LRBounds::default(),
+        }
+    }
+
+    /// Updates the cycle state to the current epoch, which may be fractional in
+    /// [`StepMode::Iteration`].
+    ///
+    /// The cycle is the number of cycles completed so far, while the cycle position is the
+    /// fraction of the current cycle elapsed so far.
+    fn update_cycle(&self) {
+        let epoch = self.steps.progress();
+        let cycle_len = (self.step_size_up + self.step_size_down) as f32;
+        let cycle = (epoch / cycle_len).floor();
+
+        self.cycle.set(cycle);
+        self.cycle_position.set(epoch / cycle_len - cycle);
+    }
+
+    /// Computes the learning rate at the current position of the cycle.
+    fn cyclic_lr(&self) -> f32 {
+        let up_ratio = self.step_size_up as f32 / (self.step_size_up + self.step_size_down) as f32;
+        let position = self.cycle_position.get();
+        let height = if position <= up_ratio {
+            position / up_ratio
+        } else {
+            (1. - position) / (1. - up_ratio)
+        };
+        let scale = match self.mode {
+            CyclicMode::Triangular => 1.,
+            CyclicMode::Triangular2 => 0.5_f32.powf(self.cycle.get()),
+            CyclicMode::ExpRange { gamma } => gamma.powf(self.steps.progress()),
+        };
+
+        self.base_lr + (self.max_lr - self.base_lr) * height * scale
+    }
+
+    /// Moves the learning rate along the cycle.
+    pub fn step(&self) {
+        LRScheduler::step(self);
+    }
+
+    /// Returns the last learning rate value computed by this learning rate scheduler.
+    pub fn get_last_lr(&self) -> f32 {
+        LRScheduler::get_last_lr(self)
+    }
+
+    /// Returns the current learning rate value computed by this learning rate scheduler.
+    pub fn get_current_lr(&self) -> f32 {
+        LRScheduler::get_current_lr(self)
+    }
+
+    /// Sets the current epoch for this learning rate scheduler.
+    pub fn set_current_epoch(&self, epoch: usize) {
+        LRScheduler::set_current_epoch(self, epoch);
+    }
+
+    /// Returns the current epoch for this learning rate scheduler.
+    pub fn get_current_epoch(&self) -> usize {
+        LRScheduler::get_current_epoch(self)
+    }
+
+    /// Sets the range in which the learning rates computed by this scheduler are clamped.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_lr` - lower bound, defaults to `f32::MIN_POSITIVE`.
+    ///
+    /// * `max_lr` - upper bound, defaults to `f32::MAX`.
+    ///
+    /// # Panics
+    ///
+    /// If `min_lr` is negative or greater than `max_lr`.
+    pub fn set_lr_bounds(&self, min_lr: f32, max_lr: f32) {
+        LRScheduler::set_lr_bounds(self, min_lr, max_lr);
+    }
+
+    /// Returns the range in which the learning rates computed by this scheduler are clamped.
+    pub fn get_lr_bounds(&self) -> (f32, f32) {
+        LRScheduler::get_lr_bounds(self)
+    }
+
+    /// Sets the granularity at which `.step()` is called, see [`StepMode`].
+    ///
+    /// # Panics
+    ///
+    /// If `mode` is [`StepMode::Iteration`] and `steps_per_epoch` is zero.
+    pub fn set_step_mode(&self, mode: StepMode) {
+        LRScheduler::set_step_mode(self, mode);
+    }
+
+    /// Returns the granularity at which `.step()` is called.
+    pub fn get_step_mode(&self) -> StepMode {
+        LRScheduler::get_step_mode(self)
+    }
+
+    /// Returns the number of iterations performed so far. In [`StepMode::Epoch`] this is always
+    /// zero.
+    pub fn get_current_iteration(&self) -> usize {
+        LRScheduler::get_current_iteration(self)
+    }
+
+    /// Prints the learning rate update together with the epoch.
+    pub fn print_lr(&self) {
+        LRScheduler::print_lr(self);
+    }
+}
+
+impl<'a, T: Optimizer<'a>> LRScheduler for CyclicLR<'a, T> {
+    fn step(&self) {
+        prepare_iteration_step(&self.last_lr, &self.current_lr, &self.steps);
+        self.update_cycle();
+        self.current_lr.set(self.bounds.clamp(self.cyclic_lr()));
+        self.optimizer.set_lr(self.current_lr.get());
+    }
+
+    fn get_last_lr(&self) -> f32 {
+        self.last_lr.get()
+    }
+
+    fn get_current_lr(&self) -> f32 {
+        self.current_lr.get()
+    }
+
+    fn set_current_epoch(&self, epoch: usize) {
+        self.steps.set_epoch(epoch);
+    }
+
+    fn get_current_epoch(&self) -> usize {
+        self.steps.epoch()
+    }
+
+    fn set_lr_bounds(&self, min_lr: f32, max_lr: f32) {
+        self.bounds.set(min_lr, max_lr);
+    }
+
+    fn get_lr_bounds(&self) -> (f32, f32) {
+        self.bounds.get()
+    }
+
+    fn set_step_mode(&self, mode: StepMode) {
+        self.steps.set_mode(mode);
+    }
+
+    fn get_step_mode(&self) -> StepMode {
+        self.steps.mode()
+    }
+
+    fn get_current_iteration(&self) -> usize {
+        self.steps.iteration()
+    }
+}
@@ -20,6 +20,15 @@
 //! }
 //! ```
 //!
+//! Epochs are counted starting from zero, and each call to `.step()` advances the current epoch
+//! before the new learning rate is computed. Consequently, the first `.step()` computes the
+//! learning rate for epoch 1, and functions passed to [`LambdaLR`] and [`MultiplicativeLR`] are
+//! never evaluated at epoch 0.
+//!
+//! Every learning rate computed by a scheduler is clamped in the range given by
+//! `.set_lr_bounds()`, which defaults to `[f32::MIN_POSITIVE, f32::MAX]`. The default floor keeps
+//! decaying schedules, such as [`ExponentialLR`], from producing subnormal or zero learning rates.
+//!
 //! Learning rate schedulers can be chained together. The result is that each scheduler is applied
 //! one after the other on the learning rate obtained by the one preceding it.
 //!
@@ -30,7 +39,7 @@
 //! # const EPOCHS: usize = 5;
 //! let optim = SGD::new(vec![], 0.01, L2::new(0.1));
 //! let scheduler1 = LambdaLR::new(&optim, |epoch| 1.0_f32 / epoch as f32);
-//! let scheduler2 = MultiplicativeLR::new(&optim, |epoch| 0.1_f32 * epoch as f32);
+//! let scheduler2 = MultiplicativeLR::new(&optim, |epoch| 1.0_f32 / (1.0 + 0.1 * epoch as f32));
 //! # let mut loss = neuronika::ones(1).requires_grad() + 0.;
 //!
 //! for epoch in 0..EPOCHS {
@@ -40,11 +49,132 @@
 //!    optim.zero_grad();
 //!    scheduler1.step();
 //!    scheduler2.step();
+//!    assert!(optim.get_lr().is_finite());
+//! }
+//! ```
+//!
+//! Schedulers count epochs, but some of them are more naturally driven once per iteration. The
+//! granularity at which `.step()` is called is set with `.set_step_mode()`: in
+//! [`StepMode::Iteration`] each scheduler converts the iterations into epochs internally, so that
+//! `.get_current_epoch()` has the same meaning in both modes and the schedule is expressed in
+//! epochs either way. Schedulers that change the learning rate once per epoch, such as
+//! [`StepLR`], update it when an epoch is completed, while the ones that anneal it, such as
+//! [`OneCycleLR`], [`CyclicLR`] and [`WarmupLR`], update it at every iteration. The only exception
+//! is the cycle of a [`OneCycleLR`], which is measured in steps, as the 1cycle policy is defined
+//! over the total number of updates of the training.
+//!
+//! ```
+//! # use neuronika::optim;
+//! # use neuronika::optim::{SGD, Optimizer, L2};
+//! # use neuronika::optim::lr_scheduler::{LRScheduler, StepLR, StepMode};
+//! # const EPOCHS: usize = 5;
+//! # const STEPS_PER_EPOCH: usize = 10;
+//! let optim = SGD::new(vec![], 0.01, L2::new(0.1));
+//! let scheduler = StepLR::new(&optim, 2, 0.1);
+//! scheduler.set_step_mode(StepMode::Iteration {
+//!     steps_per_epoch: STEPS_PER_EPOCH,
+//! });
+//! # let mut loss = neuronika::ones(1).requires_grad() + 0.;
+//!
+//! for epoch in 0..EPOCHS {
+//!     for _ in 0..STEPS_PER_EPOCH {
+//!         loss.forward();
+//!         loss.backward(1.0);
+//!         optim.step();
+//!         optim.zero_grad();
+//!         scheduler.step();
+//!     }
+//!     assert_eq!(scheduler.get_current_epoch(), epoch + 1);
+//! }
+//! ```
+//!
+//! A warmup phase can be prepended to any scheduler by wrapping it in a [`WarmupLR`], which
+//! linearly increases the learning rate for a given number of epochs before handing it over.
+//!
+//! ```
+//! # use neuronika::optim;
+//! # use neuronika::optim::{SGD, Optimizer, L2};
+//! # use neuronika::optim::lr_scheduler::{ExponentialLR, LRScheduler, WarmupLR};
+//! const WARMUP_EPOCHS: usize = 3;
+//! let optim = SGD::new(vec![], 0.01, L2::new(0.1));
+//! let scheduler = WarmupLR::new(
+//!     &optim,
+//!     Box::new(ExponentialLR::new(&optim, 0.9)),
+//!     WARMUP_EPOCHS,
+//! );
+//!
+//! for _ in 0..WARMUP_EPOCHS {
+//!     assert!(optim.get_lr() < 0.01);
+//!     scheduler.step();
+//! }
+//! assert_eq!(optim.get_lr(), 0.01);
+//! ```
+//!
+//! The learning rate can also be cycled between two boundaries with a [`CyclicLR`], which is
+//! usually stepped once per iteration.
+//!
+//! ```
+//! # use neuronika::optim;
+//! # use neuronika::optim::{SGD, Optimizer, L2};
+//! # use neuronika::optim::lr_scheduler::{CyclicLR, CyclicMode, LRScheduler};
+//! let optim = SGD::new(vec![], 0.01, L2::new(0.1));
+//! let scheduler = CyclicLR::new(&optim, 0.001, 0.01, 4, 4, CyclicMode::Triangular);
+//!
+//! for _ in 0..4 {
+//!     scheduler.step();
+//! }
+//! assert!((optim.get_lr() - 0.01).abs() <= f32::EPSILON);
+//! ```
+//!
+//! The 1cycle policy is available as a [`OneCycleLR`], which warms the learning rate up to a
+//! peak and then anneals it well below its initial value over a fixed number of steps.
+//!
+//! ```
+//! # use neuronika::optim;
+//! # use neuronika::optim::{SGD, Optimizer, L2};
+//! # use neuronika::optim::lr_scheduler::{AnnealStrategy, LRScheduler, OneCycleLR};
+//! let optim = SGD::new(vec![], 0.01, L2::new(0.1));
+//! let scheduler = OneCycleLR::new(&optim, 0.1, 100, 0.3, 25., 1e4, AnnealStrategy::Cos);
+//! assert!((optim.get_lr() - 0.004).abs() <= f32::EPSILON);
+//!
+//! for _ in 0..100 {
+//!     scheduler.step();
+//! }
+//! assert!((optim.get_lr() - 1e-5).abs() <= f32::EPSILON);
+//! ```
+//!
+//! Schedulers that react to a monitored metric, such as [`ReduceLROnPlateau`], implement
+//! [`MetricLRScheduler`] instead and are stepped with `.step_with_metric()`, passing the value of
+//! the metric at the end of each epoch.
+//!
+//! ```
+//! # use neuronika::optim;
+//! # use neuronika::optim::{SGD, Optimizer, L2};
+//! # use neuronika::optim::lr_scheduler::{MetricLRScheduler, PlateauMode, ReduceLROnPlateau};
+//! # const EPOCHS: usize = 5;
+//! let optim = SGD::new(vec![], 0.01, L2::new(0.1));
+//! let scheduler = ReduceLROnPlateau::new(&optim, PlateauMode::Min, 0.1, 2, 1e-4, 0);
+//! # let mut loss = neuronika::ones(1).requires_grad() + 0.;
+//!
+//! for epoch in 0..EPOCHS {
+//!     loss.forward();
+//!     loss.backward(1.0);
+//!     optim.step();
+//!     optim.zero_grad();
+//!     scheduler.step_with_metric(loss.data()[0]);
 //! }
 //! ```
 use super::Optimizer;
 use std::cell::Cell;
 
+mod cyclic_lr;
+mod one_cycle_lr;
+mod warmup_lr;
+
+pub use cyclic_lr::{CyclicLR, CyclicMode};
+pub use one_cycle_lr::{AnnealStrategy, OneCycleLR};
+pub use warmup_lr::WarmupLR;
+
 /// Learning rate scheduler trait, defines the scheduler's logic.
 pub trait LRScheduler {
     /// Updates the learning rate.
@@ -62,6 +192,56 @@ pub trait LRScheduler {
     /// Sets the current epoch.
     fn set_current_epoch(&self, epoch: usize);
 
+    /// Sets the range in which the computed learning rates are clamped.
+    ///
+    /// The default implementation supports only the unbounded range `[0, f32::INFINITY]`.
+    ///
+    /// # Panics
+    ///
+    /// If the scheduler doesn't support bounding its learning rates, or if `min_lr` is negative or
+    /// greater than `max_lr`.
+    fn set_lr_bounds(&self, min_lr: f32, max_lr: f32) {
+        assert!(
+            min_lr == 0. && max_lr == f32::INFINITY,
+            "error: this scheduler doesn't support learning rate bounds."
+        );
+    }
+
+    /// Returns the range in which the computed learning rates are clamped.
+    ///
+    /// The default implementation returns the unbounded range `[0, f32::INFINITY]`.
+    fn get_lr_bounds(&self) -> (f32, f32) {
+        (0., f32::INFINITY)
+    }
+
+    /// Sets the granularity at which `.step()` is called. The current epoch is kept, while the
+    /// iterations performed within it are discarded.
+    ///
+    /// The default implementation supports only [`StepMode::Epoch`].
+    ///
+    /// # Panics
+    ///
+    /// If the scheduler doesn't support `mode`, or if `mode` is [`StepMode::Iteration`] and
+    /// `steps_per_epoch` is zero.
+    fn set_step_mode(&self, mode: StepMode) {
+        assert_eq!(
+            mode,
+            StepMode::Epoch,
+            "error: this scheduler can only be stepped once per epoch."
+        );
+    }
+
+    /// Returns the granularity at which `.step()` is called.
+    fn get_step_mode(&self) -> StepMode {
+        StepMode::Epoch
+    }
+
+    /// Returns the number of iterations performed so far. In [`StepMode::Epoch`] this is always
+    /// zero.
+    fn get_current_iteration(&self) -> usize {
+        0
+    }
+
     /// Prints the update of the learning rate. It should be called after `.step()`.
     fn print_lr(&self) {
         println!(
@@ -72,16 +252,241 @@ pub trait LRScheduler {
     }
 }
 
-/// Prepares a learning rate scheduler to perform the next update step.
+/// Learning rate scheduler driven by a monitored metric, such as a validation loss.
+///
+/// It mirrors [`LRScheduler`], except that the learning rate is updated by `.step_with_metric()`,
+/// which is given the value of the metric at the end of the epoch.
+pub trait MetricLRScheduler {
+    /// Updates the learning rate given the last value of the monitored metric.
+    fn step_with_metric(&self, metric: f32);
+
+    /// Returns an immutable reference to the last computed learning rate.
+    fn get_last_lr(&self) -> f32;
+
+    /// Returns an immutable reference to the current learning rate.
+    fn get_current_lr(&self) -> f32;
+
+    /// Returns an immutable reference to the current epoch.
+    fn get_current_epoch(&self) -> usize;
+
+    /// Sets the current epoch.
+    fn set_current_epoch(&self, epoch: usize);
+
+    /// Sets the range in which the computed learning rates are clamped.
+    ///
+    /// The default implementation supports only the unbounded range `[0, f32::INFINITY]`.
+    ///
+    /// # Panics
+    ///
+    /// If the scheduler doesn't support bounding its learning rates, or if `min_lr` is negative or
+    /// greater than `max_lr`.
+    fn set_lr_bounds(&self, min_lr: f32, max_lr: f32) {
+        assert!(
+            min_lr == 0. && max_lr == f32::INFINITY,
+            "error: this scheduler doesn't support learning rate bounds."
+        );
+    }
+
+    /// Returns the range in which the computed learning rates are clamped.
+    ///
+    /// The default implementation returns the unbounded range `[0, f32::INFINITY]`.
+    fn get_lr_bounds(&self) -> (f32, f32) {
+        (0., f32::INFINITY)
+    }
+
+    /// Prints the update of the learning rate. It should be called after `.step_with_metric()`.
+    fn print_lr(&self) {
+        println!(
+            "epoch {}: learning rate adjusted to [{}]",
+            self.get_current_epoch(),
+            self.get_current_lr()
+        );
+    }
+}
+
+/// Granularity at which a learning rate scheduler is stepped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepMode {
+    /// The scheduler is stepped once per epoch.
+    Epoch,
+    /// The scheduler is stepped once per iteration, an epoch is made of `steps_per_epoch`
+    /// iterations.
+    Iteration {
+        /// Number of iterations in an epoch.
+        steps_per_epoch: usize,
+    },
+}
+
+/// Counts the steps performed by a learning rate scheduler, converting them into epochs according
+/// to its [`StepMode`].
 ///
-/// Sets `last_lr` as `current_lr` and increases `current_epoch`.
-fn prepare_step(last_lr: &Cell<f32>, current_lr: &Cell<f32>, current_epoch: &Cell<usize>) {
-    // Set current learning rate as last learning rate.
+/// In [`StepMode::Epoch`] each step completes an epoch, while in [`StepMode::Iteration`] an epoch
+/// is completed every `steps_per_epoch` steps. The epoch count saturates at `usize::MAX`.
+struct StepCounter {
+    mode: Cell<StepMode>,
+    current_epoch: Cell<usize>,
+    current_iteration: Cell<usize>,
+}
+
+impl StepCounter {
+    /// Returns the number of steps in an epoch.
+    fn steps_per_epoch(&self) -> usize {
+        match self.mode.get() {
+            StepMode::Epoch => 1,
+            StepMode::Iteration { steps_per_epoch } => steps_per_epoch,
+        }
+    }
+
+    /// Sets the step mode, the iterations performed within the current epoch are discarded.
+    ///
+    /// # Panics
+    ///
+    /// If `mode` is [`StepMode::Iteration`] and `steps_per_epoch` is zero.
+    fn set_mode(&self, mode: StepMode) {
+        if let StepMode::Iteration { steps_per_epoch } = mode {
+            assert!(
+                steps_per_epoch > 0,
+                "error: steps_per_epoch must be positive."
+            );
+        }
+        self.mode.set(mode);
+        self.current_iteration.set(0);
+    }
+
+    /// Returns the step mode.
+    fn mode(&self) -> StepMode {
+        self.mode.get()
+    }
+
+    /// Performs a step, returns `true` if it completes an epoch.
+    fn advance(&self) -> bool {
+        let current_iteration = self.current_iteration.get() + 1;
+        if current_iteration < self.steps_per_epoch() {
+            self.current_iteration.set(current_iteration);
+            return false;
+        }
+
+        self.current_iteration.set(0);
+        self.current_epoch
+            .set(self.current_epoch.get().saturating_add(1));
+        true
+    }
+
+    /// Returns the number of epochs completed so far.
+    fn epoch(&self) -> usize {
+        self.current_epoch.get()
+    }
+
+    /// Sets the number of epochs completed so far and discards the iterations performed within
+    /// the current one.
+    fn set_epoch(&self, epoch: usize) {
+        self.current_epoch.set(epoch);
+        self.current_iteration.set(0);
+    }
+
+    /// Returns the number of iterations performed so far, saturating at `usize::MAX`. In
+    /// [`StepMode::Epoch`] this is always zero.
+    fn iteration(&self) -> usize {
+        match self.mode.get() {
+            StepMode::Epoch => 0,
+            StepMode::Iteration { steps_per_epoch } => self
+                .current_epoch
+                .get()
+                .saturating_mul(steps_per_epoch)
+                .saturating_add(self.current_iteration.get()),
+        }
+    }
+
+    /// Returns the number of steps performed so far, saturating at `usize::MAX`. These are the
+    /// epochs in [`StepMode::Epoch`] and the iterations in [`StepMode::Iteration`].
+    fn step_count(&self) -> usize {
+        self.current_epoch
+            .get()
+            .saturating_mul(self.steps_per_epoch())
+            .saturating_add(self.current_iteration.get())
+    }
+
+    /// Returns the number of epochs elapsed so far, including the fraction of the current one.
+    fn progress(&self) -> f32 {
+        self.current_epoch.get() as f32
+            + self.current_iteration.get() as f32 / self.steps_per_epoch() as f32
+    }
+}
+
+impl Default for StepCounter {
+    fn default() -> Self {
+        Self {
+            mode: Cell::new(StepMode::Epoch),
+            current_epoch: Cell::new(0),
+            current_iteration: Cell::new(0),
+        }
+    }
+}
+
+/// Prepares a learning rate scheduler whose learning rate changes once per epoch to perform the
+/// next update step.
+///
+/// Advances `steps` and, if an epoch has been completed, sets `last_lr` as `current_lr` and
+/// returns `true`.
+fn prepare_step(last_lr: &Cell<f32>, current_lr: &Cell<f32>, steps: &StepCounter) -> bool {
+    let completed = steps.advance();
+    if completed {
+        last_lr.set(current_lr.get());
+    }
+
+    completed
+}
+
+/// Prepares a learning rate scheduler whose learning rate changes at every step to perform the
+/// next update step.
+///
+/// Advances `steps` and sets `last_lr` as `current_lr`.
+fn prepare_iteration_step(last_lr: &Cell<f32>, current_lr: &Cell<f32>, steps: &StepCounter) {
+    steps.advance();
     last_lr.set(current_lr.get());
-    // Set current epoch as last epoch.
-    let last_epoch = current_epoch.get();
-    // Increase current epoch.
-    current_epoch.set(last_epoch + 1);
+}
+
+/// Range in which the learning rates computed by a scheduler are clamped.
+struct LRBounds {
+    min_lr: Cell<f32>,
+    max_lr: Cell<f32>,
+}
+
+impl LRBounds {
+    /// Sets the bounds.
+    ///
+    /// # Panics
+    ///
+    /// If `min_lr` is negative or greater than `max_lr`.
+    fn set(&self, min_lr: f32, max_lr: f32) {
+        assert!(
+            min_lr >= 0. && min_lr <= max_lr,
+            "error: invalid learning rate bounds [{}, {}].",
+            min_lr,
+            max_lr
+        );
+        self.min_lr.set(min_lr);
+        self.max_lr.set(max_lr);
+    }
+
+    /// Returns the bounds.
+    fn get(&self) -> (f32, f32) {
+        (self.min_lr.get(), self.max_lr.get())
+    }
+
+    /// Clamps `lr` in the bounds.
+    fn clamp(&self, lr: f32) -> f32 {
+        lr.clamp(self.min_lr.get(), self.max_lr.get())
+    }
+}
+
+impl Default for LRBounds {
+    fn default() -> Self {
+        Self {
+            min_lr: Cell::new(f32::MIN_POSITIVE),
+            max_lr: Cell::new(f32::MAX),
+        }
+    }
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ LambdaLR ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -94,10 +499,11 @@ fn prepare_step(last_lr: &Cell<f32>, current_lr: &Cell<f32>, current_epoch: &Cel
 pub struct LambdaLR<'a, T: Optimizer<'a>, F: Fn(usize) -> f32> {
     optimizer: &'a T,
     lr_fn: F,
-    current_epoch: Cell<usize>,
+    steps: StepCounter,
     current_lr: Cell<f32>,
     last_lr: Cell<f32>,
     initial_lr: Cell<f32>,
+    bounds: LRBounds,
 }
 
 impl<'a, T: Optimizer<'a>, F: Fn(usize) -> f32> LambdaLR<'a, T, F> {
@@ -114,10 +520,11 @@ impl<'a, T: Optimizer<'a>, F: Fn(usize) -> f32> LambdaLR<'a, T, F> {
         Self {
             optimizer,
             lr_fn,
-            current_epoch: Cell::new(0),
+            steps: StepCounter::default(),
             current_lr: Cell::new(current_lr),
             last_lr: Cell::new(0.0),
             initial_lr: Cell::new(current_lr),
+            bounds: LRBounds::default(),
         }
     }
 
@@ -146,6 +553,46 @@ impl<'a, T: Optimizer<'a>, F: Fn(usize) -> f32> LambdaLR<'a, T, F> {
         LRScheduler::get_current_epoch(self)
     }
 
+    /// Sets the range in which the learning rates computed by this scheduler are clamped.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_lr` - lower bound, defaults to `f32::MIN_POSITIVE`.
+    ///
+    /// * `max_lr` - upper bound, defaults to `f32::MAX`.
+    ///
+    /// # Panics
+    ///
+    /// If `min_lr` is negative or greater than `max_lr`.
+    pub fn set_lr_bounds(&self, min_lr: f32, max_lr: f32) {
+        LRScheduler::set_lr_bounds(self, min_lr, max_lr);
+    }
+
+    /// Returns the range in which the learning rates computed by this scheduler are clamped.
+    pub fn get_lr_bounds(&self) -> (f32, f32) {
+        LRScheduler::get_lr_bounds(self)
+    }
+
+    /// Sets the granularity at which `.step()` is called, see [`StepMode`].
+    ///
+    /// # Panics
+    ///
+    /// If `mode` is [`StepMode::Iteration`] and `steps_per_epoch` is zero.
+    pub fn set_step_mode(&self, mode: StepMode) {
+        LRScheduler::set_step_mode(self, mode);
+    }
+
+    /// Returns the granularity at which `.step()` is called.
+    pub fn get_step_mode(&self) -> StepMode {
+        LRScheduler::get_step_mode(self)
+    }
+
+    /// Returns the number of iterations performed so far. In [`StepMode::Epoch`] this is always
+    /// zero.
+    pub fn get_current_iteration(&self) -> usize {
+        LRScheduler::get_current_iteration(self)
+    }
+
     /// Prints the learning rate update together with the epoch.
     pub fn print_lr(&self) {
         LRScheduler::print_lr(self);
@@ -154,10 +601,13 @@ impl<'a, T: Optimizer<'a>, F: Fn(usize) -> f32> LambdaLR<'a, T, F> {
 
 impl<'a, T: Optimizer<'a>, F: Fn(usize) -> f32> LRScheduler for LambdaLR<'a, T, F> {
     fn step(&self) {
-        prepare_step(&self.last_lr, &self.current_lr, &self.current_epoch);
-        self.current_lr
-            .set(self.initial_lr.get() * (self.lr_fn)(self.current_epoch.get()));
-        self.optimizer.set_lr(self.current_lr.get());
+        if prepare_step(&self.last_lr, &self.current_lr, &self.steps) {
+            self.current_lr.set(
+                self.bounds
+                    .clamp(self.initial_lr.get() * (self.lr_fn)(self.steps.epoch())),
+            );
+            self.optimizer.set_lr(self.current_lr.get());
+        }
     }
 
     fn get_last_lr(&self) -> f32 {
@@ -169,11 +619,31 @@ impl<'a, T: Optimizer<'a>, F: Fn(usize) -> f32> LRScheduler for LambdaLR<'a, T,
     }
 
     fn set_current_epoch(&self, epoch: usize) {
-        self.current_epoch.replace(epoch);
+        self.steps.set_epoch(epoch);
     }
 
     fn get_current_epoch(&self) -> usize {
-        self.current_epoch.get()
+        self.steps.epoch()
+    }
+
+    fn set_lr_bounds(&self, min_lr: f32, max_lr: f32) {
+        self.bounds.set(min_lr, max_lr);
+    }
+
+    fn get_lr_bounds(&self) -> (f32, f32) {
+        self.bounds.get()
+    }
+
+    fn set_step_mode(&self, mode: StepMode) {
+        self.steps.set_mode(mode);
+    }
+
+    fn get_step_mode(&self) -> StepMode {
+        self.steps.mode()
+    }
+
+    fn get_current_iteration(&self) -> usize {
+        self.steps.iteration()
     }
 }
 
@@ -187,9 +657,10 @@ impl<'a, T: Optimizer<'a>, F: Fn(usize) -> f32> LRScheduler for LambdaLR<'a, T,
 pub struct MultiplicativeLR<'a, T: Optimizer<'a>, F: Fn(usize) -> f32> {
     optimizer: &'a T,
     lr_fn: F,
-    current_epoch: Cell<usize>,
+    steps: StepCounter,
     current_lr: Cell<f32>,
     last_lr: Cell<f32>,
+    bounds: LRBounds,
 }
 
 impl<'a, T: Optimizer<'a>, F: Fn(usize) -> f32> MultiplicativeLR<'a, T, F> {
@@ -206,9 +677,10 @@ impl<'a, T: Optimizer<'a>, F: Fn(usize) -> f32> MultiplicativeLR<'a, T, F> {
         Self {
             optimizer,
             lr_fn,
-            current_epoch: Cell::new(0),
+            steps: StepCounter::default(),
             current_lr: Cell::new(current_lr),
             last_lr: Cell::new(0.0),
+            bounds: LRBounds::default(),
         }
     }
 
@@ -237,6 +709,46 @@ impl<'a, T: Optimizer<'a>, F: Fn(usize) -> f32> MultiplicativeLR<'a, T, F> {
         LRScheduler::get_current_epoch(self)
     }
 
+    /// Sets the range in which the learning rates computed by this scheduler are clamped.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_lr` - lower bound, defaults to `f32::MIN_POSITIVE`.
+    ///
+    /// * `max_lr` - upper bound, defaults to `f32::MAX`.
+    ///
+    /// # Panics
+    ///
+    /// If `min_lr` is negative or greater than `max_lr`.
+    pub fn set_lr_bounds(&self, min_lr: f32, max_lr: f32) {
+        LRScheduler::set_lr_bounds(self, min_lr, max_lr);
+    }
+
+    /// Returns the range in which the learning rates computed by this scheduler are clamped.
+    pub fn get_lr_bounds(&self) -> (f32, f32) {
+        LRScheduler::get_lr_bounds(self)
+    }
+
+    /// Sets the granularity at which `.step()` is called, see [`StepMode`].
+    ///
+    /// # Panics
+    ///
+    /// If `mode` is [`StepMode::Iteration`] and `steps_per_epoch` is zero.
+    pub fn set_step_mode(&self, mode: StepMode) {
+        LRScheduler::set_step_mode(self, mode);
+    }
+
+    /// Returns the granularity at which `.step()` is called.
+    pub fn get_step_mode(&self) -> StepMode {
+        LRScheduler::get_step_mode(self)
+    }
+
+    /// Returns the number of iterations performed so far. In [`StepMode::Epoch`] this is always
+    /// zero.
+    pub fn get_current_iteration(&self) -> usize {
+        LRScheduler::get_current_iteration(self)
+    }
+
     /// Prints the learning rate update together with the epoch.
     pub fn print_lr(&self) {
         LRScheduler::print_lr(self);
@@ -245,10 +757,13 @@ impl<'a, T: Optimizer<'a>, F: Fn(usize) -> f32> MultiplicativeLR<'a, T, F> {
 
 impl<'a, T: Optimizer<'a>, F: Fn(usize) -> f32> LRScheduler for MultiplicativeLR<'a, T, F> {
     fn step(&self) {
-        prepare_step(&self.last_lr, &self.current_lr, &self.current_epoch);
-        self.current_lr
-            .set(self.last_lr.get() * (self.lr_fn)(self.current_epoch.get()));
-        self.optimizer.set_lr(self.current_lr.get());
+        if prepare_step(&self.last_lr, &self.current_lr, &self.steps) {
+            self.current_lr.set(
+                self.bounds
+                    .clamp(self.last_lr.get() * (self.lr_fn)(self.steps.epoch())),
+            );
+            self.optimizer.set_lr(self.current_lr.get());
+        }
     }
 
     fn get_last_lr(&self) -> f32 {
@@ -260,11 +775,31 @@ impl<'a, T: Optimizer<'a>, F: Fn(usize) -> f32> LRScheduler for MultiplicativeLR
     }
 
     fn set_current_epoch(&self, epoch: usize) {
-        self.current_epoch.replace(epoch);
+        self.steps.set_epoch(epoch);
     }
 
     fn get_current_epoch(&self) -> usize {
-        self.current_epoch.get()
+        self.steps.epoch()
+    }
+
+    fn set_lr_bounds(&self, min_lr: f32, max_lr: f32) {
+        self.bounds.set(min_lr, max_lr);
+    }
+
+    fn get_lr_bounds(&self) -> (f32, f32) {
+        self.bounds.get()
+    }
+
+    fn set_step_mode(&self, mode: StepMode) {
+        self.steps.set_mode(mode);
+    }
+
+    fn get_step_mode(&self) -> StepMode {
+        self.steps.mode()
+    }
+
+    fn get_current_iteration(&self) -> usize {
+        self.steps.iteration()
     }
 }
 
@@ -279,9 +814,10 @@ pub struct StepLR<'a, T: Optimizer<'a>> {
     optimizer: &'a T,
     gamma: f32,
     step_size: usize,
-    current_epoch: Cell<usize>,
+    steps: StepCounter,
     current_lr: Cell<f32>,
     last_lr: Cell<f32>,
+    bounds: LRBounds,
 }
 
 impl<'a, T: Optimizer<'a>> StepLR<'a, T> {
@@ -301,9 +837,10 @@ impl<'a, T: Optimizer<'a>> StepLR<'a, T> {
             optimizer,
             gamma,
             step_size,
-            current_epoch: Cell::new(0),
+            steps: StepCounter::default(),
             current_lr: Cell::new(current_lr),
             last_lr: Cell::new(0.0),
+            bounds: LRBounds::default(),
         }
     }
 
@@ -332,6 +869,46 @@ impl<'a, T: Optimizer<'a>> StepLR<'a, T> {
         LRScheduler::get_current_epoch(self)
     }
 
+    /// Sets the range in which the learning rates computed by this scheduler are clamped.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_lr` - lower bound, defaults to `f32::MIN_POSITIVE`.
+    ///
+    /// * `max_lr` - upper bound, defaults to `f32::MAX`.
+    ///
+    /// # Panics
+    ///
+    /// If `min_lr` is negative or greater than `max_lr`.
+    pub fn set_lr_bounds(&self, min_lr: f32, max_lr: f32) {
+        LRScheduler::set_lr_bounds(self, min_lr, max_lr);
+    }
+
+    /// Returns the range in which the learning rates computed by this scheduler are clamped.
+    pub fn get_lr_bounds(&self) -> (f32, f32) {
+        LRScheduler::get_lr_bounds(self)
+    }
+
+    /// Sets the granularity at which `.step()` is called, see [`StepMode`].
+    ///
+    /// # Panics
+    ///
+    /// If `mode` is [`StepMode::Iteration`] and `steps_per_epoch` is zero.
+    pub fn set_step_mode(&self, mode: StepMode) {
+        LRScheduler::set_step_mode(self, mode);
+    }
+
+    /// Returns the granularity at which `.step()` is called.
+    pub fn get_step_mode(&self) -> StepMode {
+        LRScheduler::get_step_mode(self)
+    }
+
+    /// Returns the number of iterations performed so far. In [`StepMode::Epoch`] this is always
+    /// zero.
+    pub fn get_current_iteration(&self) -> usize {
+        LRScheduler::get_current_iteration(self)
+    }
+
     /// Prints the learning rate update together with the epoch.
     pub fn print_lr(&self) {
         LRScheduler::print_lr(self);
@@ -340,9 +917,11 @@ impl<'a, T: Optimizer<'a>> StepLR<'a, T> {
 
 impl<'a, T: Optimizer<'a>> LRScheduler for StepLR<'a, T> {
     fn step(&self) {
-        prepare_step(&self.last_lr, &self.current_lr, &self.current_epoch);
-        if self.current_epoch.get().rem_euclid(self.step_size) == 0 {
-            self.current_lr.set(self.last_lr.get() * self.gamma);
+        if prepare_step(&self.last_lr, &self.current_lr, &self.steps)
+            && self.steps.epoch().rem_euclid(self.step_size) == 0
+        {
+            self.current_lr
+                .set(self.bounds.clamp(self.last_lr.get() * self.gamma));
             self.optimizer.set_lr(self.current_lr.get());
         }
     }
@@ -356,11 +935,31 @@ impl<'a, T: Optimizer<'a>> LRScheduler for StepLR<'a, T> {
     }
 
     fn set_current_epoch(&self, epoch: usize) {
-        self.current_epoch.replace(epoch);
+        self.steps.set_epoch(epoch);
     }
 
     fn get_current_epoch(&self) -> usize {
-        self.current_epoch.get()
+        self.steps.epoch()
+    }
+
+    fn set_lr_bounds(&self, min_lr: f32, max_lr: f32) {
+        self.bounds.set(min_lr, max_lr);
+    }
+
+    fn get_lr_bounds(&self) -> (f32, f32) {
+        self.bounds.get()
+    }
+
+    fn set_step_mode(&self, mode: StepMode) {
+        self.steps.set_mode(mode);
+    }
+
+    fn get_step_mode(&self) -> StepMode {
+        self.steps.mode()
+    }
+
+    fn get_current_iteration(&self) -> usize {
+        self.steps.iteration()
     }
 }
 
@@ -376,9 +975,10 @@ pub struct MultiStepLR<'a, T: Optimizer<'a>, const N: usize> {
     optimizer: &'a T,
     gamma: f32,
     milestones: [usize; N],
-    current_epoch: Cell<usize>,
+    steps: StepCounter,
     current_lr: Cell<f32>,
     last_lr: Cell<f32>,
+    bounds: LRBounds,
 }
 
 impl<'a, T: Optimizer<'a>, const N: usize> MultiStepLR<'a, T, N> {
@@ -398,9 +998,10 @@ impl<'a, T: Optimizer<'a>, const N: usize> MultiStepLR<'a, T, N> {
             optimizer,
             gamma,
             milestones,
-            current_epoch: Cell::new(0),
+            steps: StepCounter::default(),
             current_lr: Cell::new(current_lr),
             last_lr: Cell::new(0.0),
+            bounds: LRBounds::default(),
         }
     }
 
@@ -429,6 +1030,46 @@ impl<'a, T: Optimizer<'a>, const N: usize> MultiStepLR<'a, T, N> {
         LRScheduler::get_current_epoch(self)
     }
 
+    /// Sets the range in which the learning rates computed by this scheduler are clamped.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_lr` - lower bound, defaults to `f32::MIN_POSITIVE`.
+    ///
+    /// * `max_lr` - upper bound, defaults to `f32::MAX`.
+    ///
+    /// # Panics
+    ///
+    /// If `min_lr` is negative or greater than `max_lr`.
+    pub fn set_lr_bounds(&self, min_lr: f32, max_lr: f32) {
+        LRScheduler::set_lr_bounds(self, min_lr, max_lr);
+    }
+
+    /// Returns the range in which the learning rates computed by this scheduler are clamped.
+    pub fn get_lr_bounds(&self) -> (f32, f32) {
+        LRScheduler::get_lr_bounds(self)
+    }
+
+    /// Sets the granularity at which `.step()` is called, see [`StepMode`].
+    ///
+    /// # Panics
+    ///
+    /// If `mode` is [`StepMode::Iteration`] and `steps_per_epoch` is zero.
+    pub fn set_step_mode(&self, mode: StepMode) {
+        LRScheduler::set_step_mode(self, mode);
+    }
+
+    /// Returns the granularity at which `.step()` is called.
+    pub fn get_step_mode(&self) -> StepMode {
+        LRScheduler::get_step_mode(self)
+    }
+
+    /// Returns the number of iterations performed so far. In [`StepMode::Epoch`] this is always
+    /// zero.
+    pub fn get_current_iteration(&self) -> usize {
+        LRScheduler::get_current_iteration(self)
+    }
+
     /// Prints the learning rate update together with the epoch.
     pub fn print_lr(&self) {
         LRScheduler::print_lr(self);
@@ -437,13 +1078,14 @@ impl<'a, T: Optimizer<'a>, const N: usize> MultiStepLR<'a, T, N> {
 
 impl<'a, T: Optimizer<'a>, const N: usize> LRScheduler for MultiStepLR<'a, T, N> {
     fn step(&self) {
-        prepare_step(&self.last_lr, &self.current_lr, &self.current_epoch);
-        if self
-            .milestones
-            .iter()
-            .any(|milestone| *milestone == self.current_epoch.get())
+        if prepare_step(&self.last_lr, &self.current_lr, &self.steps)
+            && self
+                .milestones
+                .iter()
+                .any(|milestone| *milestone == self.steps.epoch())
         {
-            self.current_lr.set(self.last_lr.get() * self.gamma);
+            self.current_lr
+                .set(self.bounds.clamp(self.last_lr.get() * self.gamma));
             self.optimizer.set_lr(self.current_lr.get());
         }
     }
@@ -457,11 +1099,31 @@ impl<'a, T: Optimizer<'a>, const N: usize> LRScheduler for MultiStepLR<'a, T, N>
     }
 
     fn set_current_epoch(&self, epoch: usize) {
-        self.current_epoch.replace(epoch);
+        self.steps.set_epoch(epoch);
     }
 
     fn get_current_epoch(&self) -> usize {
-        self.current_epoch.get()
+        self.steps.epoch()
+    }
+
+    fn set_lr_bounds(&self, min_lr: f32, max_lr: f32) {
+        self.bounds.set(min_lr, max_lr);
+    }
+
+    fn get_lr_bounds(&self) -> (f32, f32) {
+        self.bounds.get()
+    }
+
+    fn set_step_mode(&self, mode: StepMode) {
+        self.steps.set_mode(mode);
+    }
+
+    fn get_step_mode(&self) -> StepMode {
+        self.steps.mode()
+    }
+
+    fn get_current_iteration(&self) -> usize {
+        self.steps.iteration()
     }
 }
 
@@ -475,9 +1137,10 @@ impl<'a, T: Optimizer<'a>, const N: usize> LRScheduler for MultiStepLR<'a, T, N>
 pub struct ExponentialLR<'a, T: Optimizer<'a>> {
     optimizer: &'a T,
     gamma: f32,
-    current_epoch: Cell<usize>,
+    steps: StepCounter,
     current_lr: Cell<f32>,
     last_lr: Cell<f32>,
+    bounds: LRBounds,
 }
 
 impl<'a, T: Optimizer<'a>> ExponentialLR<'a, T> {
@@ -494,9 +1157,10 @@ impl<'a, T: Optimizer<'a>> ExponentialLR<'a, T> {
         Self {
             optimizer,
             gamma,
-            current_epoch: Cell::new(0),
+            steps: StepCounter::default(),
             current_lr: Cell::new(current_lr),
             last_lr: Cell::new(0.0),
+            bounds: LRBounds::default(),
         }
     }
 
@@ -525,6 +1189,46 @@ impl<'a, T: Optimizer<'a>> ExponentialLR<'a, T> {
         LRScheduler::get_current_epoch(self)
     }
 
+    /// Sets the range in which the learning rates computed by this scheduler are clamped.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_lr` - lower bound, defaults to `f32::MIN_POSITIVE`.
+    ///
+    /// * `max_lr` - upper bound, defaults to `f32::MAX`.
+    ///
+    /// # Panics
+    ///
+    /// If `min_lr` is negative or greater than `max_lr`.
+    pub fn set_lr_bounds(&self, min_lr: f32, max_lr: f32) {
+        LRScheduler::set_lr_bounds(self, min_lr, max_lr);
+    }
+
+    /// Returns the range in which the learning rates computed by this scheduler are clamped.
+    pub fn get_lr_bounds(&self) -> (f32, f32) {
+        LRScheduler::get_lr_bounds(self)
+    }
+
+    /// Sets the granularity at which `.step()` is called, see [`StepMode`].
+    ///
+    /// # Panics
+    ///
+    /// If `mode` is [`StepMode::Iteration`] and `steps_per_epoch` is zero.
+    pub fn set_step_mode(&self, mode: StepMode) {
+        LRScheduler::set_step_mode(self, mode);
+    }
+
+    /// Returns the granularity at which `.step()` is called.
+    pub fn get_step_mode(&self) -> StepMode {
+        LRScheduler::get_step_mode(self)
+    }
+
+    /// Returns the number of iterations performed so far. In [`StepMode::Epoch`] this is always
+    /// zero.
+    pub fn get_current_iteration(&self) -> usize {
+        LRScheduler::get_current_iteration(self)
+    }
+
     /// Prints the learning rate update together with the epoch.
     pub fn print_lr(&self) {
         LRScheduler::print_lr(self);
@@ -533,9 +1237,11 @@ impl<'a, T: Optimizer<'a>> ExponentialLR<'a, T> {
 
 impl<'a, T: Optimizer<'a>> LRScheduler for ExponentialLR<'a, T> {
     fn step(&self) {
-        prepare_step(&self.last_lr, &self.current_lr, &self.current_epoch);
-        self.current_lr.set(self.last_lr.get() * self.gamma);
-        self.optimizer.set_lr(self.current_lr.get());
+        if prepare_step(&self.last_lr, &self.current_lr, &self.steps) {
+            self.current_lr
+                .set(self.bounds.clamp(self.last_lr.get() * self.gamma));
+            self.optimizer.set_lr(self.current_lr.get());
+        }
     }
 
     fn get_last_lr(&self) -> f32 {
@@ -547,12 +1253,264 @@ impl<'a, T: Optimizer<'a>> LRScheduler for ExponentialLR<'a, T> {
     }
 
     fn set_current_epoch(&self, epoch: usize) {
-        self.current_epoch.replace(epoch);
+        self.steps.set_epoch(epoch);
     }
 
     fn get_current_epoch(&self) -> usize {
-        self.current_epoch.get()
+        self.steps.epoch()
     }
+
+    fn set_lr_bounds(&self, min_lr: f32, max_lr: f32) {
+        self.bounds.set(min_lr, max_lr);
+    }
+
+    fn get_lr_bounds(&self) -> (f32, f32) {
+        self.bounds.get()
+    }
+
+    fn set_step_mode(&self, mode: StepMode) {
+        self.steps.set_mode(mode);
+    }
+
+    fn get_step_mode(&self) -> StepMode {
+        self.steps.mode()
+    }
+
+    fn get_current_iteration(&self) -> usize {
+        self.steps.iteration()
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ ReduceLROnPlateau ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Whether a monitored metric should be minimized or maximized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlateauMode {
+    /// The metric improves when it decreases, as a loss does.
+    Min,
+    /// The metric improves when it increases, as an accuracy does.
+    Max,
+}
+
+/// Reduces the learning rate when a metric has stopped improving.
+///
+/// The metric is considered improved when it is better than the best one seen so far by more than
+/// `threshold` times its magnitude. After `patience` consecutive epochs without improvement the
+/// learning rate is multiplied by `factor`, then no epoch is counted as bad for the next `cooldown`
+/// epochs.
+///
+///```text
+/// lrₜ = lrₜ₋₁ * factor if the metric did not improve for more than patience epochs else lrₜ₋₁
+///```
+pub struct ReduceLROnPlateau<'a, T: Optimizer<'a>> {
+    optimizer: &'a T,
+    mode: PlateauMode,
+    factor: f32,
+    patience: usize,
+    threshold: f32,
+    cooldown: usize,
+    best: Cell<f32>,
+    num_bad_epochs: Cell<usize>,
+    cooldown_counter: Cell<usize>,
+    steps: StepCounter,
+    current_lr: Cell<f32>,
+    last_lr: Cell<f32>,
+    bounds: LRBounds,
 }
+
+impl<'a, T: Optimizer<'a>> ReduceLROnPlateau<'a, T> {
+    /// Creates a new ReduceLROnPlateau scheduler.
+    ///
+    /// # Arguments
+    ///
+    /// * `optimizer` - wrapped optimizer.
+    ///
+    /// * `mode` - whether the metric should be minimized or maximized.
+    ///
+    /// * `factor` - multiplicative factor for the learning rate decay.
+    ///
+    /// * `patience` - number of epochs without improvement after which the learning rate is
+    /// decayed.
+    ///
+    /// * `threshold` - relative amount by which the metric must improve to be considered better.
+    ///
+    /// * `cooldown` - number of epochs to wait after a decay before counting bad epochs again.
+    ///
+    /// # Panics
+    ///
+    /// If `factor` is not in the open range `(0, 1)` or `threshold` is negative.
+    pub fn new(
+        optimizer: &'a T,
+        mode: PlateauMode,
+        factor: f32,
+        patience: usize,
+        threshold: f32,
+        cooldown: usize,
+    ) -> Self {
+        assert!(
+            factor > 0. && factor < 1.,
+            "error: factor must be in (0, 1), got {}.",
+            factor
+        );
+        assert!(
+            threshold >= 0.,
+            "error: threshold must be non negative, got {}.",
+            threshold
+        );
+        let current_lr = optimizer.get_lr();
+
+        Self {
+            optimizer,
+            mode,
+            factor,
+            patience,
+            threshold,
+            cooldown,
+            best: Cell::new(Self::worst(mode)),
+            num_bad_epochs: Cell::new(0),
+            cooldown_counter: Cell::new(0),
+            steps: StepCounter::default(),
+            current_lr: Cell::new(current_lr),
+            last_lr: Cell::new(0.0),
+            bounds: LRBounds::default(),
+        }
+    }
+
+    /// Records the metric of the epoch that just ended and decays the learning rate if it has not
+    /// improved for more than `patience` epochs.
+    pub fn step_with_metric(&self, metric: f32) {
+        MetricLRScheduler::step_with_metric(self, metric);
+    }
+
+    /// Returns the last learning rate value computed by this learning rate scheduler.
+    pub fn get_last_lr(&self) -> f32 {
+        MetricLRScheduler::get_last_lr(self)
+    }
+
+    /// Returns the current learning rate value computed by this learning rate scheduler.
+    pub fn get_current_lr(&self) -> f32 {
+        MetricLRScheduler::get_current_lr(self)
+    }
+
+    /// Sets the current epoch for this learning rate scheduler.
+    pub fn set_current_epoch(&self, epoch: usize) {
+        MetricLRScheduler::set_current_epoch(self, epoch);
+    }
+
+    /// Returns the current epoch for this learning rate scheduler.
+    pub fn get_current_epoch(&self) -> usize {
+        MetricLRScheduler::get_current_epoch(self)
+    }
+
+    /// Sets the range in which the learning rates computed by this scheduler are clamped.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_lr` - lower bound, defaults to `f32::MIN_POSITIVE`.
+    ///
+    /// * `max_lr` - upper bound, defaults to `f32::MAX`.
+    ///
+    /// # Panics
+    ///
+    /// If `min_lr` is negative or greater than `max_lr`.
+    pub fn set_lr_bounds(&self, min_lr: f32, max_lr: f32) {
+        MetricLRScheduler::set_lr_bounds(self, min_lr, max_lr);
+    }
+
+    /// Returns the range in which the learning rates computed by this scheduler are clamped.
+    pub fn get_lr_bounds(&self) -> (f32, f32) {
+        MetricLRScheduler::get_lr_bounds(self)
+    }
+
+    /// Returns the best metric seen so far.
+    pub fn get_best_metric(&self) -> f32 {
+        self.best.get()
+    }
+
+    /// Returns the number of consecutive epochs without improvement.
+    pub fn get_num_bad_epochs(&self) -> usize {
+        self.num_bad_epochs.get()
+    }
+
+    /// Prints the learning rate update together with the epoch.
+    pub fn print_lr(&self) {
+        MetricLRScheduler::print_lr(self);
+    }
+
+    /// Returns the worst possible value of a metric in `mode`.
+    fn worst(mode: PlateauMode) -> f32 {
+        match mode {
+            PlateauMode::Min => f32::INFINITY,
+            PlateauMode::Max => f32::NEG_INFINITY,
+        }
+    }
+
+    /// Returns `true` if `metric` improves on the best metric by more than the threshold.
+    fn is_better(&self, metric: f32) -> bool {
+        let best = self.best.get();
+        // Before the first step the best metric is infinite and any finite metric improves on it.
+        let margin = if best.is_finite() {
+            self.threshold * best.abs()
+        } else {
+            0.
+        };
+        match self.mode {
+            PlateauMode::Min => metric < best - margin,
+            PlateauMode::Max => metric > best + margin,
+        }
+    }
+}
+
+impl<'a, T: Optimizer<'a>> MetricLRScheduler for ReduceLROnPlateau<'a, T> {
+    fn step_with_metric(&self, metric: f32) {
+        prepare_step(&self.last_lr, &self.current_lr, &self.steps);
+
+        if self.is_better(metric) {
+            self.best.set(metric);
+            self.num_bad_epochs.set(0);
+        } else {
+            self.num_bad_epochs
+                .set(self.num_bad_epochs.get().saturating_add(1));
+        }
+
+        if self.cooldown_counter.get() > 0 {
+            self.cooldown_counter.set(self.cooldown_counter.get() - 1);
+            self.num_bad_epochs.set(0);
+        }
+
+        if self.num_bad_epochs.get() > self.patience {
+            self.current_lr
+                .set(self.bounds.clamp(self.last_lr.get() * self.factor));
+            self.optimizer.set_lr(self.current_lr.get());
+            self.cooldown_counter.set(self.cooldown);
+            self.num_bad_epochs.set(0);
+        }
+    }
+
+    fn get_last_lr(&self) -> f32 {
+        self.last_lr.get()
+    }
+
+    fn get_current_lr(&self) -> f32 {
+        self.current_lr.get()
+    }
+
+    fn set_current_epoch(&self, epoch: usize) {
+        self.steps.set_epoch(epoch);
+    }
+
+    fn get_current_epoch(&self) -> usize {
+        self.steps.epoch()
+    }
+
+    fn set_lr_bounds(&self, min_lr: f32, max_lr: f32) {
+        self.bounds.set(min_lr, max_lr);
+    }
+
+    fn get_lr_bounds(&self) -> (f32, f32) {
+        self.bounds.get()
+    }
+}
+
 #[cfg(test)]
 mod test;
@@ -0,0 +1,255 @@
+use super::{
+    super::Optimizer, prepare_iteration_step, LRBounds, LRScheduler, StepCounter, StepMode,
+};
+use std::{cell::Cell, f32::consts::PI};
+
+/// Strategy by which a [`OneCycleLR`] scheduler interpolates the learning rate within a phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnnealStrategy {
+    /// Cosine annealing.
+    Cos,
+    /// Linear annealing.
+    Linear,
+}
+
+impl AnnealStrategy {
+    /// Interpolates between `start` and `end`, `progress` goes from 0 to 1.
+    fn anneal(&self, start: f32, end: f32, progress: f32) -> f32 {
+        match self {
+            AnnealStrategy::Cos => end + (start - end) / 2. * (1. + (PI * progress).cos()),
+            AnnealStrategy::Linear => start + (end - start) * progress,
+        }
+    }
+}
+
+/// Sets the learning rate according to the 1cycle policy, as described in
+/// [A disciplined approach to neural network hyper-parameters](https://arxiv.org/abs/1803.09820).
+///
+/// The policy is made of three phases:
+///
+/// 1. the learning rate is annealed from `max_lr / div_factor` up to `max_lr` during the first
+/// `pct_start * total_steps` steps.
+///
+/// 2. the learning rate is annealed back to `max_lr / div_factor` in the same number of steps,
+/// or in all but the last of the remaining ones if they are fewer.
+///
+/// 3. the learning rate is annealed down to `max_lr / final_div_factor` during the remaining
+/// steps, and it stays there afterwards.
+///
+/// The phases are measured in steps, that is, in calls to `.step()`. The policy is meant to be
+/// applied once per iteration: if that's the desired behaviour set [`StepMode::Iteration`] with
+/// `.set_step_mode()`, call `.step()` after each optimizer's update and count `total_steps` in
+/// iterations, `.get_current_epoch()` then keeps counting the epochs.
+pub struct OneCycleLR<'a, T: Optimizer<'a>> {
+    optimizer: &'a T,
+    initial_lr: f32,
+    max_lr: f32,
+    min_lr: f32,
+    step_size: usize,
+    decay_size: usize,
+    total_steps: usize,
+    anneal_strategy: AnnealStrategy,
+    steps: StepCounter,
+    current_lr: Cell<f32>,
+    last_lr: Cell<f32>,
+    bounds: LRBounds,
+}
+
+impl<'a, T: Optimizer<'a>> OneCycleLR<'a, T> {
+    /// Creates a new OneCycleLR scheduler.
+    ///
+    /// The optimizer's learning rate is immediately set to `max_lr / div_factor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `optimizer` - wrapped optimizer.
+    ///
+    /// * `max_lr` - peak learning rate of the cycle.
+    ///
+    /// * `total_steps` - number of steps in the cycle.
+    ///
+    /// * `pct_start` - fraction of the cycle spent increasing the learning rate.
+    ///
+    /// * `div_factor` - the initial learning rate is `max_lr / div_factor`.
+    ///
+    /// * `final_div_factor` - the final learning rate is `max_lr / final_div_factor`.
+    ///
+    /// * `anneal_strategy` - interpolation strategy used within each phase.
+    ///
+    /// # Panics
+    ///
+    /// If `pct_start` is not in the range (0, 1) or if `total_steps` is too small to fit the
+    /// three phases.
+    pub fn new(
+        optimizer: &'a T,
+        max_lr: f32,
+        total_steps: usize,
+        pct_start: f32,
+        div_factor: f32,
+        final_div_factor: f32,
+        anneal_strategy: AnnealStrategy,
+    ) -> Self {
+        assert!(
+            pct_start > 0. && pct_start < 1.,
+            "error: pct_start must be in the range (0, 1), got {}.",
+            pct_start
+        );
+        let step_size = (pct_start * total_steps as f32).round() as usize;
+        assert!(
+            step_size > 0 && step_size + 2 <= total_steps,
+            "error: total_steps is too small to fit the three phases, got {}.",
+            total_steps
+        );
+        // The last phase lasts at least one step.
+        let decay_size = step_size.min(total_steps - step_size - 1);
+        let initial_lr = max_lr / div_factor;
+        optimizer.set_lr(initial_lr);
+
+        Self {
+            optimizer,
+            initial_lr,
+            max_lr,
+            min_lr: max_lr / final_div_factor,
+            step_size,
+            decay_size,
+            total_steps,
+            anneal_strategy,
+            steps: StepCounter::default(),
+            current_lr: Cell::new(initial_lr),
+            last_lr: Cell::new(0.0),
+            bounds: LRBounds::default(),
+        }
+    }
+
+    /// Computes the learning rate at the current step of the cycle.
+    fn one_cycle_lr(&self) -> f32 {
+        let step = self.steps.step_count();
+        let (start, end, progress) = if step <= self.step_size {
+            let progress = step as f32 / self.step_size as f32;
+            (self.initial_lr, self.max_lr, progress)
+        } else if step <= self.step_size + self.decay_size {
+            let progress = (step - self.step_size) as f32 / self.decay_size as f32;
+            (self.max_lr, self.initial_lr, progress)
+        } else {
+            let annealed = self.step_size + self.decay_size;
+            let progress = (step - annealed) as f32 / (self.total_steps - annealed) as f32;
+            (self.initial_lr, self.min_lr, progress.min(1.))
+        };
+
+        self.anneal_strategy.anneal(start, end, progress)
+    }
+
+    /// Moves the learning rate along the cycle.
+    pub fn step(&self) {
+        LRScheduler::step(self);
+    }
+
+    /// Returns the last learning rate value computed by this learning rate scheduler.
+    pub fn get_last_lr(&self) -> f32 {
+        LRScheduler::get_last_lr(self)
+    }
+
+    /// Returns the current learning rate value computed by this learning rate scheduler.
+    pub fn get_current_lr(&self) -> f32 {
+        LRScheduler::get_current_lr(self)
+    }
+
+    /// Sets the current epoch for this learning rate scheduler.
+    pub fn set_current_epoch(&self, epoch: usize) {
+        LRScheduler::set_current_epoch(self, epoch);
+    }
+
+    /// Returns the current epoch for this learning rate scheduler.
+    pub fn get_current_epoch(&self) -> usize {
+        LRScheduler::get_current_epoch(self)
+    }
+
+    /// Sets the range in which the learning rates computed by this scheduler are clamped.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_lr` - lower bound, defaults to `f32::MIN_POSITIVE`.
+    ///
+    /// * `max_lr` - upper bound, defaults to `f32::MAX`.
+    ///
+    /// # Panics
+    ///
+    /// If `min_lr` is negative or greater than `max_lr`.
+    pub fn set_lr_bounds(&self, min_lr: f32, max_lr: f32) {
+        LRScheduler::set_lr_bounds(self, min_lr, max_lr);
+    }
+
+    /// Returns the range in which the learning rates computed by this scheduler are clamped.
+    pub fn get_lr_bounds(&self) -> (f32, f32) {
+        LRScheduler::get_lr_bounds(self)
+    }
+
+    /// Sets the granularity at which `.step()` is called, see [`StepMode`].
+    ///
+    /// # Panics
+    ///
+    /// If `mode` is [`StepMode::Iteration`] and `steps_per_epoch` is zero.
+    pub fn set_step_mode(&self, mode: StepMode) {
+        LRScheduler::set_step_mode(self, mode);
+    }
+
+    /// Returns the granularity at which `.step()` is called.
+    pub fn get_step_mode(&self) -> StepMode {
+        LRScheduler::get_step_mode(self)
+    }
+
+    /// Returns the number of iterations performed so far. In [`StepMode::Epoch`] this is always
+    /// zero.
+    pub fn get_current_iteration(&self) -> usize {
+        LRScheduler::get_current_iteration(self)
+    }
+
+    /// Prints the learning rate update together with the epoch.
+    pub fn print_lr(&self) {
+        LRScheduler::print_lr(self);
+    }
+}
+
+impl<'a, T: Optimizer<'a>> LRScheduler for OneCycleLR<'a, T> {
+    fn step(&self) {
+        prepare_iteration_step(&self.last_lr, &self.current_lr, &self.steps);
+        self.current_lr.set(self.bounds.clamp(self.one_cycle_lr()));
+        self.optimizer.set_lr(self.current_lr.get());
+    }
+
+    fn get_last_lr(&self) -> f32 {
+        self.last_lr.get()
+    }
+
+    fn get_current_lr(&self) -> f32 {
+        self.current_lr.get()
+    }
+
+    fn set_current_epoch(&self, epoch: usize) {
+        self.steps.set_epoch(epoch);
+    }
+
+    fn get_current_epoch(&self) -> usize {
+        self.steps.epoch()
+    }
+
+    fn set_lr_bounds(&self, min_lr: f32, max_lr: f32) {
+        self.bounds.set(min_lr, max_lr);
+    }
+
+    fn get_lr_bounds(&self) -> (f32, f32) {
+        self.bounds.get()
+    }
+
+    fn set_step_mode(&self, mode: StepMode) {
+        self.steps.set_mode(mode);
+    }
+
+    fn get_step_mode(&self) -> StepMode {
+        self.steps.mode()
+    }
+
+    fn get_current_iteration(&self) -> usize {
+        self.steps.iteration()
+    }
+}
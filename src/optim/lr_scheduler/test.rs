@@ -1,5 +1,40 @@
 use super::super::{L2, SGD};
-use super::{ExponentialLR, LambdaLR, MultiStepLR, MultiplicativeLR, StepLR};
+use super::{
+    AnnealStrategy, CyclicLR, CyclicMode, ExponentialLR, LRScheduler, LambdaLR, MultiStepLR,
+    MultiplicativeLR, OneCycleLR, PlateauMode, ReduceLROnPlateau, StepLR, StepMode, WarmupLR,
+};
+use std::cell::Cell;
+
+/// Scheduler implementing only the required methods of `LRScheduler`, as a downstream crate would.
+struct HalvingLR<'a> {
+    optimizer: &'a SGD<'a, L2>,
+    current_epoch: Cell<usize>,
+    last_lr: Cell<f32>,
+}
+
+impl<'a> LRScheduler for HalvingLR<'a> {
+    fn step(&self) {
+        self.last_lr.set(self.optimizer.get_lr());
+        self.current_epoch.set(self.current_epoch.get() + 1);
+        self.optimizer.set_lr(self.last_lr.get() / 2.);
+    }
+
+    fn get_last_lr(&self) -> f32 {
+        self.last_lr.get()
+    }
+
+    fn get_current_lr(&self) -> f32 {
+        self.optimizer.get_lr()
+    }
+
+    fn get_current_epoch(&self) -> usize {
+        self.current_epoch.get()
+    }
+
+    fn set_current_epoch(&self, epoch: usize) {
+        self.current_epoch.set(epoch);
+    }
+}
 
 #[test]
 fn lambda_lr() {
@@ -115,3 +150,513 @@ fn exponential_lr() {
     assert!((scheduler.get_current_lr() - 5_f32.powi(5)).abs() <= f32::EPSILON);
     // Should be 5^5.
 }
+
+#[test]
+fn step_mode() {
+    const EPOCHS: usize = 6;
+    const STEPS_PER_EPOCH: usize = 4;
+    let epoch_optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let epoch_scheduler = StepLR::new(&epoch_optim, 2, 0.5);
+    let iteration_optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let iteration_scheduler = StepLR::new(&iteration_optim, 2, 0.5);
+    iteration_scheduler.set_step_mode(StepMode::Iteration {
+        steps_per_epoch: STEPS_PER_EPOCH,
+    });
+    assert_eq!(epoch_scheduler.get_step_mode(), StepMode::Epoch);
+    assert_eq!(
+        iteration_scheduler.get_step_mode(),
+        StepMode::Iteration {
+            steps_per_epoch: STEPS_PER_EPOCH
+        }
+    );
+
+    iteration_scheduler.set_current_epoch(3);
+    assert_eq!(iteration_scheduler.get_current_epoch(), 3);
+    assert_eq!(iteration_scheduler.get_current_iteration(), 12);
+    iteration_scheduler.set_current_epoch(0);
+    assert_eq!(iteration_scheduler.get_current_iteration(), 0);
+
+    let mut epoch_lrs = Vec::new();
+    let mut iteration_lrs = Vec::new();
+    for epoch in 0..EPOCHS {
+        for _ in 0..STEPS_PER_EPOCH {
+            iteration_optim.step();
+            iteration_scheduler.step();
+        }
+        epoch_optim.step();
+        epoch_scheduler.step();
+
+        assert_eq!(epoch_scheduler.get_current_epoch(), epoch + 1);
+        assert_eq!(iteration_scheduler.get_current_epoch(), epoch + 1);
+        epoch_lrs.push(epoch_optim.get_lr());
+        iteration_lrs.push(iteration_optim.get_lr());
+    }
+
+    assert_eq!(epoch_lrs, iteration_lrs);
+    assert_eq!(epoch_lrs, vec![1., 0.5, 0.5, 0.25, 0.25, 0.125]);
+    assert_eq!(
+        iteration_scheduler.get_current_iteration(),
+        EPOCHS * STEPS_PER_EPOCH
+    );
+    assert_eq!(epoch_scheduler.get_current_iteration(), 0);
+}
+
+#[test]
+#[should_panic(expected = "error: steps_per_epoch must be positive.")]
+fn step_mode_zero_steps_per_epoch() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    StepLR::new(&optim, 2, 0.5).set_step_mode(StepMode::Iteration { steps_per_epoch: 0 });
+}
+
+#[test]
+fn one_cycle_lr_iteration_mode() {
+    const STEPS_PER_EPOCH: usize = 4;
+    let epoch_optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let epoch_scheduler =
+        OneCycleLR::new(&epoch_optim, 1., 10, 0.3, 10., 100., AnnealStrategy::Cos);
+    let iteration_optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    // The cycle is measured in steps, which are iterations in the iteration mode.
+    let iteration_scheduler = OneCycleLR::new(
+        &iteration_optim,
+        1.,
+        10 * STEPS_PER_EPOCH,
+        0.3,
+        10.,
+        100.,
+        AnnealStrategy::Cos,
+    );
+    iteration_scheduler.set_step_mode(StepMode::Iteration {
+        steps_per_epoch: STEPS_PER_EPOCH,
+    });
+
+    for epoch in 0..10 {
+        let last_lr = iteration_optim.get_lr();
+        let mut lrs = Vec::new();
+        for _ in 0..STEPS_PER_EPOCH {
+            iteration_scheduler.step();
+            lrs.push(iteration_optim.get_lr());
+        }
+        epoch_scheduler.step();
+
+        // The learning rate is annealed at every iteration and agrees with the epoch mode at the
+        // end of each epoch.
+        assert!(lrs.iter().all(|lr| *lr != last_lr));
+        assert!((epoch_optim.get_lr() - iteration_optim.get_lr()).abs() <= 1e-6);
+        assert_eq!(iteration_scheduler.get_current_epoch(), epoch + 1);
+        assert_eq!(
+            iteration_scheduler.get_current_epoch(),
+            epoch_scheduler.get_current_epoch()
+        );
+    }
+    assert!((iteration_optim.get_lr() - 0.01).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn cyclic_lr_iteration_mode() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = CyclicLR::new(&optim, 0.1, 1., 2, 2, CyclicMode::Triangular);
+    scheduler.set_step_mode(StepMode::Iteration { steps_per_epoch: 2 });
+
+    let mut lrs = Vec::new();
+    for _ in 0..8 {
+        scheduler.step();
+        lrs.push(optim.get_lr());
+    }
+    let expected = [0.325, 0.55, 0.775, 1., 0.775, 0.55, 0.325, 0.1];
+    for (lr, expected) in lrs.iter().zip(expected) {
+        assert!((lr - expected).abs() <= 1e-6);
+    }
+    assert_eq!(scheduler.get_current_epoch(), 4);
+    assert_eq!(scheduler.get_current_iteration(), 8);
+}
+
+#[test]
+fn warmup_lr() {
+    const WARMUP_STEPS: usize = 3;
+    const EPOCHS: usize = 6;
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = WarmupLR::new(&optim, Box::new(StepLR::new(&optim, 2, 0.5)), WARMUP_STEPS);
+    let reference_optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let reference = StepLR::new(&reference_optim, 2, 0.5);
+
+    // The learning rate grows linearly up to the initial one of the wrapped scheduler.
+    let mut warmup_lrs = vec![optim.get_lr()];
+    for epoch in 0..WARMUP_STEPS {
+        assert_eq!(scheduler.get_current_epoch(), epoch);
+        scheduler.step();
+        warmup_lrs.push(optim.get_lr());
+    }
+    assert_eq!(warmup_lrs, vec![0.25, 0.5, 0.75, 1.]);
+    assert_eq!(scheduler.get_current_lr(), 1.);
+    assert_eq!(scheduler.get_last_lr(), 0.75);
+    assert_eq!(scheduler.scheduler().get_current_epoch(), 0);
+
+    // Then it follows the wrapped scheduler's policy as if there was no warmup.
+    for epoch in 0..EPOCHS {
+        scheduler.step();
+        reference.step();
+
+        assert_eq!(scheduler.get_current_epoch(), WARMUP_STEPS + epoch + 1);
+        assert_eq!(scheduler.scheduler().get_current_epoch(), epoch + 1);
+        assert_eq!(optim.get_lr(), reference_optim.get_lr());
+        assert_eq!(scheduler.get_current_lr(), reference.get_current_lr());
+        assert_eq!(scheduler.get_last_lr(), reference.get_last_lr());
+    }
+}
+
+#[test]
+fn warmup_lr_current_epoch() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = WarmupLR::new(&optim, Box::new(StepLR::new(&optim, 2, 0.5)), 4);
+
+    scheduler.set_current_epoch(2);
+    assert_eq!(scheduler.get_current_epoch(), 2);
+    assert_eq!(scheduler.scheduler().get_current_epoch(), 0);
+
+    scheduler.set_current_epoch(7);
+    assert_eq!(scheduler.get_current_epoch(), 7);
+    assert_eq!(scheduler.scheduler().get_current_epoch(), 3);
+
+    scheduler.set_current_epoch(0);
+    assert_eq!(scheduler.get_current_epoch(), 0);
+    assert_eq!(scheduler.scheduler().get_current_epoch(), 0);
+}
+
+#[test]
+fn warmup_lr_no_warmup() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = WarmupLR::new(&optim, Box::new(ExponentialLR::new(&optim, 0.5)), 0);
+    assert_eq!(optim.get_lr(), 1.);
+
+    scheduler.step();
+    assert_eq!(optim.get_lr(), 0.5);
+    assert_eq!(scheduler.get_current_epoch(), 1);
+}
+
+#[test]
+fn warmup_lr_iteration_mode() {
+    const WARMUP_STEPS: usize = 2;
+    const STEPS_PER_EPOCH: usize = 2;
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = WarmupLR::new(&optim, Box::new(StepLR::new(&optim, 1, 0.5)), WARMUP_STEPS);
+    scheduler.set_step_mode(StepMode::Iteration {
+        steps_per_epoch: STEPS_PER_EPOCH,
+    });
+    assert_eq!(
+        scheduler.scheduler().get_step_mode(),
+        StepMode::Iteration {
+            steps_per_epoch: STEPS_PER_EPOCH
+        }
+    );
+
+    // The warmup grows the learning rate at every iteration, then the wrapped scheduler decays it
+    // at the end of each of its epochs.
+    let mut lrs = vec![optim.get_lr()];
+    let mut epochs = Vec::new();
+    for _ in 0..8 {
+        scheduler.step();
+        lrs.push(optim.get_lr());
+        epochs.push(scheduler.get_current_epoch());
+    }
+    let expected = [1. / 3., 0.5, 2. / 3., 5. / 6., 1., 1., 0.5, 0.5, 0.25];
+    for (lr, expected) in lrs.iter().zip(expected) {
+        assert!((lr - expected).abs() <= 1e-6);
+    }
+    assert_eq!(epochs, vec![0, 1, 1, 2, 2, 3, 3, 4]);
+    assert_eq!(scheduler.get_current_iteration(), 8);
+    assert_eq!(scheduler.scheduler().get_current_epoch(), 2);
+}
+
+#[test]
+fn cyclic_lr_triangular() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = CyclicLR::new(&optim, 0.1, 1., 2, 2, CyclicMode::Triangular);
+    assert_eq!(optim.get_lr(), 0.1);
+
+    let mut lrs = Vec::new();
+    for _ in 0..8 {
+        scheduler.step();
+        lrs.push(optim.get_lr());
+    }
+    let expected = [0.55, 1., 0.55, 0.1, 0.55, 1., 0.55, 0.1];
+    for (lr, expected) in lrs.iter().zip(expected) {
+        assert!((lr - expected).abs() <= f32::EPSILON);
+    }
+    assert_eq!(scheduler.get_current_epoch(), 8);
+}
+
+#[test]
+fn cyclic_lr_triangular2() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = CyclicLR::new(&optim, 0.1, 1., 1, 3, CyclicMode::Triangular2);
+
+    let mut peaks = Vec::new();
+    for _ in 0..3 {
+        scheduler.step();
+        peaks.push(optim.get_lr());
+        for _ in 0..3 {
+            scheduler.step();
+        }
+        assert!((optim.get_lr() - 0.1).abs() <= f32::EPSILON);
+    }
+    let expected = [1., 0.55, 0.325];
+    for (peak, expected) in peaks.iter().zip(expected) {
+        assert!((peak - expected).abs() <= f32::EPSILON);
+    }
+}
+
+#[test]
+fn cyclic_lr_exp_range() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = CyclicLR::new(&optim, 0., 1., 1, 1, CyclicMode::ExpRange { gamma: 0.5 });
+
+    let mut peaks = Vec::new();
+    for _ in 0..3 {
+        scheduler.step();
+        peaks.push(optim.get_lr());
+        scheduler.step();
+    }
+    assert_eq!(peaks, vec![0.5, 0.125, 0.03125]);
+}
+
+#[test]
+#[should_panic(expected = "error: base_lr must not be greater than max_lr, got 1 and 0.1.")]
+fn cyclic_lr_inverted() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    CyclicLR::new(&optim, 1., 0.1, 2, 2, CyclicMode::Triangular);
+}
+
+#[test]
+fn one_cycle_lr_cos() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = OneCycleLR::new(&optim, 1., 10, 0.3, 10., 100., AnnealStrategy::Cos);
+    assert!((optim.get_lr() - 0.1).abs() <= f32::EPSILON);
+
+    let mut lrs = Vec::new();
+    for _ in 0..10 {
+        scheduler.step();
+        lrs.push(optim.get_lr());
+    }
+    assert!(lrs[..2].iter().all(|lr| *lr > 0.1 && *lr < 1.));
+    assert!((lrs[2] - 1.).abs() <= f32::EPSILON);
+    assert!((lrs[5] - 0.1).abs() <= f32::EPSILON);
+    assert!((lrs[9] - 0.01).abs() <= f32::EPSILON);
+    assert!(lrs.windows(2).skip(2).all(|lrs| lrs[0] > lrs[1]));
+
+    scheduler.step();
+    assert!((optim.get_lr() - 0.01).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn one_cycle_lr_linear() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = OneCycleLR::new(&optim, 1., 10, 0.2, 5., 50., AnnealStrategy::Linear);
+
+    let mut lrs = Vec::new();
+    for _ in 0..10 {
+        scheduler.step();
+        lrs.push(optim.get_lr());
+    }
+    let expected = [0.6, 1., 0.6, 0.2, 0.17, 0.14, 0.11, 0.08, 0.05, 0.02];
+    for (lr, expected) in lrs.iter().zip(expected.iter()) {
+        assert!((lr - expected).abs() <= 1e-6);
+    }
+}
+
+#[test]
+fn one_cycle_lr_late_peak() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = OneCycleLR::new(&optim, 1., 10, 0.6, 5., 50., AnnealStrategy::Linear);
+
+    let mut lrs = Vec::new();
+    for _ in 0..10 {
+        scheduler.step();
+        lrs.push(optim.get_lr());
+    }
+    // The peak comes after six steps, the learning rate then needs three steps to get back to
+    // its initial value and one more to reach its final one.
+    let expected = [
+        0.3333333, 0.4666667, 0.6, 0.7333333, 0.8666667, 1., 0.7333333, 0.4666667, 0.2, 0.02,
+    ];
+    for (lr, expected) in lrs.iter().zip(expected.iter()) {
+        assert!((lr - expected).abs() <= 1e-6);
+    }
+}
+
+#[test]
+#[should_panic(expected = "error: pct_start must be in the range (0, 1), got 1.")]
+fn one_cycle_lr_invalid_pct_start() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    OneCycleLR::new(&optim, 1., 10, 1., 10., 100., AnnealStrategy::Cos);
+}
+
+#[test]
+#[should_panic(expected = "error: total_steps is too small to fit the three phases, got 2.")]
+fn one_cycle_lr_too_few_steps() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    OneCycleLR::new(&optim, 1., 2, 0.3, 10., 100., AnnealStrategy::Cos);
+}
+
+#[test]
+fn lambda_lr_first_step_uses_epoch_one() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = LambdaLR::new(&optim, |epoch| 1. / epoch as f32);
+
+    scheduler.step();
+    assert_eq!(scheduler.get_current_epoch(), 1);
+    assert!((scheduler.get_current_lr() - 1.).abs() <= f32::EPSILON);
+    scheduler.step();
+    assert!((scheduler.get_current_lr() - 0.5).abs() <= f32::EPSILON);
+    assert!(optim.get_lr().is_finite());
+}
+
+#[test]
+fn exponential_lr_no_subnormals() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = ExponentialLR::new(&optim, 0.1);
+    assert_eq!(scheduler.get_lr_bounds(), (f32::MIN_POSITIVE, f32::MAX));
+
+    for _ in 0..100 {
+        scheduler.step();
+        assert!(scheduler.get_current_lr().is_normal());
+    }
+    assert_eq!(scheduler.get_current_lr(), f32::MIN_POSITIVE);
+    assert_eq!(optim.get_lr(), f32::MIN_POSITIVE);
+}
+
+#[test]
+fn lr_bounds() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = ExponentialLR::new(&optim, 2.);
+    scheduler.set_lr_bounds(0.5, 4.);
+    assert_eq!(scheduler.get_lr_bounds(), (0.5, 4.));
+
+    let mut lrs = Vec::new();
+    for _ in 0..4 {
+        scheduler.step();
+        lrs.push(optim.get_lr());
+    }
+    assert_eq!(lrs, vec![2., 4., 4., 4.]);
+
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = MultiplicativeLR::new(&optim, |_| 0.);
+    scheduler.set_lr_bounds(0., 1.);
+    scheduler.step();
+    assert_eq!(scheduler.get_current_lr(), 0.);
+}
+
+#[test]
+#[should_panic]
+fn lr_bounds_inverted() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = StepLR::new(&optim, 2, 0.5);
+    scheduler.set_lr_bounds(1., 0.5);
+}
+
+#[test]
+fn default_lr_bounds() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = HalvingLR {
+        optimizer: &optim,
+        current_epoch: Cell::new(0),
+        last_lr: Cell::new(0.),
+    };
+    assert_eq!(scheduler.get_lr_bounds(), (0., f32::INFINITY));
+    scheduler.set_lr_bounds(0., f32::INFINITY);
+
+    let warmup = WarmupLR::new(&optim, Box::new(scheduler), 1);
+    assert_eq!(warmup.get_lr_bounds(), (0., f32::INFINITY));
+    assert_eq!(optim.get_lr(), 0.5);
+    let mut lrs = Vec::new();
+    for _ in 0..3 {
+        warmup.step();
+        lrs.push(optim.get_lr());
+    }
+    assert_eq!(lrs, vec![1., 0.5, 0.25]);
+}
+
+#[test]
+#[should_panic(expected = "error: this scheduler doesn't support learning rate bounds.")]
+fn default_lr_bounds_unsupported() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = HalvingLR {
+        optimizer: &optim,
+        current_epoch: Cell::new(0),
+        last_lr: Cell::new(0.),
+    };
+    scheduler.set_lr_bounds(0.5, 1.);
+}
+
+#[test]
+fn saturating_epochs() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = MultiStepLR::new(&optim, [1], 0.5);
+    scheduler.set_current_epoch(usize::MAX);
+    scheduler.step();
+    assert_eq!(scheduler.get_current_epoch(), usize::MAX);
+
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = LambdaLR::new(&optim, |epoch| 1. / epoch as f32);
+    scheduler.set_step_mode(StepMode::Iteration { steps_per_epoch: 4 });
+    scheduler.set_current_epoch(usize::MAX);
+    assert_eq!(scheduler.get_current_iteration(), usize::MAX);
+    scheduler.step();
+    assert_eq!(scheduler.get_current_iteration(), usize::MAX);
+    assert_eq!(scheduler.get_current_epoch(), usize::MAX);
+}
+
+#[test]
+fn reduce_lr_on_plateau() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = ReduceLROnPlateau::new(&optim, PlateauMode::Min, 0.5, 2, 1e-4, 0);
+
+    let mut lrs = Vec::new();
+    for _ in 0..7 {
+        scheduler.step_with_metric(1.);
+        lrs.push(optim.get_lr());
+    }
+    assert_eq!(lrs, vec![1., 1., 1., 0.5, 0.5, 0.5, 0.25]);
+    assert_eq!(scheduler.get_current_epoch(), 7);
+    assert!((scheduler.get_last_lr() - 0.5).abs() <= f32::EPSILON);
+    assert!((scheduler.get_current_lr() - 0.25).abs() <= f32::EPSILON);
+    assert_eq!(scheduler.get_best_metric(), 1.);
+}
+
+#[test]
+fn reduce_lr_on_plateau_improving() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = ReduceLROnPlateau::new(&optim, PlateauMode::Min, 0.5, 1, 0.1, 0);
+
+    for metric in [10., 8., 6., 4., 2.] {
+        scheduler.step_with_metric(metric);
+        assert_eq!(scheduler.get_num_bad_epochs(), 0);
+    }
+    assert_eq!(optim.get_lr(), 1.);
+
+    // Improvements smaller than the threshold do not count.
+    scheduler.step_with_metric(1.9);
+    assert_eq!(scheduler.get_num_bad_epochs(), 1);
+    scheduler.step_with_metric(1.85);
+    assert_eq!(optim.get_lr(), 0.5);
+    assert_eq!(scheduler.get_best_metric(), 2.);
+}
+
+#[test]
+fn reduce_lr_on_plateau_max_cooldown() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    let scheduler = ReduceLROnPlateau::new(&optim, PlateauMode::Max, 0.5, 0, 0., 2);
+
+    let mut lrs = Vec::new();
+    for _ in 0..5 {
+        scheduler.step_with_metric(0.9);
+        lrs.push(optim.get_lr());
+    }
+    assert_eq!(lrs, vec![1., 0.5, 0.5, 0.5, 0.25]);
+}
+
+#[test]
+#[should_panic]
+fn reduce_lr_on_plateau_invalid_factor() {
+    let optim = SGD::new(Vec::new(), 1., L2::new(0.1));
+    ReduceLROnPlateau::new(&optim, PlateauMode::Min, 1., 2, 1e-4, 0);
+}
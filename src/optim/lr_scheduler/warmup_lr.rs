@@ -0,0 +1,219 @@
+use super::{super::Optimizer, prepare_iteration_step, LRScheduler, StepCounter, StepMode};
+use std::cell::Cell;
+
+/// Linearly warms up the learning rate for `warmup_steps` epochs, then hands it over to a wrapped
+/// scheduler.
+///
+/// During the warmup the learning rate grows from a fraction of the wrapped scheduler's initial
+/// learning rate up to the full value, which is reached at epoch `warmup_steps`. From there on the
+/// wrapped scheduler takes over and counts its epochs from zero, so that its policy is applied
+/// exactly as it would be without the warmup, just delayed by `warmup_steps` epochs.
+///
+///```text
+/// lrₜ = lr₀ * (t + 1) / (warmup_steps + 1) if t < warmup_steps else scheduler(t - warmup_steps)
+///```
+///
+/// The epoch reported by `.get_current_epoch()` is the overall one, that is the number of warmup
+/// epochs performed so far plus the current epoch of the wrapped scheduler.
+///
+/// The step mode set with `.set_step_mode()` is shared with the wrapped scheduler. In
+/// [`StepMode::Iteration`] the learning rate grows at every iteration of the warmup, and `t` in
+/// the formula above includes the fraction of the current epoch.
+pub struct WarmupLR<'a, T: Optimizer<'a>> {
+    optimizer: &'a T,
+    scheduler: Box<dyn LRScheduler + 'a>,
+    warmup_steps: usize,
+    initial_lr: f32,
+    steps: StepCounter,
+    current_lr: Cell<f32>,
+    last_lr: Cell<f32>,
+}
+
+impl<'a, T: Optimizer<'a>> WarmupLR<'a, T> {
+    /// Creates a new WarmupLR scheduler.
+    ///
+    /// The optimizer's learning rate is immediately set to the one of the first warmup epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `optimizer` - wrapped optimizer, the same one of `scheduler`.
+    ///
+    /// * `scheduler` - wrapped scheduler, to which the learning rate is handed over after the
+    /// warmup.
+    ///
+    /// * `warmup_steps` - number of warmup epochs.
+    pub fn new(
+        optimizer: &'a T,
+        scheduler: Box<dyn LRScheduler + 'a>,
+        warmup_steps: usize,
+    ) -> Self {
+        let initial_lr = scheduler.get_current_lr();
+        let warmup = Self {
+            optimizer,
+            scheduler,
+            warmup_steps,
+            initial_lr,
+            steps: StepCounter::default(),
+            current_lr: Cell::new(initial_lr),
+            last_lr: Cell::new(0.0),
+        };
+        warmup.current_lr.set(warmup.warmup_lr());
+        warmup.optimizer.set_lr(warmup.current_lr.get());
+
+        warmup
+    }
+
+    /// Computes the learning rate at the current point of the warmup, at its end this is the
+    /// wrapped scheduler's initial learning rate.
+    fn warmup_lr(&self) -> f32 {
+        let (min_lr, max_lr) = self.scheduler.get_lr_bounds();
+        let fraction = (self.steps.progress() + 1.) / (self.warmup_steps + 1) as f32;
+
+        (self.initial_lr * fraction.min(1.)).clamp(min_lr, max_lr)
+    }
+
+    /// Returns `true` if the wrapped scheduler has not been stepped yet.
+    fn is_warming_up(&self) -> bool {
+        self.scheduler.get_current_epoch() == 0 && self.scheduler.get_current_iteration() == 0
+    }
+
+    /// Performs a warmup step or steps the wrapped scheduler if the warmup is over.
+    pub fn step(&self) {
+        LRScheduler::step(self);
+    }
+
+    /// Returns the last learning rate value computed by this learning rate scheduler.
+    pub fn get_last_lr(&self) -> f32 {
+        LRScheduler::get_last_lr(self)
+    }
+
+    /// Returns the current learning rate value computed by this learning rate scheduler.
+    pub fn get_current_lr(&self) -> f32 {
+        LRScheduler::get_current_lr(self)
+    }
+
+    /// Sets the current epoch for this learning rate scheduler and the wrapped one.
+    pub fn set_current_epoch(&self, epoch: usize) {
+        LRScheduler::set_current_epoch(self, epoch);
+    }
+
+    /// Returns the current epoch for this learning rate scheduler.
+    pub fn get_current_epoch(&self) -> usize {
+        LRScheduler::get_current_epoch(self)
+    }
+
+    /// Sets the range in which the learning rates computed by this scheduler and by the wrapped
+    /// one are clamped.
+    ///
+    /// # Panics
+    ///
+    /// If `min_lr` is negative or greater than `max_lr`.
+    pub fn set_lr_bounds(&self, min_lr: f32, max_lr: f32) {
+        LRScheduler::set_lr_bounds(self, min_lr, max_lr);
+    }
+
+    /// Returns the range in which the learning rates computed by this scheduler are clamped.
+    pub fn get_lr_bounds(&self) -> (f32, f32) {
+        LRScheduler::get_lr_bounds(self)
+    }
+
+    /// Sets the granularity at which `.step()` is called for this learning rate scheduler and
+    /// the wrapped one, see [`StepMode`].
+    ///
+    /// # Panics
+    ///
+    /// If `mode` is [`StepMode::Iteration`] and `steps_per_epoch` is zero, or if the wrapped
+    /// scheduler doesn't support `mode`.
+    pub fn set_step_mode(&self, mode: StepMode) {
+        LRScheduler::set_step_mode(self, mode);
+    }
+
+    /// Returns the granularity at which `.step()` is called.
+    pub fn get_step_mode(&self) -> StepMode {
+        LRScheduler::get_step_mode(self)
+    }
+
+    /// Returns the number of iterations performed so far, including the ones of the wrapped
+    /// scheduler. In [`StepMode::Epoch`] this is always zero.
+    pub fn get_current_iteration(&self) -> usize {
+        LRScheduler::get_current_iteration(self)
+    }
+
+    /// Returns the number of warmup epochs.
+    pub fn get_warmup_steps(&self) -> usize {
+        self.warmup_steps
+    }
+
+    /// Returns a reference to the wrapped scheduler.
+    pub fn scheduler(&self) -> &dyn LRScheduler {
+        self.scheduler.as_ref()
+    }
+
+    /// Prints the learning rate update together with the epoch.
+    pub fn print_lr(&self) {
+        LRScheduler::print_lr(self);
+    }
+}
+
+impl<'a, T: Optimizer<'a>> LRScheduler for WarmupLR<'a, T> {
+    fn step(&self) {
+        if self.steps.epoch() < self.warmup_steps {
+            prepare_iteration_step(&self.last_lr, &self.current_lr, &self.steps);
+            self.current_lr.set(self.warmup_lr());
+            self.optimizer.set_lr(self.current_lr.get());
+        } else {
+            self.scheduler.step();
+        }
+    }
+
+    fn get_last_lr(&self) -> f32 {
+        if self.is_warming_up() {
+            self.last_lr.get()
+        } else {
+            self.scheduler.get_last_lr()
+        }
+    }
+
+    fn get_current_lr(&self) -> f32 {
+        if self.is_warming_up() {
+            self.current_lr.get()
+        } else {
+            self.scheduler.get_current_lr()
+        }
+    }
+
+    fn set_current_epoch(&self, epoch: usize) {
+        self.steps.set_epoch(epoch.min(self.warmup_steps));
+        self.scheduler
+            .set_current_epoch(epoch.saturating_sub(self.warmup_steps));
+    }
+
+    fn get_current_epoch(&self) -> usize {
+        self.steps
+            .epoch()
+            .saturating_add(self.scheduler.get_current_epoch())
+    }
+
+    fn set_lr_bounds(&self, min_lr: f32, max_lr: f32) {
+        self.scheduler.set_lr_bounds(min_lr, max_lr);
+    }
+
+    fn get_lr_bounds(&self) -> (f32, f32) {
+        self.scheduler.get_lr_bounds()
+    }
+
+    fn set_step_mode(&self, mode: StepMode) {
+        self.scheduler.set_step_mode(mode);
+        self.steps.set_mode(mode);
+    }
+
+    fn get_step_mode(&self) -> StepMode {
+        self.steps.mode()
+    }
+
+    fn get_current_iteration(&self) -> usize {
+        self.steps
+            .iteration()
+            .saturating_add(self.scheduler.get_current_iteration())
+    }
+}
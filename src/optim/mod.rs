@@ -60,11 +60,51 @@
 //! let model_optim = Adam::new(model.parameters(), 0.01, (0.9, 0.999), L2::new(0.01), 1e-8);
 //! ```
 //!
+//! The parameters optimized by [`SGD`] can also be split in several [`ParamGroup`], each one with
+//! its own learning rate and penalty regularization, with [`SGD::from_param_groups`]. More groups
+//! can be added later on with [`SGD::add_param_group`].
+//!
 //! ## Taking an optimization step
 //!
 //! All neuronika's optimizer implement a [`.step()`](Optimizer::step()) method that updates the
 //! parameters.
 //!
+//! ## Accumulating the gradients
+//!
+//! The gradients of the parameters are accumulated across backward passes until they are zeroed
+//! with [`.zero_grad()`](Optimizer::zero_grad()). When a batch doesn't fit in memory it can thus
+//! be split in several smaller ones, whose gradients are accumulated before calling
+//! [`.step_accumulated()`](Optimizer::step_accumulated()), which averages them and then takes the
+//! step.
+//!
+//! ```
+//! # #[cfg(feature = "blas")]
+//! # extern crate blas_src;
+//! use neuronika::optim::{Optimizer, SGD, L2};
+//!
+//! let w = neuronika::ones(3).requires_grad();
+//! let x = neuronika::rand((4, 3));
+//! let loss = x.clone().mv(w.clone()).pow(2).mean();
+//! let optim = SGD::new(loss.parameters(), 0.01, L2::new(0.));
+//!
+//! for _ in 0..4 {
+//!     // Loads the next mini-batch.
+//!     *x.data_mut() = neuronika::rand((4, 3)).data().to_owned();
+//!     loss.forward();
+//!     loss.backward(1.);
+//! }
+//!
+//! optim.step_accumulated(4);
+//! optim.zero_grad();
+//! ```
+//!
+//! ## Freezing the parameters
+//!
+//! The optimizers don't know whether a parameter is [frozen](crate::VarDiff::freeze()), and keep
+//! updating it when a penalty or a momentum is involved. Frozen parameters must be left out of the
+//! ones given to the optimizer, those that are unfrozen later on can be added with
+//! [`SGD::add_param_group`].
+//!
 //! # Implementing an optimizer
 //!
 //! Implementing an optimizer in neuronika is quick and simple. The procedure consists in *3* steps:
@@ -195,30 +235,45 @@
 //! # Adjusting the learning rate
 //!
 //! The [`lr_scheduler`] module provides several methods to adjust the learning rate based on the
-//! number of epochs.
+//! number of epochs or on the value of a monitored metric.
+//!
+//! # Clipping the gradients
+//!
+//! Gradients can be clipped before taking an optimization step, either with [`clip_grad_norm_`],
+//! which bounds their total norm, or with [`clip_grad_value_`], which clamps each of their elements.
 //!
 //! # Algorithms
 //!
 //! List of all implemented optimizers.
 //!
+//! * [`AdaDelta`] - Implements the AdaDelta algorithm.
+//!
 //! * [`Adagrad`] - Implements the Adagrad algorithm.
 //!
 //! * [`Adam`] - Implements the Adam algorithm.
 //!
+//! * [`AdamW`] - Implements the AdamW algorithm.
+//!
 //! * [`AMSGrad`] - Implements the AMSGrad algorithm.
 //!
+//! * [`RAdam`] - Implements the RAdam algorithm.
+//!
 //! * [`RMSProp`] - Implements the RMSProp algorithm.
 //!
 //! * [`SGD`] - Implements the stochastic gradient descent algorithm.
 use crate::variable::Param;
+pub use adadelta::{AdaDelta, AdaDeltaParam};
 pub use adagrad::{Adagrad, AdagradParam};
 pub use adam::{Adam, AdamParam};
+pub use adamw::{AdamW, AdamWParam};
 pub use amsgrad::{AMSGrad, AMSGradParam};
+pub use radam::{RAdam, RAdamParam};
 pub use rmsprop::{
     RMSProp, RMSPropCentered, RMSPropCenteredParam, RMSPropCenteredWithMomentum,
     RMSPropCenteredWithMomentumParam, RMSPropParam, RMSPropWithMomentum, RMSPropWithMomentumParam,
 };
 pub use sgd::{SGDParam, SGDWithMomentum, SGDWithMomentumParam, SGD};
+pub use utils::{clip_grad_norm_, clip_grad_value_};
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Optimizer Trait ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -235,6 +290,25 @@ pub trait Optimizer<'a> {
     /// Zeroes the gradients of all the parameters to optimize.
     fn zero_grad(&self);
 
+    /// Performs a single optimization step with the gradients accumulated over `n_steps`
+    /// backward passes.
+    ///
+    /// All neuronika's optimizers divide the gradients by `n_steps` before the step, so that the
+    /// update is the same as the one computed on a single batch as large as all the accumulated
+    /// ones together. The default implementation divides the learning rate instead, for the
+    /// duration of the step, which is equivalent only for the optimizers with no penalty and whose
+    /// update is linear in the gradients.
+    ///
+    /// # Panics
+    ///
+    /// If `n_steps` is zero.
+    fn step_accumulated(&self, n_steps: usize) {
+        let lr = self.get_lr();
+        self.set_lr(lr * accumulation_factor(n_steps));
+        self.step();
+        self.set_lr(lr);
+    }
+
     /// Transforms a vector of parameter representations into a vector of another kind of parameter
     /// representations.
     ///
@@ -317,6 +391,32 @@ impl ElasticNet {
             lambda_l2,
         }
     }
+
+    /// Creates a new ElasticNet penalty regularization from its overall strength and the
+    /// proportion of it given to the L1 penalty.
+    ///
+    /// The L1 and L2 regularization coefficients are respectively `alpha * l1_ratio` and
+    /// `alpha * (1 - l1_ratio)`, so that a ratio of *1.0* yields a pure L1 penalty and a ratio of
+    /// *0.0* a pure L2 one.
+    ///
+    /// # Arguments
+    ///
+    /// * `alpha` - overall regularization coefficient.
+    ///
+    /// * `l1_ratio` - proportion of the L1 penalty, in the range *[0, 1]*.
+    ///
+    /// # Panics
+    ///
+    /// If `l1_ratio` is not in the range *[0, 1]*.
+    pub fn from_l1_ratio(alpha: f32, l1_ratio: f32) -> Self {
+        assert!(
+            (0. ..=1.).contains(&l1_ratio),
+            "error: l1_ratio must be in the range [0, 1], got {}.",
+            l1_ratio
+        );
+
+        Self::new(alpha * l1_ratio, alpha * (1. - l1_ratio))
+    }
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -341,18 +441,127 @@ impl Penalty for ElasticNet {
     }
 }
 
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Parameter Groups ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// A group of parameters optimized with their own learning rate and penalty regularization.
+pub struct ParamGroup<'a, T> {
+    params: Vec<Param<'a>>,
+    lr: f32,
+    penalty: T,
+}
+
+impl<'a, T: Penalty> ParamGroup<'a, T> {
+    /// Creates a new parameter group.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - vector of [`Param`] to optimize.
+    ///
+    /// * `lr` - learning rate of the group.
+    ///
+    /// * `penalty` - penalty regularization of the group.
+    pub fn new(params: Vec<Param<'a>>, lr: f32, penalty: T) -> Self {
+        Self {
+            params,
+            lr,
+            penalty,
+        }
+    }
+}
+
+/// The internal representation of a parameter group.
+struct Group<P, T> {
+    params: Vec<P>,
+    lr: f32,
+    penalty: T,
+}
+
+impl<P, T> Group<P, T> {
+    /// Converts the representations of the parameters of this group, keeping its settings.
+    fn convert<Q: From<P>>(self) -> Group<Q, T> {
+        Group {
+            params: self.params.into_iter().map(Q::from).collect(),
+            lr: self.lr,
+            penalty: self.penalty,
+        }
+    }
+}
+
+impl<'a, P: From<Param<'a>>, T> From<ParamGroup<'a, T>> for Group<P, T> {
+    fn from(group: ParamGroup<'a, T>) -> Self {
+        let ParamGroup {
+            params,
+            lr,
+            penalty,
+        } = group;
+
+        Self {
+            params: params.into_iter().map(P::from).collect(),
+            lr,
+            penalty,
+        }
+    }
+}
+
+/// Returns the factor by which the gradients accumulated over `n_steps` backward passes are scaled
+/// to be averaged.
+///
+/// # Panics
+///
+/// If `n_steps` is zero.
+fn accumulation_factor(n_steps: usize) -> f32 {
+    assert!(
+        n_steps > 0,
+        "error: the number of accumulated steps must be positive."
+    );
+
+    1. / n_steps as f32
+}
+
+/// Sets `lr` as the learning rate of the first of `groups` and scales those of the others by the
+/// same factor, so that the ratios between them are kept. If the learning rate of the first group
+/// is zero, `lr` is set for every group.
+fn scale_lr<P, T>(groups: &mut [Group<P, T>], lr: f32) {
+    let first_lr = match groups.first() {
+        Some(group) => group.lr,
+        None => return,
+    };
+
+    for group in groups.iter_mut() {
+        group.lr = if first_lr == 0. {
+            lr
+        } else {
+            lr * (group.lr / first_lr)
+        };
+    }
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Optimizers ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+mod adadelta;
 mod adagrad;
 mod adam;
+mod adamw;
 mod amsgrad;
+mod radam;
 mod rmsprop;
 mod sgd;
 
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Utilities ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+mod utils;
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Learning Rate Schedulers ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 pub mod lr_scheduler;
+
+#[cfg(test)]
+mod test;
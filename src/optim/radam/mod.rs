@@ -0,0 +1,220 @@
+use super::{accumulation_factor, Optimizer, Param, Penalty};
+use ndarray::{ArrayD, ArrayViewMutD, Zip};
+use rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
+use std::cell::{Cell, RefCell};
+
+/// **RAdam** optimizer.
+///
+/// It has been proposed in
+/// [On the Variance of the Adaptive Learning Rate and Beyond](https://arxiv.org/abs/1908.03265).
+///
+/// The adaptive learning rate of Adam is rectified by a term that accounts for its variance.
+/// During the first steps, when the length of the approximated simple moving average is too short
+/// for such variance to be tractable, the parameters are updated as in stochastic gradient
+/// descent with momentum.
+#[allow(clippy::upper_case_acronyms)]
+pub struct RAdam<'a, T: Penalty> {
+    params: RefCell<Vec<RAdamParam<'a>>>,
+    lr: Cell<f32>,
+    betas: Cell<(f32, f32)>,
+    eps: Cell<f32>,
+    penalty: T,
+}
+
+impl<'a, T: Penalty> RAdam<'a, T> {
+    /// Creates a new *RAdam* optimizer.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - vector of [`Param`] to optimize.
+    ///
+    /// * `lr` - learning rate.
+    ///
+    /// * `betas` - a 2-tuple of coefficients used for computing running averages of the gradient
+    /// and its square. Good default is: *(0.9, 0.999)*.
+    ///
+    /// * `eps` - small constant for numerical stability. A good default value is *1e-8*.
+    ///
+    /// * `penalty` - penalty regularization.
+    pub fn new(params: Vec<Param<'a>>, lr: f32, betas: (f32, f32), eps: f32, penalty: T) -> Self {
+        let params = RefCell::new(Self::build_params(params));
+        let lr = Cell::new(lr);
+
+        Self {
+            params,
+            lr,
+            betas: Cell::new(betas),
+            eps: Cell::new(eps),
+            penalty,
+        }
+    }
+
+    /// Return the current learning rate.
+    pub fn get_lr(&self) -> f32 {
+        Optimizer::get_lr(self)
+    }
+
+    /// Sets `lr` as the  new value for the learning rate.
+    pub fn set_lr(&self, lr: f32) {
+        Optimizer::set_lr(self, lr);
+    }
+
+    /// Return the current values for the exponential decay rates.
+    pub fn get_betas(&self) -> (f32, f32) {
+        self.betas.get()
+    }
+
+    /// Sets `betas` as the  new value for the exponential decay rates.
+    pub fn set_betas(&self, betas: (f32, f32)) {
+        self.betas.set(betas)
+    }
+
+    /// Return the current *eps* constant.
+    pub fn get_eps(&self) -> f32 {
+        self.eps.get()
+    }
+
+    /// Sets `eps` as the  new value for the *eps* constant.
+    pub fn set_eps(&self, eps: f32) {
+        self.eps.set(eps)
+    }
+
+    /// Performs a single RAdam optimization step.
+    pub fn step(&self) {
+        Optimizer::step(self);
+    }
+
+    /// Zeroes the gradient of this optimizer's parameters.
+    pub fn zero_grad(&self) {
+        Optimizer::zero_grad(self);
+    }
+
+    /// Performs a single optimization step with the gradients accumulated over `n_steps`
+    /// backward passes, averaging them first.
+    ///
+    /// # Panics
+    ///
+    /// If `n_steps` is zero.
+    pub fn step_accumulated(&self, n_steps: usize) {
+        Optimizer::step_accumulated(self, n_steps);
+    }
+}
+
+/// A Parameter used by the *RAdam* optimizer.
+#[allow(clippy::upper_case_acronyms)]
+pub struct RAdamParam<'a> {
+    data: ArrayViewMutD<'a, f32>,
+    grad: ArrayViewMutD<'a, f32>,
+    step: usize,
+    exp_avg: ArrayD<f32>,
+    exp_avg_sq: ArrayD<f32>,
+}
+
+impl<'a> From<Param<'a>> for RAdamParam<'a> {
+    fn from(param: Param<'a>) -> Self {
+        let Param { data, grad } = param;
+        let step = 0;
+        let (exp_avg, exp_avg_sq) = (ArrayD::zeros(grad.raw_dim()), ArrayD::zeros(grad.raw_dim()));
+
+        Self {
+            data,
+            grad,
+            step,
+            exp_avg,
+            exp_avg_sq,
+        }
+    }
+}
+
+impl<'a, T: Penalty> Optimizer<'a> for RAdam<'a, T> {
+    type ParamRepr = RAdamParam<'a>;
+
+    fn step(&self) {
+        let (lr, penalty, mut params, (beta1, beta2), eps) = (
+            self.lr.get(),
+            &self.penalty,
+            self.params.borrow_mut(),
+            &self.betas.get(),
+            &self.eps.get(),
+        );
+        // Maximum length of the approximated simple moving average.
+        let rho_inf = 2. / (1. - beta2) - 1.;
+
+        params.par_iter_mut().for_each(|param| {
+            let (step, exp_avg, exp_avg_sq) =
+                (&mut param.step, &mut param.exp_avg, &mut param.exp_avg_sq);
+
+            *step += 1;
+            let bias_correction1 = 1. - beta1.powi(*step as i32);
+            let bias_correction2 = 1. - beta2.powi(*step as i32);
+            let rho_t = rho_inf - 2. * *step as f32 * beta2.powi(*step as i32) / bias_correction2;
+
+            let mut p_grad = param.grad.to_owned();
+            Zip::from(&mut p_grad)
+                .and(&param.data)
+                .for_each(|p_grad_el, data_el| *p_grad_el += penalty.penalize(data_el));
+
+            Zip::from(exp_avg)
+                .and(&p_grad)
+                .for_each(|exp_avg_el, p_grad_el| {
+                    *exp_avg_el = *exp_avg_el * beta1 + p_grad_el * (1. - beta1)
+                });
+
+            Zip::from(exp_avg_sq)
+                .and(&p_grad)
+                .for_each(|exp_avg_sq_el, p_grad_el| {
+                    *exp_avg_sq_el = *exp_avg_sq_el * beta2 + p_grad_el * p_grad_el * (1. - beta2)
+                });
+
+            // The variance of the adaptive learning rate is tractable only when the length of the
+            // approximated simple moving average is greater than 4, a threshold of 5 is used.
+            if rho_t > 5. {
+                let rect = ((rho_t - 4.) * (rho_t - 2.) * rho_inf
+                    / ((rho_inf - 4.) * (rho_inf - 2.) * rho_t))
+                    .sqrt();
+
+                Zip::from(&mut param.data)
+                    .and(&param.exp_avg)
+                    .and(&param.exp_avg_sq)
+                    .for_each(|data_el, exp_avg_el, exp_avg_sq_el| {
+                        *data_el += exp_avg_el
+                            / ((exp_avg_sq_el.sqrt() / bias_correction2.sqrt()) + *eps)
+                            * (-lr * rect / bias_correction1)
+                    })
+            } else {
+                Zip::from(&mut param.data)
+                    .and(&param.exp_avg)
+                    .for_each(|data_el, exp_avg_el| {
+                        *data_el += exp_avg_el * (-lr / bias_correction1)
+                    })
+            }
+        });
+    }
+
+    fn zero_grad(&self) {
+        self.params.borrow_mut().par_iter_mut().for_each(|param| {
+            let grad = &mut param.grad;
+            Zip::from(grad).for_each(|grad_el| *grad_el = 0.);
+        });
+    }
+
+    fn step_accumulated(&self, n_steps: usize) {
+        let factor = accumulation_factor(n_steps);
+        self.params.borrow_mut().par_iter_mut().for_each(|param| {
+            let grad = &mut param.grad;
+            Zip::from(grad).for_each(|grad_el| *grad_el *= factor);
+        });
+        self.step();
+    }
+
+    fn get_lr(&self) -> f32 {
+        self.lr.get()
+    }
+
+    fn set_lr(&self, lr: f32) {
+        self.lr.set(lr)
+    }
+}
+
+#[cfg(test)]
+mod test;
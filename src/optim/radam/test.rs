@@ -0,0 +1,110 @@
+use super::{
+    super::{Adam, L2, SGD},
+    RAdam,
+};
+use ndarray::array;
+
+#[test]
+fn creation() {
+    let optim = RAdam::new(Vec::new(), 1e-2, (0.9, 0.999), 1e-8, L2::new(1e-2));
+
+    assert_eq!(optim.params.borrow().len(), 0);
+    assert!((optim.get_lr() - 1e-2).abs() <= f32::EPSILON);
+    assert_eq!(optim.get_betas(), (0.9, 0.999));
+    assert!((optim.get_eps() - 1e-8).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_lr() {
+    let optim = RAdam::new(Vec::new(), 1e-2, (0.9, 0.999), 1e-8, L2::new(1e-2));
+
+    optim.set_lr(1e-3);
+    assert!((optim.get_lr() - 1e-3).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_betas() {
+    let optim = RAdam::new(Vec::new(), 1e-2, (0.9, 0.999), 1e-8, L2::new(1e-2));
+
+    optim.set_betas((0.91, 0.9991));
+    assert_eq!(optim.get_betas(), (0.91, 0.9991));
+}
+
+#[test]
+fn set_eps() {
+    let optim = RAdam::new(Vec::new(), 1e-2, (0.9, 0.999), 1e-8, L2::new(1e-2));
+
+    optim.set_eps(1e-9);
+    assert!((optim.get_eps() - 1e-9).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn rectification() {
+    // With a constant gradient the bias-corrected first moment equals the gradient itself.
+    let x = crate::zeros(2).requires_grad();
+    x.grad_mut().assign(&array![0.5, -2.]);
+    let radam = RAdam::new(x.parameters(), 0.1, (0.9, 0.9), 1e-8, L2::new(0.));
+
+    let y = crate::zeros(2).requires_grad();
+    y.grad_mut().assign(&array![0.5, -2.]);
+    let sgd = SGD::new(y.parameters(), 0.1, L2::new(0.));
+
+    let z = crate::zeros(2).requires_grad();
+    z.grad_mut().assign(&array![0.5, -2.]);
+    let adam = Adam::new(z.parameters(), 0.1, (0.9, 0.9), L2::new(0.), 1e-8);
+
+    // The maximum length of the approximated SMA is 19, the threshold is met at the sixth step.
+    for _ in 0..5 {
+        let (x_prev, y_prev) = (x.data().clone(), y.data().clone());
+        radam.step();
+        sgd.step();
+        adam.step();
+
+        let (x_step, y_step) = (&*x.data() - &x_prev, &*y.data() - &y_prev);
+        assert!(x_step
+            .iter()
+            .zip(y_step.iter())
+            .all(|(x_el, y_el)| (x_el - y_el).abs() <= 1e-6));
+    }
+
+    // As the length of the SMA approaches its maximum the rectification term approaches 1.
+    for _ in 5..200 {
+        radam.step();
+        adam.step();
+    }
+
+    let (x_prev, z_prev) = (x.data().clone(), z.data().clone());
+    radam.step();
+    adam.step();
+
+    let (x_step, z_step) = (&*x.data() - &x_prev, &*z.data() - &z_prev);
+    assert!(x_step
+        .iter()
+        .zip(z_step.iter())
+        .all(|(x_el, z_el)| (x_el - z_el).abs() <= 1e-6));
+}
+
+const EPOCHS: usize = 200;
+
+#[test]
+fn step() {
+    let x = crate::rand((3, 3));
+    let y = crate::rand((3, 3));
+    let z = x.clone().mm(y);
+
+    let w = crate::rand((3, 3)).requires_grad();
+    let loss = (x.mm(w) - z).pow(2).sum();
+    loss.forward();
+
+    let first_value = loss.data().clone().into_scalar();
+    let optim = RAdam::new(loss.parameters(), 0.01, (0.9, 0.999), 1e-8, L2::new(0.0));
+
+    for _ in 0..EPOCHS {
+        loss.forward();
+        loss.backward(1.0);
+
+        optim.step();
+        optim.zero_grad();
+    }
+    assert!(loss.data().clone().into_scalar() < first_value);
+}
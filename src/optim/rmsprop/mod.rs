@@ -1,4 +1,4 @@
-use super::{Optimizer, Param, Penalty};
+use super::{accumulation_factor, Optimizer, Param, Penalty};
 use ndarray::{ArrayD, ArrayViewMutD, Zip};
 use rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
 use std::cell::{Cell, RefCell};
@@ -147,6 +147,16 @@ impl<'a, T: Penalty> RMSProp<'a, T> {
     pub fn zero_grad(&self) {
         Optimizer::zero_grad(self);
     }
+
+    /// Performs a single optimization step with the gradients accumulated over `n_steps`
+    /// backward passes, averaging them first.
+    ///
+    /// # Panics
+    ///
+    /// If `n_steps` is zero.
+    pub fn step_accumulated(&self, n_steps: usize) {
+        Optimizer::step_accumulated(self, n_steps);
+    }
 }
 
 /// A parameter used by the *RMSProp* optimizer.
@@ -212,6 +222,15 @@ impl<'a, T: Penalty> Optimizer<'a> for RMSProp<'a, T> {
         });
     }
 
+    fn step_accumulated(&self, n_steps: usize) {
+        let factor = accumulation_factor(n_steps);
+        self.params.borrow_mut().par_iter_mut().for_each(|param| {
+            let grad = &mut param.grad;
+            Zip::from(grad).for_each(|grad_el| *grad_el *= factor);
+        });
+        self.step();
+    }
+
     fn get_lr(&self) -> f32 {
         self.lr.get()
     }
@@ -308,6 +327,16 @@ impl<'a, T: Penalty> RMSPropWithMomentum<'a, T> {
     pub fn zero_grad(&self) {
         Optimizer::zero_grad(self);
     }
+
+    /// Performs a single optimization step with the gradients accumulated over `n_steps`
+    /// backward passes, averaging them first.
+    ///
+    /// # Panics
+    ///
+    /// If `n_steps` is zero.
+    pub fn step_accumulated(&self, n_steps: usize) {
+        Optimizer::step_accumulated(self, n_steps);
+    }
 }
 
 impl<'a> From<Param<'a>> for RMSPropWithMomentumParam<'a> {
@@ -384,6 +413,15 @@ impl<'a, T: Penalty> Optimizer<'a> for RMSPropWithMomentum<'a, T> {
         });
     }
 
+    fn step_accumulated(&self, n_steps: usize) {
+        let factor = accumulation_factor(n_steps);
+        self.params.borrow_mut().par_iter_mut().for_each(|param| {
+            let grad = &mut param.grad;
+            Zip::from(grad).for_each(|grad_el| *grad_el *= factor);
+        });
+        self.step();
+    }
+
     fn get_lr(&self) -> f32 {
         self.lr.get()
     }
@@ -463,6 +501,16 @@ impl<'a, T: Penalty> RMSPropCentered<'a, T> {
     pub fn zero_grad(&self) {
         Optimizer::zero_grad(self);
     }
+
+    /// Performs a single optimization step with the gradients accumulated over `n_steps`
+    /// backward passes, averaging them first.
+    ///
+    /// # Panics
+    ///
+    /// If `n_steps` is zero.
+    pub fn step_accumulated(&self, n_steps: usize) {
+        Optimizer::step_accumulated(self, n_steps);
+    }
 }
 
 /// A parameter used by the *centered RMSProp* optimizer.
@@ -553,6 +601,15 @@ impl<'a, T: Penalty> Optimizer<'a> for RMSPropCentered<'a, T> {
         });
     }
 
+    fn step_accumulated(&self, n_steps: usize) {
+        let factor = accumulation_factor(n_steps);
+        self.params.borrow_mut().par_iter_mut().for_each(|param| {
+            let grad = &mut param.grad;
+            Zip::from(grad).for_each(|grad_el| *grad_el *= factor);
+        });
+        self.step();
+    }
+
     fn get_lr(&self) -> f32 {
         self.lr.get()
     }
@@ -623,6 +680,16 @@ impl<'a, T: Penalty> RMSPropCenteredWithMomentum<'a, T> {
     pub fn zero_grad(&self) {
         Optimizer::zero_grad(self);
     }
+
+    /// Performs a single optimization step with the gradients accumulated over `n_steps`
+    /// backward passes, averaging them first.
+    ///
+    /// # Panics
+    ///
+    /// If `n_steps` is zero.
+    pub fn step_accumulated(&self, n_steps: usize) {
+        Optimizer::step_accumulated(self, n_steps);
+    }
 }
 
 /// A parameter used by the *centered RMSProp* optimizer with *momentum*.
@@ -758,6 +825,15 @@ impl<'a, T: Penalty> Optimizer<'a> for RMSPropCenteredWithMomentum<'a, T> {
         });
     }
 
+    fn step_accumulated(&self, n_steps: usize) {
+        let factor = accumulation_factor(n_steps);
+        self.params.borrow_mut().par_iter_mut().for_each(|param| {
+            let grad = &mut param.grad;
+            Zip::from(grad).for_each(|grad_el| *grad_el *= factor);
+        });
+        self.step();
+    }
+
     fn get_lr(&self) -> f32 {
         self.lr.get()
     }
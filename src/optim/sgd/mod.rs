@@ -1,14 +1,16 @@
-use super::{Optimizer, Param, Penalty};
+use super::{accumulation_factor, scale_lr, Group, Optimizer, Param, ParamGroup, Penalty};
 use ndarray::{ArrayD, ArrayViewMutD, Zip};
 use rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
 use std::cell::{Cell, RefCell};
 
 #[allow(clippy::upper_case_acronyms)]
 /// **Stochastic Gradient Descent** optimizer.
+///
+/// The parameters can be split in [`ParamGroup`]s, each one with its own learning rate and
+/// penalty regularization. The heavy-ball and the Nesterov momentum variants are obtained with
+/// [`.with_momentum()`](SGD::with_momentum()).
 pub struct SGD<'a, T> {
-    params: RefCell<Vec<SGDParam<'a>>>,
-    lr: Cell<f32>,
-    penalty: T,
+    groups: RefCell<Vec<Group<SGDParam<'a>, T>>>,
 }
 
 #[allow(clippy::upper_case_acronyms)]
@@ -29,28 +31,43 @@ impl<'a, T: Penalty> Optimizer<'a> for SGD<'a, T> {
     type ParamRepr = SGDParam<'a>;
 
     fn step(&self) {
-        let (lr, penalty, mut params) = (self.lr.get(), &self.penalty, self.params.borrow_mut());
-        params.par_iter_mut().for_each(|param| {
-            let (data, grad) = (&mut param.data, &param.grad);
-            Zip::from(data).and(grad).for_each(|data_el, grad_el| {
-                *data_el += -(grad_el + penalty.penalize(data_el)) * lr
+        for group in self.groups.borrow_mut().iter_mut() {
+            let (lr, penalty) = (group.lr, &group.penalty);
+            group.params.par_iter_mut().for_each(|param| {
+                let (data, grad) = (&mut param.data, &param.grad);
+                Zip::from(data).and(grad).for_each(|data_el, grad_el| {
+                    *data_el += -(grad_el + penalty.penalize(data_el)) * lr
+                });
             });
-        });
+        }
     }
 
     fn zero_grad(&self) {
-        self.params.borrow_mut().par_iter_mut().for_each(|param| {
-            let grad = &mut param.grad;
-            Zip::from(grad).for_each(|grad_el| *grad_el = 0.);
-        });
+        for group in self.groups.borrow_mut().iter_mut() {
+            group.params.par_iter_mut().for_each(|param| {
+                let grad = &mut param.grad;
+                Zip::from(grad).for_each(|grad_el| *grad_el = 0.);
+            });
+        }
+    }
+
+    fn step_accumulated(&self, n_steps: usize) {
+        let factor = accumulation_factor(n_steps);
+        for group in self.groups.borrow_mut().iter_mut() {
+            group.params.par_iter_mut().for_each(|param| {
+                let grad = &mut param.grad;
+                Zip::from(grad).for_each(|grad_el| *grad_el *= factor);
+            });
+        }
+        self.step();
     }
 
     fn get_lr(&self) -> f32 {
-        self.lr.get()
+        self.groups.borrow()[0].lr
     }
 
     fn set_lr(&self, lr: f32) {
-        self.lr.set(lr)
+        scale_lr(&mut self.groups.borrow_mut(), lr)
     }
 }
 
@@ -65,22 +82,61 @@ impl<'a, T: Penalty> SGD<'a, T> {
     ///
     /// * `penalty` - penalty regularization.
     pub fn new(parameters: Vec<Param<'a>>, lr: f32, penalty: T) -> Self {
-        let params = RefCell::new(Self::build_params(parameters));
-        let lr = Cell::new(lr);
+        Self::from_param_groups(vec![ParamGroup::new(parameters, lr, penalty)])
+    }
+
+    /// Creates a new *SGD* optimizer from groups of parameters, each one with its own learning
+    /// rate and penalty regularization.
+    ///
+    /// # Arguments
+    ///
+    /// * `groups` - vector of [`ParamGroup`] to optimize.
+    ///
+    /// # Panics
+    ///
+    /// If `groups` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "blas")]
+    /// # extern crate blas_src;
+    /// use neuronika::optim::{ParamGroup, L2, SGD};
+    ///
+    /// let backbone = neuronika::rand(5).requires_grad();
+    /// let head = neuronika::rand(5).requires_grad();
+    ///
+    /// let optim = SGD::from_param_groups(vec![
+    ///     ParamGroup::new(backbone.parameters(), 1e-3, L2::new(0.)),
+    ///     ParamGroup::new(head.parameters(), 1e-2, L2::new(0.)),
+    /// ]);
+    /// ```
+    pub fn from_param_groups(groups: Vec<ParamGroup<'a, T>>) -> Self {
+        assert!(
+            !groups.is_empty(),
+            "error: an optimizer needs at least one parameter group."
+        );
 
         Self {
-            params,
-            lr,
-            penalty,
+            groups: RefCell::new(groups.into_iter().map(Group::from).collect()),
         }
     }
 
-    /// Return the current learning rate.
+    /// Adds `group` to the parameters to optimize.
+    ///
+    /// This is useful when some parameters, such as those of the pre-trained layers of a model,
+    /// are to be unfrozen during training.
+    pub fn add_param_group(&mut self, group: ParamGroup<'a, T>) {
+        self.groups.get_mut().push(Group::from(group));
+    }
+
+    /// Returns the current learning rate, that is the one of the first parameter group.
     pub fn get_lr(&self) -> f32 {
         Optimizer::get_lr(self)
     }
 
-    /// Sets `lr` as the  new value for the learning rate.
+    /// Sets `lr` as the  new value for the learning rate of the first parameter group. The
+    /// learning rates of the other groups are scaled by the same factor.
     pub fn set_lr(&self, lr: f32) {
         Optimizer::set_lr(self, lr);
     }
@@ -95,6 +151,16 @@ impl<'a, T: Penalty> SGD<'a, T> {
         Optimizer::zero_grad(self);
     }
 
+    /// Performs a single optimization step with the gradients accumulated over `n_steps`
+    /// backward passes, averaging them first.
+    ///
+    /// # Panics
+    ///
+    /// If `n_steps` is zero.
+    pub fn step_accumulated(&self, n_steps: usize) {
+        Optimizer::step_accumulated(self, n_steps);
+    }
+
     /// Transforms this *SGD* optimizer in the *momentum* version of the algorithm.
     ///
     /// Nesterov momentum is based on the formula from
@@ -133,13 +199,10 @@ impl<'a, T: Penalty> SGD<'a, T> {
         dampening: f32,
         nesterov: bool,
     ) -> SGDWithMomentum<'a, T> {
-        let params: RefCell<Vec<SGDWithMomentumParam>> =
-            RefCell::new(Self::build_params(self.params.into_inner()));
+        let groups = self.groups.into_inner().into_iter().map(Group::convert);
 
         SGDWithMomentum {
-            params,
-            lr: self.lr,
-            penalty: self.penalty,
+            groups: RefCell::new(groups.collect()),
             momentum: Cell::new(momentum),
             dampening: Cell::new(dampening),
             nesterov: Cell::new(nesterov),
@@ -150,9 +213,7 @@ impl<'a, T: Penalty> SGD<'a, T> {
 #[allow(clippy::upper_case_acronyms)]
 /// The momentum variant of the *Stochastic Gradient Descent* optimizer.
 pub struct SGDWithMomentum<'a, T> {
-    params: RefCell<Vec<SGDWithMomentumParam<'a>>>,
-    lr: Cell<f32>,
-    penalty: T,
+    groups: RefCell<Vec<Group<SGDWithMomentumParam<'a>, T>>>,
     momentum: Cell<f32>,
     dampening: Cell<f32>,
     nesterov: Cell<bool>,
@@ -186,61 +247,82 @@ impl<'a, T: Penalty> Optimizer<'a> for SGDWithMomentum<'a, T> {
     type ParamRepr = SGDWithMomentumParam<'a>;
 
     fn step(&self) {
-        let (lr, penalty, momentum, dampening, nesterov, mut params) = (
-            self.lr.get(),
-            &self.penalty,
+        let (momentum, dampening, nesterov) = (
             &self.momentum.get(),
             &self.dampening.get(),
             &self.nesterov.get(),
-            self.params.borrow_mut(),
         );
 
-        params.par_iter_mut().for_each(|param| {
-            let mut p_grad = param.grad.to_owned();
-            Zip::from(&mut p_grad)
-                .and(&param.data)
-                .for_each(|p_grad_el, data_el| *p_grad_el += penalty.penalize(data_el));
-
-            Zip::from(&mut param.buffer)
-                .and(&p_grad)
-                .for_each(|buffer_el, p_grad_el| {
-                    *buffer_el = *buffer_el * *momentum + p_grad_el * (1. - dampening)
-                });
-
-            let zip = Zip::from(&mut param.data).and(&param.buffer);
-            if *nesterov {
-                zip.and(&p_grad).for_each(|data_el, buffer_el, p_grad_el| {
-                    *data_el += -(p_grad_el + *buffer_el * *momentum) * lr
-                });
-            } else {
-                zip.for_each(|data_el, buffer_el| *data_el += -*buffer_el * lr);
-            }
-        });
+        for group in self.groups.borrow_mut().iter_mut() {
+            let (lr, penalty) = (group.lr, &group.penalty);
+            group.params.par_iter_mut().for_each(|param| {
+                let mut p_grad = param.grad.to_owned();
+                Zip::from(&mut p_grad)
+                    .and(&param.data)
+                    .for_each(|p_grad_el, data_el| *p_grad_el += penalty.penalize(data_el));
+
+                Zip::from(&mut param.buffer)
+                    .and(&p_grad)
+                    .for_each(|buffer_el, p_grad_el| {
+                        *buffer_el = *buffer_el * *momentum + p_grad_el * (1. - dampening)
+                    });
+
+                let zip = Zip::from(&mut param.data).and(&param.buffer);
+                if *nesterov {
+                    zip.and(&p_grad).for_each(|data_el, buffer_el, p_grad_el| {
+                        *data_el += -(p_grad_el + *buffer_el * *momentum) * lr
+                    });
+                } else {
+                    zip.for_each(|data_el, buffer_el| *data_el += -*buffer_el * lr);
+                }
+            });
+        }
     }
 
     fn zero_grad(&self) {
-        self.params.borrow_mut().par_iter_mut().for_each(|param| {
-            let grad = &mut param.grad;
-            Zip::from(grad).for_each(|grad_el| *grad_el = 0.);
-        });
+        for group in self.groups.borrow_mut().iter_mut() {
+            group.params.par_iter_mut().for_each(|param| {
+                let grad = &mut param.grad;
+                Zip::from(grad).for_each(|grad_el| *grad_el = 0.);
+            });
+        }
+    }
+
+    fn step_accumulated(&self, n_steps: usize) {
+        let factor = accumulation_factor(n_steps);
+        for group in self.groups.borrow_mut().iter_mut() {
+            group.params.par_iter_mut().for_each(|param| {
+                let grad = &mut param.grad;
+                Zip::from(grad).for_each(|grad_el| *grad_el *= factor);
+            });
+        }
+        self.step();
     }
 
     fn get_lr(&self) -> f32 {
-        self.lr.get()
+        self.groups.borrow()[0].lr
     }
 
     fn set_lr(&self, lr: f32) {
-        self.lr.set(lr)
+        scale_lr(&mut self.groups.borrow_mut(), lr)
     }
 }
 
 impl<'a, T: Penalty> SGDWithMomentum<'a, T> {
-    /// Returns the current learning rate.
+    /// Adds `group` to the parameters to optimize.
+    ///
+    /// The momentum buffers of the parameters already being optimized are left untouched.
+    pub fn add_param_group(&mut self, group: ParamGroup<'a, T>) {
+        self.groups.get_mut().push(Group::from(group));
+    }
+
+    /// Returns the current learning rate, that is the one of the first parameter group.
     pub fn get_lr(&self) -> f32 {
         Optimizer::get_lr(self)
     }
 
-    /// Sets `lr` as the new value for the learning rate.
+    /// Sets `lr` as the new value for the learning rate of the first parameter group. The
+    /// learning rates of the other groups are scaled by the same factor.
     pub fn set_lr(&self, lr: f32) {
         Optimizer::set_lr(self, lr);
     }
@@ -284,6 +366,16 @@ impl<'a, T: Penalty> SGDWithMomentum<'a, T> {
     pub fn zero_grad(&self) {
         Optimizer::zero_grad(self);
     }
+
+    /// Performs a single optimization step with the gradients accumulated over `n_steps`
+    /// backward passes, averaging them first.
+    ///
+    /// # Panics
+    ///
+    /// If `n_steps` is zero.
+    pub fn step_accumulated(&self, n_steps: usize) {
+        Optimizer::step_accumulated(self, n_steps);
+    }
 }
 
 #[cfg(test)]
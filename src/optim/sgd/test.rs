@@ -1,15 +1,19 @@
-use super::{super::L2, SGD};
+use super::{
+    super::{ParamGroup, L2},
+    SGD,
+};
+use ndarray::array;
 
 #[test]
 fn creation() {
     let optim = SGD::new(Vec::new(), 1e-2, L2::new(1e-2));
 
-    assert_eq!(optim.params.borrow().len(), 0);
+    assert_eq!(optim.groups.borrow()[0].params.len(), 0);
     assert!((optim.get_lr() - 1e-2).abs() <= f32::EPSILON);
 
     let optim = optim.with_momentum(0.5, 0.0, true);
 
-    assert_eq!(optim.params.borrow().len(), 0);
+    assert_eq!(optim.groups.borrow()[0].params.len(), 0);
     assert!((optim.get_lr() - 1e-2).abs() <= f32::EPSILON);
     assert!((optim.get_momentum() - 0.5).abs() <= f32::EPSILON);
     assert!(optim.get_dampening().abs() <= f32::EPSILON);
@@ -53,6 +57,74 @@ fn set_nesterov() {
     assert!(optim.get_nesterov());
 }
 
+#[test]
+fn param_groups() {
+    let x = crate::zeros(2).requires_grad();
+    let y = crate::zeros(2).requires_grad();
+    x.grad_mut().fill(1.);
+    y.grad_mut().fill(1.);
+
+    let optim = SGD::from_param_groups(vec![
+        ParamGroup::new(x.parameters(), 0.1, L2::new(0.)),
+        ParamGroup::new(y.parameters(), 0.01, L2::new(0.)),
+    ]);
+    assert!((optim.get_lr() - 0.1).abs() <= f32::EPSILON);
+
+    optim.step();
+    assert_eq!(*x.data(), array![-0.1, -0.1]);
+    assert_eq!(*y.data(), array![-0.01, -0.01]);
+
+    // The ratio between the learning rates of the groups is kept.
+    optim.set_lr(0.2);
+    assert!((optim.get_lr() - 0.2).abs() <= f32::EPSILON);
+    assert!((optim.groups.borrow()[1].lr - 0.02).abs() <= f32::EPSILON);
+}
+
+#[test]
+#[should_panic(expected = "error: an optimizer needs at least one parameter group.")]
+fn param_groups_empty() {
+    SGD::<L2>::from_param_groups(Vec::new());
+}
+
+#[test]
+fn add_param_group() {
+    let x = crate::zeros(2).requires_grad();
+    let y = crate::zeros(2).requires_grad();
+    x.grad_mut().fill(1.);
+    y.grad_mut().fill(1.);
+
+    let mut optim = SGD::new(x.parameters(), 0.1, L2::new(0.));
+    optim.step();
+    assert_eq!(*x.data(), array![-0.1, -0.1]);
+
+    optim.add_param_group(ParamGroup::new(y.parameters(), 0.5, L2::new(0.)));
+    optim.step();
+    assert!(x.data().iter().all(|el| (el + 0.2).abs() <= f32::EPSILON));
+    assert_eq!(*y.data(), array![-0.5, -0.5]);
+
+    optim.zero_grad();
+    assert_eq!(*x.grad(), array![0., 0.]);
+    assert_eq!(*y.grad(), array![0., 0.]);
+}
+
+#[test]
+fn add_param_group_with_momentum() {
+    let x = crate::zeros(2).requires_grad();
+    let y = crate::zeros(2).requires_grad();
+    x.grad_mut().fill(1.);
+    y.grad_mut().fill(1.);
+
+    let mut optim = SGD::new(x.parameters(), 0.1, L2::new(0.)).with_momentum(0.5, 0., false);
+    optim.step();
+    assert!(x.data().iter().all(|el| (el + 0.1).abs() <= f32::EPSILON));
+
+    // The momentum buffer of x survives the addition of the new group, the one of y starts empty.
+    optim.add_param_group(ParamGroup::new(y.parameters(), 0.1, L2::new(0.)));
+    optim.step();
+    assert!(x.data().iter().all(|el| (el + 0.25).abs() <= f32::EPSILON));
+    assert!(y.data().iter().all(|el| (el + 0.1).abs() <= f32::EPSILON));
+}
+
 const EPOCHS: usize = 200;
 
 #[test]
@@ -126,3 +198,65 @@ fn step_with_nesterov_momentum() {
     }
     assert!(loss.data().clone().into_scalar() < first_value.clone());
 }
+
+#[test]
+fn momentum_accelerates_convergence() {
+    // Minimizes the quadratic Σ w² starting from the same point, with and without momentum.
+    let w = crate::ones(3).requires_grad();
+    let loss = w.pow(2).sum();
+    let optim = SGD::new(loss.parameters(), 1e-2, L2::new(0.));
+    for _ in 0..20 {
+        loss.forward();
+        loss.backward(1.0);
+
+        optim.step();
+        optim.zero_grad();
+    }
+    loss.forward();
+    let vanilla_value = loss.data().clone().into_scalar();
+
+    let w = crate::ones(3).requires_grad();
+    let loss = w.pow(2).sum();
+    let optim = SGD::new(loss.parameters(), 1e-2, L2::new(0.)).with_momentum(0.5, 0., false);
+    for _ in 0..20 {
+        loss.forward();
+        loss.backward(1.0);
+
+        optim.step();
+        optim.zero_grad();
+    }
+    loss.forward();
+    assert!(loss.data().clone().into_scalar() < vanilla_value);
+}
+
+#[test]
+fn nesterov_correction() {
+    // With a constant gradient g the velocities are v₁ = g and v₂ = (1 + μ)g. Each Nesterov step
+    // must differ from the heavy-ball one by the look-ahead correction -lr * μ * (vₜ - vₜ₋₁).
+    let (lr, momentum) = (0.1, 0.5);
+    let heavy_ball = crate::zeros(1).requires_grad();
+    let nesterov = crate::zeros(1).requires_grad();
+    heavy_ball.grad_mut().fill(1.);
+    nesterov.grad_mut().fill(1.);
+
+    let heavy_ball_optim =
+        SGD::new(heavy_ball.parameters(), lr, L2::new(0.)).with_momentum(momentum, 0., false);
+    let nesterov_optim =
+        SGD::new(nesterov.parameters(), lr, L2::new(0.)).with_momentum(momentum, 0., true);
+
+    let mut previous_velocity = 0.;
+    let (mut heavy_ball_previous, mut nesterov_previous) = (0., 0.);
+    for velocity in [1., 1. + momentum] {
+        heavy_ball_optim.step();
+        nesterov_optim.step();
+
+        let (heavy_ball_now, nesterov_now) = (heavy_ball.data()[0], nesterov.data()[0]);
+        let correction =
+            (nesterov_now - nesterov_previous) - (heavy_ball_now - heavy_ball_previous);
+        assert!((correction + lr * momentum * (velocity - previous_velocity)).abs() <= 1e-6);
+
+        previous_velocity = velocity;
+        heavy_ball_previous = heavy_ball_now;
+        nesterov_previous = nesterov_now;
+    }
+}
@@ -0,0 +1,175 @@
+use super::{Adam, ElasticNet, Optimizer, Penalty, SGDParam, L1, L2, SGD};
+use ndarray::{array, s, Array1, Array2};
+
+const WEIGHTS: [f32; 5] = [-2., -0.3, 0., 0.05, 1.5];
+
+#[test]
+fn l1_penalize() {
+    let penalty = L1::new(0.1);
+
+    assert!((penalty.penalize(&-2.) + 0.1).abs() <= f32::EPSILON);
+    assert!((penalty.penalize(&0.05) - 0.1).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn l2_penalize() {
+    let penalty = L2::new(0.1);
+
+    assert!((penalty.penalize(&-2.) + 0.4).abs() <= f32::EPSILON);
+    assert!((penalty.penalize(&0.05) - 0.01).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn l1_sparsity() {
+    // With no loss the weights are driven by the penalties alone. L1 pulls them towards zero by a
+    // constant amount, while L2 does it proportionally to their magnitude.
+    let w_l1 = crate::full(3, 0.01).requires_grad();
+    let w_l2 = crate::full(3, 0.01).requires_grad();
+    let optim_l1 = SGD::new(w_l1.parameters(), 0.01, L1::new(0.1));
+    let optim_l2 = SGD::new(w_l2.parameters(), 0.01, L2::new(0.1));
+
+    for _ in 0..5 {
+        optim_l1.step();
+        optim_l2.step();
+    }
+
+    assert!(w_l1.data().iter().all(|w| (w - 0.005).abs() <= 1e-6));
+    assert!(w_l1
+        .data()
+        .iter()
+        .zip(w_l2.data().iter())
+        .all(|(w_l1, w_l2)| w_l1.abs() < w_l2.abs()));
+}
+
+#[test]
+fn elastic_net_penalize() {
+    let penalty = ElasticNet::new(0.1, 0.2);
+    let (l1, l2) = (L1::new(0.1), L2::new(0.2));
+
+    assert!(WEIGHTS
+        .iter()
+        .all(|w| (penalty.penalize(w) - l1.penalize(w) - l2.penalize(w)).abs() <= f32::EPSILON));
+}
+
+#[test]
+fn elastic_net_pure_l1() {
+    let penalty = ElasticNet::from_l1_ratio(0.3, 1.);
+    let l1 = L1::new(0.3);
+
+    assert!(WEIGHTS
+        .iter()
+        .all(|w| (penalty.penalize(w) - l1.penalize(w)).abs() <= f32::EPSILON));
+}
+
+#[test]
+fn elastic_net_pure_l2() {
+    let penalty = ElasticNet::from_l1_ratio(0.3, 0.);
+    let l2 = L2::new(0.3);
+
+    assert!(WEIGHTS
+        .iter()
+        .all(|w| (penalty.penalize(w) - l2.penalize(w)).abs() <= f32::EPSILON));
+}
+
+#[test]
+#[should_panic(expected = "error: l1_ratio must be in the range [0, 1], got 1.5.")]
+fn elastic_net_invalid_ratio() {
+    ElasticNet::from_l1_ratio(0.3, 1.5);
+}
+
+/// Returns the weights obtained by a single step of the optimizer built by `step`, either on the
+/// full batch or on its two halves with the gradients accumulated.
+fn accumulated_step(accumulate: bool, step: impl Fn(Vec<crate::Param>, usize)) -> Array1<f32> {
+    let batch: Array2<f32> = array![[1., 2.], [-1., 0.5], [0.3, -2.], [2., 1.]];
+    let target: Array1<f32> = array![1., 0., -1., 2.];
+    let n_steps = if accumulate { 2 } else { 1 };
+    let size = 4 / n_steps;
+
+    let w = crate::from_ndarray(array![0.5, -0.5]).requires_grad();
+    let x = crate::zeros((size, 2));
+    let y = crate::zeros(size);
+    let loss = (x.clone().mv(w.clone()) - y.clone()).pow(2).mean();
+
+    for chunk in 0..n_steps {
+        let (start, end) = (chunk * size, (chunk + 1) * size);
+        x.data_mut().assign(&batch.slice(s![start..end, ..]));
+        y.data_mut().assign(&target.slice(s![start..end]));
+        loss.forward();
+        loss.backward(1.);
+    }
+    step(loss.parameters(), n_steps);
+
+    let weights = w.data().to_owned();
+    weights
+}
+
+#[test]
+fn step_accumulated() {
+    let full = accumulated_step(false, |params, n_steps| {
+        SGD::new(params, 0.1, L2::new(0.01)).step_accumulated(n_steps)
+    });
+    let accumulated = accumulated_step(true, |params, n_steps| {
+        SGD::new(params, 0.1, L2::new(0.01)).step_accumulated(n_steps)
+    });
+    assert!(full
+        .iter()
+        .zip(accumulated.iter())
+        .all(|(full, accumulated)| (full - accumulated).abs() <= 1e-6));
+
+    let full = accumulated_step(false, |params, n_steps| {
+        Adam::new(params, 0.1, (0.9, 0.999), L2::new(0.01), 1e-8).step_accumulated(n_steps)
+    });
+    let accumulated = accumulated_step(true, |params, n_steps| {
+        Adam::new(params, 0.1, (0.9, 0.999), L2::new(0.01), 1e-8).step_accumulated(n_steps)
+    });
+    assert!(full
+        .iter()
+        .zip(accumulated.iter())
+        .all(|(full, accumulated)| (full - accumulated).abs() <= 1e-6));
+}
+
+/// Optimizer relying on the default implementation of `.step_accumulated()`.
+struct PlainSGD<'a>(SGD<'a, L2>);
+
+impl<'a> Optimizer<'a> for PlainSGD<'a> {
+    type ParamRepr = SGDParam<'a>;
+
+    fn step(&self) {
+        self.0.step();
+    }
+
+    fn zero_grad(&self) {
+        self.0.zero_grad();
+    }
+
+    fn get_lr(&self) -> f32 {
+        self.0.get_lr()
+    }
+
+    fn set_lr(&self, lr: f32) {
+        self.0.set_lr(lr);
+    }
+}
+
+#[test]
+fn step_accumulated_default() {
+    // Dividing the learning rate scales the penalty as well, hence there's none.
+    let full = accumulated_step(false, |params, n_steps| {
+        SGD::new(params, 0.1, L2::new(0.)).step_accumulated(n_steps)
+    });
+    let accumulated = accumulated_step(true, |params, n_steps| {
+        let optim = PlainSGD(SGD::new(params, 0.1, L2::new(0.)));
+        optim.step_accumulated(n_steps);
+        assert!((optim.get_lr() - 0.1).abs() <= f32::EPSILON);
+    });
+    assert!(full
+        .iter()
+        .zip(accumulated.iter())
+        .all(|(full, accumulated)| (full - accumulated).abs() <= 1e-6));
+}
+
+#[test]
+#[should_panic(expected = "error: the number of accumulated steps must be positive.")]
+fn step_accumulated_zero_steps() {
+    SGD::new(Vec::new(), 0.1, L2::new(0.)).step_accumulated(0);
+}
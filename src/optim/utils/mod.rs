@@ -0,0 +1,123 @@
+use crate::variable::Param;
+use ndarray::Zip;
+
+/// Clips the gradients of `params` so that their total norm is at most `max_norm`.
+///
+/// The norm is computed over all the gradients together, as if they were concatenated into a
+/// single vector. If it exceeds `max_norm` every gradient is rescaled by `max_norm / total_norm`,
+/// otherwise the gradients are left untouched.
+///
+/// The gradients are modified in place, so this function must be called after the backward pass
+/// and before the optimizer's step.
+///
+/// Returns the total norm of the gradients before clipping.
+///
+/// # Arguments
+///
+/// * `params` - parameters whose gradients are to be clipped.
+///
+/// * `max_norm` - maximum norm of the gradients.
+///
+/// * `norm_type` - order of the norm, `f32::INFINITY` for the largest absolute value.
+///
+/// # Panics
+///
+/// If `norm_type` is not positive.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "blas")]
+/// # extern crate blas_src;
+/// use neuronika::optim::{self, Optimizer, SGD, L2};
+///
+/// let w = neuronika::full(4, 3.).requires_grad();
+/// let loss = (w.clone() * 2.).sum();
+/// let optim = SGD::new(loss.parameters(), 0.1, L2::new(0.));
+///
+/// loss.forward();
+/// loss.backward(1.);
+///
+/// let norm = optim::clip_grad_norm_(&mut loss.parameters(), 1., 2.);
+/// assert!((norm - 4.).abs() <= f32::EPSILON);
+/// assert!(w.grad().iter().all(|grad| (grad - 0.5).abs() <= f32::EPSILON));
+///
+/// optim.step();
+/// ```
+pub fn clip_grad_norm_(params: &mut [Param], max_norm: f32, norm_type: f32) -> f32 {
+    assert!(
+        norm_type > 0.,
+        "error: norm_type must be positive, got {}.",
+        norm_type
+    );
+
+    let total_norm = if norm_type.is_infinite() {
+        params
+            .iter()
+            .flat_map(|param| param.grad.iter())
+            .fold(0., |max: f32, grad| max.max(grad.abs()))
+    } else {
+        params
+            .iter()
+            .flat_map(|param| param.grad.iter())
+            .map(|grad| grad.abs().powf(norm_type))
+            .sum::<f32>()
+            .powf(1. / norm_type)
+    };
+
+    if total_norm > max_norm {
+        let scale = max_norm / total_norm;
+        for param in params.iter_mut() {
+            param.grad.mapv_inplace(|grad| grad * scale);
+        }
+    }
+
+    total_norm
+}
+
+/// Clamps every element of the gradients of `params` in the range `[-clip_value, clip_value]`.
+///
+/// The gradients are modified in place, so this function must be called after the backward pass
+/// and before the optimizer's step.
+///
+/// # Arguments
+///
+/// * `params` - parameters whose gradients are to be clipped.
+///
+/// * `clip_value` - maximum absolute value of the elements of the gradients.
+///
+/// # Panics
+///
+/// If `clip_value` is negative or NaN.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "blas")]
+/// # extern crate blas_src;
+/// use neuronika::optim;
+///
+/// let w = neuronika::full(3, 3.).requires_grad();
+/// let loss = (w.clone() * w.clone()).sum();
+///
+/// loss.forward();
+/// loss.backward(1.);
+///
+/// optim::clip_grad_value_(&mut loss.parameters(), 1.);
+/// assert!(w.grad().iter().all(|grad| (grad - 1.).abs() <= f32::EPSILON));
+/// ```
+pub fn clip_grad_value_(params: &mut [Param], clip_value: f32) {
+    assert!(
+        clip_value >= 0.,
+        "error: clip_value must be non-negative, got {}.",
+        clip_value
+    );
+
+    for param in params.iter_mut() {
+        Zip::from(&mut param.grad)
+            .for_each(|grad_el| *grad_el = grad_el.clamp(-clip_value, clip_value));
+    }
+}
+
+#[cfg(test)]
+mod test;
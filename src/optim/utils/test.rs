@@ -0,0 +1,113 @@
+use super::{clip_grad_norm_, clip_grad_value_};
+use ndarray::array;
+
+fn total_norm(grads: &[f32], norm_type: f32) -> f32 {
+    grads
+        .iter()
+        .map(|grad| grad.abs().powf(norm_type))
+        .sum::<f32>()
+        .powf(1. / norm_type)
+}
+
+#[test]
+fn clip_grad_norm() {
+    let x = crate::ones(3).requires_grad();
+    let y = crate::ones((2, 2)).requires_grad();
+    let z = x.clone().sum() + y.clone().sum();
+    x.grad_mut().assign(&array![1., -2., 3.]);
+    y.grad_mut().assign(&array![[-4., 5.], [6., -7.]]);
+
+    let norm = clip_grad_norm_(&mut z.parameters(), 2., 2.);
+    assert!((norm - 140_f32.sqrt()).abs() <= 1e-5);
+
+    let grads: Vec<f32> = x.grad().iter().chain(y.grad().iter()).copied().collect();
+    assert!((total_norm(&grads, 2.) - 2.).abs() <= 1e-5);
+
+    // Clipping only rescales the gradients.
+    let scale = 2. / 140_f32.sqrt();
+    assert!(x
+        .grad()
+        .iter()
+        .zip(&[1., -2., 3.])
+        .all(|(grad, expected)| (grad - expected * scale).abs() <= 1e-6));
+}
+
+#[test]
+fn clip_grad_norm_p() {
+    let x = crate::ones(4).requires_grad();
+    x.grad_mut().assign(&array![1., -2., 3., -4.]);
+
+    let norm = clip_grad_norm_(&mut x.parameters(), 5., 1.);
+    assert!((norm - 10.).abs() <= 1e-5);
+    assert!((total_norm(x.grad().as_slice().unwrap(), 1.) - 5.).abs() <= 1e-5);
+
+    x.grad_mut().assign(&array![1., -2., 3., -4.]);
+
+    let norm = clip_grad_norm_(&mut x.parameters(), 1., 3.);
+    assert!((norm - 100_f32.cbrt()).abs() <= 1e-5);
+    assert!((total_norm(x.grad().as_slice().unwrap(), 3.) - 1.).abs() <= 1e-5);
+}
+
+#[test]
+fn clip_grad_norm_inf() {
+    let x = crate::ones(4).requires_grad();
+    x.grad_mut().assign(&array![1., -8., 3., -4.]);
+
+    let norm = clip_grad_norm_(&mut x.parameters(), 2., f32::INFINITY);
+    assert!((norm - 8.).abs() <= f32::EPSILON);
+    assert_eq!(*x.grad(), array![0.25, -2., 0.75, -1.]);
+}
+
+#[test]
+fn clip_grad_norm_below_max() {
+    let x = crate::ones(2).requires_grad();
+    x.grad_mut().assign(&array![3., 4.]);
+
+    let norm = clip_grad_norm_(&mut x.parameters(), 10., 2.);
+    assert!((norm - 5.).abs() <= f32::EPSILON);
+    assert_eq!(*x.grad(), array![3., 4.]);
+}
+
+#[test]
+#[should_panic(expected = "error: norm_type must be positive, got 0.")]
+fn clip_grad_norm_invalid_norm_type() {
+    let x = crate::ones(2).requires_grad();
+
+    clip_grad_norm_(&mut x.parameters(), 1., 0.);
+}
+
+#[test]
+fn clip_grad_value() {
+    let x = crate::ones(4).requires_grad();
+    let y = crate::ones((2, 2)).requires_grad();
+    let z = x.clone().sum() + y.clone().sum();
+    x.grad_mut().assign(&array![0.5, -3., 1., -1.]);
+    y.grad_mut().assign(&array![[-0.25, 2.], [0., -7.]]);
+
+    clip_grad_value_(&mut z.parameters(), 1.);
+    assert!(x.grad().iter().all(|grad| grad.abs() <= 1.));
+    assert!(y.grad().iter().all(|grad| grad.abs() <= 1.));
+    assert_eq!(*x.grad(), array![0.5, -1., 1., -1.]);
+    assert_eq!(*y.grad(), array![[-0.25, 1.], [0., -1.]]);
+}
+
+#[test]
+fn clip_grad_value_after_no_grad() {
+    // Disabling the gradients de-allocates those of the intermediate nodes only, the leaves
+    // referred to by the parameters keep theirs.
+    let x = crate::ones(3).requires_grad();
+    let z = (x.clone() * 2.).sum();
+    z.no_grad();
+    x.grad_mut().assign(&array![5., -0.5, -5.]);
+
+    clip_grad_value_(&mut z.parameters(), 2.);
+    assert_eq!(*x.grad(), array![2., -0.5, -2.]);
+}
+
+#[test]
+#[should_panic(expected = "error: clip_value must be non-negative, got -1.")]
+fn clip_grad_value_negative() {
+    let x = crate::ones(2).requires_grad();
+
+    clip_grad_value_(&mut x.parameters(), -1.);
+}
@@ -0,0 +1,88 @@
+use crate::variable::{Data, Gradient, GraphNode, VarDiff};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+    rc::Rc,
+};
+
+/// Renders the computational graph leading to `var` as a **Graphviz** DOT string.
+///
+/// Each node is labeled with the name of its type, the shape of its data and whether its data was
+/// computed, while each edge goes from an operand to the node that depends on it. The output can
+/// be drawn, for instance, with `dot -Tsvg graph.dot -o graph.svg`.
+///
+/// Every node is visited once, so shared sub-graphs are rendered a single time and a malformed
+/// graph containing a cycle doesn't cause an endless traversal.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "blas")]
+/// # extern crate blas_src;
+/// let x = neuronika::ones((2, 3));
+/// let w = neuronika::rand((4, 3)).requires_grad();
+/// let y = x.mm_t(w).relu();
+///
+/// let dot = neuronika::utils::graph_to_dot(&y);
+/// assert!(dot.starts_with("digraph {"));
+/// assert!(dot.contains("ReLU"));
+/// ```
+pub fn graph_to_dot<T: ?Sized, U: ?Sized>(var: &VarDiff<T, U>) -> String
+where
+    T: Data + 'static,
+    U: Gradient + 'static,
+{
+    let past = &var.var.past;
+    let graph = past.graph();
+
+    let mut visited = BTreeSet::new();
+    let mut stack: Vec<usize> = past.heads().iter().copied().collect();
+    while let Some(id) = stack.pop() {
+        if !visited.insert(id) {
+            continue;
+        }
+        if let Some(node) = graph.get(&id) {
+            stack.extend(node.operands.iter().filter(|id| !visited.contains(id)));
+        }
+    }
+
+    render(graph, &visited)
+}
+
+/// Writes the nodes of `graph` whose id is in `ids`, together with the edges among them.
+fn render(graph: &BTreeMap<usize, Rc<GraphNode>>, ids: &BTreeSet<usize>) -> String {
+    let mut dot = String::from("digraph {\n    node [shape=box];\n");
+    for (id, node) in ids
+        .iter()
+        .filter_map(|id| graph.get(id).map(|node| (id, node)))
+    {
+        writeln!(
+            dot,
+            "    {} [label=\"{}\\nshape={:?}\\ncomputed={}\"];",
+            id,
+            short_name(node.name),
+            node.shape,
+            node.node.was_computed()
+        )
+        .unwrap();
+    }
+    for (id, node) in ids
+        .iter()
+        .filter_map(|id| graph.get(id).map(|node| (id, node)))
+    {
+        for operand in node.operands.iter().filter(|operand| ids.contains(operand)) {
+            writeln!(dot, "    {} -> {};", operand, id).unwrap();
+        }
+    }
+    dot.push('}');
+
+    dot
+}
+
+/// Strips the module path and the generic arguments from a type name, e.g.
+/// `neuronika::variable::node::Input<ndarray::Dim<[usize; 2]>>` becomes `Input`.
+fn short_name(name: &str) -> &str {
+    let name = name.split('<').next().unwrap_or(name);
+
+    name.rsplit("::").next().unwrap_or(name)
+}
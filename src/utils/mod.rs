@@ -0,0 +1,22 @@
+//! Utilities for inspecting computational graphs and persisting weights.
+//!
+//! * [`graph_to_dot`] - Renders the computational graph of a differentiable variable in the
+//! [DOT](https://graphviz.org/doc/info/lang.html) language, so that it can be drawn by
+//! **Graphviz**.
+//!
+//! * [`save_weights`] - Saves the data of some named variables to a file.
+//!
+//! * [`load_weights`] - Restores the data of some named variables from a file written by
+//! [`save_weights`].
+//!
+//! The last two are available with the `serialize` feature only.
+mod dot;
+#[cfg(feature = "serialize")]
+mod serialization;
+
+pub use dot::graph_to_dot;
+#[cfg(feature = "serialize")]
+pub use serialization::{load_weights, save_weights};
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,132 @@
+use crate::{
+    io::{Container, ContainerError},
+    AnyVarDiff,
+};
+use std::{
+    collections::HashMap,
+    io::{self, ErrorKind},
+    path::Path,
+};
+
+/// Kind of the containers holding weights.
+const WEIGHTS_KIND: &str = "weights";
+
+/// Name of the section holding the serialized weights.
+const WEIGHTS_SECTION: &str = "weights";
+
+/// Saves the data of the variables in `var_list` to the file at `path`, each one under its name.
+///
+/// The weights are stored as a map from names to shapes and flat vectors of values, serialized with
+/// **bincode** inside a neuronika [`Container`], so that an existing file is never left partially
+/// overwritten. The variables can be restored with [`load_weights`], usually pairing them with
+/// the names given by the `.named_parameters()` method of the layers.
+///
+/// # Errors
+///
+/// If the file cannot be written.
+///
+/// # Examples
+///
+/// ```
+/// use neuronika::{nn::Linear, utils::{load_weights, save_weights}};
+///
+/// let path = std::env::temp_dir().join("neuronika_save_weights_example.nrk");
+/// let linear = Linear::new(3, 2);
+/// save_weights(&linear.named_parameters(), &path).unwrap();
+///
+/// let other = Linear::new(3, 2);
+/// load_weights(&other.named_parameters(), &path).unwrap();
+/// assert_eq!(*other.weight.data(), *linear.weight.data());
+/// # std::fs::remove_file(path).unwrap();
+/// ```
+pub fn save_weights<S: AsRef<str>>(var_list: &[(S, AnyVarDiff)], path: &Path) -> io::Result<()> {
+    let weights: HashMap<String, (Vec<usize>, Vec<f32>)> = var_list
+        .iter()
+        .map(|(name, var)| {
+            (
+                name.as_ref().to_string(),
+                (var.shape(), var.to_array().into_iter().collect()),
+            )
+        })
+        .collect();
+    let payload = bincode::serialize(&weights).map_err(invalid_data)?;
+
+    let mut container = Container::new(WEIGHTS_KIND);
+    container.push_section(WEIGHTS_SECTION, payload);
+    container.write(path).map_err(into_io_error)
+}
+
+/// Loads the data of the variables in `var_list` from the file at `path`, which must have been
+/// written by [`save_weights`].
+///
+/// Each variable is looked up by its name, the entries of the file that are not in `var_list` are
+/// ignored. No variable is modified unless all of them can be restored.
+///
+/// # Errors
+///
+/// If the file cannot be read, doesn't hold weights, lacks one of the names in `var_list` or holds
+/// weights whose shape doesn't match the one of the corresponding variable.
+pub fn load_weights<S: AsRef<str>>(var_list: &[(S, AnyVarDiff)], path: &Path) -> io::Result<()> {
+    let container = Container::read(path).map_err(into_io_error)?;
+    if container.kind() != WEIGHTS_KIND {
+        return Err(invalid_data(format!(
+            "error: {} holds a {:?} container, not weights.",
+            path.display(),
+            container.kind()
+        )));
+    }
+    let payload = container.section(WEIGHTS_SECTION).ok_or_else(|| {
+        invalid_data(format!(
+            "error: {} has no {:?} section.",
+            path.display(),
+            WEIGHTS_SECTION
+        ))
+    })?;
+    let weights: HashMap<String, (Vec<usize>, Vec<f32>)> =
+        bincode::deserialize(payload).map_err(invalid_data)?;
+
+    let values = var_list
+        .iter()
+        .map(|(name, var)| {
+            let name = name.as_ref();
+            let (shape, values) = weights.get(name).ok_or_else(|| {
+                io::Error::new(
+                    ErrorKind::NotFound,
+                    format!("error: no weights named {:?} in {}.", name, path.display()),
+                )
+            })?;
+            if *shape != var.shape() || values.len() != shape.iter().product::<usize>() {
+                return Err(invalid_data(format!(
+                    "error: the weights named {:?} have shape {:?}, but the variable has shape {:?}.",
+                    name,
+                    shape,
+                    var.shape()
+                )));
+            }
+            Ok(values)
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+
+    var_list
+        .iter()
+        .zip(values)
+        .for_each(|((_, var), values)| var.copy_from_slice(values));
+    Ok(())
+}
+
+/// Wraps `error` into an [`io::Error`] of kind [`ErrorKind::InvalidData`].
+fn invalid_data<E>(error: E) -> io::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    io::Error::new(ErrorKind::InvalidData, error)
+}
+
+/// Converts a [`ContainerError`] into an [`io::Error`], keeping the underlying I/O errors as they
+/// are.
+fn into_io_error(error: ContainerError) -> io::Error {
+    match error {
+        ContainerError::Io(error) => error,
+        error => invalid_data(error),
+    }
+}
@@ -0,0 +1,144 @@
+use super::graph_to_dot;
+#[cfg(feature = "serialize")]
+use super::{load_weights, save_weights};
+#[cfg(feature = "serialize")]
+use crate::nn::Linear;
+#[cfg(feature = "serialize")]
+use std::{io::ErrorKind, path::PathBuf};
+
+/// Returns a path in the temporary directory that is unique to the test named `name`.
+#[cfg(feature = "serialize")]
+fn tmp_file(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("neuronika_{}_{}.nrk", name, std::process::id()))
+}
+
+#[test]
+fn two_layers() {
+    let x = crate::ones((2, 3));
+    let w1 = crate::ones((4, 3)).requires_grad();
+    let b1 = crate::zeros(4).requires_grad();
+    let w2 = crate::ones((1, 4)).requires_grad();
+    let y = (x.mm_t(w1) + b1).relu().mm_t(w2);
+
+    let dot = graph_to_dot(&y);
+    assert!(dot.starts_with("digraph {\n"));
+    assert!(dot.ends_with('}'));
+    assert_eq!(dot.matches("label=\"Input\\n").count(), 4);
+    assert_eq!(dot.matches("label=\"MatrixMatrixMulT\\n").count(), 2);
+    assert!(dot.contains("label=\"Addition\\nshape=[2, 4]\\ncomputed=false\""));
+    assert!(dot.contains("label=\"ReLU\\nshape=[2, 4]\\ncomputed=false\""));
+    assert!(dot.contains("label=\"MatrixMatrixMulT\\nshape=[2, 1]\\ncomputed=false\""));
+    assert_eq!(dot.matches(" -> ").count(), 7);
+
+    y.forward();
+    let dot = graph_to_dot(&y);
+    assert!(!dot.contains("computed=false"));
+}
+
+#[test]
+fn shared_nodes() {
+    // The hidden activation is used twice, it must be rendered once with two outgoing edges.
+    let x = crate::ones((2, 3)).requires_grad();
+    let h = x.sigmoid();
+    let y = h.clone() * h.clone() + h;
+
+    let dot = graph_to_dot(&y);
+    assert_eq!(dot.matches("label=\"Sigmoid\\n").count(), 1);
+    assert_eq!(dot.matches("label=\"Input\\n").count(), 1);
+
+    let sigmoid = dot
+        .lines()
+        .find(|line| line.contains("Sigmoid"))
+        .and_then(|line| line.split_whitespace().next())
+        .unwrap()
+        .to_string();
+    assert_eq!(dot.matches(&format!("    {} -> ", sigmoid)).count(), 2);
+}
+
+#[test]
+fn leaf() {
+    let x = crate::zeros(3).requires_grad();
+
+    assert_eq!(
+        graph_to_dot(&x)
+            .lines()
+            .filter(|line| line.contains("label"))
+            .count(),
+        1
+    );
+    assert!(!graph_to_dot(&x).contains(" -> "));
+}
+
+#[test]
+#[cfg(feature = "serialize")]
+fn save_and_load_weights() {
+    let path = tmp_file("save_and_load_weights");
+    let linear = Linear::new(3, 2);
+    let linear_bias = linear.bias.as_ref().unwrap();
+    let (weight, bias) = (linear.weight.data().clone(), linear_bias.data().clone());
+    save_weights(&linear.named_parameters(), &path).unwrap();
+
+    linear.weight.data_mut().fill(0.);
+    linear_bias.data_mut().fill(0.);
+    load_weights(&linear.named_parameters(), &path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(*linear.weight.data(), weight);
+    assert_eq!(*linear_bias.data(), bias);
+}
+
+#[test]
+#[cfg(feature = "serialize")]
+fn load_missing_weights() {
+    let path = tmp_file("load_missing_weights");
+    let linear = Linear::new(3, 2);
+    save_weights(&linear.named_parameters()[..1], &path).unwrap();
+
+    let bias = linear.bias.as_ref().unwrap().data().clone();
+    linear.weight.data_mut().fill(0.);
+    let error = load_weights(&linear.named_parameters(), &path).unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(error.kind(), ErrorKind::NotFound);
+    assert!(error.to_string().contains("\"bias\""));
+    // Nothing is restored when a name is missing.
+    assert!(linear.weight.data().iter().all(|el| *el == 0.));
+    assert_eq!(*linear.bias.as_ref().unwrap().data(), bias);
+}
+
+#[test]
+#[cfg(feature = "serialize")]
+fn load_mismatched_weights() {
+    let path = tmp_file("load_mismatched_weights");
+    save_weights(&Linear::new(3, 2).named_parameters(), &path).unwrap();
+
+    let error = load_weights(&Linear::new(4, 2).named_parameters(), &path).unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+    assert_eq!(
+        error.to_string(),
+        "error: the weights named \"weight\" have shape [2, 3], but the variable has shape [2, 4]."
+    );
+}
+
+#[test]
+#[cfg(feature = "serialize")]
+fn load_transposed_weights() {
+    let path = tmp_file("load_transposed_weights");
+    let linear = Linear::new(3, 2);
+    save_weights(&linear.named_parameters()[..1], &path).unwrap();
+
+    // Same number of values, but laid out differently.
+    let transposed = Linear::new(2, 3);
+    let weight = transposed.weight.data().clone();
+    let error = load_weights(&transposed.named_parameters()[..1], &path).unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+    assert_eq!(
+        error.to_string(),
+        "error: the weights named \"weight\" have shape [2, 3], but the variable has shape [3, 2]."
+    );
+    assert_eq!(*transposed.weight.data(), weight);
+}
@@ -0,0 +1,438 @@
+#[cfg(feature = "serialize")]
+use super::Input;
+use super::{Data, Gradient, Param, Summary, Var, VarDiff};
+use ndarray::{ArrayD, Dimension, Ix0, Ix1, Ix2, Ix3, Ix4, Ix5, Ix6, IxDyn};
+#[cfg(feature = "serialize")]
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, Serializer},
+};
+use std::fmt::{Debug, Display};
+
+/// Applies `$body` to the variable wrapped by any of the variants of `$any`.
+macro_rules! on_any {
+    ($any:ty, $value:expr, $variable:ident => $body:expr) => {{
+        type Any = $any;
+        match $value {
+            Any::D0($variable) => $body,
+            Any::D1($variable) => $body,
+            Any::D2($variable) => $body,
+            Any::D3($variable) => $body,
+            Any::D4($variable) => $body,
+            Any::D5($variable) => $body,
+            Any::D6($variable) => $body,
+            Any::Dyn($variable) => $body,
+        }
+    }};
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Rank ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+/// Dimensionalities that can be erased by [`AnyVar`] and [`AnyVarDiff`].
+///
+/// This trait is implemented for all the fixed dimensionalities from [`Ix0`] to [`Ix6`] and for
+/// [`IxDyn`].
+pub trait Rank: Dimension + 'static {
+    /// Wraps `var` into the matching variant of [`AnyVar`].
+    fn erase_var(var: Var<dyn Data<Dim = Self>>) -> AnyVar;
+
+    /// Unwraps `any` if it holds a variable of dimensionality `Self`, otherwise gives it back
+    /// boxed.
+    fn restore_var(any: AnyVar) -> Result<Var<dyn Data<Dim = Self>>, Box<AnyVar>>;
+
+    /// Wraps `var` into the matching variant of [`AnyVarDiff`].
+    fn erase_var_diff(var: VarDiff<dyn Data<Dim = Self>, dyn Gradient<Dim = Self>>) -> AnyVarDiff;
+
+    /// Unwraps `any` if it holds a differentiable variable of dimensionality `Self`, otherwise
+    /// gives it back boxed.
+    #[allow(clippy::type_complexity)]
+    fn restore_var_diff(
+        any: AnyVarDiff,
+    ) -> Result<VarDiff<dyn Data<Dim = Self>, dyn Gradient<Dim = Self>>, Box<AnyVarDiff>>;
+}
+
+macro_rules! impl_rank {
+    ($dim:ty, $variant:ident) => {
+        impl Rank for $dim {
+            fn erase_var(var: Var<dyn Data<Dim = Self>>) -> AnyVar {
+                AnyVar::$variant(var)
+            }
+
+            fn restore_var(any: AnyVar) -> Result<Var<dyn Data<Dim = Self>>, Box<AnyVar>> {
+                match any {
+                    AnyVar::$variant(var) => Ok(var),
+                    other => Err(Box::new(other)),
+                }
+            }
+
+            fn erase_var_diff(
+                var: VarDiff<dyn Data<Dim = Self>, dyn Gradient<Dim = Self>>,
+            ) -> AnyVarDiff {
+                AnyVarDiff::$variant(var)
+            }
+
+            fn restore_var_diff(
+                any: AnyVarDiff,
+            ) -> Result<VarDiff<dyn Data<Dim = Self>, dyn Gradient<Dim = Self>>, Box<AnyVarDiff>>
+            {
+                match any {
+                    AnyVarDiff::$variant(var) => Ok(var),
+                    other => Err(Box::new(other)),
+                }
+            }
+        }
+    };
+}
+
+impl_rank!(Ix0, D0);
+impl_rank!(Ix1, D1);
+impl_rank!(Ix2, D2);
+impl_rank!(Ix3, D3);
+impl_rank!(Ix4, D4);
+impl_rank!(Ix5, D5);
+impl_rank!(Ix6, D6);
+impl_rank!(IxDyn, Dyn);
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ AnyVar ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+/// A variable whose dimensionality has been erased.
+///
+/// Variables of different dimensionalities are unrelated types, an `AnyVar` can be used whenever
+/// they must be handled uniformly, for instance when they have to be stored in the same
+/// collection. The typed variable can be recovered with [`.downcast()`](AnyVar::downcast()).
+///
+/// # Examples
+///
+/// ```
+/// use neuronika::AnyVar;
+///
+/// let vars: Vec<AnyVar> = vec![
+///     neuronika::ones(3).into(),
+///     neuronika::ones((2, 2)).into(),
+/// ];
+/// assert_eq!(vars[1].shape(), vec![2, 2]);
+///
+/// let matrix = vars[1].clone().downcast::<ndarray::Ix2>().unwrap();
+/// assert_eq!(*matrix.data(), ndarray::array![[1., 1.], [1., 1.]]);
+/// ```
+#[derive(Clone)]
+pub enum AnyVar {
+    D0(Var<dyn Data<Dim = Ix0>>),
+    D1(Var<dyn Data<Dim = Ix1>>),
+    D2(Var<dyn Data<Dim = Ix2>>),
+    D3(Var<dyn Data<Dim = Ix3>>),
+    D4(Var<dyn Data<Dim = Ix4>>),
+    D5(Var<dyn Data<Dim = Ix5>>),
+    D6(Var<dyn Data<Dim = Ix6>>),
+    Dyn(Var<dyn Data<Dim = IxDyn>>),
+}
+
+impl AnyVar {
+    /// Propagates the computations forwards and populates all the variables from the leaves of the
+    /// graph to `self`.
+    pub fn forward(&self) {
+        on_any!(AnyVar, self, var => var.forward())
+    }
+
+    /// Returns the shape of the data inside `self`.
+    pub fn shape(&self) -> Vec<usize> {
+        on_any!(AnyVar, self, var => var.data().shape().to_vec())
+    }
+
+    /// Returns the number of dimensions of `self`.
+    pub fn ndim(&self) -> usize {
+        on_any!(AnyVar, self, var => var.data().ndim())
+    }
+
+    /// Returns the only element of `self`, or `None` if it doesn't hold exactly one element.
+    pub fn item(&self) -> Option<f32> {
+        on_any!(AnyVar, self, var => {
+            let data = var.data();
+            if data.len() == 1 {
+                data.iter().next().copied()
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the sum of all the elements inside `self`.
+    ///
+    /// Differently from [`Var::sum()`] this doesn't add a node to the computational graph.
+    pub fn sum_all(&self) -> f32 {
+        on_any!(AnyVar, self, var => var.data().sum())
+    }
+
+    /// Returns a dynamically dimensioned copy of the data inside `self`.
+    pub fn to_array(&self) -> ArrayD<f32> {
+        on_any!(AnyVar, self, var => var.data().view().into_dyn().to_owned())
+    }
+
+    /// Attempts to recover the variable of dimensionality `D` wrapped by `self`.
+    ///
+    /// # Errors
+    ///
+    /// If `self` doesn't hold a variable of dimensionality `D` it is returned unchanged and boxed.
+    pub fn downcast<D: Rank>(self) -> Result<Var<dyn Data<Dim = D>>, Box<Self>> {
+        D::restore_var(self)
+    }
+}
+
+impl<T> From<Var<T>> for AnyVar
+where
+    T: Data + 'static,
+    T::Dim: Rank,
+{
+    fn from(var: Var<T>) -> Self {
+        <T::Dim as Rank>::erase_var(var.into_dyn())
+    }
+}
+
+impl Debug for AnyVar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        on_any!(AnyVar, self, var => f
+            .debug_struct("AnyVar")
+            .field("data", &Summary(&var.data()))
+            .finish())
+    }
+}
+
+impl Display for AnyVar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        on_any!(AnyVar, self, var => write!(f, "{}", Summary(&var.data())))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ AnyVarDiff ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+/// A differentiable variable whose dimensionality has been erased.
+///
+/// This is the differentiable counterpart of [`AnyVar`].
+///
+/// # Examples
+///
+/// ```
+/// use neuronika::AnyVarDiff;
+///
+/// let outputs: Vec<AnyVarDiff> = vec![
+///     neuronika::ones(3).requires_grad().sum().into(),
+///     (neuronika::ones((2, 2)).requires_grad() * 2.).into(),
+/// ];
+///
+/// for output in &outputs {
+///     output.forward();
+///     output.backward(1.);
+/// }
+/// assert_eq!(outputs[1].sum_all(), 8.);
+/// ```
+#[derive(Clone)]
+pub enum AnyVarDiff {
+    D0(VarDiff<dyn Data<Dim = Ix0>, dyn Gradient<Dim = Ix0>>),
+    D1(VarDiff<dyn Data<Dim = Ix1>, dyn Gradient<Dim = Ix1>>),
+    D2(VarDiff<dyn Data<Dim = Ix2>, dyn Gradient<Dim = Ix2>>),
+    D3(VarDiff<dyn Data<Dim = Ix3>, dyn Gradient<Dim = Ix3>>),
+    D4(VarDiff<dyn Data<Dim = Ix4>, dyn Gradient<Dim = Ix4>>),
+    D5(VarDiff<dyn Data<Dim = Ix5>, dyn Gradient<Dim = Ix5>>),
+    D6(VarDiff<dyn Data<Dim = Ix6>, dyn Gradient<Dim = Ix6>>),
+    Dyn(VarDiff<dyn Data<Dim = IxDyn>, dyn Gradient<Dim = IxDyn>>),
+}
+
+impl AnyVarDiff {
+    /// Propagates the computations forwards and populates all the variables and differentiable
+    /// variables from the leaves of the graph to `self`.
+    pub fn forward(&self) {
+        on_any!(AnyVarDiff, self, var => var.forward())
+    }
+
+    /// Back-propagates through the computational graph seeding the gradient of `self` with
+    /// `seed`. See [`VarDiff::backward()`].
+    pub fn backward(&self, seed: f32) {
+        on_any!(AnyVarDiff, self, var => var.backward(seed))
+    }
+
+    /// Disables gradient computation and de-allocates the gradient for `self` and all of its
+    /// ancestors.
+    pub fn no_grad(&self) {
+        on_any!(AnyVarDiff, self, var => var.no_grad())
+    }
+
+    /// Re-enables gradient computation and re-allocates the gradient for `self` and all of its
+    /// ancestors.
+    pub fn with_grad(&self) {
+        on_any!(AnyVarDiff, self, var => var.with_grad())
+    }
+
+    /// Returns a vector of [`Param`] referencing all the differentiable leaves that are ancestors
+    /// of `self`. See [`VarDiff::parameters()`].
+    pub fn parameters(&self) -> Vec<Param<'_>> {
+        on_any!(AnyVarDiff, self, var => var.parameters())
+    }
+
+    /// Returns the shape of the data inside `self`.
+    pub fn shape(&self) -> Vec<usize> {
+        on_any!(AnyVarDiff, self, var => var.data().shape().to_vec())
+    }
+
+    /// Returns the number of dimensions of `self`.
+    pub fn ndim(&self) -> usize {
+        on_any!(AnyVarDiff, self, var => var.data().ndim())
+    }
+
+    /// Returns the only element of `self`, or `None` if it doesn't hold exactly one element.
+    pub fn item(&self) -> Option<f32> {
+        on_any!(AnyVarDiff, self, var => {
+            let data = var.data();
+            if data.len() == 1 {
+                data.iter().next().copied()
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the sum of all the elements inside `self`.
+    ///
+    /// Differently from [`VarDiff::sum()`] this doesn't add a node to the computational graph.
+    pub fn sum_all(&self) -> f32 {
+        on_any!(AnyVarDiff, self, var => var.data().sum())
+    }
+
+    /// Returns a dynamically dimensioned copy of the data inside `self`.
+    pub fn to_array(&self) -> ArrayD<f32> {
+        on_any!(AnyVarDiff, self, var => var.data().view().into_dyn().to_owned())
+    }
+
+    /// Returns a dynamically dimensioned copy of the gradient of `self`.
+    pub fn grad_to_array(&self) -> ArrayD<f32> {
+        on_any!(AnyVarDiff, self, var => var.grad().view().into_dyn().to_owned())
+    }
+
+    /// Overwrites the data inside `self` with `values`, taken in logical order.
+    ///
+    /// The caller must ensure that `values` holds exactly as many elements as `self`.
+    #[cfg(feature = "serialize")]
+    pub(crate) fn copy_from_slice(&self, values: &[f32]) {
+        on_any!(AnyVarDiff, self, var => {
+            var.data_mut()
+                .iter_mut()
+                .zip(values)
+                .for_each(|(el, value)| *el = *value)
+        })
+    }
+
+    /// Attempts to recover the differentiable variable of dimensionality `D` wrapped by `self`.
+    ///
+    /// # Errors
+    ///
+    /// If `self` doesn't hold a differentiable variable of dimensionality `D` it is returned
+    /// unchanged and boxed.
+    #[allow(clippy::type_complexity)]
+    pub fn downcast<D: Rank>(
+        self,
+    ) -> Result<VarDiff<dyn Data<Dim = D>, dyn Gradient<Dim = D>>, Box<Self>> {
+        D::restore_var_diff(self)
+    }
+}
+
+impl<T, U> From<VarDiff<T, U>> for AnyVarDiff
+where
+    T: Data + 'static,
+    U: Gradient<Dim = T::Dim> + 'static,
+    T::Dim: Rank,
+{
+    fn from(var: VarDiff<T, U>) -> Self {
+        <T::Dim as Rank>::erase_var_diff(var.into_dyn())
+    }
+}
+
+impl Debug for AnyVarDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        on_any!(AnyVarDiff, self, var => f
+            .debug_struct("AnyVarDiff")
+            .field("data", &Summary(&var.data()))
+            .field("grad", &Summary(&var.grad()))
+            .finish())
+    }
+}
+
+impl Display for AnyVarDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        on_any!(AnyVarDiff, self, var => write!(f, "{}", Summary(&var.data())))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Serialize ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(feature = "serialize")]
+impl Serialize for AnyVar {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        on_any!(AnyVar, self, var => var.data().view().into_dyn().serialize(serializer))
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl Serialize for AnyVarDiff {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        on_any!(AnyVarDiff, self, var => var.data().view().into_dyn().serialize(serializer))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Deserialize ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+/// Selects the fixed dimensionality matching the number of dimensions of `data`, falling back to
+/// the dynamic one, and applies `$leaf` to it.
+#[cfg(feature = "serialize")]
+macro_rules! with_rank {
+    ($data:expr, $leaf:ident) => {{
+        let data: ArrayD<f32> = $data;
+        match data.ndim() {
+            0 => $leaf::<Ix0>(data),
+            1 => $leaf::<Ix1>(data),
+            2 => $leaf::<Ix2>(data),
+            3 => $leaf::<Ix3>(data),
+            4 => $leaf::<Ix4>(data),
+            5 => $leaf::<Ix5>(data),
+            6 => $leaf::<Ix6>(data),
+            _ => $leaf::<IxDyn>(data),
+        }
+    }};
+}
+
+#[cfg(feature = "serialize")]
+impl<'d> Deserialize<'d> for AnyVar {
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: Deserializer<'d>,
+    {
+        fn leaf<D: Rank>(data: ArrayD<f32>) -> AnyVar {
+            Input::new(data.into_dimensionality::<D>().unwrap()).into()
+        }
+
+        let data = ArrayD::<f32>::deserialize(deserializer)?;
+        Ok(with_rank!(data, leaf))
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'d> Deserialize<'d> for AnyVarDiff {
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: Deserializer<'d>,
+    {
+        fn leaf<D: Rank>(data: ArrayD<f32>) -> AnyVarDiff {
+            Input::new(data.into_dimensionality::<D>().unwrap())
+                .requires_grad()
+                .into()
+        }
+
+        let data = ArrayD::<f32>::deserialize(deserializer)?;
+        Ok(with_rank!(data, leaf))
+    }
+}
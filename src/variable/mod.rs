@@ -1,40 +1,45 @@
+mod any;
+mod no_grad;
 mod node;
+mod print;
 mod var;
 mod vardiff;
 
-use ndarray::{ArrayViewMutD, Ix, RawArrayViewMut};
+pub use any::{AnyVar, AnyVarDiff, Rank};
+use ndarray::{Array, ArrayViewMutD, Dimension, Ix, Ix2, IxDyn, RawArrayViewMut};
+pub use no_grad::{is_grad_enabled, no_grad, NoGradGuard};
+pub use print::{
+    print_options, set_print_options, with_print_options, PrintOptions, ShapedDisplay,
+};
 use std::{
     cell::{Ref, RefCell},
-    collections::{BTreeMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashSet},
     hash::{Hash, Hasher},
     rc::Rc,
+    sync::atomic::{AtomicUsize, Ordering},
 };
 pub use var::Var;
 pub use vardiff::VarDiff;
 
 pub(crate) use node::*;
 pub use node::{
-    Backward, Cache, Constant, Convolve, ConvolveWithGroups, Data, Eval, Forward, Gradient, Input,
-    InputBackward, MaxPooling, Overwrite, PaddingMode, Reflective, Replicative, Zero,
+    AdaptiveAvgPooling, AdaptiveMaxPooling, AvgPooling, Backward, Cache, Constant, Convolve,
+    ConvolveTransposed, ConvolveWithGroups, Data, Eval, Forward, Gradient, Input, InputBackward,
+    MaxPoolIndices, MaxPooling, Overwrite, PadMode, PaddingMode, Reflective, Replicative, Zero,
 };
+pub(crate) use print::Summary;
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Global Var Identifier ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 /// Keeps track of each operations. It is also used to provide an identifier to computational nodes.
-pub(crate) struct OperationsCounter {
-    count: usize,
-}
+static OPERATIONS_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
-impl OperationsCounter {
-    pub fn next(&mut self) -> usize {
-        self.count += 1;
-        self.count
-    }
+/// Returns the next operation identifier.
+pub(crate) fn next_operation_id() -> usize {
+    OPERATIONS_COUNTER.fetch_add(1, Ordering::Relaxed) + 1
 }
 
-pub(crate) static mut OPERATIONS_COUNTER: OperationsCounter = OperationsCounter { count: 0 };
-
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Histories ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -46,6 +51,8 @@ pub struct VarHistory {
     path: BTreeMap<usize, Rc<dyn Forward>>,
     buffer: RefCell<Vec<Rc<dyn Forward>>>,
     changeables: HashSet<Changeable>,
+    graph: BTreeMap<usize, Rc<GraphNode>>,
+    heads: BTreeSet<usize>,
 }
 
 impl VarHistory {
@@ -55,6 +62,8 @@ impl VarHistory {
             path: BTreeMap::new(),
             buffer: RefCell::new(Vec::new()),
             changeables: HashSet::new(),
+            graph: BTreeMap::new(),
+            heads: BTreeSet::new(),
         }
     }
 
@@ -65,6 +74,47 @@ impl VarHistory {
     /// `other` - other VarHistory.
     pub(crate) fn merge(&mut self, mut other: VarHistory) {
         self.path.append(&mut other.path);
+        self.graph.append(&mut other.graph);
+        self.heads.append(&mut other.heads);
+    }
+
+    /// Appends a new node to the graph of `self`. The new node has id `id` and its operands are
+    /// the variables whose histories were merged into `self` since the last append.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - id of the new node.
+    /// * `name` - type name of the new node.
+    /// * `shape` - shape of the new node's data.
+    /// * `node` - node to append.
+    pub(crate) fn append_node(
+        &mut self,
+        id: usize,
+        name: &'static str,
+        shape: Rc<[usize]>,
+        node: Rc<dyn Cache>,
+    ) {
+        let operands = std::mem::take(&mut self.heads).into_iter().collect();
+        self.graph.insert(
+            id,
+            Rc::new(GraphNode {
+                name,
+                shape,
+                node,
+                operands,
+            }),
+        );
+        self.heads.insert(id);
+    }
+
+    /// Returns the nodes of the graph leading to the variable to whom `self` belongs.
+    pub(crate) fn graph(&self) -> &BTreeMap<usize, Rc<GraphNode>> {
+        &self.graph
+    }
+
+    /// Returns the ids of the nodes of the graph that are not operands of any other node.
+    pub(crate) fn heads(&self) -> &BTreeSet<usize> {
+        &self.heads
     }
 
     /// Appends a new forward computational node to `self`. The new node has id `id`.
@@ -111,6 +161,18 @@ impl VarHistory {
     }
 }
 
+/// A node of the computational graph, as recorded for inspection purposes.
+pub(crate) struct GraphNode {
+    /// Type name of the node, e.g. `neuronika::variable::node::Input<...>`.
+    pub(crate) name: &'static str,
+    /// Shape of the node's data.
+    pub(crate) shape: Rc<[usize]>,
+    /// The node itself, used to query whether it was computed.
+    pub(crate) node: Rc<dyn Cache>,
+    /// Ids of the node's operands.
+    pub(crate) operands: Vec<usize>,
+}
+
 #[derive(Clone)]
 /// The computational backward-history of a variable. It keeps track of the computation up to the
 /// variable to whom the struct belongs.
@@ -335,6 +397,166 @@ pub trait VecVecMul<Rhs> {
     fn vv(self, other: Rhs) -> Self::Output;
 }
 
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Outer Product ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Outer product between vectors.
+pub trait OuterProduct<Rhs> {
+    /// The type of the outer product's result. See the [*differentiability arithmetic*] for
+    /// more details.
+    ///
+    /// [*differentiability arithmetic*]: index.html#differentiability-arithmetic
+    type Output;
+
+    /// Computes the outer product between `self` and `other`.
+    fn outer(self, other: Rhs) -> Self::Output;
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Batched Matrix Multiplication ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Batched matrix-matrix multiplication.
+pub trait BatchedMatMatMul<Rhs> {
+    /// The type of the batched matrix-matrix multiplication's result. See the
+    /// [*differentiability arithmetic*] for more details.
+    ///
+    /// [*differentiability arithmetic*]: index.html#differentiability-arithmetic
+    type Output;
+
+    /// Computes the batched matrix-matrix multiplication between `self` and `other`.
+    fn bmm(self, other: Rhs) -> Self::Output;
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Kronecker Product ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Kronecker product between matrices.
+pub trait KroneckerProduct<Rhs> {
+    /// The type of the Kronecker product's result. See the [*differentiability arithmetic*] for
+    /// more details.
+    ///
+    /// [*differentiability arithmetic*]: index.html#differentiability-arithmetic
+    type Output;
+
+    /// Computes the Kronecker product between `self` and `other`.
+    fn kron(self, other: Rhs) -> Self::Output;
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tensordot ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Tensor contraction over arbitrary axes.
+///
+/// The axes `lhs_axes` of the left operand are paired with the axes `rhs_axes` of the right one
+/// and summed over. The result has the remaining axes of the left operand followed by the
+/// remaining axes of the right one, in their original order.
+///
+/// The contraction is computed by permuting and reshaping the operands into matrices, multiplying
+/// them and reshaping the product back, every step being a node of the computational graph.
+pub trait TensorDot<Rhs> {
+    /// The type of the contraction's result. See the [*differentiability arithmetic*] for
+    /// more details.
+    ///
+    /// [*differentiability arithmetic*]: index.html#differentiability-arithmetic
+    type Output;
+
+    /// Contracts `self` and `other` over the axes `lhs_axes` of `self` and `rhs_axes` of `other`.
+    ///
+    /// # Panics
+    ///
+    /// If `lhs_axes` and `rhs_axes` have different lengths, if an axis is out of bounds or
+    /// repeated, or if two paired axes have different lengths.
+    fn tensordot(self, other: Rhs, lhs_axes: &[usize], rhs_axes: &[usize]) -> Self::Output;
+}
+
+/// Layout of a contraction computed as a matrix multiplication.
+///
+/// The left operand is permuted so that its free axes come first and reshaped to `lhs_shape`, the
+/// right one is permuted so that its free axes come last and reshaped to `rhs_shape`. Their
+/// product is finally reshaped to `shape`.
+pub(crate) struct Contraction {
+    lhs_permutation: Vec<usize>,
+    rhs_permutation: Vec<usize>,
+    pub(crate) lhs_shape: Ix2,
+    pub(crate) rhs_shape: Ix2,
+    pub(crate) shape: IxDyn,
+}
+
+impl Contraction {
+    pub(crate) fn new(
+        lhs_shape: &[usize],
+        rhs_shape: &[usize],
+        lhs_axes: &[usize],
+        rhs_axes: &[usize],
+    ) -> Self {
+        assert_eq!(
+            lhs_axes.len(),
+            rhs_axes.len(),
+            "error: tensordot axes must have the same length, got {} and {}.",
+            lhs_axes.len(),
+            rhs_axes.len()
+        );
+        for (axes, shape) in [(lhs_axes, lhs_shape), (rhs_axes, rhs_shape)] {
+            for (i, axis) in axes.iter().enumerate() {
+                assert!(
+                    *axis < shape.len(),
+                    "error: axis {} is out of bounds for a variable with {} dimensions.",
+                    axis,
+                    shape.len()
+                );
+                assert!(
+                    !axes[..i].contains(axis),
+                    "error: axis {} is repeated.",
+                    axis
+                );
+            }
+        }
+        for (lhs_axis, rhs_axis) in lhs_axes.iter().zip(rhs_axes) {
+            let (lhs_length, rhs_length) = (lhs_shape[*lhs_axis], rhs_shape[*rhs_axis]);
+            assert_eq!(
+                lhs_length, rhs_length,
+                "error: cannot contract axis {} of length {} with axis {} of length {}.",
+                lhs_axis, lhs_length, rhs_axis, rhs_length
+            );
+        }
+
+        let lhs_free: Vec<usize> = (0..lhs_shape.len())
+            .filter(|axis| !lhs_axes.contains(axis))
+            .collect();
+        let rhs_free: Vec<usize> = (0..rhs_shape.len())
+            .filter(|axis| !rhs_axes.contains(axis))
+            .collect();
+        let contracted = lhs_axes.iter().map(|axis| lhs_shape[*axis]).product();
+        let rows = lhs_free.iter().map(|axis| lhs_shape[*axis]).product();
+        let columns = rhs_free.iter().map(|axis| rhs_shape[*axis]).product();
+        let shape: Vec<usize> = lhs_free
+            .iter()
+            .map(|axis| lhs_shape[*axis])
+            .chain(rhs_free.iter().map(|axis| rhs_shape[*axis]))
+            .collect();
+
+        Self {
+            lhs_permutation: lhs_free.iter().chain(lhs_axes).copied().collect(),
+            rhs_permutation: rhs_axes.iter().chain(&rhs_free).copied().collect(),
+            lhs_shape: Ix2(rows, contracted),
+            rhs_shape: Ix2(contracted, columns),
+            shape: IxDyn(&shape),
+        }
+    }
+
+    /// Returns the permutation bringing the free axes of the left operand first.
+    pub(crate) fn lhs_permutation<D: Dimension>(&self) -> D {
+        into_permutation(&self.lhs_permutation)
+    }
+
+    /// Returns the permutation bringing the free axes of the right operand last.
+    pub(crate) fn rhs_permutation<D: Dimension>(&self) -> D {
+        into_permutation(&self.rhs_permutation)
+    }
+}
+
+fn into_permutation<D: Dimension>(axes: &[usize]) -> D {
+    let mut permutation = D::zeros(axes.len());
+    permutation.slice_mut().copy_from_slice(axes);
+    permutation
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Cat and Stack traits ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -363,6 +585,43 @@ pub trait Stack<Rhs> {
     fn stack(self, other: Rhs, axis: usize) -> Self::Output;
 }
 
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ ScatterAdd trait ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Scatter addition.
+pub trait ScatterAdd<Rhs> {
+    /// The type of the scatter addition's result. See the [*differentiability arithmetic*] for
+    /// more details.
+    ///
+    /// [*differentiability arithmetic*]: index.html#differentiability-arithmetic
+    type Output;
+
+    /// The dimensionality of the index.
+    type Dim: Dimension;
+
+    /// Adds the elements of `src` to the ones of this variable along the given axis, at the
+    /// positions specified by `index`.
+    fn scatter_add(self, axis: usize, index: Array<usize, Self::Dim>, src: Rhs) -> Self::Output;
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Where trait ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Elementwise selection.
+pub trait Where<Cond, Rhs> {
+    /// The type of the selection's result. See the [*differentiability arithmetic*] for more
+    /// details.
+    ///
+    /// [*differentiability arithmetic*]: index.html#differentiability-arithmetic
+    type Output;
+
+    /// Takes the elements of this variable where `condition` is non-zero and the ones of `other`
+    /// elsewhere.
+    fn where_(self, condition: Cond, other: Rhs) -> Self::Output;
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -0,0 +1,59 @@
+use std::cell::Cell;
+
+thread_local! {
+    static GRAD_ENABLED: Cell<bool> = const { Cell::new(true) };
+}
+
+/// Guard disabling the gradient tracking of the current thread for as long as it lives.
+///
+/// It's returned by [`no_grad()`], the previous state is restored when the guard is dropped, so
+/// that guards can be nested.
+#[must_use = "the gradient tracking is enabled again as soon as the guard is dropped"]
+pub struct NoGradGuard {
+    previous: bool,
+}
+
+impl Drop for NoGradGuard {
+    fn drop(&mut self) {
+        GRAD_ENABLED.with(|enabled| enabled.set(self.previous));
+    }
+}
+
+/// Disables the gradient tracking of the current thread until the returned guard is dropped.
+///
+/// While the tracking is disabled, the leaves promoted by
+/// [`.requires_grad()`](crate::Var::requires_grad()) are created frozen and are not registered as
+/// parameters, thus their gradients are never populated. This is useful for inference, where no
+/// gradient is needed.
+///
+/// # Examples
+///
+/// ```
+/// let w = {
+///     let _guard = neuronika::no_grad();
+///     assert!(!neuronika::is_grad_enabled());
+///
+///     let w = neuronika::ones(3).requires_grad();
+///     let y = (w.clone() * 2.).sum();
+///     y.forward();
+///     y.backward(1.);
+///
+///     assert!(y.parameters().is_empty());
+///     w
+/// };
+///
+/// assert!(neuronika::is_grad_enabled());
+/// assert_eq!(*w.grad(), ndarray::arr1(&[0., 0., 0.]));
+/// ```
+pub fn no_grad() -> NoGradGuard {
+    NoGradGuard {
+        previous: GRAD_ENABLED.with(|enabled| enabled.replace(false)),
+    }
+}
+
+/// Returns `true` if the gradient tracking of the current thread is enabled.
+///
+/// See also [`no_grad()`].
+pub fn is_grad_enabled() -> bool {
+    GRAD_ENABLED.with(Cell::get)
+}
@@ -2,7 +2,7 @@
 use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
 use super::{
     cobroadcasted_zeros, expect_tensor, expect_tensor_mut, push_gradient, reduce, Backward,
-    BroadTensor, Broadcasted, Cache, Data, Forward, Gradient, Overwrite, Tensor,
+    BroadTensor, Broadcasted, Cache, Data, Forward, Gradient, Overwrite, Summary, Tensor,
 };
 use ndarray::{DimMax, Dimension, Zip};
 use std::{
@@ -102,7 +102,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Addition")
-            .field("data", &self.data.borrow())
+            .field("data", &Summary(&self.data.borrow()))
             .field("computed", &self.computed.get())
             .finish()
     }
@@ -115,7 +115,7 @@ where
     Lhs::Dim: Dimension + DimMax<Rhs::Dim>,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", &self.data.borrow())
+        write!(f, "{}", Summary(&self.data.borrow()))
     }
 }
 
@@ -218,7 +218,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("AdditionBackward")
-            .field("gradient", &self.gradient.borrow())
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
             .field("overwrite", &self.overwrite.get())
             .finish()
     }
@@ -232,7 +232,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
@@ -332,7 +332,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         f.debug_struct("AdditionBackwardUnary")
-            .field("gradient", &self.gradient.borrow())
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
             .field("overwrite", &self.overwrite.get())
             .finish()
     }
@@ -346,7 +346,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
@@ -2,7 +2,7 @@
 use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
 use super::{
     cobroadcasted_zeros, expect_tensor, expect_tensor_mut, push_gradient, reduce, Backward,
-    BroadTensor, Broadcasted, Cache, Data, Forward, Gradient, Overwrite, Tensor,
+    BroadTensor, Broadcasted, Cache, Data, Forward, Gradient, Overwrite, Summary, Tensor,
 };
 use ndarray::{DimMax, Dimension, Zip};
 use std::{
@@ -103,7 +103,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Multiplication")
-            .field("data", &self.data.borrow())
+            .field("data", &Summary(&self.data.borrow()))
             .field("computed", &self.computed.get())
             .finish()
     }
@@ -116,7 +116,7 @@ where
     Lhs::Dim: Dimension + DimMax<Rhs::Dim>,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", &self.data.borrow())
+        write!(f, "{}", Summary(&self.data.borrow()))
     }
 }
 
@@ -263,7 +263,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MultiplicationBackward")
-            .field("gradient", &self.gradient.borrow())
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
             .field("overwrite", &self.overwrite.get())
             .finish()
     }
@@ -281,7 +281,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
@@ -392,7 +392,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MultiplicationBackwardUnary")
-            .field("gradient", &self.gradient.borrow())
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
             .field("overwrite", &self.overwrite.get())
             .finish()
     }
@@ -406,7 +406,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
@@ -2,7 +2,7 @@
 use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
 use super::{
     cobroadcasted_zeros, expect_tensor, expect_tensor_mut, push_gradient, reduce, Backward,
-    BroadTensor, Broadcasted, Cache, Data, Forward, Gradient, Overwrite, Tensor,
+    BroadTensor, Broadcasted, Cache, Data, Forward, Gradient, Overwrite, Summary, Tensor,
 };
 use ndarray::{DimMax, Dimension, Zip};
 use std::{
@@ -103,7 +103,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         f.debug_struct("Subtraction")
-            .field("data", &self.data.borrow())
+            .field("data", &Summary(&self.data.borrow()))
             .field("computed", &self.computed.get())
             .finish()
     }
@@ -116,7 +116,7 @@ where
     Lhs::Dim: Dimension + DimMax<Rhs::Dim>,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", &self.data.borrow())
+        write!(f, "{}", Summary(&self.data.borrow()))
     }
 }
 
@@ -226,7 +226,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("SubtractionBackward")
-            .field("gradient", &self.gradient.borrow())
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
             .field("overwrite", &self.overwrite.get())
             .finish()
     }
@@ -240,7 +240,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
@@ -340,7 +340,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         f.debug_struct("SubtractionBackwardLeft")
-            .field("gradient", &self.gradient.borrow())
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
             .field("overwrite", &self.overwrite.get())
             .finish()
     }
@@ -354,7 +354,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
@@ -461,7 +461,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         f.debug_struct("SubtractionBackwardRight")
-            .field("gradient", &self.gradient.borrow())
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
             .field("overwrite", &self.overwrite.get())
             .finish()
     }
@@ -475,7 +475,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
@@ -2,7 +2,7 @@
 use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
 use super::{
     expect_tensor, expect_tensor_mut, push_gradient, Backward, Cache, Data, Forward, Gradient,
-    Overwrite, Tensor,
+    Overwrite, Summary, Tensor,
 };
 use ndarray::{concatenate, Axis, RemoveAxis, Zip};
 use std::{
@@ -123,7 +123,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         f.debug_struct("Concatenate")
-            .field("data", &self.data.borrow())
+            .field("data", &Summary(&self.data.borrow()))
             .field("axis", &self.axis)
             .field("computed", &self.computed.get())
             .finish()
@@ -137,7 +137,7 @@ where
     Lhs::Dim: RemoveAxis,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", &self.data.borrow())
+        write!(f, "{}", Summary(&self.data.borrow()))
     }
 }
 
@@ -249,7 +249,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         f.debug_struct("ConcatenateBackward")
-            .field("gradient", &self.gradient.borrow())
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
             .field("axis", &self.axis)
             .field("overwrite", &self.overwrite.get())
             .finish()
@@ -264,7 +264,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
@@ -369,7 +369,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         f.debug_struct("ConcatenateBackwardLeft")
-            .field("gradient", &self.gradient.borrow())
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
             .field("axis", &self.axis)
             .field("overwrite", &self.overwrite.get())
             .finish()
@@ -383,7 +383,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
@@ -486,7 +486,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         f.debug_struct("ConcatenateBackwardRight")
-            .field("gradient", &self.gradient.borrow())
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
             .field("axis", &self.axis)
             .field("overwrite", &self.overwrite.get())
             .finish()
@@ -500,7 +500,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
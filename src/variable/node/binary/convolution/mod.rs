@@ -2,7 +2,7 @@
 use super::{new_backward_input, new_input};
 use crate::variable::{
     expect_tensor, expect_tensor_mut, Backward, Cache, Data as NData, Forward, Gradient, Overwrite,
-    Tensor, Var, VarDiff,
+    Summary, Tensor, Var, VarDiff,
 };
 use ndarray::{Dimension, RemoveAxis};
 use std::{
@@ -25,6 +25,9 @@ use numeric::{
     convolution_with_groups_unary_backward, pad,
 };
 
+mod transposed;
+pub use transposed::ConvolveTransposed;
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Convolve Trait ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -471,7 +474,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Convolution")
-            .field("data", &self.data.borrow())
+            .field("data", &Summary(&self.data.borrow()))
             .field("stride", &self.stride)
             .field("dilation", &self.dilation)
             .field("padding", &self.padding)
@@ -488,7 +491,7 @@ where
     Pad: PaddingMode,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", &self.data.borrow())
+        write!(f, "{}", Summary(&self.data.borrow()))
     }
 }
 
@@ -648,7 +651,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("GroupedConvolution")
-            .field("data", &self.data.borrow())
+            .field("data", &Summary(&self.data.borrow()))
             .field("stride", &self.stride)
             .field("dilation", &self.dilation)
             .field("padding", &self.padding)
@@ -666,7 +669,7 @@ where
     Pad: PaddingMode,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", &self.data.borrow())
+        write!(f, "{}", Summary(&self.data.borrow()))
     }
 }
 
@@ -825,6 +828,7 @@ where
             &*gradient,
             &*kernel,
             padding,
+            padding_mode,
             stride,
             dilation,
             overwrite_input_grad,
@@ -878,7 +882,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ConvolutionBackward")
-            .field("gradient", &self.gradient.borrow())
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
             .field("stride", &self.stride)
             .field("dilation", &self.dilation)
             .field("padding", &self.padding)
@@ -899,7 +903,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
@@ -1064,7 +1068,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ConvolutionBackwardUnary")
-            .field("gradient", &self.gradient.borrow())
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
             .field("stride", &self.stride)
             .field("dilation", &self.dilation)
             .field("padding", &self.padding)
@@ -1082,7 +1086,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
@@ -1253,6 +1257,7 @@ where
                 &*input,
                 &*kernel,
                 padding,
+                padding_mode,
                 stride,
                 dilation,
                 *groups,
@@ -1268,6 +1273,7 @@ where
                 &padded_input,
                 &*kernel,
                 padding,
+                padding_mode,
                 stride,
                 dilation,
                 *groups,
@@ -1304,7 +1310,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("GroupedConvolutionBackward")
-            .field("gradient", &self.gradient.borrow())
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
             .field("stride", &self.stride)
             .field("dilation", &self.dilation)
             .field("padding", &self.padding)
@@ -1326,7 +1332,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
@@ -1501,7 +1507,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("GroupedConvolutionBackwardUnary")
-            .field("gradient", &self.gradient.borrow())
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
             .field("stride", &self.stride)
             .field("dilation", &self.dilation)
             .field("padding", &self.padding)
@@ -1523,7 +1529,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
@@ -807,21 +807,27 @@ pub(super) fn convolution<
 /// * `padding` - padding that must be taken into account while accumulating the input's
 /// gradient.
 ///
+/// * `padding_mode` - padding mode, the gradient of the padded elements that are copies of the
+/// input's ones is accumulated into them.
+///
 /// * `stride` - stride.
 ///
 /// * `dilation` - the dilation.
 ///
 /// * `overwrite_input_grad`  - specifies the kind of accumulation operation to be performed on
 /// the input's gradient.
+#[allow(clippy::too_many_arguments)]
 pub(super) fn convolution_backward_input<
     D: Dimension + RemoveAxis,
     S: DataMut<Elem = f32>,
     T: Data<Elem = f32>,
+    Pad: PaddingMode,
 >(
     input_grad: &mut ArrayBase<S, D>,
     grad: &ArrayBase<T, D>,
     kernel: &ArrayBase<T, D>,
     padding: &[usize],
+    padding_mode: &Pad,
     stride: &[usize],
     dilation: &[usize],
     overwrite_input_grad: bool,
@@ -832,10 +838,11 @@ pub(super) fn convolution_backward_input<
         grad.shape(),
     );
 
+    // The buffer must be laid out as the columns, one row for each of the output map's positions.
     let mut buffer_shape = Ix3::zeros(3);
     buffer_shape[0] = grad_shape[0];
-    buffer_shape[1] = flattened_kernel.shape()[1];
-    buffer_shape[2] = grad_shape.iter().skip(2).product();
+    buffer_shape[1] = grad_shape.iter().skip(2).product();
+    buffer_shape[2] = flattened_kernel.shape()[1];
     let mut buffer = Array::<f32, Ix3>::zeros(buffer_shape);
 
     Zip::from(grad.axis_iter(Axis(0)))
@@ -847,14 +854,17 @@ pub(super) fn convolution_backward_input<
                 .unwrap();
             general_mat_mul(
                 1.,
-                &flattened_kernel.t(),
-                &flattened_sample_in,
+                &flattened_sample_in.t(),
+                &flattened_kernel,
                 0.,
                 &mut buffer_sample,
             );
         });
 
     if padding.iter().all(|pad| *pad == 0) {
+        if overwrite_input_grad {
+            input_grad.fill(0.);
+        }
         assign_from_cols(input_grad, buffer, kernel_shape, stride, dilation);
     } else {
         let mut padded_buffer: Array<f32, D> =
@@ -863,7 +873,7 @@ pub(super) fn convolution_backward_input<
 
         // The actual input's incoming gradient is extracted from the buffer and assigned.
         let actual_gradient = unpad(&padded_buffer, padding);
-        let input_gradient_zip = Zip::from(input_grad).and(actual_gradient);
+        let input_gradient_zip = Zip::from(&mut *input_grad).and(actual_gradient);
         if overwrite_input_grad {
             input_gradient_zip
                 .par_for_each(|input_grad_el, incoming_grad_el| *input_grad_el = *incoming_grad_el);
@@ -872,6 +882,52 @@ pub(super) fn convolution_backward_input<
                 *input_grad_el += *incoming_grad_el
             });
         }
+        fold_padding(input_grad, &padded_buffer, padding, padding_mode);
+    }
+}
+
+/// Accumulates the gradient of the elements added by the padding into the elements of the input
+/// they are copies of, if any.
+///
+/// # Arguments
+///
+/// * `input_grad` - gradient of the input map.
+///
+/// * `padded_grad` - gradient of the padded input map.
+///
+/// * `padding` - padding applied to the input.
+///
+/// * `padding_mode` - padding mode.
+fn fold_padding<D: Dimension, S: DataMut<Elem = f32>, Pad: PaddingMode>(
+    input_grad: &mut ArrayBase<S, D>,
+    padded_grad: &Array<f32, D>,
+    padding: &[usize],
+    padding_mode: &Pad,
+) {
+    let mut input_grad = input_grad.view_mut().into_dyn();
+    let shape = input_grad.raw_dim();
+    for (mut index, padded_grad_el) in padded_grad.view().into_dyn().indexed_iter() {
+        let mut on_border = false;
+        let is_copy = index
+            .slice_mut()
+            .iter_mut()
+            .skip(2)
+            .zip(shape.slice().iter().skip(2))
+            .zip(padding)
+            .all(|((index_el, &len), &pad)| {
+                let position = *index_el as isize - pad as isize;
+                let source = if position >= 0 && (position as usize) < len {
+                    Some(position as usize)
+                } else {
+                    on_border = true;
+                    padding_mode.border_source(position, len)
+                };
+                source.map(|source| *index_el = source).is_some()
+            });
+
+        if on_border && is_copy {
+            input_grad[index] += *padded_grad_el;
+        }
     }
 }
 
@@ -995,6 +1051,8 @@ pub(super) fn convolution_with_groups<D: Dimension + RemoveAxis>(
 ///
 /// * `padding` -  padding that must be taken into account while accumulating the input's gradient.
 ///
+/// * `padding_mode` - padding mode.
+///
 /// * `stride` -  stride.
 ///
 /// * `dilation` -  dilation.
@@ -1007,13 +1065,14 @@ pub(super) fn convolution_with_groups<D: Dimension + RemoveAxis>(
 /// * `overwrite_kernel_grad` - specifies the kind of accumulation operation to be performed on
 /// the kernel gradient.
 #[allow(clippy::too_many_arguments)]
-pub(super) fn convolution_with_groups_backward<D: Dimension + RemoveAxis>(
+pub(super) fn convolution_with_groups_backward<D: Dimension + RemoveAxis, Pad: PaddingMode>(
     input_grad: &mut Array<f32, D>,
     kernel_grad: &mut Array<f32, D>,
     grad: &Array<f32, D>,
     input: &Array<f32, D>,
     kernel: &Array<f32, D>,
     padding: &[usize],
+    padding_mode: &Pad,
     stride: &[usize],
     dilation: &[usize],
     groups: usize,
@@ -1044,6 +1103,7 @@ pub(super) fn convolution_with_groups_backward<D: Dimension + RemoveAxis>(
                     &gradient,
                     &kernel,
                     padding,
+                    padding_mode,
                     stride,
                     dilation,
                     overwrite_input_grad,
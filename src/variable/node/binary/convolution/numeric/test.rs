@@ -420,6 +420,7 @@ mod convolution_numeric {
             &conv_out_grad,
             &kernel,
             padding,
+            &crate::variable::Zero,
             stride,
             dilation,
             true,
@@ -534,6 +535,7 @@ mod convolution_numeric {
             &conv_out_grad,
             &kernel,
             padding,
+            &crate::variable::Zero,
             stride,
             dilation,
             true,
@@ -658,6 +660,7 @@ mod convolution_numeric {
             &conv_out_grad,
             &kernel,
             padding,
+            &crate::variable::Zero,
             stride,
             dilation,
             true,
@@ -784,6 +787,7 @@ mod convolution_numeric {
             &conv_out_grad,
             &kernel,
             padding,
+            &crate::variable::Zero,
             stride,
             dilation,
             true,
@@ -890,6 +894,7 @@ mod convolution_numeric {
             &conv_out_grad,
             &kernel,
             padding,
+            &crate::variable::Zero,
             stride,
             dilation,
             true,
@@ -981,6 +986,7 @@ mod convolution_numeric {
             &conv_out_grad,
             &kernel,
             padding,
+            &crate::variable::Zero,
             stride,
             dilation,
             true,
@@ -1096,6 +1102,7 @@ mod convolution_numeric {
             &conv_out_grad,
             &kernel,
             padding,
+            &crate::variable::Zero,
             stride,
             dilation,
             true,
@@ -1201,6 +1208,7 @@ mod convolution_numeric {
             &conv_out_grad,
             &kernel,
             padding,
+            &crate::variable::Zero,
             stride,
             dilation,
             true,
@@ -1287,6 +1295,7 @@ mod convolution_numeric {
             &conv_out_grad,
             &kernel,
             padding,
+            &crate::variable::Zero,
             stride,
             dilation,
             true,
@@ -1435,6 +1444,7 @@ mod convolution_numeric {
             &conv_out_grad,
             &kernel,
             padding,
+            &crate::variable::Zero,
             stride,
             dilation,
             true,
@@ -1519,6 +1529,7 @@ mod convolution_numeric {
             &input,
             &kernel,
             padding,
+            &crate::variable::Zero,
             stride,
             dilation,
             groups,
@@ -1641,6 +1652,7 @@ mod convolution_numeric {
             &input,
             &kernel,
             padding,
+            &crate::variable::Zero,
             stride,
             dilation,
             groups,
@@ -1784,6 +1796,7 @@ mod convolution_numeric {
             &input,
             &kernel,
             padding,
+            &crate::variable::Zero,
             stride,
             dilation,
             groups,
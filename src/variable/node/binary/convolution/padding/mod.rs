@@ -15,6 +15,16 @@ pub trait PaddingMode: Send + Sync + Copy + Clone + Debug {
         input: &Array<f32, D>,
         padding: E,
     ) -> Array<f32, D>;
+
+    /// Returns the index of the element whose value is copied at `position` by the padding of an
+    /// axis of length `len`, where `position` lies outside of the axis and is relative to its
+    /// start. The gradient of the padded element flows back to such element.
+    ///
+    /// The default implementation returns `None`, as for padding modes filling the borders with
+    /// values that don't depend on the padded array.
+    fn border_source(&self, _position: isize, _len: usize) -> Option<usize> {
+        None
+    }
 }
 
 /// Zero padding.
@@ -266,6 +276,18 @@ impl PaddingMode for Reflective {
     ) -> Array<f32, D> {
         D::reflection_pad(input, padding.into_dimension().slice())
     }
+
+    /// Returns the index of the element reflected at `position`, the boundary excluded.
+    fn border_source(&self, position: isize, len: usize) -> Option<usize> {
+        let last = len as isize - 1;
+        let source = if position < 0 {
+            -position
+        } else {
+            2 * last - position
+        };
+
+        Some(source as usize)
+    }
 }
 
 impl PaddingMode for Replicative {
@@ -340,6 +362,11 @@ impl PaddingMode for Replicative {
     ) -> Array<f32, D> {
         D::replication_pad(input, padding.into_dimension().slice())
     }
+
+    /// Returns the index of the boundary element replicated at `position`.
+    fn border_source(&self, position: isize, len: usize) -> Option<usize> {
+        Some(position.clamp(0, len as isize - 1) as usize)
+    }
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -38,6 +38,42 @@ mod forward {
         assert!(!node.was_computed());
     }
 
+    #[test]
+    fn output_shape() {
+        let input = new_input((1, 1, 4, 4), (0..16).map(|el| el as f32).collect());
+        let kernel = new_input((1, 1, 3, 3), vec![1.; 9]);
+        let node = Convolution::new(
+            input.clone(),
+            kernel.clone(),
+            &[1, 1],
+            &[1, 1],
+            &[0, 0],
+            Zero,
+        );
+
+        node.forward();
+        assert_eq!(node.data().shape(), &[1, 1, 2, 2]);
+        assert_eq!(
+            *node.data(),
+            Tensor::from_shape_vec((1, 1, 2, 2), vec![45., 54., 81., 90.]).unwrap()
+        );
+
+        let node = Convolution::new(
+            input.clone(),
+            kernel.clone(),
+            &[1, 1],
+            &[1, 1],
+            &[1, 1],
+            Zero,
+        );
+        node.forward();
+        assert_eq!(node.data().shape(), &[1, 1, 4, 4]);
+
+        let node = Convolution::new(input, kernel, &[2, 2], &[1, 1], &[1, 1], Zero);
+        node.forward();
+        assert_eq!(node.data().shape(), &[1, 1, 2, 2]);
+    }
+
     #[test]
     fn debug() {
         let input = new_input((1, 1, 3, 3), vec![0.; 9]);
@@ -116,8 +152,8 @@ mod forward_grouped {
 
 mod backward {
     use super::{
-        conv_out_shape, new_backward_input, new_input, Backward, ConvolutionBackward, Gradient,
-        Overwrite, Tensor, Zero,
+        conv_out_shape, new_backward_input, new_input, Backward, Convolution, ConvolutionBackward,
+        Forward, Gradient, NData, Overwrite, Tensor, Zero,
     };
 
     #[test]
@@ -208,6 +244,68 @@ mod backward {
         assert!(!kernel_grad.can_overwrite());
     }
 
+    #[test]
+    fn gradient_check() {
+        let input_data = (0..16).map(|el| el as f32).collect::<Vec<f32>>();
+        let kernel_data = vec![1., -2., 3., 0.5];
+
+        let input_grad = new_backward_input((1, 1, 4, 4), vec![0.; 16]);
+        let kernel_grad = new_backward_input((1, 1, 2, 2), vec![0.; 4]);
+        let node = ConvolutionBackward::new(
+            input_grad.clone(),
+            kernel_grad.clone(),
+            new_input((1, 1, 4, 4), input_data.clone()),
+            new_input((1, 1, 2, 2), kernel_data.clone()),
+            &[1, 1],
+            &[1, 1],
+            &[1, 1],
+            Zero,
+        );
+        node.gradient_mut().fill(1.);
+        node.backward();
+
+        // The loss is the sum of the output, so it is linear both in the input and in the kernel
+        // and a unitary perturbation gives back the exact partial derivative.
+        let loss = |input: Vec<f32>, kernel: Vec<f32>| {
+            let conv = Convolution::new(
+                new_input((1, 1, 4, 4), input),
+                new_input((1, 1, 2, 2), kernel),
+                &[1, 1],
+                &[1, 1],
+                &[1, 1],
+                Zero,
+            );
+            conv.forward();
+            let sum = conv.data().sum();
+            sum
+        };
+        let base = loss(input_data.clone(), kernel_data.clone());
+
+        let numeric_kernel_grad = (0..4)
+            .map(|i| {
+                let mut kernel = kernel_data.clone();
+                kernel[i] += 1.;
+                loss(input_data.clone(), kernel) - base
+            })
+            .collect();
+        assert_eq!(
+            *kernel_grad.gradient(),
+            Tensor::from_shape_vec((1, 1, 2, 2), numeric_kernel_grad).unwrap()
+        );
+
+        let numeric_input_grad = (0..16)
+            .map(|i| {
+                let mut input = input_data.clone();
+                input[i] += 1.;
+                loss(input, kernel_data.clone()) - base
+            })
+            .collect();
+        assert_eq!(
+            *input_grad.gradient(),
+            Tensor::from_shape_vec((1, 1, 4, 4), numeric_input_grad).unwrap()
+        );
+    }
+
     #[test]
     fn debug() {
         let node = ConvolutionBackward::new(
@@ -0,0 +1,918 @@
+use super::{convolution, convolution_backward_input, convolution_backward_kernel, Zero};
+#[cfg(test)]
+use super::{new_backward_input, new_input};
+use crate::variable::{
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data as NData, Forward, Gradient, Overwrite,
+    Summary, Tensor, Var, VarDiff,
+};
+use ndarray::{ArrayBase, Data, DataMut, Dimension, RemoveAxis, Slice};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    ops::Range,
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Convolve Transposed Trait ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Transposed convolution.
+pub trait ConvolveTransposed<Inp, Ker> {
+    /// The type of the transposed convolution's result. See the [*differentiability arithmetic*]
+    /// for more details.
+    ///
+    /// [*differentiability arithmetic*]: index.html#differentiability-arithmetic
+    type Output;
+
+    /// Applies a *n*-dimensional transposed convolution with the given parameters. *n* can be
+    /// either 1, 2 or 3.
+    ///
+    /// The kernel must be of shape *(Cin, Cout, ...)*, the transposed convolution is the gradient
+    /// of the convolution with the same kernel, stride, dilation and padding with respect to its
+    /// input. `output_padding` adds rows at the end of each spatial axis of the output and is
+    /// used to pick among the output shapes that a strided convolution would map to the same
+    /// input shape.
+    fn convolve_transposed(
+        input: Inp,
+        kernel: Ker,
+        stride: &[usize],
+        dilation: &[usize],
+        padding: &[usize],
+        output_padding: &[usize],
+    ) -> Self::Output;
+}
+
+impl<F1: ?Sized, F2: ?Sized> ConvolveTransposed<Self, Var<F2>> for Var<F1>
+where
+    F1: NData + 'static,
+    F1::Dim: RemoveAxis,
+    F2: NData<Dim = F1::Dim> + 'static,
+{
+    type Output = Var<TransposedConvolution<F1, F2>>;
+
+    fn convolve_transposed(
+        mut input: Self,
+        kernel: Var<F2>,
+        stride: &[usize],
+        dilation: &[usize],
+        padding: &[usize],
+        output_padding: &[usize],
+    ) -> Self::Output {
+        input.past.merge(kernel.past);
+        Var::from(
+            TransposedConvolution::new(
+                input.node,
+                kernel.node,
+                stride,
+                dilation,
+                padding,
+                output_padding,
+            ),
+            input.past,
+        )
+    }
+}
+
+impl<F1: ?Sized, F2: ?Sized, B2: ?Sized> ConvolveTransposed<Self, VarDiff<F2, B2>> for Var<F1>
+where
+    F1: NData + 'static,
+    F1::Dim: RemoveAxis,
+    F2: NData<Dim = F1::Dim> + 'static,
+    B2: Gradient<Dim = F2::Dim>,
+{
+    type Output =
+        VarDiff<TransposedConvolution<F1, F2>, TransposedConvolutionBackwardUnary<F1, B2>>;
+
+    fn convolve_transposed(
+        input: Self,
+        kernel: VarDiff<F2, B2>,
+        stride: &[usize],
+        dilation: &[usize],
+        padding: &[usize],
+        output_padding: &[usize],
+    ) -> Self::Output {
+        let node = TransposedConvolutionBackwardUnary::new(
+            kernel.node,
+            input.node.clone(),
+            kernel.var.node.clone(),
+            stride,
+            dilation,
+            padding,
+            output_padding,
+        );
+        VarDiff::from(
+            node,
+            kernel.past,
+            Var::convolve_transposed(input, kernel.var, stride, dilation, padding, output_padding),
+        )
+    }
+}
+
+impl<F1: ?Sized, B1: ?Sized, F2: ?Sized, B2: ?Sized> ConvolveTransposed<Self, VarDiff<F2, B2>>
+    for VarDiff<F1, B1>
+where
+    F1: NData + 'static,
+    F1::Dim: RemoveAxis,
+    B1: Gradient<Dim = F1::Dim> + Overwrite,
+    F2: NData<Dim = F1::Dim> + 'static,
+    B2: Gradient<Dim = F2::Dim>,
+{
+    type Output =
+        VarDiff<TransposedConvolution<F1, F2>, TransposedConvolutionBackward<F1, B1, F2, B2>>;
+
+    fn convolve_transposed(
+        mut input: Self,
+        kernel: VarDiff<F2, B2>,
+        stride: &[usize],
+        dilation: &[usize],
+        padding: &[usize],
+        output_padding: &[usize],
+    ) -> Self::Output {
+        input.past.merge(kernel.past);
+        let node = TransposedConvolutionBackward::new(
+            input.node,
+            kernel.node,
+            input.var.node.clone(),
+            kernel.var.node.clone(),
+            stride,
+            dilation,
+            padding,
+            output_padding,
+        );
+        VarDiff::from(
+            node,
+            input.past,
+            Var::convolve_transposed(
+                input.var,
+                kernel.var,
+                stride,
+                dilation,
+                padding,
+                output_padding,
+            ),
+        )
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Shapes and Checks ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Checks that the arguments are correct for the given **transposed convolution**.
+///
+/// It verifies that the stride, the dilation, the padding and the output padding have one
+/// component for each spatial axis of the input, that the input and the kernel have the same
+/// number of dimensions and that the input channels match the first axis of the kernel. Each
+/// component of `output_padding` must be smaller than either the stride or the dilation of the
+/// same axis and the resulting output must not be empty.
+///
+/// # Arguments
+///
+/// * `input_shape` - the shape of the input map of the transposed convolution.
+///
+/// * `kernel_shape` - the shape of the kernel.
+///
+/// * `stride` - the stride.
+///
+/// * `dilation` - the dilation.
+///
+/// * `padding` - the padding removed from the output.
+///
+/// * `output_padding` - the additional size of the output.
+fn check_transposed_conv_args(
+    input_shape: &[usize],
+    kernel_shape: &[usize],
+    stride: &[usize],
+    dilation: &[usize],
+    padding: &[usize],
+    output_padding: &[usize],
+) {
+    assert_eq!(
+        kernel_shape.len(),
+        input_shape.len(),
+        "error: invalid kernel's shape {:?} for {}d transposed conv.",
+        kernel_shape,
+        input_shape.len() - 2
+    );
+    let convolution_dimension = input_shape.len() - 2;
+    for (name, arg) in [
+        ("stride", stride),
+        ("dilation", dilation),
+        ("padding", padding),
+        ("output padding", output_padding),
+    ] {
+        assert_eq!(
+            convolution_dimension,
+            arg.len(),
+            "error: invalid {} {:?} for {}d transposed conv.",
+            name,
+            arg,
+            convolution_dimension
+        );
+    }
+
+    assert_eq!(
+        input_shape[1], kernel_shape[0],
+        "error: the input has {} channels but the kernel expects {}.",
+        input_shape[1], kernel_shape[0]
+    );
+
+    itertools::izip!(output_padding, stride, dilation).for_each(
+        |(output_padding, stride, dilation)| {
+            assert!(
+                output_padding < stride || output_padding < dilation,
+                "error: output padding {} must be smaller than either stride {} or dilation {}.",
+                output_padding,
+                stride,
+                dilation
+            )
+        },
+    );
+
+    let unpadded_shape: Vec<usize> = transposed_conv_out_shape::<ndarray::IxDyn>(
+        input_shape,
+        kernel_shape,
+        stride,
+        dilation,
+        &vec![0; convolution_dimension],
+        output_padding,
+    )
+    .slice()
+    .to_vec();
+    unpadded_shape
+        .iter()
+        .skip(2)
+        .zip(padding)
+        .for_each(|(unpadded_dim, padding)| {
+            assert!(
+                *unpadded_dim > 2 * padding,
+                "error: padding {} is too large for the output {:?} of the transposed conv.",
+                padding,
+                &unpadded_shape[2..]
+            )
+        });
+}
+
+/// Computes the shape of the array resulting from the **n**-dimensional transposed convolution
+/// performed with the given parameters.
+///
+/// Each spatial axis of the output is of length
+/// `(input - 1) * stride - 2 * padding + dilation * (kernel - 1) + output_padding + 1`, the number
+/// of output channels is given by the second axis of the kernel.
+///
+/// # Arguments
+///
+/// * `input_shape` - the shape of the input.
+///
+/// * `kernel_shape` - the shape of the kernel.
+///
+/// * `stride` - the stride.
+///
+/// * `dilation` - the dilation.
+///
+/// * `padding` - the padding removed from the output.
+///
+/// * `output_padding` - the additional size of the output.
+fn transposed_conv_out_shape<D: Dimension>(
+    input_shape: &[usize],
+    kernel_shape: &[usize],
+    stride: &[usize],
+    dilation: &[usize],
+    padding: &[usize],
+    output_padding: &[usize],
+) -> D {
+    let mut output_map_shape = D::zeros(input_shape.len());
+    output_map_shape[0] = input_shape[0];
+    output_map_shape[1] = kernel_shape[1];
+    itertools::izip!(
+        output_map_shape.slice_mut().iter_mut().skip(2),
+        input_shape.iter().skip(2),
+        kernel_shape.iter().skip(2),
+        stride,
+        dilation,
+        padding,
+        output_padding,
+    )
+    .for_each(
+        |(output_map_dim, input_dim, kernel_dim, stride, dilation, padding, output_padding)| {
+            *output_map_dim =
+                ((input_dim - 1) * stride + dilation * (kernel_dim - 1) + 1 + output_padding)
+                    .saturating_sub(2 * padding)
+        },
+    );
+    output_map_shape
+}
+
+/// Returns, for each axis, the range of the full buffer of a transposed convolution, the one
+/// computed without padding and output padding, that overlaps with the output, and the
+/// corresponding range of the output.
+///
+/// The positions of the output falling outside of the buffer, due to the output padding, are
+/// not reached by any input position.
+fn overlapping_ranges(
+    buffer_shape: &[usize],
+    output_shape: &[usize],
+    padding: &[usize],
+) -> Vec<(Range<usize>, Range<usize>)> {
+    buffer_shape
+        .iter()
+        .zip(output_shape)
+        .enumerate()
+        .map(|(axis, (buffer_len, output_len))| {
+            if axis < 2 {
+                return (0..*buffer_len, 0..*output_len);
+            }
+            let start = padding[axis - 2].min(*buffer_len);
+            let len = (buffer_len - start).min(*output_len);
+            (start..start + len, 0..len)
+        })
+        .collect()
+}
+
+/// Copies the portion of `buffer` overlapping with `output` into it. The rest of `output` is
+/// zeroed.
+fn crop<D, S, T>(buffer: &ArrayBase<S, D>, output: &mut ArrayBase<T, D>, padding: &[usize])
+where
+    D: Dimension,
+    S: Data<Elem = f32>,
+    T: DataMut<Elem = f32>,
+{
+    let ranges = overlapping_ranges(buffer.shape(), output.shape(), padding);
+    output.fill(0.);
+    output
+        .slice_each_axis_mut(|ax| Slice::from(ranges[ax.axis.index()].1.clone()))
+        .assign(&buffer.slice_each_axis(|ax| Slice::from(ranges[ax.axis.index()].0.clone())));
+}
+
+/// Places `output` into a zeroed array shaped as the full buffer of a transposed convolution.
+/// This is the adjoint of [`crop`].
+fn uncrop<D, S>(output: &ArrayBase<S, D>, buffer_shape: D, padding: &[usize]) -> Tensor<D>
+where
+    D: Dimension,
+    S: Data<Elem = f32>,
+{
+    let mut buffer = Tensor::zeros(buffer_shape);
+    let ranges = overlapping_ranges(buffer.shape(), output.shape(), padding);
+    buffer
+        .slice_each_axis_mut(|ax| Slice::from(ranges[ax.axis.index()].0.clone()))
+        .assign(&output.slice_each_axis(|ax| Slice::from(ranges[ax.axis.index()].1.clone())));
+    buffer
+}
+
+/// Returns the shape of the full buffer of a transposed convolution, computed without padding
+/// and output padding.
+fn buffer_shape<D: Dimension>(
+    input_shape: &[usize],
+    kernel_shape: &[usize],
+    stride: &[usize],
+    dilation: &[usize],
+) -> D {
+    let zeros = vec![0; stride.len()];
+    transposed_conv_out_shape(input_shape, kernel_shape, stride, dilation, &zeros, &zeros)
+}
+
+/// Computes the gradient of a transposed convolution with respect to its kernel, given the
+/// `buffer` holding the uncropped incoming gradient.
+fn transposed_convolution_backward_kernel<D: Dimension + RemoveAxis>(
+    kernel_grad: &mut Tensor<D>,
+    buffer: &Tensor<D>,
+    input: &Tensor<D>,
+    stride: &[usize],
+    dilation: &[usize],
+    overwrite_kernel_grad: bool,
+) {
+    // The transposed convolution maps the input as the convolution maps its gradients, so that
+    // the roles of the input and of the incoming gradient are swapped.
+    convolution_backward_kernel(
+        kernel_grad,
+        input,
+        buffer,
+        stride,
+        dilation,
+        overwrite_kernel_grad,
+    );
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ TransposedConvolution ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct TransposedConvolution<Inp: ?Sized, Ker: ?Sized>
+where
+    Inp: NData,
+    Ker: NData<Dim = Inp::Dim>,
+{
+    input: Rc<Inp>,
+    kernel: Rc<Ker>,
+    stride: Vec<usize>,
+    dilation: Vec<usize>,
+    padding: Vec<usize>,
+    output_padding: Vec<usize>,
+    data: RefCell<Tensor<Inp::Dim>>,
+    computed: Cell<bool>,
+}
+
+impl<Inp: ?Sized, Ker: ?Sized> TransposedConvolution<Inp, Ker>
+where
+    Inp: NData,
+    Ker: NData<Dim = Inp::Dim>,
+{
+    pub fn new(
+        input: Rc<Inp>,
+        kernel: Rc<Ker>,
+        stride: &[usize],
+        dilation: &[usize],
+        padding: &[usize],
+        output_padding: &[usize],
+    ) -> Self {
+        let shape: Inp::Dim = {
+            let (input_data, kernel_data) = (input.data(), kernel.data());
+            check_transposed_conv_args(
+                input_data.shape(),
+                kernel_data.shape(),
+                stride,
+                dilation,
+                padding,
+                output_padding,
+            );
+            transposed_conv_out_shape(
+                input_data.shape(),
+                kernel_data.shape(),
+                stride,
+                dilation,
+                padding,
+                output_padding,
+            )
+        };
+
+        Self {
+            input,
+            kernel,
+            stride: stride.to_vec(),
+            dilation: dilation.to_vec(),
+            padding: padding.to_vec(),
+            output_padding: output_padding.to_vec(),
+            data: RefCell::new(Tensor::zeros(shape)),
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<Inp: ?Sized, Ker: ?Sized> NData for TransposedConvolution<Inp, Ker>
+where
+    Inp: NData,
+    Ker: NData<Dim = Inp::Dim>,
+{
+    type Dim = Inp::Dim;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<Inp: ?Sized, Ker: ?Sized> Cache for TransposedConvolution<Inp, Ker>
+where
+    Inp: NData,
+    Ker: NData<Dim = Inp::Dim>,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<Inp: ?Sized, Ker: ?Sized> Forward for TransposedConvolution<Inp, Ker>
+where
+    Inp: NData,
+    Inp::Dim: RemoveAxis,
+    Ker: NData<Dim = Inp::Dim>,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let (input, kernel, stride, dilation) = (
+            self.input.data(),
+            self.kernel.data(),
+            &self.stride,
+            &self.dilation,
+        );
+
+        // The full output is the gradient of a convolution mapping it to the input, the padding
+        // is then stripped off.
+        let mut buffer = Tensor::zeros(buffer_shape::<Inp::Dim>(
+            input.shape(),
+            kernel.shape(),
+            stride,
+            dilation,
+        ));
+        convolution_backward_input(
+            &mut buffer,
+            &*input,
+            &*kernel,
+            &vec![0; stride.len()],
+            &Zero,
+            stride,
+            dilation,
+            true,
+        );
+        crop(&buffer, &mut *self.data.borrow_mut(), &self.padding);
+    }
+}
+
+impl<Inp: ?Sized, Ker: ?Sized> Debug for TransposedConvolution<Inp, Ker>
+where
+    Inp: NData,
+    Ker: NData<Dim = Inp::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TransposedConvolution")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("stride", &self.stride)
+            .field("dilation", &self.dilation)
+            .field("padding", &self.padding)
+            .field("output_padding", &self.output_padding)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<Inp: ?Sized, Ker: ?Sized> Display for TransposedConvolution<Inp, Ker>
+where
+    Inp: NData,
+    Ker: NData<Dim = Inp::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ TransposedConvolutionBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct TransposedConvolutionBackward<InpD: ?Sized, InpG: ?Sized, KerD: ?Sized, KerG: ?Sized>
+where
+    InpD: NData,
+    InpG: Gradient<Dim = InpD::Dim>,
+    KerD: NData<Dim = InpD::Dim>,
+    KerG: Gradient<Dim = KerD::Dim>,
+{
+    input_grad: Rc<InpG>,
+    kernel_grad: Rc<KerG>,
+    gradient: RefCell<Option<Tensor<InpG::Dim>>>,
+    input: Rc<InpD>,
+    kernel: Rc<KerD>,
+    stride: Vec<usize>,
+    dilation: Vec<usize>,
+    padding: Vec<usize>,
+    output_padding: Vec<usize>,
+    shape: InpD::Dim,
+    overwrite: Cell<bool>,
+}
+
+impl<InpD: ?Sized, InpG: ?Sized, KerD: ?Sized, KerG: ?Sized>
+    TransposedConvolutionBackward<InpD, InpG, KerD, KerG>
+where
+    InpD: NData,
+    InpG: Gradient<Dim = InpD::Dim>,
+    KerD: NData<Dim = InpD::Dim>,
+    KerG: Gradient<Dim = KerD::Dim>,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        input_grad: Rc<InpG>,
+        kernel_grad: Rc<KerG>,
+        input: Rc<InpD>,
+        kernel: Rc<KerD>,
+        stride: &[usize],
+        dilation: &[usize],
+        padding: &[usize],
+        output_padding: &[usize],
+    ) -> Self {
+        let shape: InpD::Dim = transposed_conv_out_shape(
+            input.data().shape(),
+            kernel.data().shape(),
+            stride,
+            dilation,
+            padding,
+            output_padding,
+        );
+
+        Self {
+            input_grad,
+            kernel_grad,
+            gradient: RefCell::new(Some(Tensor::zeros(shape.clone()))),
+            input,
+            kernel,
+            stride: stride.to_vec(),
+            dilation: dilation.to_vec(),
+            padding: padding.to_vec(),
+            output_padding: output_padding.to_vec(),
+            shape,
+            overwrite: Cell::new(true),
+        }
+    }
+}
+
+impl<InpD: ?Sized, InpG: ?Sized, KerD: ?Sized, KerG: ?Sized> Gradient
+    for TransposedConvolutionBackward<InpD, InpG, KerD, KerG>
+where
+    InpD: NData,
+    InpG: Gradient<Dim = InpD::Dim>,
+    KerD: NData<Dim = InpD::Dim>,
+    KerG: Gradient<Dim = KerD::Dim>,
+{
+    type Dim = InpG::Dim;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<InpD: ?Sized, InpG: ?Sized, KerD: ?Sized, KerG: ?Sized> Overwrite
+    for TransposedConvolutionBackward<InpD, InpG, KerD, KerG>
+where
+    InpD: NData,
+    InpG: Gradient<Dim = InpD::Dim>,
+    KerD: NData<Dim = InpD::Dim>,
+    KerG: Gradient<Dim = KerD::Dim>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<InpD: ?Sized, InpG: ?Sized, KerD: ?Sized, KerG: ?Sized> Backward
+    for TransposedConvolutionBackward<InpD, InpG, KerD, KerG>
+where
+    InpD: NData,
+    InpD::Dim: RemoveAxis,
+    InpG: Gradient<Dim = InpD::Dim>,
+    KerD: NData<Dim = InpD::Dim>,
+    KerG: Gradient<Dim = KerD::Dim>,
+{
+    fn backward(&self) {
+        let (gradient, input, kernel, stride, dilation) = (
+            self.gradient(),
+            self.input.data(),
+            self.kernel.data(),
+            &self.stride,
+            &self.dilation,
+        );
+        let buffer = uncrop(
+            &*gradient,
+            buffer_shape(input.shape(), kernel.shape(), stride, dilation),
+            &self.padding,
+        );
+
+        // The gradient with respect to the input is the convolution of the incoming gradient.
+        let mut input_grad = self.input_grad.gradient_mut();
+        if self.input_grad.can_overwrite() {
+            convolution(&buffer, &*kernel, &mut *input_grad, stride, dilation);
+            self.input_grad.set_overwrite(false);
+        } else {
+            let mut convolved = Tensor::zeros(input_grad.raw_dim());
+            convolution(&buffer, &*kernel, &mut convolved, stride, dilation);
+            *input_grad += &convolved;
+        }
+
+        let overwrite_kernel_grad = self.kernel_grad.can_overwrite();
+        transposed_convolution_backward_kernel(
+            &mut *self.kernel_grad.gradient_mut(),
+            &buffer,
+            &input,
+            stride,
+            dilation,
+            overwrite_kernel_grad,
+        );
+        if overwrite_kernel_grad {
+            self.kernel_grad.set_overwrite(false);
+        }
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<InpD: ?Sized, InpG: ?Sized, KerD: ?Sized, KerG: ?Sized> Debug
+    for TransposedConvolutionBackward<InpD, InpG, KerD, KerG>
+where
+    InpD: NData,
+    InpG: Gradient<Dim = InpD::Dim>,
+    KerD: NData<Dim = InpD::Dim>,
+    KerG: Gradient<Dim = KerD::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TransposedConvolutionBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("stride", &self.stride)
+            .field("dilation", &self.dilation)
+            .field("padding", &self.padding)
+            .field("output_padding", &self.output_padding)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<InpD: ?Sized, InpG: ?Sized, KerD: ?Sized, KerG: ?Sized> Display
+    for TransposedConvolutionBackward<InpD, InpG, KerD, KerG>
+where
+    InpD: NData,
+    InpG: Gradient<Dim = InpD::Dim>,
+    KerD: NData<Dim = InpD::Dim>,
+    KerG: Gradient<Dim = KerD::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ TransposedConvolutionBackwardUnary ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct TransposedConvolutionBackwardUnary<InpD: ?Sized, KerG: ?Sized>
+where
+    InpD: NData,
+    KerG: Gradient<Dim = InpD::Dim>,
+{
+    kernel_grad: Rc<KerG>,
+    gradient: RefCell<Option<Tensor<KerG::Dim>>>,
+    input: Rc<InpD>,
+    stride: Vec<usize>,
+    dilation: Vec<usize>,
+    padding: Vec<usize>,
+    output_padding: Vec<usize>,
+    buffer_shape: InpD::Dim,
+    shape: InpD::Dim,
+    overwrite: Cell<bool>,
+}
+
+impl<InpD: ?Sized, KerG: ?Sized> TransposedConvolutionBackwardUnary<InpD, KerG>
+where
+    InpD: NData,
+    KerG: Gradient<Dim = InpD::Dim>,
+{
+    pub fn new<KerD: ?Sized>(
+        kernel_grad: Rc<KerG>,
+        input: Rc<InpD>,
+        kernel: Rc<KerD>,
+        stride: &[usize],
+        dilation: &[usize],
+        padding: &[usize],
+        output_padding: &[usize],
+    ) -> Self
+    where
+        KerD: NData<Dim = KerG::Dim>,
+    {
+        let (shape, buffer_shape): (InpD::Dim, InpD::Dim) = {
+            let (input_data, kernel_data) = (input.data(), kernel.data());
+            (
+                transposed_conv_out_shape(
+                    input_data.shape(),
+                    kernel_data.shape(),
+                    stride,
+                    dilation,
+                    padding,
+                    output_padding,
+                ),
+                buffer_shape(input_data.shape(), kernel_data.shape(), stride, dilation),
+            )
+        };
+
+        Self {
+            kernel_grad,
+            gradient: RefCell::new(Some(Tensor::zeros(shape.clone()))),
+            input,
+            stride: stride.to_vec(),
+            dilation: dilation.to_vec(),
+            padding: padding.to_vec(),
+            output_padding: output_padding.to_vec(),
+            buffer_shape,
+            shape,
+            overwrite: Cell::new(true),
+        }
+    }
+}
+
+impl<InpD: ?Sized, KerG: ?Sized> Gradient for TransposedConvolutionBackwardUnary<InpD, KerG>
+where
+    InpD: NData,
+    KerG: Gradient<Dim = InpD::Dim>,
+{
+    type Dim = KerG::Dim;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<InpD: ?Sized, KerG: ?Sized> Overwrite for TransposedConvolutionBackwardUnary<InpD, KerG>
+where
+    InpD: NData,
+    KerG: Gradient<Dim = InpD::Dim>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<InpD: ?Sized, KerG: ?Sized> Backward for TransposedConvolutionBackwardUnary<InpD, KerG>
+where
+    InpD: NData,
+    InpD::Dim: RemoveAxis,
+    KerG: Gradient<Dim = InpD::Dim>,
+{
+    fn backward(&self) {
+        let buffer = uncrop(&*self.gradient(), self.buffer_shape.clone(), &self.padding);
+
+        let overwrite_kernel_grad = self.kernel_grad.can_overwrite();
+        transposed_convolution_backward_kernel(
+            &mut *self.kernel_grad.gradient_mut(),
+            &buffer,
+            &self.input.data(),
+            &self.stride,
+            &self.dilation,
+            overwrite_kernel_grad,
+        );
+        if overwrite_kernel_grad {
+            self.kernel_grad.set_overwrite(false);
+        }
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<InpD: ?Sized, KerG: ?Sized> Debug for TransposedConvolutionBackwardUnary<InpD, KerG>
+where
+    InpD: NData,
+    KerG: Gradient<Dim = InpD::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TransposedConvolutionBackwardUnary")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("stride", &self.stride)
+            .field("dilation", &self.dilation)
+            .field("padding", &self.padding)
+            .field("output_padding", &self.output_padding)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<InpD: ?Sized, KerG: ?Sized> Display for TransposedConvolutionBackwardUnary<InpD, KerG>
+where
+    InpD: NData,
+    KerG: Gradient<Dim = InpD::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
@@ -0,0 +1,319 @@
+use super::{
+    new_backward_input, new_input, transposed_conv_out_shape, Backward, Cache, Forward, Gradient,
+    NData, Overwrite, Tensor, TransposedConvolution, TransposedConvolutionBackward,
+    TransposedConvolutionBackwardUnary,
+};
+use ndarray::Ix4;
+
+mod forward {
+    use super::{
+        new_input, transposed_conv_out_shape, Cache, Forward, Ix4, NData, Tensor,
+        TransposedConvolution,
+    };
+
+    #[test]
+    fn output_shape_formula() {
+        let shape: Ix4 = transposed_conv_out_shape(
+            &[2, 3, 4, 5],
+            &[3, 6, 3, 3],
+            &[1, 1],
+            &[1, 1],
+            &[0, 0],
+            &[0, 0],
+        );
+        assert_eq!(shape, ndarray::Dim([2, 6, 6, 7]));
+
+        let shape: Ix4 = transposed_conv_out_shape(
+            &[2, 3, 4, 5],
+            &[3, 6, 3, 3],
+            &[2, 2],
+            &[1, 1],
+            &[1, 1],
+            &[1, 0],
+        );
+        assert_eq!(shape, ndarray::Dim([2, 6, 8, 9]));
+
+        let shape: Ix4 = transposed_conv_out_shape(
+            &[1, 1, 3, 3],
+            &[1, 2, 3, 2],
+            &[1, 3],
+            &[2, 1],
+            &[0, 0],
+            &[1, 0],
+        );
+        assert_eq!(shape, ndarray::Dim([1, 2, 8, 8]));
+    }
+
+    #[test]
+    fn creation() {
+        let input = new_input((2, 3, 4, 4), vec![0.; 2 * 3 * 4 * 4]);
+        let kernel = new_input((3, 5, 3, 3), vec![0.; 3 * 5 * 3 * 3]);
+        let node = TransposedConvolution::new(input, kernel, &[2, 2], &[1, 1], &[1, 1], &[1, 1]);
+
+        assert_eq!(*node.data(), Tensor::zeros((2, 5, 8, 8)));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((2, 3, 4, 4), vec![0.; 2 * 3 * 4 * 4]);
+        let kernel = new_input((3, 5, 3, 3), vec![0.; 3 * 5 * 3 * 3]);
+        let node = TransposedConvolution::new(input, kernel, &[1, 1], &[1, 1], &[0, 0], &[0, 0]);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: output padding 2 must be smaller than either stride 2 or dilation 1."
+    )]
+    fn output_padding_too_large() {
+        let input = new_input((1, 1, 3, 3), vec![0.; 9]);
+        let kernel = new_input((1, 1, 2, 2), vec![0.; 4]);
+        TransposedConvolution::new(input, kernel, &[2, 2], &[1, 1], &[0, 0], &[2, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "error: the input has 2 channels but the kernel expects 1.")]
+    fn channels_mismatch() {
+        let input = new_input((1, 2, 3, 3), vec![0.; 18]);
+        let kernel = new_input((1, 1, 2, 2), vec![0.; 4]);
+        TransposedConvolution::new(input, kernel, &[1, 1], &[1, 1], &[0, 0], &[0, 0]);
+    }
+
+    #[test]
+    fn stride_one() {
+        let input = new_input((1, 1, 2, 2), vec![1., 2., 3., 4.]);
+        let kernel = new_input((1, 1, 2, 2), vec![1.; 4]);
+        let node = TransposedConvolution::new(input, kernel, &[1, 1], &[1, 1], &[0, 0], &[0, 0]);
+
+        node.forward();
+        assert_eq!(
+            *node.data(),
+            Tensor::from_shape_vec((1, 1, 3, 3), vec![1., 3., 2., 4., 10., 6., 3., 7., 4.])
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn stride_two() {
+        let input = new_input((1, 1, 2, 2), vec![1., 2., 3., 4.]);
+        let kernel = new_input((1, 1, 2, 2), vec![1., 10., 100., 1000.]);
+        let node = TransposedConvolution::new(input, kernel, &[2, 2], &[1, 1], &[0, 0], &[0, 0]);
+
+        // Each input element scales its own copy of the kernel.
+        node.forward();
+        assert_eq!(
+            *node.data(),
+            Tensor::from_shape_vec(
+                (1, 1, 4, 4),
+                vec![
+                    1., 10., 2., 20., 100., 1000., 200., 2000., 3., 30., 4., 40., 300., 3000.,
+                    400., 4000.,
+                ]
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn padding_and_output_padding() {
+        let input = new_input((1, 1, 2, 2), vec![1., 2., 3., 4.]);
+        let kernel = new_input((1, 1, 2, 2), vec![1., 10., 100., 1000.]);
+
+        // The padding strips the border of the full output.
+        let node = TransposedConvolution::new(
+            input.clone(),
+            kernel.clone(),
+            &[2, 2],
+            &[1, 1],
+            &[1, 1],
+            &[0, 0],
+        );
+        node.forward();
+        assert_eq!(
+            *node.data(),
+            Tensor::from_shape_vec((1, 1, 2, 2), vec![1000., 200., 30., 4.]).unwrap()
+        );
+
+        // The output padding adds positions that no input element reaches.
+        let node = TransposedConvolution::new(input, kernel, &[2, 2], &[1, 1], &[0, 0], &[1, 1]);
+        node.forward();
+        let data = node.data();
+        assert_eq!(data.shape(), &[1, 1, 5, 5]);
+        assert_eq!(data[[0, 0, 3, 3]], 4000.);
+        assert!(data
+            .slice(ndarray::s![0, 0, 4, ..])
+            .iter()
+            .all(|el| *el == 0.));
+        assert!(data
+            .slice(ndarray::s![0, 0, .., 4])
+            .iter()
+            .all(|el| *el == 0.));
+    }
+}
+
+mod backward {
+    use super::{
+        new_backward_input, new_input, Backward, Forward, Gradient, NData, Overwrite, Tensor,
+        TransposedConvolution, TransposedConvolutionBackward, TransposedConvolutionBackwardUnary,
+    };
+    use ndarray::{Array, Ix4};
+
+    const STRIDE: &[usize] = &[2, 1];
+    const DILATION: &[usize] = &[1, 2];
+    const PADDING: &[usize] = &[1, 1];
+    const OUTPUT_PADDING: &[usize] = &[1, 1];
+
+    /// Computes the transposed convolution of `input` by `kernel`.
+    fn transposed_convolution(input: &Tensor<Ix4>, kernel: &Tensor<Ix4>) -> Tensor<Ix4> {
+        let node = TransposedConvolution::new(
+            new_input(input.raw_dim(), input.iter().copied().collect()),
+            new_input(kernel.raw_dim(), kernel.iter().copied().collect()),
+            STRIDE,
+            DILATION,
+            PADDING,
+            OUTPUT_PADDING,
+        );
+        node.forward();
+        let data = node.data().clone();
+        data
+    }
+
+    /// Returns the gradient of the sum of the transposed convolution weighted by `gradient`, with
+    /// respect to the element `index` of either the input or the kernel. The transposed
+    /// convolution is linear in both, so that a unit perturbation yields it exactly.
+    fn expected_gradient(
+        input: &Tensor<Ix4>,
+        kernel: &Tensor<Ix4>,
+        gradient: &Tensor<Ix4>,
+        wrt_input: bool,
+    ) -> Tensor<Ix4> {
+        let target = if wrt_input { input } else { kernel };
+        let mut expected = Tensor::zeros(target.raw_dim());
+        for (index, expected) in expected.iter_mut().enumerate() {
+            let mut unit = Tensor::zeros(target.raw_dim());
+            unit.as_slice_mut().unwrap()[index] = 1.;
+            let output = if wrt_input {
+                transposed_convolution(&unit, kernel)
+            } else {
+                transposed_convolution(input, &unit)
+            };
+            *expected = (output * gradient).sum();
+        }
+        expected
+    }
+
+    fn operands() -> (Tensor<Ix4>, Tensor<Ix4>, Tensor<Ix4>) {
+        let input = Array::range(0., 24., 1.).into_shape((2, 2, 2, 3)).unwrap() / 10.;
+        let kernel = Array::range(0., 24., 1.)
+            .mapv(|el: f32| el.sin())
+            .into_shape((2, 3, 2, 2))
+            .unwrap();
+        let output_shape = transposed_convolution(&input, &kernel).raw_dim();
+        let gradient = Array::from_shape_fn(output_shape, |(n, c, h, w)| {
+            (n + 2 * c) as f32 - (h * w) as f32 / 3.
+        });
+
+        (input, kernel, gradient)
+    }
+
+    #[test]
+    fn creation() {
+        let node = TransposedConvolutionBackward::new(
+            new_backward_input((2, 3, 4, 4), vec![0.; 2 * 3 * 4 * 4]),
+            new_backward_input((3, 5, 3, 3), vec![0.; 3 * 5 * 3 * 3]),
+            new_input((2, 3, 4, 4), vec![0.; 2 * 3 * 4 * 4]),
+            new_input((3, 5, 3, 3), vec![0.; 3 * 5 * 3 * 3]),
+            &[2, 2],
+            &[1, 1],
+            &[1, 1],
+            &[1, 1],
+        );
+
+        assert_eq!(*node.gradient(), Tensor::zeros((2, 5, 8, 8)));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        let (input, kernel, gradient) = operands();
+        let input_grad = new_backward_input(input.raw_dim(), vec![0.; input.len()]);
+        let kernel_grad = new_backward_input(kernel.raw_dim(), vec![0.; kernel.len()]);
+        let node = TransposedConvolutionBackward::new(
+            input_grad.clone(),
+            kernel_grad.clone(),
+            new_input(input.raw_dim(), input.iter().copied().collect()),
+            new_input(kernel.raw_dim(), kernel.iter().copied().collect()),
+            STRIDE,
+            DILATION,
+            PADDING,
+            OUTPUT_PADDING,
+        );
+        node.gradient_mut().assign(&gradient);
+
+        let expected_input_grad = expected_gradient(&input, &kernel, &gradient, true);
+        let expected_kernel_grad = expected_gradient(&input, &kernel, &gradient, false);
+
+        // Overwrites.
+        node.backward();
+        assert!(!input_grad.can_overwrite());
+        assert!(!kernel_grad.can_overwrite());
+        assert!(input_grad
+            .gradient()
+            .iter()
+            .zip(expected_input_grad.iter())
+            .all(|(actual, expected)| (actual - expected).abs() <= 1e-4));
+        assert!(kernel_grad
+            .gradient()
+            .iter()
+            .zip(expected_kernel_grad.iter())
+            .all(|(actual, expected)| (actual - expected).abs() <= 1e-4));
+
+        // Accumulates.
+        node.backward();
+        assert!(input_grad
+            .gradient()
+            .iter()
+            .zip(expected_input_grad.iter())
+            .all(|(actual, expected)| (actual - 2. * expected).abs() <= 1e-4));
+        assert!(kernel_grad
+            .gradient()
+            .iter()
+            .zip(expected_kernel_grad.iter())
+            .all(|(actual, expected)| (actual - 2. * expected).abs() <= 1e-4));
+    }
+
+    #[test]
+    fn backward_unary() {
+        let (input, kernel, gradient) = operands();
+        let kernel_grad = new_backward_input(kernel.raw_dim(), vec![0.; kernel.len()]);
+        let node = TransposedConvolutionBackwardUnary::new(
+            kernel_grad.clone(),
+            new_input(input.raw_dim(), input.iter().copied().collect()),
+            new_input(kernel.raw_dim(), kernel.iter().copied().collect()),
+            STRIDE,
+            DILATION,
+            PADDING,
+            OUTPUT_PADDING,
+        );
+        node.gradient_mut().assign(&gradient);
+
+        let expected_kernel_grad = expected_gradient(&input, &kernel, &gradient, false);
+
+        node.backward();
+        assert!(!kernel_grad.can_overwrite());
+        assert!(kernel_grad
+            .gradient()
+            .iter()
+            .zip(expected_kernel_grad.iter())
+            .all(|(actual, expected)| (actual - expected).abs() <= 1e-4));
+    }
+}
@@ -0,0 +1,606 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, DotDim, Forward, Gradient, Overwrite,
+    Summary, Tensor,
+};
+use ndarray::{
+    linalg::general_mat_mul, ArrayView2, ArrayViewD, ArrayViewMut2, ArrayViewMutD, Axis, Dimension,
+    Ix2, Ix3,
+};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+/// Returns the matrix at `index` along the batch axis of `array`. Two-dimensional arrays are
+/// broadcast along the batch axis, thus they are returned whole.
+fn batch(array: ArrayViewD<f32>, index: usize) -> ArrayView2<f32> {
+    let matrix = match array.ndim() {
+        2 => array,
+        _ => array.index_axis_move(Axis(0), index),
+    };
+
+    matrix.into_dimensionality::<Ix2>().unwrap()
+}
+
+/// Mutable counterpart of [`batch`].
+fn batch_mut(array: ArrayViewMutD<f32>, index: usize) -> ArrayViewMut2<f32> {
+    let matrix = match array.ndim() {
+        2 => array,
+        _ => array.index_axis_move(Axis(0), index),
+    };
+
+    matrix.into_dimensionality::<Ix2>().unwrap()
+}
+
+/// Returns a view of `array` with its two innermost axes swapped.
+fn transposed<D: Dimension>(array: &Tensor<D>) -> ArrayViewD<f32> {
+    let mut view = array.view().into_dyn();
+    let ndim = view.ndim();
+    view.swap_axes(ndim - 2, ndim - 1);
+    view
+}
+
+/// Performs gradient accumulation into `destination_node`.
+///
+/// This functions accumulates the gradient of the batched matrix multiplication operation. If the
+/// gradient of `destination_node` is two-dimensional the contributions of all the batches are
+/// summed up.
+///
+/// # Arguments
+///
+/// * `destination_node` - a node of the computational graph.
+///
+/// * `first` - two or three-dimensional array.
+///
+/// * `second` - two or three-dimensional array.
+fn push_batched_gradient<T: ?Sized>(
+    destination_node: &T,
+    first: ArrayViewD<f32>,
+    second: ArrayViewD<f32>,
+) where
+    T: Gradient,
+{
+    let mut gradient = destination_node.gradient_mut();
+    if destination_node.can_overwrite() {
+        gradient.fill(0.);
+        destination_node.set_overwrite(false);
+    }
+
+    let batches = match first.ndim() {
+        3 => first.len_of(Axis(0)),
+        _ => second.len_of(Axis(0)),
+    };
+    for index in 0..batches {
+        general_mat_mul(
+            1.,
+            &batch(first.view(), index),
+            &batch(second.view(), index),
+            1.,
+            &mut batch_mut(gradient.view_mut().into_dyn(), index),
+        );
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ BatchedMatMul ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct BatchedMatMul<Lhs: ?Sized, Rhs: ?Sized>
+where
+    Lhs: Data,
+    Lhs::Dim: DotDim<Ix3, Output = Ix3>,
+    Rhs: Data<Dim = Ix3>,
+{
+    left: Rc<Lhs>,
+    right: Rc<Rhs>,
+    data: RefCell<Tensor<Ix3>>,
+    computed: Cell<bool>,
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> BatchedMatMul<Lhs, Rhs>
+where
+    Lhs: Data,
+    Lhs::Dim: DotDim<Ix3, Output = Ix3>,
+    Rhs: Data<Dim = Ix3>,
+{
+    pub fn new(left: Rc<Lhs>, right: Rc<Rhs>) -> Self {
+        let (left_dim, right_dim) = (left.data().raw_dim(), right.data().raw_dim());
+        if left_dim.ndim() == 3 {
+            assert_eq!(
+                left_dim[0], right_dim[0],
+                "error: batched matrix multiplication between {} and {} batches.",
+                left_dim[0], right_dim[0]
+            );
+        }
+        let data = RefCell::new(Tensor::zeros(DotDim::shape(left_dim, right_dim)));
+
+        Self {
+            left,
+            right,
+            data,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Data for BatchedMatMul<Lhs, Rhs>
+where
+    Lhs: Data,
+    Lhs::Dim: DotDim<Ix3, Output = Ix3>,
+    Rhs: Data<Dim = Ix3>,
+{
+    type Dim = Ix3;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Cache for BatchedMatMul<Lhs, Rhs>
+where
+    Lhs: Data,
+    Lhs::Dim: DotDim<Ix3, Output = Ix3>,
+    Rhs: Data<Dim = Ix3>,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Forward for BatchedMatMul<Lhs, Rhs>
+where
+    Lhs: Data,
+    Lhs::Dim: DotDim<Ix3, Output = Ix3>,
+    Rhs: Data<Dim = Ix3>,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let (left, right) = (self.left.data(), self.right.data());
+        for (index, mut data) in self.data.borrow_mut().outer_iter_mut().enumerate() {
+            general_mat_mul(
+                1.0,
+                &batch(left.view().into_dyn(), index),
+                &right.index_axis(Axis(0), index),
+                0.0,
+                &mut data,
+            );
+        }
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Debug for BatchedMatMul<Lhs, Rhs>
+where
+    Lhs: Data,
+    Lhs::Dim: DotDim<Ix3, Output = Ix3>,
+    Rhs: Data<Dim = Ix3>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BatchedMatMul")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Display for BatchedMatMul<Lhs, Rhs>
+where
+    Lhs: Data,
+    Lhs::Dim: DotDim<Ix3, Output = Ix3>,
+    Rhs: Data<Dim = Ix3>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ BatchedMatMulBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct BatchedMatMulBackward<LhsD: ?Sized, LhsG: ?Sized, RhsD: ?Sized, RhsG: ?Sized>
+where
+    LhsD: Data,
+    LhsD::Dim: DotDim<Ix3, Output = Ix3>,
+    RhsD: Data<Dim = Ix3>,
+    LhsG: Gradient<Dim = LhsD::Dim>,
+    RhsG: Gradient<Dim = Ix3>,
+{
+    gradient: RefCell<Option<Tensor<Ix3>>>,
+    shape: Ix3,
+    overwrite: Cell<bool>,
+    left_data: Rc<LhsD>,
+    left_grad: Rc<LhsG>,
+    right_data: Rc<RhsD>,
+    right_grad: Rc<RhsG>,
+}
+
+impl<LhsD: ?Sized, LhsG: ?Sized, RhsD: ?Sized, RhsG: ?Sized>
+    BatchedMatMulBackward<LhsD, LhsG, RhsD, RhsG>
+where
+    LhsD: Data,
+    LhsD::Dim: DotDim<Ix3, Output = Ix3>,
+    RhsD: Data<Dim = Ix3>,
+    LhsG: Gradient<Dim = LhsD::Dim>,
+    RhsG: Gradient<Dim = Ix3>,
+{
+    pub fn new(
+        left_data: Rc<LhsD>,
+        left_grad: Rc<LhsG>,
+        right_data: Rc<RhsD>,
+        right_grad: Rc<RhsG>,
+    ) -> Self {
+        let shape = DotDim::shape(
+            left_grad.gradient().raw_dim(),
+            right_grad.gradient().raw_dim(),
+        );
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape))),
+            shape,
+            overwrite: Cell::new(true),
+            left_data,
+            left_grad,
+            right_data,
+            right_grad,
+        }
+    }
+}
+
+impl<LhsD: ?Sized, LhsG: ?Sized, RhsD: ?Sized, RhsG: ?Sized> Gradient
+    for BatchedMatMulBackward<LhsD, LhsG, RhsD, RhsG>
+where
+    LhsD: Data,
+    LhsD::Dim: DotDim<Ix3, Output = Ix3>,
+    RhsD: Data<Dim = Ix3>,
+    LhsG: Gradient<Dim = LhsD::Dim>,
+    RhsG: Gradient<Dim = Ix3>,
+{
+    type Dim = Ix3;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<LhsD: ?Sized, LhsG: ?Sized, RhsD: ?Sized, RhsG: ?Sized> Overwrite
+    for BatchedMatMulBackward<LhsD, LhsG, RhsD, RhsG>
+where
+    LhsD: Data,
+    LhsD::Dim: DotDim<Ix3, Output = Ix3>,
+    RhsD: Data<Dim = Ix3>,
+    LhsG: Gradient<Dim = LhsD::Dim>,
+    RhsG: Gradient<Dim = Ix3>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<LhsD: ?Sized, LhsG: ?Sized, RhsD: ?Sized, RhsG: ?Sized> Backward
+    for BatchedMatMulBackward<LhsD, LhsG, RhsD, RhsG>
+where
+    LhsD: Data,
+    LhsD::Dim: DotDim<Ix3, Output = Ix3>,
+    RhsD: Data<Dim = Ix3>,
+    LhsG: Gradient<Dim = LhsD::Dim>,
+    RhsG: Gradient<Dim = Ix3>,
+{
+    fn backward(&self) {
+        let gradient = self.gradient();
+        push_batched_gradient(
+            &*self.left_grad,
+            gradient.view().into_dyn(),
+            transposed(&self.right_data.data()),
+        );
+        push_batched_gradient(
+            &*self.right_grad,
+            transposed(&self.left_data.data()),
+            gradient.view().into_dyn(),
+        );
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape));
+    }
+}
+
+impl<LhsD: ?Sized, LhsG: ?Sized, RhsD: ?Sized, RhsG: ?Sized> Debug
+    for BatchedMatMulBackward<LhsD, LhsG, RhsD, RhsG>
+where
+    LhsD: Data,
+    LhsD::Dim: DotDim<Ix3, Output = Ix3>,
+    RhsD: Data<Dim = Ix3>,
+    LhsG: Gradient<Dim = LhsD::Dim>,
+    RhsG: Gradient<Dim = Ix3>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BatchedMatMulBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<LhsD: ?Sized, LhsG: ?Sized, RhsD: ?Sized, RhsG: ?Sized> Display
+    for BatchedMatMulBackward<LhsD, LhsG, RhsD, RhsG>
+where
+    LhsD: Data,
+    LhsD::Dim: DotDim<Ix3, Output = Ix3>,
+    RhsD: Data<Dim = Ix3>,
+    LhsG: Gradient<Dim = LhsD::Dim>,
+    RhsG: Gradient<Dim = Ix3>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ BatchedMatMulBackwardLeft ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct BatchedMatMulBackwardLeft<LhsG: ?Sized, RhsD: ?Sized>
+where
+    RhsD: Data<Dim = Ix3>,
+    LhsG: Gradient,
+    LhsG::Dim: DotDim<Ix3, Output = Ix3>,
+{
+    gradient: RefCell<Option<Tensor<Ix3>>>,
+    shape: Ix3,
+    overwrite: Cell<bool>,
+    left_grad: Rc<LhsG>,
+    right_data: Rc<RhsD>,
+}
+
+impl<LhsG: ?Sized, RhsD: ?Sized> BatchedMatMulBackwardLeft<LhsG, RhsD>
+where
+    RhsD: Data<Dim = Ix3>,
+    LhsG: Gradient,
+    LhsG::Dim: DotDim<Ix3, Output = Ix3>,
+{
+    pub fn new(left_grad: Rc<LhsG>, right_data: Rc<RhsD>) -> Self {
+        let shape = DotDim::shape(left_grad.gradient().raw_dim(), right_data.data().raw_dim());
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape))),
+            shape,
+            overwrite: Cell::new(true),
+            left_grad,
+            right_data,
+        }
+    }
+}
+
+impl<LhsG: ?Sized, RhsD: ?Sized> Gradient for BatchedMatMulBackwardLeft<LhsG, RhsD>
+where
+    RhsD: Data<Dim = Ix3>,
+    LhsG: Gradient,
+    LhsG::Dim: DotDim<Ix3, Output = Ix3>,
+{
+    type Dim = Ix3;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<LhsG: ?Sized, RhsD: ?Sized> Overwrite for BatchedMatMulBackwardLeft<LhsG, RhsD>
+where
+    RhsD: Data<Dim = Ix3>,
+    LhsG: Gradient,
+    LhsG::Dim: DotDim<Ix3, Output = Ix3>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<LhsG: ?Sized, RhsD: ?Sized> Backward for BatchedMatMulBackwardLeft<LhsG, RhsD>
+where
+    RhsD: Data<Dim = Ix3>,
+    LhsG: Gradient,
+    LhsG::Dim: DotDim<Ix3, Output = Ix3>,
+{
+    fn backward(&self) {
+        push_batched_gradient(
+            &*self.left_grad,
+            self.gradient().view().into_dyn(),
+            transposed(&self.right_data.data()),
+        );
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape));
+    }
+}
+
+impl<LhsG: ?Sized, RhsD: ?Sized> Debug for BatchedMatMulBackwardLeft<LhsG, RhsD>
+where
+    RhsD: Data<Dim = Ix3>,
+    LhsG: Gradient,
+    LhsG::Dim: DotDim<Ix3, Output = Ix3>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BatchedMatMulBackwardLeft")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<LhsG: ?Sized, RhsD: ?Sized> Display for BatchedMatMulBackwardLeft<LhsG, RhsD>
+where
+    RhsD: Data<Dim = Ix3>,
+    LhsG: Gradient,
+    LhsG::Dim: DotDim<Ix3, Output = Ix3>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ BatchedMatMulBackwardRight ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct BatchedMatMulBackwardRight<LhsD: ?Sized, RhsG: ?Sized>
+where
+    LhsD: Data,
+    LhsD::Dim: DotDim<Ix3, Output = Ix3>,
+    RhsG: Gradient<Dim = Ix3>,
+{
+    gradient: RefCell<Option<Tensor<Ix3>>>,
+    shape: Ix3,
+    overwrite: Cell<bool>,
+    left_data: Rc<LhsD>,
+    right_grad: Rc<RhsG>,
+}
+
+impl<LhsD: ?Sized, RhsG: ?Sized> BatchedMatMulBackwardRight<LhsD, RhsG>
+where
+    LhsD: Data,
+    LhsD::Dim: DotDim<Ix3, Output = Ix3>,
+    RhsG: Gradient<Dim = Ix3>,
+{
+    pub fn new(left_data: Rc<LhsD>, right_grad: Rc<RhsG>) -> Self {
+        let shape = DotDim::shape(left_data.data().raw_dim(), right_grad.gradient().raw_dim());
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape))),
+            shape,
+            overwrite: Cell::new(true),
+            left_data,
+            right_grad,
+        }
+    }
+}
+
+impl<LhsD: ?Sized, RhsG: ?Sized> Gradient for BatchedMatMulBackwardRight<LhsD, RhsG>
+where
+    LhsD: Data,
+    LhsD::Dim: DotDim<Ix3, Output = Ix3>,
+    RhsG: Gradient<Dim = Ix3>,
+{
+    type Dim = Ix3;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<LhsD: ?Sized, RhsG: ?Sized> Overwrite for BatchedMatMulBackwardRight<LhsD, RhsG>
+where
+    LhsD: Data,
+    LhsD::Dim: DotDim<Ix3, Output = Ix3>,
+    RhsG: Gradient<Dim = Ix3>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<LhsD: ?Sized, RhsG: ?Sized> Backward for BatchedMatMulBackwardRight<LhsD, RhsG>
+where
+    LhsD: Data,
+    LhsD::Dim: DotDim<Ix3, Output = Ix3>,
+    RhsG: Gradient<Dim = Ix3>,
+{
+    fn backward(&self) {
+        push_batched_gradient(
+            &*self.right_grad,
+            transposed(&self.left_data.data()),
+            self.gradient().view().into_dyn(),
+        );
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape));
+    }
+}
+
+impl<LhsD: ?Sized, RhsG: ?Sized> Debug for BatchedMatMulBackwardRight<LhsD, RhsG>
+where
+    LhsD: Data,
+    LhsD::Dim: DotDim<Ix3, Output = Ix3>,
+    RhsG: Gradient<Dim = Ix3>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BatchedMatMulBackwardRight")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<LhsD: ?Sized, RhsG: ?Sized> Display for BatchedMatMulBackwardRight<LhsD, RhsG>
+where
+    LhsD: Data,
+    LhsD::Dim: DotDim<Ix3, Output = Ix3>,
+    RhsG: Gradient<Dim = Ix3>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
@@ -0,0 +1,536 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, BatchedMatMul,
+    BatchedMatMulBackward, BatchedMatMulBackwardLeft, BatchedMatMulBackwardRight, Cache, Data,
+    Forward, Gradient, Overwrite, Tensor,
+};
+
+#[cfg(feature = "blas")]
+extern crate blas_src;
+
+mod forward {
+    use super::{
+        assert_almost_equals, new_input, new_tensor, BatchedMatMul, Cache, Data, Forward, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let left = new_input(
+            (2, 2, 3),
+            vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.],
+        );
+        let right = new_input((2, 3, 2), vec![1.; 12]);
+        let node = BatchedMatMul::new(left, right);
+
+        assert_eq!(*node.data(), Tensor::from_elem((2, 2, 2), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((2, 2, 2), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic]
+    fn creation_fail() {
+        let left = new_input((3, 2, 3), vec![0.; 18]);
+        let right = new_input((2, 3, 2), vec![0.; 12]);
+        BatchedMatMul::new(left, right);
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let left = new_input(
+            (2, 2, 3),
+            vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.],
+        );
+        let right = new_input((2, 3, 2), vec![1.; 12]);
+        let node = BatchedMatMul::new(left, right);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn forward() {
+        let left = new_input(
+            (2, 2, 3),
+            vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.],
+        );
+        let right = new_input((2, 3, 2), vec![1.; 12]);
+        let node = BatchedMatMul::new(left, right.clone());
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((2, 2, 2), vec![6., 6., 15., 15., 24., 24., 33., 33.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *right.data_mut() = new_tensor((2, 3, 2), vec![-2.; 12]);
+        assert_almost_equals(&*right.data(), &new_tensor((2, 3, 2), vec![-2.; 12]));
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((2, 2, 2), vec![6., 6., 15., 15., 24., 24., 33., 33.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (2, 2, 2),
+                vec![-12., -12., -30., -30., -48., -48., -66., -66.],
+            ),
+        );
+    }
+
+    #[test]
+    fn forward_broadcast() {
+        let left = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let right = new_input(
+            (2, 3, 2),
+            vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.],
+        );
+        let node = BatchedMatMul::new(left, right);
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((2, 2, 2), vec![22., 28., 49., 64., 58., 64., 139., 154.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let left = new_input(
+            (2, 2, 3),
+            vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.],
+        );
+        let right = new_input((2, 3, 2), vec![1.; 12]);
+        let node = BatchedMatMul::new(left, right);
+
+        let output = "BatchedMatMul { data: [[[0.0, 0.0],\n  [0.0, 0.0]],\n\n [[0.0, 0.0],\n  [0.0, 0.0]]], shape=[2, 2, 2], strides=[4, 2, 1], layout=Cc (0x5), const ndim=3, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let left = new_input(
+            (2, 2, 3),
+            vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.],
+        );
+        let right = new_input((2, 3, 2), vec![1.; 12]);
+        let node = BatchedMatMul::new(left, right);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_input, new_tensor, Backward,
+        BatchedMatMulBackward, BatchedMatMulBackwardLeft, BatchedMatMulBackwardRight, Gradient,
+        Overwrite, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = BatchedMatMulBackward::new(
+            new_input(
+                (2, 2, 3),
+                vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.],
+            ),
+            new_backward_input((2, 2, 3), vec![0.; 12]),
+            new_input(
+                (2, 3, 2),
+                vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.],
+            ),
+            new_backward_input((2, 3, 2), vec![0.; 12]),
+        );
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((2, 2, 2), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((2, 2, 2), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let lhs = new_backward_input((2, 2, 3), vec![0.; 12]);
+        let rhs = new_backward_input((2, 3, 2), vec![0.; 12]);
+        let node = BatchedMatMulBackward::new(
+            new_input(
+                (2, 2, 3),
+                vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.],
+            ),
+            lhs.clone(),
+            new_input(
+                (2, 3, 2),
+                vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.],
+            ),
+            rhs.clone(),
+        );
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+
+        lhs.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+
+        rhs.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(rhs.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(rhs.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        let lhs = new_backward_input((2, 2, 3), vec![0.; 12]);
+        let rhs = new_backward_input((2, 3, 2), vec![0.; 12]);
+        let node = BatchedMatMulBackward::new(
+            new_input(
+                (2, 2, 3),
+                vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.],
+            ),
+            lhs.clone(),
+            new_input(
+                (2, 3, 2),
+                vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.],
+            ),
+            rhs.clone(),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((2, 2, 2), vec![1.; 8]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((2, 2, 2), vec![1.; 8]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*lhs.gradient(),
+            &new_tensor(
+                (2, 2, 3),
+                vec![3., 7., 11., 3., 7., 11., 15., 19., 23., 15., 19., 23.],
+            ),
+        );
+        assert_almost_equals(
+            &*rhs.gradient(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![5., 5., 7., 7., 9., 9., 17., 17., 19., 19., 21., 21.],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*lhs.gradient(),
+            &new_tensor(
+                (2, 2, 3),
+                vec![6., 14., 22., 6., 14., 22., 30., 38., 46., 30., 38., 46.],
+            ),
+        );
+        assert_almost_equals(
+            &*rhs.gradient(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![10., 10., 14., 14., 18., 18., 34., 34., 38., 38., 42., 42.],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        lhs.set_overwrite(true);
+        rhs.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*lhs.gradient(),
+            &new_tensor(
+                (2, 2, 3),
+                vec![3., 7., 11., 3., 7., 11., 15., 19., 23., 15., 19., 23.],
+            ),
+        );
+        assert_almost_equals(
+            &*rhs.gradient(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![5., 5., 7., 7., 9., 9., 17., 17., 19., 19., 21., 21.],
+            ),
+        );
+    }
+
+    #[test]
+    fn backward_broadcast() {
+        let lhs = new_backward_input((2, 3), vec![0.; 6]);
+        let rhs = new_backward_input((2, 3, 2), vec![0.; 12]);
+        let node = BatchedMatMulBackward::new(
+            new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]),
+            lhs.clone(),
+            new_input(
+                (2, 3, 2),
+                vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.],
+            ),
+            rhs.clone(),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((2, 2, 2), vec![1.; 8]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((2, 2, 2), vec![1.; 8]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*lhs.gradient(),
+            &new_tensor((2, 3), vec![18., 26., 34., 18., 26., 34.]),
+        );
+        assert_almost_equals(
+            &*rhs.gradient(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![5., 5., 7., 7., 9., 9., 5., 5., 7., 7., 9., 9.],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*lhs.gradient(),
+            &new_tensor((2, 3), vec![36., 52., 68., 36., 52., 68.]),
+        );
+        assert_almost_equals(
+            &*rhs.gradient(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![10., 10., 14., 14., 18., 18., 10., 10., 14., 14., 18., 18.],
+            ),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let lhs = new_backward_input((2, 2, 3), vec![0.; 12]);
+        let rhs = new_backward_input((2, 3, 2), vec![0.; 12]);
+        let node = BatchedMatMulBackward::new(
+            new_input((2, 2, 3), vec![0.; 12]),
+            lhs,
+            new_input((2, 3, 2), vec![0.; 12]),
+            rhs,
+        );
+
+        let output = "BatchedMatMulBackward { gradient: Some([[[0.0, 0.0],\n  [0.0, 0.0]],\n\n [[0.0, 0.0],\n  [0.0, 0.0]]], shape=[2, 2, 2], strides=[4, 2, 1], layout=Cc (0x5), const ndim=3), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let lhs = new_backward_input((2, 2, 3), vec![0.; 12]);
+        let rhs = new_backward_input((2, 3, 2), vec![0.; 12]);
+        let node = BatchedMatMulBackward::new(
+            new_input((2, 2, 3), vec![0.; 12]),
+            lhs,
+            new_input((2, 3, 2), vec![0.; 12]),
+            rhs,
+        );
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn backward_left() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = BatchedMatMulBackwardLeft::new(
+            diff.clone(),
+            new_input(
+                (2, 3, 2),
+                vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((2, 2, 2), vec![1.; 8]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((2, 2, 2), vec![1.; 8]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![18., 26., 34., 18., 26., 34.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![36., 52., 68., 36., 52., 68.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![18., 26., 34., 18., 26., 34.]),
+        );
+    }
+
+    #[test]
+    fn debug_left() {
+        let node = BatchedMatMulBackwardLeft::new(
+            new_backward_input((2, 2, 3), vec![0.; 12]),
+            new_input((2, 3, 2), vec![0.; 12]),
+        );
+
+        let output = "BatchedMatMulBackwardLeft { gradient: Some([[[0.0, 0.0],\n  [0.0, 0.0]],\n\n [[0.0, 0.0],\n  [0.0, 0.0]]], shape=[2, 2, 2], strides=[4, 2, 1], layout=Cc (0x5), const ndim=3), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display_left() {
+        let node = BatchedMatMulBackwardLeft::new(
+            new_backward_input((2, 2, 3), vec![0.; 12]),
+            new_input((2, 3, 2), vec![0.; 12]),
+        );
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn backward_right() {
+        let diff = new_backward_input((2, 3, 2), vec![0.; 12]);
+        let node = BatchedMatMulBackwardRight::new(
+            new_input(
+                (2, 2, 3),
+                vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.],
+            ),
+            diff.clone(),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((2, 2, 2), vec![1.; 8]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((2, 2, 2), vec![1.; 8]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![5., 5., 7., 7., 9., 9., 17., 17., 19., 19., 21., 21.],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![10., 10., 14., 14., 18., 18., 34., 34., 38., 38., 42., 42.],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![5., 5., 7., 7., 9., 9., 17., 17., 19., 19., 21., 21.],
+            ),
+        );
+    }
+
+    #[test]
+    fn debug_right() {
+        let node = BatchedMatMulBackwardRight::new(
+            new_input((2, 2, 3), vec![0.; 12]),
+            new_backward_input((2, 3, 2), vec![0.; 12]),
+        );
+
+        let output = "BatchedMatMulBackwardRight { gradient: Some([[[0.0, 0.0],\n  [0.0, 0.0]],\n\n [[0.0, 0.0],\n  [0.0, 0.0]]], shape=[2, 2, 2], strides=[4, 2, 1], layout=Cc (0x5), const ndim=3), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display_right() {
+        let node = BatchedMatMulBackwardRight::new(
+            new_input((2, 2, 3), vec![0.; 12]),
+            new_backward_input((2, 3, 2), vec![0.; 12]),
+        );
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // BatchedMatMulBackward
+        let node = BatchedMatMulBackward::new(
+            new_input((2, 2, 3), vec![0.; 12]),
+            new_backward_input((2, 2, 3), vec![0.; 12]),
+            new_input((2, 3, 2), vec![0.; 12]),
+            new_backward_input((2, 3, 2), vec![0.; 12]),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+
+        // BatchedMatMulBackwardLeft
+        let node = BatchedMatMulBackwardLeft::new(
+            new_backward_input((2, 2, 3), vec![0.; 12]),
+            new_input((2, 3, 2), vec![0.; 12]),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+
+        // BatchedMatMulBackwardRight
+        let node = BatchedMatMulBackwardRight::new(
+            new_input((2, 2, 3), vec![0.; 12]),
+            new_backward_input((2, 3, 2), vec![0.; 12]),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
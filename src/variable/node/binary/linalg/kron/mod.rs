@@ -0,0 +1,539 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, push_gradient, Backward, Cache, Data, Forward, Gradient,
+    Overwrite, Summary, Tensor,
+};
+use ndarray::{s, ArrayView4, Ix2, NewAxis, Zip};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+/// Returns the shape of the Kronecker product between matrices of shapes `left` and `right`.
+fn kron_shape(left: Ix2, right: Ix2) -> Ix2 {
+    Ix2(left[0] * right[0], left[1] * right[1])
+}
+
+/// Returns the shape under which the Kronecker product between matrices of shapes `left`, (m, n),
+/// and `right`, (p, q), is seen as an (m, p, n, q) tensor, whose entry (i, k, j, l) is the product
+/// of the entries (i, j) of the left operand and (k, l) of the right one.
+fn blocks_shape(left: Ix2, right: Ix2) -> (usize, usize, usize, usize) {
+    (left[0], right[0], left[1], right[1])
+}
+
+/// Views `gradient` as the (m, p, n, q) tensor described in [`blocks_shape`].
+fn blocks(gradient: &Tensor<Ix2>, left: Ix2, right: Ix2) -> ArrayView4<f32> {
+    gradient
+        .view()
+        .into_shape(blocks_shape(left, right))
+        .unwrap()
+}
+
+/// Computes the gradient of the left operand, of shape `left`, of the Kronecker product. Each of
+/// its entries is the sum of the corresponding block of `gradient` weighted by `right`.
+fn left_gradient(gradient: &Tensor<Ix2>, left: Ix2, right: &Tensor<Ix2>) -> Tensor<Ix2> {
+    let blocks = blocks(gradient, left, right.raw_dim());
+
+    Tensor::from_shape_fn(left, |(i, j)| {
+        Zip::from(blocks.slice(s![i, .., j, ..]))
+            .and(right)
+            .fold(0., |acc, g, r| acc + g * r)
+    })
+}
+
+/// Computes the gradient of the right operand, of shape `right`, of the Kronecker product. Each
+/// of its entries is the sum of the corresponding entry of every block of `gradient` weighted by
+/// `left`.
+fn right_gradient(gradient: &Tensor<Ix2>, left: &Tensor<Ix2>, right: Ix2) -> Tensor<Ix2> {
+    let blocks = blocks(gradient, left.raw_dim(), right);
+
+    Tensor::from_shape_fn(right, |(k, l)| {
+        Zip::from(blocks.slice(s![.., k, .., l]))
+            .and(left)
+            .fold(0., |acc, g, l| acc + g * l)
+    })
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Kron ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct Kron<Lhs: ?Sized, Rhs: ?Sized>
+where
+    Lhs: Data<Dim = Ix2>,
+    Rhs: Data<Dim = Ix2>,
+{
+    left: Rc<Lhs>,
+    right: Rc<Rhs>,
+    data: RefCell<Tensor<Ix2>>,
+    computed: Cell<bool>,
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Kron<Lhs, Rhs>
+where
+    Lhs: Data<Dim = Ix2>,
+    Rhs: Data<Dim = Ix2>,
+{
+    pub fn new(left: Rc<Lhs>, right: Rc<Rhs>) -> Self {
+        let shape = kron_shape(left.data().raw_dim(), right.data().raw_dim());
+        let data = RefCell::new(Tensor::zeros(shape));
+
+        Self {
+            left,
+            right,
+            data,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Data for Kron<Lhs, Rhs>
+where
+    Lhs: Data<Dim = Ix2>,
+    Rhs: Data<Dim = Ix2>,
+{
+    type Dim = Ix2;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Cache for Kron<Lhs, Rhs>
+where
+    Lhs: Data<Dim = Ix2>,
+    Rhs: Data<Dim = Ix2>,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Forward for Kron<Lhs, Rhs>
+where
+    Lhs: Data<Dim = Ix2>,
+    Rhs: Data<Dim = Ix2>,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let (left, right) = (self.left.data(), self.right.data());
+        let mut data = self.data.borrow_mut();
+        let mut blocks = data
+            .view_mut()
+            .into_shape(blocks_shape(left.raw_dim(), right.raw_dim()))
+            .unwrap();
+        Zip::from(&mut blocks)
+            .and_broadcast(&left.slice(s![.., NewAxis, .., NewAxis]))
+            .and_broadcast(&right.slice(s![NewAxis, .., NewAxis, ..]))
+            .for_each(|v, l, r| *v = l * r);
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Debug for Kron<Lhs, Rhs>
+where
+    Lhs: Data<Dim = Ix2>,
+    Rhs: Data<Dim = Ix2>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Kron")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Display for Kron<Lhs, Rhs>
+where
+    Lhs: Data<Dim = Ix2>,
+    Rhs: Data<Dim = Ix2>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ KronBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct KronBackward<LhsD: ?Sized, LhsG: ?Sized, RhsD: ?Sized, RhsG: ?Sized>
+where
+    LhsD: Data<Dim = Ix2>,
+    RhsD: Data<Dim = Ix2>,
+    LhsG: Gradient<Dim = Ix2> + Overwrite,
+    RhsG: Gradient<Dim = Ix2> + Overwrite,
+{
+    gradient: RefCell<Option<Tensor<Ix2>>>,
+    shape: Ix2,
+    overwrite: Cell<bool>,
+    left_data: Rc<LhsD>,
+    left_grad: Rc<LhsG>,
+    right_data: Rc<RhsD>,
+    right_grad: Rc<RhsG>,
+}
+
+impl<LhsD: ?Sized, LhsG: ?Sized, RhsD: ?Sized, RhsG: ?Sized> KronBackward<LhsD, LhsG, RhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix2>,
+    RhsD: Data<Dim = Ix2>,
+    LhsG: Gradient<Dim = Ix2> + Overwrite,
+    RhsG: Gradient<Dim = Ix2> + Overwrite,
+{
+    pub fn new(
+        left_data: Rc<LhsD>,
+        left_grad: Rc<LhsG>,
+        right_data: Rc<RhsD>,
+        right_grad: Rc<RhsG>,
+    ) -> Self {
+        let shape = kron_shape(
+            left_grad.gradient().raw_dim(),
+            right_grad.gradient().raw_dim(),
+        );
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape))),
+            shape,
+            overwrite: Cell::new(true),
+            left_data,
+            left_grad,
+            right_data,
+            right_grad,
+        }
+    }
+}
+
+impl<LhsD: ?Sized, LhsG: ?Sized, RhsD: ?Sized, RhsG: ?Sized> Gradient
+    for KronBackward<LhsD, LhsG, RhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix2>,
+    RhsD: Data<Dim = Ix2>,
+    LhsG: Gradient<Dim = Ix2> + Overwrite,
+    RhsG: Gradient<Dim = Ix2> + Overwrite,
+{
+    type Dim = Ix2;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<LhsD: ?Sized, LhsG: ?Sized, RhsD: ?Sized, RhsG: ?Sized> Overwrite
+    for KronBackward<LhsD, LhsG, RhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix2>,
+    RhsD: Data<Dim = Ix2>,
+    LhsG: Gradient<Dim = Ix2> + Overwrite,
+    RhsG: Gradient<Dim = Ix2> + Overwrite,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<LhsD: ?Sized, LhsG: ?Sized, RhsD: ?Sized, RhsG: ?Sized> Backward
+    for KronBackward<LhsD, LhsG, RhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix2>,
+    RhsD: Data<Dim = Ix2>,
+    LhsG: Gradient<Dim = Ix2> + Overwrite,
+    RhsG: Gradient<Dim = Ix2> + Overwrite,
+{
+    fn backward(&self) {
+        let gradient = self.gradient();
+        let (left_data, right_data) = (self.left_data.data(), self.right_data.data());
+        push_gradient(
+            &*self.left_grad,
+            &left_gradient(&gradient, left_data.raw_dim(), &right_data),
+        );
+        push_gradient(
+            &*self.right_grad,
+            &right_gradient(&gradient, &left_data, right_data.raw_dim()),
+        );
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape));
+    }
+}
+
+impl<LhsD: ?Sized, LhsG: ?Sized, RhsD: ?Sized, RhsG: ?Sized> Debug
+    for KronBackward<LhsD, LhsG, RhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix2>,
+    RhsD: Data<Dim = Ix2>,
+    LhsG: Gradient<Dim = Ix2> + Overwrite,
+    RhsG: Gradient<Dim = Ix2> + Overwrite,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KronBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<LhsD: ?Sized, LhsG: ?Sized, RhsD: ?Sized, RhsG: ?Sized> Display
+    for KronBackward<LhsD, LhsG, RhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix2>,
+    RhsD: Data<Dim = Ix2>,
+    LhsG: Gradient<Dim = Ix2> + Overwrite,
+    RhsG: Gradient<Dim = Ix2> + Overwrite,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ KronBackwardLeft ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct KronBackwardLeft<LhsG: ?Sized, RhsD: ?Sized>
+where
+    RhsD: Data<Dim = Ix2>,
+    LhsG: Gradient<Dim = Ix2> + Overwrite,
+{
+    gradient: RefCell<Option<Tensor<Ix2>>>,
+    shape: Ix2,
+    overwrite: Cell<bool>,
+    left_grad: Rc<LhsG>,
+    right_data: Rc<RhsD>,
+}
+
+impl<LhsG: ?Sized, RhsD: ?Sized> KronBackwardLeft<LhsG, RhsD>
+where
+    RhsD: Data<Dim = Ix2>,
+    LhsG: Gradient<Dim = Ix2> + Overwrite,
+{
+    pub fn new(left_grad: Rc<LhsG>, right_data: Rc<RhsD>) -> Self {
+        let shape = kron_shape(left_grad.gradient().raw_dim(), right_data.data().raw_dim());
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape))),
+            shape,
+            overwrite: Cell::new(true),
+            left_grad,
+            right_data,
+        }
+    }
+}
+
+impl<LhsG: ?Sized, RhsD: ?Sized> Gradient for KronBackwardLeft<LhsG, RhsD>
+where
+    RhsD: Data<Dim = Ix2>,
+    LhsG: Gradient<Dim = Ix2> + Overwrite,
+{
+    type Dim = Ix2;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<LhsG: ?Sized, RhsD: ?Sized> Overwrite for KronBackwardLeft<LhsG, RhsD>
+where
+    RhsD: Data<Dim = Ix2>,
+    LhsG: Gradient<Dim = Ix2> + Overwrite,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<LhsG: ?Sized, RhsD: ?Sized> Backward for KronBackwardLeft<LhsG, RhsD>
+where
+    RhsD: Data<Dim = Ix2>,
+    LhsG: Gradient<Dim = Ix2> + Overwrite,
+{
+    fn backward(&self) {
+        let right_data = self.right_data.data();
+        let left_shape = self.left_grad.gradient().raw_dim();
+        let gradient = left_gradient(&self.gradient(), left_shape, &right_data);
+        push_gradient(&*self.left_grad, &gradient);
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape));
+    }
+}
+
+impl<LhsG: ?Sized, RhsD: ?Sized> Debug for KronBackwardLeft<LhsG, RhsD>
+where
+    RhsD: Data<Dim = Ix2>,
+    LhsG: Gradient<Dim = Ix2> + Overwrite,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KronBackwardLeft")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<LhsG: ?Sized, RhsD: ?Sized> Display for KronBackwardLeft<LhsG, RhsD>
+where
+    RhsD: Data<Dim = Ix2>,
+    LhsG: Gradient<Dim = Ix2> + Overwrite,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ KronBackwardRight ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct KronBackwardRight<LhsD: ?Sized, RhsG: ?Sized>
+where
+    LhsD: Data<Dim = Ix2>,
+    RhsG: Gradient<Dim = Ix2> + Overwrite,
+{
+    gradient: RefCell<Option<Tensor<Ix2>>>,
+    shape: Ix2,
+    overwrite: Cell<bool>,
+    left_data: Rc<LhsD>,
+    right_grad: Rc<RhsG>,
+}
+
+impl<LhsD: ?Sized, RhsG: ?Sized> KronBackwardRight<LhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix2>,
+    RhsG: Gradient<Dim = Ix2> + Overwrite,
+{
+    pub fn new(left_data: Rc<LhsD>, right_grad: Rc<RhsG>) -> Self {
+        let shape = kron_shape(left_data.data().raw_dim(), right_grad.gradient().raw_dim());
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape))),
+            shape,
+            overwrite: Cell::new(true),
+            left_data,
+            right_grad,
+        }
+    }
+}
+
+impl<LhsD: ?Sized, RhsG: ?Sized> Gradient for KronBackwardRight<LhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix2>,
+    RhsG: Gradient<Dim = Ix2> + Overwrite,
+{
+    type Dim = Ix2;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<LhsD: ?Sized, RhsG: ?Sized> Overwrite for KronBackwardRight<LhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix2>,
+    RhsG: Gradient<Dim = Ix2> + Overwrite,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<LhsD: ?Sized, RhsG: ?Sized> Backward for KronBackwardRight<LhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix2>,
+    RhsG: Gradient<Dim = Ix2> + Overwrite,
+{
+    fn backward(&self) {
+        let left_data = self.left_data.data();
+        let right_shape = self.right_grad.gradient().raw_dim();
+        let gradient = right_gradient(&self.gradient(), &left_data, right_shape);
+        push_gradient(&*self.right_grad, &gradient);
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape));
+    }
+}
+
+impl<LhsD: ?Sized, RhsG: ?Sized> Debug for KronBackwardRight<LhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix2>,
+    RhsG: Gradient<Dim = Ix2> + Overwrite,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KronBackwardRight")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<LhsD: ?Sized, RhsG: ?Sized> Display for KronBackwardRight<LhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix2>,
+    RhsG: Gradient<Dim = Ix2> + Overwrite,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
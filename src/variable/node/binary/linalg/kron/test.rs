@@ -0,0 +1,382 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, Kron, KronBackward, KronBackwardLeft, KronBackwardRight, Overwrite, Tensor,
+};
+
+mod forward {
+    use super::{assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, Kron, Tensor};
+
+    #[test]
+    fn creation() {
+        let left = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let right = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Kron::new(left, right);
+
+        assert_eq!(*node.data(), Tensor::from_elem((6, 6), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((6, 6), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let left = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let right = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Kron::new(left, right);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn forward() {
+        let left = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let right = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Kron::new(left, right.clone());
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (6, 6),
+                vec![
+                    1., 2., 2., 4., 3., 6., 3., 4., 6., 8., 9., 12., 5., 6., 10., 12., 15., 18.,
+                    4., 8., 5., 10., 6., 12., 12., 16., 15., 20., 18., 24., 20., 24., 25., 30.,
+                    30., 36.,
+                ],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *right.data_mut() = new_tensor((3, 2), vec![-2.; 6]);
+        assert_almost_equals(&*right.data(), &new_tensor((3, 2), vec![-2.; 6]));
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (6, 6),
+                vec![
+                    1., 2., 2., 4., 3., 6., 3., 4., 6., 8., 9., 12., 5., 6., 10., 12., 15., 18.,
+                    4., 8., 5., 10., 6., 12., 12., 16., 15., 20., 18., 24., 20., 24., 25., 30.,
+                    30., 36.,
+                ],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (6, 6),
+                vec![
+                    -2., -2., -4., -4., -6., -6., -2., -2., -4., -4., -6., -6., -2., -2., -4., -4.,
+                    -6., -6., -8., -8., -10., -10., -12., -12., -8., -8., -10., -10., -12., -12.,
+                    -8., -8., -10., -10., -12., -12.,
+                ],
+            ),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let left = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let right = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Kron::new(left, right);
+
+        let output = "Kron { data: [[0.0, 0.0, 0.0, 0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0, 0.0, 0.0, 0.0]], shape=[6, 6], strides=[6, 1], layout=Cc (0x5), const ndim=2, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let left = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let right = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Kron::new(left, right);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Gradient,
+        KronBackward, KronBackwardLeft, KronBackwardRight, Overwrite, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = KronBackward::new(
+            new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]),
+            new_backward_input((2, 3), vec![0.; 6]),
+            new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+            new_backward_input((3, 2), vec![0.; 6]),
+        );
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((6, 6), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((6, 6), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let lhs = new_backward_input((2, 3), vec![0.; 6]);
+        let rhs = new_backward_input((3, 2), vec![0.; 6]);
+        let node = KronBackward::new(
+            new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]),
+            lhs.clone(),
+            new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+            rhs.clone(),
+        );
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+
+        lhs.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+
+        lhs.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+
+        rhs.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(rhs.can_overwrite());
+
+        rhs.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(rhs.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(rhs.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(rhs.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        let lhs = new_backward_input((2, 3), vec![0.; 6]);
+        let rhs = new_backward_input((3, 2), vec![0.; 6]);
+        let node = KronBackward::new(
+            new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]),
+            lhs.clone(),
+            new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+            rhs.clone(),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((6, 6), vec![1.; 36]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((6, 6), vec![1.; 36]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(&*lhs.gradient(), &new_tensor((2, 3), vec![21.; 6]));
+        assert_almost_equals(&*rhs.gradient(), &new_tensor((3, 2), vec![21.; 6]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(&*lhs.gradient(), &new_tensor((2, 3), vec![42.; 6]));
+        assert_almost_equals(&*rhs.gradient(), &new_tensor((3, 2), vec![42.; 6]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        lhs.set_overwrite(true);
+        rhs.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(&*lhs.gradient(), &new_tensor((2, 3), vec![21.; 6]));
+        assert_almost_equals(&*rhs.gradient(), &new_tensor((3, 2), vec![21.; 6]));
+    }
+
+    #[test]
+    fn debug() {
+        let node = KronBackward::new(
+            new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]),
+            new_backward_input((2, 3), vec![0.; 6]),
+            new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+            new_backward_input((3, 2), vec![0.; 6]),
+        );
+
+        let output = "KronBackward { gradient: Some([[0.0, 0.0, 0.0, 0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0, 0.0, 0.0, 0.0]], shape=[6, 6], strides=[6, 1], layout=Cc (0x5), const ndim=2), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let node = KronBackward::new(
+            new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]),
+            new_backward_input((2, 3), vec![0.; 6]),
+            new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+            new_backward_input((3, 2), vec![0.; 6]),
+        );
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn backward_left() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = KronBackwardLeft::new(
+            diff.clone(),
+            new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((6, 6), vec![1.; 36]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((6, 6), vec![1.; 36]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor((2, 3), vec![21.; 6]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor((2, 3), vec![42.; 6]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor((2, 3), vec![21.; 6]));
+    }
+
+    #[test]
+    fn debug_left() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = KronBackwardLeft::new(diff, new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]));
+
+        let output = "KronBackwardLeft { gradient: Some([[0.0, 0.0, 0.0, 0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0, 0.0, 0.0, 0.0]], shape=[6, 6], strides=[6, 1], layout=Cc (0x5), const ndim=2), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display_left() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = KronBackwardLeft::new(diff, new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]));
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn backward_right() {
+        let diff = new_backward_input((3, 2), vec![0.; 6]);
+        let node = KronBackwardRight::new(
+            new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]),
+            diff.clone(),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((6, 6), vec![1.; 36]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((6, 6), vec![1.; 36]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor((3, 2), vec![21.; 6]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor((3, 2), vec![42.; 6]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor((3, 2), vec![21.; 6]));
+    }
+
+    #[test]
+    fn debug_right() {
+        let diff = new_backward_input((3, 2), vec![0.; 6]);
+        let node = KronBackwardRight::new(new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]), diff);
+
+        let output = "KronBackwardRight { gradient: Some([[0.0, 0.0, 0.0, 0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0, 0.0, 0.0, 0.0]], shape=[6, 6], strides=[6, 1], layout=Cc (0x5), const ndim=2), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display_right() {
+        let diff = new_backward_input((3, 2), vec![0.; 6]);
+        let node = KronBackwardRight::new(new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]), diff);
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // KronBackward
+        let node = KronBackward::new(
+            new_input((2, 3), vec![0.; 6]),
+            new_backward_input((2, 3), vec![0.; 6]),
+            new_input((3, 2), vec![0.; 6]),
+            new_backward_input((3, 2), vec![0.; 6]),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+
+        // KronBackwardLeft
+        let node = KronBackwardLeft::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            new_input((3, 2), vec![0.; 6]),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+
+        // KronBackwardRight
+        let node = KronBackwardRight::new(
+            new_input((2, 3), vec![0.; 6]),
+            new_backward_input((3, 2), vec![0.; 6]),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
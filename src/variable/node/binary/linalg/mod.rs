@@ -1,18 +1,25 @@
+mod batched_mat_mul;
+mod kron;
 mod matrix_matrix_mul;
 mod matrix_matrix_mul_t;
 mod matrix_vector_mul;
+mod outer;
 mod vector_matrix_mul;
 mod vector_vector_mul;
 
 use super::{
-    expect_tensor, expect_tensor_mut, push_mat_mat_gradient, push_mat_vec_gradient,
+    expect_tensor, expect_tensor_mut, push_gradient, push_mat_mat_gradient, push_mat_vec_gradient,
     push_vec_mat_gradient, push_vec_vec_gradient, Backward, Cache, Data, DotDim, Forward, Gradient,
-    Overwrite, Tensor,
+    Overwrite, Summary, Tensor,
 };
 
 #[cfg(test)]
 use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
 
+pub(crate) use batched_mat_mul::{
+    BatchedMatMul, BatchedMatMulBackward, BatchedMatMulBackwardLeft, BatchedMatMulBackwardRight,
+};
+pub(crate) use kron::{Kron, KronBackward, KronBackwardLeft, KronBackwardRight};
 pub(crate) use matrix_matrix_mul::{
     MatrixMatrixMul, MatrixMatrixMulBackward, MatrixMatrixMulBackwardLeft,
     MatrixMatrixMulBackwardRight,
@@ -26,6 +33,7 @@ pub(crate) use matrix_vector_mul::{
     MatrixVectorMul, MatrixVectorMulBackward, MatrixVectorMulBackwardLeft,
     MatrixVectorMulBackwardRight,
 };
+pub(crate) use outer::{Outer, OuterBackward, OuterBackwardLeft, OuterBackwardRight};
 pub(crate) use vector_matrix_mul::{
     VectorMatrixMul, VectorMatrixMulBackward, VectorMatrixMulBackwardLeft,
     VectorMatrixMulBackwardRight,
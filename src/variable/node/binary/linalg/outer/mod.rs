@@ -0,0 +1,476 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, push_vec_mat_gradient, Backward, Cache, Data, Forward,
+    Gradient, Overwrite, Summary, Tensor,
+};
+use ndarray::{s, Ix1, Ix2, NewAxis, Zip};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Outer ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct Outer<Lhs: ?Sized, Rhs: ?Sized>
+where
+    Lhs: Data<Dim = Ix1>,
+    Rhs: Data<Dim = Ix1>,
+{
+    left: Rc<Lhs>,
+    right: Rc<Rhs>,
+    data: RefCell<Tensor<Ix2>>,
+    computed: Cell<bool>,
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Outer<Lhs, Rhs>
+where
+    Lhs: Data<Dim = Ix1>,
+    Rhs: Data<Dim = Ix1>,
+{
+    pub fn new(left: Rc<Lhs>, right: Rc<Rhs>) -> Self {
+        let shape = (left.data().len(), right.data().len());
+        let data = RefCell::new(Tensor::zeros(shape));
+
+        Self {
+            left,
+            right,
+            data,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Data for Outer<Lhs, Rhs>
+where
+    Lhs: Data<Dim = Ix1>,
+    Rhs: Data<Dim = Ix1>,
+{
+    type Dim = Ix2;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Cache for Outer<Lhs, Rhs>
+where
+    Lhs: Data<Dim = Ix1>,
+    Rhs: Data<Dim = Ix1>,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Forward for Outer<Lhs, Rhs>
+where
+    Lhs: Data<Dim = Ix1>,
+    Rhs: Data<Dim = Ix1>,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        Zip::from(&mut *self.data.borrow_mut())
+            .and_broadcast(&self.left.data().slice(s![.., NewAxis]))
+            .and_broadcast(&*self.right.data())
+            .for_each(|v, l, r| *v = l * r);
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Debug for Outer<Lhs, Rhs>
+where
+    Lhs: Data<Dim = Ix1>,
+    Rhs: Data<Dim = Ix1>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Outer")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Display for Outer<Lhs, Rhs>
+where
+    Lhs: Data<Dim = Ix1>,
+    Rhs: Data<Dim = Ix1>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ OuterBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct OuterBackward<LhsD: ?Sized, LhsG: ?Sized, RhsD: ?Sized, RhsG: ?Sized>
+where
+    LhsD: Data<Dim = Ix1>,
+    RhsD: Data<Dim = Ix1>,
+    LhsG: Gradient<Dim = Ix1> + Overwrite,
+    RhsG: Gradient<Dim = Ix1> + Overwrite,
+{
+    gradient: RefCell<Option<Tensor<Ix2>>>,
+    shape: Ix2,
+    overwrite: Cell<bool>,
+    left_data: Rc<LhsD>,
+    left_grad: Rc<LhsG>,
+    right_data: Rc<RhsD>,
+    right_grad: Rc<RhsG>,
+}
+
+impl<LhsD: ?Sized, LhsG: ?Sized, RhsD: ?Sized, RhsG: ?Sized> OuterBackward<LhsD, LhsG, RhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix1>,
+    RhsD: Data<Dim = Ix1>,
+    LhsG: Gradient<Dim = Ix1> + Overwrite,
+    RhsG: Gradient<Dim = Ix1> + Overwrite,
+{
+    pub fn new(
+        left_data: Rc<LhsD>,
+        left_grad: Rc<LhsG>,
+        right_data: Rc<RhsD>,
+        right_grad: Rc<RhsG>,
+    ) -> Self {
+        let shape = Ix2(left_grad.gradient().len(), right_grad.gradient().len());
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape))),
+            shape,
+            overwrite: Cell::new(true),
+            left_data,
+            left_grad,
+            right_data,
+            right_grad,
+        }
+    }
+}
+
+impl<LhsD: ?Sized, LhsG: ?Sized, RhsD: ?Sized, RhsG: ?Sized> Gradient
+    for OuterBackward<LhsD, LhsG, RhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix1>,
+    RhsD: Data<Dim = Ix1>,
+    LhsG: Gradient<Dim = Ix1> + Overwrite,
+    RhsG: Gradient<Dim = Ix1> + Overwrite,
+{
+    type Dim = Ix2;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<LhsD: ?Sized, LhsG: ?Sized, RhsD: ?Sized, RhsG: ?Sized> Overwrite
+    for OuterBackward<LhsD, LhsG, RhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix1>,
+    RhsD: Data<Dim = Ix1>,
+    LhsG: Gradient<Dim = Ix1> + Overwrite,
+    RhsG: Gradient<Dim = Ix1> + Overwrite,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<LhsD: ?Sized, LhsG: ?Sized, RhsD: ?Sized, RhsG: ?Sized> Backward
+    for OuterBackward<LhsD, LhsG, RhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix1>,
+    RhsD: Data<Dim = Ix1>,
+    LhsG: Gradient<Dim = Ix1> + Overwrite,
+    RhsG: Gradient<Dim = Ix1> + Overwrite,
+{
+    fn backward(&self) {
+        let gradient = self.gradient();
+        push_vec_mat_gradient(&*self.left_grad, &gradient, &self.right_data.data());
+        push_vec_mat_gradient(&*self.right_grad, &gradient.t(), &self.left_data.data());
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape));
+    }
+}
+
+impl<LhsD: ?Sized, LhsG: ?Sized, RhsD: ?Sized, RhsG: ?Sized> Debug
+    for OuterBackward<LhsD, LhsG, RhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix1>,
+    RhsD: Data<Dim = Ix1>,
+    LhsG: Gradient<Dim = Ix1> + Overwrite,
+    RhsG: Gradient<Dim = Ix1> + Overwrite,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OuterBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<LhsD: ?Sized, LhsG: ?Sized, RhsD: ?Sized, RhsG: ?Sized> Display
+    for OuterBackward<LhsD, LhsG, RhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix1>,
+    RhsD: Data<Dim = Ix1>,
+    LhsG: Gradient<Dim = Ix1> + Overwrite,
+    RhsG: Gradient<Dim = Ix1> + Overwrite,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ OuterBackwardLeft ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct OuterBackwardLeft<LhsG: ?Sized, RhsD: ?Sized>
+where
+    RhsD: Data<Dim = Ix1>,
+    LhsG: Gradient<Dim = Ix1> + Overwrite,
+{
+    gradient: RefCell<Option<Tensor<Ix2>>>,
+    shape: Ix2,
+    overwrite: Cell<bool>,
+    left_grad: Rc<LhsG>,
+    right_data: Rc<RhsD>,
+}
+
+impl<LhsG: ?Sized, RhsD: ?Sized> OuterBackwardLeft<LhsG, RhsD>
+where
+    RhsD: Data<Dim = Ix1>,
+    LhsG: Gradient<Dim = Ix1> + Overwrite,
+{
+    pub fn new(left_grad: Rc<LhsG>, right_data: Rc<RhsD>) -> Self {
+        let shape = Ix2(left_grad.gradient().len(), right_data.data().len());
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape))),
+            shape,
+            overwrite: Cell::new(true),
+            left_grad,
+            right_data,
+        }
+    }
+}
+
+impl<LhsG: ?Sized, RhsD: ?Sized> Gradient for OuterBackwardLeft<LhsG, RhsD>
+where
+    RhsD: Data<Dim = Ix1>,
+    LhsG: Gradient<Dim = Ix1> + Overwrite,
+{
+    type Dim = Ix2;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<LhsG: ?Sized, RhsD: ?Sized> Overwrite for OuterBackwardLeft<LhsG, RhsD>
+where
+    RhsD: Data<Dim = Ix1>,
+    LhsG: Gradient<Dim = Ix1> + Overwrite,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<LhsG: ?Sized, RhsD: ?Sized> Backward for OuterBackwardLeft<LhsG, RhsD>
+where
+    RhsD: Data<Dim = Ix1>,
+    LhsG: Gradient<Dim = Ix1> + Overwrite,
+{
+    fn backward(&self) {
+        push_vec_mat_gradient(&*self.left_grad, &self.gradient(), &self.right_data.data());
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape));
+    }
+}
+
+impl<LhsG: ?Sized, RhsD: ?Sized> Debug for OuterBackwardLeft<LhsG, RhsD>
+where
+    RhsD: Data<Dim = Ix1>,
+    LhsG: Gradient<Dim = Ix1> + Overwrite,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OuterBackwardLeft")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<LhsG: ?Sized, RhsD: ?Sized> Display for OuterBackwardLeft<LhsG, RhsD>
+where
+    RhsD: Data<Dim = Ix1>,
+    LhsG: Gradient<Dim = Ix1> + Overwrite,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ OuterBackwardRight ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct OuterBackwardRight<LhsD: ?Sized, RhsG: ?Sized>
+where
+    LhsD: Data<Dim = Ix1>,
+    RhsG: Gradient<Dim = Ix1> + Overwrite,
+{
+    gradient: RefCell<Option<Tensor<Ix2>>>,
+    shape: Ix2,
+    overwrite: Cell<bool>,
+    left_data: Rc<LhsD>,
+    right_grad: Rc<RhsG>,
+}
+
+impl<LhsD: ?Sized, RhsG: ?Sized> OuterBackwardRight<LhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix1>,
+    RhsG: Gradient<Dim = Ix1> + Overwrite,
+{
+    pub fn new(left_data: Rc<LhsD>, right_grad: Rc<RhsG>) -> Self {
+        let shape = Ix2(left_data.data().len(), right_grad.gradient().len());
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape))),
+            shape,
+            overwrite: Cell::new(true),
+            left_data,
+            right_grad,
+        }
+    }
+}
+
+impl<LhsD: ?Sized, RhsG: ?Sized> Gradient for OuterBackwardRight<LhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix1>,
+    RhsG: Gradient<Dim = Ix1> + Overwrite,
+{
+    type Dim = Ix2;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<LhsD: ?Sized, RhsG: ?Sized> Overwrite for OuterBackwardRight<LhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix1>,
+    RhsG: Gradient<Dim = Ix1> + Overwrite,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<LhsD: ?Sized, RhsG: ?Sized> Backward for OuterBackwardRight<LhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix1>,
+    RhsG: Gradient<Dim = Ix1> + Overwrite,
+{
+    fn backward(&self) {
+        push_vec_mat_gradient(
+            &*self.right_grad,
+            &self.gradient().t(),
+            &self.left_data.data(),
+        );
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape));
+    }
+}
+
+impl<LhsD: ?Sized, RhsG: ?Sized> Debug for OuterBackwardRight<LhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix1>,
+    RhsG: Gradient<Dim = Ix1> + Overwrite,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OuterBackwardRight")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<LhsD: ?Sized, RhsG: ?Sized> Display for OuterBackwardRight<LhsD, RhsG>
+where
+    LhsD: Data<Dim = Ix1>,
+    RhsG: Gradient<Dim = Ix1> + Overwrite,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
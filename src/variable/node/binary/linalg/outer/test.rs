@@ -0,0 +1,356 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, Outer, OuterBackward, OuterBackwardLeft, OuterBackwardRight, Overwrite,
+    Tensor,
+};
+
+mod forward {
+    use super::{assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, Outer, Tensor};
+
+    #[test]
+    fn creation() {
+        let left = new_input(3, vec![1., 2., 3.]);
+        let right = new_input(2, vec![1., 2.]);
+        let node = Outer::new(left, right);
+
+        assert_eq!(*node.data(), Tensor::from_elem((3, 2), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((3, 2), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let left = new_input(3, vec![1., 2., 3.]);
+        let right = new_input(2, vec![1., 2.]);
+        let node = Outer::new(left, right);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn forward() {
+        let left = new_input(3, vec![1., 2., 3.]);
+        let right = new_input(2, vec![1., 2.]);
+        let node = Outer::new(left, right.clone());
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 2), vec![1., 2., 2., 4., 3., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *right.data_mut() = new_tensor(2, vec![-2.; 2]);
+        assert_almost_equals(&*right.data(), &new_tensor(2, vec![-2.; 2]));
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 2), vec![1., 2., 2., 4., 3., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 2), vec![-2., -2., -4., -4., -6., -6.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let left = new_input(3, vec![1., 2., 3.]);
+        let right = new_input(2, vec![1., 2.]);
+        let node = Outer::new(left, right);
+
+        let output = "Outer { data: [[0.0, 0.0],\n [0.0, 0.0],\n [0.0, 0.0]], shape=[3, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let left = new_input(3, vec![1., 2., 3.]);
+        let right = new_input(2, vec![1., 2.]);
+        let node = Outer::new(left, right);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Gradient,
+        OuterBackward, OuterBackwardLeft, OuterBackwardRight, Overwrite, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = OuterBackward::new(
+            new_input(3, vec![1., 2., 3.]),
+            new_backward_input(3, vec![0.; 3]),
+            new_input(2, vec![1., 2.]),
+            new_backward_input(2, vec![0.; 2]),
+        );
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((3, 2), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((3, 2), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let lhs = new_backward_input(3, vec![0.; 3]);
+        let rhs = new_backward_input(2, vec![0.; 2]);
+        let node = OuterBackward::new(
+            new_input(3, vec![1., 2., 3.]),
+            lhs.clone(),
+            new_input(2, vec![1., 2.]),
+            rhs.clone(),
+        );
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+
+        lhs.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+
+        lhs.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+
+        rhs.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(rhs.can_overwrite());
+
+        rhs.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(rhs.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(rhs.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(rhs.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        let lhs = new_backward_input(3, vec![0.; 3]);
+        let rhs = new_backward_input(2, vec![0.; 2]);
+        let node = OuterBackward::new(
+            new_input(3, vec![1., 2., 3.]),
+            lhs.clone(),
+            new_input(2, vec![1., 2.]),
+            rhs.clone(),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((3, 2), vec![1.; 6]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((3, 2), vec![1.; 6]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(&*lhs.gradient(), &new_tensor(3, vec![3.; 3]));
+        assert_almost_equals(&*rhs.gradient(), &new_tensor(2, vec![6.; 2]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(&*lhs.gradient(), &new_tensor(3, vec![6.; 3]));
+        assert_almost_equals(&*rhs.gradient(), &new_tensor(2, vec![12.; 2]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        lhs.set_overwrite(true);
+        rhs.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(&*lhs.gradient(), &new_tensor(3, vec![3.; 3]));
+        assert_almost_equals(&*rhs.gradient(), &new_tensor(2, vec![6.; 2]));
+    }
+
+    #[test]
+    fn debug() {
+        let node = OuterBackward::new(
+            new_input(3, vec![1., 2., 3.]),
+            new_backward_input(3, vec![0.; 3]),
+            new_input(2, vec![1., 2.]),
+            new_backward_input(2, vec![0.; 2]),
+        );
+
+        let output = "OuterBackward { gradient: Some([[0.0, 0.0],\n [0.0, 0.0],\n [0.0, 0.0]], shape=[3, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let node = OuterBackward::new(
+            new_input(3, vec![1., 2., 3.]),
+            new_backward_input(3, vec![0.; 3]),
+            new_input(2, vec![1., 2.]),
+            new_backward_input(2, vec![0.; 2]),
+        );
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn backward_left() {
+        let diff = new_backward_input(3, vec![0.; 3]);
+        let node = OuterBackwardLeft::new(diff.clone(), new_input(2, vec![1., 2.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((3, 2), vec![1.; 6]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((3, 2), vec![1.; 6]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor(3, vec![3.; 3]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor(3, vec![6.; 3]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor(3, vec![3.; 3]));
+    }
+
+    #[test]
+    fn debug_left() {
+        let diff = new_backward_input(3, vec![0.; 3]);
+        let node = OuterBackwardLeft::new(diff, new_input(2, vec![1., 2.]));
+
+        let output = "OuterBackwardLeft { gradient: Some([[0.0, 0.0],\n [0.0, 0.0],\n [0.0, 0.0]], shape=[3, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display_left() {
+        let diff = new_backward_input(3, vec![0.; 3]);
+        let node = OuterBackwardLeft::new(diff, new_input(2, vec![1., 2.]));
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn backward_right() {
+        let diff = new_backward_input(2, vec![0.; 2]);
+        let node = OuterBackwardRight::new(new_input(3, vec![1., 2., 3.]), diff.clone());
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((3, 2), vec![1.; 6]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((3, 2), vec![1.; 6]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor(2, vec![6.; 2]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor(2, vec![12.; 2]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor(2, vec![6.; 2]));
+    }
+
+    #[test]
+    fn debug_right() {
+        let diff = new_backward_input(2, vec![0.; 2]);
+        let node = OuterBackwardRight::new(new_input(3, vec![1., 2., 3.]), diff);
+
+        let output = "OuterBackwardRight { gradient: Some([[0.0, 0.0],\n [0.0, 0.0],\n [0.0, 0.0]], shape=[3, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display_right() {
+        let diff = new_backward_input(2, vec![0.; 2]);
+        let node = OuterBackwardRight::new(new_input(3, vec![1., 2., 3.]), diff);
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // OuterBackward
+        let node = OuterBackward::new(
+            new_input(3, vec![0.; 3]),
+            new_backward_input(3, vec![0.; 3]),
+            new_input(2, vec![0.; 2]),
+            new_backward_input(2, vec![0.; 2]),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+
+        // OuterBackwardLeft
+        let node = OuterBackwardLeft::new(
+            new_backward_input(3, vec![0.; 3]),
+            new_input(2, vec![0.; 2]),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+
+        // OuterBackwardRight
+        let node = OuterBackwardRight::new(
+            new_input(3, vec![0.; 3]),
+            new_backward_input(2, vec![0.; 2]),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
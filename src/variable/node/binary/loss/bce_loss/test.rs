@@ -12,14 +12,14 @@ fn mean() {
     let loss = BCELoss::new(input.clone(), target.clone(), Reduction::Mean);
 
     loss.forward();
-    assert_almost_equals(&*loss.data(), &arr0(22.9244));
+    assert_almost_equals(&*loss.data(), &arr0(22.9244).into_dyn());
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     let input_diff = new_backward_input((3, 3), vec![0.; 9]);
     let loss_backward = BCELossBackward::new(input_diff.clone(), input, target, Reduction::Mean);
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-    *loss_backward.gradient_mut() = arr0(1.);
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     loss_backward.backward();
@@ -70,7 +70,7 @@ fn sum() {
     let loss = BCELoss::new(input.clone(), target.clone(), Reduction::Sum);
 
     loss.forward();
-    assert_almost_equals(&*loss.data(), &arr0(206.3199));
+    assert_almost_equals(&*loss.data(), &arr0(206.3199).into_dyn());
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
@@ -78,7 +78,7 @@ fn sum() {
     let loss_backward = BCELossBackward::new(input_diff.clone(), input, target, Reduction::Sum);
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-    *loss_backward.gradient_mut() = arr0(1.);
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     loss_backward.backward();
@@ -135,7 +135,7 @@ fn no_grad() {
     assert!(node.gradient.borrow().is_none());
 
     node.with_grad();
-    assert_eq!(&*node.gradient(), arr0(0.));
+    assert_eq!(&*node.gradient(), arr0(0.).into_dyn());
 }
 
 #[test]
@@ -144,7 +144,7 @@ fn debug_forward() {
     let input = new_input((3, 3), vec![0.1, 0.9, 0.9, 0., 0., 0., 0.8, 0., 0.]);
     let loss = BCELoss::new(input.clone(), target.clone(), Reduction::Mean);
 
-    let output = "BCELoss { data: 0.0, shape=[], strides=[], layout=CFcf (0xf), const ndim=0, reduction: Mean, computed: false }";
+    let output = "BCELoss { data: 0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0, reduction: Mean, computed: false }";
 
     assert_eq!(output, format!("{:?}", loss));
 }
@@ -167,7 +167,7 @@ fn debug_backward() {
         Reduction::Mean,
     );
 
-    let output = "BCELossBackward { gradient: Some(0.0, shape=[], strides=[], layout=CFcf (0xf), const ndim=0), reduction: Mean, overwrite: true }";
+    let output = "BCELossBackward { gradient: Some(0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0), reduction: Mean, overwrite: true }";
 
     assert_eq!(output, format!("{:?}", loss));
 }
@@ -2,9 +2,9 @@
 use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
 use super::{
     expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite,
-    Reduction, Tensor,
+    Reduction, Summary, Tensor,
 };
-use ndarray::{arr0, Ix0, Zip};
+use ndarray::{arr0, ArrayD, Dimension, IxDyn, Zip};
 use std::{
     cell::{Cell, Ref, RefCell, RefMut},
     fmt::{Debug, Display},
@@ -22,7 +22,8 @@ where
 {
     input: Rc<T>,
     target: Rc<U>,
-    data: RefCell<Tensor<Ix0>>,
+    data: RefCell<Tensor<IxDyn>>,
+    pos_weight: Option<Tensor<T::Dim>>,
     reduction: Reduction,
     computed: Cell<bool>,
 }
@@ -32,11 +33,22 @@ where
     T: Data,
     U: Data<Dim = T::Dim>,
 {
-    pub(crate) fn new(input: Rc<T>, target: Rc<U>, reduction: Reduction) -> Self {
+    pub(crate) fn new(
+        input: Rc<T>,
+        target: Rc<U>,
+        pos_weight: Option<ArrayD<f32>>,
+        reduction: Reduction,
+    ) -> Self {
+        let pos_weight =
+            pos_weight.map(|pos_weight| broadcast_pos_weight(&pos_weight, input.data().raw_dim()));
+
+        let data = Tensor::zeros(reduction.shape(input.data().raw_dim()));
+
         Self {
             input,
             target,
-            data: RefCell::new(arr0(0.)),
+            data: RefCell::new(data),
+            pos_weight,
             reduction,
             computed: Cell::new(false),
         }
@@ -48,7 +60,7 @@ where
     T: Data,
     U: Data<Dim = T::Dim>,
 {
-    type Dim = Ix0;
+    type Dim = IxDyn;
 
     fn data(&self) -> Ref<Tensor<Self::Dim>> {
         self.data.borrow()
@@ -91,21 +103,16 @@ where
                 self.target.data(),
             )
         };
-        *loss_data = {
-            let total_loss =
-                Zip::from(&*input_data)
-                    .and(&*target_data)
-                    .fold(0.0, |loss, input, target| {
-                        let max = (-input).max(0.);
-                        loss + (1. - target) * input
-                            + max
-                            + ((-max).exp() + (-input - max).exp()).ln()
-                    });
-            match self.reduction {
-                Reduction::Mean => arr0(total_loss / input_data.len() as f32),
-                Reduction::Sum => arr0(total_loss),
-            }
-        };
+        let one = arr0(1.);
+        let pos_weight = self.pos_weight.as_ref().map_or_else(
+            || one.broadcast(input_data.raw_dim()).unwrap(),
+            Tensor::view,
+        );
+        let loss = Zip::from(&*input_data)
+            .and(&*target_data)
+            .and(&pos_weight)
+            .map_collect(|input, target, pos_weight| logit_loss(*input, *target, *pos_weight));
+        *loss_data = self.reduction.reduce(loss, input_data.len() as f32);
     }
 }
 
@@ -116,7 +123,8 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("BCEWithLogitsLoss")
-            .field("data", &self.data.borrow())
+            .field("data", &Summary(&self.data.borrow()))
+            .field("pos_weight", &self.pos_weight.as_ref().map(Summary))
             .field("reduction", &self.reduction)
             .field("computed", &self.computed.get())
             .finish()
@@ -129,7 +137,7 @@ where
     U: Data<Dim = T::Dim>,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", &self.data.borrow())
+        write!(f, "{}", Summary(&self.data.borrow()))
     }
 }
 
@@ -143,11 +151,12 @@ where
     U: Data<Dim = T::Dim>,
     V: Data<Dim = T::Dim>,
 {
-    gradient: RefCell<Option<Tensor<Ix0>>>,
+    gradient: RefCell<Option<Tensor<IxDyn>>>,
     overwrite: Cell<bool>,
     diff_input: Rc<T>,
     input: Rc<U>,
     target: Rc<V>,
+    pos_weight: Option<Tensor<T::Dim>>,
     reduction: Reduction,
 }
 
@@ -161,13 +170,20 @@ where
         diff_input: Rc<T>,
         input: Rc<U>,
         target: Rc<V>,
+        pos_weight: Option<ArrayD<f32>>,
         reduction: Reduction,
     ) -> Self {
+        let pos_weight = pos_weight
+            .map(|pos_weight| broadcast_pos_weight(&pos_weight, diff_input.gradient().raw_dim()));
+
+        let gradient = Tensor::zeros(reduction.shape(input.data().raw_dim()));
+
         Self {
             diff_input,
             input,
             target,
-            gradient: RefCell::new(Some(arr0(0.))),
+            gradient: RefCell::new(Some(gradient)),
+            pos_weight,
             reduction,
             overwrite: Cell::new(true),
         }
@@ -180,7 +196,7 @@ where
     U: Data<Dim = T::Dim>,
     V: Data<Dim = T::Dim>,
 {
-    type Dim = Ix0;
+    type Dim = IxDyn;
 
     fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
         expect_tensor(&self.gradient)
@@ -222,41 +238,27 @@ where
             )
         };
 
+        let one = arr0(1.);
+        let pos_weight = self.pos_weight.as_ref().map_or_else(
+            || one.broadcast(input_data.raw_dim()).unwrap(),
+            Tensor::view,
+        );
+        let n = self.reduction.scale(input_data.len() as f32);
         let zip = Zip::from(&mut *operand_gradient)
             .and_broadcast(&*gradient)
             .and(&*input_data)
-            .and(&*target_data);
-
-        match self.reduction {
-            Reduction::Mean => {
-                let n = input_data.len() as f32;
-                if self.diff_input.can_overwrite() {
-                    zip.for_each(|op_grad, grad, input, target| {
-                        let input_sigmoid = 1.0 / (1.0 + (-input).exp());
-                        *op_grad = (input_sigmoid - target) * grad / n
-                    });
-                    self.diff_input.set_overwrite(false);
-                } else {
-                    zip.for_each(|op_grad, grad, input, target| {
-                        let input_sigmoid = 1.0 / (1.0 + (-input).exp());
-                        *op_grad += (input_sigmoid - target) * grad / n
-                    });
-                }
-            }
-            Reduction::Sum => {
-                if self.diff_input.can_overwrite() {
-                    zip.for_each(|op_grad, grad, input, target| {
-                        let input_sigmoid = 1.0 / (1.0 + (-input).exp());
-                        *op_grad = (input_sigmoid - target) * grad
-                    });
-                    self.diff_input.set_overwrite(false);
-                } else {
-                    zip.for_each(|op_grad, grad, input, target| {
-                        let input_sigmoid = 1.0 / (1.0 + (-input).exp());
-                        *op_grad += (input_sigmoid - target) * grad
-                    });
-                }
-            }
+            .and(&*target_data)
+            .and(&pos_weight);
+
+        if self.diff_input.can_overwrite() {
+            zip.for_each(|op_grad, grad, input, target, pos_weight| {
+                *op_grad = logit_gradient(*input, *target, *pos_weight) * grad / n
+            });
+            self.diff_input.set_overwrite(false);
+        } else {
+            zip.for_each(|op_grad, grad, input, target, pos_weight| {
+                *op_grad += logit_gradient(*input, *target, *pos_weight) * grad / n
+            });
         }
     }
 
@@ -265,7 +267,8 @@ where
     }
 
     fn with_grad(&self) {
-        *self.gradient.borrow_mut() = Some(arr0(0.));
+        let shape = self.reduction.shape(self.input.data().raw_dim());
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(shape));
     }
 }
 
@@ -277,7 +280,8 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("BCEWithLogitsLossBackward")
-            .field("gradient", &self.gradient.borrow())
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("pos_weight", &self.pos_weight.as_ref().map(Summary))
             .field("reduction", &self.reduction)
             .field("overwrite", &self.overwrite.get())
             .finish()
@@ -292,12 +296,45 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
 }
 
+/// Broadcasts the weight of the positive examples to `shape`.
+///
+/// # Panics
+///
+/// If `pos_weight` is not broadcastable to `shape`.
+fn broadcast_pos_weight<D: Dimension>(pos_weight: &ArrayD<f32>, shape: D) -> Tensor<D> {
+    match pos_weight.broadcast(shape.clone()) {
+        Some(pos_weight) => pos_weight.to_owned(),
+        None => panic!(
+            "error: cannot broadcast the positive weight of shape {:?} to shape {:?}.",
+            pos_weight.shape(),
+            shape.slice()
+        ),
+    }
+}
+
+/// Computes the loss of a single logit as `(1 - t) * x + (1 + (p - 1) * t) * ln(1 + exp(-x))`,
+/// where the softplus is evaluated with the log-sum-exp trick so that it never overflows.
+fn logit_loss(input: f32, target: f32, pos_weight: f32) -> f32 {
+    let max = (-input).max(0.);
+    let softplus = max + ((-max).exp() + (-input - max).exp()).ln();
+
+    (1. - target) * input + (1. + (pos_weight - 1.) * target) * softplus
+}
+
+/// Computes the derivative of [`logit_loss`] with respect to the logit, which for a unitary
+/// positive weight reduces to `sigmoid(x) - t`.
+fn logit_gradient(input: f32, target: f32, pos_weight: f32) -> f32 {
+    let input_sigmoid = 1. / (1. + (-input).exp());
+
+    (1. + (pos_weight - 1.) * target) * input_sigmoid - pos_weight * target
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -9,18 +9,18 @@ fn mean() {
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     let target = new_input((3, 3), vec![1., 1., 0., 0., 0., 1., 0., 0., 1.]);
     let input = new_input((3, 3), vec![10., 11., 12., 13., 14., 15., 16., 17., 18.]);
-    let loss = BCEWithLogitsLoss::new(input.clone(), target.clone(), Reduction::Mean);
+    let loss = BCEWithLogitsLoss::new(input.clone(), target.clone(), None, Reduction::Mean);
 
     loss.forward();
-    assert_almost_equals(&*loss.data(), &arr0(8.));
+    assert_almost_equals(&*loss.data(), &arr0(8.).into_dyn());
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
     let input_diff = new_backward_input((3, 3), vec![0.; 9]);
     let loss_backward =
-        BCEWithLogitsLossBackward::new(input_diff.clone(), input, target, Reduction::Mean);
+        BCEWithLogitsLossBackward::new(input_diff.clone(), input, target, None, Reduction::Mean);
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-    *loss_backward.gradient_mut() = arr0(1.);
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     loss_backward.backward();
@@ -68,18 +68,18 @@ fn sum() {
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     let target = new_input((3, 3), vec![1., 1., 0., 0., 0., 1., 0., 0., 1.]);
     let input = new_input((3, 3), vec![10., 11., 12., 13., 14., 15., 16., 17., 18.]);
-    let loss = BCEWithLogitsLoss::new(input.clone(), target.clone(), Reduction::Sum);
+    let loss = BCEWithLogitsLoss::new(input.clone(), target.clone(), None, Reduction::Sum);
 
     loss.forward();
-    assert_almost_equals(&*loss.data(), &arr0(72.0001));
+    assert_almost_equals(&*loss.data(), &arr0(72.0001).into_dyn());
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     let input_diff = new_backward_input((3, 3), vec![0.; 9]);
     let loss_backward =
-        BCEWithLogitsLossBackward::new(input_diff.clone(), input, target, Reduction::Sum);
+        BCEWithLogitsLossBackward::new(input_diff.clone(), input, target, None, Reduction::Sum);
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-    *loss_backward.gradient_mut() = arr0(1.);
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     loss_backward.backward();
@@ -122,13 +122,94 @@ fn sum() {
     );
 }
 
+#[test]
+fn pos_weight() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let target = new_input((2, 3), vec![1., 0., 1., 0., 1., 1.]);
+    let input = new_input((2, 3), vec![0.5, -1., 2., 0., 1., -2.]);
+    let pos_weight = Some(ndarray::array![2., 1., 3.].into_dyn());
+    let loss = BCEWithLogitsLoss::new(
+        input.clone(),
+        target.clone(),
+        pos_weight.clone(),
+        Reduction::Sum,
+    );
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(9.029393).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input((2, 3), vec![0.; 6]);
+    let loss_backward = BCEWithLogitsLossBackward::new(
+        input_diff.clone(),
+        input,
+        target,
+        pos_weight,
+        Reduction::Sum,
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (2, 3),
+            vec![-0.755081, 0.268941, -0.357609, 0.5, -0.268941, -2.642391],
+        ),
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "error: cannot broadcast the positive weight of shape [2] to shape [2, 3]."
+)]
+fn pos_weight_fail() {
+    BCEWithLogitsLoss::new(
+        new_input((2, 3), vec![0.; 6]),
+        new_input((2, 3), vec![0.; 6]),
+        Some(ndarray::array![1., 2.].into_dyn()),
+        Reduction::Mean,
+    );
+}
+
+#[test]
+fn large_logits() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let target = new_input(4, vec![1., 1., 0., 0.]);
+    let input = new_input(4, vec![100., -100., 100., -100.]);
+    let loss = BCEWithLogitsLoss::new(input.clone(), target.clone(), None, Reduction::Sum);
+
+    loss.forward();
+    assert!(loss.data()[[]].is_finite());
+    assert_almost_equals(&*loss.data(), &arr0(200.).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input(4, vec![0.; 4]);
+    let loss_backward =
+        BCEWithLogitsLossBackward::new(input_diff.clone(), input, target, None, Reduction::Sum);
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert!(input_diff.gradient().iter().all(|el| el.is_finite()));
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(4, vec![0., -1., 1., 0.]),
+    );
+}
+
 #[test]
 fn debug_forward() {
     let target = new_input((3, 3), vec![1., 1., 0., 0., 0., 1., 0., 0., 1.]);
     let input = new_input((3, 3), vec![0.1, 0.9, 0.9, 0., 0., 0., 0.8, 0., 0.]);
-    let loss = BCEWithLogitsLoss::new(input.clone(), target.clone(), Reduction::Mean);
+    let loss = BCEWithLogitsLoss::new(input.clone(), target.clone(), None, Reduction::Mean);
 
-    let output = "BCEWithLogitsLoss { data: 0.0, shape=[], strides=[], layout=CFcf (0xf), const ndim=0, reduction: Mean, computed: false }";
+    let output = "BCEWithLogitsLoss { data: 0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0, pos_weight: None, reduction: Mean, computed: false }";
 
     assert_eq!(output, format!("{:?}", loss));
 }
@@ -137,7 +218,7 @@ fn debug_forward() {
 fn display_forward() {
     let target = new_input((3, 3), vec![1., 1., 0., 0., 0., 1., 0., 0., 1.]);
     let input = new_input((3, 3), vec![0.1, 0.9, 0.9, 0., 0., 0., 0.8, 0., 0.]);
-    let loss = BCEWithLogitsLoss::new(input.clone(), target.clone(), Reduction::Mean);
+    let loss = BCEWithLogitsLoss::new(input.clone(), target.clone(), None, Reduction::Mean);
 
     assert_eq!(format!("{}", loss.data()), format!("{}", loss));
 }
@@ -148,10 +229,11 @@ fn debug_backward() {
         new_backward_input(3, vec![0.; 3]),
         new_input(3, vec![0.; 3]),
         new_input(3, vec![0.; 3]),
+        None,
         Reduction::Mean,
     );
 
-    let output = "BCEWithLogitsLossBackward { gradient: Some(0.0, shape=[], strides=[], layout=CFcf (0xf), const ndim=0), reduction: Mean, overwrite: true }";
+    let output = "BCEWithLogitsLossBackward { gradient: Some(0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0), pos_weight: None, reduction: Mean, overwrite: true }";
 
     assert_eq!(output, format!("{:?}", loss));
 }
@@ -162,6 +244,7 @@ fn display_backward() {
         new_backward_input(3, vec![0.; 3]),
         new_input(3, vec![0.; 3]),
         new_input(3, vec![0.; 3]),
+        None,
         Reduction::Mean,
     );
 
@@ -175,6 +258,7 @@ fn no_grad() {
         new_backward_input(3, vec![0.; 3]),
         new_input(3, vec![0.; 3]),
         new_input(3, vec![0.; 3]),
+        None,
         Reduction::Mean,
     );
 
@@ -182,5 +266,5 @@ fn no_grad() {
     assert!(node.gradient.borrow().is_none());
 
     node.with_grad();
-    assert_eq!(&*node.gradient(), arr0(0.));
+    assert_eq!(&*node.gradient(), arr0(0.).into_dyn());
 }
@@ -0,0 +1,520 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite,
+    Reduction, Summary, Tensor,
+};
+use ndarray::{s, Array, Array2, ArrayView1, ArrayView2, ArrayViewMut2, Axis, Ix2, Ix3, IxDyn};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ CTCLoss ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct CTCLoss<T: ?Sized, U: ?Sized>
+where
+    T: Data<Dim = Ix3>,
+    U: Data<Dim = Ix2>,
+{
+    input: Rc<T>,
+    target: Rc<U>,
+    data: RefCell<Tensor<IxDyn>>,
+    input_lengths: Vec<usize>,
+    target_lengths: Vec<usize>,
+    blank: usize,
+    reduction: Reduction,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized, U: ?Sized> CTCLoss<T, U>
+where
+    T: Data<Dim = Ix3>,
+    U: Data<Dim = Ix2>,
+{
+    pub(crate) fn new(
+        input: Rc<T>,
+        target: Rc<U>,
+        input_lengths: Vec<usize>,
+        target_lengths: Vec<usize>,
+        blank: usize,
+        reduction: Reduction,
+    ) -> Self {
+        check_lengths(
+            input.data().dim(),
+            target.data().dim(),
+            &input_lengths,
+            &target_lengths,
+            blank,
+        );
+        let data = Tensor::zeros(reduction.shape(input.data().len_of(Axis(1))));
+
+        Self {
+            input,
+            target,
+            data: RefCell::new(data),
+            input_lengths,
+            target_lengths,
+            blank,
+            reduction,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Data for CTCLoss<T, U>
+where
+    T: Data<Dim = Ix3>,
+    U: Data<Dim = Ix2>,
+{
+    type Dim = IxDyn;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Cache for CTCLoss<T, U>
+where
+    T: Data<Dim = Ix3>,
+    U: Data<Dim = Ix2>,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Forward for CTCLoss<T, U>
+where
+    T: Data<Dim = Ix3>,
+    U: Data<Dim = Ix2>,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let (mut loss_data, input_data, target_data) = {
+            (
+                self.data.borrow_mut(),
+                self.input.data(),
+                self.target.data(),
+            )
+        };
+        let batch_size = input_data.len_of(Axis(1));
+        let loss = Array::from_iter((0..batch_size).map(|sample| {
+            let log_probs = input_data
+                .index_axis(Axis(1), sample)
+                .slice_move(s![..self.input_lengths[sample], ..]);
+            let labels = extended_labels(
+                target_data.row(sample),
+                self.target_lengths[sample],
+                self.blank,
+            );
+            let loss = -log_likelihood(&alphas(log_probs, &labels));
+            match self.reduction {
+                Reduction::Mean => loss / self.target_lengths[sample].max(1) as f32,
+                Reduction::None | Reduction::Sum => loss,
+            }
+        }));
+        *loss_data = self.reduction.reduce(loss, batch_size as f32);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Debug for CTCLoss<T, U>
+where
+    T: Data<Dim = Ix3>,
+    U: Data<Dim = Ix2>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CTCLoss")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("input_lengths", &self.input_lengths)
+            .field("target_lengths", &self.target_lengths)
+            .field("blank", &self.blank)
+            .field("reduction", &self.reduction)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Display for CTCLoss<T, U>
+where
+    T: Data<Dim = Ix3>,
+    U: Data<Dim = Ix2>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ CTCLossBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct CTCLossBackward<T: ?Sized, U: ?Sized, V: ?Sized>
+where
+    T: Gradient<Dim = Ix3>,
+    U: Data<Dim = Ix3>,
+    V: Data<Dim = Ix2>,
+{
+    gradient: RefCell<Option<Tensor<IxDyn>>>,
+    overwrite: Cell<bool>,
+    diff_input: Rc<T>,
+    input: Rc<U>,
+    target: Rc<V>,
+    input_lengths: Vec<usize>,
+    target_lengths: Vec<usize>,
+    blank: usize,
+    reduction: Reduction,
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> CTCLossBackward<T, U, V>
+where
+    T: Gradient<Dim = Ix3>,
+    U: Data<Dim = Ix3>,
+    V: Data<Dim = Ix2>,
+{
+    pub(crate) fn new(
+        diff_input: Rc<T>,
+        input: Rc<U>,
+        target: Rc<V>,
+        input_lengths: Vec<usize>,
+        target_lengths: Vec<usize>,
+        blank: usize,
+        reduction: Reduction,
+    ) -> Self {
+        check_lengths(
+            input.data().dim(),
+            target.data().dim(),
+            &input_lengths,
+            &target_lengths,
+            blank,
+        );
+        let gradient = Tensor::zeros(reduction.shape(input.data().len_of(Axis(1))));
+
+        Self {
+            diff_input,
+            input,
+            target,
+            gradient: RefCell::new(Some(gradient)),
+            input_lengths,
+            target_lengths,
+            blank,
+            reduction,
+            overwrite: Cell::new(true),
+        }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Gradient for CTCLossBackward<T, U, V>
+where
+    T: Gradient<Dim = Ix3>,
+    U: Data<Dim = Ix3>,
+    V: Data<Dim = Ix2>,
+{
+    type Dim = IxDyn;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Overwrite for CTCLossBackward<T, U, V>
+where
+    T: Gradient<Dim = Ix3>,
+    U: Data<Dim = Ix3>,
+    V: Data<Dim = Ix2>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Backward for CTCLossBackward<T, U, V>
+where
+    T: Gradient<Dim = Ix3>,
+    U: Data<Dim = Ix3>,
+    V: Data<Dim = Ix2>,
+{
+    fn backward(&self) {
+        let (mut operand_gradient, gradient, input_data, target_data) = {
+            (
+                self.diff_input.gradient_mut(),
+                self.gradient(),
+                self.input.data(),
+                self.target.data(),
+            )
+        };
+
+        if self.diff_input.can_overwrite() {
+            operand_gradient.fill(0.);
+            self.diff_input.set_overwrite(false);
+        }
+
+        let batch_size = input_data.len_of(Axis(1));
+        let gradient = gradient.broadcast(batch_size).unwrap();
+        for sample in 0..batch_size {
+            let grad = match self.reduction {
+                Reduction::Mean => {
+                    gradient[sample] / (batch_size * self.target_lengths[sample].max(1)) as f32
+                }
+                Reduction::None | Reduction::Sum => gradient[sample],
+            };
+            let input_length = self.input_lengths[sample];
+            let log_probs = input_data
+                .index_axis(Axis(1), sample)
+                .slice_move(s![..input_length, ..]);
+            let op_grad = operand_gradient
+                .index_axis_mut(Axis(1), sample)
+                .slice_move(s![..input_length, ..]);
+            let labels = extended_labels(
+                target_data.row(sample),
+                self.target_lengths[sample],
+                self.blank,
+            );
+            accumulate_gradient(op_grad, log_probs, &labels, grad);
+        }
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        let shape = self.reduction.shape(self.input.data().len_of(Axis(1)));
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(shape));
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Debug for CTCLossBackward<T, U, V>
+where
+    T: Gradient<Dim = Ix3>,
+    U: Data<Dim = Ix3>,
+    V: Data<Dim = Ix2>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CTCLossBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("input_lengths", &self.input_lengths)
+            .field("target_lengths", &self.target_lengths)
+            .field("blank", &self.blank)
+            .field("reduction", &self.reduction)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Display for CTCLossBackward<T, U, V>
+where
+    T: Gradient<Dim = Ix3>,
+    U: Data<Dim = Ix3>,
+    V: Data<Dim = Ix2>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+/// Checks that the lengths of the sequences are consistent with the shapes of the log-probabilities
+/// *(T, N, C)* and of the targets *(N, S)* and that the blank is a valid class.
+fn check_lengths(
+    input_shape: (usize, usize, usize),
+    target_shape: (usize, usize),
+    input_lengths: &[usize],
+    target_lengths: &[usize],
+    blank: usize,
+) {
+    let (steps, batch_size, classes) = input_shape;
+    assert!(
+        input_lengths.len() == batch_size && target_lengths.len() == batch_size,
+        "error: {} input lengths and {} target lengths were given for a batch of {} sequences.",
+        input_lengths.len(),
+        target_lengths.len(),
+        batch_size
+    );
+    assert_eq!(
+        target_shape.0, batch_size,
+        "error: the targets hold {} sequences, but the batch has {}.",
+        target_shape.0, batch_size
+    );
+    assert!(
+        input_lengths.iter().all(|&length| length <= steps),
+        "error: input lengths must not exceed the {} time steps of the input.",
+        steps
+    );
+    assert!(
+        target_lengths
+            .iter()
+            .all(|&length| length <= target_shape.1),
+        "error: target lengths must not exceed the {} labels of the targets.",
+        target_shape.1
+    );
+    assert!(
+        blank < classes,
+        "error: the blank {} is not a class of an input with {} classes.",
+        blank,
+        classes
+    );
+}
+
+/// Returns the first `length` labels of `target` interleaved with blanks, starting and ending with
+/// one.
+fn extended_labels(target: ArrayView1<f32>, length: usize, blank: usize) -> Vec<usize> {
+    let mut labels = vec![blank; 2 * length + 1];
+    labels
+        .iter_mut()
+        .skip(1)
+        .step_by(2)
+        .zip(target.iter())
+        .for_each(|(label, class)| *label = *class as usize);
+    labels
+}
+
+/// Adds two probabilities given in log space, without leaving it.
+fn log_add(lhs: f32, rhs: f32) -> f32 {
+    if lhs == f32::NEG_INFINITY {
+        return rhs;
+    }
+    if rhs == f32::NEG_INFINITY {
+        return lhs;
+    }
+    let max = lhs.max(rhs);
+    max + (-(lhs - rhs).abs()).exp().ln_1p()
+}
+
+/// Tells whether a path may skip the blank preceding the `s`-th extended label, which is allowed
+/// only between two different labels. As blanks lie at even positions, this never holds for them.
+fn can_skip(labels: &[usize], s: usize) -> bool {
+    s >= 2 && labels[s] != labels[s - 2]
+}
+
+/// Computes the forward variables of the CTC recursion in log space, that is the log-probability
+/// of all the prefixes of the paths ending in each extended label at each time step.
+fn alphas(log_probs: ArrayView2<f32>, labels: &[usize]) -> Array2<f32> {
+    let (steps, states) = (log_probs.nrows(), labels.len());
+    let mut alphas = Array2::from_elem((steps, states), f32::NEG_INFINITY);
+    if steps == 0 {
+        return alphas;
+    }
+    alphas[(0, 0)] = log_probs[(0, labels[0])];
+    if states > 1 {
+        alphas[(0, 1)] = log_probs[(0, labels[1])];
+    }
+    for t in 1..steps {
+        for s in 0..states {
+            let mut alpha = alphas[(t - 1, s)];
+            if s >= 1 {
+                alpha = log_add(alpha, alphas[(t - 1, s - 1)]);
+            }
+            if can_skip(labels, s) {
+                alpha = log_add(alpha, alphas[(t - 1, s - 2)]);
+            }
+            alphas[(t, s)] = alpha + log_probs[(t, labels[s])];
+        }
+    }
+    alphas
+}
+
+/// Computes the backward variables of the CTC recursion in log space, that is the log-probability
+/// of all the suffixes of the paths starting from each extended label at each time step.
+fn betas(log_probs: ArrayView2<f32>, labels: &[usize]) -> Array2<f32> {
+    let (steps, states) = (log_probs.nrows(), labels.len());
+    let mut betas = Array2::from_elem((steps, states), f32::NEG_INFINITY);
+    if steps == 0 {
+        return betas;
+    }
+    let last = steps - 1;
+    betas[(last, states - 1)] = log_probs[(last, labels[states - 1])];
+    if states > 1 {
+        betas[(last, states - 2)] = log_probs[(last, labels[states - 2])];
+    }
+    for t in (0..last).rev() {
+        for s in 0..states {
+            let mut beta = betas[(t + 1, s)];
+            if s + 1 < states {
+                beta = log_add(beta, betas[(t + 1, s + 1)]);
+            }
+            if s + 2 < states && can_skip(labels, s + 2) {
+                beta = log_add(beta, betas[(t + 1, s + 2)]);
+            }
+            betas[(t, s)] = beta + log_probs[(t, labels[s])];
+        }
+    }
+    betas
+}
+
+/// Returns the log-likelihood of the target given the forward variables, the paths must end either
+/// in the last label or in the trailing blank.
+fn log_likelihood(alphas: &Array2<f32>) -> f32 {
+    let (steps, states) = alphas.dim();
+    if steps == 0 {
+        return if states == 1 { 0. } else { f32::NEG_INFINITY };
+    }
+    let last = alphas.row(steps - 1);
+    if states > 1 {
+        log_add(last[states - 1], last[states - 2])
+    } else {
+        last[0]
+    }
+}
+
+/// Accumulates into `op_grad` the gradient of the negative log-likelihood of a sequence wrt its
+/// log-probabilities, scaled by `grad`.
+///
+/// The gradient of each log-probability is minus the share of the likelihood carried by the paths
+/// passing through it. Targets that cannot be aligned with the input receive no gradient.
+fn accumulate_gradient(
+    mut op_grad: ArrayViewMut2<f32>,
+    log_probs: ArrayView2<f32>,
+    labels: &[usize],
+    grad: f32,
+) {
+    let alphas = alphas(log_probs, labels);
+    let log_likelihood = log_likelihood(&alphas);
+    if !log_likelihood.is_finite() {
+        return;
+    }
+    let betas = betas(log_probs, labels);
+
+    let mut occupancy = Array2::from_elem(log_probs.raw_dim(), f32::NEG_INFINITY);
+    for ((t, s), alpha) in alphas.indexed_iter() {
+        let class = labels[s];
+        occupancy[(t, class)] = log_add(occupancy[(t, class)], alpha + betas[(t, s)]);
+    }
+    op_grad
+        .indexed_iter_mut()
+        .for_each(|((t, class), op_grad_el)| {
+            let occupancy = occupancy[(t, class)];
+            if occupancy != f32::NEG_INFINITY {
+                *op_grad_el -= (occupancy - log_probs[(t, class)] - log_likelihood).exp() * grad;
+            }
+        });
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
@@ -0,0 +1,358 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, CTCLoss,
+    CTCLossBackward, Data, Forward, Gradient, Reduction,
+};
+use ndarray::arr0;
+use std::f32::consts::LN_2;
+
+/// Log-probabilities of shape (3, 2, 2), the first sequence lasts two steps and the second three.
+fn log_probs() -> Vec<f32> {
+    vec![
+        -0.916291, -0.510826, -0.916291, -0.510826, // t = 0
+        -1.203973, -0.356675, -LN_2, -LN_2, // t = 1
+        -LN_2, -LN_2, -1.203973, -0.356675, // t = 2
+    ]
+}
+
+#[test]
+fn single_label() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    // The label is emitted by the paths "11", "01" and "10", whose probability is 0.88.
+    let target = new_input((1, 1), vec![1.]);
+    let input = new_input((2, 1, 2), vec![-0.916291, -0.510826, -1.203973, -0.356675]);
+    let loss = CTCLoss::new(
+        input.clone(),
+        target.clone(),
+        vec![2],
+        vec![1],
+        0,
+        Reduction::Sum,
+    );
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(0.127833).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input((2, 1, 2), vec![0.; 4]);
+    let loss_backward = CTCLossBackward::new(
+        input_diff.clone(),
+        input,
+        target,
+        vec![2],
+        vec![1],
+        0,
+        Reduction::Sum,
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor((2, 1, 2), vec![-0.318182, -0.681818, -0.204545, -0.795455]),
+    );
+}
+
+#[test]
+fn repeated_labels() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    // The repeated labels must be separated by a blank, so "101" is the only valid path.
+    let target = new_input((1, 2), vec![1., 1.]);
+    let input = new_input(
+        (3, 1, 2),
+        vec![-0.916291, -0.510826, -LN_2, -LN_2, -1.203973, -0.356675],
+    );
+    let loss = CTCLoss::new(
+        input.clone(),
+        target.clone(),
+        vec![3],
+        vec![2],
+        0,
+        Reduction::Sum,
+    );
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(1.560648).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input((3, 1, 2), vec![0.; 6]);
+    let loss_backward = CTCLossBackward::new(
+        input_diff.clone(),
+        input,
+        target,
+        vec![3],
+        vec![2],
+        0,
+        Reduction::Sum,
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor((3, 1, 2), vec![0., -1., -1., 0., 0., -1.]),
+    );
+}
+
+#[test]
+fn infeasible() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    // Two steps are not enough to emit two repeated labels.
+    let target = new_input((1, 2), vec![1., 1.]);
+    let input = new_input((2, 1, 2), vec![-0.916291, -0.510826, -1.203973, -0.356675]);
+    let loss = CTCLoss::new(
+        input.clone(),
+        target.clone(),
+        vec![2],
+        vec![2],
+        0,
+        Reduction::Sum,
+    );
+
+    loss.forward();
+    assert_eq!(loss.data()[[]], f32::INFINITY);
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input((2, 1, 2), vec![0.; 4]);
+    let loss_backward = CTCLossBackward::new(
+        input_diff.clone(),
+        input,
+        target,
+        vec![2],
+        vec![2],
+        0,
+        Reduction::Sum,
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(&*input_diff.gradient(), &new_tensor((2, 1, 2), vec![0.; 4]));
+}
+
+#[test]
+fn mean() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let target = new_input((2, 2), vec![1., 0., 1., 1.]);
+    let input = new_input((3, 2, 2), log_probs());
+    let loss = CTCLoss::new(
+        input.clone(),
+        target.clone(),
+        vec![2, 3],
+        vec![1, 2],
+        0,
+        Reduction::Mean,
+    );
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(0.454079).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input((3, 2, 2), vec![0.; 12]);
+    let loss_backward = CTCLossBackward::new(
+        input_diff.clone(),
+        input,
+        target,
+        vec![2, 3],
+        vec![1, 2],
+        0,
+        Reduction::Mean,
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (3, 2, 2),
+            vec![
+                -0.159091, -0.340909, 0., -0.25, -0.102273, -0.397727, -0.25, 0., 0., 0., 0., -0.25,
+            ],
+        ),
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ 2nd Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (3, 2, 2),
+            vec![
+                -0.318182, -0.681818, 0., -0.5, -0.204545, -0.795455, -0.5, 0., 0., 0., 0., -0.5,
+            ],
+        ),
+    );
+}
+
+#[test]
+fn sum() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let target = new_input((2, 2), vec![1., 0., 1., 1.]);
+    let input = new_input((3, 2, 2), log_probs());
+    let loss = CTCLoss::new(
+        input.clone(),
+        target.clone(),
+        vec![2, 3],
+        vec![1, 2],
+        0,
+        Reduction::Sum,
+    );
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(1.688481).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input((3, 2, 2), vec![0.; 12]);
+    let loss_backward = CTCLossBackward::new(
+        input_diff.clone(),
+        input,
+        target,
+        vec![2, 3],
+        vec![1, 2],
+        0,
+        Reduction::Sum,
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (3, 2, 2),
+            vec![
+                -0.318182, -0.681818, 0., -1., -0.204545, -0.795455, -1., 0., 0., 0., 0., -1.,
+            ],
+        ),
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ 2nd Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (3, 2, 2),
+            vec![
+                -0.636364, -1.363636, 0., -2., -0.409091, -1.590909, -2., 0., 0., 0., 0., -2.,
+            ],
+        ),
+    );
+}
+
+#[test]
+#[should_panic(expected = "error: the blank 2 is not a class of an input with 2 classes.")]
+fn wrong_blank() {
+    CTCLoss::new(
+        new_input((3, 2, 2), log_probs()),
+        new_input((2, 2), vec![1., 0., 1., 1.]),
+        vec![2, 3],
+        vec![1, 2],
+        2,
+        Reduction::Mean,
+    );
+}
+
+#[test]
+#[should_panic(expected = "error: input lengths must not exceed the 3 time steps of the input.")]
+fn wrong_input_lengths() {
+    CTCLoss::new(
+        new_input((3, 2, 2), log_probs()),
+        new_input((2, 2), vec![1., 0., 1., 1.]),
+        vec![2, 4],
+        vec![1, 2],
+        0,
+        Reduction::Mean,
+    );
+}
+
+#[test]
+fn debug_forward() {
+    let target = new_input((1, 1), vec![1.]);
+    let input = new_input((2, 1, 2), vec![-0.916291, -0.510826, -1.203973, -0.356675]);
+    let loss = CTCLoss::new(input, target, vec![2], vec![1], 0, Reduction::Mean);
+
+    let output = "CTCLoss { data: 0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0, input_lengths: [2], target_lengths: [1], blank: 0, reduction: Mean, computed: false }";
+
+    assert_eq!(output, format!("{:?}", loss));
+}
+
+#[test]
+fn display_forward() {
+    let target = new_input((1, 1), vec![1.]);
+    let input = new_input((2, 1, 2), vec![-0.916291, -0.510826, -1.203973, -0.356675]);
+    let loss = CTCLoss::new(input, target, vec![2], vec![1], 0, Reduction::Mean);
+
+    assert_eq!(format!("{}", loss.data()), format!("{}", loss));
+}
+
+#[test]
+fn debug_backward() {
+    let target = new_input((1, 1), vec![1.]);
+    let input_diff = new_backward_input((2, 1, 2), vec![0.; 4]);
+    let input = new_input((2, 1, 2), vec![-0.916291, -0.510826, -1.203973, -0.356675]);
+
+    let loss = CTCLossBackward::new(
+        input_diff,
+        input,
+        target,
+        vec![2],
+        vec![1],
+        0,
+        Reduction::Mean,
+    );
+
+    let output = "CTCLossBackward { gradient: Some(0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0), input_lengths: [2], target_lengths: [1], blank: 0, reduction: Mean, overwrite: true }";
+
+    assert_eq!(output, format!("{:?}", loss));
+}
+
+#[test]
+fn display_backward() {
+    let target = new_input((1, 1), vec![1.]);
+    let input_diff = new_backward_input((2, 1, 2), vec![0.; 4]);
+    let input = new_input((2, 1, 2), vec![-0.916291, -0.510826, -1.203973, -0.356675]);
+
+    let loss = CTCLossBackward::new(
+        input_diff,
+        input,
+        target,
+        vec![2],
+        vec![1],
+        0,
+        Reduction::Mean,
+    );
+
+    assert_eq!(format!("{}", loss.gradient()), format!("{}", loss));
+}
+
+#[test]
+fn no_grad() {
+    // CTCLossBackward
+    let node = CTCLossBackward::new(
+        new_backward_input((2, 1, 2), vec![0.; 4]),
+        new_input((2, 1, 2), vec![0.; 4]),
+        new_input((1, 1), vec![1.]),
+        vec![2],
+        vec![1],
+        0,
+        Reduction::Mean,
+    );
+
+    node.no_grad();
+    assert!(node.gradient.borrow().is_none());
+
+    node.with_grad();
+    assert_eq!(&*node.gradient(), arr0(0.).into_dyn());
+}
@@ -0,0 +1,352 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, sample_gradient, Backward, Cache, Data, Forward, Gradient,
+    Overwrite, Reduction, Summary, Tensor,
+};
+use ndarray::{Array1, ArrayView1, Axis, Ix1, Ix2, IxDyn, Zip};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ FocalLoss ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct FocalLoss<T: ?Sized, U: ?Sized>
+where
+    T: Data<Dim = Ix2>,
+    U: Data<Dim = Ix1>,
+{
+    input: Rc<T>,
+    target: Rc<U>,
+    data: RefCell<Tensor<IxDyn>>,
+    alpha: Vec<f32>,
+    gamma: f32,
+    reduction: Reduction,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized, U: ?Sized> FocalLoss<T, U>
+where
+    T: Data<Dim = Ix2>,
+    U: Data<Dim = Ix1>,
+{
+    pub(crate) fn new(
+        input: Rc<T>,
+        target: Rc<U>,
+        alpha: Vec<f32>,
+        gamma: f32,
+        reduction: Reduction,
+    ) -> Self {
+        check_alpha(input.data().len_of(Axis(1)), &alpha);
+        let data = Tensor::zeros(reduction.shape(input.data().len_of(Axis(0))));
+
+        Self {
+            input,
+            target,
+            data: RefCell::new(data),
+            alpha,
+            gamma,
+            reduction,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Data for FocalLoss<T, U>
+where
+    T: Data<Dim = Ix2>,
+    U: Data<Dim = Ix1>,
+{
+    type Dim = IxDyn;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Cache for FocalLoss<T, U>
+where
+    T: Data<Dim = Ix2>,
+    U: Data<Dim = Ix1>,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Forward for FocalLoss<T, U>
+where
+    T: Data<Dim = Ix2>,
+    U: Data<Dim = Ix1>,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let (mut loss_data, input_data, target_data) = {
+            (
+                self.data.borrow_mut(),
+                self.input.data(),
+                self.target.data(),
+            )
+        };
+        let loss = Zip::from(input_data.rows())
+            .and(&*target_data)
+            .map_collect(|logits, target| {
+                let target = *target as usize;
+                let log_prob = log_softmax(logits)[target];
+                let modulation = (1. - log_prob.exp()).powf(self.gamma);
+                -self.alpha[target] * modulation * log_prob
+            });
+        *loss_data = self
+            .reduction
+            .reduce(loss, input_data.len_of(Axis(0)) as f32);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Debug for FocalLoss<T, U>
+where
+    T: Data<Dim = Ix2>,
+    U: Data<Dim = Ix1>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FocalLoss")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("alpha", &self.alpha)
+            .field("gamma", &self.gamma)
+            .field("reduction", &self.reduction)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Display for FocalLoss<T, U>
+where
+    T: Data<Dim = Ix2>,
+    U: Data<Dim = Ix1>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ FocalLossBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct FocalLossBackward<T: ?Sized, U: ?Sized, V: ?Sized>
+where
+    T: Gradient<Dim = Ix2>,
+    U: Data<Dim = Ix2>,
+    V: Data<Dim = Ix1>,
+{
+    gradient: RefCell<Option<Tensor<IxDyn>>>,
+    overwrite: Cell<bool>,
+    diff_input: Rc<T>,
+    input: Rc<U>,
+    target: Rc<V>,
+    alpha: Vec<f32>,
+    gamma: f32,
+    reduction: Reduction,
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> FocalLossBackward<T, U, V>
+where
+    T: Gradient<Dim = Ix2>,
+    U: Data<Dim = Ix2>,
+    V: Data<Dim = Ix1>,
+{
+    pub(crate) fn new(
+        diff_input: Rc<T>,
+        input: Rc<U>,
+        target: Rc<V>,
+        alpha: Vec<f32>,
+        gamma: f32,
+        reduction: Reduction,
+    ) -> Self {
+        check_alpha(diff_input.gradient().len_of(Axis(1)), &alpha);
+        let gradient = Tensor::zeros(reduction.shape(input.data().len_of(Axis(0))));
+
+        Self {
+            diff_input,
+            input,
+            target,
+            gradient: RefCell::new(Some(gradient)),
+            alpha,
+            gamma,
+            reduction,
+            overwrite: Cell::new(true),
+        }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Gradient for FocalLossBackward<T, U, V>
+where
+    T: Gradient<Dim = Ix2>,
+    U: Data<Dim = Ix2>,
+    V: Data<Dim = Ix1>,
+{
+    type Dim = IxDyn;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Overwrite for FocalLossBackward<T, U, V>
+where
+    T: Gradient<Dim = Ix2>,
+    U: Data<Dim = Ix2>,
+    V: Data<Dim = Ix1>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Backward for FocalLossBackward<T, U, V>
+where
+    T: Gradient<Dim = Ix2>,
+    U: Data<Dim = Ix2>,
+    V: Data<Dim = Ix1>,
+{
+    fn backward(&self) {
+        let (mut operand_gradient, gradient, input_data, target_data) = {
+            (
+                self.diff_input.gradient_mut(),
+                self.gradient(),
+                self.input.data(),
+                self.target.data(),
+            )
+        };
+        let n = self.reduction.scale(input_data.len_of(Axis(0)) as f32);
+        let zip = Zip::from(operand_gradient.rows_mut())
+            .and(input_data.rows())
+            .and(&*target_data)
+            .and_broadcast(sample_gradient(&gradient, 1));
+
+        // The derivative of the loss with respect to the i-th logit is the one of the target
+        // log-probability scaled by (δᵢ - pᵢ), so the whole row is updated in a single pass.
+        let overwrite = self.diff_input.can_overwrite();
+        zip.for_each(|mut op_grad, logits, target, grad| {
+            let target = *target as usize;
+            let log_probs = log_softmax(logits);
+            let scale =
+                self.alpha[target] * grad / n * log_prob_derivative(log_probs[target], self.gamma);
+            Zip::indexed(&mut op_grad)
+                .and(&log_probs)
+                .for_each(|class, op_grad_el, log_prob| {
+                    let indicator = if class == target { 1. } else { 0. };
+                    let derivative = scale * (indicator - log_prob.exp());
+                    if overwrite {
+                        *op_grad_el = derivative;
+                    } else {
+                        *op_grad_el += derivative;
+                    }
+                });
+        });
+        if overwrite {
+            self.diff_input.set_overwrite(false);
+        }
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        let shape = self.reduction.shape(self.input.data().len_of(Axis(0)));
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(shape));
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Debug for FocalLossBackward<T, U, V>
+where
+    T: Gradient<Dim = Ix2>,
+    U: Data<Dim = Ix2>,
+    V: Data<Dim = Ix1>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FocalLossBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("alpha", &self.alpha)
+            .field("gamma", &self.gamma)
+            .field("reduction", &self.reduction)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Display for FocalLossBackward<T, U, V>
+where
+    T: Gradient<Dim = Ix2>,
+    U: Data<Dim = Ix2>,
+    V: Data<Dim = Ix1>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+/// Checks that there is exactly one balancing factor for each of the `classes`.
+fn check_alpha(classes: usize, alpha: &[f32]) {
+    assert_eq!(
+        alpha.len(),
+        classes,
+        "error: {} balancing factors were given for an input with {} classes.",
+        alpha.len(),
+        classes
+    );
+}
+
+/// Computes the log-probabilities of `logits`, shifting them by their maximum for numerical
+/// stability exactly as the log-softmax node does.
+fn log_softmax(logits: ArrayView1<f32>) -> Array1<f32> {
+    let max = logits.fold(f32::MIN, |x, y| x.max(*y));
+    let log_sum_exp = logits.map(|el| (el - max).exp()).sum().ln();
+    logits.map(|el| el - log_sum_exp - max)
+}
+
+/// Derivative of the loss of a single sample with respect to the log-probability of its target
+/// class, multiplied by the probability itself.
+///
+/// For *p = exp(log_prob)* this is *γ (1 - p)^(γ - 1) p ln(p) - (1 - p)^γ*. The first term
+/// vanishes whenever the target class is predicted with certainty or the focusing is disabled.
+fn log_prob_derivative(log_prob: f32, gamma: f32) -> f32 {
+    let complement = 1. - log_prob.exp();
+    let focusing = if gamma == 0. || complement <= 0. {
+        0.
+    } else {
+        gamma * complement.powf(gamma - 1.) * log_prob.exp() * log_prob
+    };
+    focusing - complement.powf(gamma)
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
@@ -0,0 +1,190 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Data, FocalLoss,
+    FocalLossBackward, Forward, Gradient, Reduction,
+};
+use ndarray::arr0;
+
+#[test]
+fn mean() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let target = new_input(3, vec![1., 0., 2.]);
+    let input = new_input((3, 3), vec![1., 2., 0.5, 0.3, -1., 2., -0.5, 0.2, 0.1]);
+    let alpha = vec![0.25, 0.5, 0.75];
+    let loss = FocalLoss::new(
+        input.clone(),
+        target.clone(),
+        alpha.clone(),
+        2.,
+        Reduction::Mean,
+    );
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(0.220872).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input((3, 3), vec![0.; 9]);
+    let loss_backward = FocalLossBackward::new(
+        input_diff.clone(),
+        input,
+        target,
+        alpha,
+        2.,
+        Reduction::Mean,
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (3, 3),
+            vec![
+                0.013674, -0.021968, 0.008294, -0.085714, 0.004065, 0.081649, 0.043776, 0.088155,
+                -0.131931,
+            ],
+        ),
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ 2nd Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (3, 3),
+            vec![
+                0.027348, -0.043936, 0.016588, -0.171428, 0.00813, 0.163298, 0.087552, 0.17631,
+                -0.263862,
+            ],
+        ),
+    );
+}
+
+#[test]
+fn sum() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let target = new_input(3, vec![1., 0., 2.]);
+    let input = new_input((3, 3), vec![1., 2., 0.5, 0.3, -1., 2., -0.5, 0.2, 0.1]);
+    let alpha = vec![0.25, 0.5, 0.75];
+    let loss = FocalLoss::new(
+        input.clone(),
+        target.clone(),
+        alpha.clone(),
+        2.,
+        Reduction::Sum,
+    );
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(0.662615).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input((3, 3), vec![0.; 9]);
+    let loss_backward =
+        FocalLossBackward::new(input_diff.clone(), input, target, alpha, 2., Reduction::Sum);
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (3, 3),
+            vec![
+                0.041023, -0.065904, 0.024881, -0.257143, 0.012195, 0.244948, 0.131329, 0.264464,
+                -0.395792,
+            ],
+        ),
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ 2nd Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (3, 3),
+            vec![
+                0.082045, -0.131808, 0.049763, -0.514287, 0.02439, 0.489896, 0.262657, 0.528927,
+                -0.791585,
+            ],
+        ),
+    );
+}
+
+#[test]
+#[should_panic(expected = "error: 2 balancing factors were given for an input with 3 classes.")]
+fn wrong_alpha() {
+    FocalLoss::new(
+        new_input((3, 3), vec![0.; 9]),
+        new_input(3, vec![0.; 3]),
+        vec![0.25, 0.75],
+        2.,
+        Reduction::Mean,
+    );
+}
+
+#[test]
+fn debug_forward() {
+    let target = new_input(3, vec![1., 0., 2.]);
+    let input = new_input((3, 3), vec![1., 2., 0.5, 0.3, -1., 2., -0.5, 0.2, 0.1]);
+    let loss = FocalLoss::new(input, target, vec![0.5; 3], 2., Reduction::Mean);
+
+    let output = "FocalLoss { data: 0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0, alpha: [0.5, 0.5, 0.5], gamma: 2.0, reduction: Mean, computed: false }";
+
+    assert_eq!(output, format!("{:?}", loss));
+}
+
+#[test]
+fn display_forward() {
+    let target = new_input(3, vec![1., 0., 2.]);
+    let input = new_input((3, 3), vec![1., 2., 0.5, 0.3, -1., 2., -0.5, 0.2, 0.1]);
+    let loss = FocalLoss::new(input, target, vec![0.5; 3], 2., Reduction::Mean);
+
+    assert_eq!(format!("{}", loss.data()), format!("{}", loss));
+}
+
+#[test]
+fn debug_backward() {
+    let target = new_input(3, vec![1., 0., 2.]);
+    let input_diff = new_backward_input((3, 3), vec![0.; 9]);
+    let input = new_input((3, 3), vec![1., 2., 0.5, 0.3, -1., 2., -0.5, 0.2, 0.1]);
+
+    let loss = FocalLossBackward::new(input_diff, input, target, vec![0.5; 3], 2., Reduction::Mean);
+
+    let output = "FocalLossBackward { gradient: Some(0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0), alpha: [0.5, 0.5, 0.5], gamma: 2.0, reduction: Mean, overwrite: true }";
+
+    assert_eq!(output, format!("{:?}", loss));
+}
+
+#[test]
+fn display_backward() {
+    let target = new_input(3, vec![1., 0., 2.]);
+    let input_diff = new_backward_input((3, 3), vec![0.; 9]);
+    let input = new_input((3, 3), vec![1., 2., 0.5, 0.3, -1., 2., -0.5, 0.2, 0.1]);
+
+    let loss = FocalLossBackward::new(input_diff, input, target, vec![0.5; 3], 2., Reduction::Mean);
+
+    assert_eq!(format!("{}", loss.gradient()), format!("{}", loss));
+}
+
+#[test]
+fn no_grad() {
+    // FocalLossBackward
+    let node = FocalLossBackward::new(
+        new_backward_input((3, 3), vec![0.; 9]),
+        new_input((3, 3), vec![0.; 9]),
+        new_input(3, vec![0.; 3]),
+        vec![1.; 3],
+        2.,
+        Reduction::Mean,
+    );
+
+    node.no_grad();
+    assert!(node.gradient.borrow().is_none());
+
+    node.with_grad();
+    assert_eq!(&*node.gradient(), arr0(0.).into_dyn());
+}
@@ -0,0 +1,286 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite,
+    Reduction, Summary, Tensor,
+};
+use ndarray::{IxDyn, Zip};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ HingeLoss ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct HingeLoss<T: ?Sized, U: ?Sized>
+where
+    T: Data,
+    U: Data<Dim = T::Dim>,
+{
+    input: Rc<T>,
+    target: Rc<U>,
+    data: RefCell<Tensor<IxDyn>>,
+    reduction: Reduction,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized, U: ?Sized> HingeLoss<T, U>
+where
+    T: Data,
+    U: Data<Dim = T::Dim>,
+{
+    pub(crate) fn new(input: Rc<T>, target: Rc<U>, reduction: Reduction) -> Self {
+        let data = Tensor::zeros(reduction.shape(input.data().raw_dim()));
+
+        Self {
+            input,
+            target,
+            data: RefCell::new(data),
+            reduction,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Data for HingeLoss<T, U>
+where
+    T: Data,
+    U: Data<Dim = T::Dim>,
+{
+    type Dim = IxDyn;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Cache for HingeLoss<T, U>
+where
+    T: Data,
+    U: Data<Dim = T::Dim>,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Forward for HingeLoss<T, U>
+where
+    T: Data,
+    U: Data<Dim = T::Dim>,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let (mut loss_data, input_data, target_data) = {
+            (
+                self.data.borrow_mut(),
+                self.input.data(),
+                self.target.data(),
+            )
+        };
+        let loss = Zip::from(&*input_data)
+            .and(&*target_data)
+            .map_collect(|input, target| (1. - target * input).max(0.));
+        *loss_data = self.reduction.reduce(loss, input_data.len() as f32);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Debug for HingeLoss<T, U>
+where
+    T: Data,
+    U: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HingeLoss")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("reduction", &self.reduction)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Display for HingeLoss<T, U>
+where
+    T: Data,
+    U: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ HingeLossBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct HingeLossBackward<T: ?Sized, U: ?Sized, V: ?Sized>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    V: Data<Dim = U::Dim>,
+{
+    gradient: RefCell<Option<Tensor<IxDyn>>>,
+    overwrite: Cell<bool>,
+    diff_input: Rc<T>,
+    input: Rc<U>,
+    target: Rc<V>,
+    reduction: Reduction,
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> HingeLossBackward<T, U, V>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    V: Data<Dim = U::Dim>,
+{
+    pub(crate) fn new(
+        diff_input: Rc<T>,
+        input: Rc<U>,
+        target: Rc<V>,
+        reduction: Reduction,
+    ) -> Self {
+        let gradient = Tensor::zeros(reduction.shape(input.data().raw_dim()));
+
+        Self {
+            diff_input,
+            input,
+            target,
+            gradient: RefCell::new(Some(gradient)),
+            reduction,
+            overwrite: Cell::new(true),
+        }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Gradient for HingeLossBackward<T, U, V>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    V: Data<Dim = U::Dim>,
+{
+    type Dim = IxDyn;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Overwrite for HingeLossBackward<T, U, V>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    V: Data<Dim = T::Dim>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Backward for HingeLossBackward<T, U, V>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    V: Data<Dim = T::Dim>,
+{
+    fn backward(&self) {
+        let (mut operand_gradient, gradient, input_data, target_data) = {
+            (
+                self.diff_input.gradient_mut(),
+                self.gradient(),
+                self.input.data(),
+                self.target.data(),
+            )
+        };
+
+        let n = self.reduction.scale(input_data.len() as f32);
+        let zip = Zip::from(&mut *operand_gradient)
+            .and_broadcast(&*gradient)
+            .and(&*input_data)
+            .and(&*target_data);
+        if self.diff_input.can_overwrite() {
+            zip.for_each(|op_grad, grad, input, target| {
+                *op_grad = margin_derivative(*input, *target) * grad / n
+            });
+            self.diff_input.set_overwrite(false);
+        } else {
+            zip.for_each(|op_grad, grad, input, target| {
+                *op_grad += margin_derivative(*input, *target) * grad / n
+            });
+        }
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        let shape = self.reduction.shape(self.input.data().raw_dim());
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(shape));
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Debug for HingeLossBackward<T, U, V>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    V: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HingeLossBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("reduction", &self.reduction)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Display for HingeLossBackward<T, U, V>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    V: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+/// Derivative of the hinge loss of a single element with respect to the input. The margin is
+/// active only when it is strictly positive, samples lying on the boundary get no gradient.
+fn margin_derivative(input: f32, target: f32) -> f32 {
+    if 1. - target * input > 0. {
+        -target
+    } else {
+        0.
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
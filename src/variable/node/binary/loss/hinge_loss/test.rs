@@ -0,0 +1,162 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Data, Forward,
+    Gradient, HingeLoss, HingeLossBackward, Reduction,
+};
+use ndarray::arr0;
+
+#[test]
+fn mean() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let target = new_input((3, 3), vec![1., 1., 1., -1., -1., 1., -1., 1., -1.]);
+    let input = new_input((3, 3), vec![2., 1., 0.5, -1., -0.5, 0., 0.3, -2., 1.]);
+    let loss = HingeLoss::new(input.clone(), target.clone(), Reduction::Mean);
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(0.922222).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input((3, 3), vec![0.; 9]);
+    let loss_backward = HingeLossBackward::new(input_diff.clone(), input, target, Reduction::Mean);
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (3, 3),
+            vec![
+                0., 0., -0.111111, 0., 0.111111, -0.111111, 0.111111, -0.111111, 0.111111,
+            ],
+        ),
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ 2nd Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (3, 3),
+            vec![
+                0., 0., -0.222222, 0., 0.222222, -0.222222, 0.222222, -0.222222, 0.222222,
+            ],
+        ),
+    );
+}
+
+#[test]
+fn sum() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let target = new_input((3, 3), vec![1., 1., 1., -1., -1., 1., -1., 1., -1.]);
+    let input = new_input((3, 3), vec![2., 1., 0.5, -1., -0.5, 0., 0.3, -2., 1.]);
+    let loss = HingeLoss::new(input.clone(), target.clone(), Reduction::Sum);
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(8.3).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input((3, 3), vec![0.; 9]);
+    let loss_backward = HingeLossBackward::new(input_diff.clone(), input, target, Reduction::Sum);
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor((3, 3), vec![0., 0., -1., 0., 1., -1., 1., -1., 1.]),
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ 2nd Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor((3, 3), vec![0., 0., -2., 0., 2., -2., 2., -2., 2.]),
+    );
+}
+
+#[test]
+fn boundary() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let target = new_input(4, vec![1., -1., 1., -1.]);
+    let input = new_input(4, vec![1., -1., 1., -1.]);
+    let loss = HingeLoss::new(input.clone(), target.clone(), Reduction::Sum);
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(0.).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input(4, vec![0.; 4]);
+    let loss_backward = HingeLossBackward::new(input_diff.clone(), input, target, Reduction::Sum);
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(&*input_diff.gradient(), &new_tensor(4, vec![0.; 4]));
+}
+
+#[test]
+fn debug_forward() {
+    let target = new_input((3, 3), vec![1., 1., 1., -1., -1., 1., -1., 1., -1.]);
+    let input = new_input((3, 3), vec![2., 1., 0.5, -1., -0.5, 0., 0.3, -2., 1.]);
+    let loss = HingeLoss::new(input.clone(), target.clone(), Reduction::Mean);
+
+    let output = "HingeLoss { data: 0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0, reduction: Mean, computed: false }";
+
+    assert_eq!(output, format!("{:?}", loss));
+}
+
+#[test]
+fn display_forward() {
+    let target = new_input((3, 3), vec![1., 1., 1., -1., -1., 1., -1., 1., -1.]);
+    let input = new_input((3, 3), vec![2., 1., 0.5, -1., -0.5, 0., 0.3, -2., 1.]);
+    let loss = HingeLoss::new(input.clone(), target.clone(), Reduction::Mean);
+
+    assert_eq!(format!("{}", loss.data()), format!("{}", loss));
+}
+
+#[test]
+fn debug_backward() {
+    let target = new_input((3, 3), vec![1., 1., 1., -1., -1., 1., -1., 1., -1.]);
+    let input_diff = new_backward_input((3, 3), vec![0.; 9]);
+    let input = new_input((3, 3), vec![2., 1., 0.5, -1., -0.5, 0., 0.3, -2., 1.]);
+
+    let loss = HingeLossBackward::new(input_diff.clone(), input, target, Reduction::Mean);
+
+    let output = "HingeLossBackward { gradient: Some(0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0), reduction: Mean, overwrite: true }";
+
+    assert_eq!(output, format!("{:?}", loss));
+}
+
+#[test]
+fn display_backward() {
+    let target = new_input((3, 3), vec![1., 1., 1., -1., -1., 1., -1., 1., -1.]);
+    let input_diff = new_backward_input((3, 3), vec![0.; 9]);
+    let input = new_input((3, 3), vec![2., 1., 0.5, -1., -0.5, 0., 0.3, -2., 1.]);
+
+    let loss = HingeLossBackward::new(input_diff.clone(), input, target, Reduction::Mean);
+
+    assert_eq!(format!("{}", loss.gradient()), format!("{}", loss));
+}
+
+#[test]
+fn no_grad() {
+    // HingeLossBackward
+    let node = HingeLossBackward::new(
+        new_backward_input(3, vec![0.; 3]),
+        new_input(3, vec![0.; 3]),
+        new_input(3, vec![0.; 3]),
+        Reduction::Mean,
+    );
+
+    node.no_grad();
+    assert!(node.gradient.borrow().is_none());
+
+    node.with_grad();
+    assert_eq!(&*node.gradient(), arr0(0.).into_dyn());
+}
@@ -0,0 +1,308 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite,
+    Reduction, Summary, Tensor,
+};
+use ndarray::{IxDyn, Zip};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ HuberLoss ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct HuberLoss<T: ?Sized, U: ?Sized>
+where
+    T: Data,
+    U: Data<Dim = T::Dim>,
+{
+    input: Rc<T>,
+    target: Rc<U>,
+    data: RefCell<Tensor<IxDyn>>,
+    delta: f32,
+    reduction: Reduction,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized, U: ?Sized> HuberLoss<T, U>
+where
+    T: Data,
+    U: Data<Dim = T::Dim>,
+{
+    pub(crate) fn new(input: Rc<T>, target: Rc<U>, delta: f32, reduction: Reduction) -> Self {
+        check_delta(delta);
+
+        let data = Tensor::zeros(reduction.shape(input.data().raw_dim()));
+
+        Self {
+            input,
+            target,
+            data: RefCell::new(data),
+            delta,
+            reduction,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Data for HuberLoss<T, U>
+where
+    T: Data,
+    U: Data<Dim = T::Dim>,
+{
+    type Dim = IxDyn;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Cache for HuberLoss<T, U>
+where
+    T: Data,
+    U: Data<Dim = T::Dim>,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Forward for HuberLoss<T, U>
+where
+    T: Data,
+    U: Data<Dim = T::Dim>,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let (mut loss_data, input_data, target_data) = {
+            (
+                self.data.borrow_mut(),
+                self.input.data(),
+                self.target.data(),
+            )
+        };
+        let delta = self.delta;
+        let loss = Zip::from(&*input_data)
+            .and(&*target_data)
+            .map_collect(|input, target| {
+                let diff = (input - target).abs();
+                if diff <= delta {
+                    0.5 * diff * diff
+                } else {
+                    delta * (diff - 0.5 * delta)
+                }
+            });
+        *loss_data = self.reduction.reduce(loss, input_data.len() as f32);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Debug for HuberLoss<T, U>
+where
+    T: Data,
+    U: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HuberLoss")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("delta", &self.delta)
+            .field("reduction", &self.reduction)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Display for HuberLoss<T, U>
+where
+    T: Data,
+    U: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ HuberLossBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct HuberLossBackward<T: ?Sized, U: ?Sized, V: ?Sized>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    V: Data<Dim = T::Dim>,
+{
+    gradient: RefCell<Option<Tensor<IxDyn>>>,
+    overwrite: Cell<bool>,
+    diff_input: Rc<T>,
+    input: Rc<U>,
+    target: Rc<V>,
+    delta: f32,
+    reduction: Reduction,
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> HuberLossBackward<T, U, V>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    V: Data<Dim = T::Dim>,
+{
+    pub(crate) fn new(
+        diff_input: Rc<T>,
+        input: Rc<U>,
+        target: Rc<V>,
+        delta: f32,
+        reduction: Reduction,
+    ) -> Self {
+        check_delta(delta);
+
+        let gradient = Tensor::zeros(reduction.shape(input.data().raw_dim()));
+
+        Self {
+            diff_input,
+            input,
+            target,
+            gradient: RefCell::new(Some(gradient)),
+            delta,
+            reduction,
+            overwrite: Cell::new(true),
+        }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Gradient for HuberLossBackward<T, U, V>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    V: Data<Dim = T::Dim>,
+{
+    type Dim = IxDyn;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Overwrite for HuberLossBackward<T, U, V>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    V: Data<Dim = T::Dim>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Backward for HuberLossBackward<T, U, V>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    V: Data<Dim = T::Dim>,
+{
+    fn backward(&self) {
+        let (mut operand_gradient, gradient, input_data, target_data) = {
+            (
+                self.diff_input.gradient_mut(),
+                self.gradient(),
+                self.input.data(),
+                self.target.data(),
+            )
+        };
+
+        let zip = Zip::from(&mut *operand_gradient)
+            .and_broadcast(&*gradient)
+            .and(&*input_data)
+            .and(&*target_data);
+
+        // The derivative is the error itself inside the quadratic zone and is clipped to ±delta
+        // outside of it.
+        let delta = self.delta;
+        let n = self.reduction.scale(input_data.len() as f32);
+        if self.diff_input.can_overwrite() {
+            zip.for_each(|op_grad, grad, input, target| {
+                *op_grad = (input - target).clamp(-delta, delta) * grad / n
+            });
+            self.diff_input.set_overwrite(false);
+        } else {
+            zip.for_each(|op_grad, grad, input, target| {
+                *op_grad += (input - target).clamp(-delta, delta) * grad / n
+            });
+        }
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        let shape = self.reduction.shape(self.input.data().raw_dim());
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(shape));
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Debug for HuberLossBackward<T, U, V>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    V: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HuberLossBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("delta", &self.delta)
+            .field("reduction", &self.reduction)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Display for HuberLossBackward<T, U, V>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    V: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+/// Checks that the threshold between the quadratic and the linear zone is positive.
+fn check_delta(delta: f32) {
+    assert!(
+        delta > 0.,
+        "error: the delta of the huber loss must be positive, found {}.",
+        delta
+    );
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
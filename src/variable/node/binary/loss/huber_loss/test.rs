@@ -0,0 +1,163 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Data, Forward,
+    Gradient, HuberLoss, HuberLossBackward, Reduction,
+};
+use ndarray::arr0;
+
+#[test]
+fn mean() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let target = new_input(4, vec![0.; 4]);
+    let input = new_input(4, vec![0.5, -1., 2., -3.]);
+    let loss = HuberLoss::new(input.clone(), target.clone(), 1., Reduction::Mean);
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(1.15625).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input(4, vec![0.; 4]);
+    let loss_backward =
+        HuberLossBackward::new(input_diff.clone(), input, target, 1., Reduction::Mean);
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(4, vec![0.125, -0.25, 0.25, -0.25]),
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ 2nd Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(4, vec![0.25, -0.5, 0.5, -0.5]),
+    );
+}
+
+#[test]
+fn sum() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let target = new_input(4, vec![0.; 4]);
+    let input = new_input(4, vec![0.5, -1., 2., -3.]);
+    let loss = HuberLoss::new(input.clone(), target.clone(), 1., Reduction::Sum);
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(4.625).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input(4, vec![0.; 4]);
+    let loss_backward =
+        HuberLossBackward::new(input_diff.clone(), input, target, 1., Reduction::Sum);
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(4, vec![0.5, -1., 1., -1.]),
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ 2nd Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(4, vec![1., -2., 2., -2.]),
+    );
+}
+
+#[test]
+fn transition() {
+    // At |e| = delta both branches agree on the value and on the gradient.
+    let target = new_input(2, vec![1., 1.]);
+    let input = new_input(2, vec![3., -1.]);
+    let loss = HuberLoss::new(input.clone(), target.clone(), 2., Reduction::Sum);
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(4.).into_dyn());
+
+    let input_diff = new_backward_input(2, vec![0.; 2]);
+    let loss_backward =
+        HuberLossBackward::new(input_diff.clone(), input, target, 2., Reduction::Sum);
+
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+    loss_backward.backward();
+    assert_almost_equals(&*input_diff.gradient(), &new_tensor(2, vec![2., -2.]));
+}
+
+#[test]
+#[should_panic(expected = "error: the delta of the huber loss must be positive, found 0.")]
+fn fail() {
+    HuberLoss::new(
+        new_input(2, vec![0.; 2]),
+        new_input(2, vec![0.; 2]),
+        0.,
+        Reduction::Mean,
+    );
+}
+
+#[test]
+fn debug_forward() {
+    let target = new_input(4, vec![0.; 4]);
+    let input = new_input(4, vec![0.5, -1., 2., -3.]);
+    let loss = HuberLoss::new(input, target, 1., Reduction::Mean);
+
+    let output = "HuberLoss { data: 0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0, delta: 1.0, reduction: Mean, computed: false }";
+
+    assert_eq!(output, format!("{:?}", loss));
+}
+
+#[test]
+fn display_forward() {
+    let target = new_input(4, vec![0.; 4]);
+    let input = new_input(4, vec![0.5, -1., 2., -3.]);
+    let loss = HuberLoss::new(input, target, 1., Reduction::Mean);
+
+    assert_eq!(format!("{}", loss.data()), format!("{}", loss));
+}
+
+#[test]
+fn debug_backward() {
+    let target = new_input(4, vec![0.; 4]);
+    let input_diff = new_backward_input(4, vec![0.; 4]);
+    let input = new_input(4, vec![0.5, -1., 2., -3.]);
+
+    let loss = HuberLossBackward::new(input_diff, input, target, 1., Reduction::Mean);
+
+    let output = "HuberLossBackward { gradient: Some(0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0), delta: 1.0, reduction: Mean, overwrite: true }";
+
+    assert_eq!(output, format!("{:?}", loss));
+}
+
+#[test]
+fn display_backward() {
+    let target = new_input(4, vec![0.; 4]);
+    let input_diff = new_backward_input(4, vec![0.; 4]);
+    let input = new_input(4, vec![0.5, -1., 2., -3.]);
+
+    let loss = HuberLossBackward::new(input_diff, input, target, 1., Reduction::Mean);
+
+    assert_eq!(format!("{}", loss.gradient()), format!("{}", loss));
+}
+
+#[test]
+fn no_grad() {
+    // HuberLossBackward
+    let node = HuberLossBackward::new(
+        new_backward_input(3, vec![0.; 3]),
+        new_input(3, vec![0.; 3]),
+        new_input(3, vec![0.; 3]),
+        1.,
+        Reduction::Mean,
+    );
+
+    node.no_grad();
+    assert!(node.gradient.borrow().is_none());
+
+    node.with_grad();
+    assert_eq!(&*node.gradient(), arr0(0.).into_dyn());
+}
@@ -0,0 +1,301 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, sample_gradient, sample_loss, Backward, Cache, Data, Forward,
+    Gradient, Overwrite, Reduction, Summary, Tensor,
+};
+use ndarray::{Axis, IxDyn, Zip};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ JSDivLoss ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[allow(clippy::upper_case_acronyms)]
+pub struct JSDivLoss<T: ?Sized, U: ?Sized>
+where
+    T: Data,
+    U: Data<Dim = T::Dim>,
+{
+    input: Rc<T>,
+    target: Rc<U>,
+    data: RefCell<Tensor<IxDyn>>,
+    reduction: Reduction,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized, U: ?Sized> JSDivLoss<T, U>
+where
+    T: Data,
+    U: Data<Dim = T::Dim>,
+{
+    pub(crate) fn new(input: Rc<T>, target: Rc<U>, reduction: Reduction) -> Self {
+        let data = Tensor::zeros(reduction.shape(input.data().len_of(Axis(0))));
+
+        Self {
+            input,
+            target,
+            data: RefCell::new(data),
+            reduction,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Data for JSDivLoss<T, U>
+where
+    T: Data,
+    U: Data<Dim = T::Dim>,
+{
+    type Dim = IxDyn;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Cache for JSDivLoss<T, U>
+where
+    T: Data,
+    U: Data<Dim = T::Dim>,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Forward for JSDivLoss<T, U>
+where
+    T: Data,
+    U: Data<Dim = T::Dim>,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+        self.computed.set(true);
+        let (mut loss_data, input_data, target_data) = {
+            (
+                self.data.borrow_mut(),
+                self.input.data(),
+                self.target.data(),
+            )
+        };
+        let loss = Zip::from(&*input_data)
+            .and(&*target_data)
+            .map_collect(|log, target| {
+                let input = log.exp();
+                let mixture = 0.5 * (input + target);
+                0.5 * (entropy_term(*target, mixture) + entropy_term(input, mixture))
+            });
+        *loss_data = self
+            .reduction
+            .reduce(sample_loss(loss), input_data.len_of(Axis(0)) as f32);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Debug for JSDivLoss<T, U>
+where
+    T: Data,
+    U: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JSDivLoss")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("reduction", &self.reduction)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Display for JSDivLoss<T, U>
+where
+    T: Data,
+    U: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ JSDivLossBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[allow(clippy::upper_case_acronyms)]
+pub struct JSDivLossBackward<T: ?Sized, U: ?Sized, V: ?Sized>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    V: Data<Dim = T::Dim>,
+{
+    diff_input: Rc<T>,
+    input: Rc<U>,
+    target: Rc<V>,
+    gradient: RefCell<Option<Tensor<IxDyn>>>,
+    reduction: Reduction,
+    overwrite: Cell<bool>,
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> JSDivLossBackward<T, U, V>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    V: Data<Dim = T::Dim>,
+{
+    pub(crate) fn new(
+        diff_input: Rc<T>,
+        input: Rc<U>,
+        target: Rc<V>,
+        reduction: Reduction,
+    ) -> Self {
+        let gradient = Tensor::zeros(reduction.shape(input.data().len_of(Axis(0))));
+
+        Self {
+            diff_input,
+            input,
+            target,
+            gradient: RefCell::new(Some(gradient)),
+            reduction,
+            overwrite: Cell::new(true),
+        }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Gradient for JSDivLossBackward<T, U, V>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    V: Data<Dim = T::Dim>,
+{
+    type Dim = IxDyn;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Overwrite for JSDivLossBackward<T, U, V>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    V: Data<Dim = T::Dim>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Backward for JSDivLossBackward<T, U, V>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    V: Data<Dim = T::Dim>,
+{
+    fn backward(&self) {
+        let (mut operand_gradient, gradient, input_data, target_data) = {
+            (
+                self.diff_input.gradient_mut(),
+                self.gradient(),
+                self.input.data(),
+                self.target.data(),
+            )
+        };
+        let scale = self.reduction.scale(target_data.len_of(Axis(0)) as f32);
+        let zip = Zip::from(&mut *operand_gradient)
+            .and_broadcast(sample_gradient(&gradient, target_data.ndim()))
+            .and(&*input_data)
+            .and(&*target_data);
+
+        // The derivative with respect to the input probability q is ln(q / m) / 2, where m is
+        // the mixture of the two distributions, and the chain rule through exp multiplies it
+        // by q.
+        if self.diff_input.can_overwrite() {
+            zip.for_each(|op_grad, grad, log, target| {
+                *op_grad = input_derivative(*log, *target) * grad / scale
+            });
+            self.diff_input.set_overwrite(false);
+        } else {
+            zip.for_each(|op_grad, grad, log, target| {
+                *op_grad += input_derivative(*log, *target) * grad / scale
+            });
+        }
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        let shape = self.reduction.shape(self.input.data().len_of(Axis(0)));
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(shape));
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Debug for JSDivLossBackward<T, U, V>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    V: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JSDivLossBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("reduction", &self.reduction)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Display for JSDivLossBackward<T, U, V>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    V: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+/// Computes p * ln(p / m), taking it to be 0 when p is 0.
+fn entropy_term(probability: f32, mixture: f32) -> f32 {
+    if probability > 0. {
+        probability * (probability / mixture).ln()
+    } else {
+        0.
+    }
+}
+
+/// Computes the derivative of the divergence with respect to an input log-probability.
+fn input_derivative(log: f32, target: f32) -> f32 {
+    let input = log.exp();
+    0.5 * entropy_term(input, 0.5 * (input + target))
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
@@ -0,0 +1,157 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Data, Forward,
+    Gradient, JSDivLoss, JSDivLossBackward, Reduction,
+};
+use ndarray::arr0;
+
+#[test]
+fn mean() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    // Both the target and the input hold exact zeros.
+    let target = new_input((2, 3), vec![0.2, 0.5, 0.3, 0.6, 0.0, 0.4]);
+    let v: Vec<f32> = [0.4, 0.5, 0.1, 0.0, 0.5, 0.5]
+        .iter()
+        .map(|&el: &f32| el.ln())
+        .collect();
+    let input = new_input((2, 3), v);
+
+    let loss = JSDivLoss::new(input.clone(), target.clone(), Reduction::Mean);
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(0.213583).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+    let input_diff = new_backward_input((2, 3), vec![0.; 6]);
+    let loss_backward = JSDivLossBackward::new(input_diff.clone(), input, target, Reduction::Mean);
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (2, 3),
+            vec![0.028768, 0.0000, -0.017329, 0.0000, 0.086643, 0.013170],
+        ),
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ 2nd Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &(&new_tensor(
+            (2, 3),
+            vec![0.028768, 0.0000, -0.017329, 0.0000, 0.086643, 0.013170],
+        ) * 2.),
+    );
+}
+
+#[test]
+fn sum() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let target = new_input((2, 3), vec![0.2, 0.5, 0.3, 0.6, 0.0, 0.4]);
+    let v: Vec<f32> = [0.4, 0.5, 0.1, 0.0, 0.5, 0.5]
+        .iter()
+        .map(|&el: &f32| el.ln())
+        .collect();
+    let input = new_input((2, 3), v);
+
+    let loss = JSDivLoss::new(input.clone(), target.clone(), Reduction::Sum);
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(0.427167).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+    let input_diff = new_backward_input((2, 3), vec![0.; 6]);
+    let loss_backward = JSDivLossBackward::new(input_diff.clone(), input, target, Reduction::Sum);
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (2, 3),
+            vec![0.057536, 0.0000, -0.034657, 0.0000, 0.173287, 0.026340],
+        ),
+    );
+}
+
+#[test]
+fn identical_distributions() {
+    let target = new_input((1, 3), vec![0.2, 0.0, 0.8]);
+    let v: Vec<f32> = [0.2, 0.0, 0.8].iter().map(|&el: &f32| el.ln()).collect();
+    let input = new_input((1, 3), v);
+
+    let loss = JSDivLoss::new(input, target, Reduction::Sum);
+
+    loss.forward();
+    assert!(loss.data()[[]].abs() <= f32::EPSILON);
+}
+
+#[test]
+fn debug_forward() {
+    let target = new_input((2, 3), vec![0.2, 0.5, 0.3, 0.6, 0.0, 0.4]);
+    let input = new_input((2, 3), vec![0.; 6]);
+
+    let loss = JSDivLoss::new(input, target, Reduction::Mean);
+
+    let output = "JSDivLoss { data: 0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0, reduction: Mean, computed: false }";
+
+    assert_eq!(output, format!("{:?}", loss));
+}
+
+#[test]
+fn display_forward() {
+    let target = new_input((2, 3), vec![0.2, 0.5, 0.3, 0.6, 0.0, 0.4]);
+    let input = new_input((2, 3), vec![0.; 6]);
+
+    let loss = JSDivLoss::new(input, target, Reduction::Mean);
+
+    assert_eq!(format!("{}", loss.data()), format!("{}", loss));
+}
+
+#[test]
+fn debug_backward() {
+    let target = new_input((2, 3), vec![0.2, 0.5, 0.3, 0.6, 0.0, 0.4]);
+    let input = new_input((2, 3), vec![0.; 6]);
+    let input_diff = new_backward_input((2, 3), vec![0.; 6]);
+    let loss = JSDivLossBackward::new(input_diff, input, target, Reduction::Mean);
+
+    let output = "JSDivLossBackward { gradient: Some(0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0), reduction: Mean, overwrite: true }";
+
+    assert_eq!(output, format!("{:?}", loss));
+}
+
+#[test]
+fn display_backward() {
+    let target = new_input((2, 3), vec![0.2, 0.5, 0.3, 0.6, 0.0, 0.4]);
+    let input = new_input((2, 3), vec![0.; 6]);
+    let input_diff = new_backward_input((2, 3), vec![0.; 6]);
+    let loss = JSDivLossBackward::new(input_diff, input, target, Reduction::Mean);
+
+    assert_eq!(format!("{}", loss.gradient()), format!("{}", loss));
+}
+
+#[test]
+fn no_grad() {
+    // JSDivLossBackward
+    let node = JSDivLossBackward::new(
+        new_backward_input(3, vec![0.; 3]),
+        new_input(3, vec![0.; 3]),
+        new_input(3, vec![0.; 3]),
+        Reduction::Mean,
+    );
+
+    node.no_grad();
+    assert!(node.gradient.borrow().is_none());
+
+    node.with_grad();
+    assert_eq!(&*node.gradient(), arr0(0.).into_dyn());
+}
@@ -1,10 +1,10 @@
 #[cfg(test)]
 use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
 use super::{
-    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite,
-    Reduction, Tensor,
+    expect_tensor, expect_tensor_mut, sample_gradient, sample_loss, Backward, Cache, Data, Forward,
+    Gradient, Overwrite, Reduction, Summary, Tensor,
 };
-use ndarray::{arr0, Axis, Ix0, Zip};
+use ndarray::{Axis, IxDyn, Zip};
 use std::{
     cell::{Cell, Ref, RefCell, RefMut},
     fmt::{Debug, Display},
@@ -22,7 +22,8 @@ where
 {
     input: Rc<T>,
     target: Rc<U>,
-    data: RefCell<Tensor<Ix0>>,
+    data: RefCell<Tensor<IxDyn>>,
+    log_target: bool,
     reduction: Reduction,
     computed: Cell<bool>,
 }
@@ -32,11 +33,14 @@ where
     T: Data,
     U: Data<Dim = T::Dim>,
 {
-    pub(crate) fn new(input: Rc<T>, target: Rc<U>, reduction: Reduction) -> Self {
+    pub(crate) fn new(input: Rc<T>, target: Rc<U>, log_target: bool, reduction: Reduction) -> Self {
+        let data = Tensor::zeros(reduction.shape(input.data().len_of(Axis(0))));
+
         Self {
             input,
             target,
-            data: RefCell::new(arr0(0.)),
+            data: RefCell::new(data),
+            log_target,
             reduction,
             computed: Cell::new(false),
         }
@@ -48,7 +52,7 @@ where
     T: Data,
     U: Data<Dim = T::Dim>,
 {
-    type Dim = Ix0;
+    type Dim = IxDyn;
 
     fn data(&self) -> Ref<Tensor<Self::Dim>> {
         self.data.borrow()
@@ -90,22 +94,29 @@ where
                 self.target.data(),
             )
         };
-        *loss_data = {
-            let total_loss =
-                Zip::from(&*input_data)
-                    .and(&*target_data)
-                    .fold(0.0, |loss, log, target| {
-                        if *target > 0. {
-                            loss + target * (target.ln() - log)
-                        } else {
-                            loss + 0.
-                        }
-                    });
-            match self.reduction {
-                Reduction::Mean => arr0(total_loss / input_data.len_of(Axis(0)) as f32),
-                Reduction::Sum => arr0(total_loss),
-            }
+        let zip = Zip::from(&*input_data).and(&*target_data);
+        // Zero targets contribute nothing, as 0 * ln(0) is taken to be 0.
+        let loss = if self.log_target {
+            zip.map_collect(|log, log_target| {
+                let target = log_target.exp();
+                if target > 0. {
+                    target * (log_target - log)
+                } else {
+                    0.
+                }
+            })
+        } else {
+            zip.map_collect(|log, target| {
+                if *target > 0. {
+                    target * (target.ln() - log)
+                } else {
+                    0.
+                }
+            })
         };
+        *loss_data = self
+            .reduction
+            .reduce(sample_loss(loss), input_data.len_of(Axis(0)) as f32);
     }
 }
 
@@ -116,7 +127,8 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("KLDivLoss")
-            .field("data", &self.data.borrow())
+            .field("data", &Summary(&self.data.borrow()))
+            .field("log_target", &self.log_target)
             .field("reduction", &self.reduction)
             .field("computed", &self.computed.get())
             .finish()
@@ -129,7 +141,7 @@ where
     U: Data<Dim = T::Dim>,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", &self.data.borrow())
+        write!(f, "{}", Summary(&self.data.borrow()))
     }
 }
 
@@ -144,7 +156,8 @@ where
 {
     diff_input: Rc<T>,
     target: Rc<U>,
-    gradient: RefCell<Option<Tensor<Ix0>>>,
+    gradient: RefCell<Option<Tensor<IxDyn>>>,
+    log_target: bool,
     reduction: Reduction,
     overwrite: Cell<bool>,
 }
@@ -154,11 +167,19 @@ where
     T: Gradient,
     U: Data<Dim = T::Dim>,
 {
-    pub(crate) fn new(diff_input: Rc<T>, target: Rc<U>, reduction: Reduction) -> Self {
+    pub(crate) fn new(
+        diff_input: Rc<T>,
+        target: Rc<U>,
+        log_target: bool,
+        reduction: Reduction,
+    ) -> Self {
+        let gradient = Tensor::zeros(reduction.shape(target.data().len_of(Axis(0))));
+
         Self {
             diff_input,
             target,
-            gradient: RefCell::new(Some(arr0(0.))),
+            gradient: RefCell::new(Some(gradient)),
+            log_target,
             reduction,
             overwrite: Cell::new(true),
         }
@@ -170,7 +191,7 @@ where
     T: Gradient,
     U: Data<Dim = T::Dim>,
 {
-    type Dim = Ix0;
+    type Dim = IxDyn;
 
     fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
         expect_tensor(&self.gradient)
@@ -208,28 +229,22 @@ where
                 self.target.data(),
             )
         };
+        let scale = self.reduction.scale(target_data.len_of(Axis(0)) as f32);
+        let log_target = self.log_target;
         let zip = Zip::from(&mut *operand_gradient)
-            .and_broadcast(&*gradient)
+            .and_broadcast(sample_gradient(&gradient, target_data.ndim()))
             .and(&*target_data);
 
-        match self.reduction {
-            Reduction::Mean => {
-                let n = target_data.len_of(Axis(0)) as f32;
-                if self.diff_input.can_overwrite() {
-                    zip.for_each(|op_grad, grad, target| *op_grad = -target * grad / n);
-                    self.diff_input.set_overwrite(false);
-                } else {
-                    zip.for_each(|op_grad, grad, target| *op_grad += -target * grad / n);
-                }
-            }
-            Reduction::Sum => {
-                if self.diff_input.can_overwrite() {
-                    zip.for_each(|op_grad, grad, target| *op_grad = -target * grad);
-                    self.diff_input.set_overwrite(false);
-                } else {
-                    zip.for_each(|op_grad, grad, target| *op_grad += -target * grad);
-                }
-            }
+        // The derivative with respect to the input is minus the target probability.
+        if self.diff_input.can_overwrite() {
+            zip.for_each(|op_grad, grad, target| {
+                *op_grad = -probability(*target, log_target) * grad / scale
+            });
+            self.diff_input.set_overwrite(false);
+        } else {
+            zip.for_each(|op_grad, grad, target| {
+                *op_grad += -probability(*target, log_target) * grad / scale
+            });
         }
     }
 
@@ -238,7 +253,8 @@ where
     }
 
     fn with_grad(&self) {
-        *self.gradient.borrow_mut() = Some(arr0(0.));
+        let shape = self.reduction.shape(self.target.data().len_of(Axis(0)));
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(shape));
     }
 }
 
@@ -249,7 +265,8 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("KLDivLossBackward")
-            .field("gradient", &self.gradient.borrow())
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("log_target", &self.log_target)
             .field("reduction", &self.reduction)
             .field("overwrite", &self.overwrite.get())
             .finish()
@@ -263,12 +280,21 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
 }
 
+/// Returns the probability held by a target element, which may be given in log-space.
+fn probability(target: f32, log_target: bool) -> f32 {
+    if log_target {
+        target.exp()
+    } else {
+        target
+    }
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
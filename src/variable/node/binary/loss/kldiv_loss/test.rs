@@ -8,24 +8,24 @@ use ndarray::arr0;
 fn mean() {
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     let target = new_input((2, 3), vec![0.2, 0.5, 0.3, 0.6, 0.0, 0.4]);
-    let v: Vec<f32> = vec![0.4, 0.5, 0.1, 0.6, 0.1, 0.3]
+    let v: Vec<f32> = [0.4, 0.5, 0.1, 0.6, 0.1, 0.3]
         .iter()
         .map(|&el: &f32| el.ln())
         .collect();
     let input = new_input((2, 3), v);
 
-    let loss = KLDivLoss::new(input, target.clone(), Reduction::Mean);
+    let loss = KLDivLoss::new(input, target.clone(), false, Reduction::Mean);
 
     loss.forward();
-    assert_almost_equals(&*loss.data(), &arr0(0.1530));
+    assert_almost_equals(&*loss.data(), &arr0(0.1530).into_dyn());
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
     let input_diff = new_backward_input((2, 3), vec![0.; 6]);
-    let loss_backward = KLDivLossBackward::new(input_diff.clone(), target, Reduction::Mean);
+    let loss_backward = KLDivLossBackward::new(input_diff.clone(), target, false, Reduction::Mean);
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-    *loss_backward.gradient_mut() = arr0(1.);
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     loss_backward.backward();
@@ -52,24 +52,24 @@ fn mean() {
 fn sum() {
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     let target = new_input((2, 3), vec![0.2, 0.5, 0.3, 0.6, 0.0, 0.4]);
-    let v: Vec<f32> = vec![0.4, 0.5, 0.1, 0.6, 0.1, 0.3]
+    let v: Vec<f32> = [0.4, 0.5, 0.1, 0.6, 0.1, 0.3]
         .iter()
         .map(|&el: &f32| el.ln())
         .collect();
     let input = new_input((2, 3), v);
 
-    let loss = KLDivLoss::new(input, target.clone(), Reduction::Sum);
+    let loss = KLDivLoss::new(input, target.clone(), false, Reduction::Sum);
 
     loss.forward();
-    assert_almost_equals(&*loss.data(), &arr0(0.3060));
+    assert_almost_equals(&*loss.data(), &arr0(0.3060).into_dyn());
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
     let input_diff = new_backward_input((2, 3), vec![0.; 6]);
-    let loss_backward = KLDivLossBackward::new(input_diff.clone(), target, Reduction::Sum);
+    let loss_backward = KLDivLossBackward::new(input_diff.clone(), target, false, Reduction::Sum);
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-    *loss_backward.gradient_mut() = arr0(1.);
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     loss_backward.backward();
@@ -92,18 +92,92 @@ fn sum() {
     );
 }
 
+#[test]
+fn log_target() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    // The zero target becomes -inf in log-space and must still contribute nothing.
+    let target = new_input(
+        (2, 3),
+        [0.2, 0.5, 0.3, 0.6, 0.0, 0.4]
+            .iter()
+            .map(|&el: &f32| el.ln())
+            .collect(),
+    );
+    let v: Vec<f32> = [0.4, 0.5, 0.1, 0.6, 0.1, 0.3]
+        .iter()
+        .map(|&el: &f32| el.ln())
+        .collect();
+    let input = new_input((2, 3), v);
+
+    let loss = KLDivLoss::new(input, target.clone(), true, Reduction::Mean);
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(0.1530).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+    let input_diff = new_backward_input((2, 3), vec![0.; 6]);
+    let loss_backward = KLDivLossBackward::new(input_diff.clone(), target, true, Reduction::Mean);
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (2, 3),
+            vec![-0.1000, -0.2500, -0.1500, -0.3000, 0.0000, -0.2000],
+        ),
+    );
+}
+
+#[test]
+fn none() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let target = new_input((2, 3), vec![0.2, 0.5, 0.3, 0.6, 0.0, 0.4]);
+    let v: Vec<f32> = [0.4, 0.5, 0.1, 0.6, 0.1, 0.3]
+        .iter()
+        .map(|&el: &f32| el.ln())
+        .collect();
+    let input = new_input((2, 3), v);
+
+    let loss = KLDivLoss::new(input, target.clone(), false, Reduction::None);
+
+    loss.forward();
+    assert_almost_equals(
+        &*loss.data(),
+        &new_tensor(2, vec![0.1910, 0.1151]).into_dyn(),
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input((2, 3), vec![0.; 6]);
+    let loss_backward = KLDivLossBackward::new(input_diff.clone(), target, false, Reduction::None);
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = new_tensor(2, vec![1., 2.]).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor((2, 3), vec![-0.2, -0.5, -0.3, -1.2, 0., -0.8]),
+    );
+}
+
 #[test]
 fn debug_forward() {
     let target = new_input((2, 3), vec![0.2, 0.5, 0.3, 0.6, 0.0, 0.4]);
-    let v: Vec<f32> = vec![0.4, 0.5, 0.1, 0.6, 0.1, 0.3]
+    let v: Vec<f32> = [0.4, 0.5, 0.1, 0.6, 0.1, 0.3]
         .iter()
         .map(|&el: &f32| el.ln())
         .collect();
     let input = new_input((2, 3), v);
 
-    let loss = KLDivLoss::new(input, target.clone(), Reduction::Mean);
+    let loss = KLDivLoss::new(input, target.clone(), false, Reduction::Mean);
 
-    let output = "KLDivLoss { data: 0.0, shape=[], strides=[], layout=CFcf (0xf), const ndim=0, reduction: Mean, computed: false }";
+    let output = "KLDivLoss { data: 0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0, log_target: false, reduction: Mean, computed: false }";
 
     assert_eq!(output, format!("{:?}", loss));
 }
@@ -111,13 +185,13 @@ fn debug_forward() {
 #[test]
 fn display_forward() {
     let target = new_input((2, 3), vec![0.2, 0.5, 0.3, 0.6, 0.0, 0.4]);
-    let v: Vec<f32> = vec![0.4, 0.5, 0.1, 0.6, 0.1, 0.3]
+    let v: Vec<f32> = [0.4, 0.5, 0.1, 0.6, 0.1, 0.3]
         .iter()
         .map(|&el: &f32| el.ln())
         .collect();
     let input = new_input((2, 3), v);
 
-    let loss = KLDivLoss::new(input, target.clone(), Reduction::Mean);
+    let loss = KLDivLoss::new(input, target.clone(), false, Reduction::Mean);
 
     assert_eq!(format!("{}", loss.data()), format!("{}", loss));
 }
@@ -126,9 +200,9 @@ fn display_forward() {
 fn debug_backward() {
     let target = new_input((2, 3), vec![0.2, 0.5, 0.3, 0.6, 0.0, 0.4]);
     let input_diff = new_backward_input((2, 3), vec![0.; 6]);
-    let loss = KLDivLossBackward::new(input_diff.clone(), target, Reduction::Mean);
+    let loss = KLDivLossBackward::new(input_diff.clone(), target, false, Reduction::Mean);
 
-    let output = "KLDivLossBackward { gradient: Some(0.0, shape=[], strides=[], layout=CFcf (0xf), const ndim=0), reduction: Mean, overwrite: true }";
+    let output = "KLDivLossBackward { gradient: Some(0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0), log_target: false, reduction: Mean, overwrite: true }";
 
     assert_eq!(output, format!("{:?}", loss));
 }
@@ -137,7 +211,7 @@ fn debug_backward() {
 fn display_backward() {
     let target = new_input((2, 3), vec![0.2, 0.5, 0.3, 0.6, 0.0, 0.4]);
     let input_diff = new_backward_input((2, 3), vec![0.; 6]);
-    let loss = KLDivLossBackward::new(input_diff.clone(), target, Reduction::Mean);
+    let loss = KLDivLossBackward::new(input_diff.clone(), target, false, Reduction::Mean);
 
     assert_eq!(format!("{}", loss.gradient()), format!("{}", loss));
 }
@@ -148,6 +222,7 @@ fn no_grad() {
     let node = KLDivLossBackward::new(
         new_backward_input(3, vec![0.; 3]),
         new_input(3, vec![0.; 3]),
+        false,
         Reduction::Mean,
     );
 
@@ -155,5 +230,5 @@ fn no_grad() {
     assert!(node.gradient.borrow().is_none());
 
     node.with_grad();
-    assert_eq!(&*node.gradient(), arr0(0.));
+    assert_eq!(&*node.gradient(), arr0(0.).into_dyn());
 }
@@ -12,14 +12,14 @@ fn mean() {
     let loss = MAELoss::new(input.clone(), target.clone(), Reduction::Mean);
 
     loss.forward();
-    assert_almost_equals(&*loss.data(), &arr0(9.));
+    assert_almost_equals(&*loss.data(), &arr0(9.).into_dyn());
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     let input_diff = new_backward_input((3, 3), vec![0.; 9]);
     let loss_backward = MAELossBackward::new(input_diff.clone(), input, target, Reduction::Mean);
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-    *loss_backward.gradient_mut() = arr0(1.);
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     loss_backward.backward();
@@ -44,14 +44,14 @@ fn sum() {
     let loss = MAELoss::new(input.clone(), target.clone(), Reduction::Sum);
 
     loss.forward();
-    assert_almost_equals(&*loss.data(), &arr0(81.));
+    assert_almost_equals(&*loss.data(), &arr0(81.).into_dyn());
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     let input_diff = new_backward_input((3, 3), vec![0.; 9]);
     let loss_backward = MAELossBackward::new(input_diff.clone(), input, target, Reduction::Sum);
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-    *loss_backward.gradient_mut() = arr0(1.);
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     loss_backward.backward();
@@ -68,7 +68,7 @@ fn debug_forward() {
     let input = new_input((3, 3), vec![10., 11., 12., 13., 14., 15., 16., 17., 18.]);
     let loss = MAELoss::new(input.clone(), target.clone(), Reduction::Mean);
 
-    let output = "MAELoss { data: 0.0, shape=[], strides=[], layout=CFcf (0xf), const ndim=0, reduction: Mean, computed: false }";
+    let output = "MAELoss { data: 0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0, reduction: Mean, computed: false }";
 
     assert_eq!(output, format!("{:?}", loss));
 }
@@ -90,7 +90,7 @@ fn debug_backward() {
 
     let loss = MAELossBackward::new(input_diff.clone(), input, target, Reduction::Mean);
 
-    let output = "MAELossBackward { gradient: Some(0.0, shape=[], strides=[], layout=CFcf (0xf), const ndim=0), reduction: Mean, overwrite: true }";
+    let output = "MAELossBackward { gradient: Some(0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0), reduction: Mean, overwrite: true }";
 
     assert_eq!(output, format!("{:?}", loss));
 }
@@ -120,5 +120,5 @@ fn no_grad() {
     assert!(node.gradient.borrow().is_none());
 
     node.with_grad();
-    assert_eq!(&*node.gradient(), arr0(0.));
+    assert_eq!(&*node.gradient(), arr0(0.).into_dyn());
 }
@@ -1,22 +1,83 @@
 mod bce_loss;
 mod bce_with_logits_loss;
+mod ctc_loss;
+mod focal_loss;
+mod hinge_loss;
+mod huber_loss;
+mod jsdiv_loss;
 mod kldiv_loss;
 mod mae_loss;
 mod mse_loss;
+mod multi_margin_loss;
 mod nll_loss;
 
 use super::{
-    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite, Tensor,
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite, Summary,
+    Tensor,
 };
 
 use crate::nn::loss::Reduction;
+use ndarray::{arr0, Array, ArrayViewD, Axis, Dimension, IntoDimension, Ix1, IxDyn};
 
 #[cfg(test)]
 use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
 
 pub(crate) use bce_loss::{BCELoss, BCELossBackward};
 pub(crate) use bce_with_logits_loss::{BCEWithLogitsLoss, BCEWithLogitsLossBackward};
+pub(crate) use ctc_loss::{CTCLoss, CTCLossBackward};
+pub(crate) use focal_loss::{FocalLoss, FocalLossBackward};
+pub(crate) use hinge_loss::{HingeLoss, HingeLossBackward};
+pub(crate) use huber_loss::{HuberLoss, HuberLossBackward};
+pub(crate) use jsdiv_loss::{JSDivLoss, JSDivLossBackward};
 pub(crate) use kldiv_loss::{KLDivLoss, KLDivLossBackward};
 pub(crate) use mae_loss::{MAELoss, MAELossBackward};
 pub(crate) use mse_loss::{MSELoss, MSELossBackward};
+pub(crate) use multi_margin_loss::{MultiMarginLoss, MultiMarginLossBackward};
 pub(crate) use nll_loss::{NLLLoss, NLLLossBackward};
+
+impl Reduction {
+    /// Returns the shape of the output of a loss whose unreduced value has shape `shape`.
+    pub(crate) fn shape<Sh: IntoDimension>(&self, shape: Sh) -> IxDyn {
+        match self {
+            Reduction::None => shape.into_dimension().into_dyn(),
+            Reduction::Sum | Reduction::Mean => IxDyn(&[]),
+        }
+    }
+
+    /// Reduces the unreduced `loss`, whose sum is divided by `denominator` for the mean.
+    pub(crate) fn reduce<D: Dimension>(&self, loss: Tensor<D>, denominator: f32) -> Tensor<IxDyn> {
+        match self {
+            Reduction::None => loss.into_dyn(),
+            Reduction::Sum => arr0(loss.sum()).into_dyn(),
+            Reduction::Mean => arr0(loss.sum() / denominator).into_dyn(),
+        }
+    }
+
+    /// Returns the number by which the gradient of the unreduced loss is divided, that is
+    /// `denominator` for the mean and one otherwise.
+    pub(crate) fn scale(&self, denominator: f32) -> f32 {
+        match self {
+            Reduction::Mean => denominator,
+            Reduction::None | Reduction::Sum => 1.,
+        }
+    }
+}
+
+/// Sums the unreduced `loss` over all its axes but the first one, giving the loss of each sample.
+pub(crate) fn sample_loss<D: Dimension>(loss: Tensor<D>) -> Tensor<Ix1> {
+    let loss = loss.into_dyn();
+    Array::from_iter(loss.outer_iter().map(|sample| sample.sum()))
+}
+
+/// Returns a view of the `gradient` of a loss that was computed for each sample, which
+/// broadcasts over the remaining axes of an input with `ndim` dimensions.
+pub(crate) fn sample_gradient(gradient: &Tensor<IxDyn>, ndim: usize) -> ArrayViewD<f32> {
+    let mut gradient = gradient.view();
+    if gradient.ndim() > 0 {
+        while gradient.ndim() < ndim {
+            gradient.insert_axis_inplace(Axis(gradient.ndim()));
+        }
+    }
+
+    gradient
+}
@@ -2,9 +2,9 @@
 use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
 use super::{
     expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite,
-    Reduction, Tensor,
+    Reduction, Summary, Tensor,
 };
-use ndarray::{arr0, Ix0, Zip};
+use ndarray::{IxDyn, Zip};
 use std::{
     cell::{Cell, Ref, RefCell, RefMut},
     fmt::{Debug, Display},
@@ -22,7 +22,7 @@ where
 {
     input: Rc<T>,
     target: Rc<U>,
-    data: RefCell<Tensor<Ix0>>,
+    data: RefCell<Tensor<IxDyn>>,
     reduction: Reduction,
     computed: Cell<bool>,
 }
@@ -33,10 +33,12 @@ where
     U: Data<Dim = T::Dim>,
 {
     pub(crate) fn new(input: Rc<T>, target: Rc<U>, reduction: Reduction) -> Self {
+        let data = Tensor::zeros(reduction.shape(input.data().raw_dim()));
+
         Self {
             input,
             target,
-            data: RefCell::new(arr0(0.)),
+            data: RefCell::new(data),
             reduction,
             computed: Cell::new(false),
         }
@@ -48,7 +50,7 @@ where
     T: Data,
     U: Data<Dim = T::Dim>,
 {
-    type Dim = Ix0;
+    type Dim = IxDyn;
 
     fn data(&self) -> Ref<Tensor<Self::Dim>> {
         self.data.borrow()
@@ -91,15 +93,10 @@ where
                 self.target.data(),
             )
         };
-        *loss_data = {
-            let total_loss = Zip::from(&*input_data)
-                .and(&*target_data)
-                .fold(0.0, |loss, input, target| loss + (input - target).powi(2));
-            match self.reduction {
-                Reduction::Mean => arr0(total_loss / input_data.len() as f32),
-                Reduction::Sum => arr0(total_loss),
-            }
-        };
+        let loss = Zip::from(&*input_data)
+            .and(&*target_data)
+            .map_collect(|input, target| (input - target).powi(2));
+        *loss_data = self.reduction.reduce(loss, input_data.len() as f32);
     }
 }
 
@@ -110,7 +107,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MSELoss")
-            .field("data", &self.data.borrow())
+            .field("data", &Summary(&self.data.borrow()))
             .field("reduction", &self.reduction)
             .field("computed", &self.computed.get())
             .finish()
@@ -123,7 +120,7 @@ where
     U: Data<Dim = T::Dim>,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", &self.data.borrow())
+        write!(f, "{}", Summary(&self.data.borrow()))
     }
 }
 
@@ -137,7 +134,7 @@ where
     U: Data<Dim = T::Dim>,
     V: Data<Dim = U::Dim>,
 {
-    gradient: RefCell<Option<Tensor<Ix0>>>,
+    gradient: RefCell<Option<Tensor<IxDyn>>>,
     overwrite: Cell<bool>,
     diff_input: Rc<T>,
     input: Rc<U>,
@@ -157,11 +154,13 @@ where
         target: Rc<V>,
         reduction: Reduction,
     ) -> Self {
+        let gradient = Tensor::zeros(reduction.shape(input.data().raw_dim()));
+
         Self {
             diff_input,
             input,
             target,
-            gradient: RefCell::new(Some(arr0(0.))),
+            gradient: RefCell::new(Some(gradient)),
             reduction,
             overwrite: Cell::new(true),
         }
@@ -174,7 +173,7 @@ where
     U: Data<Dim = T::Dim>,
     V: Data<Dim = U::Dim>,
 {
-    type Dim = Ix0;
+    type Dim = IxDyn;
 
     fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
         expect_tensor(&self.gradient)
@@ -216,36 +215,20 @@ where
             )
         };
 
+        let n = self.reduction.scale(input_data.len() as f32);
         let zip = Zip::from(&mut *operand_gradient)
             .and_broadcast(&*gradient)
             .and(&*input_data)
             .and(&*target_data);
-        match self.reduction {
-            Reduction::Mean => {
-                let n = input_data.len() as f32;
-                if self.diff_input.can_overwrite() {
-                    zip.for_each(|op_grad, grad, input, target| {
-                        *op_grad = (2.0 * (input - target)) * grad / n
-                    });
-                    self.diff_input.set_overwrite(false);
-                } else {
-                    zip.for_each(|op_grad, grad, input, target| {
-                        *op_grad += (2.0 * (input - target)) * grad / n
-                    });
-                }
-            }
-            Reduction::Sum => {
-                if self.diff_input.can_overwrite() {
-                    zip.for_each(|op_grad, grad, input, target| {
-                        *op_grad = (2.0 * (input - target)) * grad
-                    });
-                    self.diff_input.set_overwrite(false);
-                } else {
-                    zip.for_each(|op_grad, grad, input, target| {
-                        *op_grad += (2.0 * (input - target)) * grad
-                    });
-                }
-            }
+        if self.diff_input.can_overwrite() {
+            zip.for_each(|op_grad, grad, input, target| {
+                *op_grad = (2.0 * (input - target)) * grad / n
+            });
+            self.diff_input.set_overwrite(false);
+        } else {
+            zip.for_each(|op_grad, grad, input, target| {
+                *op_grad += (2.0 * (input - target)) * grad / n
+            });
         }
     }
 
@@ -254,7 +237,8 @@ where
     }
 
     fn with_grad(&self) {
-        *self.gradient.borrow_mut() = Some(arr0(0.));
+        let shape = self.reduction.shape(self.input.data().raw_dim());
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(shape));
     }
 }
 
@@ -266,7 +250,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MSELossBackward")
-            .field("gradient", &self.gradient.borrow())
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
             .field("reduction", &self.reduction)
             .field("overwrite", &self.overwrite.get())
             .finish()
@@ -281,7 +265,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
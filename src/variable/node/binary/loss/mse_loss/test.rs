@@ -12,14 +12,14 @@ fn mean() {
     let loss = MSELoss::new(input.clone(), target.clone(), Reduction::Mean);
 
     loss.forward();
-    assert_almost_equals(&*loss.data(), &arr0(81.));
+    assert_almost_equals(&*loss.data(), &arr0(81.).into_dyn());
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     let input_diff = new_backward_input((3, 3), vec![0.; 9]);
     let loss_backward = MSELossBackward::new(input_diff.clone(), input, target, Reduction::Mean);
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-    *loss_backward.gradient_mut() = arr0(1.);
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     loss_backward.backward();
@@ -38,14 +38,14 @@ fn sum() {
     let loss = MSELoss::new(input.clone(), target.clone(), Reduction::Sum);
 
     loss.forward();
-    assert_almost_equals(&*loss.data(), &arr0(729.));
+    assert_almost_equals(&*loss.data(), &arr0(729.).into_dyn());
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     let input_diff = new_backward_input((3, 3), vec![0.; 9]);
     let loss_backward = MSELossBackward::new(input_diff.clone(), input, target, Reduction::Sum);
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-    *loss_backward.gradient_mut() = arr0(1.);
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     loss_backward.backward();
@@ -56,13 +56,39 @@ fn sum() {
     assert_almost_equals(&*input_diff.gradient(), &new_tensor((3, 3), vec![36.; 9]));
 }
 
+#[test]
+fn none() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let target = new_input((3, 3), vec![1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+    let input = new_input((3, 3), vec![10., 11., 12., 13., 14., 15., 16., 17., 18.]);
+    let loss = MSELoss::new(input.clone(), target.clone(), Reduction::None);
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &new_tensor((3, 3), vec![81.; 9]).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input((3, 3), vec![0.; 9]);
+    let loss_backward = MSELossBackward::new(input_diff.clone(), input, target, Reduction::None);
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() =
+        new_tensor((3, 3), vec![1., 2., 3., 1., 2., 3., 1., 2., 3.]).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor((3, 3), vec![18., 36., 54., 18., 36., 54., 18., 36., 54.]),
+    );
+}
+
 #[test]
 fn debug_forward() {
     let target = new_input((3, 3), vec![1., 2., 3., 4., 5., 6., 7., 8., 9.]);
     let input = new_input((3, 3), vec![10., 11., 12., 13., 14., 15., 16., 17., 18.]);
     let loss = MSELoss::new(input.clone(), target.clone(), Reduction::Mean);
 
-    let output = "MSELoss { data: 0.0, shape=[], strides=[], layout=CFcf (0xf), const ndim=0, reduction: Mean, computed: false }";
+    let output = "MSELoss { data: 0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0, reduction: Mean, computed: false }";
 
     assert_eq!(output, format!("{:?}", loss));
 }
@@ -84,7 +110,7 @@ fn debug_backward() {
 
     let loss = MSELossBackward::new(input_diff.clone(), input, target, Reduction::Mean);
 
-    let output = "MSELossBackward { gradient: Some(0.0, shape=[], strides=[], layout=CFcf (0xf), const ndim=0), reduction: Mean, overwrite: true }";
+    let output = "MSELossBackward { gradient: Some(0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0), reduction: Mean, overwrite: true }";
 
     assert_eq!(output, format!("{:?}", loss));
 }
@@ -114,5 +140,5 @@ fn no_grad() {
     assert!(node.gradient.borrow().is_none());
 
     node.with_grad();
-    assert_eq!(&*node.gradient(), arr0(0.));
+    assert_eq!(&*node.gradient(), arr0(0.).into_dyn());
 }
@@ -0,0 +1,332 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, sample_gradient, Backward, Cache, Data, Forward, Gradient,
+    Overwrite, Reduction, Summary, Tensor,
+};
+use ndarray::{Axis, Ix1, Ix2, IxDyn, Zip};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ MultiMarginLoss ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct MultiMarginLoss<T: ?Sized, U: ?Sized>
+where
+    T: Data<Dim = Ix2>,
+    U: Data<Dim = Ix1>,
+{
+    input: Rc<T>,
+    target: Rc<U>,
+    data: RefCell<Tensor<IxDyn>>,
+    margin: f32,
+    p: i32,
+    reduction: Reduction,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized, U: ?Sized> MultiMarginLoss<T, U>
+where
+    T: Data<Dim = Ix2>,
+    U: Data<Dim = Ix1>,
+{
+    pub(crate) fn new(
+        input: Rc<T>,
+        target: Rc<U>,
+        margin: f32,
+        p: i32,
+        reduction: Reduction,
+    ) -> Self {
+        check_p(p);
+        let data = Tensor::zeros(reduction.shape(input.data().len_of(Axis(0))));
+
+        Self {
+            input,
+            target,
+            data: RefCell::new(data),
+            margin,
+            p,
+            reduction,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Data for MultiMarginLoss<T, U>
+where
+    T: Data<Dim = Ix2>,
+    U: Data<Dim = Ix1>,
+{
+    type Dim = IxDyn;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Cache for MultiMarginLoss<T, U>
+where
+    T: Data<Dim = Ix2>,
+    U: Data<Dim = Ix1>,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Forward for MultiMarginLoss<T, U>
+where
+    T: Data<Dim = Ix2>,
+    U: Data<Dim = Ix1>,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let (mut loss_data, input_data, target_data) = {
+            (
+                self.data.borrow_mut(),
+                self.input.data(),
+                self.target.data(),
+            )
+        };
+        let classes = input_data.len_of(Axis(1)) as f32;
+        let loss = Zip::from(input_data.rows())
+            .and(&*target_data)
+            .map_collect(|logits, target| {
+                let target = *target as usize;
+                let correct = logits[target];
+                let sample_loss = logits
+                    .iter()
+                    .enumerate()
+                    .filter(|(class, _)| *class != target)
+                    .map(|(_, logit)| (self.margin - correct + logit).max(0.).powi(self.p))
+                    .sum::<f32>();
+                sample_loss / classes
+            });
+        *loss_data = self
+            .reduction
+            .reduce(loss, input_data.len_of(Axis(0)) as f32);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Debug for MultiMarginLoss<T, U>
+where
+    T: Data<Dim = Ix2>,
+    U: Data<Dim = Ix1>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultiMarginLoss")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("margin", &self.margin)
+            .field("p", &self.p)
+            .field("reduction", &self.reduction)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Display for MultiMarginLoss<T, U>
+where
+    T: Data<Dim = Ix2>,
+    U: Data<Dim = Ix1>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ MultiMarginLossBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct MultiMarginLossBackward<T: ?Sized, U: ?Sized, V: ?Sized>
+where
+    T: Gradient<Dim = Ix2>,
+    U: Data<Dim = Ix2>,
+    V: Data<Dim = Ix1>,
+{
+    gradient: RefCell<Option<Tensor<IxDyn>>>,
+    overwrite: Cell<bool>,
+    diff_input: Rc<T>,
+    input: Rc<U>,
+    target: Rc<V>,
+    margin: f32,
+    p: i32,
+    reduction: Reduction,
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> MultiMarginLossBackward<T, U, V>
+where
+    T: Gradient<Dim = Ix2>,
+    U: Data<Dim = Ix2>,
+    V: Data<Dim = Ix1>,
+{
+    pub(crate) fn new(
+        diff_input: Rc<T>,
+        input: Rc<U>,
+        target: Rc<V>,
+        margin: f32,
+        p: i32,
+        reduction: Reduction,
+    ) -> Self {
+        check_p(p);
+        let gradient = Tensor::zeros(reduction.shape(input.data().len_of(Axis(0))));
+
+        Self {
+            diff_input,
+            input,
+            target,
+            gradient: RefCell::new(Some(gradient)),
+            margin,
+            p,
+            reduction,
+            overwrite: Cell::new(true),
+        }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Gradient for MultiMarginLossBackward<T, U, V>
+where
+    T: Gradient<Dim = Ix2>,
+    U: Data<Dim = Ix2>,
+    V: Data<Dim = Ix1>,
+{
+    type Dim = IxDyn;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Overwrite for MultiMarginLossBackward<T, U, V>
+where
+    T: Gradient<Dim = Ix2>,
+    U: Data<Dim = Ix2>,
+    V: Data<Dim = Ix1>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Backward for MultiMarginLossBackward<T, U, V>
+where
+    T: Gradient<Dim = Ix2>,
+    U: Data<Dim = Ix2>,
+    V: Data<Dim = Ix1>,
+{
+    fn backward(&self) {
+        let (mut operand_gradient, gradient, input_data, target_data) = {
+            (
+                self.diff_input.gradient_mut(),
+                self.gradient(),
+                self.input.data(),
+                self.target.data(),
+            )
+        };
+
+        if self.diff_input.can_overwrite() {
+            operand_gradient.fill(0.);
+            self.diff_input.set_overwrite(false);
+        }
+
+        let scale = self.reduction.scale(input_data.len_of(Axis(0)) as f32)
+            * input_data.len_of(Axis(1)) as f32;
+        Zip::from(operand_gradient.rows_mut())
+            .and(input_data.rows())
+            .and(&*target_data)
+            .and_broadcast(sample_gradient(&gradient, 1))
+            .for_each(|mut op_grad, logits, target, grad| {
+                let grad = grad / scale;
+                let target = *target as usize;
+                let correct = logits[target];
+                let mut violated = 0.;
+                for (class, logit) in logits.iter().enumerate() {
+                    let margin = self.margin - correct + logit;
+                    if class == target || margin <= 0. {
+                        continue;
+                    }
+                    let derivative = self.p as f32 * margin.powi(self.p - 1) * grad;
+                    op_grad[class] += derivative;
+                    violated += derivative;
+                }
+                op_grad[target] -= violated;
+            });
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        let shape = self.reduction.shape(self.input.data().len_of(Axis(0)));
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(shape));
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Debug for MultiMarginLossBackward<T, U, V>
+where
+    T: Gradient<Dim = Ix2>,
+    U: Data<Dim = Ix2>,
+    V: Data<Dim = Ix1>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultiMarginLossBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("margin", &self.margin)
+            .field("p", &self.p)
+            .field("reduction", &self.reduction)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized, V: ?Sized> Display for MultiMarginLossBackward<T, U, V>
+where
+    T: Gradient<Dim = Ix2>,
+    U: Data<Dim = Ix2>,
+    V: Data<Dim = Ix1>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+/// Checks that the exponent of the margins is either 1 or 2.
+fn check_p(p: i32) {
+    assert!(
+        p == 1 || p == 2,
+        "error: the exponent of the multi margin loss must be either 1 or 2, got {}.",
+        p
+    );
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
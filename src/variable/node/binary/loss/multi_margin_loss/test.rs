@@ -0,0 +1,185 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Data, Forward,
+    Gradient, MultiMarginLoss, MultiMarginLossBackward, Reduction,
+};
+use ndarray::arr0;
+
+#[test]
+fn mean() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let target = new_input(2, vec![2., 0.]);
+    let input = new_input((2, 3), vec![0.1, 0.2, 0.4, 0.8, 0.3, -0.2]);
+    let loss = MultiMarginLoss::new(input.clone(), target.clone(), 1., 1, Reduction::Mean);
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(0.333333).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input((2, 3), vec![0.; 6]);
+    let loss_backward =
+        MultiMarginLossBackward::new(input_diff.clone(), input, target, 1., 1, Reduction::Mean);
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (2, 3),
+            vec![0.166667, 0.166667, -0.333333, -0.166667, 0.166667, 0.],
+        ),
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ 2nd Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (2, 3),
+            vec![0.333333, 0.333333, -0.666667, -0.333333, 0.333333, 0.],
+        ),
+    );
+}
+
+#[test]
+fn sum() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let target = new_input(2, vec![2., 0.]);
+    let input = new_input((2, 3), vec![0.1, 0.2, 0.4, 0.8, 0.3, -0.2]);
+    let loss = MultiMarginLoss::new(input.clone(), target.clone(), 1., 2, Reduction::Sum);
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(0.46).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input((2, 3), vec![0.; 6]);
+    let loss_backward =
+        MultiMarginLossBackward::new(input_diff.clone(), input, target, 1., 2, Reduction::Sum);
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (2, 3),
+            vec![0.466667, 0.533333, -1., -0.333333, 0.333333, 0.],
+        ),
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ 2nd Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (2, 3),
+            vec![0.933333, 1.066667, -2., -0.666667, 0.666667, 0.],
+        ),
+    );
+}
+
+#[test]
+fn boundary() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let target = new_input(2, vec![0., 1.]);
+    let input = new_input((2, 3), vec![1., 0., -1., -0.5, 0.5, -0.5]);
+    let loss = MultiMarginLoss::new(input.clone(), target.clone(), 1., 1, Reduction::Sum);
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(0.).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input((2, 3), vec![0.; 6]);
+    let loss_backward =
+        MultiMarginLossBackward::new(input_diff.clone(), input, target, 1., 1, Reduction::Sum);
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(&*input_diff.gradient(), &new_tensor((2, 3), vec![0.; 6]));
+}
+
+#[test]
+#[should_panic(
+    expected = "error: the exponent of the multi margin loss must be either 1 or 2, got 3."
+)]
+fn wrong_p() {
+    MultiMarginLoss::new(
+        new_input((2, 3), vec![0.; 6]),
+        new_input(2, vec![0.; 2]),
+        1.,
+        3,
+        Reduction::Mean,
+    );
+}
+
+#[test]
+fn debug_forward() {
+    let target = new_input(2, vec![2., 0.]);
+    let input = new_input((2, 3), vec![0.1, 0.2, 0.4, 0.8, 0.3, -0.2]);
+    let loss = MultiMarginLoss::new(input.clone(), target.clone(), 1., 1, Reduction::Mean);
+
+    let output = "MultiMarginLoss { data: 0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0, margin: 1.0, p: 1, reduction: Mean, computed: false }";
+
+    assert_eq!(output, format!("{:?}", loss));
+}
+
+#[test]
+fn display_forward() {
+    let target = new_input(2, vec![2., 0.]);
+    let input = new_input((2, 3), vec![0.1, 0.2, 0.4, 0.8, 0.3, -0.2]);
+    let loss = MultiMarginLoss::new(input.clone(), target.clone(), 1., 1, Reduction::Mean);
+
+    assert_eq!(format!("{}", loss.data()), format!("{}", loss));
+}
+
+#[test]
+fn debug_backward() {
+    let target = new_input(2, vec![2., 0.]);
+    let input_diff = new_backward_input((2, 3), vec![0.; 6]);
+    let input = new_input((2, 3), vec![0.1, 0.2, 0.4, 0.8, 0.3, -0.2]);
+
+    let loss =
+        MultiMarginLossBackward::new(input_diff.clone(), input, target, 1., 1, Reduction::Mean);
+
+    let output = "MultiMarginLossBackward { gradient: Some(0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0), margin: 1.0, p: 1, reduction: Mean, overwrite: true }";
+
+    assert_eq!(output, format!("{:?}", loss));
+}
+
+#[test]
+fn display_backward() {
+    let target = new_input(2, vec![2., 0.]);
+    let input_diff = new_backward_input((2, 3), vec![0.; 6]);
+    let input = new_input((2, 3), vec![0.1, 0.2, 0.4, 0.8, 0.3, -0.2]);
+
+    let loss =
+        MultiMarginLossBackward::new(input_diff.clone(), input, target, 1., 1, Reduction::Mean);
+
+    assert_eq!(format!("{}", loss.gradient()), format!("{}", loss));
+}
+
+#[test]
+fn no_grad() {
+    // MultiMarginLossBackward
+    let node = MultiMarginLossBackward::new(
+        new_backward_input((2, 3), vec![0.; 6]),
+        new_input((2, 3), vec![0.; 6]),
+        new_input(2, vec![0.; 2]),
+        1.,
+        1,
+        Reduction::Mean,
+    );
+
+    node.no_grad();
+    assert!(node.gradient.borrow().is_none());
+
+    node.with_grad();
+    assert_eq!(&*node.gradient(), arr0(0.).into_dyn());
+}
@@ -2,9 +2,9 @@
 use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
 use super::{
     expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite,
-    Reduction, Tensor,
+    Reduction, Summary, Tensor,
 };
-use ndarray::{arr0, Axis, Dimension, IntoDimension, Ix0, Zip};
+use ndarray::{Axis, Dimension, IntoDimension, IxDyn, Zip};
 use std::{
     cell::{Cell, Ref, RefCell, RefMut},
     fmt::{Debug, Display},
@@ -23,7 +23,10 @@ where
 {
     input: Rc<T>,
     target: Rc<U>,
-    data: RefCell<Tensor<Ix0>>,
+    data: RefCell<Tensor<IxDyn>>,
+    weight: Option<Vec<f32>>,
+    ignore_index: Option<usize>,
+    label_smoothing: f32,
     reduction: Reduction,
     computed: Cell<bool>,
 }
@@ -34,11 +37,26 @@ where
     T::Dim: Copy,
     U: Data,
 {
-    pub(crate) fn new(input: Rc<T>, target: Rc<U>, reduction: Reduction) -> Self {
+    pub(crate) fn new(
+        input: Rc<T>,
+        target: Rc<U>,
+        weight: Option<Vec<f32>>,
+        ignore_index: Option<usize>,
+        label_smoothing: f32,
+        reduction: Reduction,
+    ) -> Self {
+        check_weight(input.data().shape()[1], &weight);
+        check_label_smoothing(label_smoothing);
+
+        let data = Tensor::zeros(reduction.shape(target.data().raw_dim()));
+
         Self {
             input,
             target,
-            data: RefCell::new(arr0(0.)),
+            data: RefCell::new(data),
+            weight,
+            ignore_index,
+            label_smoothing,
             reduction,
             computed: Cell::new(false),
         }
@@ -51,7 +69,7 @@ where
     T::Dim: Copy,
     U: Data,
 {
-    type Dim = Ix0;
+    type Dim = IxDyn;
 
     fn data(&self) -> Ref<Tensor<Self::Dim>> {
         self.data.borrow()
@@ -95,21 +113,28 @@ where
                 self.target.data(),
             )
         };
-        *loss_data = {
-            let total_loss = Zip::indexed(&*input_data)
-                .and_broadcast(&target_data.view().insert_axis(Axis(1)))
-                .fold(0.0, |loss, idx, log, target| {
-                    if idx.into_dimension()[1] == *target as usize {
-                        loss + log
-                    } else {
-                        loss + 0.
-                    }
-                });
-            match self.reduction {
-                Reduction::Mean => arr0(-total_loss / input_data.len_of(Axis(0)) as f32),
-                Reduction::Sum => arr0(-total_loss),
-            }
-        };
+        let (weight, ignore_index) = (self.weight.as_deref(), self.ignore_index);
+        let classes = input_data.shape()[1];
+        let loss = Zip::indexed(&*input_data)
+            .and_broadcast(target_data.view().insert_axis(Axis(1)))
+            .map_collect(|idx, log, target| {
+                let probability = target_probability(
+                    idx.into_dimension()[1],
+                    *target,
+                    classes,
+                    self.label_smoothing,
+                );
+                if probability != 0. {
+                    -log * (target_weight(*target, weight, ignore_index) * probability)
+                } else {
+                    0.
+                }
+            });
+        // The loss of each target is the sum of the terms of all the classes.
+        let loss = loss.into_dyn().sum_axis(Axis(1));
+        *loss_data = self
+            .reduction
+            .reduce(loss, mean_denominator(&target_data, weight, ignore_index));
     }
 }
 
@@ -121,7 +146,10 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("NLLLoss")
-            .field("data", &self.data.borrow())
+            .field("data", &Summary(&self.data.borrow()))
+            .field("weight", &self.weight)
+            .field("ignore_index", &self.ignore_index)
+            .field("label_smoothing", &self.label_smoothing)
             .field("reduction", &self.reduction)
             .field("computed", &self.computed.get())
             .finish()
@@ -135,7 +163,7 @@ where
     U: Data,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", &self.data.borrow())
+        write!(f, "{}", Summary(&self.data.borrow()))
     }
 }
 
@@ -151,7 +179,10 @@ where
 {
     diff_input: Rc<T>,
     target: Rc<U>,
-    gradient: RefCell<Option<Tensor<Ix0>>>,
+    gradient: RefCell<Option<Tensor<IxDyn>>>,
+    weight: Option<Vec<f32>>,
+    ignore_index: Option<usize>,
+    label_smoothing: f32,
     reduction: Reduction,
     overwrite: Cell<bool>,
 }
@@ -162,11 +193,26 @@ where
     U: Data,
     T::Dim: Copy,
 {
-    pub(crate) fn new(diff_input: Rc<T>, target: Rc<U>, reduction: Reduction) -> Self {
+    pub(crate) fn new(
+        diff_input: Rc<T>,
+        target: Rc<U>,
+        weight: Option<Vec<f32>>,
+        ignore_index: Option<usize>,
+        label_smoothing: f32,
+        reduction: Reduction,
+    ) -> Self {
+        check_weight(diff_input.gradient().shape()[1], &weight);
+        check_label_smoothing(label_smoothing);
+
+        let gradient = Tensor::zeros(reduction.shape(target.data().raw_dim()));
+
         Self {
             diff_input,
             target,
-            gradient: RefCell::new(Some(arr0(0.))),
+            gradient: RefCell::new(Some(gradient)),
+            weight,
+            ignore_index,
+            label_smoothing,
             reduction,
             overwrite: Cell::new(true),
         }
@@ -179,7 +225,7 @@ where
     U: Data,
     T::Dim: Copy,
 {
-    type Dim = Ix0;
+    type Dim = IxDyn;
 
     fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
         expect_tensor(&self.gradient)
@@ -219,52 +265,43 @@ where
                 self.target.data(),
             )
         };
+        let (weight, ignore_index) = (self.weight.as_deref(), self.ignore_index);
+        let denominator =
+            self.reduction
+                .scale(mean_denominator(&target_data, weight, ignore_index));
+        let (classes, label_smoothing) = (operand_gradient.shape()[1], self.label_smoothing);
+        // The unreduced gradient holds an entry for each target, which is shared by all the
+        // classes.
+        let gradient = if gradient.ndim() > 0 {
+            gradient.view().insert_axis(Axis(1))
+        } else {
+            gradient.view()
+        };
         let zip = Zip::indexed(&mut *operand_gradient)
-            .and_broadcast(&*gradient)
+            .and_broadcast(gradient)
             .and_broadcast(target_data.view().insert_axis(Axis(1)));
 
-        match self.reduction {
-            Reduction::Mean => {
-                let n = target_data.len() as f32;
-                if self.diff_input.can_overwrite() {
-                    zip.for_each(|idx, op_grad, grad, target| {
-                        if idx.into_dimension().last_elem() == *target as usize {
-                            *op_grad = grad * -1. / n
-                        } else {
-                            *op_grad = 0.;
-                        }
-                    });
-                    self.diff_input.set_overwrite(false);
+        if self.diff_input.can_overwrite() {
+            zip.for_each(|idx, op_grad, grad, target| {
+                let probability =
+                    target_probability(idx.into_dimension()[1], *target, classes, label_smoothing);
+                if probability != 0. {
+                    *op_grad = -grad * (target_weight(*target, weight, ignore_index) * probability)
+                        / denominator;
                 } else {
-                    zip.for_each(|idx, op_grad, grad, target| {
-                        if idx.into_dimension().last_elem() == *target as usize {
-                            *op_grad += grad * -1. / n
-                        } else {
-                            *op_grad += 0.;
-                        }
-                    });
+                    *op_grad = 0.;
                 }
-            }
-            Reduction::Sum => {
-                if self.diff_input.can_overwrite() {
-                    zip.for_each(|idx, op_grad, grad, target| {
-                        if idx.into_dimension().last_elem() == *target as usize {
-                            *op_grad = grad * -1.
-                        } else {
-                            *op_grad = 0.
-                        }
-                    });
-                    self.diff_input.set_overwrite(false);
-                } else {
-                    zip.for_each(|idx, op_grad, grad, target| {
-                        if idx.into_dimension().last_elem() == *target as usize {
-                            *op_grad += grad * -1.
-                        } else {
-                            *op_grad += 0.
-                        }
-                    });
+            });
+            self.diff_input.set_overwrite(false);
+        } else {
+            zip.for_each(|idx, op_grad, grad, target| {
+                let probability =
+                    target_probability(idx.into_dimension()[1], *target, classes, label_smoothing);
+                if probability != 0. {
+                    *op_grad -= grad * (target_weight(*target, weight, ignore_index) * probability)
+                        / denominator;
                 }
-            }
+            });
         }
     }
 
@@ -273,7 +310,8 @@ where
     }
 
     fn with_grad(&self) {
-        *self.gradient.borrow_mut() = Some(arr0(0.));
+        let shape = self.reduction.shape(self.target.data().raw_dim());
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(shape));
     }
 }
 
@@ -285,7 +323,10 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("NLLLossBackward")
-            .field("gradient", &self.gradient.borrow())
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("weight", &self.weight)
+            .field("ignore_index", &self.ignore_index)
+            .field("label_smoothing", &self.label_smoothing)
             .field("reduction", &self.reduction)
             .field("overwrite", &self.overwrite.get())
             .finish()
@@ -300,12 +341,75 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
 }
 
+/// Checks that there is exactly one weight for each of the `classes`.
+fn check_weight(classes: usize, weight: &Option<Vec<f32>>) {
+    if let Some(weight) = weight {
+        assert_eq!(
+            weight.len(),
+            classes,
+            "error: {} class weights were given for an input with {} classes.",
+            weight.len(),
+            classes
+        );
+    }
+}
+
+/// Checks that the label smoothing factor lies in *[0, 1]*.
+fn check_label_smoothing(label_smoothing: f32) {
+    assert!(
+        (0. ..=1.).contains(&label_smoothing),
+        "error: the label smoothing must be in [0, 1], got {}.",
+        label_smoothing
+    );
+}
+
+/// Returns the probability of `class` in the target distribution of a sample of class `target`,
+/// which is *1 - label_smoothing* for the target class and *label_smoothing / (classes - 1)* for
+/// all the others.
+fn target_probability(class: usize, target: f32, classes: usize, label_smoothing: f32) -> f32 {
+    if class == target as usize {
+        1. - label_smoothing
+    } else {
+        label_smoothing / (classes - 1) as f32
+    }
+}
+
+/// Returns the weight of the class `target`, which is zero if such class is ignored.
+fn target_weight(target: f32, weight: Option<&[f32]>, ignore_index: Option<usize>) -> f32 {
+    let target = target as usize;
+    if ignore_index == Some(target) {
+        0.
+    } else {
+        weight.map_or(1., |weight| weight[target])
+    }
+}
+
+/// Returns the sum of the weights of the `targets`, by which the mean reduction divides the loss.
+/// When such sum is zero, e.g. because every target is ignored, one is returned instead so that
+/// the loss and its gradient are zero rather than NaN.
+fn mean_denominator<D: Dimension>(
+    targets: &Tensor<D>,
+    weight: Option<&[f32]>,
+    ignore_index: Option<usize>,
+) -> f32 {
+    let total = targets
+        .iter()
+        .map(|target| target_weight(*target, weight, ignore_index))
+        .sum::<f32>();
+
+    if total > 0. {
+        total
+    } else {
+        1.
+    }
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -20,18 +20,19 @@ fn mean() {
     ));
     input.forward();
 
-    let loss = NLLLoss::new(input, target.clone(), Reduction::Mean);
+    let loss = NLLLoss::new(input, target.clone(), None, None, 0., Reduction::Mean);
 
     loss.forward();
-    assert_almost_equals(&*loss.data(), &arr0(1.52222));
+    assert_almost_equals(&*loss.data(), &arr0(1.52222).into_dyn());
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
     let input_diff = new_backward_input((3, 5), vec![0.; 15]);
-    let loss_backward = NLLLossBackward::new(input_diff.clone(), target, Reduction::Mean);
+    let loss_backward =
+        NLLLossBackward::new(input_diff.clone(), target, None, None, 0., Reduction::Mean);
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-    *loss_backward.gradient_mut() = arr0(1.);
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     loss_backward.backward();
@@ -75,18 +76,19 @@ fn sum() {
     ));
     input.forward();
 
-    let loss = NLLLoss::new(input, target.clone(), Reduction::Sum);
+    let loss = NLLLoss::new(input, target.clone(), None, None, 0., Reduction::Sum);
 
     loss.forward();
-    assert_almost_equals(&*loss.data(), &arr0(4.56666));
+    assert_almost_equals(&*loss.data(), &arr0(4.56666).into_dyn());
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
     let input_diff = new_backward_input((3, 5), vec![0.; 15]);
-    let loss_backward = NLLLossBackward::new(input_diff.clone(), target, Reduction::Sum);
+    let loss_backward =
+        NLLLossBackward::new(input_diff.clone(), target, None, None, 0., Reduction::Sum);
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-    *loss_backward.gradient_mut() = arr0(1.);
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     loss_backward.backward();
@@ -115,6 +117,206 @@ fn sum() {
     );
 }
 
+#[test]
+fn weighted_mean() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let target = new_input(3, vec![0., 2., 1.]);
+    let input = new_input(
+        (3, 3),
+        vec![-1., -2., -3., -0.5, -1.5, -2.5, -2., -1., -0.1],
+    );
+    let weight = Some(vec![1., 100., 0.01]);
+
+    let loss = NLLLoss::new(
+        input,
+        target.clone(),
+        weight.clone(),
+        None,
+        0.,
+        Reduction::Mean,
+    );
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(101.025 / 101.01).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input((3, 3), vec![0.; 9]);
+    let loss_backward = NLLLossBackward::new(
+        input_diff.clone(),
+        target,
+        weight,
+        None,
+        0.,
+        Reduction::Mean,
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (3, 3),
+            vec![
+                -1. / 101.01,
+                0.,
+                0.,
+                0.,
+                0.,
+                -0.01 / 101.01,
+                0.,
+                -100. / 101.01,
+                0.,
+            ],
+        ),
+    );
+}
+
+#[test]
+fn ignore_index() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let target = new_input(3, vec![0., 2., 1.]);
+    let input = new_input(
+        (3, 3),
+        vec![-1., -2., -3., -0.5, -1.5, -2.5, -2., -1., -0.1],
+    );
+
+    let sum = NLLLoss::new(
+        input.clone(),
+        target.clone(),
+        None,
+        Some(2),
+        0.,
+        Reduction::Sum,
+    );
+    sum.forward();
+    assert_almost_equals(&*sum.data(), &arr0(2.).into_dyn());
+
+    let mean = NLLLoss::new(input, target.clone(), None, Some(2), 0., Reduction::Mean);
+    mean.forward();
+    assert_almost_equals(&*mean.data(), &arr0(1.).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    // The stale gradient of the ignored sample must be discarded on overwrite.
+    let input_diff = new_backward_input((3, 3), vec![5.; 9]);
+    let loss_backward = NLLLossBackward::new(
+        input_diff.clone(),
+        target,
+        None,
+        Some(2),
+        0.,
+        Reduction::Mean,
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor((3, 3), vec![-0.5, 0., 0., 0., 0., 0., 0., -0.5, 0.]),
+    );
+}
+
+#[test]
+fn all_ignored() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let target = new_input(3, vec![1., 1., 1.]);
+    let input = new_input(
+        (3, 3),
+        vec![-1., -2., -3., -0.5, -1.5, -2.5, -2., -1., -0.1],
+    );
+
+    let loss = NLLLoss::new(input, target.clone(), None, Some(1), 0., Reduction::Mean);
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(0.).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input((3, 3), vec![0.; 9]);
+    let loss_backward = NLLLossBackward::new(
+        input_diff.clone(),
+        target,
+        None,
+        Some(1),
+        0.,
+        Reduction::Mean,
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(&*input_diff.gradient(), &new_tensor((3, 3), vec![0.; 9]));
+}
+
+#[test]
+fn label_smoothing() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let target = new_input(2, vec![2., 0.]);
+    let input = Rc::new(LogSoftmax::new(
+        new_input((2, 3), vec![0.2, -1., 0.5, 1.5, 0.3, -0.4]),
+        1,
+    ));
+    input.forward();
+
+    let loss = NLLLoss::new(input, target.clone(), None, None, 0.1, Reduction::Mean);
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(0.646023).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input((2, 3), vec![0.; 6]);
+    let loss_backward =
+        NLLLossBackward::new(input_diff.clone(), target, None, None, 0.1, Reduction::Mean);
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor((2, 3), vec![-0.025, -0.025, -0.45, -0.45, -0.025, -0.025]),
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ 2nd Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor((2, 3), vec![-0.05, -0.05, -0.9, -0.9, -0.05, -0.05]),
+    );
+}
+
+#[test]
+#[should_panic(expected = "error: the label smoothing must be in [0, 1], got 1.5.")]
+fn label_smoothing_fail() {
+    NLLLoss::new(
+        new_input((3, 3), vec![0.; 9]),
+        new_input(3, vec![0.; 3]),
+        None,
+        None,
+        1.5,
+        Reduction::Mean,
+    );
+}
+
+#[test]
+#[should_panic(expected = "error: 2 class weights were given for an input with 3 classes.")]
+fn weight_fail() {
+    NLLLoss::new(
+        new_input((3, 3), vec![0.; 9]),
+        new_input(3, vec![0.; 3]),
+        Some(vec![1., 2.]),
+        None,
+        0.,
+        Reduction::Mean,
+    );
+}
+
 #[test]
 fn debug_forward() {
     let target = new_input(3, vec![2., 0., 4.]);
@@ -128,9 +330,9 @@ fn debug_forward() {
         1,
     ));
 
-    let loss = NLLLoss::new(input, target.clone(), Reduction::Mean);
+    let loss = NLLLoss::new(input, target.clone(), None, None, 0., Reduction::Mean);
 
-    let output = "NLLLoss { data: 0.0, shape=[], strides=[], layout=CFcf (0xf), const ndim=0, reduction: Mean, computed: false }";
+    let output = "NLLLoss { data: 0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0, weight: None, ignore_index: None, label_smoothing: 0.0, reduction: Mean, computed: false }";
 
     assert_eq!(output, format!("{:?}", loss));
 }
@@ -148,7 +350,7 @@ fn display_forward() {
         1,
     ));
 
-    let loss = NLLLoss::new(input, target.clone(), Reduction::Mean);
+    let loss = NLLLoss::new(input, target.clone(), None, None, 0., Reduction::Mean);
 
     assert_eq!(format!("{}", loss.data()), format!("{}", loss));
 }
@@ -158,9 +360,9 @@ fn debug_backward() {
     let input_diff = new_backward_input((3, 5), vec![0.; 15]);
     let target = new_input(3, vec![2., 0., 4.]);
 
-    let loss = NLLLossBackward::new(input_diff.clone(), target, Reduction::Mean);
+    let loss = NLLLossBackward::new(input_diff.clone(), target, None, None, 0., Reduction::Mean);
 
-    let output = "NLLLossBackward { gradient: Some(0.0, shape=[], strides=[], layout=CFcf (0xf), const ndim=0), reduction: Mean, overwrite: true }";
+    let output = "NLLLossBackward { gradient: Some(0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0), weight: None, ignore_index: None, label_smoothing: 0.0, reduction: Mean, overwrite: true }";
 
     assert_eq!(output, format!("{:?}", loss));
 }
@@ -170,7 +372,7 @@ fn display_backward() {
     let input_diff = new_backward_input((3, 5), vec![0.; 15]);
     let target = new_input(3, vec![2., 0., 4.]);
 
-    let loss = NLLLossBackward::new(input_diff.clone(), target, Reduction::Mean);
+    let loss = NLLLossBackward::new(input_diff.clone(), target, None, None, 0., Reduction::Mean);
 
     assert_eq!(format!("{}", loss.gradient()), format!("{}", loss));
 }
@@ -181,6 +383,9 @@ fn no_grad() {
     let node = NLLLossBackward::new(
         new_backward_input((3, 3), vec![0.; 9]),
         new_input(3, vec![0.; 3]),
+        None,
+        None,
+        0.,
         Reduction::Mean,
     );
 
@@ -188,5 +393,5 @@ fn no_grad() {
     assert!(node.gradient.borrow().is_none());
 
     node.with_grad();
-    assert_eq!(&*node.gradient(), arr0(0.));
+    assert_eq!(&*node.gradient(), arr0(0.).into_dyn());
 }
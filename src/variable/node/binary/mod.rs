@@ -3,12 +3,14 @@ mod concatenate;
 mod convolution;
 mod linalg;
 mod loss;
+mod scatter_add;
+mod select;
 mod stack;
 
 use super::{
     cobroadcasted_zeros, expect_tensor, expect_tensor_mut, push_gradient, push_mat_mat_gradient,
     push_mat_vec_gradient, push_vec_mat_gradient, push_vec_vec_gradient, reduce, Backward,
-    BroadTensor, Broadcasted, Cache, Data, DotDim, Forward, Gradient, Overwrite, Tensor,
+    BroadTensor, Broadcasted, Cache, Data, DotDim, Forward, Gradient, Overwrite, Summary, Tensor,
 };
 
 #[cfg(test)]
@@ -18,8 +20,11 @@ pub(crate) use arithmetic::*;
 pub(crate) use concatenate::*;
 pub(crate) use linalg::*;
 pub(crate) use loss::*;
+pub(crate) use scatter_add::*;
+pub(crate) use select::*;
 pub(crate) use stack::*;
 
 pub use convolution::{
-    Constant, Convolve, ConvolveWithGroups, PaddingMode, Reflective, Replicative, Zero,
+    Constant, Convolve, ConvolveTransposed, ConvolveWithGroups, PaddingMode, Reflective,
+    Replicative, Zero,
 };
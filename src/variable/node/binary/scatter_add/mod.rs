@@ -0,0 +1,532 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, push_gradient, Backward, Cache, Data, Forward, Gradient,
+    Overwrite, Summary, Tensor,
+};
+use ndarray::{Array, Dimension, IntoDimension};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ ScatterAddition ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct ScatterAddition<Lhs: ?Sized, Rhs: ?Sized>
+where
+    Lhs: Data<Dim = Rhs::Dim>,
+    Rhs: Data,
+{
+    left: Rc<Lhs>,
+    right: Rc<Rhs>,
+    axis: usize,
+    index: Array<usize, Lhs::Dim>,
+    data: RefCell<Tensor<Lhs::Dim>>,
+    computed: Cell<bool>,
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> ScatterAddition<Lhs, Rhs>
+where
+    Lhs: Data<Dim = Rhs::Dim>,
+    Rhs: Data,
+{
+    pub fn new(left: Rc<Lhs>, right: Rc<Rhs>, axis: usize, index: Array<usize, Lhs::Dim>) -> Self {
+        check_scatter(left.data().shape(), right.data().shape(), axis, &index);
+        let data = RefCell::new(Tensor::zeros(left.data().raw_dim()));
+
+        Self {
+            left,
+            right,
+            axis,
+            index,
+            data,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Data for ScatterAddition<Lhs, Rhs>
+where
+    Lhs: Data<Dim = Rhs::Dim>,
+    Rhs: Data,
+{
+    type Dim = Lhs::Dim;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Cache for ScatterAddition<Lhs, Rhs>
+where
+    Lhs: Data<Dim = Rhs::Dim>,
+    Rhs: Data,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Forward for ScatterAddition<Lhs, Rhs>
+where
+    Lhs: Data<Dim = Rhs::Dim>,
+    Rhs: Data,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let mut data = self.data.borrow_mut();
+        data.assign(&*self.left.data());
+
+        // Colliding indices add up all their sources.
+        let rhs_data = self.right.data();
+        for (rhs_el, (position, index)) in rhs_data.iter().zip(self.index.indexed_iter()) {
+            let mut position = position.into_dimension();
+            position[self.axis] = *index;
+            data[position] += rhs_el;
+        }
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Debug for ScatterAddition<Lhs, Rhs>
+where
+    Lhs: Data<Dim = Rhs::Dim>,
+    Rhs: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        f.debug_struct("ScatterAddition")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("axis", &self.axis)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Display for ScatterAddition<Lhs, Rhs>
+where
+    Lhs: Data<Dim = Rhs::Dim>,
+    Rhs: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ ScatterAdditionBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct ScatterAdditionBackward<Lhs: ?Sized, Rhs: ?Sized>
+where
+    Lhs: Gradient,
+    Rhs: Gradient<Dim = Lhs::Dim> + Overwrite,
+{
+    gradient: RefCell<Option<Tensor<Lhs::Dim>>>,
+    shape: Lhs::Dim,
+    overwrite: Cell<bool>,
+    left: Rc<Lhs>,
+    right: Rc<Rhs>,
+    axis: usize,
+    index: Array<usize, Lhs::Dim>,
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> ScatterAdditionBackward<Lhs, Rhs>
+where
+    Lhs: Gradient,
+    Rhs: Gradient<Dim = Lhs::Dim> + Overwrite,
+{
+    pub fn new(left: Rc<Lhs>, right: Rc<Rhs>, axis: usize, index: Array<usize, Lhs::Dim>) -> Self {
+        check_scatter(
+            left.gradient().shape(),
+            right.gradient().shape(),
+            axis,
+            &index,
+        );
+        let shape = left.gradient().raw_dim();
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape.clone()))),
+            shape,
+            overwrite: Cell::new(true),
+            left,
+            right,
+            axis,
+            index,
+        }
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Gradient for ScatterAdditionBackward<Lhs, Rhs>
+where
+    Lhs: Gradient,
+    Rhs: Gradient<Dim = Lhs::Dim> + Overwrite,
+{
+    type Dim = Lhs::Dim;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Overwrite for ScatterAdditionBackward<Lhs, Rhs>
+where
+    Lhs: Gradient,
+    Rhs: Gradient<Dim = Lhs::Dim> + Overwrite,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Backward for ScatterAdditionBackward<Lhs, Rhs>
+where
+    Lhs: Gradient,
+    Rhs: Gradient<Dim = Lhs::Dim> + Overwrite,
+{
+    fn backward(&self) {
+        let gradient = self.gradient();
+        push_gradient(&*self.left, &*gradient);
+        push_gradient(&*self.right, &gather(&gradient, self.axis, &self.index));
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Debug for ScatterAdditionBackward<Lhs, Rhs>
+where
+    Lhs: Gradient,
+    Rhs: Gradient<Dim = Lhs::Dim> + Overwrite,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        f.debug_struct("ScatterAdditionBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("axis", &self.axis)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> Display for ScatterAdditionBackward<Lhs, Rhs>
+where
+    Lhs: Gradient,
+    Rhs: Gradient<Dim = Lhs::Dim> + Overwrite,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ ScatterAdditionBackwardLeft ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct ScatterAdditionBackwardLeft<T: ?Sized>
+where
+    T: Gradient,
+{
+    gradient: RefCell<Option<Tensor<T::Dim>>>,
+    shape: T::Dim,
+    overwrite: Cell<bool>,
+    left: Rc<T>,
+    axis: usize,
+}
+
+impl<T: ?Sized> ScatterAdditionBackwardLeft<T>
+where
+    T: Gradient,
+{
+    pub fn new<U: ?Sized>(
+        left: Rc<T>,
+        right: Rc<U>,
+        axis: usize,
+        index: &Array<usize, T::Dim>,
+    ) -> Self
+    where
+        U: Data<Dim = T::Dim>,
+    {
+        check_scatter(left.gradient().shape(), right.data().shape(), axis, index);
+        let shape = left.gradient().raw_dim();
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape.clone()))),
+            shape,
+            overwrite: Cell::new(true),
+            left,
+            axis,
+        }
+    }
+}
+
+impl<T: ?Sized> Gradient for ScatterAdditionBackwardLeft<T>
+where
+    T: Gradient,
+{
+    type Dim = T::Dim;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized> Overwrite for ScatterAdditionBackwardLeft<T>
+where
+    T: Gradient,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized> Backward for ScatterAdditionBackwardLeft<T>
+where
+    T: Gradient,
+{
+    fn backward(&self) {
+        push_gradient(&*self.left, &*self.gradient());
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<T: ?Sized> Debug for ScatterAdditionBackwardLeft<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        f.debug_struct("ScatterAdditionBackwardLeft")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("axis", &self.axis)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for ScatterAdditionBackwardLeft<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ ScatterAdditionBackwardRight ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct ScatterAdditionBackwardRight<T: ?Sized>
+where
+    T: Gradient,
+{
+    gradient: RefCell<Option<Tensor<T::Dim>>>,
+    shape: T::Dim,
+    overwrite: Cell<bool>,
+    right: Rc<T>,
+    axis: usize,
+    index: Array<usize, T::Dim>,
+}
+
+impl<T: ?Sized> ScatterAdditionBackwardRight<T>
+where
+    T: Gradient,
+{
+    pub fn new<U: ?Sized>(
+        left: Rc<U>,
+        right: Rc<T>,
+        axis: usize,
+        index: Array<usize, T::Dim>,
+    ) -> Self
+    where
+        U: Data<Dim = T::Dim>,
+    {
+        check_scatter(left.data().shape(), right.gradient().shape(), axis, &index);
+        let shape = left.data().raw_dim();
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape.clone()))),
+            shape,
+            overwrite: Cell::new(true),
+            right,
+            axis,
+            index,
+        }
+    }
+}
+
+impl<T: ?Sized> Gradient for ScatterAdditionBackwardRight<T>
+where
+    T: Gradient,
+{
+    type Dim = T::Dim;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized> Overwrite for ScatterAdditionBackwardRight<T>
+where
+    T: Gradient,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized> Backward for ScatterAdditionBackwardRight<T>
+where
+    T: Gradient,
+{
+    fn backward(&self) {
+        push_gradient(
+            &*self.right,
+            &gather(&self.gradient(), self.axis, &self.index),
+        );
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<T: ?Sized> Debug for ScatterAdditionBackwardRight<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        f.debug_struct("ScatterAdditionBackwardRight")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("axis", &self.axis)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for ScatterAdditionBackwardRight<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+/// Gathers the elements of `gradient` scattered by `index` along `axis`, that is the gradient of
+/// the source operand of a scatter addition.
+fn gather<D: Dimension>(gradient: &Tensor<D>, axis: usize, index: &Array<usize, D>) -> Tensor<D> {
+    let mut gathered = Tensor::zeros(index.raw_dim());
+    for (gathered_el, (position, index)) in gathered.iter_mut().zip(index.indexed_iter()) {
+        let mut position = position.into_dimension();
+        position[axis] = *index;
+        *gathered_el = gradient[position];
+    }
+
+    gathered
+}
+
+/// Checks that `index` can scatter a source of shape `src_shape` along `axis` into a destination
+/// of shape `dst_shape`: it must have the same shape as the source, must not be larger than the
+/// destination along any other axis and its elements must lie within `axis`.
+fn check_scatter<D: Dimension>(
+    dst_shape: &[usize],
+    src_shape: &[usize],
+    axis: usize,
+    index: &Array<usize, D>,
+) {
+    assert!(
+        axis < dst_shape.len(),
+        "error: axis {} is out of bounds for a variable with {} dimensions.",
+        axis,
+        dst_shape.len()
+    );
+    assert_eq!(
+        index.shape(),
+        src_shape,
+        "error: the index of shape {:?} doesn't match the source of shape {:?}.",
+        index.shape(),
+        src_shape
+    );
+    assert!(
+        index
+            .shape()
+            .iter()
+            .zip(dst_shape)
+            .enumerate()
+            .all(|(i, (index_len, len))| i == axis || index_len <= len),
+        "error: cannot scatter with an index of shape {:?} into a variable of shape {:?} along axis {}.",
+        index.shape(),
+        dst_shape,
+        axis
+    );
+    if let Some(index) = index.iter().find(|index| **index >= dst_shape[axis]) {
+        panic!(
+            "error: index {} is out of bounds for axis {} of length {}.",
+            index, axis, dst_shape[axis]
+        );
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
@@ -0,0 +1,508 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, Overwrite, ScatterAddition, ScatterAdditionBackward,
+    ScatterAdditionBackwardLeft, ScatterAdditionBackwardRight, Tensor,
+};
+use ndarray::array;
+
+mod forward {
+    use super::{
+        array, assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, ScatterAddition,
+        Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let left = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let right = new_input((4, 2), vec![10., 20., 30., 40., 50., 60., 70., 80.]);
+        let node = ScatterAddition::new(left, right, 0, array![[0, 2], [1, 0], [0, 0], [2, 1]]);
+
+        assert_eq!(*node.data(), Tensor::from_elem((3, 2), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((3, 2), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let left = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let right = new_input((4, 2), vec![10., 20., 30., 40., 50., 60., 70., 80.]);
+        let node = ScatterAddition::new(left, right, 0, array![[0, 2], [1, 0], [0, 0], [2, 1]]);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic(expected = "error: index 3 is out of bounds for axis 0 of length 3.")]
+    fn fail() {
+        ScatterAddition::new(
+            new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+            new_input((2, 2), vec![1.; 4]),
+            0,
+            array![[0, 3], [2, 1]],
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: the index of shape [2, 1] doesn't match the source of shape [2, 2]."
+    )]
+    fn fail_source_shape() {
+        ScatterAddition::new(
+            new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+            new_input((2, 2), vec![1.; 4]),
+            0,
+            array![[0], [2]],
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: cannot scatter with an index of shape [2, 3] into a variable of shape [3, 2] along axis 0."
+    )]
+    fn fail_index_shape() {
+        ScatterAddition::new(
+            new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+            new_input((2, 3), vec![1.; 6]),
+            0,
+            array![[0, 1, 2], [2, 1, 0]],
+        );
+    }
+
+    #[test]
+    fn forward() {
+        let left = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let right = new_input((4, 2), vec![10., 20., 30., 40., 50., 60., 70., 80.]);
+        let node = ScatterAddition::new(
+            left.clone(),
+            right,
+            0,
+            array![[0, 2], [1, 0], [0, 0], [2, 1]],
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        // Colliding indices add up all their sources.
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 2), vec![61., 102., 33., 84., 75., 26.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = left.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(
+            &*left.data(),
+            &new_tensor((3, 2), vec![2., 3., 4., 5., 6., 7.]),
+        );
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 2), vec![61., 102., 33., 84., 75., 26.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 2), vec![62., 103., 34., 85., 76., 27.]),
+        );
+    }
+
+    #[test]
+    fn forward_inner_axis() {
+        let left = new_input((2, 3), vec![0.; 6]);
+        let right = new_input((2, 2), vec![1., 2., 3., 4.]);
+        let node = ScatterAddition::new(left, right, 1, array![[2, 2], [0, 1]]);
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((2, 3), vec![0., 0., 3., 3., 4., 0.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let left = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let right = new_input((2, 2), vec![1.; 4]);
+        let node = ScatterAddition::new(left, right, 0, array![[0, 2], [1, 0]]);
+
+        let output = "ScatterAddition { data: [[0.0, 0.0],\n [0.0, 0.0],\n [0.0, 0.0]], shape=[3, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2, axis: 0, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let left = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let right = new_input((2, 2), vec![1.; 4]);
+        let node = ScatterAddition::new(left, right, 0, array![[0, 2], [1, 0]]);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        array, assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Gradient,
+        Overwrite, ScatterAdditionBackward, ScatterAdditionBackwardLeft,
+        ScatterAdditionBackwardRight, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = ScatterAdditionBackward::new(
+            new_backward_input((3, 2), vec![0.; 6]),
+            new_backward_input((4, 2), vec![0.; 8]),
+            0,
+            array![[0, 2], [1, 0], [0, 0], [2, 1]],
+        );
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((3, 2), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((3, 2), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    #[should_panic(expected = "error: index 2 is out of bounds for axis 1 of length 2.")]
+    fn fail() {
+        ScatterAdditionBackward::new(
+            new_backward_input((3, 2), vec![0.; 6]),
+            new_backward_input((1, 2), vec![0.; 2]),
+            1,
+            array![[0, 2]],
+        );
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let lhs = new_backward_input((3, 2), vec![0.; 6]);
+        let rhs = new_backward_input((4, 2), vec![0.; 8]);
+        let node = ScatterAdditionBackward::new(
+            lhs.clone(),
+            rhs.clone(),
+            0,
+            array![[0, 2], [1, 0], [0, 0], [2, 1]],
+        );
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+
+        lhs.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+
+        lhs.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+
+        rhs.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(rhs.can_overwrite());
+
+        rhs.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(rhs.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(rhs.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(rhs.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        // The stale gradients of the operands must be discarded on overwrite.
+        let lhs = new_backward_input((3, 2), vec![5.; 6]);
+        let rhs = new_backward_input((4, 2), vec![5.; 8]);
+        let node = ScatterAdditionBackward::new(
+            lhs.clone(),
+            rhs.clone(),
+            0,
+            array![[0, 2], [1, 0], [0, 0], [2, 1]],
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        assert_almost_equals(
+            &*node.gradient(),
+            &new_tensor((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        // The sources of a colliding index all receive its gradient.
+        node.backward();
+        assert_almost_equals(
+            &*lhs.gradient(),
+            &new_tensor((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+        );
+        assert_almost_equals(
+            &*rhs.gradient(),
+            &new_tensor((4, 2), vec![1., 6., 3., 2., 1., 2., 5., 4.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*lhs.gradient(),
+            &new_tensor((3, 2), vec![2., 4., 6., 8., 10., 12.]),
+        );
+        assert_almost_equals(
+            &*rhs.gradient(),
+            &new_tensor((4, 2), vec![2., 12., 6., 4., 2., 4., 10., 8.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        lhs.set_overwrite(true);
+        rhs.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*lhs.gradient(),
+            &new_tensor((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+        );
+        assert_almost_equals(
+            &*rhs.gradient(),
+            &new_tensor((4, 2), vec![1., 6., 3., 2., 1., 2., 5., 4.]),
+        );
+    }
+
+    #[test]
+    fn backward_left() {
+        let lhs = new_backward_input((3, 2), vec![5.; 6]);
+        let node = ScatterAdditionBackwardLeft::new(
+            lhs.clone(),
+            new_input((4, 2), vec![0.; 8]),
+            0,
+            &array![[0, 2], [1, 0], [0, 0], [2, 1]],
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        assert_almost_equals(
+            &*node.gradient(),
+            &new_tensor((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*lhs.gradient(),
+            &new_tensor((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*lhs.gradient(),
+            &new_tensor((3, 2), vec![2., 4., 6., 8., 10., 12.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        lhs.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*lhs.gradient(),
+            &new_tensor((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+        );
+    }
+
+    #[test]
+    fn backward_right() {
+        let rhs = new_backward_input((4, 2), vec![5.; 8]);
+        let node = ScatterAdditionBackwardRight::new(
+            new_input((3, 2), vec![0.; 6]),
+            rhs.clone(),
+            0,
+            array![[0, 2], [1, 0], [0, 0], [2, 1]],
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        assert_almost_equals(
+            &*node.gradient(),
+            &new_tensor((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*rhs.gradient(),
+            &new_tensor((4, 2), vec![1., 6., 3., 2., 1., 2., 5., 4.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*rhs.gradient(),
+            &new_tensor((4, 2), vec![2., 12., 6., 4., 2., 4., 10., 8.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        rhs.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*rhs.gradient(),
+            &new_tensor((4, 2), vec![1., 6., 3., 2., 1., 2., 5., 4.]),
+        );
+    }
+
+    #[test]
+    fn no_grad() {
+        // ScatterAdditionBackward
+        let node = ScatterAdditionBackward::new(
+            new_backward_input((3, 2), vec![0.; 6]),
+            new_backward_input((2, 2), vec![0.; 4]),
+            0,
+            array![[0, 2], [1, 0]],
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+
+        // ScatterAdditionBackwardLeft
+        let node = ScatterAdditionBackwardLeft::new(
+            new_backward_input((3, 2), vec![0.; 6]),
+            new_input((2, 2), vec![0.; 4]),
+            0,
+            &array![[0, 2], [1, 0]],
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+
+        // ScatterAdditionBackwardRight
+        let node = ScatterAdditionBackwardRight::new(
+            new_input((3, 2), vec![0.; 6]),
+            new_backward_input((2, 2), vec![0.; 4]),
+            0,
+            array![[0, 2], [1, 0]],
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+
+    #[test]
+    fn debug() {
+        let node = ScatterAdditionBackward::new(
+            new_backward_input(2, vec![0.; 2]),
+            new_backward_input(1, vec![0.]),
+            0,
+            array![1],
+        );
+
+        let output = "ScatterAdditionBackward { gradient: Some([0.0, 0.0], shape=[2], strides=[1], layout=CFcf (0xf), const ndim=1), axis: 0, overwrite: true }";
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn debug_left() {
+        let node = ScatterAdditionBackwardLeft::new(
+            new_backward_input(2, vec![0.; 2]),
+            new_input(1, vec![0.]),
+            0,
+            &array![1],
+        );
+
+        let output = "ScatterAdditionBackwardLeft { gradient: Some([0.0, 0.0], shape=[2], strides=[1], layout=CFcf (0xf), const ndim=1), axis: 0, overwrite: true }";
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn debug_right() {
+        let node = ScatterAdditionBackwardRight::new(
+            new_input(2, vec![0.; 2]),
+            new_backward_input(1, vec![0.]),
+            0,
+            array![1],
+        );
+
+        let output = "ScatterAdditionBackwardRight { gradient: Some([0.0, 0.0], shape=[2], strides=[1], layout=CFcf (0xf), const ndim=1), axis: 0, overwrite: true }";
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let node = ScatterAdditionBackward::new(
+            new_backward_input(2, vec![0.; 2]),
+            new_backward_input(1, vec![0.]),
+            0,
+            array![1],
+        );
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn display_left() {
+        let node = ScatterAdditionBackwardLeft::new(
+            new_backward_input(2, vec![0.; 2]),
+            new_input(1, vec![0.]),
+            0,
+            &array![1],
+        );
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn display_right() {
+        let node = ScatterAdditionBackwardRight::new(
+            new_input(2, vec![0.; 2]),
+            new_backward_input(1, vec![0.]),
+            0,
+            array![1],
+        );
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+}
@@ -0,0 +1,579 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    cobroadcasted_zeros, expect_tensor, expect_tensor_mut, push_gradient, reduce, Backward,
+    Broadcasted, Cache, Data, Forward, Gradient, Overwrite, Summary, Tensor,
+};
+use ndarray::{DimMax, Dimension, Zip};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+type SelectDim<C, Lhs, Rhs> = Broadcasted<Broadcasted<C, Lhs>, Rhs>;
+type SelectTensor<C, Lhs, Rhs> = Tensor<SelectDim<C, Lhs, Rhs>>;
+type SelectGradient<C, Lhs, Rhs> = RefCell<Option<SelectTensor<C, Lhs, Rhs>>>;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Select ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct Select<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized>
+where
+    C: Data,
+    Lhs: Data,
+    Rhs: Data,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    condition: Rc<C>,
+    left: Rc<Lhs>,
+    right: Rc<Rhs>,
+    data: RefCell<SelectTensor<C::Dim, Lhs::Dim, Rhs::Dim>>,
+    computed: Cell<bool>,
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> Select<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Data,
+    Rhs: Data,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    pub fn new(condition: Rc<C>, left: Rc<Lhs>, right: Rc<Rhs>) -> Self {
+        let data = cobroadcasted_zeros(
+            &cobroadcasted_zeros(&condition.data(), &left.data()),
+            &right.data(),
+        );
+
+        Self {
+            condition,
+            left,
+            right,
+            data: RefCell::new(data),
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> Data for Select<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Data,
+    Rhs: Data,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    type Dim = SelectDim<C::Dim, Lhs::Dim, Rhs::Dim>;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> Cache for Select<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Data,
+    Rhs: Data,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> Forward for Select<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Data,
+    Rhs: Data,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        Zip::from(&mut *self.data.borrow_mut())
+            .and_broadcast(&*self.condition.data())
+            .and_broadcast(&*self.left.data())
+            .and_broadcast(&*self.right.data())
+            .for_each(|v, c, l, r| *v = if *c != 0. { *l } else { *r });
+    }
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> Debug for Select<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Data,
+    Rhs: Data,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Select")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> Display for Select<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Data,
+    Rhs: Data,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ SelectBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct SelectBackward<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized>
+where
+    C: Data,
+    Lhs: Gradient,
+    Rhs: Gradient,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    gradient: SelectGradient<C::Dim, Lhs::Dim, Rhs::Dim>,
+    shape: SelectDim<C::Dim, Lhs::Dim, Rhs::Dim>,
+    overwrite: Cell<bool>,
+    condition: Rc<C>,
+    left: Rc<Lhs>,
+    right: Rc<Rhs>,
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> SelectBackward<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Gradient,
+    Rhs: Gradient,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    pub fn new(condition: Rc<C>, left: Rc<Lhs>, right: Rc<Rhs>) -> Self {
+        let gradient = cobroadcasted_zeros(
+            &cobroadcasted_zeros(&condition.data(), &left.gradient()),
+            &right.gradient(),
+        );
+        let shape = gradient.raw_dim();
+
+        Self {
+            gradient: RefCell::new(Some(gradient)),
+            shape,
+            overwrite: Cell::new(true),
+            condition,
+            left,
+            right,
+        }
+    }
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> Gradient for SelectBackward<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Gradient,
+    Rhs: Gradient,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    type Dim = SelectDim<C::Dim, Lhs::Dim, Rhs::Dim>;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> Overwrite for SelectBackward<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Gradient,
+    Rhs: Gradient,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> Backward for SelectBackward<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Gradient,
+    Rhs: Gradient,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    fn backward(&self) {
+        let gradient = select_gradient(&self.gradient(), &self.condition.data(), true);
+        let reduced = reduce(self.left.gradient().raw_dim(), &gradient);
+        push_gradient(&self.left, &reduced);
+
+        let gradient = select_gradient(&self.gradient(), &self.condition.data(), false);
+        let reduced = reduce(self.right.gradient().raw_dim(), &gradient);
+        push_gradient(&self.right, &reduced);
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> Debug for SelectBackward<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Gradient,
+    Rhs: Gradient,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SelectBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> Display for SelectBackward<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Gradient,
+    Rhs: Gradient,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ SelectBackwardLeft ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct SelectBackwardLeft<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized>
+where
+    C: Data,
+    Lhs: Gradient,
+    Rhs: Data,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    gradient: SelectGradient<C::Dim, Lhs::Dim, Rhs::Dim>,
+    shape: SelectDim<C::Dim, Lhs::Dim, Rhs::Dim>,
+    overwrite: Cell<bool>,
+    condition: Rc<C>,
+    left: Rc<Lhs>,
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> SelectBackwardLeft<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Gradient,
+    Rhs: Data,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    pub fn new(condition: Rc<C>, left: Rc<Lhs>, right: Rc<Rhs>) -> Self {
+        let gradient = cobroadcasted_zeros(
+            &cobroadcasted_zeros(&condition.data(), &left.gradient()),
+            &right.data(),
+        );
+        let shape = gradient.raw_dim();
+
+        Self {
+            gradient: RefCell::new(Some(gradient)),
+            shape,
+            overwrite: Cell::new(true),
+            condition,
+            left,
+        }
+    }
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> Gradient for SelectBackwardLeft<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Gradient,
+    Rhs: Data,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    type Dim = SelectDim<C::Dim, Lhs::Dim, Rhs::Dim>;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> Overwrite for SelectBackwardLeft<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Gradient,
+    Rhs: Data,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> Backward for SelectBackwardLeft<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Gradient,
+    Rhs: Data,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    fn backward(&self) {
+        let gradient = select_gradient(&self.gradient(), &self.condition.data(), true);
+        let reduced = reduce(self.left.gradient().raw_dim(), &gradient);
+        push_gradient(&self.left, &reduced);
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> Debug for SelectBackwardLeft<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Gradient,
+    Rhs: Data,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SelectBackwardLeft")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> Display for SelectBackwardLeft<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Gradient,
+    Rhs: Data,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ SelectBackwardRight ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct SelectBackwardRight<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized>
+where
+    C: Data,
+    Lhs: Data,
+    Rhs: Gradient,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    gradient: SelectGradient<C::Dim, Lhs::Dim, Rhs::Dim>,
+    shape: SelectDim<C::Dim, Lhs::Dim, Rhs::Dim>,
+    overwrite: Cell<bool>,
+    condition: Rc<C>,
+    right: Rc<Rhs>,
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> SelectBackwardRight<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Data,
+    Rhs: Gradient,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    pub fn new(condition: Rc<C>, left: Rc<Lhs>, right: Rc<Rhs>) -> Self {
+        let gradient = cobroadcasted_zeros(
+            &cobroadcasted_zeros(&condition.data(), &left.data()),
+            &right.gradient(),
+        );
+        let shape = gradient.raw_dim();
+
+        Self {
+            gradient: RefCell::new(Some(gradient)),
+            shape,
+            overwrite: Cell::new(true),
+            condition,
+            right,
+        }
+    }
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> Gradient for SelectBackwardRight<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Data,
+    Rhs: Gradient,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    type Dim = SelectDim<C::Dim, Lhs::Dim, Rhs::Dim>;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> Overwrite for SelectBackwardRight<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Data,
+    Rhs: Gradient,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> Backward for SelectBackwardRight<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Data,
+    Rhs: Gradient,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    fn backward(&self) {
+        let gradient = select_gradient(&self.gradient(), &self.condition.data(), false);
+        let reduced = reduce(self.right.gradient().raw_dim(), &gradient);
+        push_gradient(&self.right, &reduced);
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> Debug for SelectBackwardRight<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Data,
+    Rhs: Gradient,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SelectBackwardRight")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<C: ?Sized, Lhs: ?Sized, Rhs: ?Sized> Display for SelectBackwardRight<C, Lhs, Rhs>
+where
+    C: Data,
+    Lhs: Data,
+    Rhs: Gradient,
+    C::Dim: Dimension + DimMax<Lhs::Dim>,
+    Broadcasted<C::Dim, Lhs::Dim>: DimMax<Rhs::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+/// Returns the elements of `gradient` at the positions where `condition` is non-zero if
+/// `selected` is `true`, or zero if it is `false`, and zeros elsewhere.
+fn select_gradient<D: Dimension, E: Dimension>(
+    gradient: &Tensor<D>,
+    condition: &Tensor<E>,
+    selected: bool,
+) -> Tensor<D> {
+    let mut selected_gradient = Tensor::zeros(gradient.raw_dim());
+    Zip::from(&mut selected_gradient)
+        .and(gradient)
+        .and_broadcast(condition)
+        .for_each(|s, g, c| {
+            if (*c != 0.) == selected {
+                *s = *g
+            }
+        });
+
+    selected_gradient
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
@@ -0,0 +1,425 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, Overwrite, Select, SelectBackward, SelectBackwardLeft, SelectBackwardRight,
+    Tensor,
+};
+
+mod forward {
+
+    use super::{
+        assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, Select, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let condition = new_input((3, 3), vec![1., 0., 1., 0., 1., 0., 1., 0., 1.]);
+        let left = new_input((3, 3), vec![1.; 9]);
+        let right = new_input((3, 3), vec![-1.; 9]);
+        let node = Select::new(condition, left, right);
+
+        assert_eq!(*node.data(), Tensor::from_elem((3, 3), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((3, 3), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let condition = new_input((3, 3), vec![1., 0., 1., 0., 1., 0., 1., 0., 1.]);
+        let left = new_input((3, 3), vec![1.; 9]);
+        let right = new_input((3, 3), vec![-1.; 9]);
+        let node = Select::new(condition, left, right);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn forward() {
+        let condition = new_input((3, 3), vec![1., 0., 1., 0., 1., 0., 1., 0., 1.]);
+        let left = new_input((3, 3), vec![1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+        let right = new_input((3, 3), vec![-1.; 9]);
+        let node = Select::new(condition.clone(), left, right);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 3), vec![1., -1., 3., -1., 5., -1., 7., -1., 9.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = condition.data_mut();
+            *data = &*data - &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(
+            &*condition.data(),
+            &new_tensor((3, 3), vec![0., -1., 0., -1., 0., -1., 0., -1., 0.]),
+        );
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 3), vec![1., -1., 3., -1., 5., -1., 7., -1., 9.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 3), vec![-1., 2., -1., 4., -1., 6., -1., 8., -1.]),
+        );
+    }
+
+    #[test]
+    fn broadcast_forward() {
+        let condition = new_input((3, 1), vec![1., 0., 1.]);
+        let left = new_input(3, vec![1., 2., 3.]);
+        let right = new_input((1, 1), vec![0.]);
+        let node = Select::new(condition, left, right);
+
+        assert_eq!(*node.data(), Tensor::from_elem((3, 3), 0.));
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 3), vec![1., 2., 3., 0., 0., 0., 1., 2., 3.]),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "error: the two tensors have incompatible shape.")]
+    fn fail() {
+        Select::new(
+            new_input(2, vec![1., 0.]),
+            new_input((3, 3), vec![1.; 9]),
+            new_input((3, 3), vec![-1.; 9]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let condition = new_input(1, vec![1.]);
+        let left = new_input(1, vec![0.]);
+        let right = new_input(1, vec![0.]);
+        let node = Select::new(condition, left, right);
+
+        let output = "Select { data: [0.0], shape=[1], strides=[1], layout=CFcf (0xf), const ndim=1, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let condition = new_input(1, vec![1.]);
+        let left = new_input(1, vec![0.]);
+        let right = new_input(1, vec![0.]);
+        let node = Select::new(condition, left, right);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Gradient,
+        Overwrite, SelectBackward, SelectBackwardLeft, SelectBackwardRight, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = SelectBackward::new(
+            new_input((3, 3), vec![1., 0., 1., 0., 1., 0., 1., 0., 1.]),
+            new_backward_input((3, 3), vec![0.; 9]),
+            new_backward_input((3, 3), vec![0.; 9]),
+        );
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((3, 3), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((3, 3), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let lhs = new_backward_input((3, 3), vec![0.; 9]);
+        let rhs = new_backward_input((3, 3), vec![0.; 9]);
+        let node = SelectBackward::new(
+            new_input((3, 3), vec![1., 0., 1., 0., 1., 0., 1., 0., 1.]),
+            lhs.clone(),
+            rhs.clone(),
+        );
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+
+        lhs.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+
+        rhs.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(rhs.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(lhs.can_overwrite());
+        assert!(rhs.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!lhs.can_overwrite());
+        assert!(!rhs.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        let lhs = new_backward_input((3, 3), vec![0.; 9]);
+        let rhs = new_backward_input((3, 3), vec![0.; 9]);
+        let node = SelectBackward::new(
+            new_input((3, 3), vec![1., 0., 1., 0., 1., 0., 1., 0., 1.]),
+            lhs.clone(),
+            rhs.clone(),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((3, 3), vec![1.; 9]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((3, 3), vec![1.; 9]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*lhs.gradient(),
+            &new_tensor((3, 3), vec![1., 0., 1., 0., 1., 0., 1., 0., 1.]),
+        );
+        assert_almost_equals(
+            &*rhs.gradient(),
+            &new_tensor((3, 3), vec![0., 1., 0., 1., 0., 1., 0., 1., 0.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*lhs.gradient(),
+            &new_tensor((3, 3), vec![2., 0., 2., 0., 2., 0., 2., 0., 2.]),
+        );
+        assert_almost_equals(
+            &*rhs.gradient(),
+            &new_tensor((3, 3), vec![0., 2., 0., 2., 0., 2., 0., 2., 0.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        lhs.set_overwrite(true);
+        rhs.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*lhs.gradient(),
+            &new_tensor((3, 3), vec![1., 0., 1., 0., 1., 0., 1., 0., 1.]),
+        );
+        assert_almost_equals(
+            &*rhs.gradient(),
+            &new_tensor((3, 3), vec![0., 1., 0., 1., 0., 1., 0., 1., 0.]),
+        );
+    }
+
+    #[test]
+    fn backward_broadcast() {
+        let lhs = new_backward_input(3, vec![0.; 3]);
+        let rhs = new_backward_input((1, 1), vec![0.]);
+        let node = SelectBackward::new(
+            new_input((3, 1), vec![1., 0., 1.]),
+            lhs.clone(),
+            rhs.clone(),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((3, 3), vec![1.; 9]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((3, 3), vec![1.; 9]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(&*lhs.gradient(), &new_tensor(3, vec![2.; 3]));
+        assert_almost_equals(&*rhs.gradient(), &new_tensor((1, 1), vec![3.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(&*lhs.gradient(), &new_tensor(3, vec![4.; 3]));
+        assert_almost_equals(&*rhs.gradient(), &new_tensor((1, 1), vec![6.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        lhs.set_overwrite(true);
+        rhs.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(&*lhs.gradient(), &new_tensor(3, vec![2.; 3]));
+        assert_almost_equals(&*rhs.gradient(), &new_tensor((1, 1), vec![3.]));
+    }
+
+    #[test]
+    fn backward_nan() {
+        let lhs = new_backward_input(3, vec![0.; 3]);
+        let rhs = new_backward_input(3, vec![0.; 3]);
+        let node = SelectBackward::new(new_input(3, vec![1., 0., 1.]), lhs.clone(), rhs.clone());
+
+        *node.gradient_mut() = new_tensor(3, vec![1., f32::NAN, 1.]);
+        node.backward();
+        assert_almost_equals(&*lhs.gradient(), &new_tensor(3, vec![1., 0., 1.]));
+        assert!(rhs.gradient()[1].is_nan());
+        assert_eq!(rhs.gradient()[0], 0.);
+        assert_eq!(rhs.gradient()[2], 0.);
+    }
+
+    #[test]
+    fn backward_left() {
+        let diff = new_backward_input((3, 3), vec![0.; 9]);
+        let node = SelectBackwardLeft::new(
+            new_input((3, 3), vec![1., 0., 1., 0., 1., 0., 1., 0., 1.]),
+            diff.clone(),
+            new_input((3, 3), vec![0.; 9]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((3, 3), vec![1.; 9]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((3, 3), vec![1.; 9]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 3), vec![1., 0., 1., 0., 1., 0., 1., 0., 1.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 3), vec![2., 0., 2., 0., 2., 0., 2., 0., 2.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 3), vec![1., 0., 1., 0., 1., 0., 1., 0., 1.]),
+        );
+    }
+
+    #[test]
+    fn backward_right() {
+        let diff = new_backward_input((3, 3), vec![0.; 9]);
+        let node = SelectBackwardRight::new(
+            new_input((3, 3), vec![1., 0., 1., 0., 1., 0., 1., 0., 1.]),
+            new_input((3, 3), vec![0.; 9]),
+            diff.clone(),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((3, 3), vec![1.; 9]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((3, 3), vec![1.; 9]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 3), vec![0., 1., 0., 1., 0., 1., 0., 1., 0.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 3), vec![0., 2., 0., 2., 0., 2., 0., 2., 0.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 3), vec![0., 1., 0., 1., 0., 1., 0., 1., 0.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let node = SelectBackward::new(
+            new_input(1, vec![1.]),
+            new_backward_input(1, vec![0.]),
+            new_backward_input(1, vec![0.]),
+        );
+
+        let output = "SelectBackward { gradient: Some([0.0], shape=[1], strides=[1], layout=CFcf (0xf), const ndim=1), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let node = SelectBackward::new(
+            new_input(1, vec![1.]),
+            new_backward_input(1, vec![0.]),
+            new_backward_input(1, vec![0.]),
+        );
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // SelectBackward
+        let node = SelectBackward::new(
+            new_input((3, 3), vec![1.; 9]),
+            new_backward_input((3, 3), vec![0.; 9]),
+            new_backward_input((3, 3), vec![0.; 9]),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+
+        // SelectBackwardLeft
+        let node = SelectBackwardLeft::new(
+            new_input((3, 3), vec![1.; 9]),
+            new_backward_input((3, 3), vec![0.; 9]),
+            new_input((3, 3), vec![0.; 9]),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+
+        // SelectBackwardRight
+        let node = SelectBackwardRight::new(
+            new_input((3, 3), vec![1.; 9]),
+            new_input((3, 3), vec![0.; 9]),
+            new_backward_input((3, 3), vec![0.; 9]),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
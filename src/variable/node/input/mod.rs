@@ -1,5 +1,5 @@
 use super::{
-    expect_tensor, expect_tensor_mut, Cache, Data, Dimension, Gradient, Overwrite, Tensor,
+    expect_tensor, expect_tensor_mut, Cache, Data, Dimension, Gradient, Overwrite, Summary, Tensor,
 };
 use std::{
     cell::{Cell, Ref, RefCell, RefMut},
@@ -28,6 +28,8 @@ impl<D: Dimension> Input<D> {
         InputBackward {
             gradient: RefCell::new(Some(Tensor::zeros(self.data().raw_dim()))),
             overwrite: Cell::new(true),
+            frozen: Cell::new(false),
+            sink: RefCell::new(None),
         }
     }
 }
@@ -55,14 +57,14 @@ impl<D: Dimension> Cache for Input<D> {
 impl<D: Dimension> Debug for Input<D> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Input")
-            .field("data", &self.data.borrow())
+            .field("data", &Summary(&self.data.borrow()))
             .finish()
     }
 }
 
 impl<D: Dimension> Display for Input<D> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.data.borrow())
+        write!(f, "{}", Summary(&self.data.borrow()))
     }
 }
 
@@ -71,15 +73,40 @@ impl<D: Dimension> Display for Input<D> {
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 /// The backward component of a differentiable leaf of the computational graph.
+///
+/// A frozen leaf doesn't accumulate gradients: while frozen, the gradients pushed into it by the
+/// nodes of the graph land into a scratch buffer that is discarded on unfreezing, and its actual
+/// gradient stays zeroed.
 pub struct InputBackward<D: Dimension> {
     gradient: RefCell<Option<Tensor<D>>>,
     overwrite: Cell<bool>,
+    frozen: Cell<bool>,
+    sink: RefCell<Option<Tensor<D>>>,
 }
 
 impl<D: Dimension> InputBackward<D> {
     pub fn zero_grad(&self) {
         expect_tensor_mut(&self.gradient).fill(0.);
     }
+
+    pub fn freeze(&self) {
+        if self.frozen.replace(true) {
+            return;
+        }
+
+        let mut gradient = expect_tensor_mut(&self.gradient);
+        gradient.fill(0.);
+        *self.sink.borrow_mut() = Some(Tensor::zeros(gradient.raw_dim()));
+    }
+
+    pub fn unfreeze(&self) {
+        self.frozen.set(false);
+        *self.sink.borrow_mut() = None;
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen.get()
+    }
 }
 
 impl<D: Dimension> Gradient for InputBackward<D> {
@@ -90,7 +117,11 @@ impl<D: Dimension> Gradient for InputBackward<D> {
     }
 
     fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
-        expect_tensor_mut(&self.gradient)
+        if self.frozen.get() {
+            expect_tensor_mut(&self.sink)
+        } else {
+            expect_tensor_mut(&self.gradient)
+        }
     }
 }
 
@@ -107,8 +138,9 @@ impl<D: Dimension> Overwrite for InputBackward<D> {
 impl<D: Dimension> Debug for InputBackward<D> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("InputBackward")
-            .field("gradient", &self.gradient.borrow())
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
             .field("overwrite", &self.overwrite.get())
+            .field("frozen", &self.frozen.get())
             .finish()
     }
 }
@@ -116,7 +148,7 @@ impl<D: Dimension> Debug for InputBackward<D> {
 impl<D: Dimension> Display for InputBackward<D> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
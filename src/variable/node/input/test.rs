@@ -54,6 +54,8 @@ mod backward {
         let input = InputBackward {
             gradient: RefCell::new(Some(Tensor::zeros((3, 3)))),
             overwrite: Cell::new(true),
+            frozen: Cell::new(false),
+            sink: RefCell::new(None),
         };
         assert!(input.can_overwrite());
         assert_eq!(*input.gradient(), Tensor::from_elem((3, 3), 0.));
@@ -65,6 +67,8 @@ mod backward {
         let input = InputBackward {
             gradient: RefCell::new(Some(Tensor::zeros((3, 3)))),
             overwrite: Cell::new(true),
+            frozen: Cell::new(false),
+            sink: RefCell::new(None),
         };
 
         assert!(input.can_overwrite());
@@ -79,20 +83,48 @@ mod backward {
         let input = InputBackward {
             gradient: RefCell::new(Some(Tensor::ones((3, 3)))),
             overwrite: Cell::new(true),
+            frozen: Cell::new(false),
+            sink: RefCell::new(None),
         };
 
         input.zero_grad();
         assert_eq!(*input.gradient(), Tensor::zeros((3, 3)));
     }
 
+    #[test]
+    fn freeze() {
+        let input = InputBackward {
+            gradient: RefCell::new(Some(Tensor::ones((3, 3)))),
+            overwrite: Cell::new(true),
+            frozen: Cell::new(false),
+            sink: RefCell::new(None),
+        };
+
+        input.freeze();
+        assert!(input.is_frozen());
+        assert_eq!(*input.gradient(), Tensor::zeros((3, 3)));
+
+        // Accumulation into a frozen leaf has no effect on its gradient.
+        input.gradient_mut().fill(5.);
+        assert_eq!(*input.gradient(), Tensor::zeros((3, 3)));
+
+        input.unfreeze();
+        assert!(!input.is_frozen());
+        assert!(input.sink.borrow().is_none());
+        input.gradient_mut().fill(5.);
+        assert_eq!(*input.gradient(), Tensor::from_elem((3, 3), 5.));
+    }
+
     #[test]
     fn debug() {
         let node = InputBackward {
             gradient: RefCell::new(Some(Tensor::zeros(1))),
             overwrite: Cell::new(false),
+            frozen: Cell::new(false),
+            sink: RefCell::new(None),
         };
         let output =
-            "InputBackward { gradient: Some([0.0], shape=[1], strides=[1], layout=CFcf (0xf), const ndim=1), overwrite: false }";
+            "InputBackward { gradient: Some([0.0], shape=[1], strides=[1], layout=CFcf (0xf), const ndim=1), overwrite: false, frozen: false }";
 
         assert_eq!(output, format!("{:?}", node));
     }
@@ -102,6 +134,8 @@ mod backward {
         let node = InputBackward {
             gradient: RefCell::new(Some(Tensor::zeros(1))),
             overwrite: Cell::new(false),
+            frozen: Cell::new(false),
+            sink: RefCell::new(None),
         };
 
         assert_eq!(format!("{}", node.gradient()), format!("{}", node));
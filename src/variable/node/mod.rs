@@ -1,20 +1,26 @@
 use ndarray::{
     linalg::{general_mat_mul, general_mat_vec_mul},
-    Array, ArrayBase, ArrayD, ArrayView, Axis, DimMax, Dimension, IntoNdProducer, Ix1, Ix2, Zip,
+    Array, ArrayBase, ArrayD, ArrayView, Axis, DimMax, Dimension, IntoNdProducer, Ix1, Ix2, Ix3,
+    Zip,
 };
 use std::{
     cell::{Ref, RefCell, RefMut},
     rc::Rc,
 };
 
+use super::Summary;
+
 pub(crate) use binary::*;
 pub use binary::{
-    Constant, Convolve, ConvolveWithGroups, PaddingMode, Reflective, Replicative, Zero,
+    Constant, Convolve, ConvolveTransposed, ConvolveWithGroups, PaddingMode, Reflective,
+    Replicative, Zero,
 };
 pub use input::{Input, InputBackward};
 pub(crate) use nary::*;
 pub(crate) use unary::*;
-pub use unary::MaxPooling;
+pub use unary::{
+    AdaptiveAvgPooling, AdaptiveMaxPooling, AvgPooling, MaxPoolIndices, MaxPooling, PadMode,
+};
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Nodes' Modules ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -184,7 +190,7 @@ pub trait Eval {
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 /// Utility trait useful to compute the dimensionality of algebraic operations' results.
-trait DotDim<Rhs>
+pub trait DotDim<Rhs>
 where
     Self: Dimension,
     Rhs: Dimension,
@@ -231,6 +237,30 @@ impl DotDim<Ix2> for Ix2 {
     }
 }
 
+impl DotDim<Ix3> for Ix2 {
+    type Output = Ix3;
+
+    fn shape(lhs: Self, rhs: Ix3) -> <Self as DotDim<Ix3>>::Output {
+        let mut res_shape = Ix3::zeros(3);
+        res_shape[0] = rhs[0];
+        res_shape[1] = lhs[0];
+        res_shape[2] = rhs[2];
+        res_shape
+    }
+}
+
+impl DotDim<Ix3> for Ix3 {
+    type Output = Ix3;
+
+    fn shape(lhs: Self, rhs: Ix3) -> <Self as DotDim<Ix3>>::Output {
+        let mut res_shape = Ix3::zeros(3);
+        res_shape[0] = lhs[0];
+        res_shape[1] = lhs[1];
+        res_shape[2] = rhs[2];
+        res_shape
+    }
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Gradient Accumulation Utilities  ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -593,3 +623,50 @@ where
 {
     Tensor::from_shape_vec(shape, elements).unwrap()
 }
+
+#[cfg(test)]
+/// Checks the gradient of `param` against the one computed by means of central finite
+/// differences of `loss`.
+///
+/// # Arguments
+///
+/// * `param` - differentiable variable whose gradient is checked.
+///
+/// * `loss` - loss depending on `param`, if it has more than one element their sum is checked.
+pub(crate) fn check_gradient<T: ?Sized, U: ?Sized, F: ?Sized, B: ?Sized>(
+    param: &super::VarDiff<T, U>,
+    loss: &super::VarDiff<F, B>,
+) where
+    T: Data + 'static,
+    U: Gradient<Dim = T::Dim> + 'static,
+    F: Data + 'static,
+    B: Gradient<Dim = F::Dim> + 'static,
+{
+    loss.forward();
+    param.zero_grad();
+    loss.backward(1.);
+    let gradient = param.grad().clone();
+
+    let h = 1e-2;
+    for (i, grad) in gradient.iter().enumerate() {
+        let evaluate = |delta: f32| {
+            if let Some(el) = param.data_mut().iter_mut().nth(i) {
+                *el += delta;
+            }
+            loss.forward();
+            let value = loss.data().sum();
+            if let Some(el) = param.data_mut().iter_mut().nth(i) {
+                *el -= delta;
+            }
+            value
+        };
+
+        let numeric = (evaluate(h) - evaluate(-h)) / (2. * h);
+        assert!(
+            (grad - numeric).abs() <= 1e-2,
+            "analytic: {}, numeric: {}",
+            grad,
+            numeric
+        );
+    }
+}
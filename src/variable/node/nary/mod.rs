@@ -3,7 +3,7 @@ mod multi_stack;
 
 use super::{
     expect_tensor, expect_tensor_mut, push_gradient, Backward, Cache, Data, Forward, Gradient,
-    Overwrite, Tensor,
+    Overwrite, Summary, Tensor,
 };
 
 #[cfg(test)]
@@ -0,0 +1,665 @@
+use super::{
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite, Summary,
+    Tensor,
+};
+#[cfg(test)]
+use super::{new_backward_input, new_input, new_tensor};
+use crate::{Var, VarDiff};
+use ndarray::{Array, Dimension, IntoDimension, RemoveAxis, Slice, Zip};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    ops::Range,
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Adaptive Traits ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+pub trait AdaptiveAvgPooling<T> {
+    type Output;
+
+    fn adaptive_avg_pool(operand: T, output_shape: &[usize]) -> Self::Output;
+}
+
+pub trait AdaptiveMaxPooling<T> {
+    type Output;
+
+    fn adaptive_max_pool(operand: T, output_shape: &[usize]) -> Self::Output;
+}
+
+impl<T: ?Sized> AdaptiveAvgPooling<Self> for Var<T>
+where
+    T: Data,
+    T::Dim: RemoveAxis,
+    <T::Dim as Dimension>::Smaller: RemoveAxis,
+{
+    type Output = Var<AdaptiveAvgPool<T>>;
+
+    fn adaptive_avg_pool(operand: Self, output_shape: &[usize]) -> Self::Output {
+        Var::from(
+            AdaptiveAvgPool::new(operand.node, output_shape),
+            operand.past,
+        )
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> AdaptiveAvgPooling<Self> for VarDiff<U, T>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    <T as Gradient>::Dim: RemoveAxis,
+    <<T as Gradient>::Dim as Dimension>::Smaller: RemoveAxis,
+{
+    type Output = VarDiff<AdaptiveAvgPool<U>, AdaptiveAvgPoolBackward<T>>;
+
+    fn adaptive_avg_pool(operand: Self, output_shape: &[usize]) -> Self::Output {
+        let var = Var::adaptive_avg_pool(operand.var, output_shape);
+        let node = AdaptiveAvgPoolBackward::new(operand.node, output_shape);
+        VarDiff::from(node, operand.past, var)
+    }
+}
+
+impl<T: ?Sized> AdaptiveMaxPooling<Self> for Var<T>
+where
+    T: Data,
+    T::Dim: RemoveAxis,
+    <T::Dim as Dimension>::Smaller: RemoveAxis,
+{
+    type Output = Var<AdaptiveMaxPool<T>>;
+
+    fn adaptive_max_pool(operand: Self, output_shape: &[usize]) -> Self::Output {
+        Var::from(
+            AdaptiveMaxPool::new(operand.node, output_shape),
+            operand.past,
+        )
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> AdaptiveMaxPooling<Self> for VarDiff<U, T>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    <T as Gradient>::Dim: RemoveAxis,
+    <<T as Gradient>::Dim as Dimension>::Smaller: RemoveAxis,
+{
+    type Output = VarDiff<AdaptiveMaxPool<U>, AdaptiveMaxPoolBackward<T, U>>;
+
+    fn adaptive_max_pool(operand: Self, output_shape: &[usize]) -> Self::Output {
+        let var = Var::adaptive_max_pool(operand.var, output_shape);
+        let node = AdaptiveMaxPoolBackward::new(operand.node, var.node.clone());
+        VarDiff::from(node, operand.past, var)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Utilities ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Checks the output shape of an adaptive pooling over an operand of shape *(N, C, ...)* and
+/// returns the shape of the result.
+fn adaptive_pooling_shape<D: Dimension>(shape: &D, output_shape: &[usize]) -> D {
+    let operand_ndims = shape.ndim() - 2;
+    assert_eq!(
+        operand_ndims,
+        output_shape.len(),
+        "error: invalid output shape {:?} for {}d input.",
+        output_shape,
+        operand_ndims
+    );
+    assert!(
+        output_shape.iter().all(|len| *len > 0),
+        "error: output shape {:?} must not be empty.",
+        output_shape
+    );
+    assert!(
+        shape.slice().iter().skip(2).all(|len| *len > 0),
+        "error: input shape {:?} must not be empty.",
+        shape.slice()
+    );
+
+    let mut pooled = shape.clone();
+    pooled
+        .slice_mut()
+        .iter_mut()
+        .skip(2)
+        .zip(output_shape)
+        .for_each(|(dim, output_dim)| *dim = *output_dim);
+
+    pooled
+}
+
+/// Returns, for each spatial axis, the range of the operand pooled into the element `index` of
+/// the output. The `i`-th of `output` pools along an axis of length `len` starts at
+/// `floor(i * len / output)` and ends at `ceil((i + 1) * len / output)`, so that consecutive
+/// pools may overlap when `len` is not a multiple of `output`.
+pub(crate) fn adaptive_window(
+    index: &[usize],
+    shape: &[usize],
+    output_shape: &[usize],
+) -> Vec<Range<usize>> {
+    itertools::izip!(index, shape, output_shape)
+        .map(|(index, len, output_len)| {
+            let start = index * len / output_len;
+            let end = ((index + 1) * len).div_ceil(*output_len);
+            start..end
+        })
+        .collect()
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ AdaptiveAvgPool ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct AdaptiveAvgPool<T: ?Sized>
+where
+    T: Data,
+{
+    operand: Rc<T>,
+    data: RefCell<Tensor<T::Dim>>,
+    output_shape: Vec<usize>,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized> AdaptiveAvgPool<T>
+where
+    T: Data,
+{
+    pub fn new(operand: Rc<T>, output_shape: &[usize]) -> Self {
+        let shape = adaptive_pooling_shape(&operand.data().raw_dim(), output_shape);
+
+        Self {
+            operand,
+            data: RefCell::new(Tensor::zeros(shape)),
+            output_shape: output_shape.to_vec(),
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized> Cache for AdaptiveAvgPool<T>
+where
+    T: Data,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized> Forward for AdaptiveAvgPool<T>
+where
+    T: Data,
+    <T as Data>::Dim: RemoveAxis,
+    <<T as Data>::Dim as Dimension>::Smaller: RemoveAxis,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let (operand, mut data) = (self.operand.data(), self.data.borrow_mut());
+        let channel_shape = &operand.shape()[2..];
+
+        Zip::from(data.outer_iter_mut())
+            .and(operand.outer_iter())
+            .for_each(|mut data_sample, op_sample| {
+                Zip::from(data_sample.outer_iter_mut())
+                    .and(op_sample.outer_iter())
+                    .for_each(|mut data_channel, op_channel| {
+                        data_channel.indexed_iter_mut().for_each(|(i, y)| {
+                            let window = adaptive_window(
+                                i.into_dimension().slice(),
+                                channel_shape,
+                                &self.output_shape,
+                            );
+
+                            *y = op_channel
+                                .slice_each_axis(|ax| Slice::from(window[ax.axis.index()].clone()))
+                                .mean()
+                                .unwrap();
+                        })
+                    })
+            })
+    }
+}
+
+impl<T: ?Sized> Data for AdaptiveAvgPool<T>
+where
+    T: Data,
+{
+    type Dim = T::Dim;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> Debug for AdaptiveAvgPool<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AdaptiveAvgPool")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("output_shape", &self.output_shape)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for AdaptiveAvgPool<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ AdaptiveAvgPoolBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+pub struct AdaptiveAvgPoolBackward<T: ?Sized>
+where
+    T: Gradient,
+{
+    gradient: RefCell<Option<Tensor<T::Dim>>>,
+    shape: T::Dim,
+    overwrite: Cell<bool>,
+    operand: Rc<T>,
+    output_shape: Vec<usize>,
+}
+
+impl<T: ?Sized> AdaptiveAvgPoolBackward<T>
+where
+    T: Gradient,
+{
+    pub fn new(operand: Rc<T>, output_shape: &[usize]) -> Self {
+        let shape = adaptive_pooling_shape(&operand.gradient().raw_dim(), output_shape);
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape.clone()))),
+            shape,
+            overwrite: Cell::new(true),
+            operand,
+            output_shape: output_shape.to_vec(),
+        }
+    }
+}
+
+impl<T: ?Sized> Gradient for AdaptiveAvgPoolBackward<T>
+where
+    T: Gradient,
+{
+    type Dim = T::Dim;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized> Overwrite for AdaptiveAvgPoolBackward<T>
+where
+    T: Gradient,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized> Backward for AdaptiveAvgPoolBackward<T>
+where
+    T: Gradient,
+    <T as Gradient>::Dim: RemoveAxis,
+    <<T as Gradient>::Dim as Dimension>::Smaller: RemoveAxis,
+{
+    fn backward(&self) {
+        let mut op_grad = self.operand.gradient_mut();
+        let grad = self.gradient();
+        let channel_shape = op_grad.shape()[2..].to_vec();
+
+        if self.operand.can_overwrite() {
+            op_grad.fill(0.);
+            self.operand.set_overwrite(false);
+        }
+
+        Zip::from(grad.outer_iter())
+            .and(op_grad.outer_iter_mut())
+            .for_each(|grad_sample, mut op_grad_sample| {
+                Zip::from(grad_sample.outer_iter())
+                    .and(op_grad_sample.outer_iter_mut())
+                    .for_each(|grad_channel, mut op_grad_channel| {
+                        grad_channel.indexed_iter().for_each(|(i, grad_el)| {
+                            let window = adaptive_window(
+                                i.into_dimension().slice(),
+                                &channel_shape,
+                                &self.output_shape,
+                            );
+                            let mut op_grad_window = op_grad_channel.slice_each_axis_mut(|ax| {
+                                Slice::from(window[ax.axis.index()].clone())
+                            });
+                            let share = grad_el / op_grad_window.len() as f32;
+
+                            op_grad_window
+                                .iter_mut()
+                                .for_each(|op_grad_el| *op_grad_el += share);
+                        })
+                    })
+            });
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<T: ?Sized> Debug for AdaptiveAvgPoolBackward<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AdaptiveAvgPoolBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("output_shape", &self.output_shape)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for AdaptiveAvgPoolBackward<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ AdaptiveMaxPool ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct AdaptiveMaxPool<T: ?Sized>
+where
+    T: Data,
+{
+    operand: Rc<T>,
+    data: RefCell<Tensor<T::Dim>>,
+    indices: RefCell<Array<T::Dim, T::Dim>>,
+    output_shape: Vec<usize>,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized> AdaptiveMaxPool<T>
+where
+    T: Data,
+{
+    pub fn new(operand: Rc<T>, output_shape: &[usize]) -> Self {
+        let shape = adaptive_pooling_shape(&operand.data().raw_dim(), output_shape);
+
+        Self {
+            operand,
+            data: RefCell::new(Tensor::zeros(shape.clone())),
+            indices: RefCell::new(Array::from_elem(shape.clone(), shape)),
+            output_shape: output_shape.to_vec(),
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized> Cache for AdaptiveMaxPool<T>
+where
+    T: Data,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized> Forward for AdaptiveMaxPool<T>
+where
+    T: Data,
+    <T as Data>::Dim: RemoveAxis,
+    <<T as Data>::Dim as Dimension>::Smaller: RemoveAxis,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let (operand, mut data, mut indices) = (
+            self.operand.data(),
+            self.data.borrow_mut(),
+            self.indices.borrow_mut(),
+        );
+        let channel_shape = &operand.shape()[2..];
+
+        // Stores, for each element of the output, the position of its maximum in the operand.
+        data.indexed_iter_mut()
+            .zip(indices.iter_mut())
+            .for_each(|((i, y), index)| {
+                let i = i.into_dimension();
+                let window = adaptive_window(&i.slice()[2..], channel_shape, &self.output_shape);
+
+                let op_channel = operand.index_axis(ndarray::Axis(0), i[0]);
+                let op_channel = op_channel.index_axis(ndarray::Axis(0), i[1]);
+
+                // Ties are won by the first element of the pool.
+                let (mut max, mut argmax) = (f32::NEG_INFINITY, None);
+                op_channel
+                    .slice_each_axis(|ax| Slice::from(window[ax.axis.index()].clone()))
+                    .indexed_iter()
+                    .for_each(|(j, el)| {
+                        if argmax.is_none() || *el > max {
+                            max = *el;
+                            argmax = Some(j.into_dimension());
+                        }
+                    });
+
+                *y = max;
+                let argmax = argmax.unwrap();
+                index[0] = i[0];
+                index[1] = i[1];
+                index
+                    .slice_mut()
+                    .iter_mut()
+                    .skip(2)
+                    .zip(argmax.slice())
+                    .zip(&window)
+                    .for_each(|((position, offset), range)| *position = range.start + offset);
+            });
+    }
+}
+
+impl<T: ?Sized> Data for AdaptiveMaxPool<T>
+where
+    T: Data,
+{
+    type Dim = T::Dim;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> Debug for AdaptiveMaxPool<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AdaptiveMaxPool")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("output_shape", &self.output_shape)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for AdaptiveMaxPool<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ AdaptiveMaxPoolBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+pub struct AdaptiveMaxPoolBackward<T: ?Sized, U: ?Sized>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    gradient: RefCell<Option<Tensor<T::Dim>>>,
+    shape: T::Dim,
+    overwrite: Cell<bool>,
+    diff_operand: Rc<T>,
+    no_diff_operand: Rc<AdaptiveMaxPool<U>>,
+}
+
+impl<T: ?Sized, U: ?Sized> AdaptiveMaxPoolBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    pub fn new(diff_operand: Rc<T>, no_diff_operand: Rc<AdaptiveMaxPool<U>>) -> Self {
+        let shape = no_diff_operand.data().raw_dim();
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape.clone()))),
+            shape,
+            overwrite: Cell::new(true),
+            diff_operand,
+            no_diff_operand,
+        }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Gradient for AdaptiveMaxPoolBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    type Dim = T::Dim;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Overwrite for AdaptiveMaxPoolBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Backward for AdaptiveMaxPoolBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    fn backward(&self) {
+        let mut op_grad = self.diff_operand.gradient_mut();
+        let indices = self.no_diff_operand.indices.borrow();
+        let grad = self.gradient();
+
+        if self.diff_operand.can_overwrite() {
+            op_grad.fill(0.);
+            self.diff_operand.set_overwrite(false);
+        }
+
+        // The gradient flows to the maximum of each pool, accumulating over overlapping ones.
+        grad.iter()
+            .zip(indices.iter())
+            .for_each(|(grad_el, index)| op_grad[index.clone()] += grad_el);
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Debug for AdaptiveMaxPoolBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AdaptiveMaxPoolBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("output_shape", &self.no_diff_operand.output_shape)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Display for AdaptiveMaxPoolBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
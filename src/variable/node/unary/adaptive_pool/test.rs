@@ -0,0 +1,309 @@
+use super::{
+    adaptive_window, new_backward_input, new_input, new_tensor, AdaptiveAvgPool,
+    AdaptiveAvgPoolBackward, AdaptiveMaxPool, AdaptiveMaxPoolBackward, Backward, Cache, Data,
+    Forward, Gradient, Overwrite, Tensor,
+};
+
+#[test]
+fn windows() {
+    let windows: Vec<_> = (0..3)
+        .map(|i| adaptive_window(&[i], &[7], &[3]).pop().unwrap())
+        .collect();
+
+    assert_eq!(windows, vec![0..3, 2..5, 4..7]);
+
+    // When the input divides evenly the pools don't overlap.
+    let windows: Vec<_> = (0..3)
+        .map(|i| adaptive_window(&[i], &[6], &[3]).pop().unwrap())
+        .collect();
+
+    assert_eq!(windows, vec![0..2, 2..4, 4..6]);
+}
+
+mod forward {
+    use super::{
+        new_input, new_tensor, AdaptiveAvgPool, AdaptiveMaxPool, Cache, Data, Forward, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((2, 3, 7, 7), vec![0.; 2 * 3 * 7 * 7]);
+        let node = AdaptiveAvgPool::new(input.clone(), &[3, 3]);
+
+        assert_eq!(*node.data(), Tensor::zeros((2, 3, 3, 3)));
+        assert_eq!(*node.data_mut(), Tensor::zeros((2, 3, 3, 3)));
+        assert!(!node.was_computed());
+
+        let node = AdaptiveMaxPool::new(input, &[3, 3]);
+
+        assert_eq!(*node.data(), Tensor::zeros((2, 3, 3, 3)));
+        assert_eq!(*node.data_mut(), Tensor::zeros((2, 3, 3, 3)));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic(expected = "error: invalid output shape [3] for 2d input.")]
+    fn creation_invalid_output_shape() {
+        let input = new_input((2, 3, 7, 7), vec![0.; 2 * 3 * 7 * 7]);
+        AdaptiveAvgPool::new(input, &[3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "error: output shape [0, 3] must not be empty.")]
+    fn creation_empty_output_shape() {
+        let input = new_input((2, 3, 7, 7), vec![0.; 2 * 3 * 7 * 7]);
+        AdaptiveMaxPool::new(input, &[0, 3]);
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((2, 3, 7, 7), vec![0.; 2 * 3 * 7 * 7]);
+        let node = AdaptiveAvgPool::new(input, &[3, 3]);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn forward_avg() {
+        let input = new_input((1, 1, 7, 7), (0..49).map(|el| el as f32).collect());
+        let node = AdaptiveAvgPool::new(input, &[3, 3]);
+
+        // Pools span rows and columns [0, 3), [2, 5) and [4, 7).
+        node.forward();
+        assert_eq!(
+            *node.data(),
+            new_tensor(
+                (1, 1, 3, 3),
+                vec![8., 10., 12., 22., 24., 26., 36., 38., 40.]
+            )
+        );
+    }
+
+    #[test]
+    fn forward_max() {
+        let input = new_input((1, 1, 7, 7), (0..49).map(|el| el as f32).collect());
+        let node = AdaptiveMaxPool::new(input, &[3, 3]);
+
+        node.forward();
+        assert_eq!(
+            *node.data(),
+            new_tensor(
+                (1, 1, 3, 3),
+                vec![16., 18., 20., 30., 32., 34., 44., 46., 48.]
+            )
+        );
+    }
+
+    #[test]
+    fn forward_global() {
+        let input = new_input((1, 2, 2, 2), vec![1., 2., 3., 4., -1., -2., -3., -4.]);
+
+        let node = AdaptiveAvgPool::new(input.clone(), &[1, 1]);
+        node.forward();
+        assert_eq!(*node.data(), new_tensor((1, 2, 1, 1), vec![2.5, -2.5]));
+
+        let node = AdaptiveMaxPool::new(input, &[1, 1]);
+        node.forward();
+        assert_eq!(*node.data(), new_tensor((1, 2, 1, 1), vec![4., -1.]));
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((1, 1, 2, 2), vec![1., 2., 3., 4.]);
+        let node = AdaptiveAvgPool::new(input.clone(), &[1, 1]);
+
+        let output = "AdaptiveAvgPool { data: [[[[0.0]]]], shape=[1, 1, 1, 1], strides=[1, 1, 1, 1], layout=CFcf (0xf), const ndim=4, output_shape: [1, 1], computed: false }";
+        assert_eq!(output, format!("{:?}", node));
+
+        let node = AdaptiveMaxPool::new(input, &[1, 1]);
+
+        let output = "AdaptiveMaxPool { data: [[[[0.0]]]], shape=[1, 1, 1, 1], strides=[1, 1, 1, 1], layout=CFcf (0xf), const ndim=4, output_shape: [1, 1], computed: false }";
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((1, 1, 2, 2), vec![1., 2., 3., 4.]);
+        let node = AdaptiveAvgPool::new(input.clone(), &[1, 1]);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+
+        let node = AdaptiveMaxPool::new(input, &[1, 1]);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        new_backward_input, new_input, new_tensor, AdaptiveAvgPoolBackward, AdaptiveMaxPool,
+        AdaptiveMaxPoolBackward, Backward, Forward, Gradient, Overwrite, Tensor,
+    };
+    use std::rc::Rc;
+
+    #[test]
+    fn creation() {
+        let node = AdaptiveAvgPoolBackward::new(
+            new_backward_input((2, 3, 7, 7), vec![0.; 2 * 3 * 7 * 7]),
+            &[3, 3],
+        );
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((2, 3, 3, 3), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((2, 3, 3, 3), 0.));
+        assert!(node.can_overwrite());
+
+        let node = AdaptiveMaxPoolBackward::new(
+            new_backward_input((2, 3, 7, 7), vec![0.; 2 * 3 * 7 * 7]),
+            Rc::new(AdaptiveMaxPool::new(
+                new_input((2, 3, 7, 7), vec![0.; 2 * 3 * 7 * 7]),
+                &[3, 3],
+            )),
+        );
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((2, 3, 3, 3), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((2, 3, 3, 3), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((2, 3, 7, 7), vec![0.; 2 * 3 * 7 * 7]);
+        let node = AdaptiveAvgPoolBackward::new(diff.clone(), &[3, 3]);
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward_avg() {
+        let diff = new_backward_input((1, 1, 7, 7), vec![0.; 49]);
+        let node = AdaptiveAvgPoolBackward::new(diff.clone(), &[3, 3]);
+        *node.gradient_mut() = new_tensor((1, 1, 3, 3), vec![9.; 9]);
+
+        // Rows and columns 2 and 4 belong to two pools each.
+        let row = [1., 1., 2., 1., 2., 1., 1.];
+        let expected: Vec<f32> = row
+            .iter()
+            .flat_map(|r| row.iter().map(move |c| r * c))
+            .collect();
+
+        node.backward();
+        assert_eq!(*diff.gradient(), new_tensor((1, 1, 7, 7), expected.clone()));
+
+        node.backward();
+        assert_eq!(
+            *diff.gradient(),
+            new_tensor((1, 1, 7, 7), expected.iter().map(|el| el * 2.).collect())
+        );
+
+        diff.set_overwrite(true);
+        node.backward();
+        assert_eq!(*diff.gradient(), new_tensor((1, 1, 7, 7), expected));
+    }
+
+    #[test]
+    fn backward_max() {
+        let input = new_input((1, 1, 7, 7), (0..49).map(|el| el as f32).collect());
+        let pool = Rc::new(AdaptiveMaxPool::new(input, &[3, 3]));
+        pool.forward();
+
+        let diff = new_backward_input((1, 1, 7, 7), vec![0.; 49]);
+        let node = AdaptiveMaxPoolBackward::new(diff.clone(), pool);
+        *node.gradient_mut() = new_tensor((1, 1, 3, 3), vec![1.; 9]);
+
+        let mut expected = vec![0.; 49];
+        [2, 4, 6]
+            .iter()
+            .for_each(|r| [2, 4, 6].iter().for_each(|c| expected[r * 7 + c] = 1.));
+
+        node.backward();
+        assert_eq!(*diff.gradient(), new_tensor((1, 1, 7, 7), expected.clone()));
+
+        node.backward();
+        assert_eq!(
+            *diff.gradient(),
+            new_tensor((1, 1, 7, 7), expected.iter().map(|el| el * 2.).collect())
+        );
+
+        diff.set_overwrite(true);
+        node.backward();
+        assert_eq!(*diff.gradient(), new_tensor((1, 1, 7, 7), expected));
+    }
+
+    #[test]
+    fn backward_max_shared() {
+        // The maximum at the center belongs to all of the four overlapping pools.
+        let mut data = vec![0.; 9];
+        data[4] = 1.;
+        let input = new_input((1, 1, 3, 3), data);
+        let pool = Rc::new(AdaptiveMaxPool::new(input, &[2, 2]));
+        pool.forward();
+
+        let diff = new_backward_input((1, 1, 3, 3), vec![0.; 9]);
+        let node = AdaptiveMaxPoolBackward::new(diff.clone(), pool);
+        *node.gradient_mut() = new_tensor((1, 1, 2, 2), vec![1.; 4]);
+
+        node.backward();
+        assert_eq!(
+            *diff.gradient(),
+            new_tensor((1, 1, 3, 3), vec![0., 0., 0., 0., 4., 0., 0., 0., 0.])
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let diff = new_backward_input((1, 1, 2, 2), vec![0.; 4]);
+        let node = AdaptiveAvgPoolBackward::new(diff, &[1, 1]);
+
+        let output = "AdaptiveAvgPoolBackward { gradient: Some([[[[0.0]]]], shape=[1, 1, 1, 1], strides=[1, 1, 1, 1], layout=CFcf (0xf), const ndim=4), output_shape: [1, 1], overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let diff = new_backward_input((1, 1, 2, 2), vec![0.; 4]);
+        let node = AdaptiveAvgPoolBackward::new(diff, &[1, 1]);
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        let diff = new_backward_input((1, 1, 2, 2), vec![0.; 4]);
+        let node = AdaptiveAvgPoolBackward::new(diff, &[1, 1]);
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
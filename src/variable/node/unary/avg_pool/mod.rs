@@ -0,0 +1,380 @@
+use super::{
+    expect_tensor, expect_tensor_mut, pool_window, pooling_shape, Backward, Cache, Data, Forward,
+    Gradient, Overwrite, Summary, Tensor,
+};
+#[cfg(test)]
+use super::{new_backward_input, new_input, new_tensor};
+use crate::{Var, VarDiff};
+use ndarray::{Dimension, IntoDimension, RemoveAxis, Slice, Zip};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    ops::Range,
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ AvgPooling Trait ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+pub trait AvgPooling<T> {
+    type Output;
+
+    fn avg_pool(
+        operand: T,
+        pool_shape: &[usize],
+        stride: &[usize],
+        padding: &[usize],
+        count_include_pad: bool,
+    ) -> Self::Output;
+}
+
+impl<T: ?Sized> AvgPooling<Self> for Var<T>
+where
+    T: Data,
+    T::Dim: RemoveAxis,
+    <T::Dim as Dimension>::Smaller: RemoveAxis,
+{
+    type Output = Var<AvgPool<T>>;
+
+    fn avg_pool(
+        operand: Self,
+        pool_shape: &[usize],
+        stride: &[usize],
+        padding: &[usize],
+        count_include_pad: bool,
+    ) -> Self::Output {
+        Var::from(
+            AvgPool::new(operand.node, pool_shape, stride, padding, count_include_pad),
+            operand.past,
+        )
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> AvgPooling<Self> for VarDiff<U, T>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    <T as Gradient>::Dim: RemoveAxis,
+    <<T as Gradient>::Dim as Dimension>::Smaller: RemoveAxis,
+{
+    type Output = VarDiff<AvgPool<U>, AvgPoolBackward<T>>;
+
+    fn avg_pool(
+        operand: Self,
+        pool_shape: &[usize],
+        stride: &[usize],
+        padding: &[usize],
+        count_include_pad: bool,
+    ) -> Self::Output {
+        let var = Var::avg_pool(operand.var, pool_shape, stride, padding, count_include_pad);
+        let node =
+            AvgPoolBackward::new(operand.node, pool_shape, stride, padding, count_include_pad);
+        VarDiff::from(node, operand.past, var)
+    }
+}
+
+/// Returns the number of elements the pool covered by `window` is averaged over.
+fn divisor(window: &[Range<usize>], pool_shape: &[usize], count_include_pad: bool) -> f32 {
+    if count_include_pad {
+        pool_shape.iter().product::<usize>() as f32
+    } else {
+        window.iter().map(|range| range.len()).product::<usize>() as f32
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ AvgPool ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct AvgPool<T: ?Sized>
+where
+    T: Data,
+{
+    operand: Rc<T>,
+    data: RefCell<Tensor<T::Dim>>,
+    pool_shape: Vec<usize>,
+    stride: Vec<usize>,
+    padding: Vec<usize>,
+    count_include_pad: bool,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized> AvgPool<T>
+where
+    T: Data,
+{
+    pub fn new(
+        operand: Rc<T>,
+        pool_shape: &[usize],
+        stride: &[usize],
+        padding: &[usize],
+        count_include_pad: bool,
+    ) -> Self {
+        let shape = pooling_shape(&operand.data().raw_dim(), pool_shape, stride, padding);
+
+        Self {
+            operand,
+            data: RefCell::new(Tensor::zeros(shape)),
+            pool_shape: pool_shape.to_vec(),
+            stride: stride.to_vec(),
+            padding: padding.to_vec(),
+            count_include_pad,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized> Cache for AvgPool<T>
+where
+    T: Data,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized> Forward for AvgPool<T>
+where
+    T: Data,
+    <T as Data>::Dim: RemoveAxis,
+    <<T as Data>::Dim as Dimension>::Smaller: RemoveAxis,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let (operand, mut data) = (self.operand.data(), self.data.borrow_mut());
+        let (pool_shape, stride, padding) = (&self.pool_shape, &self.stride, &self.padding);
+        let channel_shape = &operand.shape()[2..];
+
+        Zip::from(data.outer_iter_mut())
+            .and(operand.outer_iter())
+            .for_each(|mut data_sample, op_sample| {
+                Zip::from(data_sample.outer_iter_mut())
+                    .and(op_sample.outer_iter())
+                    .for_each(|mut data_channel, op_channel| {
+                        data_channel.indexed_iter_mut().for_each(|(i, y)| {
+                            let window = pool_window(
+                                i.into_dimension().slice(),
+                                channel_shape,
+                                pool_shape,
+                                stride,
+                                padding,
+                            );
+
+                            *y = op_channel
+                                .slice_each_axis(|ax| Slice::from(window[ax.axis.index()].clone()))
+                                .sum()
+                                / divisor(&window, pool_shape, self.count_include_pad);
+                        })
+                    })
+            })
+    }
+}
+
+impl<T: ?Sized> Data for AvgPool<T>
+where
+    T: Data,
+{
+    type Dim = T::Dim;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> Debug for AvgPool<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AvgPool")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("pool_shape", &self.pool_shape)
+            .field("stride", &self.stride)
+            .field("padding", &self.padding)
+            .field("count_include_pad", &self.count_include_pad)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for AvgPool<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ AvgPoolBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+pub struct AvgPoolBackward<T: ?Sized>
+where
+    T: Gradient,
+{
+    gradient: RefCell<Option<Tensor<T::Dim>>>,
+    shape: T::Dim,
+    overwrite: Cell<bool>,
+    operand: Rc<T>,
+    pool_shape: Vec<usize>,
+    stride: Vec<usize>,
+    padding: Vec<usize>,
+    count_include_pad: bool,
+}
+
+impl<T: ?Sized> AvgPoolBackward<T>
+where
+    T: Gradient,
+{
+    pub fn new(
+        operand: Rc<T>,
+        pool_shape: &[usize],
+        stride: &[usize],
+        padding: &[usize],
+        count_include_pad: bool,
+    ) -> Self {
+        let shape = pooling_shape(&operand.gradient().raw_dim(), pool_shape, stride, padding);
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape.clone()))),
+            shape,
+            overwrite: Cell::new(true),
+            operand,
+            pool_shape: pool_shape.to_vec(),
+            stride: stride.to_vec(),
+            padding: padding.to_vec(),
+            count_include_pad,
+        }
+    }
+}
+
+impl<T: ?Sized> Gradient for AvgPoolBackward<T>
+where
+    T: Gradient,
+{
+    type Dim = T::Dim;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized> Overwrite for AvgPoolBackward<T>
+where
+    T: Gradient,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized> Backward for AvgPoolBackward<T>
+where
+    T: Gradient,
+    <T as Gradient>::Dim: RemoveAxis,
+    <<T as Gradient>::Dim as Dimension>::Smaller: RemoveAxis,
+{
+    fn backward(&self) {
+        let mut op_grad = self.operand.gradient_mut();
+        let grad = self.gradient();
+        let (pool_shape, stride, padding) = (&self.pool_shape, &self.stride, &self.padding);
+        let channel_shape = op_grad.shape()[2..].to_vec();
+
+        if self.operand.can_overwrite() {
+            op_grad.fill(0.);
+            self.operand.set_overwrite(false);
+        }
+
+        // Each element of a pool receives an equal share of its gradient.
+        Zip::from(grad.outer_iter())
+            .and(op_grad.outer_iter_mut())
+            .for_each(|grad_sample, mut op_grad_sample| {
+                Zip::from(grad_sample.outer_iter())
+                    .and(op_grad_sample.outer_iter_mut())
+                    .for_each(|grad_channel, mut op_grad_channel| {
+                        grad_channel.indexed_iter().for_each(|(i, grad_el)| {
+                            let window = pool_window(
+                                i.into_dimension().slice(),
+                                &channel_shape,
+                                pool_shape,
+                                stride,
+                                padding,
+                            );
+                            let share =
+                                grad_el / divisor(&window, pool_shape, self.count_include_pad);
+
+                            op_grad_channel
+                                .slice_each_axis_mut(|ax| {
+                                    Slice::from(window[ax.axis.index()].clone())
+                                })
+                                .iter_mut()
+                                .for_each(|op_grad_el| *op_grad_el += share);
+                        })
+                    })
+            });
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<T: ?Sized> Debug for AvgPoolBackward<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AvgPoolBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("pool_shape", &self.pool_shape)
+            .field("stride", &self.stride)
+            .field("padding", &self.padding)
+            .field("count_include_pad", &self.count_include_pad)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for AvgPoolBackward<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
@@ -0,0 +1,225 @@
+use super::{
+    new_backward_input, new_input, new_tensor, AvgPool, AvgPoolBackward, Backward, Cache, Data,
+    Forward, Gradient, Overwrite, Tensor,
+};
+
+mod forward {
+    use super::{new_input, new_tensor, AvgPool, Cache, Data, Forward, Tensor};
+
+    #[test]
+    fn creation() {
+        let input = new_input((4, 4, 6, 5), vec![0.; 4 * 4 * 6 * 5]);
+        let node = AvgPool::new(input, &[2, 3], &[2, 1], &[1, 1], true);
+
+        assert_eq!(*node.data(), Tensor::zeros((4, 4, 4, 5)));
+        assert_eq!(*node.data_mut(), Tensor::zeros((4, 4, 4, 5)));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic(expected = "Pool shape [2, 6] doesn't fit in input shape [4, 4, 6, 5].")]
+    fn creation_pool_doesnt_fit() {
+        let input = new_input((4, 4, 6, 5), vec![0.; 4 * 4 * 6 * 5]);
+        AvgPool::new(input, &[2, 6], &[2, 1], &[0, 0], true);
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((4, 4, 6, 5), vec![0.; 4 * 4 * 6 * 5]);
+        let node = AvgPool::new(input, &[2, 3], &[2, 1], &[0, 0], true);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((1, 1, 2, 4), vec![1., 2., 3., 4., 5., 6., 7., 8.]);
+        let node = AvgPool::new(input, &[2, 2], &[2, 2], &[0, 0], true);
+
+        node.forward();
+        assert_eq!(*node.data(), new_tensor((1, 1, 1, 2), vec![3.5, 5.5]));
+    }
+
+    #[test]
+    fn forward_count_include_pad() {
+        let input = new_input((1, 1, 2, 2), vec![1., 2., 3., 4.]);
+        let node = AvgPool::new(input, &[2, 2], &[1, 1], &[1, 1], true);
+
+        node.forward();
+        assert_eq!(
+            *node.data(),
+            new_tensor(
+                (1, 1, 3, 3),
+                vec![0.25, 0.75, 0.5, 1., 2.5, 1.5, 0.75, 1.75, 1.]
+            )
+        );
+    }
+
+    #[test]
+    fn forward_count_exclude_pad() {
+        let input = new_input((1, 1, 2, 2), vec![1., 2., 3., 4.]);
+        let node = AvgPool::new(input, &[2, 2], &[1, 1], &[1, 1], false);
+
+        node.forward();
+        assert_eq!(
+            *node.data(),
+            new_tensor((1, 1, 3, 3), vec![1., 1.5, 2., 2., 2.5, 3., 3., 3.5, 4.])
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((2, 2, 2), vec![1., 2., 3., 4., 5., 6., 7., 8.]);
+        let node = AvgPool::new(input, &[2], &[1], &[0], true);
+
+        let output = "AvgPool { data: [[[0.0],\n  [0.0]],\n\n [[0.0],\n  [0.0]]], shape=[2, 2, 1], strides=[2, 1, 1], layout=Cc (0x5), const ndim=3, pool_shape: [2], stride: [1], padding: [0], count_include_pad: true, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((2, 2, 2), vec![1., 2., 3., 4., 5., 6., 7., 8.]);
+        let node = AvgPool::new(input, &[2], &[1], &[0], true);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        new_backward_input, new_tensor, AvgPoolBackward, Backward, Gradient, Overwrite, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = AvgPoolBackward::new(
+            new_backward_input((4, 4, 6, 5), vec![0.; 4 * 4 * 6 * 5]),
+            &[2, 2],
+            &[2, 2],
+            &[0, 0],
+            true,
+        );
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((4, 4, 3, 2), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((4, 4, 3, 2), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((4, 4, 6, 5), vec![0.; 4 * 4 * 6 * 5]);
+        let node = AvgPoolBackward::new(diff.clone(), &[2, 2], &[2, 2], &[0, 0], true);
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward_overlapping() {
+        let diff = new_backward_input((1, 1, 3, 3), vec![0.; 9]);
+        let node = AvgPoolBackward::new(diff.clone(), &[2, 2], &[1, 1], &[0, 0], true);
+        *node.gradient_mut() = new_tensor((1, 1, 2, 2), vec![1.; 4]);
+
+        // Each element gathers a quarter of the gradient of every pool it belongs to.
+        node.backward();
+        assert_eq!(
+            *diff.gradient(),
+            new_tensor(
+                (1, 1, 3, 3),
+                vec![0.25, 0.5, 0.25, 0.5, 1., 0.5, 0.25, 0.5, 0.25]
+            )
+        );
+
+        node.backward();
+        assert_eq!(
+            *diff.gradient(),
+            new_tensor((1, 1, 3, 3), vec![0.5, 1., 0.5, 1., 2., 1., 0.5, 1., 0.5])
+        );
+
+        diff.set_overwrite(true);
+        node.backward();
+        assert_eq!(
+            *diff.gradient(),
+            new_tensor(
+                (1, 1, 3, 3),
+                vec![0.25, 0.5, 0.25, 0.5, 1., 0.5, 0.25, 0.5, 0.25]
+            )
+        );
+    }
+
+    #[test]
+    fn backward_padded() {
+        let diff = new_backward_input((1, 1, 2, 2), vec![0.; 4]);
+        let node = AvgPoolBackward::new(diff.clone(), &[2, 2], &[1, 1], &[1, 1], true);
+        *node.gradient_mut() = new_tensor((1, 1, 3, 3), vec![1.; 9]);
+
+        node.backward();
+        assert_eq!(*diff.gradient(), new_tensor((1, 1, 2, 2), vec![1.; 4]));
+
+        // Without the padding in the divisor the pools on the borders weigh more.
+        let diff = new_backward_input((1, 1, 2, 2), vec![0.; 4]);
+        let node = AvgPoolBackward::new(diff.clone(), &[2, 2], &[1, 1], &[1, 1], false);
+        *node.gradient_mut() = new_tensor((1, 1, 3, 3), vec![1.; 9]);
+
+        node.backward();
+        assert_eq!(*diff.gradient(), new_tensor((1, 1, 2, 2), vec![2.25; 4]));
+    }
+
+    #[test]
+    fn debug() {
+        let diff = new_backward_input((2, 2, 2), vec![1., 2., 3., 4., 5., 6., 7., 8.]);
+        let node = AvgPoolBackward::new(diff, &[2], &[1], &[0], false);
+
+        let output = "AvgPoolBackward { gradient: Some([[[0.0],\n  [0.0]],\n\n [[0.0],\n  [0.0]]], shape=[2, 2, 1], strides=[2, 1, 1], layout=Cc (0x5), const ndim=3), pool_shape: [2], stride: [1], padding: [0], count_include_pad: false, overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let diff = new_backward_input((2, 2, 2), vec![1., 2., 3., 4., 5., 6., 7., 8.]);
+        let node = AvgPoolBackward::new(diff, &[2], &[1], &[0], false);
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        let diff = new_backward_input((2, 2, 2), vec![1., 2., 3., 4., 5., 6., 7., 8.]);
+        let node = AvgPoolBackward::new(diff, &[2], &[1], &[0], false);
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
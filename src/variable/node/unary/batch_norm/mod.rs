@@ -0,0 +1,374 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Eval, Forward, Gradient, Input,
+    Overwrite, Summary, Tensor,
+};
+use ndarray::{Axis, Dimension, Ix1, RemoveAxis, Zip};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+/// Returns the number of elements of each channel of a tensor with shape *(N, C, ...)*.
+fn channel_len<D: RemoveAxis>(tensor: &Tensor<D>) -> usize {
+    tensor.len() / tensor.len_of(Axis(1))
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ BatchNorm ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct BatchNorm<T: ?Sized>
+where
+    T: Data,
+    T::Dim: RemoveAxis,
+{
+    operand: Rc<T>,
+    data: RefCell<Tensor<T::Dim>>,
+    inv_std: RefCell<Tensor<Ix1>>,
+    running_mean: Rc<Input<Ix1>>,
+    running_var: Rc<Input<Ix1>>,
+    momentum: f32,
+    eps: f32,
+    batch_statistics: Cell<bool>,
+    computed: Cell<bool>,
+    train: Rc<Cell<bool>>,
+}
+
+impl<T: ?Sized> BatchNorm<T>
+where
+    T: Data,
+    T::Dim: RemoveAxis,
+{
+    pub fn new(
+        operand: Rc<T>,
+        running_mean: Rc<Input<Ix1>>,
+        running_var: Rc<Input<Ix1>>,
+        momentum: f32,
+        eps: f32,
+        status: Rc<Cell<bool>>,
+    ) -> Self {
+        let shape = operand.data().raw_dim();
+        assert!(
+            shape.ndim() >= 2,
+            "error: batch normalization expects an input of shape (N, C, ...)."
+        );
+        let channels = shape[1];
+        assert!(
+            running_mean.data().len() == channels && running_var.data().len() == channels,
+            "error: the running statistics must have {} elements, one per channel.",
+            channels
+        );
+
+        Self {
+            operand,
+            data: RefCell::new(Tensor::zeros(shape)),
+            inv_std: RefCell::new(Tensor::zeros(channels)),
+            running_mean,
+            running_var,
+            momentum,
+            eps,
+            batch_statistics: Cell::new(true),
+            computed: Cell::new(false),
+            train: status,
+        }
+    }
+
+    pub(crate) fn inv_std(&self) -> Ref<Tensor<Ix1>> {
+        self.inv_std.borrow()
+    }
+
+    /// Returns `true` if the last evaluation normalized the operand with the statistics of the
+    /// batch rather than with the running ones.
+    pub(crate) fn batch_statistics(&self) -> bool {
+        self.batch_statistics.get()
+    }
+}
+
+impl<T: ?Sized> Cache for BatchNorm<T>
+where
+    T: Data,
+    T::Dim: RemoveAxis,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized> Forward for BatchNorm<T>
+where
+    T: Data,
+    T::Dim: RemoveAxis,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let training = self.train.get();
+        self.batch_statistics.set(training);
+
+        let (operand_data, mut data, mut inv_std) = (
+            self.operand.data(),
+            self.data.borrow_mut(),
+            self.inv_std.borrow_mut(),
+        );
+        let (mut running_mean, mut running_var) =
+            (self.running_mean.data_mut(), self.running_var.data_mut());
+        let (momentum, len) = (self.momentum, channel_len(&operand_data));
+
+        for (channel, inv_std_el) in inv_std.iter_mut().enumerate() {
+            let operand_lane = operand_data.index_axis(Axis(1), channel);
+            let (mean, var) = if training {
+                let mean = operand_lane.sum() / len as f32;
+                let var = operand_lane.fold(0., |acc, el| acc + (el - mean).powi(2)) / len as f32;
+                // The running variance is updated with the unbiased estimator.
+                let unbiased_var = if len > 1 {
+                    var * len as f32 / (len - 1) as f32
+                } else {
+                    var
+                };
+                running_mean[channel] = (1. - momentum) * running_mean[channel] + momentum * mean;
+                running_var[channel] =
+                    (1. - momentum) * running_var[channel] + momentum * unbiased_var;
+                (mean, var)
+            } else {
+                (running_mean[channel], running_var[channel])
+            };
+
+            *inv_std_el = 1. / (var + self.eps).sqrt();
+            Zip::from(data.index_axis_mut(Axis(1), channel))
+                .and(&operand_lane)
+                .for_each(|data_el, operand_el| *data_el = (operand_el - mean) * *inv_std_el);
+        }
+    }
+}
+
+impl<T: ?Sized> Data for BatchNorm<T>
+where
+    T: Data,
+    T::Dim: RemoveAxis,
+{
+    type Dim = T::Dim;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> Eval for BatchNorm<T>
+where
+    T: Data,
+    T::Dim: RemoveAxis,
+{
+    fn train(&self) {
+        self.train.set(true);
+    }
+
+    fn eval(&self) {
+        self.train.set(false);
+    }
+}
+
+impl<T: ?Sized> Debug for BatchNorm<T>
+where
+    T: Data,
+    T::Dim: RemoveAxis,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BatchNorm")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("momentum", &self.momentum)
+            .field("eps", &self.eps)
+            .field("train", &self.train.get())
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for BatchNorm<T>
+where
+    T: Data,
+    T::Dim: RemoveAxis,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ BatchNormBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct BatchNormBackward<T: ?Sized, U: ?Sized>
+where
+    T: Gradient,
+    T::Dim: RemoveAxis,
+    U: Data<Dim = T::Dim>,
+{
+    gradient: RefCell<Option<Tensor<T::Dim>>>,
+    shape: T::Dim,
+    overwrite: Cell<bool>,
+    diff_operand: Rc<T>,
+    no_diff_operand: Rc<BatchNorm<U>>,
+}
+
+impl<T: ?Sized, U: ?Sized> BatchNormBackward<T, U>
+where
+    T: Gradient,
+    T::Dim: RemoveAxis,
+    U: Data<Dim = T::Dim>,
+{
+    pub fn new(diff_operand: Rc<T>, no_diff_operand: Rc<BatchNorm<U>>) -> Self {
+        let shape = diff_operand.gradient().raw_dim();
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape.clone()))),
+            shape,
+            overwrite: Cell::new(true),
+            diff_operand,
+            no_diff_operand,
+        }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Gradient for BatchNormBackward<T, U>
+where
+    T: Gradient,
+    T::Dim: RemoveAxis,
+    U: Data<Dim = T::Dim>,
+{
+    type Dim = T::Dim;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Overwrite for BatchNormBackward<T, U>
+where
+    T: Gradient,
+    T::Dim: RemoveAxis,
+    U: Data<Dim = T::Dim>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Backward for BatchNormBackward<T, U>
+where
+    T: Gradient,
+    T::Dim: RemoveAxis,
+    U: Data<Dim = T::Dim>,
+{
+    fn backward(&self) {
+        let (gradient, normalized, inv_std) = (
+            self.gradient(),
+            self.no_diff_operand.data(),
+            self.no_diff_operand.inv_std(),
+        );
+        let mut operand_gradient = self.diff_operand.gradient_mut();
+        let (batch_statistics, len) = (
+            self.no_diff_operand.batch_statistics(),
+            channel_len(&gradient) as f32,
+        );
+        let overwrite = self.diff_operand.can_overwrite();
+
+        for (channel, inv_std_el) in inv_std.iter().enumerate() {
+            let (gradient_lane, normalized_lane) = (
+                gradient.index_axis(Axis(1), channel),
+                normalized.index_axis(Axis(1), channel),
+            );
+            // With the batch statistics the gradient also flows through the mean and the variance.
+            let (gradient_mean, projection) = if batch_statistics {
+                let projection = Zip::from(&gradient_lane)
+                    .and(&normalized_lane)
+                    .fold(0., |acc, grad_el, normalized_el| {
+                        acc + grad_el * normalized_el
+                    });
+                (gradient_lane.sum() / len, projection / len)
+            } else {
+                (0., 0.)
+            };
+
+            let zip = Zip::from(operand_gradient.index_axis_mut(Axis(1), channel))
+                .and(&gradient_lane)
+                .and(&normalized_lane);
+            if overwrite {
+                zip.for_each(|operand_grad_el, grad_el, normalized_el| {
+                    *operand_grad_el =
+                        (grad_el - gradient_mean - normalized_el * projection) * inv_std_el
+                });
+            } else {
+                zip.for_each(|operand_grad_el, grad_el, normalized_el| {
+                    *operand_grad_el +=
+                        (grad_el - gradient_mean - normalized_el * projection) * inv_std_el
+                });
+            }
+        }
+
+        if overwrite {
+            self.diff_operand.set_overwrite(false);
+        }
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Debug for BatchNormBackward<T, U>
+where
+    T: Gradient,
+    T::Dim: RemoveAxis,
+    U: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BatchNormBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Display for BatchNormBackward<T, U>
+where
+    T: Gradient,
+    T::Dim: RemoveAxis,
+    U: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
@@ -0,0 +1,393 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, BatchNorm,
+    BatchNormBackward, Cache, Data, Forward, Gradient, Overwrite, Tensor,
+};
+use std::{cell::Cell, rc::Rc};
+
+mod forward {
+    use super::{
+        assert_almost_equals, new_input, new_tensor, BatchNorm, Cache, Cell, Data, Forward, Rc,
+        Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((3, 2), vec![1., 2., 2., 4., 6., 3.]);
+        let node = BatchNorm::new(
+            input,
+            new_input(2, vec![0.; 2]),
+            new_input(2, vec![1.; 2]),
+            0.1,
+            1e-5,
+            Rc::new(Cell::new(true)),
+        );
+
+        assert_eq!(*node.data(), Tensor::from_elem((3, 2), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((3, 2), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: the running statistics must have 2 elements, one per channel."
+    )]
+    fn creation_wrong_statistics() {
+        let input = new_input((3, 2), vec![1., 2., 2., 4., 6., 3.]);
+        let _ = BatchNorm::new(
+            input,
+            new_input(3, vec![0.; 3]),
+            new_input(3, vec![1.; 3]),
+            0.1,
+            1e-5,
+            Rc::new(Cell::new(true)),
+        );
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((3, 2), vec![1., 2., 2., 4., 6., 3.]);
+        let node = BatchNorm::new(
+            input,
+            new_input(2, vec![0.; 2]),
+            new_input(2, vec![1.; 2]),
+            0.1,
+            1e-5,
+            Rc::new(Cell::new(true)),
+        );
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn forward_train() {
+        let input = new_input((3, 2), vec![1., 2., 2., 4., 6., 3.]);
+        let (running_mean, running_var) = (new_input(2, vec![0.; 2]), new_input(2, vec![1.; 2]));
+        let node = BatchNorm::new(
+            input.clone(),
+            running_mean.clone(),
+            running_var.clone(),
+            0.1,
+            1e-5,
+            Rc::new(Cell::new(true)),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (3, 2),
+                vec![-0.925819, -1.224736, -0.462910, 1.224736, 1.388729, 0.],
+            ),
+        );
+        assert_almost_equals(&*running_mean.data(), &new_tensor(2, vec![0.3, 0.3]));
+        assert_almost_equals(&*running_var.data(), &new_tensor(2, vec![1.6, 1.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data * &Tensor::from_elem(1, 2.);
+        }
+        node.forward();
+        assert_almost_equals(&*running_mean.data(), &new_tensor(2, vec![0.3, 0.3]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        // The normalization is invariant to the scaling, the running statistics are not.
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (3, 2),
+                vec![-0.925819, -1.224736, -0.462910, 1.224736, 1.388729, 0.],
+            ),
+        );
+        assert_almost_equals(&*running_mean.data(), &new_tensor(2, vec![0.87, 0.87]));
+        assert_almost_equals(&*running_var.data(), &new_tensor(2, vec![4.24, 1.3]));
+    }
+
+    #[test]
+    fn forward_eval() {
+        let input = new_input((3, 2), vec![1., 2., 2., 4., 6., 3.]);
+        let (running_mean, running_var) = (new_input(2, vec![1., 2.]), new_input(2, vec![4., 16.]));
+        let node = BatchNorm::new(
+            input,
+            running_mean.clone(),
+            running_var.clone(),
+            0.1,
+            0.,
+            Rc::new(Cell::new(false)),
+        );
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 2), vec![0., 0., 0.5, 0.5, 2.5, 0.25]),
+        );
+        // The running statistics are left untouched.
+        assert_almost_equals(&*running_mean.data(), &new_tensor(2, vec![1., 2.]));
+        assert_almost_equals(&*running_var.data(), &new_tensor(2, vec![4., 16.]));
+    }
+
+    #[test]
+    fn forward_channels() {
+        // Each channel is normalized over the batch and the spatial axes.
+        let input = new_input((2, 2, 1, 2), vec![1., 3., 10., 10., 5., 7., 20., 40.]);
+        let node = BatchNorm::new(
+            input,
+            new_input(2, vec![0.; 2]),
+            new_input(2, vec![1.; 2]),
+            0.1,
+            0.,
+            Rc::new(Cell::new(true)),
+        );
+
+        node.forward();
+        let data = node.data();
+        for channel in data.axis_iter(ndarray::Axis(1)) {
+            let mean = channel.mean().unwrap();
+            let var = channel.mapv(|el| (el - mean).powi(2)).mean().unwrap();
+            assert!(mean.abs() <= 1e-6);
+            assert!((var - 1.).abs() <= 1e-5);
+        }
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((3, 2), vec![1., 2., 2., 4., 6., 3.]);
+        let node = BatchNorm::new(
+            input,
+            new_input(2, vec![0.; 2]),
+            new_input(2, vec![1.; 2]),
+            0.1,
+            1e-5,
+            Rc::new(Cell::new(true)),
+        );
+
+        let output = "BatchNorm { data: [[0.0, 0.0],\n [0.0, 0.0],\n [0.0, 0.0]], shape=[3, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2, momentum: 0.1, eps: 1e-5, train: true, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((3, 2), vec![1., 2., 2., 4., 6., 3.]);
+        let node = BatchNorm::new(
+            input,
+            new_input(2, vec![0.; 2]),
+            new_input(2, vec![1.; 2]),
+            0.1,
+            1e-5,
+            Rc::new(Cell::new(true)),
+        );
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, BatchNorm,
+        BatchNormBackward, Cell, Forward, Gradient, Overwrite, Rc, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = BatchNormBackward::new(
+            new_backward_input((3, 2), vec![0.; 6]),
+            Rc::new(BatchNorm::new(
+                new_input((3, 2), vec![1., 2., 2., 4., 6., 3.]),
+                new_input(2, vec![0.; 2]),
+                new_input(2, vec![1.; 2]),
+                0.1,
+                1e-5,
+                Rc::new(Cell::new(true)),
+            )),
+        );
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((3, 2), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((3, 2), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((3, 2), vec![0.; 6]);
+        let not_diff = Rc::new(BatchNorm::new(
+            new_input((3, 2), vec![1., 2., 2., 4., 6., 3.]),
+            new_input(2, vec![0.; 2]),
+            new_input(2, vec![1.; 2]),
+            0.1,
+            1e-5,
+            Rc::new(Cell::new(true)),
+        ));
+        not_diff.forward();
+        let node = BatchNormBackward::new(diff.clone(), not_diff);
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward_train() {
+        let diff = new_backward_input((3, 2), vec![0.; 6]);
+        let not_diff = Rc::new(BatchNorm::new(
+            new_input((3, 2), vec![1., 2., 2., 4., 6., 3.]),
+            new_input(2, vec![0.; 2]),
+            new_input(2, vec![1.; 2]),
+            0.1,
+            1e-5,
+            Rc::new(Cell::new(true)),
+        ));
+        not_diff.forward();
+        let node = BatchNormBackward::new(diff.clone(), not_diff);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((3, 2), vec![1., -2., 0.5, 3., -1., 1.]);
+        assert_almost_equals(
+            &*node.gradient(),
+            &new_tensor((3, 2), vec![1., -2., 0.5, 3., -1., 1.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        let expected = vec![
+            0.022044, -0.204169, -0.027554, -0.204077, 0.005510, 0.408245,
+        ];
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor((3, 2), expected.clone()));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ 2nd Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 2), expected.iter().map(|el| el * 2.).collect()),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor((3, 2), expected));
+    }
+
+    #[test]
+    fn backward_eval() {
+        let diff = new_backward_input((3, 2), vec![0.; 6]);
+        let status = Rc::new(Cell::new(false));
+        let not_diff = Rc::new(BatchNorm::new(
+            new_input((3, 2), vec![1., 2., 2., 4., 6., 3.]),
+            new_input(2, vec![1., 2.]),
+            new_input(2, vec![4., 16.]),
+            0.1,
+            0.,
+            status.clone(),
+        ));
+        not_diff.forward();
+        let node = BatchNormBackward::new(diff.clone(), not_diff);
+        *node.gradient_mut() = new_tensor((3, 2), vec![1., -2., 0.5, 3., -1., 1.]);
+
+        // The mode of the evaluation is the one of the forward pass.
+        status.set(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 2), vec![0.5, -0.5, 0.25, 0.75, -0.5, 0.25]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let node = BatchNormBackward::new(
+            new_backward_input((3, 2), vec![0.; 6]),
+            Rc::new(BatchNorm::new(
+                new_input((3, 2), vec![1., 2., 2., 4., 6., 3.]),
+                new_input(2, vec![0.; 2]),
+                new_input(2, vec![1.; 2]),
+                0.1,
+                1e-5,
+                Rc::new(Cell::new(true)),
+            )),
+        );
+
+        let output = "BatchNormBackward { gradient: Some([[0.0, 0.0],\n [0.0, 0.0],\n [0.0, 0.0]], shape=[3, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let node = BatchNormBackward::new(
+            new_backward_input((3, 2), vec![0.; 6]),
+            Rc::new(BatchNorm::new(
+                new_input((3, 2), vec![1., 2., 2., 4., 6., 3.]),
+                new_input(2, vec![0.; 2]),
+                new_input(2, vec![1.; 2]),
+                0.1,
+                1e-5,
+                Rc::new(Cell::new(true)),
+            )),
+        );
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // BatchNormBackward
+        let node = BatchNormBackward::new(
+            new_backward_input((3, 2), vec![0.; 6]),
+            Rc::new(BatchNorm::new(
+                new_input((3, 2), vec![1., 2., 2., 4., 6., 3.]),
+                new_input(2, vec![0.; 2]),
+                new_input(2, vec![1.; 2]),
+                0.1,
+                1e-5,
+                Rc::new(Cell::new(true)),
+            )),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
@@ -1,7 +1,8 @@
 #[cfg(test)]
 use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
 use super::{
-    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite, Tensor,
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite, Summary,
+    Tensor,
 };
 use ndarray::Zip;
 use std::{
@@ -102,7 +103,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Chunk")
-            .field("data", &self.data.borrow())
+            .field("data", &Summary(&self.data.borrow()))
             .field("chunk_no", &self.chunk_no)
             .field("computed", &self.computed.get())
             .finish()
@@ -114,7 +115,7 @@ where
     T: Data,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", &self.data.borrow())
+        write!(f, "{}", Summary(&self.data.borrow()))
     }
 }
 
@@ -185,6 +186,12 @@ where
         let (mut diff_operand, grad, chunk_no) =
             (self.operand.gradient_mut(), self.gradient(), self.chunk_no);
 
+        // The other chunks may not be part of the graph, so the whole gradient is cleared.
+        if self.operand.can_overwrite() {
+            diff_operand.fill(0.);
+            self.operand.set_overwrite(false);
+        }
+
         let mut op_gradient_chunk = diff_operand
             .exact_chunks_mut(self.shape.clone())
             .into_iter()
@@ -193,13 +200,9 @@ where
             .next()
             .unwrap();
 
-        let zip = Zip::from(&mut op_gradient_chunk).and(&*grad);
-        if self.operand.can_overwrite() {
-            zip.for_each(|dest, src| *dest = *src);
-            self.operand.set_overwrite(false);
-        } else {
-            zip.for_each(|dest, src| *dest += src);
-        }
+        Zip::from(&mut op_gradient_chunk)
+            .and(&*grad)
+            .for_each(|dest, src| *dest += src);
     }
 
     fn no_grad(&self) {
@@ -217,7 +220,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ChunkBackward")
-            .field("gradient", &self.gradient.borrow())
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
             .field("chunk_no", &self.chunk_no)
             .field("overwrite", &self.overwrite.get())
             .finish()
@@ -230,7 +233,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
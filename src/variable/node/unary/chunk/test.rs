@@ -171,6 +171,20 @@ mod backward {
         );
     }
 
+    #[test]
+    fn backward_overwrite_stale() {
+        let diff = new_backward_input((3, 3), vec![5.; 9]);
+        let node = ChunkBackward::new(diff.clone(), Tensor::zeros((1, 3)), 1);
+        *node.gradient_mut() = new_tensor((1, 3), vec![1.; 3]);
+
+        // The rows of the other chunks must not keep the values of a previous pass.
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 3), vec![0., 0., 0., 1., 1., 1., 0., 0., 0.]),
+        );
+    }
+
     #[test]
     fn no_grad() {
         let node = ChunkBackward::new(
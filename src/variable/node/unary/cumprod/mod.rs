@@ -0,0 +1,282 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite, Summary,
+    Tensor,
+};
+use ndarray::{Axis, Zip};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ CumProd ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct CumProd<T: ?Sized>
+where
+    T: Data,
+{
+    operand: Rc<T>,
+    data: RefCell<Tensor<T::Dim>>,
+    axis: usize,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized> CumProd<T>
+where
+    T: Data,
+{
+    pub fn new(operand: Rc<T>, axis: usize) -> Self {
+        let data = RefCell::new(Tensor::zeros(operand.data().raw_dim()));
+
+        Self {
+            operand,
+            data,
+            axis,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized> Cache for CumProd<T>
+where
+    T: Data,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized> Forward for CumProd<T>
+where
+    T: Data,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let axis = self.axis;
+        Zip::from(self.data.borrow_mut().lanes_mut(Axis(axis)))
+            .and(self.operand.data().lanes(Axis(axis)))
+            .for_each(|lane_v, lane_o| {
+                let mut acc = 1.;
+                Zip::from(lane_v)
+                    .and(lane_o)
+                    .for_each(|lane_v_el, lane_o_el| {
+                        acc *= lane_o_el;
+                        *lane_v_el = acc;
+                    });
+            });
+    }
+}
+
+impl<T: ?Sized> Data for CumProd<T>
+where
+    T: Data,
+{
+    type Dim = T::Dim;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> Debug for CumProd<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CumProd")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("axis", &self.axis)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for CumProd<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ CumProdBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct CumProdBackward<T: ?Sized, U: ?Sized>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    gradient: RefCell<Option<Tensor<T::Dim>>>,
+    shape: T::Dim,
+    overwrite: Cell<bool>,
+    diff_operand: Rc<T>,
+    no_diff_operand: Rc<U>,
+    cumprod: Rc<CumProd<U>>,
+}
+
+impl<T: ?Sized, U: ?Sized> CumProdBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    pub fn new(diff_operand: Rc<T>, no_diff_operand: Rc<U>, cumprod: Rc<CumProd<U>>) -> Self {
+        let shape = diff_operand.gradient().raw_dim();
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape.clone()))),
+            shape,
+            overwrite: Cell::new(true),
+            diff_operand,
+            no_diff_operand,
+            cumprod,
+        }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Gradient for CumProdBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    type Dim = T::Dim;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Overwrite for CumProdBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Backward for CumProdBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    fn backward(&self) {
+        let mut op_grad = self.diff_operand.gradient_mut();
+        let data = self.no_diff_operand.data();
+        let cumprod = self.cumprod.data();
+        let grad = self.gradient();
+        let axis = self.cumprod.axis;
+        let overwrite = self.diff_operand.can_overwrite();
+
+        // The gradient of the i-th element of a lane is the reversed cumulative sum of
+        // `grad * cumprod` divided by the element itself. Such formulation doesn't hold when the
+        // lane contains zeros: the elements following the first zero receive no gradient, while
+        // the gradient of the first zero is computed from the running product that skips it.
+        Zip::from(op_grad.lanes_mut(Axis(axis)))
+            .and(data.lanes(Axis(axis)))
+            .and(cumprod.lanes(Axis(axis)))
+            .and(grad.lanes(Axis(axis)))
+            .for_each(|mut op_grad_lane, data_lane, cumprod_lane, grad_lane| {
+                let len = data_lane.len();
+                let first_zero = data_lane.iter().position(|&el| el == 0.).unwrap_or(len);
+                let mut lane_grad = vec![0.; len];
+
+                let mut acc = 0.;
+                for i in (0..first_zero).rev() {
+                    acc += grad_lane[i] * cumprod_lane[i];
+                    lane_grad[i] = acc / data_lane[i];
+                }
+
+                if first_zero < len {
+                    let mut prod = if first_zero > 0 {
+                        cumprod_lane[first_zero - 1]
+                    } else {
+                        1.
+                    };
+                    let mut acc = grad_lane[first_zero] * prod;
+                    for j in first_zero + 1..len {
+                        prod *= data_lane[j];
+                        acc += grad_lane[j] * prod;
+                    }
+                    lane_grad[first_zero] = acc;
+                }
+
+                let zip = Zip::from(&mut op_grad_lane).and(&lane_grad[..]);
+                if overwrite {
+                    zip.for_each(|op_grad_el, lane_grad_el| *op_grad_el = *lane_grad_el);
+                } else {
+                    zip.for_each(|op_grad_el, lane_grad_el| *op_grad_el += *lane_grad_el);
+                }
+            });
+
+        if overwrite {
+            self.diff_operand.set_overwrite(false);
+        }
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Debug for CumProdBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CumProdBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("axis", &self.cumprod.axis)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Display for CumProdBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
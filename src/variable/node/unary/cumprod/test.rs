@@ -0,0 +1,278 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, CumProd,
+    CumProdBackward, Data, Forward, Gradient, Overwrite, Rc, Tensor,
+};
+
+mod forward {
+    use super::{
+        assert_almost_equals, new_input, new_tensor, Cache, CumProd, Data, Forward, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((2, 3, 2), (1..=12).map(|el| el as f32).collect());
+        let node = CumProd::new(input, 1);
+
+        assert_eq!(*node.data(), Tensor::from_elem((2, 3, 2), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((2, 3, 2), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((2, 3, 2), (1..=12).map(|el| el as f32).collect());
+        let node = CumProd::new(input, 1);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((2, 3, 2), (1..=12).map(|el| el as f32).collect());
+        let node = CumProd::new(input.clone(), 1);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![1., 2., 3., 8., 15., 48., 7., 8., 63., 80., 693., 960.],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data - &Tensor::from_elem(1, 1.);
+        }
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![1., 2., 3., 8., 15., 48., 7., 8., 63., 80., 693., 960.],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![0., 1., 0., 3., 0., 15., 6., 7., 48., 63., 480., 693.],
+            ),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((2, 2), vec![1., 2., 3., 4.]);
+        let node = CumProd::new(input, 1);
+
+        let output = "CumProd { data: [[0.0, 0.0],\n [0.0, 0.0]], shape=[2, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2, axis: 1, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((2, 2), vec![1., 2., 3., 4.]);
+        let node = CumProd::new(input, 1);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, CumProd,
+        CumProdBackward, Data, Forward, Gradient, Overwrite, Rc, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((2, 3, 2), (1..=12).map(|el| el as f32).collect());
+        let node = CumProdBackward::new(
+            new_backward_input((2, 3, 2), vec![0.; 12]),
+            input.clone(),
+            Rc::new(CumProd::new(input, 1)),
+        );
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((2, 3, 2), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((2, 3, 2), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let input = new_input((2, 3, 2), (1..=12).map(|el| el as f32).collect());
+        let diff = new_backward_input((2, 3, 2), vec![0.; 12]);
+        let node =
+            CumProdBackward::new(diff.clone(), input.clone(), Rc::new(CumProd::new(input, 1)));
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        let input = new_input((2, 3, 2), (1..=12).map(|el| el as f32).collect());
+        let diff = new_backward_input((2, 3, 2), vec![0.; 12]);
+        let cumprod = Rc::new(CumProd::new(input.clone(), 1));
+        cumprod.forward();
+        let node = CumProdBackward::new(diff.clone(), input, cumprod);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((2, 3, 2), vec![1.; 12]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((2, 3, 2), vec![1.; 12]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![19., 29., 6., 14., 3., 8., 109., 131., 84., 104., 63., 80.],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![
+                    38., 58., 12., 28., 6., 16., 218., 262., 168., 208., 126., 160.,
+                ],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![19., 29., 6., 14., 3., 8., 109., 131., 84., 104., 63., 80.],
+            ),
+        );
+    }
+
+    #[test]
+    fn backward_zeros() {
+        // The zeros lie in the middle of two of the lanes.
+        let input = new_input(
+            (2, 3, 2),
+            vec![1., 2., 0., 4., 5., 6., 7., 8., 9., 0., 11., 12.],
+        );
+        let diff = new_backward_input((2, 3, 2), vec![0.; 12]);
+        let cumprod = Rc::new(CumProd::new(input.clone(), 1));
+        cumprod.forward();
+        assert_almost_equals(
+            &*cumprod.data(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![1., 2., 0., 8., 0., 48., 7., 8., 63., 0., 693., 0.],
+            ),
+        );
+        let node = CumProdBackward::new(diff.clone(), input, cumprod);
+
+        *node.gradient_mut() = new_tensor((2, 3, 2), vec![1.; 12]);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![1., 29., 6., 14., 0., 8., 109., 1., 84., 104., 63., 0.],
+            ),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((2, 2), vec![1., 2., 3., 4.]);
+        let node = CumProdBackward::new(
+            new_backward_input((2, 2), vec![0.; 4]),
+            input.clone(),
+            Rc::new(CumProd::new(input, 1)),
+        );
+
+        let output = "CumProdBackward { gradient: Some([[0.0, 0.0],\n [0.0, 0.0]], shape=[2, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2), axis: 1, overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((2, 2), vec![1., 2., 3., 4.]);
+        let node = CumProdBackward::new(
+            new_backward_input((2, 2), vec![0.; 4]),
+            input.clone(),
+            Rc::new(CumProd::new(input, 1)),
+        );
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // CumProdBackward
+        let input = new_input((2, 2), vec![1., 2., 3., 4.]);
+        let node = CumProdBackward::new(
+            new_backward_input((2, 2), vec![0.; 4]),
+            input.clone(),
+            Rc::new(CumProd::new(input, 1)),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
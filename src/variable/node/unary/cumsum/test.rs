@@ -0,0 +1,224 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, CumSum,
+    CumSumBackward, Data, Forward, Gradient, Overwrite, Tensor,
+};
+
+mod forward {
+    use super::{
+        assert_almost_equals, new_input, new_tensor, Cache, CumSum, Data, Forward, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((2, 3, 2), (1..=12).map(|el| el as f32).collect());
+        let node = CumSum::new(input, 1);
+
+        assert_eq!(*node.data(), Tensor::from_elem((2, 3, 2), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((2, 3, 2), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((2, 3, 2), (1..=12).map(|el| el as f32).collect());
+        let node = CumSum::new(input, 1);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((2, 3, 2), (1..=12).map(|el| el as f32).collect());
+        let node = CumSum::new(input.clone(), 1);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![1., 2., 4., 6., 9., 12., 7., 8., 16., 18., 27., 30.],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![1., 2., 4., 6., 9., 12., 7., 8., 16., 18., 27., 30.],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![2., 3., 6., 8., 12., 15., 8., 9., 18., 20., 30., 33.],
+            ),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((2, 2), vec![1., 2., 3., 4.]);
+        let node = CumSum::new(input, 1);
+
+        let output = "CumSum { data: [[0.0, 0.0],\n [0.0, 0.0]], shape=[2, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2, axis: 1, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((2, 2), vec![1., 2., 3., 4.]);
+        let node = CumSum::new(input, 1);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_tensor, Backward, CumSumBackward, Gradient,
+        Overwrite, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = CumSumBackward::new(new_backward_input((2, 3, 2), vec![0.; 12]), 1);
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((2, 3, 2), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((2, 3, 2), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((2, 3, 2), vec![0.; 12]);
+        let node = CumSumBackward::new(diff.clone(), 1);
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        let diff = new_backward_input((2, 3, 2), vec![0.; 12]);
+        let node = CumSumBackward::new(diff.clone(), 1);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((2, 3, 2), (1..=12).map(|el| el as f32).collect());
+        assert_almost_equals(
+            &*node.gradient(),
+            &new_tensor((2, 3, 2), (1..=12).map(|el| el as f32).collect()),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![9., 12., 8., 10., 5., 6., 27., 30., 20., 22., 11., 12.],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![18., 24., 16., 20., 10., 12., 54., 60., 40., 44., 22., 24.],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![9., 12., 8., 10., 5., 6., 27., 30., 20., 22., 11., 12.],
+            ),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let node = CumSumBackward::new(new_backward_input((2, 2), vec![0.; 4]), 1);
+
+        let output = "CumSumBackward { gradient: Some([[0.0, 0.0],\n [0.0, 0.0]], shape=[2, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2), axis: 1, overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let node = CumSumBackward::new(new_backward_input((2, 2), vec![0.; 4]), 1);
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // CumSumBackward
+        let node = CumSumBackward::new(new_backward_input((2, 2), vec![0.; 4]), 1);
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
@@ -0,0 +1,230 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    diagonal, expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient,
+    Overwrite, Summary, Tensor,
+};
+use ndarray::{Ix1, Ix2};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+/// Returns the shape of the square matrix having a vector of length `len` as its diagonal with
+/// the given `offset`.
+fn embedding_shape(len: usize, offset: isize) -> Ix2 {
+    let side = len + offset.unsigned_abs();
+
+    Ix2(side, side)
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ DiagEmbed ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct DiagEmbed<T: ?Sized>
+where
+    T: Data<Dim = Ix1>,
+{
+    operand: Rc<T>,
+    data: RefCell<Tensor<Ix2>>,
+    offset: isize,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized> DiagEmbed<T>
+where
+    T: Data<Dim = Ix1>,
+{
+    pub fn new(operand: Rc<T>, offset: isize) -> Self {
+        let data = RefCell::new(Tensor::zeros(embedding_shape(operand.data().len(), offset)));
+
+        Self {
+            operand,
+            data,
+            offset,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized> Cache for DiagEmbed<T>
+where
+    T: Data<Dim = Ix1>,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized> Forward for DiagEmbed<T>
+where
+    T: Data<Dim = Ix1>,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        // The elements outside of the diagonal are never written, so they stay zero.
+        diagonal(self.data.borrow_mut().view_mut(), self.offset).assign(&*self.operand.data());
+    }
+}
+
+impl<T: ?Sized> Data for DiagEmbed<T>
+where
+    T: Data<Dim = Ix1>,
+{
+    type Dim = Ix2;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> Debug for DiagEmbed<T>
+where
+    T: Data<Dim = Ix1>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DiagEmbed")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("offset", &self.offset)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for DiagEmbed<T>
+where
+    T: Data<Dim = Ix1>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ DiagEmbedBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct DiagEmbedBackward<T: ?Sized>
+where
+    T: Gradient<Dim = Ix1>,
+{
+    gradient: RefCell<Option<Tensor<Ix2>>>,
+    shape: Ix2,
+    overwrite: Cell<bool>,
+    operand: Rc<T>,
+    offset: isize,
+}
+
+impl<T: ?Sized> DiagEmbedBackward<T>
+where
+    T: Gradient<Dim = Ix1>,
+{
+    pub fn new(operand: Rc<T>, offset: isize) -> Self {
+        let shape = embedding_shape(operand.gradient().len(), offset);
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape))),
+            shape,
+            overwrite: Cell::new(true),
+            operand,
+            offset,
+        }
+    }
+}
+
+impl<T: ?Sized> Gradient for DiagEmbedBackward<T>
+where
+    T: Gradient<Dim = Ix1>,
+{
+    type Dim = Ix2;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized> Overwrite for DiagEmbedBackward<T>
+where
+    T: Gradient<Dim = Ix1>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized> Backward for DiagEmbedBackward<T>
+where
+    T: Gradient<Dim = Ix1>,
+{
+    fn backward(&self) {
+        let mut op_grad = self.operand.gradient_mut();
+        let grad = self.gradient();
+        let grad_diag = diagonal(grad.view(), self.offset);
+
+        if self.operand.can_overwrite() {
+            op_grad.assign(&grad_diag);
+            self.operand.set_overwrite(false);
+        } else {
+            *op_grad += &grad_diag;
+        }
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape));
+    }
+}
+
+impl<T: ?Sized> Debug for DiagEmbedBackward<T>
+where
+    T: Gradient<Dim = Ix1>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DiagEmbedBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("offset", &self.offset)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for DiagEmbedBackward<T>
+where
+    T: Gradient<Dim = Ix1>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
@@ -0,0 +1,234 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    DiagEmbed, DiagEmbedBackward, Forward, Gradient, Overwrite, Tensor,
+};
+
+mod forward {
+    use super::{
+        assert_almost_equals, new_input, new_tensor, Cache, Data, DiagEmbed, Forward, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input(3, vec![1., 2., 3.]);
+        let node = DiagEmbed::new(input, 0);
+
+        assert_eq!(*node.data(), Tensor::from_elem((3, 3), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((3, 3), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input(3, vec![1., 2., 3.]);
+        let node = DiagEmbed::new(input, 0);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input(3, vec![1., 2., 3.]);
+        let node = DiagEmbed::new(input.clone(), 0);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 3), vec![1., 0., 0., 0., 2., 0., 0., 0., 3.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(&*input.data(), &new_tensor(3, vec![2., 3., 4.]));
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 3), vec![1., 0., 0., 0., 2., 0., 0., 0., 3.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 3), vec![2., 0., 0., 0., 3., 0., 0., 0., 4.]),
+        );
+    }
+
+    #[test]
+    fn forward_offset() {
+        let input = new_input(2, vec![1., 2.]);
+
+        let expected = [
+            (1, vec![0., 1., 0., 0., 0., 2., 0., 0., 0.]),
+            (-1, vec![0., 0., 0., 1., 0., 0., 0., 2., 0.]),
+            (
+                2,
+                vec![
+                    0., 0., 1., 0., 0., 0., 0., 2., 0., 0., 0., 0., 0., 0., 0., 0.,
+                ],
+            ),
+        ];
+        for (offset, expected) in expected {
+            let node = DiagEmbed::new(input.clone(), offset);
+            node.forward();
+
+            let side = 2 + offset.unsigned_abs();
+            assert_eq!(*node.data(), new_tensor((side, side), expected));
+        }
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input(2, vec![1., 2.]);
+        let node = DiagEmbed::new(input, 1);
+
+        let output = "DiagEmbed { data: [[0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0]], shape=[3, 3], strides=[3, 1], layout=Cc (0x5), const ndim=2, offset: 1, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input(2, vec![1., 2.]);
+        let node = DiagEmbed::new(input, 0);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_tensor, Backward, DiagEmbedBackward,
+        Gradient, Overwrite, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = DiagEmbedBackward::new(new_backward_input(3, vec![0.; 3]), -1);
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((4, 4), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((4, 4), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input(3, vec![0.; 3]);
+        let node = DiagEmbedBackward::new(diff.clone(), 0);
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        let diff = new_backward_input(3, vec![0.; 3]);
+        let node = DiagEmbedBackward::new(diff.clone(), 0);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((3, 3), (1..=9).map(|el| el as f32).collect());
+        assert_almost_equals(
+            &*node.gradient(),
+            &new_tensor((3, 3), (1..=9).map(|el| el as f32).collect()),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor(3, vec![1., 5., 9.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor(3, vec![2., 10., 18.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor(3, vec![1., 5., 9.]));
+    }
+
+    #[test]
+    fn backward_offset() {
+        let expected = [(1, vec![2., 6.]), (-1, vec![4., 8.])];
+        for (offset, expected) in expected {
+            let diff = new_backward_input(2, vec![0.; 2]);
+            let node = DiagEmbedBackward::new(diff.clone(), offset);
+            *node.gradient_mut() = new_tensor((3, 3), (1..=9).map(|el| el as f32).collect());
+            node.backward();
+
+            assert_almost_equals(&*diff.gradient(), &new_tensor(2, expected));
+        }
+    }
+
+    #[test]
+    fn debug() {
+        let node = DiagEmbedBackward::new(new_backward_input(2, vec![0.; 2]), 1);
+
+        let output = "DiagEmbedBackward { gradient: Some([[0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0]], shape=[3, 3], strides=[3, 1], layout=Cc (0x5), const ndim=2), offset: 1, overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let node = DiagEmbedBackward::new(new_backward_input(2, vec![0.; 2]), 0);
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // DiagEmbedBackward
+        let node = DiagEmbedBackward::new(new_backward_input(2, vec![0.; 2]), 0);
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
@@ -0,0 +1,241 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite, Summary,
+    Tensor,
+};
+use ndarray::{ArrayBase, Axis, Ix1, Ix2, RawData, Slice};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+/// Returns the diagonal of `array` with the given `offset`. A positive offset selects a diagonal
+/// above the main one, a negative offset one below it. Out of range offsets give an empty diagonal.
+pub(crate) fn diagonal<S: RawData>(
+    mut array: ArrayBase<S, Ix2>,
+    offset: isize,
+) -> ArrayBase<S, Ix1> {
+    let (axis, start) = if offset >= 0 {
+        (Axis(1), offset as usize)
+    } else {
+        (Axis(0), offset.unsigned_abs())
+    };
+    let start = start.min(array.len_of(axis));
+    array.slice_axis_inplace(axis, Slice::from(start..));
+
+    array.into_diag()
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Diagonal ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct Diagonal<T: ?Sized>
+where
+    T: Data<Dim = Ix2>,
+{
+    operand: Rc<T>,
+    data: RefCell<Tensor<Ix1>>,
+    offset: isize,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized> Diagonal<T>
+where
+    T: Data<Dim = Ix2>,
+{
+    pub fn new(operand: Rc<T>, offset: isize) -> Self {
+        let data = RefCell::new(Tensor::zeros(
+            diagonal(operand.data().view(), offset).raw_dim(),
+        ));
+
+        Self {
+            operand,
+            data,
+            offset,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized> Cache for Diagonal<T>
+where
+    T: Data<Dim = Ix2>,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized> Forward for Diagonal<T>
+where
+    T: Data<Dim = Ix2>,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        self.data
+            .borrow_mut()
+            .assign(&diagonal(self.operand.data().view(), self.offset));
+    }
+}
+
+impl<T: ?Sized> Data for Diagonal<T>
+where
+    T: Data<Dim = Ix2>,
+{
+    type Dim = Ix1;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> Debug for Diagonal<T>
+where
+    T: Data<Dim = Ix2>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Diagonal")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("offset", &self.offset)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for Diagonal<T>
+where
+    T: Data<Dim = Ix2>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ DiagonalBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct DiagonalBackward<T: ?Sized>
+where
+    T: Gradient<Dim = Ix2>,
+{
+    gradient: RefCell<Option<Tensor<Ix1>>>,
+    shape: Ix1,
+    overwrite: Cell<bool>,
+    operand: Rc<T>,
+    offset: isize,
+}
+
+impl<T: ?Sized> DiagonalBackward<T>
+where
+    T: Gradient<Dim = Ix2>,
+{
+    pub fn new(operand: Rc<T>, offset: isize) -> Self {
+        let shape = diagonal(operand.gradient().view(), offset).raw_dim();
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape))),
+            shape,
+            overwrite: Cell::new(true),
+            operand,
+            offset,
+        }
+    }
+}
+
+impl<T: ?Sized> Gradient for DiagonalBackward<T>
+where
+    T: Gradient<Dim = Ix2>,
+{
+    type Dim = Ix1;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized> Overwrite for DiagonalBackward<T>
+where
+    T: Gradient<Dim = Ix2>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized> Backward for DiagonalBackward<T>
+where
+    T: Gradient<Dim = Ix2>,
+{
+    fn backward(&self) {
+        let mut op_grad = self.operand.gradient_mut();
+        let grad = self.gradient();
+
+        if self.operand.can_overwrite() {
+            op_grad.fill(0.);
+            self.operand.set_overwrite(false);
+        }
+        let mut op_grad_diag = diagonal(op_grad.view_mut(), self.offset);
+        op_grad_diag += &*grad;
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape));
+    }
+}
+
+impl<T: ?Sized> Debug for DiagonalBackward<T>
+where
+    T: Gradient<Dim = Ix2>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DiagonalBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("offset", &self.offset)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for DiagonalBackward<T>
+where
+    T: Gradient<Dim = Ix2>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
@@ -0,0 +1,244 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Diagonal, DiagonalBackward, Forward, Gradient, Overwrite, Tensor,
+};
+
+mod forward {
+    use super::{
+        assert_almost_equals, new_input, new_tensor, Cache, Data, Diagonal, Forward, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((3, 4), (1..=12).map(|el| el as f32).collect());
+        let node = Diagonal::new(input, 0);
+
+        assert_eq!(*node.data(), Tensor::from_elem(3, 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem(3, 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((3, 4), (1..=12).map(|el| el as f32).collect());
+        let node = Diagonal::new(input, 0);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((3, 4), (1..=12).map(|el| el as f32).collect());
+        let node = Diagonal::new(input.clone(), 0);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor(3, vec![1., 6., 11.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(
+            &*input.data(),
+            &new_tensor((3, 4), (2..=13).map(|el| el as f32).collect()),
+        );
+
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor(3, vec![1., 6., 11.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor(3, vec![2., 7., 12.]));
+    }
+
+    #[test]
+    fn forward_tall() {
+        let input = new_input((4, 3), (1..=12).map(|el| el as f32).collect());
+        let node = Diagonal::new(input, 0);
+
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor(3, vec![1., 5., 9.]));
+    }
+
+    #[test]
+    fn forward_offset() {
+        let input = new_input((3, 4), (1..=12).map(|el| el as f32).collect());
+
+        let expected = [
+            (1, vec![2., 7., 12.]),
+            (2, vec![3., 8.]),
+            (3, vec![4.]),
+            (4, vec![]),
+            (-1, vec![5., 10.]),
+            (-2, vec![9.]),
+            (-3, vec![]),
+        ];
+        for (offset, expected) in expected {
+            let node = Diagonal::new(input.clone(), offset);
+            node.forward();
+            assert_eq!(*node.data(), new_tensor(expected.len(), expected));
+        }
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((3, 4), (1..=12).map(|el| el as f32).collect());
+        let node = Diagonal::new(input, 1);
+
+        let output = "Diagonal { data: [0.0, 0.0, 0.0], shape=[3], strides=[1], layout=CFcf (0xf), const ndim=1, offset: 1, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((3, 4), (1..=12).map(|el| el as f32).collect());
+        let node = Diagonal::new(input, 0);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_tensor, Backward, DiagonalBackward, Gradient,
+        Overwrite, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = DiagonalBackward::new(new_backward_input((3, 4), vec![0.; 12]), 0);
+
+        assert_eq!(*node.gradient(), Tensor::from_elem(3, 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem(3, 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((3, 4), vec![0.; 12]);
+        let node = DiagonalBackward::new(diff.clone(), 0);
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        let diff = new_backward_input((3, 4), vec![0.; 12]);
+        let node = DiagonalBackward::new(diff.clone(), 0);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor(3, vec![1., 2., 3.]);
+        assert_almost_equals(&*node.gradient(), &new_tensor(3, vec![1., 2., 3.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 4), vec![1., 0., 0., 0., 0., 2., 0., 0., 0., 0., 3., 0.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 4), vec![2., 0., 0., 0., 0., 4., 0., 0., 0., 0., 6., 0.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 4), vec![1., 0., 0., 0., 0., 2., 0., 0., 0., 0., 3., 0.]),
+        );
+    }
+
+    #[test]
+    fn backward_offset() {
+        let expected = [
+            (1, vec![0., 1., 0., 0., 0., 0., 1., 0., 0., 0., 0., 1.]),
+            (-1, vec![0., 0., 0., 0., 1., 0., 0., 0., 0., 1., 0., 0.]),
+        ];
+        for (offset, expected) in expected {
+            // The entries off the diagonal are cleared when the gradient is overwritten.
+            let diff = new_backward_input((3, 4), vec![5.; 12]);
+            let node = DiagonalBackward::new(diff.clone(), offset);
+            node.gradient_mut().fill(1.);
+            node.backward();
+
+            assert_almost_equals(&*diff.gradient(), &new_tensor((3, 4), expected));
+        }
+    }
+
+    #[test]
+    fn debug() {
+        let node = DiagonalBackward::new(new_backward_input((3, 4), vec![0.; 12]), 1);
+
+        let output = "DiagonalBackward { gradient: Some([0.0, 0.0, 0.0], shape=[3], strides=[1], layout=CFcf (0xf), const ndim=1), offset: 1, overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let node = DiagonalBackward::new(new_backward_input((3, 4), vec![0.; 12]), 0);
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // DiagonalBackward
+        let node = DiagonalBackward::new(new_backward_input((3, 4), vec![0.; 12]), 0);
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
@@ -0,0 +1,249 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite, Summary,
+    Tensor,
+};
+use ndarray::{Array2, Axis, Ix2, Ix3, Zip};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Embedding ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct Embedding<T: ?Sized>
+where
+    T: Data<Dim = Ix2>,
+{
+    operand: Rc<T>,
+    data: RefCell<Tensor<Ix3>>,
+    indices: Array2<usize>,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized> Embedding<T>
+where
+    T: Data<Dim = Ix2>,
+{
+    pub fn new(operand: Rc<T>, indices: Array2<usize>) -> Self {
+        let (rows, columns) = operand.data().dim();
+        check_indices(rows, &indices);
+        let (batch, sequence) = indices.dim();
+
+        Self {
+            operand,
+            data: RefCell::new(Tensor::zeros((batch, sequence, columns))),
+            indices,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized> Cache for Embedding<T>
+where
+    T: Data<Dim = Ix2>,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized> Forward for Embedding<T>
+where
+    T: Data<Dim = Ix2>,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let (mut data, operand_data) = (self.data.borrow_mut(), self.operand.data());
+        Zip::from(data.lanes_mut(Axis(2)))
+            .and(&self.indices)
+            .for_each(|mut row, index| row.assign(&operand_data.row(*index)));
+    }
+}
+
+impl<T: ?Sized> Data for Embedding<T>
+where
+    T: Data<Dim = Ix2>,
+{
+    type Dim = Ix3;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> Debug for Embedding<T>
+where
+    T: Data<Dim = Ix2>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Embedding")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("indices", &self.indices)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for Embedding<T>
+where
+    T: Data<Dim = Ix2>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ EmbeddingBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct EmbeddingBackward<T: ?Sized>
+where
+    T: Gradient<Dim = Ix2>,
+{
+    gradient: RefCell<Option<Tensor<Ix3>>>,
+    shape: Ix3,
+    overwrite: Cell<bool>,
+    operand: Rc<T>,
+    indices: Array2<usize>,
+    padding_idx: Option<usize>,
+}
+
+impl<T: ?Sized> EmbeddingBackward<T>
+where
+    T: Gradient<Dim = Ix2>,
+{
+    pub fn new(operand: Rc<T>, indices: Array2<usize>, padding_idx: Option<usize>) -> Self {
+        let (rows, columns) = operand.gradient().dim();
+        check_indices(rows, &indices);
+        let (batch, sequence) = indices.dim();
+        let shape = Ix3(batch, sequence, columns);
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape))),
+            shape,
+            overwrite: Cell::new(true),
+            operand,
+            indices,
+            padding_idx,
+        }
+    }
+}
+
+impl<T: ?Sized> Gradient for EmbeddingBackward<T>
+where
+    T: Gradient<Dim = Ix2>,
+{
+    type Dim = Ix3;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized> Overwrite for EmbeddingBackward<T>
+where
+    T: Gradient<Dim = Ix2>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized> Backward for EmbeddingBackward<T>
+where
+    T: Gradient<Dim = Ix2>,
+{
+    fn backward(&self) {
+        let (mut operand_gradient, gradient) = (self.operand.gradient_mut(), self.gradient());
+
+        // The gradient of the rows that weren't looked up is zero.
+        if self.operand.can_overwrite() {
+            operand_gradient.fill(0.);
+            self.operand.set_overwrite(false);
+        }
+
+        // Repeated indices accumulate their gradients, the padding row never receives any.
+        Zip::from(gradient.lanes(Axis(2)))
+            .and(&self.indices)
+            .for_each(|row, index| {
+                if self.padding_idx != Some(*index) {
+                    let mut operand_row = operand_gradient.row_mut(*index);
+                    operand_row += &row;
+                }
+            });
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape));
+    }
+}
+
+impl<T: ?Sized> Debug for EmbeddingBackward<T>
+where
+    T: Gradient<Dim = Ix2>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EmbeddingBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("indices", &self.indices)
+            .field("padding_idx", &self.padding_idx)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for EmbeddingBackward<T>
+where
+    T: Gradient<Dim = Ix2>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+/// Checks that every one of `indices` addresses one of the `rows` rows of the embedding table.
+fn check_indices(rows: usize, indices: &Array2<usize>) {
+    if let Some(index) = indices.iter().find(|index| **index >= rows) {
+        panic!(
+            "error: index {} is out of bounds for an embedding table with {} rows.",
+            index, rows
+        );
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
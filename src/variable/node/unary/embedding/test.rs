@@ -0,0 +1,257 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Embedding, EmbeddingBackward, Forward, Gradient, Overwrite, Tensor,
+};
+
+mod forward {
+    use super::{
+        assert_almost_equals, new_input, new_tensor, Cache, Data, Embedding, Forward, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Embedding::new(input, ndarray::array![[0, 2], [1, 2]]);
+
+        assert_eq!(*node.data(), Tensor::from_elem((2, 2, 2), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((2, 2, 2), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Embedding::new(input, ndarray::array![[0, 2], [1, 2]]);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: index 3 is out of bounds for an embedding table with 3 rows."
+    )]
+    fn fail() {
+        Embedding::new(
+            new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+            ndarray::array![[0, 3]],
+        );
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Embedding::new(input.clone(), ndarray::array![[0, 2], [1, 2]]);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((2, 2, 2), vec![1., 2., 5., 6., 3., 4., 5., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(
+            &*input.data(),
+            &new_tensor((3, 2), vec![2., 3., 4., 5., 6., 7.]),
+        );
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((2, 2, 2), vec![1., 2., 5., 6., 3., 4., 5., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((2, 2, 2), vec![2., 3., 6., 7., 4., 5., 6., 7.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Embedding::new(input, ndarray::array![[0, 2]]);
+
+        let output = "Embedding { data: [[[0.0, 0.0],\n  [0.0, 0.0]]], shape=[1, 2, 2], strides=[4, 2, 1], layout=Cc (0x5), const ndim=3, indices: [[0, 2]], shape=[1, 2], strides=[2, 1], layout=CFcf (0xf), const ndim=2, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Embedding::new(input, ndarray::array![[0, 2]]);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_tensor, Backward, EmbeddingBackward,
+        Gradient, Overwrite, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = EmbeddingBackward::new(
+            new_backward_input((3, 2), vec![0.; 6]),
+            ndarray::array![[0, 2], [1, 2]],
+            None,
+        );
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((2, 2, 2), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((2, 2, 2), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: index 5 is out of bounds for an embedding table with 3 rows."
+    )]
+    fn fail() {
+        EmbeddingBackward::new(
+            new_backward_input((3, 2), vec![0.; 6]),
+            ndarray::array![[5]],
+            None,
+        );
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((3, 2), vec![0.; 6]);
+        let node = EmbeddingBackward::new(diff.clone(), ndarray::array![[0, 2], [1, 2]], None);
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        // The stale gradient of the operand must be discarded on overwrite.
+        let diff = new_backward_input((3, 2), vec![5.; 6]);
+        let node = EmbeddingBackward::new(diff.clone(), ndarray::array![[0, 2], [2, 2]], None);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((2, 2, 2), vec![1., 2., 3., 4., 5., 6., 7., 8.]);
+        assert_almost_equals(
+            &*node.gradient(),
+            &new_tensor((2, 2, 2), vec![1., 2., 3., 4., 5., 6., 7., 8.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 2), vec![1., 2., 0., 0., 15., 18.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 2), vec![2., 4., 0., 0., 30., 36.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 2), vec![1., 2., 0., 0., 15., 18.]),
+        );
+    }
+
+    #[test]
+    fn backward_padding_idx() {
+        let diff = new_backward_input((3, 2), vec![0.; 6]);
+        let node = EmbeddingBackward::new(diff.clone(), ndarray::array![[0, 2], [2, 1]], Some(2));
+
+        *node.gradient_mut() = new_tensor((2, 2, 2), vec![1.; 8]);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 2), vec![1., 1., 1., 1., 0., 0.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let diff = new_backward_input((3, 2), vec![0.; 6]);
+        let node = EmbeddingBackward::new(diff, ndarray::array![[0, 2]], Some(1));
+
+        let output = "EmbeddingBackward { gradient: Some([[[0.0, 0.0],\n  [0.0, 0.0]]], shape=[1, 2, 2], strides=[4, 2, 1], layout=Cc (0x5), const ndim=3), indices: [[0, 2]], shape=[1, 2], strides=[2, 1], layout=CFcf (0xf), const ndim=2, padding_idx: Some(1), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let diff = new_backward_input((3, 2), vec![0.; 6]);
+        let node = EmbeddingBackward::new(diff, ndarray::array![[0, 2]], Some(1));
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // EmbeddingBackward
+        let node = EmbeddingBackward::new(
+            new_backward_input((3, 2), vec![0.; 6]),
+            ndarray::array![[0, 2], [1, 2]],
+            None,
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
@@ -0,0 +1,234 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data, Flip,
+    FlipBackward, Forward, Gradient, Overwrite, Tensor,
+};
+
+mod forward {
+    use super::{assert_almost_equals, new_input, new_tensor, Cache, Data, Flip, Forward, Tensor};
+
+    #[test]
+    fn creation() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Flip::new(input, &[1]);
+
+        assert_eq!(*node.data(), Tensor::from_elem((2, 3), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((2, 3), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Flip::new(input, &[1]);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic(expected = "error: axis 2 is out of bounds for a variable with 2 dimensions.")]
+    fn fail() {
+        Flip::new(new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]), &[0, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "error: axis 1 is repeated in [1, 0, 1].")]
+    fn fail_repeated() {
+        Flip::new(new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]), &[1, 0, 1]);
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Flip::new(input.clone(), &[1]);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((2, 3), vec![3., 2., 1., 6., 5., 4.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(
+            &*input.data(),
+            &new_tensor((2, 3), vec![2., 3., 4., 5., 6., 7.]),
+        );
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((2, 3), vec![3., 2., 1., 6., 5., 4.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((2, 3), vec![4., 3., 2., 7., 6., 5.]),
+        );
+    }
+
+    #[test]
+    fn forward_all_axes() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Flip::new(input, &[0, 1]);
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((2, 3), vec![6., 5., 4., 3., 2., 1.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Flip::new(input, &[1]);
+
+        let output = "Flip { data: [[0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0]], shape=[2, 3], strides=[3, 1], layout=Cc (0x5), const ndim=2, axes: [1], computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Flip::new(input, &[1]);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_tensor, Backward, FlipBackward, Gradient,
+        Overwrite, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = FlipBackward::new(new_backward_input((2, 3), vec![0.; 6]), &[1]);
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((2, 3), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((2, 3), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = FlipBackward::new(diff.clone(), &[1]);
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = FlipBackward::new(diff.clone(), &[1]);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        assert_almost_equals(
+            &*node.gradient(),
+            &new_tensor((2, 3), vec![1., 2., 3., 4., 5., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![3., 2., 1., 6., 5., 4.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![6., 4., 2., 12., 10., 8.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![3., 2., 1., 6., 5., 4.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = FlipBackward::new(diff, &[1]);
+
+        let output = "FlipBackward { gradient: Some([[0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0]], shape=[2, 3], strides=[3, 1], layout=Cc (0x5), const ndim=2), axes: [1], overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = FlipBackward::new(diff, &[1]);
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // FlipBackward
+        let node = FlipBackward::new(new_backward_input((2, 3), vec![0.; 6]), &[1]);
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
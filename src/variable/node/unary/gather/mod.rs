@@ -0,0 +1,266 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite, Summary,
+    Tensor,
+};
+use ndarray::{Array, Dimension, IntoDimension};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Gather ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct Gather<T: ?Sized>
+where
+    T: Data,
+{
+    operand: Rc<T>,
+    data: RefCell<Tensor<T::Dim>>,
+    axis: usize,
+    index: Array<usize, T::Dim>,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized> Gather<T>
+where
+    T: Data,
+{
+    pub fn new(operand: Rc<T>, axis: usize, index: Array<usize, T::Dim>) -> Self {
+        check_index(operand.data().shape(), axis, &index);
+        let data = Tensor::zeros(index.raw_dim());
+
+        Self {
+            operand,
+            data: RefCell::new(data),
+            axis,
+            index,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized> Cache for Gather<T>
+where
+    T: Data,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized> Forward for Gather<T>
+where
+    T: Data,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let operand_data = self.operand.data();
+        let mut data = self.data.borrow_mut();
+        for (data_el, (position, index)) in data.iter_mut().zip(self.index.indexed_iter()) {
+            let mut position = position.into_dimension();
+            position[self.axis] = *index;
+            *data_el = operand_data[position];
+        }
+    }
+}
+
+impl<T: ?Sized> Data for Gather<T>
+where
+    T: Data,
+{
+    type Dim = T::Dim;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> Debug for Gather<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Gather")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("axis", &self.axis)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for Gather<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ GatherBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct GatherBackward<T: ?Sized>
+where
+    T: Gradient,
+{
+    gradient: RefCell<Option<Tensor<T::Dim>>>,
+    shape: T::Dim,
+    overwrite: Cell<bool>,
+    operand: Rc<T>,
+    axis: usize,
+    index: Array<usize, T::Dim>,
+}
+
+impl<T: ?Sized> GatherBackward<T>
+where
+    T: Gradient,
+{
+    pub fn new(operand: Rc<T>, axis: usize, index: Array<usize, T::Dim>) -> Self {
+        check_index(operand.gradient().shape(), axis, &index);
+        let shape = index.raw_dim();
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape.clone()))),
+            shape,
+            overwrite: Cell::new(true),
+            operand,
+            axis,
+            index,
+        }
+    }
+}
+
+impl<T: ?Sized> Gradient for GatherBackward<T>
+where
+    T: Gradient,
+{
+    type Dim = T::Dim;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized> Overwrite for GatherBackward<T>
+where
+    T: Gradient,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized> Backward for GatherBackward<T>
+where
+    T: Gradient,
+{
+    fn backward(&self) {
+        let (mut operand_gradient, gradient) = (self.operand.gradient_mut(), self.gradient());
+
+        // The gradient of the elements that weren't gathered is zero.
+        if self.operand.can_overwrite() {
+            operand_gradient.fill(0.);
+            self.operand.set_overwrite(false);
+        }
+
+        // Elements gathered more than once accumulate their gradients.
+        for (gradient_el, (position, index)) in gradient.iter().zip(self.index.indexed_iter()) {
+            let mut position = position.into_dimension();
+            position[self.axis] = *index;
+            operand_gradient[position] += gradient_el;
+        }
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<T: ?Sized> Debug for GatherBackward<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GatherBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("axis", &self.axis)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for GatherBackward<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+/// Checks that `index` can gather elements along `axis` from a variable of shape `shape`: it must
+/// not be larger than `shape` along any other axis and its elements must lie within `axis`.
+fn check_index<D: Dimension>(shape: &[usize], axis: usize, index: &Array<usize, D>) {
+    assert!(
+        axis < shape.len(),
+        "error: axis {} is out of bounds for a variable with {} dimensions.",
+        axis,
+        shape.len()
+    );
+    assert!(
+        index
+            .shape()
+            .iter()
+            .zip(shape)
+            .enumerate()
+            .all(|(i, (index_len, len))| i == axis || index_len <= len),
+        "error: cannot gather with an index of shape {:?} from a variable of shape {:?} along axis {}.",
+        index.shape(),
+        shape,
+        axis
+    );
+    if let Some(index) = index.iter().find(|index| **index >= shape[axis]) {
+        panic!(
+            "error: index {} is out of bounds for axis {} of length {}.",
+            index, axis, shape[axis]
+        );
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
@@ -0,0 +1,252 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gather, GatherBackward, Gradient, Overwrite, Tensor,
+};
+use ndarray::array;
+
+mod forward {
+    use super::{
+        array, assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, Gather, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Gather::new(input, 1, array![[0, 0], [2, 1]]);
+
+        assert_eq!(*node.data(), Tensor::from_elem((2, 2), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((2, 2), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Gather::new(input, 1, array![[0, 0], [2, 1]]);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic(expected = "error: index 3 is out of bounds for axis 1 of length 3.")]
+    fn fail() {
+        Gather::new(
+            new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]),
+            1,
+            array![[0, 3], [2, 1]],
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: cannot gather with an index of shape [3, 1] from a variable of shape [2, 3] along axis 1."
+    )]
+    fn fail_shape() {
+        Gather::new(
+            new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]),
+            1,
+            array![[0], [1], [2]],
+        );
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Gather::new(input.clone(), 1, array![[0, 0], [2, 1]]);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((2, 2), vec![1., 1., 6., 5.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(
+            &*input.data(),
+            &new_tensor((2, 3), vec![2., 3., 4., 5., 6., 7.]),
+        );
+
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((2, 2), vec![1., 1., 6., 5.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((2, 2), vec![2., 2., 7., 6.]));
+    }
+
+    #[test]
+    fn forward_first_axis() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Gather::new(input, 0, array![[1, 0, 1]]);
+
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((1, 3), vec![4., 2., 6.]));
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Gather::new(input, 1, array![[0, 0], [2, 1]]);
+
+        let output = "Gather { data: [[0.0, 0.0],\n [0.0, 0.0]], shape=[2, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2, axis: 1, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Gather::new(input, 1, array![[0, 0], [2, 1]]);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        array, assert_almost_equals, new_backward_input, new_tensor, Backward, GatherBackward,
+        Gradient, Overwrite, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = GatherBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            1,
+            array![[0, 0], [2, 1]],
+        );
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((2, 2), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((2, 2), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    #[should_panic(expected = "error: index 2 is out of bounds for axis 0 of length 2.")]
+    fn fail() {
+        GatherBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            0,
+            array![[2, 0, 1]],
+        );
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = GatherBackward::new(diff.clone(), 1, array![[0, 0], [2, 1]]);
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        // The stale gradient of the operand must be discarded on overwrite.
+        let diff = new_backward_input((2, 3), vec![5.; 6]);
+        let node = GatherBackward::new(diff.clone(), 1, array![[0, 0], [2, 1]]);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((2, 2), vec![1., 2., 3., 4.]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((2, 2), vec![1., 2., 3., 4.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        // The element gathered twice accumulates the gradients of both its occurrences.
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![3., 0., 0., 0., 4., 3.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![6., 0., 0., 0., 8., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![3., 0., 0., 0., 4., 3.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = GatherBackward::new(diff, 1, array![[0, 0], [2, 1]]);
+
+        let output = "GatherBackward { gradient: Some([[0.0, 0.0],\n [0.0, 0.0]], shape=[2, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2), axis: 1, overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = GatherBackward::new(diff, 1, array![[0, 0], [2, 1]]);
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // GatherBackward
+        let node = GatherBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            1,
+            array![[0, 0], [2, 1]],
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
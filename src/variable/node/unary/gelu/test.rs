@@ -0,0 +1,274 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, GELUBackward, Gradient, Overwrite, Tensor, GELU,
+};
+
+mod forward {
+    use super::{assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, Tensor, GELU};
+
+    #[test]
+    fn creation() {
+        let input = new_input((3, 3), vec![-3., -2., -1., 0., 1., 2., 3., 4., 5.]);
+        let node = GELU::new(input);
+
+        assert_eq!(*node.data(), Tensor::from_elem((3, 3), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((3, 3), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((3, 3), vec![-3., -2., -1., 0., 1., 2., 3., 4., 5.]);
+        let node = GELU::new(input);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((3, 3), vec![-3., -2., -1., 0., 1., 2., 3., 4., 5.]);
+        let node = GELU::new(input.clone());
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (3, 3),
+                vec![
+                    -0.003637, -0.045402, -0.158808, 0., 0.841192, 1.9546, 2.99636, 3.99993, 5.,
+                ],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(
+            &*input.data(),
+            &new_tensor((3, 3), vec![-2., -1., 0., 1., 2., 3., 4., 5., 6.]),
+        );
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (3, 3),
+                vec![
+                    -0.003637, -0.045402, -0.158808, 0., 0.841192, 1.9546, 2.99636, 3.99993, 5.,
+                ],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (3, 3),
+                vec![
+                    -0.045402, -0.158808, 0., 0.841192, 1.9546, 2.99636, 3.99993, 5., 6.,
+                ],
+            ),
+        );
+    }
+
+    #[test]
+    fn forward_exact() {
+        let input = new_input((3, 3), vec![-4., -3., -2., -1., 0., 1., 2., 3., 4.]);
+        let node = GELU::new(input);
+        node.forward();
+
+        // Values of x * Φ(x), where Φ is the cumulative distribution function of the standard
+        // normal distribution.
+        let exact = new_tensor(
+            (3, 3),
+            vec![
+                -0.000127, -0.00405, -0.0455, -0.158655, 0., 0.841345, 1.9545, 2.99595, 3.999873,
+            ],
+        );
+        assert!(node
+            .data()
+            .iter()
+            .zip(exact.iter())
+            .all(|(approximate, exact)| (approximate - exact).abs() < 1e-3));
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((3, 3), vec![1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+        let node = GELU::new(input);
+
+        let output = "GELU { data: [[0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0]], shape=[3, 3], strides=[3, 1], layout=Cc (0x5), const ndim=2, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((3, 3), vec![1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+        let node = GELU::new(input);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Data, Forward,
+        GELUBackward, Gradient, Overwrite, Tensor, GELU,
+    };
+
+    #[test]
+    fn creation() {
+        let node = GELUBackward::new(
+            new_backward_input(3, vec![0.; 3]),
+            new_input(3, vec![1., 2., 3.]),
+        );
+
+        assert_eq!(*node.gradient(), Tensor::from_elem(3, 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem(3, 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input(3, vec![0.; 3]);
+        let node = GELUBackward::new(diff.clone(), new_input(3, vec![1., 2., 3.]));
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        let diff = new_backward_input(3, vec![0.; 3]);
+        let node = GELUBackward::new(diff.clone(), new_input(3, vec![1., 2., 3.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor(3, vec![1.; 3]);
+        assert_almost_equals(&*node.gradient(), &new_tensor(3, vec![1.; 3]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(3, vec![1.08296, 1.0861, 1.01158]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(3, vec![2.16593, 2.1722, 2.02317]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(3, vec![1.08296, 1.0861, 1.01158]),
+        );
+    }
+
+    #[test]
+    fn backward_numeric() {
+        let data = vec![-3., -1.5, -0.5, 0., 0.25, 1., 2.5];
+        let diff = new_backward_input(7, vec![0.; 7]);
+        let node = GELUBackward::new(diff.clone(), new_input(7, data.clone()));
+        *node.gradient_mut() = new_tensor(7, vec![1.; 7]);
+        node.backward();
+
+        // Central finite differences of the forward computation.
+        let h = 1e-2;
+        let gelu = |x: f32| {
+            let node = GELU::new(new_input(1, vec![x]));
+            node.forward();
+            let value = node.data()[0];
+            value
+        };
+        let numeric = data.iter().map(|&x| (gelu(x + h) - gelu(x - h)) / (2. * h));
+
+        assert!(diff
+            .gradient()
+            .iter()
+            .zip(numeric)
+            .all(|(analytic, numeric)| (analytic - numeric).abs() < 1e-2));
+    }
+
+    #[test]
+    fn debug() {
+        let diff = new_backward_input(3, vec![0.; 3]);
+        let node = GELUBackward::new(diff, new_input(3, vec![1., 2., 3.]));
+
+        let output = "GELUBackward { gradient: Some([0.0, 0.0, 0.0], shape=[3], strides=[1], layout=CFcf (0xf), const ndim=1), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let diff = new_backward_input(3, vec![0.; 3]);
+        let node = GELUBackward::new(diff, new_input(3, vec![1., 2., 3.]));
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // GELUBackward
+        let node = GELUBackward::new(
+            new_backward_input((3, 3), vec![0.; 9]),
+            new_input((3, 3), vec![0.; 9]),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
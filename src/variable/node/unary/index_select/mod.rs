@@ -0,0 +1,257 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite, Summary,
+    Tensor,
+};
+use ndarray::{Axis, Dimension, Slice, Zip};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ IndexSelect ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct IndexSelect<T: ?Sized>
+where
+    T: Data,
+{
+    operand: Rc<T>,
+    data: RefCell<Tensor<T::Dim>>,
+    axis: usize,
+    indices: Vec<usize>,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized> IndexSelect<T>
+where
+    T: Data,
+{
+    pub fn new(operand: Rc<T>, axis: usize, indices: Vec<usize>) -> Self {
+        let mut shape = operand.data().raw_dim();
+        check_indices(shape.slice(), axis, &indices);
+        shape[axis] = indices.len();
+
+        Self {
+            operand,
+            data: RefCell::new(Tensor::zeros(shape)),
+            axis,
+            indices,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized> Cache for IndexSelect<T>
+where
+    T: Data,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized> Forward for IndexSelect<T>
+where
+    T: Data,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let (mut data, operand_data) = (self.data.borrow_mut(), self.operand.data());
+        for (i, index) in self.indices.iter().enumerate() {
+            data.slice_axis_mut(Axis(self.axis), Slice::from(i..=i))
+                .assign(&operand_data.slice_axis(Axis(self.axis), Slice::from(*index..=*index)));
+        }
+    }
+}
+
+impl<T: ?Sized> Data for IndexSelect<T>
+where
+    T: Data,
+{
+    type Dim = T::Dim;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> Debug for IndexSelect<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IndexSelect")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("axis", &self.axis)
+            .field("indices", &self.indices)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for IndexSelect<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ IndexSelectBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct IndexSelectBackward<T: ?Sized>
+where
+    T: Gradient,
+{
+    gradient: RefCell<Option<Tensor<T::Dim>>>,
+    shape: T::Dim,
+    overwrite: Cell<bool>,
+    operand: Rc<T>,
+    axis: usize,
+    indices: Vec<usize>,
+}
+
+impl<T: ?Sized> IndexSelectBackward<T>
+where
+    T: Gradient,
+{
+    pub fn new(operand: Rc<T>, axis: usize, indices: Vec<usize>) -> Self {
+        let mut shape = operand.gradient().raw_dim();
+        check_indices(shape.slice(), axis, &indices);
+        shape[axis] = indices.len();
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape.clone()))),
+            shape,
+            overwrite: Cell::new(true),
+            operand,
+            axis,
+            indices,
+        }
+    }
+}
+
+impl<T: ?Sized> Gradient for IndexSelectBackward<T>
+where
+    T: Gradient,
+{
+    type Dim = T::Dim;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized> Overwrite for IndexSelectBackward<T>
+where
+    T: Gradient,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized> Backward for IndexSelectBackward<T>
+where
+    T: Gradient,
+{
+    fn backward(&self) {
+        let (mut operand_gradient, gradient) = (self.operand.gradient_mut(), self.gradient());
+
+        // The gradient of the elements that weren't selected is zero.
+        if self.operand.can_overwrite() {
+            operand_gradient.fill(0.);
+            self.operand.set_overwrite(false);
+        }
+
+        // Repeated indices accumulate their gradients.
+        for (i, index) in self.indices.iter().enumerate() {
+            Zip::from(
+                operand_gradient.slice_axis_mut(Axis(self.axis), Slice::from(*index..=*index)),
+            )
+            .and(gradient.slice_axis(Axis(self.axis), Slice::from(i..=i)))
+            .for_each(|operand_gradient_el, gradient_el| *operand_gradient_el += gradient_el);
+        }
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<T: ?Sized> Debug for IndexSelectBackward<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IndexSelectBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("axis", &self.axis)
+            .field("indices", &self.indices)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for IndexSelectBackward<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+/// Checks that `axis` lies within `shape` and that every one of `indices` lies within `axis`.
+fn check_indices(shape: &[usize], axis: usize, indices: &[usize]) {
+    assert!(
+        axis < shape.len(),
+        "error: axis {} is out of bounds for a variable with {} dimensions.",
+        axis,
+        shape.len()
+    );
+    if let Some(index) = indices.iter().find(|index| **index >= shape[axis]) {
+        panic!(
+            "error: index {} is out of bounds for axis {} of length {}.",
+            index, axis, shape[axis]
+        );
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
@@ -0,0 +1,249 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, IndexSelect, IndexSelectBackward, Overwrite, Tensor,
+};
+
+mod forward {
+    use super::{
+        assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, IndexSelect, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let node = IndexSelect::new(input, 0, vec![2, 0, 2, 1]);
+
+        assert_eq!(*node.data(), Tensor::from_elem((4, 2), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((4, 2), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let node = IndexSelect::new(input, 0, vec![2, 0, 2]);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic(expected = "error: index 3 is out of bounds for axis 0 of length 3.")]
+    fn fail() {
+        IndexSelect::new(
+            new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+            0,
+            vec![0, 3],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "error: axis 2 is out of bounds for a variable with 2 dimensions.")]
+    fn fail_axis() {
+        IndexSelect::new(new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]), 2, vec![0]);
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let node = IndexSelect::new(input.clone(), 0, vec![2, 0, 2]);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 2), vec![5., 6., 1., 2., 5., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(
+            &*input.data(),
+            &new_tensor((3, 2), vec![2., 3., 4., 5., 6., 7.]),
+        );
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 2), vec![5., 6., 1., 2., 5., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 2), vec![6., 7., 2., 3., 6., 7.]),
+        );
+    }
+
+    #[test]
+    fn forward_inner_axis() {
+        let input = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let node = IndexSelect::new(input, 1, vec![1, 1, 0]);
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 3), vec![2., 2., 1., 4., 4., 3., 6., 6., 5.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let node = IndexSelect::new(input, 0, vec![2, 0]);
+
+        let output = "IndexSelect { data: [[0.0, 0.0],\n [0.0, 0.0]], shape=[2, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2, axis: 0, indices: [2, 0], computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let node = IndexSelect::new(input, 0, vec![2, 0]);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_tensor, Backward, Gradient,
+        IndexSelectBackward, Overwrite, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node =
+            IndexSelectBackward::new(new_backward_input((3, 2), vec![0.; 6]), 0, vec![2, 0, 2, 1]);
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((4, 2), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((4, 2), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    #[should_panic(expected = "error: index 2 is out of bounds for axis 1 of length 2.")]
+    fn fail() {
+        IndexSelectBackward::new(new_backward_input((3, 2), vec![0.; 6]), 1, vec![2]);
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((3, 2), vec![0.; 6]);
+        let node = IndexSelectBackward::new(diff.clone(), 0, vec![2, 0, 2]);
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        // The stale gradient of the operand must be discarded on overwrite.
+        let diff = new_backward_input((3, 2), vec![5.; 6]);
+        let node = IndexSelectBackward::new(diff.clone(), 0, vec![2, 0, 2]);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        assert_almost_equals(
+            &*node.gradient(),
+            &new_tensor((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        // The repeated index accumulates the gradients of both its occurrences.
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 2), vec![3., 4., 0., 0., 6., 8.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 2), vec![6., 8., 0., 0., 12., 16.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 2), vec![3., 4., 0., 0., 6., 8.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let diff = new_backward_input((3, 2), vec![0.; 6]);
+        let node = IndexSelectBackward::new(diff, 0, vec![2, 0]);
+
+        let output = "IndexSelectBackward { gradient: Some([[0.0, 0.0],\n [0.0, 0.0]], shape=[2, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2), axis: 0, indices: [2, 0], overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let diff = new_backward_input((3, 2), vec![0.; 6]);
+        let node = IndexSelectBackward::new(diff, 0, vec![2, 0]);
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // IndexSelectBackward
+        let node = IndexSelectBackward::new(new_backward_input((3, 2), vec![0.; 6]), 0, vec![2, 0]);
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
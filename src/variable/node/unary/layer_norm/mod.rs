@@ -0,0 +1,307 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, push_gradient, Backward, Cache, Data, Forward, Gradient,
+    Overwrite, Summary, Tensor,
+};
+use ndarray::{Dimension, Ix1, Zip};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+/// Returns the number of rows and the length of each of them obtained by flattening the last
+/// `normalized_ndim` axes of an array of shape `shape`.
+fn rows_shape<D: Dimension>(shape: &D, normalized_ndim: usize) -> (usize, usize) {
+    let ndim = shape.ndim();
+    assert!(
+        normalized_ndim <= ndim,
+        "error: the input has less dimensions than the normalized shape."
+    );
+
+    let row_len = shape.slice()[ndim - normalized_ndim..].iter().product();
+    (
+        shape.slice()[..ndim - normalized_ndim].iter().product(),
+        row_len,
+    )
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ LayerNorm ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct LayerNorm<T: ?Sized>
+where
+    T: Data,
+{
+    operand: Rc<T>,
+    data: RefCell<Tensor<T::Dim>>,
+    inv_std: RefCell<Tensor<Ix1>>,
+    rows_shape: (usize, usize),
+    eps: f32,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized> LayerNorm<T>
+where
+    T: Data,
+{
+    pub fn new(operand: Rc<T>, normalized_ndim: usize, eps: f32) -> Self {
+        let shape = operand.data().raw_dim();
+        let rows_shape = rows_shape(&shape, normalized_ndim);
+
+        Self {
+            operand,
+            data: RefCell::new(Tensor::zeros(shape)),
+            inv_std: RefCell::new(Tensor::zeros(rows_shape.0)),
+            rows_shape,
+            eps,
+            computed: Cell::new(false),
+        }
+    }
+
+    pub(crate) fn inv_std(&self) -> Ref<Tensor<Ix1>> {
+        self.inv_std.borrow()
+    }
+}
+
+impl<T: ?Sized> Cache for LayerNorm<T>
+where
+    T: Data,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized> Forward for LayerNorm<T>
+where
+    T: Data,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let (operand_data, mut data, mut inv_std) = (
+            self.operand.data(),
+            self.data.borrow_mut(),
+            self.inv_std.borrow_mut(),
+        );
+        let (eps, len) = (self.eps, self.rows_shape.1 as f32);
+
+        // The normalized axes are flattened, so that each sample becomes a row.
+        let operand_data = operand_data.as_standard_layout();
+        let operand_rows = operand_data.view().into_shape(self.rows_shape).unwrap();
+        let mut data_rows = data.view_mut().into_shape(self.rows_shape).unwrap();
+
+        Zip::from(data_rows.rows_mut())
+            .and(operand_rows.rows())
+            .and(&mut *inv_std)
+            .for_each(|mut data_row, operand_row, inv_std_el| {
+                let mean = operand_row.sum() / len;
+                let var = operand_row.fold(0., |acc, el| acc + (el - mean).powi(2)) / len;
+
+                *inv_std_el = 1. / (var + eps).sqrt();
+                Zip::from(&mut data_row)
+                    .and(&operand_row)
+                    .for_each(|data_el, operand_el| *data_el = (operand_el - mean) * *inv_std_el);
+            });
+    }
+}
+
+impl<T: ?Sized> Data for LayerNorm<T>
+where
+    T: Data,
+{
+    type Dim = T::Dim;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> Debug for LayerNorm<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LayerNorm")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("eps", &self.eps)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for LayerNorm<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ LayerNormBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct LayerNormBackward<T: ?Sized, U: ?Sized>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    gradient: RefCell<Option<Tensor<T::Dim>>>,
+    shape: T::Dim,
+    overwrite: Cell<bool>,
+    diff_operand: Rc<T>,
+    no_diff_operand: Rc<LayerNorm<U>>,
+    buffer: RefCell<Tensor<T::Dim>>,
+}
+
+impl<T: ?Sized, U: ?Sized> LayerNormBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    pub fn new(diff_operand: Rc<T>, no_diff_operand: Rc<LayerNorm<U>>) -> Self {
+        let shape = diff_operand.gradient().raw_dim();
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape.clone()))),
+            buffer: RefCell::new(Tensor::zeros(shape.clone())),
+            shape,
+            overwrite: Cell::new(true),
+            diff_operand,
+            no_diff_operand,
+        }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Gradient for LayerNormBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    type Dim = T::Dim;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Overwrite for LayerNormBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Backward for LayerNormBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    fn backward(&self) {
+        let (gradient, normalized, inv_std) = (
+            self.gradient(),
+            self.no_diff_operand.data(),
+            self.no_diff_operand.inv_std(),
+        );
+        let mut buffer = self.buffer.borrow_mut();
+        let rows_shape = self.no_diff_operand.rows_shape;
+        let len = rows_shape.1 as f32;
+
+        let (gradient, normalized) = (gradient.as_standard_layout(), normalized.view());
+        let gradient_rows = gradient.view().into_shape(rows_shape).unwrap();
+        let normalized_rows = normalized.into_shape(rows_shape).unwrap();
+        let mut buffer_rows = buffer.view_mut().into_shape(rows_shape).unwrap();
+
+        // Besides the direct contribution, the gradient flows through the mean and the variance,
+        // this only requires the means of the incoming gradient and of its projection on the
+        // normalized data.
+        Zip::from(buffer_rows.rows_mut())
+            .and(gradient_rows.rows())
+            .and(normalized_rows.rows())
+            .and(&*inv_std)
+            .for_each(|mut buffer_row, gradient_row, normalized_row, inv_std_el| {
+                let gradient_mean = gradient_row.sum() / len;
+                let projection = Zip::from(&gradient_row)
+                    .and(&normalized_row)
+                    .fold(0., |acc, grad_el, normalized_el| {
+                        acc + grad_el * normalized_el
+                    })
+                    / len;
+
+                Zip::from(&mut buffer_row)
+                    .and(&gradient_row)
+                    .and(&normalized_row)
+                    .for_each(|buffer_el, grad_el, normalized_el| {
+                        *buffer_el =
+                            (grad_el - gradient_mean - normalized_el * projection) * inv_std_el
+                    });
+            });
+
+        push_gradient(&*self.diff_operand, &*buffer);
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Debug for LayerNormBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LayerNormBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Display for LayerNormBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
@@ -0,0 +1,323 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, LayerNorm, LayerNormBackward, Overwrite, Tensor,
+};
+use std::rc::Rc;
+
+mod forward {
+    use super::{
+        assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, LayerNorm, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((2, 3), vec![1., 2., 4., -1., 0., 3.]);
+        let node = LayerNorm::new(input, 1, 1e-5);
+
+        assert_eq!(*node.data(), Tensor::from_elem((2, 3), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((2, 3), 0.));
+        assert_eq!(*node.inv_std(), Tensor::from_elem(2, 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic(expected = "error: the input has less dimensions than the normalized shape.")]
+    fn creation_too_many_dimensions() {
+        let input = new_input((2, 3), vec![1., 2., 4., -1., 0., 3.]);
+        let _ = LayerNorm::new(input, 3, 1e-5);
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((2, 3), vec![1., 2., 4., -1., 0., 3.]);
+        let node = LayerNorm::new(input, 1, 1e-5);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((2, 3), vec![1., 2., 4., -1., 0., 3.]);
+        let node = LayerNorm::new(input.clone(), 1, 1e-5);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (2, 3),
+                vec![
+                    -1.069042, -0.26726, 1.336302, -0.980579, -0.392232, 1.372811,
+                ],
+            ),
+        );
+        assert_almost_equals(&*node.inv_std(), &new_tensor(2, vec![0.801781, 0.588347]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *input.data_mut() = new_tensor((2, 3), vec![3., 3., 3., 3., 3., 3.]);
+        node.forward();
+        assert_almost_equals(&*node.inv_std(), &new_tensor(2, vec![0.801781, 0.588347]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_eq!(*node.data(), Tensor::from_elem((2, 3), 0.));
+    }
+
+    #[test]
+    fn forward_two_axes() {
+        let input = new_input(
+            (2, 2, 3),
+            vec![1., 2., 4., -1., 0., 3., 2., 1., 2., 0., 2., 5.],
+        );
+        let node = LayerNorm::new(input, 2, 1e-5);
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (2, 2, 3),
+                vec![
+                    -0.29277, 0.29277, 1.463848, -1.463848, -0.878309, 0.878309, 0., -0.654652, 0.,
+                    -1.309305, 0., 1.963957,
+                ],
+            ),
+        );
+    }
+
+    #[test]
+    fn forward_constant_row() {
+        // The variance of a constant row is zero, so the inverse of the standard deviation is
+        // entirely determined by eps.
+        let input = new_input((1, 3), vec![3., 3., 3.]);
+        let node = LayerNorm::new(input, 1, 1e-2);
+
+        node.forward();
+        assert_eq!(*node.data(), Tensor::from_elem((1, 3), 0.));
+        assert_almost_equals(&*node.inv_std(), &new_tensor(1, vec![10.]));
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((2, 3), vec![1., 2., 4., -1., 0., 3.]);
+        let node = LayerNorm::new(input, 1, 1e-5);
+
+        let output = "LayerNorm { data: [[0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0]], shape=[2, 3], strides=[3, 1], layout=Cc (0x5), const ndim=2, eps: 1e-5, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((2, 3), vec![1., 2., 4., -1., 0., 3.]);
+        let node = LayerNorm::new(input, 1, 1e-5);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Forward,
+        Gradient, LayerNorm, LayerNormBackward, Overwrite, Rc, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = LayerNormBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            Rc::new(LayerNorm::new(
+                new_input((2, 3), vec![1., 2., 4., -1., 0., 3.]),
+                1,
+                1e-5,
+            )),
+        );
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((2, 3), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((2, 3), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let not_diff = Rc::new(LayerNorm::new(
+            new_input((2, 3), vec![1., 2., 4., -1., 0., 3.]),
+            1,
+            1e-5,
+        ));
+        not_diff.forward();
+        let node = LayerNormBackward::new(diff.clone(), not_diff);
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let not_diff = Rc::new(LayerNorm::new(
+            new_input((2, 3), vec![1., 2., 4., -1., 0., 3.]),
+            1,
+            1e-5,
+        ));
+        not_diff.forward();
+        let node = LayerNormBackward::new(diff.clone(), not_diff);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((2, 3), vec![1., -2., 0.5, 3., -1., 1.]);
+        assert_almost_equals(
+            &*node.gradient(),
+            &new_tensor((2, 3), vec![1., -2., 0.5, 3., -1., 1.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        let expected = vec![0.973591, -1.460387, 0.486796, 0.950408, -1.267209, 0.316801];
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor((2, 3), expected.clone()));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ 2nd Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), expected.iter().map(|el| el * 2.).collect()),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor((2, 3), expected));
+    }
+
+    #[test]
+    fn backward_two_axes() {
+        let diff = new_backward_input((2, 2, 3), vec![0.; 12]);
+        let not_diff = Rc::new(LayerNorm::new(
+            new_input(
+                (2, 2, 3),
+                vec![1., 2., 4., -1., 0., 3., 2., 1., 2., 0., 2., 5.],
+            ),
+            2,
+            1e-5,
+        ));
+        not_diff.forward();
+        let node = LayerNormBackward::new(diff.clone(), not_diff);
+
+        *node.gradient_mut() = new_tensor(
+            (2, 2, 3),
+            vec![-5., -4., -3., -2., -1., 0., 1., -2., 0.5, 3., -1., 2.],
+        );
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(
+                (2, 2, 3),
+                vec![
+                    -1.472212, -0.869944, -0.250945, 0.250945, 0.853214, 1.488942, 0.272772,
+                    -1.597664, -0.054554, 1.769119, -1.036533, 0.64686,
+                ],
+            ),
+        );
+    }
+
+    #[test]
+    fn backward_constant_row() {
+        let diff = new_backward_input((1, 3), vec![0.; 3]);
+        let not_diff = Rc::new(LayerNorm::new(new_input((1, 3), vec![3., 3., 3.]), 1, 1e-2));
+        not_diff.forward();
+        let node = LayerNormBackward::new(diff.clone(), not_diff);
+
+        // The normalized row is zero, thus only the centered gradient flows, scaled by eps.
+        *node.gradient_mut() = new_tensor((1, 3), vec![1., -2., 4.]);
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor((1, 3), vec![0., -30., 30.]));
+    }
+
+    #[test]
+    fn debug() {
+        let node = LayerNormBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            Rc::new(LayerNorm::new(
+                new_input((2, 3), vec![1., 2., 4., -1., 0., 3.]),
+                1,
+                1e-5,
+            )),
+        );
+
+        let output = "LayerNormBackward { gradient: Some([[0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0]], shape=[2, 3], strides=[3, 1], layout=Cc (0x5), const ndim=2), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let node = LayerNormBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            Rc::new(LayerNorm::new(
+                new_input((2, 3), vec![1., 2., 4., -1., 0., 3.]),
+                1,
+                1e-5,
+            )),
+        );
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // LayerNormBackward
+        let node = LayerNormBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            Rc::new(LayerNorm::new(
+                new_input((2, 3), vec![1., 2., 4., -1., 0., 3.]),
+                1,
+                1e-5,
+            )),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
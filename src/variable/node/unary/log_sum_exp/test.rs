@@ -0,0 +1,261 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, LogSumExp, LogSumExpBackward, Overwrite, Rc, Tensor,
+};
+
+mod forward {
+    use super::{
+        assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, LogSumExp, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((2, 3), vec![0., 1., 2., 3., 4., 5.]);
+        let node = LogSumExp::new(input, 1);
+
+        assert_eq!(*node.data(), Tensor::from_elem((2, 1), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((2, 1), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((2, 3), vec![0., 1., 2., 3., 4., 5.]);
+        let node = LogSumExp::new(input, 1);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic(expected = "error: axis 2 is out of bounds for a variable with 2 dimensions.")]
+    fn fail() {
+        LogSumExp::new(new_input((2, 3), vec![0., 1., 2., 3., 4., 5.]), 2);
+    }
+
+    #[test]
+    fn forward_rows() {
+        let input = new_input((2, 3), vec![0., 1., 2., 3., 4., 5.]);
+        let node = LogSumExp::new(input.clone(), 1);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((2, 1), vec![2.407606, 5.407606]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(
+            &*input.data(),
+            &new_tensor((2, 3), vec![1., 2., 3., 4., 5., 6.]),
+        );
+
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((2, 1), vec![2.407606, 5.407606]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((2, 1), vec![3.407606, 6.407606]));
+    }
+
+    #[test]
+    fn forward_columns() {
+        let input = new_input((2, 3), vec![0., 1., 2., 3., 4., 5.]);
+        let node = LogSumExp::new(input, 0);
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((1, 3), vec![3.048587, 4.048587, 5.048587]),
+        );
+    }
+
+    #[test]
+    fn forward_large_values() {
+        // Without subtracting the maximum the exponentials would overflow.
+        let input = new_input(3, vec![1000., 1000., -1000.]);
+        let node = LogSumExp::new(input, 0);
+
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor(1, vec![1000. + 2_f32.ln()]));
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input(3, vec![0., 1., 2.]);
+        let node = LogSumExp::new(input, 0);
+
+        let output = "LogSumExp { data: [0.0], shape=[1], strides=[1], layout=CFcf (0xf), const ndim=1, axis: 0, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input(3, vec![0., 1., 2.]);
+        let node = LogSumExp::new(input, 0);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Forward,
+        Gradient, LogSumExp, LogSumExpBackward, Overwrite, Rc, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = LogSumExpBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            Rc::new(LogSumExp::new(
+                new_input((2, 3), vec![0., 1., 2., 3., 4., 5.]),
+                1,
+            )),
+        );
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((2, 1), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((2, 1), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = LogSumExpBackward::new(
+            diff.clone(),
+            Rc::new(LogSumExp::new(
+                new_input((2, 3), vec![0., 1., 2., 3., 4., 5.]),
+                1,
+            )),
+        );
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let not_diff = Rc::new(LogSumExp::new(
+            new_input((2, 3), vec![0., 1., 2., 3., 4., 5.]),
+            1,
+        ));
+        not_diff.forward();
+        let node = LogSumExpBackward::new(diff.clone(), not_diff);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((2, 1), vec![1., 2.]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((2, 1), vec![1., 2.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(
+                (2, 3),
+                vec![0.090031, 0.244728, 0.665241, 0.180061, 0.489457, 1.330482],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(
+                (2, 3),
+                vec![0.180061, 0.489457, 1.330482, 0.360123, 0.978914, 2.660964],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(
+                (2, 3),
+                vec![0.090031, 0.244728, 0.665241, 0.180061, 0.489457, 1.330482],
+            ),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let node = LogSumExpBackward::new(
+            new_backward_input(3, vec![0.; 3]),
+            Rc::new(LogSumExp::new(new_input(3, vec![0., 1., 2.]), 0)),
+        );
+
+        let output = "LogSumExpBackward { gradient: Some([0.0], shape=[1], strides=[1], layout=CFcf (0xf), const ndim=1), axis: 0, overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let node = LogSumExpBackward::new(
+            new_backward_input(3, vec![0.; 3]),
+            Rc::new(LogSumExp::new(new_input(3, vec![0., 1., 2.]), 0)),
+        );
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // LogSumExpBackward
+        let node = LogSumExpBackward::new(
+            new_backward_input(3, vec![0.; 3]),
+            Rc::new(LogSumExp::new(new_input(3, vec![0., 1., 2.]), 0)),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
@@ -0,0 +1,269 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite, Summary,
+    Tensor,
+};
+use ndarray::{Dimension, Zip};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ MaskedFill ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct MaskedFill<T: ?Sized, M: ?Sized>
+where
+    T: Data,
+    M: Data,
+{
+    operand: Rc<T>,
+    mask: Rc<M>,
+    value: f32,
+    data: RefCell<Tensor<T::Dim>>,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized, M: ?Sized> MaskedFill<T, M>
+where
+    T: Data,
+    M: Data,
+{
+    pub fn new(operand: Rc<T>, mask: Rc<M>, value: f32) -> Self {
+        let data = Tensor::zeros(operand.data().raw_dim());
+        check_mask(&mask.data(), &data);
+
+        Self {
+            operand,
+            mask,
+            value,
+            data: RefCell::new(data),
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized, M: ?Sized> Cache for MaskedFill<T, M>
+where
+    T: Data,
+    M: Data,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized, M: ?Sized> Forward for MaskedFill<T, M>
+where
+    T: Data,
+    M: Data,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let mut data = self.data.borrow_mut();
+        let mask = self.mask.data();
+        let value = self.value;
+        Zip::from(&mut *data)
+            .and(&*self.operand.data())
+            .and_broadcast(&*mask)
+            .for_each(|data_el, operand_el, mask_el| {
+                *data_el = if *mask_el != 0. { value } else { *operand_el }
+            });
+    }
+}
+
+impl<T: ?Sized, M: ?Sized> Data for MaskedFill<T, M>
+where
+    T: Data,
+    M: Data,
+{
+    type Dim = T::Dim;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized, M: ?Sized> Debug for MaskedFill<T, M>
+where
+    T: Data,
+    M: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MaskedFill")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("value", &self.value)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, M: ?Sized> Display for MaskedFill<T, M>
+where
+    T: Data,
+    M: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ MaskedFillBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct MaskedFillBackward<T: ?Sized, M: ?Sized>
+where
+    T: Gradient,
+    M: Data,
+{
+    gradient: RefCell<Option<Tensor<T::Dim>>>,
+    shape: T::Dim,
+    overwrite: Cell<bool>,
+    operand: Rc<T>,
+    mask: Rc<M>,
+}
+
+impl<T: ?Sized, M: ?Sized> MaskedFillBackward<T, M>
+where
+    T: Gradient,
+    M: Data,
+{
+    pub fn new(operand: Rc<T>, mask: Rc<M>) -> Self {
+        let gradient = Tensor::zeros(operand.gradient().raw_dim());
+        check_mask(&mask.data(), &gradient);
+        let shape = gradient.raw_dim();
+
+        Self {
+            gradient: RefCell::new(Some(gradient)),
+            shape,
+            overwrite: Cell::new(true),
+            operand,
+            mask,
+        }
+    }
+}
+
+impl<T: ?Sized, M: ?Sized> Gradient for MaskedFillBackward<T, M>
+where
+    T: Gradient,
+    M: Data,
+{
+    type Dim = T::Dim;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized, M: ?Sized> Overwrite for MaskedFillBackward<T, M>
+where
+    T: Gradient,
+    M: Data,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized, M: ?Sized> Backward for MaskedFillBackward<T, M>
+where
+    T: Gradient,
+    M: Data,
+{
+    fn backward(&self) {
+        let (mut operand_gradient, gradient, mask) = (
+            self.operand.gradient_mut(),
+            self.gradient(),
+            self.mask.data(),
+        );
+        let zip = Zip::from(&mut *operand_gradient)
+            .and(&*gradient)
+            .and_broadcast(&*mask);
+
+        // The filled elements don't depend on the operand.
+        if self.operand.can_overwrite() {
+            zip.for_each(|operand_gradient_el, gradient_el, mask_el| {
+                *operand_gradient_el = if *mask_el != 0. { 0. } else { *gradient_el }
+            });
+            self.operand.set_overwrite(false);
+        } else {
+            zip.for_each(|operand_gradient_el, gradient_el, mask_el| {
+                if *mask_el == 0. {
+                    *operand_gradient_el += gradient_el
+                }
+            });
+        }
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<T: ?Sized, M: ?Sized> Debug for MaskedFillBackward<T, M>
+where
+    T: Gradient,
+    M: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MaskedFillBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, M: ?Sized> Display for MaskedFillBackward<T, M>
+where
+    T: Gradient,
+    M: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+/// Checks that `mask` can be broadcast to the shape of `tensor`.
+fn check_mask<D: Dimension, E: Dimension>(mask: &Tensor<E>, tensor: &Tensor<D>) {
+    assert!(
+        mask.broadcast(tensor.raw_dim()).is_some(),
+        "error: cannot broadcast a mask of shape {:?} to a variable of shape {:?}.",
+        mask.shape(),
+        tensor.shape()
+    );
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
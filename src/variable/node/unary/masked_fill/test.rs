@@ -0,0 +1,273 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, MaskedFill, MaskedFillBackward, Overwrite, Tensor,
+};
+
+mod forward {
+    use super::{
+        assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, MaskedFill, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let mask = new_input((2, 3), vec![0., 1., 0., 0., 1., 0.]);
+        let node = MaskedFill::new(input, mask, -1.);
+
+        assert_eq!(*node.data(), Tensor::from_elem((2, 3), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((2, 3), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let mask = new_input((2, 3), vec![0., 1., 0., 0., 1., 0.]);
+        let node = MaskedFill::new(input, mask, -1.);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: cannot broadcast a mask of shape [2] to a variable of shape [2, 3]."
+    )]
+    fn fail() {
+        MaskedFill::new(
+            new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]),
+            new_input(2, vec![0., 1.]),
+            -1.,
+        );
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let mask = new_input(3, vec![0., 1., 0.]);
+        let node = MaskedFill::new(input.clone(), mask, -1.);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((2, 3), vec![1., -1., 3., 4., -1., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(
+            &*input.data(),
+            &new_tensor((2, 3), vec![2., 3., 4., 5., 6., 7.]),
+        );
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((2, 3), vec![1., -1., 3., 4., -1., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((2, 3), vec![2., -1., 4., 5., -1., 7.]),
+        );
+    }
+
+    #[test]
+    fn forward_changed_mask() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let mask = new_input((2, 3), vec![0., 1., 0., 0., 1., 0.]);
+        let node = MaskedFill::new(input, mask.clone(), 0.);
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((2, 3), vec![1., 0., 3., 4., 0., 6.]),
+        );
+
+        // The mask is read at each evaluation.
+        *mask.data_mut() = new_tensor((2, 3), vec![1., 0., 0., 0., 0., 1.]);
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((2, 3), vec![0., 2., 3., 4., 5., 0.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let mask = new_input(3, vec![0., 1., 0.]);
+        let node = MaskedFill::new(input, mask, -1.);
+
+        let output = "MaskedFill { data: [[0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0]], shape=[2, 3], strides=[3, 1], layout=Cc (0x5), const ndim=2, value: -1.0, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let mask = new_input(3, vec![0., 1., 0.]);
+        let node = MaskedFill::new(input, mask, -1.);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Gradient,
+        MaskedFillBackward, Overwrite, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = MaskedFillBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            new_input(3, vec![0., 1., 0.]),
+        );
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((2, 3), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((2, 3), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: cannot broadcast a mask of shape [3, 2] to a variable of shape [2, 3]."
+    )]
+    fn fail() {
+        MaskedFillBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            new_input((3, 2), vec![0.; 6]),
+        );
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = MaskedFillBackward::new(diff.clone(), new_input(3, vec![0., 1., 0.]));
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        // The stale gradient of the operand must be discarded on overwrite.
+        let diff = new_backward_input((2, 3), vec![5.; 6]);
+        let node = MaskedFillBackward::new(diff.clone(), new_input(3, vec![0., 1., 0.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        assert_almost_equals(
+            &*node.gradient(),
+            &new_tensor((2, 3), vec![1., 2., 3., 4., 5., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![1., 0., 3., 4., 0., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![2., 0., 6., 8., 0., 12.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![1., 0., 3., 4., 0., 6.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let node = MaskedFillBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            new_input(3, vec![0., 1., 0.]),
+        );
+
+        let output = "MaskedFillBackward { gradient: Some([[0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0]], shape=[2, 3], strides=[3, 1], layout=Cc (0x5), const ndim=2), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let node = MaskedFillBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            new_input(3, vec![0., 1., 0.]),
+        );
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // MaskedFillBackward
+        let node = MaskedFillBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            new_input(3, vec![0., 1., 0.]),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
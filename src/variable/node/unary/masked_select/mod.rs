@@ -0,0 +1,284 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite, Summary,
+    Tensor,
+};
+use ndarray::{Dimension, Ix1};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ MaskedSelect ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct MaskedSelect<T: ?Sized, M: ?Sized>
+where
+    T: Data,
+    M: Data,
+{
+    operand: Rc<T>,
+    mask: Rc<M>,
+    data: RefCell<Tensor<Ix1>>,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized, M: ?Sized> MaskedSelect<T, M>
+where
+    T: Data,
+    M: Data,
+{
+    pub fn new(operand: Rc<T>, mask: Rc<M>) -> Self {
+        let len = count_selected(&mask.data(), &operand.data());
+
+        Self {
+            operand,
+            mask,
+            data: RefCell::new(Tensor::zeros(len)),
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized, M: ?Sized> Cache for MaskedSelect<T, M>
+where
+    T: Data,
+    M: Data,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized, M: ?Sized> Forward for MaskedSelect<T, M>
+where
+    T: Data,
+    M: Data,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let (mut data, operand_data, mask) = (
+            self.data.borrow_mut(),
+            self.operand.data(),
+            self.mask.data(),
+        );
+        check_selected(count_selected(&mask, &operand_data), data.len());
+
+        let mask = mask.broadcast(operand_data.raw_dim()).unwrap();
+        let selected = operand_data
+            .iter()
+            .zip(mask.iter())
+            .filter(|(_, mask_el)| **mask_el != 0.);
+        for (data_el, (operand_el, _)) in data.iter_mut().zip(selected) {
+            *data_el = *operand_el;
+        }
+    }
+}
+
+impl<T: ?Sized, M: ?Sized> Data for MaskedSelect<T, M>
+where
+    T: Data,
+    M: Data,
+{
+    type Dim = Ix1;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized, M: ?Sized> Debug for MaskedSelect<T, M>
+where
+    T: Data,
+    M: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MaskedSelect")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, M: ?Sized> Display for MaskedSelect<T, M>
+where
+    T: Data,
+    M: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ MaskedSelectBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct MaskedSelectBackward<T: ?Sized, M: ?Sized>
+where
+    T: Gradient,
+    M: Data,
+{
+    gradient: RefCell<Option<Tensor<Ix1>>>,
+    shape: Ix1,
+    overwrite: Cell<bool>,
+    operand: Rc<T>,
+    mask: Rc<M>,
+}
+
+impl<T: ?Sized, M: ?Sized> MaskedSelectBackward<T, M>
+where
+    T: Gradient,
+    M: Data,
+{
+    pub fn new(operand: Rc<T>, mask: Rc<M>) -> Self {
+        let shape = Ix1(count_selected(&mask.data(), &operand.gradient()));
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape))),
+            shape,
+            overwrite: Cell::new(true),
+            operand,
+            mask,
+        }
+    }
+}
+
+impl<T: ?Sized, M: ?Sized> Gradient for MaskedSelectBackward<T, M>
+where
+    T: Gradient,
+    M: Data,
+{
+    type Dim = Ix1;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized, M: ?Sized> Overwrite for MaskedSelectBackward<T, M>
+where
+    T: Gradient,
+    M: Data,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized, M: ?Sized> Backward for MaskedSelectBackward<T, M>
+where
+    T: Gradient,
+    M: Data,
+{
+    fn backward(&self) {
+        let (mut operand_gradient, gradient, mask) = (
+            self.operand.gradient_mut(),
+            self.gradient(),
+            self.mask.data(),
+        );
+        check_selected(count_selected(&mask, &operand_gradient), gradient.len());
+
+        // The gradient of the elements that weren't selected is zero.
+        if self.operand.can_overwrite() {
+            operand_gradient.fill(0.);
+            self.operand.set_overwrite(false);
+        }
+
+        let mask = mask.broadcast(operand_gradient.raw_dim()).unwrap();
+        let selected = operand_gradient
+            .iter_mut()
+            .zip(mask.iter())
+            .filter(|(_, mask_el)| **mask_el != 0.);
+        for ((operand_gradient_el, _), gradient_el) in selected.zip(gradient.iter()) {
+            *operand_gradient_el += gradient_el;
+        }
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape));
+    }
+}
+
+impl<T: ?Sized, M: ?Sized> Debug for MaskedSelectBackward<T, M>
+where
+    T: Gradient,
+    M: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MaskedSelectBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, M: ?Sized> Display for MaskedSelectBackward<T, M>
+where
+    T: Gradient,
+    M: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+/// Returns the number of elements of `tensor` selected by `mask`.
+///
+/// # Panics
+///
+/// If `mask` cannot be broadcast to the shape of `tensor`.
+fn count_selected<D: Dimension, E: Dimension>(mask: &Tensor<E>, tensor: &Tensor<D>) -> usize {
+    match mask.broadcast(tensor.raw_dim()) {
+        Some(mask) => mask.iter().filter(|mask_el| **mask_el != 0.).count(),
+        None => panic!(
+            "error: cannot broadcast a mask of shape {:?} to a variable of shape {:?}.",
+            mask.shape(),
+            tensor.shape()
+        ),
+    }
+}
+
+/// Checks that the mask still selects the number of elements it selected when the node was
+/// created, as that is the length of the result.
+fn check_selected(selected: usize, len: usize) {
+    assert_eq!(
+        selected, len,
+        "error: the mask selects {} elements but the result has length {}.",
+        selected, len
+    );
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
@@ -0,0 +1,262 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, MaskedSelect, MaskedSelectBackward, Overwrite, Tensor,
+};
+
+mod forward {
+    use super::{
+        assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, MaskedSelect, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let mask = new_input((2, 3), vec![1., 0., 1., 0., 0., 1.]);
+        let node = MaskedSelect::new(input, mask);
+
+        assert_eq!(*node.data(), Tensor::from_elem(3, 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem(3, 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let mask = new_input((2, 3), vec![1., 0., 1., 0., 0., 1.]);
+        let node = MaskedSelect::new(input, mask);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: cannot broadcast a mask of shape [2] to a variable of shape [2, 3]."
+    )]
+    fn fail() {
+        MaskedSelect::new(
+            new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]),
+            new_input(2, vec![0., 1.]),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "error: the mask selects 4 elements but the result has length 3.")]
+    fn fail_changed_mask() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let mask = new_input((2, 3), vec![1., 0., 1., 0., 0., 1.]);
+        let node = MaskedSelect::new(input, mask.clone());
+
+        *mask.data_mut() = new_tensor((2, 3), vec![1., 1., 1., 0., 0., 1.]);
+        node.forward();
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let mask = new_input((2, 3), vec![1., 0., 1., 0., 0., 1.]);
+        let node = MaskedSelect::new(input.clone(), mask);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor(3, vec![1., 3., 6.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(
+            &*input.data(),
+            &new_tensor((2, 3), vec![2., 3., 4., 5., 6., 7.]),
+        );
+
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor(3, vec![1., 3., 6.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor(3, vec![2., 4., 7.]));
+    }
+
+    #[test]
+    fn forward_broadcast() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let mask = new_input(3, vec![1., 0., 1.]);
+        let node = MaskedSelect::new(input, mask);
+
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor(4, vec![1., 3., 4., 6.]));
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let mask = new_input(3, vec![1., 0., 1.]);
+        let node = MaskedSelect::new(input, mask);
+
+        let output = "MaskedSelect { data: [0.0, 0.0, 0.0, 0.0], shape=[4], strides=[1], layout=CFcf (0xf), const ndim=1, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let mask = new_input(3, vec![1., 0., 1.]);
+        let node = MaskedSelect::new(input, mask);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Gradient,
+        MaskedSelectBackward, Overwrite, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = MaskedSelectBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            new_input(3, vec![1., 0., 1.]),
+        );
+
+        assert_eq!(*node.gradient(), Tensor::from_elem(4, 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem(4, 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: cannot broadcast a mask of shape [3, 2] to a variable of shape [2, 3]."
+    )]
+    fn fail() {
+        MaskedSelectBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            new_input((3, 2), vec![0.; 6]),
+        );
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = MaskedSelectBackward::new(diff.clone(), new_input(3, vec![1., 0., 1.]));
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        // The stale gradient of the operand must be discarded on overwrite.
+        let diff = new_backward_input((2, 3), vec![5.; 6]);
+        let node = MaskedSelectBackward::new(
+            diff.clone(),
+            new_input((2, 3), vec![1., 0., 1., 0., 0., 1.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor(3, vec![1., 2., 3.]);
+        assert_almost_equals(&*node.gradient(), &new_tensor(3, vec![1., 2., 3.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![1., 0., 2., 0., 0., 3.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![2., 0., 4., 0., 0., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![1., 0., 2., 0., 0., 3.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let node = MaskedSelectBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            new_input(3, vec![1., 0., 1.]),
+        );
+
+        let output = "MaskedSelectBackward { gradient: Some([0.0, 0.0, 0.0, 0.0], shape=[4], strides=[1], layout=CFcf (0xf), const ndim=1), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let node = MaskedSelectBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            new_input(3, vec![1., 0., 1.]),
+        );
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // MaskedSelectBackward
+        let node = MaskedSelectBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            new_input(3, vec![1., 0., 1.]),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
@@ -3,15 +3,15 @@ use std::fmt::{Debug, Display};
 use std::ops::Range;
 use std::rc::Rc;
 
-use ndarray::{Dimension, IntoDimension, Ix, RemoveAxis, Slice, Zip};
 use crate::{Var, VarDiff};
+use ndarray::{Array, Dimension, IntoDimension, RemoveAxis, Slice, Zip};
 
-#[cfg(test)]
-use super::{new_backward_input, new_input, new_tensor};
 use super::{
-    Backward, Cache, Data, expect_tensor, expect_tensor_mut, Forward, Gradient, Overwrite,
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite, Summary,
     Tensor,
 };
+#[cfg(test)]
+use super::{new_backward_input, new_input, new_tensor};
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ MaxPooling Trait ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -19,8 +19,22 @@ use super::{
 
 pub trait MaxPooling<T> {
     type Output;
+    type Indices;
+
+    fn max_pool(
+        operand: T,
+        pool_shape: &[usize],
+        stride: &[usize],
+        padding: &[usize],
+    ) -> Self::Output;
 
-    fn max_pool(operand: T,pool_shape: &[usize], stride: &[usize]) -> Self::Output;
+    /// Same as `max_pool`, but also returns the positions of the maxima.
+    fn max_pool_with_indices(
+        operand: T,
+        pool_shape: &[usize],
+        stride: &[usize],
+        padding: &[usize],
+    ) -> (Self::Output, Self::Indices);
 }
 
 impl<T: ?Sized> MaxPooling<Self> for Var<T>
@@ -30,136 +44,229 @@ where
     <T::Dim as Dimension>::Smaller: RemoveAxis,
 {
     type Output = Var<MaxPool<T>>;
+    type Indices = MaxPoolIndices<T::Dim>;
 
-    fn max_pool(operand: Self, pool_shape: &[usize], stride: &[usize]) -> Self::Output {
-        Var::from(
-            MaxPool::new(
-                operand.node,
-                pool_shape,
-                stride,
-            ),
-            operand.past,
-        )
+    fn max_pool(
+        operand: Self,
+        pool_shape: &[usize],
+        stride: &[usize],
+        padding: &[usize],
+    ) -> Self::Output {
+        Self::max_pool_with_indices(operand, pool_shape, stride, padding).0
+    }
+
+    fn max_pool_with_indices(
+        operand: Self,
+        pool_shape: &[usize],
+        stride: &[usize],
+        padding: &[usize],
+    ) -> (Self::Output, Self::Indices) {
+        let node = MaxPool::new(operand.node, pool_shape, stride, padding);
+        let indices = MaxPoolIndices {
+            indices: node.indices.clone(),
+        };
+
+        (Var::from(node, operand.past), indices)
     }
 }
 
 impl<T: ?Sized, U: ?Sized> MaxPooling<Self> for VarDiff<U, T>
 where
     T: Gradient,
-    U: Data<Dim=T::Dim>,
+    U: Data<Dim = T::Dim>,
     <T as Gradient>::Dim: RemoveAxis,
     <<T as Gradient>::Dim as Dimension>::Smaller: RemoveAxis,
 {
     type Output = VarDiff<MaxPool<U>, MaxPoolBackward<T, U>>;
+    type Indices = MaxPoolIndices<T::Dim>;
 
-    fn max_pool(operand: Self, pool_shape: &[usize], stride: &[usize]) -> Self::Output {
-        let var = Var::max_pool(
-            operand.var,
-            pool_shape,
-            stride,
-        );
-        let node = MaxPoolBackward::new(
-            operand.node,
-            var.node.clone(),
+    fn max_pool(
+        operand: Self,
+        pool_shape: &[usize],
+        stride: &[usize],
+        padding: &[usize],
+    ) -> Self::Output {
+        Self::max_pool_with_indices(operand, pool_shape, stride, padding).0
+    }
+
+    fn max_pool_with_indices(
+        operand: Self,
+        pool_shape: &[usize],
+        stride: &[usize],
+        padding: &[usize],
+    ) -> (Self::Output, Self::Indices) {
+        let (var, indices) = Var::max_pool_with_indices(operand.var, pool_shape, stride, padding);
+        let node = MaxPoolBackward::new(operand.node, var.node.clone());
+
+        (VarDiff::from(node, operand.past, var), indices)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ MaxPoolIndices ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Positions of the maxima selected by a max pooling.
+///
+/// Each element is the index of the maximum of the corresponding pool, flattened in row-major
+/// order over the spatial axes of the input's channel. The indices are updated at each forward
+/// pass of the pooling.
+#[derive(Clone)]
+pub struct MaxPoolIndices<D: Dimension> {
+    indices: Rc<RefCell<Array<usize, D>>>,
+}
+
+impl<D: Dimension> MaxPoolIndices<D> {
+    /// Returns the indices of the maxima, the shape is the same as the pooling's output.
+    pub fn indices(&self) -> Ref<Array<usize, D>> {
+        self.indices.borrow()
+    }
+}
+
+impl<D: Dimension> Debug for MaxPoolIndices<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MaxPoolIndices")
+            .field("indices", &self.indices.borrow())
+            .finish()
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Utilities ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Checks the arguments of a pooling over an operand of shape *(N, C, ...)* and returns the
+/// shape of the result.
+pub(crate) fn pooling_shape<D: Dimension>(
+    shape: &D,
+    pool_shape: &[usize],
+    stride: &[usize],
+    padding: &[usize],
+) -> D {
+    let operand_ndims = shape.ndim() - 2;
+    assert_eq!(
+        operand_ndims,
+        stride.len(),
+        "error: invalid stride {:?} for {}d input.",
+        stride,
+        operand_ndims
+    );
+    assert_eq!(
+        operand_ndims,
+        pool_shape.len(),
+        "error: invalid pool shape {:?} for {}d input.",
+        pool_shape,
+        operand_ndims
+    );
+    assert_eq!(
+        operand_ndims,
+        padding.len(),
+        "error: invalid padding {:?} for {}d input.",
+        padding,
+        operand_ndims
+    );
+    pool_shape
+        .iter()
+        .zip(padding)
+        .for_each(|(pool_dim, padding_dim)| {
+            assert!(
+                2 * padding_dim <= *pool_dim,
+                "error: padding {:?} should be at most half of the pool shape {:?}.",
+                padding,
+                pool_shape
+            )
+        });
+
+    let mut pooled = shape.clone();
+    itertools::izip!(
+        pooled.slice_mut().iter_mut().skip(2),
+        pool_shape,
+        stride,
+        padding
+    )
+    .for_each(|(dim, pool_dim, stride_dim, padding_dim)| {
+        assert!(
+            *dim + 2 * padding_dim >= *pool_dim,
+            "Pool shape {:?} doesn't fit in input shape {:?}.",
             pool_shape,
-            stride
+            shape.slice()
         );
-        VarDiff::from(node, operand.past, var)
-    }
+        *dim = 1 + (*dim + 2 * padding_dim - pool_dim) / stride_dim
+    });
+
+    pooled
+}
+
+/// Returns, for each spatial axis, the range of the operand covered by the pool at `index`.
+/// The ranges exclude the padding.
+pub(crate) fn pool_window(
+    index: &[usize],
+    shape: &[usize],
+    pool_shape: &[usize],
+    stride: &[usize],
+    padding: &[usize],
+) -> Vec<Range<usize>> {
+    itertools::izip!(index, shape, pool_shape, stride, padding)
+        .map(|(index, len, pool_dim, stride_dim, padding_dim)| {
+            let first = index * stride_dim;
+            first.saturating_sub(*padding_dim)..(first + pool_dim - padding_dim).min(*len)
+        })
+        .collect()
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ MaxPool ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 pub struct MaxPool<T: ?Sized>
-    where
-        T: Data,
+where
+    T: Data,
 {
     operand: Rc<T>,
     data: RefCell<Tensor<T::Dim>>,
+    indices: Rc<RefCell<Array<usize, T::Dim>>>,
     pool_shape: Vec<usize>,
     stride: Vec<usize>,
+    padding: Vec<usize>,
     computed: Cell<bool>,
 }
 
 impl<T: ?Sized> MaxPool<T>
-    where
-        T: Data,
+where
+    T: Data,
 {
-    pub fn new(operand: Rc<T>, pool_shape: &[usize], stride: &[usize]) -> Self {
-        let operand_ndims = operand.data().ndim() - 2;
-        assert_eq!(
-            operand_ndims,
-            stride.len(),
-            "error: invalid stride {:?} for {}d input.",
-            stride,
-            operand_ndims
-        );
-        assert_eq!(
-            operand_ndims,
-            pool_shape.len(),
-            "error: invalid pool shape {:?} for {}d input.",
-            pool_shape,
-            operand_ndims
-        );
-        operand.data().shape()
-            .iter()
-            .skip(2)
-            .zip(pool_shape.iter())
-            .for_each(|(operand_dim, pool_dim)| {
-                assert!(
-                    operand_dim >= pool_dim,
-                    "Pool shape {:?} doesn't fit in input shape {:?}.",
-                    pool_shape,
-                    operand.data().shape()
-                )
-            });
-
-        let shape =
-            operand.data().shape().iter()
-                .enumerate()
-                .filter(|(i, _)| *i < 2)
-                .map(|(_, e)| *e)
-                .chain(
-                    Zip::from(&operand.data().shape().iter().skip(2).cloned().collect::<Vec<usize>>())
-                        .and(pool_shape)
-                        .and(stride)
-                        .map_collect(
-                            |op_dim: &usize, pool_dim: &usize, stride_dim: &usize| {
-                                1 + (op_dim - pool_dim) / stride_dim
-                            }
-                        )).collect::<Vec<usize>>();
-        let data =
-            RefCell::new(
-                Tensor::zeros(shape).into_dimensionality::<T::Dim>().unwrap()
-            );
+    pub fn new(operand: Rc<T>, pool_shape: &[usize], stride: &[usize], padding: &[usize]) -> Self {
+        let shape = pooling_shape(&operand.data().raw_dim(), pool_shape, stride, padding);
 
         Self {
             operand,
-            data,
+            data: RefCell::new(Tensor::zeros(shape.clone())),
+            indices: Rc::new(RefCell::new(Array::zeros(shape))),
             pool_shape: pool_shape.to_vec(),
             stride: stride.to_vec(),
+            padding: padding.to_vec(),
             computed: Cell::new(false),
         }
     }
 }
 
 impl<T: ?Sized> Cache for MaxPool<T>
-    where
-        T: Data,
+where
+    T: Data,
 {
     fn was_computed(&self) -> bool {
         self.computed.get()
     }
 
-    fn reset_computation(&self) { self.computed.set(false); }
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
 }
 
 impl<T: ?Sized> Forward for MaxPool<T>
-    where
-        T: Data,
-        <T as Data>::Dim: RemoveAxis,
-        <<T as Data>::Dim as Dimension>::Smaller: RemoveAxis,
+where
+    T: Data,
+    <T as Data>::Dim: RemoveAxis,
+    <<T as Data>::Dim as Dimension>::Smaller: RemoveAxis,
 {
     fn forward(&self) {
         if self.was_computed() {
@@ -167,48 +274,67 @@ impl<T: ?Sized> Forward for MaxPool<T>
         }
 
         self.computed.set(true);
-        let (
-            operand,
-            mut data,
-            pool_shape,
-            stride,
-        ) = (
+        let (operand, mut data, mut indices) = (
             self.operand.data(),
             self.data.borrow_mut(),
-            &self.pool_shape,
-            &self.stride,
+            self.indices.borrow_mut(),
         );
+        let (pool_shape, stride, padding) = (&self.pool_shape, &self.stride, &self.padding);
+        let channel_shape = &operand.shape()[2..];
+
+        Zip::from(data.outer_iter_mut())
+            .and(indices.outer_iter_mut())
+            .and(operand.outer_iter())
+            .for_each(|mut data_sample, mut indices_sample, op_sample| {
+                Zip::from(data_sample.outer_iter_mut())
+                    .and(indices_sample.outer_iter_mut())
+                    .and(op_sample.outer_iter())
+                    .for_each(|mut data_channel, mut indices_channel, op_channel| {
+                        data_channel
+                            .indexed_iter_mut()
+                            .zip(indices_channel.iter_mut())
+                            .for_each(|((i, y), index)| {
+                                let window = pool_window(
+                                    i.into_dimension().slice(),
+                                    channel_shape,
+                                    pool_shape,
+                                    stride,
+                                    padding,
+                                );
+
+                                // Ties are won by the first element of the pool.
+                                let (mut max, mut argmax) = (f32::NEG_INFINITY, None);
+                                op_channel
+                                    .slice_each_axis(|ax| {
+                                        Slice::from(window[ax.axis.index()].clone())
+                                    })
+                                    .indexed_iter()
+                                    .for_each(|(j, el)| {
+                                        if argmax.is_none() || *el > max {
+                                            max = *el;
+                                            argmax = Some(j.into_dimension());
+                                        }
+                                    });
 
-        data.outer_iter_mut().zip(operand.outer_iter()).for_each(|(mut data_sample, op_sample)| {
-            data_sample.outer_iter_mut().zip(op_sample.outer_iter()).for_each(|(mut data_channel, op_channel)| {
-                data_channel.indexed_iter_mut()
-                    .for_each(
-                        |(i, y)| {
-                            let i_pool: Vec<Range<usize>> = i.into_dimension()
-                                .as_array_view()
-                                .iter()
-                                .enumerate()
-                                .map(
-                                    |(dim, index): (usize, &Ix)| {
-                                        let first = index * stride[dim];
-                                        first..(first + pool_shape[dim])
-                                    }
-                                ).collect();
-                            *y = op_channel.slice_each_axis(
-                                |ax| Slice::from(i_pool[ax.axis.index()].to_owned())
-                            ).iter().fold(
-                                f32::NEG_INFINITY, |acc, elem| acc.max(*elem),
-                            )
-                        }
-                    )
+                                *y = max;
+                                *index = argmax
+                                    .unwrap()
+                                    .slice()
+                                    .iter()
+                                    .zip(&window)
+                                    .zip(channel_shape)
+                                    .fold(0, |flat, ((offset, range), len)| {
+                                        flat * len + range.start + offset
+                                    });
+                            })
+                    })
             })
-        })
     }
 }
 
 impl<T: ?Sized> Data for MaxPool<T>
-    where
-        T: Data,
+where
+    T: Data,
 {
     type Dim = T::Dim;
 
@@ -222,25 +348,26 @@ impl<T: ?Sized> Data for MaxPool<T>
 }
 
 impl<T: ?Sized> Debug for MaxPool<T>
-    where
-        T: Data,
+where
+    T: Data,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MaxPool")
-            .field("data", &self.data.borrow())
+            .field("data", &Summary(&self.data.borrow()))
             .field("pool_shape", &self.pool_shape)
             .field("stride", &self.stride)
+            .field("padding", &self.padding)
             .field("computed", &self.computed.get())
             .finish()
     }
 }
 
 impl<T: ?Sized> Display for MaxPool<T>
-    where
-        T: Data,
+where
+    T: Data,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", &self.data.borrow())
+        write!(f, "{}", Summary(&self.data.borrow()))
     }
 }
 
@@ -249,43 +376,24 @@ impl<T: ?Sized> Display for MaxPool<T>
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 pub struct MaxPoolBackward<T: ?Sized, U: ?Sized>
-    where
-        T: Gradient,
-        U: Data<Dim=T::Dim>,
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
 {
     gradient: RefCell<Option<Tensor<T::Dim>>>,
     shape: T::Dim,
     overwrite: Cell<bool>,
     diff_operand: Rc<T>,
     no_diff_operand: Rc<MaxPool<U>>,
-    pool_shape: Vec<usize>,
-    stride: Vec<usize>,
 }
 
 impl<T: ?Sized, U: ?Sized> MaxPoolBackward<T, U>
-    where
-        T: Gradient,
-        U: Data<Dim=T::Dim>,
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
 {
-    pub fn new(
-        diff_operand: Rc<T>,
-        no_diff_operand: Rc<MaxPool<U>>,
-        pool_shape: &[usize],
-        stride: &[usize],
-    ) -> Self {
-        let mut shape = T::Dim::zeros(diff_operand.gradient().ndim());
-        shape[0] = diff_operand.gradient().shape()[0];
-        shape[1] = diff_operand.gradient().shape()[1];
-        itertools::izip!(
-            shape.slice_mut().iter_mut().skip(2),
-            diff_operand.gradient().shape().iter().skip(2),
-            pool_shape,
-            stride,
-        ).for_each(
-            |(dim, op_dim, pool_dim, stride)| {
-                *dim = 1 + (op_dim - pool_dim) / stride
-            },
-        );
+    pub fn new(diff_operand: Rc<T>, no_diff_operand: Rc<MaxPool<U>>) -> Self {
+        let shape = no_diff_operand.data().raw_dim();
 
         Self {
             gradient: RefCell::new(Some(Tensor::zeros(shape.clone()))),
@@ -293,16 +401,14 @@ impl<T: ?Sized, U: ?Sized> MaxPoolBackward<T, U>
             overwrite: Cell::new(true),
             diff_operand,
             no_diff_operand,
-            pool_shape: pool_shape.to_vec(),
-            stride: stride.to_vec(),
         }
     }
 }
 
 impl<T: ?Sized, U: ?Sized> Gradient for MaxPoolBackward<T, U>
-    where
-        T: Gradient,
-        U: Data<Dim=T::Dim>,
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
 {
     type Dim = T::Dim;
 
@@ -316,9 +422,9 @@ impl<T: ?Sized, U: ?Sized> Gradient for MaxPoolBackward<T, U>
 }
 
 impl<T: ?Sized, U: ?Sized> Overwrite for MaxPoolBackward<T, U>
-    where
-        T: Gradient,
-        U: Data<Dim=T::Dim>,
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
 {
     fn can_overwrite(&self) -> bool {
         self.overwrite.get()
@@ -330,119 +436,46 @@ impl<T: ?Sized, U: ?Sized> Overwrite for MaxPoolBackward<T, U>
 }
 
 impl<T: ?Sized, U: ?Sized> Backward for MaxPoolBackward<T, U>
-    where
-        T: Gradient,
-        U: Data<Dim=T::Dim>,
-        <T as Gradient>::Dim: RemoveAxis,
-        <<T as Gradient>::Dim as Dimension>::Smaller: RemoveAxis,
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+    <T as Gradient>::Dim: RemoveAxis,
+    <<T as Gradient>::Dim as Dimension>::Smaller: RemoveAxis,
 {
     fn backward(&self) {
         let mut op_grad = self.diff_operand.gradient_mut();
-        let op = self.no_diff_operand.operand.data();
-        let op_data = self.no_diff_operand.data();
+        let indices = self.no_diff_operand.indices.borrow();
         let grad = self.gradient();
-        let (
-            pool_shape,
-            stride,
-        ) = (
-            &self.pool_shape,
-            &self.stride,
-        );
 
         if self.diff_operand.can_overwrite() {
-            Zip::from(grad.outer_iter())
-                .and(op_grad.outer_iter_mut())
-                .and(op_data.outer_iter())
-                .and(op.outer_iter())
-                .for_each(|grad_sample, mut op_grad_sample, op_data_sample, op_sample| {
+            op_grad.fill(0.);
+            self.diff_operand.set_overwrite(false);
+        }
+
+        // The gradient of each pool flows to its maximum, an element that is the maximum of
+        // several overlapping pools accumulates all of their gradients.
+        Zip::from(grad.outer_iter())
+            .and(indices.outer_iter())
+            .and(op_grad.outer_iter_mut())
+            .for_each(|grad_sample, indices_sample, mut op_grad_sample| {
                 Zip::from(grad_sample.outer_iter())
+                    .and(indices_sample.outer_iter())
                     .and(op_grad_sample.outer_iter_mut())
-                    .and(op_data_sample.outer_iter())
-                    .and(op_sample.outer_iter())
-                    .for_each(|grad_channel, mut op_grad_channel, op_data_channel, op_channel| {
-                    grad_channel.indexed_iter()
-                        .zip(op_data_channel.iter())
-                        .for_each(
-                            |((i, grad_el), op_data_el)| {
-                                let i_pool: Vec<Range<usize>> = i.into_dimension()
-                                    .as_array_view()
-                                    .iter()
-                                    .enumerate()
-                                    .map(
-                                        |(dim, index): (usize, &Ix)| {
-                                            let first = index * stride[dim];
-                                            first..(first + pool_shape[dim])
-                                        }
-                                    ).collect();
-                                let mut found_max = false;
-                                op_grad_channel.slice_each_axis_mut(
-                                    |ax| Slice::from(i_pool[ax.axis.index()].to_owned())
-                                ).iter_mut()
-                                    .zip(
-                                        op_channel.slice_each_axis(
-                                            |ax| Slice::from(i_pool[ax.axis.index()].to_owned())
-                                        )
-                                    ).for_each(
-                                    |(op_grad_el, op_el)| {
-                                        if !found_max {
-                                            let is_max = op_data_el == op_el;
-                                            *op_grad_el = is_max as usize as f32 * grad_el;
-                                            found_max = is_max
-                                        }
-                                    }
-                                )
-                            }
+                    .for_each(|grad_channel, indices_channel, mut op_grad_channel| {
+                        let channel_shape = op_grad_channel.raw_dim();
+                        grad_channel.iter().zip(indices_channel.iter()).for_each(
+                            |(grad_el, index)| {
+                                let mut position = channel_shape.clone();
+                                let mut flat = *index;
+                                for axis in (0..position.ndim()).rev() {
+                                    position[axis] = flat % channel_shape[axis];
+                                    flat /= channel_shape[axis];
+                                }
+                                op_grad_channel[position] += grad_el;
+                            },
                         )
-                })
+                    })
             });
-            self.diff_operand.set_overwrite(false);
-        } else {
-            Zip::from(grad.outer_iter())
-                .and(op_grad.outer_iter_mut())
-                .and(op_data.outer_iter())
-                .and(op.outer_iter())
-                .for_each(|grad_sample, mut op_grad_sample, op_data_sample, op_sample| {
-                    Zip::from(grad_sample.outer_iter())
-                        .and(op_grad_sample.outer_iter_mut())
-                        .and(op_data_sample.outer_iter())
-                        .and(op_sample.outer_iter())
-                        .for_each(|grad_channel, mut op_grad_channel, op_data_channel, op_channel| {
-                            grad_channel.indexed_iter()
-                                .zip(op_data_channel.iter())
-                                .for_each(
-                                    |((i, grad_el), op_data_el)| {
-                                        let i_pool: Vec<Range<usize>> = i.into_dimension()
-                                            .as_array_view()
-                                            .iter()
-                                            .enumerate()
-                                            .map(
-                                                |(dim, index): (usize, &Ix)| {
-                                                    let first = index * stride[dim];
-                                                    first..(first + pool_shape[dim])
-                                                }
-                                            ).collect();
-                                        let mut found_max = false;
-                                        op_grad_channel.slice_each_axis_mut(
-                                            |ax| Slice::from(i_pool[ax.axis.index()].to_owned())
-                                        ).iter_mut()
-                                            .zip(
-                                                op_channel.slice_each_axis(
-                                                    |ax| Slice::from(i_pool[ax.axis.index()].to_owned())
-                                                )
-                                            ).for_each(
-                                            |(op_grad_el, op_el)| {
-                                                if !found_max {
-                                                    let is_max = op_data_el == op_el;
-                                                    *op_grad_el += is_max as usize as f32 * grad_el;
-                                                    found_max = is_max
-                                                }
-                                            }
-                                        )
-                                    }
-                                )
-                        })
-                });
-        }
     }
 
     fn no_grad(&self) {
@@ -455,28 +488,28 @@ impl<T: ?Sized, U: ?Sized> Backward for MaxPoolBackward<T, U>
 }
 
 impl<T: ?Sized, U: ?Sized> Debug for MaxPoolBackward<T, U>
-    where
-        T: Gradient,
-        U: Data<Dim=T::Dim>,
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MaxPoolBackward")
-            .field("gradient", &self.gradient.borrow())
-            .field("pool_shape", &self.pool_shape)
-            .field("stride", &self.stride)
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("pool_shape", &self.no_diff_operand.pool_shape)
+            .field("stride", &self.no_diff_operand.stride)
             .field("overwrite", &self.overwrite.get())
             .finish()
     }
 }
 
 impl<T: ?Sized, U: ?Sized> Display for MaxPoolBackward<T, U>
-    where
-        T: Gradient,
-        U: Data<Dim=T::Dim>,
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
@@ -1,18 +1,15 @@
 use super::{
-    Backward, Cache, Data, Forward, Gradient, MaxPool,
-    MaxPoolBackward, new_backward_input, new_input, new_tensor, Overwrite, Rc, Tensor,
+    new_backward_input, new_input, new_tensor, Backward, Cache, Data, Forward, Gradient, MaxPool,
+    MaxPoolBackward, Overwrite, Rc, Tensor,
 };
 
 mod forward {
-    use super::{
-        Cache, Data, Forward, MaxPool, new_input, new_tensor,
-        Tensor,
-    };
+    use super::{new_input, new_tensor, Cache, Data, Forward, MaxPool, Tensor};
 
     #[test]
     fn creation() {
         let input = new_input((4, 4, 6, 5), vec![0.; 4 * 4 * 6 * 5]);
-        let node = MaxPool::new(input, &[2, 3], &[2, 1]);
+        let node = MaxPool::new(input, &[2, 3], &[2, 1], &[0, 0]);
 
         assert_eq!(*node.data(), Tensor::zeros((4, 4, 3, 3)));
         assert_eq!(*node.data_mut(), Tensor::zeros((4, 4, 3, 3)));
@@ -20,27 +17,40 @@ mod forward {
     }
 
     #[test]
-    #[should_panic(
-    expected = "error: invalid pool shape [2, 3, 5] for 2d input."
-    )]
+    fn creation_padded() {
+        let input = new_input((4, 4, 6, 5), vec![0.; 4 * 4 * 6 * 5]);
+        let node = MaxPool::new(input, &[2, 3], &[2, 1], &[1, 1]);
+
+        assert_eq!(*node.data(), Tensor::zeros((4, 4, 4, 5)));
+    }
+
+    #[test]
+    #[should_panic(expected = "error: invalid pool shape [2, 3, 5] for 2d input.")]
     fn creation_dims_dont_match() {
         let input = new_input((4, 4, 6, 5), vec![0.; 4 * 4 * 6 * 5]);
-        MaxPool::new(input, &[2, 3, 5], &[2, 1]);
+        MaxPool::new(input, &[2, 3, 5], &[2, 1], &[0, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Pool shape [2, 6] doesn't fit in input shape [4, 4, 6, 5].")]
+    fn creation_pool_doesnt_fit() {
+        let input = new_input((4, 4, 6, 5), vec![0.; 4 * 4 * 6 * 5]);
+        MaxPool::new(input, &[2, 6], &[2, 1], &[0, 0]);
     }
 
     #[test]
     #[should_panic(
-    expected = "Pool shape [2, 6] doesn't fit in input shape [4, 4, 6, 5]."
+        expected = "error: padding [1, 2] should be at most half of the pool shape [2, 3]."
     )]
-    fn creation_pool_doesnt_fit() {
+    fn creation_padding_too_large() {
         let input = new_input((4, 4, 6, 5), vec![0.; 4 * 4 * 6 * 5]);
-        MaxPool::new(input, &[2, 6], &[2, 1]);
+        MaxPool::new(input, &[2, 3], &[2, 1], &[1, 2]);
     }
 
     #[test]
     fn computation_was_computed_transition() {
         let input = new_input((4, 4, 6, 5), vec![0.; 4 * 4 * 6 * 5]);
-        let node = MaxPool::new(input, &[2, 3], &[2, 1]);
+        let node = MaxPool::new(input, &[2, 3], &[2, 1], &[0, 0]);
 
         node.forward();
         assert!(node.was_computed());
@@ -57,19 +67,63 @@ mod forward {
 
     #[test]
     fn forward() {
-        let input = new_input((2, 2, 2, 2), vec![1., 4., 2., 4., 5., 4., 12., 4., 7., 6., 8., 4., 11., 7., 7., 6.]);
-        let node = MaxPool::new(input, &[2, 2], &[1, 1]);
+        let input = new_input(
+            (2, 2, 2, 2),
+            vec![
+                1., 4., 2., 4., 5., 4., 12., 4., 7., 6., 8., 4., 11., 7., 7., 6.,
+            ],
+        );
+        let node = MaxPool::new(input, &[2, 2], &[1, 1], &[0, 0]);
 
         node.forward();
-        assert_eq!(*node.data(), new_tensor((2, 2, 1, 1), vec![4., 12., 8., 11.]));
+        assert_eq!(
+            *node.data(),
+            new_tensor((2, 2, 1, 1), vec![4., 12., 8., 11.])
+        );
+        assert_eq!(
+            *node.indices.borrow(),
+            ndarray::Array::from_shape_vec((2, 2, 1, 1), vec![1, 2, 2, 0]).unwrap()
+        );
+    }
+
+    #[test]
+    fn forward_padded() {
+        let input = new_input((1, 1, 2, 2), vec![-4., -3., -2., -1.]);
+        let node = MaxPool::new(input, &[2, 2], &[1, 1], &[1, 1]);
+
+        // The padding never wins, even against negative values.
+        node.forward();
+        assert_eq!(
+            *node.data(),
+            new_tensor(
+                (1, 1, 3, 3),
+                vec![-4., -3., -3., -2., -1., -1., -2., -1., -1.]
+            )
+        );
+        assert_eq!(
+            *node.indices.borrow(),
+            ndarray::Array::from_shape_vec((1, 1, 3, 3), vec![0, 1, 1, 2, 3, 3, 2, 3, 3]).unwrap()
+        );
+    }
+
+    #[test]
+    fn forward_ties() {
+        let input = new_input((1, 1, 1, 4), vec![5., 5., 5., 5.]);
+        let node = MaxPool::new(input, &[1, 2], &[1, 2], &[0, 0]);
+
+        node.forward();
+        assert_eq!(
+            *node.indices.borrow(),
+            ndarray::Array::from_shape_vec((1, 1, 1, 2), vec![0, 2]).unwrap()
+        );
     }
 
     #[test]
     fn debug() {
         let input = new_input((2, 2, 2), vec![1., 2., 3., 4., 5., 6., 7., 8.]);
-        let node = MaxPool::new(input, &[2], &[1]);
+        let node = MaxPool::new(input, &[2], &[1], &[0]);
 
-        let output = "MaxPool { data: [[[0.0],\n  [0.0]],\n\n [[0.0],\n  [0.0]]], shape=[2, 2, 1], strides=[2, 1, 1], layout=Cc (0x5), const ndim=3, pool_shape: [2], stride: [1], computed: false }";
+        let output = "MaxPool { data: [[[0.0],\n  [0.0]],\n\n [[0.0],\n  [0.0]]], shape=[2, 2, 1], strides=[2, 1, 1], layout=Cc (0x5), const ndim=3, pool_shape: [2], stride: [1], padding: [0], computed: false }";
 
         assert_eq!(output, format!("{:?}", node));
     }
@@ -77,18 +131,16 @@ mod forward {
     #[test]
     fn display() {
         let input = new_input((2, 2, 2), vec![1., 2., 3., 4., 5., 6., 7., 8.]);
-        let node = MaxPool::new(input, &[2], &[11]);
+        let node = MaxPool::new(input, &[2], &[11], &[0]);
 
         assert_eq!(format!("{}", node.data()), format!("{}", node));
     }
 }
 
 mod backward {
-    use crate::Forward;
-
     use super::{
-        Backward, Gradient, MaxPool, MaxPoolBackward, new_backward_input,
-        new_input, new_tensor, Overwrite, Rc, Tensor,
+        new_backward_input, new_input, new_tensor, Backward, Forward, Gradient, MaxPool,
+        MaxPoolBackward, Overwrite, Rc, Tensor,
     };
 
     #[test]
@@ -99,9 +151,8 @@ mod backward {
                 new_input((4, 4, 6, 5), vec![0.; 4 * 4 * 6 * 5]),
                 &[2, 2],
                 &[2, 2],
+                &[0, 0],
             )),
-            &[2, 2],
-            &[2, 2],
         );
 
         assert_eq!(*node.gradient(), Tensor::from_elem((4, 4, 3, 2), 0.));
@@ -118,9 +169,8 @@ mod backward {
                 new_input((4, 4, 6, 5), vec![0.; 4 * 4 * 6 * 5]),
                 &[2, 2],
                 &[2, 2],
+                &[0, 0],
             )),
-            &[2, 2],
-            &[2, 2],
         );
 
         node.backward();
@@ -168,34 +218,103 @@ mod backward {
     fn backward() {
         let diff = new_backward_input((2, 2, 2, 2), vec![1.; 16]);
         let no_diff = Rc::new(MaxPool::new(
-            new_input((2, 2, 2, 2), vec![1., 4., 2., 4., 5., 4., 12., 4., 7., 6., 8., 4., 11., 7., 7., 6.]),
+            new_input(
+                (2, 2, 2, 2),
+                vec![
+                    1., 4., 2., 4., 5., 4., 12., 4., 7., 6., 8., 4., 11., 7., 7., 6.,
+                ],
+            ),
             &[2, 2],
             &[1, 1],
+            &[0, 0],
         ));
         no_diff.forward();
-        let node = MaxPoolBackward::new(
-            diff.clone(),
-            no_diff,
-            &[2, 2],
-            &[2, 2],
-        );
+        let node = MaxPoolBackward::new(diff.clone(), no_diff);
 
         // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
         *node.gradient_mut() = new_tensor((2, 2, 1, 1), vec![2., 3., 5., 4.]);
-        assert_eq!(*node.gradient(), new_tensor((2, 2, 1, 1), vec![2., 3., 5., 4.]));
+        assert_eq!(
+            *node.gradient(),
+            new_tensor((2, 2, 1, 1), vec![2., 3., 5., 4.])
+        );
 
         // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Overwrite ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
         node.backward();
-        assert_eq!(*diff.gradient(), new_tensor((2, 2, 2, 2), vec![0., 2., 0., 0., 0., 0., 3., 0., 0., 0., 5., 0., 4., 0., 0., 0.]));
+        assert_eq!(
+            *diff.gradient(),
+            new_tensor(
+                (2, 2, 2, 2),
+                vec![0., 2., 0., 0., 0., 0., 3., 0., 0., 0., 5., 0., 4., 0., 0., 0.]
+            )
+        );
 
         // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Accumulation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
         node.backward();
-        assert_eq!(*diff.gradient(), new_tensor((2, 2, 2, 2), vec![0., 4., 0., 0., 0., 0., 6., 0., 0., 0., 10., 0., 8., 0., 0., 0.]));
+        assert_eq!(
+            *diff.gradient(),
+            new_tensor(
+                (2, 2, 2, 2),
+                vec![0., 4., 0., 0., 0., 0., 6., 0., 0., 0., 10., 0., 8., 0., 0., 0.]
+            )
+        );
 
         // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Overwrite ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
         diff.set_overwrite(true);
         node.backward();
-        assert_eq!(*diff.gradient(), new_tensor((2, 2, 2, 2), vec![0., 2., 0., 0., 0., 0., 3., 0., 0., 0., 5., 0., 4., 0., 0., 0.]));
+        assert_eq!(
+            *diff.gradient(),
+            new_tensor(
+                (2, 2, 2, 2),
+                vec![0., 2., 0., 0., 0., 0., 3., 0., 0., 0., 5., 0., 4., 0., 0., 0.]
+            )
+        );
+    }
+
+    #[test]
+    fn backward_overlapping() {
+        let diff = new_backward_input((1, 1, 3, 3), vec![1.; 9]);
+        let no_diff = Rc::new(MaxPool::new(
+            new_input((1, 1, 3, 3), vec![1., 2., 3., 4., 9., 5., 6., 7., 8.]),
+            &[2, 2],
+            &[1, 1],
+            &[0, 0],
+        ));
+        no_diff.forward();
+        let node = MaxPoolBackward::new(diff.clone(), no_diff);
+        *node.gradient_mut() = new_tensor((1, 1, 2, 2), vec![1., 2., 3., 4.]);
+
+        // The center is the maximum of all the pools and gathers all of their gradients.
+        node.backward();
+        assert_eq!(
+            *diff.gradient(),
+            new_tensor((1, 1, 3, 3), vec![0., 0., 0., 0., 10., 0., 0., 0., 0.])
+        );
+
+        node.backward();
+        assert_eq!(
+            *diff.gradient(),
+            new_tensor((1, 1, 3, 3), vec![0., 0., 0., 0., 20., 0., 0., 0., 0.])
+        );
+    }
+
+    #[test]
+    fn backward_padded() {
+        let diff = new_backward_input((1, 1, 2, 2), vec![0.; 4]);
+        let no_diff = Rc::new(MaxPool::new(
+            new_input((1, 1, 2, 2), vec![-4., -3., -2., -1.]),
+            &[2, 2],
+            &[1, 1],
+            &[1, 1],
+        ));
+        no_diff.forward();
+        let node = MaxPoolBackward::new(diff.clone(), no_diff);
+        *node.gradient_mut() = new_tensor((1, 1, 3, 3), vec![1.; 9]);
+
+        node.backward();
+        assert_eq!(
+            *diff.gradient(),
+            new_tensor((1, 1, 2, 2), vec![1., 2., 2., 4.])
+        );
     }
 
     #[test]
@@ -205,14 +324,9 @@ mod backward {
             new_input((2, 2, 2), vec![1., 2., 3., 4., 5., 6., 7., 8.]),
             &[2],
             &[1],
+            &[0],
         ));
-        let node = MaxPoolBackward::new(
-            diff,
-            no_diff,
-            &[2],
-            &[1],
-        );
-
+        let node = MaxPoolBackward::new(diff, no_diff);
 
         let output = "MaxPoolBackward { gradient: Some([[[0.0],\n  [0.0]],\n\n [[0.0],\n  [0.0]]], shape=[2, 2, 1], strides=[2, 1, 1], layout=Cc (0x5), const ndim=3), pool_shape: [2], stride: [1], overwrite: true }";
 
@@ -226,13 +340,9 @@ mod backward {
             new_input((2, 2, 2), vec![1., 2., 3., 4., 5., 6., 7., 8.]),
             &[2],
             &[1],
+            &[0],
         ));
-        let node = MaxPoolBackward::new(
-            diff,
-            no_diff,
-            &[2],
-            &[1],
-        );
+        let node = MaxPoolBackward::new(diff, no_diff);
 
         assert_eq!(format!("{}", node.gradient()), format!("{}", node));
     }
@@ -244,14 +354,9 @@ mod backward {
             new_input((2, 2, 2), vec![1., 2., 3., 4., 5., 6., 7., 8.]),
             &[2],
             &[1],
+            &[0],
         ));
-        let node = MaxPoolBackward::new(
-            diff,
-            no_diff,
-            &[2],
-            &[1],
-        );
-
+        let node = MaxPoolBackward::new(diff, no_diff);
 
         node.no_grad();
         assert!(node.gradient.borrow().is_none());
@@ -259,4 +364,4 @@ mod backward {
         node.with_grad();
         assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,212 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, MeanAxes, MeanAxesBackward, Overwrite, Tensor,
+};
+
+mod forward {
+    use super::{
+        assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, MeanAxes, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((2, 3, 2), (1..=12).map(|el| el as f32).collect());
+        let node = MeanAxes::new(input, &[1, 2]);
+
+        assert_eq!(*node.data(), Tensor::from_elem((2, 1, 1), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((2, 1, 1), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((2, 3, 2), (1..=12).map(|el| el as f32).collect());
+        let node = MeanAxes::new(input, &[1, 2]);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((2, 3, 2), (1..=12).map(|el| el as f32).collect());
+        let node = MeanAxes::new(input.clone(), &[1, 2]);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((2, 1, 1), vec![3.5, 9.5]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((2, 1, 1), vec![3.5, 9.5]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((2, 1, 1), vec![4.5, 10.5]));
+    }
+
+    #[test]
+    fn forward_single_axis() {
+        let input = new_input((2, 3, 2), (1..=12).map(|el| el as f32).collect());
+        let node = MeanAxes::new(input, &[1]);
+
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((2, 1, 2), vec![3., 4., 9., 10.]));
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((2, 2), vec![1., 2., 3., 4.]);
+        let node = MeanAxes::new(input, &[1]);
+
+        let output = "MeanAxes { data: [[0.0],\n [0.0]], shape=[2, 1], strides=[1, 1], layout=CFcf (0xf), const ndim=2, axes: [1], computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((2, 2), vec![1., 2., 3., 4.]);
+        let node = MeanAxes::new(input, &[1]);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_tensor, Backward, Gradient, MeanAxesBackward,
+        Overwrite, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = MeanAxesBackward::new(new_backward_input((2, 3, 2), vec![0.; 12]), &[1, 2]);
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((2, 1, 1), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((2, 1, 1), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((2, 3, 2), vec![0.; 12]);
+        let node = MeanAxesBackward::new(diff.clone(), &[1, 2]);
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        let diff = new_backward_input((2, 3, 2), vec![0.; 12]);
+        let node = MeanAxesBackward::new(diff.clone(), &[1, 2]);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((2, 1, 1), vec![3., 6.]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((2, 1, 1), vec![3., 6.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 1., 1., 1., 1., 1., 1.],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![1., 1., 1., 1., 1., 1., 2., 2., 2., 2., 2., 2.],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 1., 1., 1., 1., 1., 1.],
+            ),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let node = MeanAxesBackward::new(new_backward_input((2, 2), vec![0.; 4]), &[1]);
+
+        let output = "MeanAxesBackward { gradient: Some([[0.0],\n [0.0]], shape=[2, 1], strides=[1, 1], layout=CFcf (0xf), const ndim=2), axes: [1], overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let node = MeanAxesBackward::new(new_backward_input((2, 2), vec![0.; 4]), &[1]);
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // MeanAxesBackward
+        let node = MeanAxesBackward::new(new_backward_input((2, 2), vec![0.; 4]), &[1]);
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
@@ -0,0 +1,304 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, Mish, MishBackward, Overwrite, Rc, Tensor,
+};
+
+mod forward {
+    use super::{assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, Mish, Tensor};
+
+    #[test]
+    fn creation() {
+        let input = new_input((3, 3), vec![-3., -2., -1., 0., 1., 2., 3., 4., 5.]);
+        let node = Mish::new(input);
+
+        assert_eq!(*node.data(), Tensor::from_elem((3, 3), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((3, 3), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((3, 3), vec![-3., -2., -1., 0., 1., 2., 3., 4., 5.]);
+        let node = Mish::new(input);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((3, 3), vec![-3., -2., -1., 0., 1., 2., 3., 4., 5.]);
+        let node = Mish::new(input.clone());
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (3, 3),
+                vec![
+                    -0.145647, -0.252501, -0.303401, 0., 0.865098, 1.943959, 2.986535, 3.997413,
+                    4.999552,
+                ],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(
+            &*input.data(),
+            &new_tensor((3, 3), vec![-2., -1., 0., 1., 2., 3., 4., 5., 6.]),
+        );
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (3, 3),
+                vec![
+                    -0.145647, -0.252501, -0.303401, 0., 0.865098, 1.943959, 2.986535, 3.997413,
+                    4.999552,
+                ],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (3, 3),
+                vec![
+                    -0.252501, -0.303401, 0., 0.865098, 1.943959, 2.986535, 3.997413, 4.999552,
+                    5.999927,
+                ],
+            ),
+        );
+    }
+
+    #[test]
+    fn forward_range() {
+        let input = new_input(8, vec![-10., -5., -1.1924, 0., 0.5, 10., 20., 100.]);
+        let node = Mish::new(input);
+        node.forward();
+
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                8,
+                vec![
+                    -0.000454, -0.033576, -0.308843, 0., 0.375245, 10., 20., 100.,
+                ],
+            ),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((3, 3), vec![1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+        let node = Mish::new(input);
+
+        let output = "Mish { data: [[0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0]], shape=[3, 3], strides=[3, 1], layout=Cc (0x5), const ndim=2, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((3, 3), vec![1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+        let node = Mish::new(input);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Data, Forward,
+        Gradient, Mish, MishBackward, Overwrite, Rc, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = MishBackward::new(
+            new_backward_input(3, vec![0.; 3]),
+            Rc::new(Mish::new(new_input(3, vec![1., 2., 3.]))),
+        );
+
+        assert_eq!(*node.gradient(), Tensor::from_elem(3, 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem(3, 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input(3, vec![0.; 3]);
+        let mish = Rc::new(Mish::new(new_input(3, vec![1., 2., 3.])));
+        mish.forward();
+        let node = MishBackward::new(diff.clone(), mish);
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        let diff = new_backward_input(3, vec![0.; 3]);
+        let mish = Rc::new(Mish::new(new_input(3, vec![1., 2., 3.])));
+        mish.forward();
+        let node = MishBackward::new(diff.clone(), mish);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor(3, vec![1.; 3]);
+        assert_almost_equals(&*node.gradient(), &new_tensor(3, vec![1.; 3]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(3, vec![1.049036, 1.069318, 1.021107]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(3, vec![2.098072, 2.138636, 2.042214]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(3, vec![1.049036, 1.069318, 1.021107]),
+        );
+    }
+
+    #[test]
+    fn backward_smooth() {
+        let data: Vec<f32> = (-120..=120).map(|el| el as f32 * 0.05).collect();
+        let diff = new_backward_input(data.len(), vec![0.; data.len()]);
+        let mish = Rc::new(Mish::new(new_input(data.len(), data.clone())));
+        mish.forward();
+        let node = MishBackward::new(diff.clone(), mish);
+        *node.gradient_mut() = new_tensor(data.len(), vec![1.; data.len()]);
+        node.backward();
+
+        let gradient = diff.gradient();
+        assert!((gradient[120] - 0.6).abs() < 1e-6);
+        // Unlike the ReLU, the gradient doesn't jump and only vanishes at the minimum of the
+        // function, near x = -1.19.
+        assert!(gradient
+            .iter()
+            .zip(gradient.iter().skip(1))
+            .all(|(current, next)| (next - current).abs() < 0.05));
+        assert!(data
+            .iter()
+            .zip(gradient.iter())
+            .filter(|(x, _)| (*x + 1.19).abs() > 0.05)
+            .all(|(_, gradient)| gradient.abs() > 1e-3));
+    }
+
+    #[test]
+    fn backward_numeric() {
+        let data = vec![-3., -1.5, -0.5, 0., 0.25, 1., 2.5];
+        let diff = new_backward_input(7, vec![0.; 7]);
+        let mish = Rc::new(Mish::new(new_input(7, data.clone())));
+        mish.forward();
+        let node = MishBackward::new(diff.clone(), mish);
+        *node.gradient_mut() = new_tensor(7, vec![1.; 7]);
+        node.backward();
+
+        // Central finite differences of the forward computation.
+        let h = 1e-2;
+        let mish = |x: f32| {
+            let node = Mish::new(new_input(1, vec![x]));
+            node.forward();
+            let value = node.data()[0];
+            value
+        };
+        let numeric = data.iter().map(|&x| (mish(x + h) - mish(x - h)) / (2. * h));
+
+        assert!(diff
+            .gradient()
+            .iter()
+            .zip(numeric)
+            .all(|(analytic, numeric)| (analytic - numeric).abs() < 1e-2));
+    }
+
+    #[test]
+    fn debug() {
+        let diff = new_backward_input(3, vec![0.; 3]);
+        let node = MishBackward::new(diff, Rc::new(Mish::new(new_input(3, vec![1., 2., 3.]))));
+
+        let output = "MishBackward { gradient: Some([0.0, 0.0, 0.0], shape=[3], strides=[1], layout=CFcf (0xf), const ndim=1), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let diff = new_backward_input(3, vec![0.; 3]);
+        let node = MishBackward::new(diff, Rc::new(Mish::new(new_input(3, vec![1., 2., 3.]))));
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // MishBackward
+        let node = MishBackward::new(
+            new_backward_input((3, 3), vec![0.; 9]),
+            Rc::new(Mish::new(new_input((3, 3), vec![0.; 9]))),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
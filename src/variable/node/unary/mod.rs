@@ -1,48 +1,112 @@
+mod adaptive_pool;
+mod avg_pool;
+mod batch_norm;
 mod chunk;
+mod cumprod;
+mod cumsum;
+mod diag_embed;
+mod diagonal;
 mod dropout;
+mod embedding;
 mod exp;
+mod flip;
+mod gather;
+mod gelu;
+mod index_select;
+mod layer_norm;
 mod leaky_relu;
+mod log_sum_exp;
 mod logn;
 mod logsoftmax;
+mod masked_fill;
+mod masked_select;
+mod max_pool;
 mod mean;
+mod mean_axes;
+mod mish;
+mod narrow;
 mod negation;
+mod norm;
+mod pad;
+mod permute;
 mod power;
 mod relu;
+mod repeat;
+mod reshape;
+mod roll;
 mod sigmoid;
 mod softmax;
 mod softplus;
+mod sparse_cross_entropy;
+mod split;
 mod sqrt;
+mod squeeze;
 mod sum;
+mod swish;
 mod tanh;
+mod trace;
 mod transpose;
+mod triangular;
 mod unsqueeze;
-mod max_pool;
 
 use super::{
     expect_tensor, expect_tensor_mut, push_gradient, Backward, Cache, Data, Eval, Forward,
-    Gradient, Overwrite, Tensor,
+    Gradient, Input, Overwrite, Summary, Tensor,
 };
 
 #[cfg(test)]
 use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
 
+pub(crate) use batch_norm::{BatchNorm, BatchNormBackward};
 pub(crate) use chunk::{Chunk, ChunkBackward};
+pub(crate) use cumprod::{CumProd, CumProdBackward};
+pub(crate) use cumsum::{CumSum, CumSumBackward};
+pub(crate) use diag_embed::{DiagEmbed, DiagEmbedBackward};
+pub(crate) use diagonal::{diagonal, Diagonal, DiagonalBackward};
 pub(crate) use dropout::{Dropout, DropoutBackward};
+pub(crate) use embedding::{Embedding, EmbeddingBackward};
 pub(crate) use exp::{Exp, ExpBackward};
+pub(crate) use flip::{Flip, FlipBackward};
+pub(crate) use gather::{Gather, GatherBackward};
+pub(crate) use gelu::{GELUBackward, GELU};
+pub(crate) use index_select::{IndexSelect, IndexSelectBackward};
+pub(crate) use layer_norm::{LayerNorm, LayerNormBackward};
 pub(crate) use leaky_relu::{LeakyReLU, LeakyReLUBackward};
+pub(crate) use log_sum_exp::{LogSumExp, LogSumExpBackward};
 pub(crate) use logn::{Logn, LognBackward};
 pub(crate) use logsoftmax::{LogSoftmax, LogSoftmaxBackward};
+pub(crate) use masked_fill::{MaskedFill, MaskedFillBackward};
+pub(crate) use masked_select::{MaskedSelect, MaskedSelectBackward};
+pub(crate) use max_pool::{pool_window, pooling_shape};
 pub(crate) use mean::{Mean, MeanBackward};
+pub(crate) use mean_axes::{MeanAxes, MeanAxesBackward};
+pub(crate) use mish::{Mish, MishBackward};
+pub(crate) use narrow::{Narrow, NarrowBackward};
 pub(crate) use negation::{Negation, NegationBackward};
+pub(crate) use norm::{Norm, NormBackward};
+pub(crate) use pad::{Pad, PadBackward};
+pub(crate) use permute::{swap_permutation, Permute, PermuteBackward};
 pub(crate) use power::{Power, PowerBackward};
 pub(crate) use relu::{ReLU, ReLUBackward};
+pub(crate) use repeat::{Repeat, RepeatBackward};
+pub(crate) use reshape::{flatten_shape, Reshape, ReshapeBackward};
+pub(crate) use roll::{Roll, RollBackward};
 pub(crate) use sigmoid::{Sigmoid, SigmoidBackward};
 pub(crate) use softmax::{Softmax, SoftmaxBackward};
 pub(crate) use softplus::{SoftPlus, SoftPlusBackward};
+pub(crate) use sparse_cross_entropy::{SparseCrossEntropyLoss, SparseCrossEntropyLossBackward};
+pub(crate) use split::{chunk_sizes, Split, SplitBackward};
 pub(crate) use sqrt::{Sqrt, SqrtBackward};
+pub(crate) use squeeze::{Squeeze, SqueezeBackward};
 pub(crate) use sum::{Sum, SumBackward};
+pub(crate) use swish::{SiLU, SiLUBackward, Swish, SwishBackward};
 pub(crate) use tanh::{TanH, TanHBackward};
+pub(crate) use trace::{Trace, TraceBackward};
 pub(crate) use transpose::{Transpose, TransposeBackward};
+pub(crate) use triangular::{Triangle, Triangular, TriangularBackward};
 pub(crate) use unsqueeze::{Unsqueeze, UnsqueezeBackward};
 
-pub use max_pool::MaxPooling;
+pub use adaptive_pool::{AdaptiveAvgPooling, AdaptiveMaxPooling};
+pub use avg_pool::AvgPooling;
+pub use max_pool::{MaxPoolIndices, MaxPooling};
+pub use pad::PadMode;
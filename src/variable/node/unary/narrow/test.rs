@@ -0,0 +1,248 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, Narrow, NarrowBackward, Overwrite, Tensor,
+};
+
+mod forward {
+    use super::{
+        assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, Narrow, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((2, 3, 2), (1..=12).map(|el| el as f32).collect());
+        let node = Narrow::new(input, 1, 1, 2);
+
+        assert_eq!(*node.data(), Tensor::from_elem((2, 2, 2), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((2, 2, 2), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((2, 3, 2), (1..=12).map(|el| el as f32).collect());
+        let node = Narrow::new(input, 1, 1, 2);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: cannot narrow axis 1 of length 3 to 2 elements starting at 2."
+    )]
+    fn fail() {
+        Narrow::new(
+            new_input((2, 3, 2), (1..=12).map(|el| el as f32).collect()),
+            1,
+            2,
+            2,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "error: axis 3 is out of bounds for a variable with 3 dimensions.")]
+    fn fail_axis() {
+        Narrow::new(
+            new_input((2, 3, 2), (1..=12).map(|el| el as f32).collect()),
+            3,
+            0,
+            1,
+        );
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((2, 3, 2), (1..=12).map(|el| el as f32).collect());
+        let node = Narrow::new(input.clone(), 1, 1, 1);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((2, 1, 2), vec![3., 4., 9., 10.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(
+            &*input.data(),
+            &new_tensor((2, 3, 2), (2..=13).map(|el| el as f32).collect()),
+        );
+
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((2, 1, 2), vec![3., 4., 9., 10.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((2, 1, 2), vec![4., 5., 10., 11.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((2, 3, 2), (1..=12).map(|el| el as f32).collect());
+        let node = Narrow::new(input, 1, 1, 1);
+
+        let output = "Narrow { data: [[[0.0, 0.0]],\n\n [[0.0, 0.0]]], shape=[2, 1, 2], strides=[2, 2, 1], layout=Cc (0x5), const ndim=3, axis: 1, start: 1, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((2, 3, 2), (1..=12).map(|el| el as f32).collect());
+        let node = Narrow::new(input, 1, 1, 1);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_tensor, Backward, Gradient, NarrowBackward,
+        Overwrite, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = NarrowBackward::new(new_backward_input((2, 3, 2), vec![0.; 12]), 1, 1, 2);
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((2, 2, 2), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((2, 2, 2), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: cannot narrow axis 2 of length 2 to 3 elements starting at 0."
+    )]
+    fn fail() {
+        NarrowBackward::new(new_backward_input((2, 3, 2), vec![0.; 12]), 2, 0, 3);
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((2, 3, 2), vec![0.; 12]);
+        let node = NarrowBackward::new(diff.clone(), 1, 1, 1);
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        // The stale gradient of the operand must be discarded on overwrite.
+        let diff = new_backward_input((2, 3, 2), vec![5.; 12]);
+        let node = NarrowBackward::new(diff.clone(), 1, 1, 1);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((2, 1, 2), vec![1., 2., 3., 4.]);
+        assert_almost_equals(
+            &*node.gradient(),
+            &new_tensor((2, 1, 2), vec![1., 2., 3., 4.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![0., 0., 1., 2., 0., 0., 0., 0., 3., 4., 0., 0.],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![0., 0., 2., 4., 0., 0., 0., 0., 6., 8., 0., 0.],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(
+                (2, 3, 2),
+                vec![0., 0., 1., 2., 0., 0., 0., 0., 3., 4., 0., 0.],
+            ),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let diff = new_backward_input((2, 3, 2), vec![0.; 12]);
+        let node = NarrowBackward::new(diff, 1, 1, 1);
+
+        let output = "NarrowBackward { gradient: Some([[[0.0, 0.0]],\n\n [[0.0, 0.0]]], shape=[2, 1, 2], strides=[2, 2, 1], layout=Cc (0x5), const ndim=3), axis: 1, start: 1, overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let diff = new_backward_input((2, 3, 2), vec![0.; 12]);
+        let node = NarrowBackward::new(diff, 1, 1, 1);
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // NarrowBackward
+        let node = NarrowBackward::new(new_backward_input((2, 3, 2), vec![0.; 12]), 1, 1, 1);
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
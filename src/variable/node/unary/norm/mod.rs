@@ -0,0 +1,303 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite, Summary,
+    Tensor,
+};
+use ndarray::{Axis, Dimension, Zip};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+/// Computes the shape of the norm of an array of shape `shape` taken along `axes`. The reduced
+/// axes are kept with length one.
+fn norm_shape<D: Dimension>(shape: &D, axes: &[usize]) -> D {
+    let mut norm_shape = shape.clone();
+    for &axis in axes {
+        norm_shape[axis] = 1;
+    }
+
+    norm_shape
+}
+
+/// Sign function that maps zero to zero.
+fn sign(x: f32) -> f32 {
+    if x == 0. {
+        0.
+    } else {
+        x.signum()
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Norm ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct Norm<T: ?Sized>
+where
+    T: Data,
+{
+    operand: Rc<T>,
+    data: RefCell<Tensor<T::Dim>>,
+    p: f32,
+    axes: Vec<usize>,
+    eps: f32,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized> Norm<T>
+where
+    T: Data,
+{
+    pub fn new(operand: Rc<T>, p: f32, axes: &[usize], eps: f32) -> Self {
+        let data = RefCell::new(Tensor::zeros(norm_shape(&operand.data().raw_dim(), axes)));
+
+        Self {
+            operand,
+            data,
+            p,
+            axes: axes.to_vec(),
+            eps,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized> Cache for Norm<T>
+where
+    T: Data,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized> Forward for Norm<T>
+where
+    T: Data,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let (p, eps) = (self.p, self.eps);
+        let operand_data = self.operand.data();
+        let mut powered = if (p - 1.).abs() < f32::EPSILON {
+            operand_data.mapv(f32::abs)
+        } else if (p - 2.).abs() < f32::EPSILON {
+            operand_data.mapv(|el| el * el)
+        } else {
+            operand_data.mapv(|el| el.abs().powf(p))
+        }
+        .into_dyn();
+
+        for &axis in &self.axes {
+            powered = powered.sum_axis(Axis(axis)).insert_axis(Axis(axis));
+        }
+
+        let mut data = self.data.borrow_mut();
+        let powered = powered.into_dimensionality::<T::Dim>().unwrap();
+        let zip = Zip::from(&mut *data).and(&powered);
+        if (p - 1.).abs() < f32::EPSILON {
+            zip.for_each(|norm, &sum| *norm = sum.max(eps));
+        } else if (p - 2.).abs() < f32::EPSILON {
+            zip.for_each(|norm, &sum| *norm = sum.sqrt().max(eps));
+        } else {
+            zip.for_each(|norm, &sum| *norm = sum.powf(1. / p).max(eps));
+        }
+    }
+}
+
+impl<T: ?Sized> Data for Norm<T>
+where
+    T: Data,
+{
+    type Dim = T::Dim;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> Debug for Norm<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Norm")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("p", &self.p)
+            .field("axes", &self.axes)
+            .field("eps", &self.eps)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for Norm<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ NormBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct NormBackward<T: ?Sized, U: ?Sized>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    gradient: RefCell<Option<Tensor<T::Dim>>>,
+    shape: T::Dim,
+    overwrite: Cell<bool>,
+    diff_operand: Rc<T>,
+    no_diff_operand: Rc<U>,
+    norm: Rc<Norm<U>>,
+}
+
+impl<T: ?Sized, U: ?Sized> NormBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    pub fn new(diff_operand: Rc<T>, no_diff_operand: Rc<U>, norm: Rc<Norm<U>>) -> Self {
+        let shape = norm.data().raw_dim();
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape.clone()))),
+            shape,
+            overwrite: Cell::new(true),
+            diff_operand,
+            no_diff_operand,
+            norm,
+        }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Gradient for NormBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    type Dim = T::Dim;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Overwrite for NormBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Backward for NormBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    fn backward(&self) {
+        let mut op_grad = self.diff_operand.gradient_mut();
+        let data = self.no_diff_operand.data();
+        let norm = self.norm.data();
+        let grad = self.gradient();
+        let (p, eps) = (self.norm.p, self.norm.eps);
+
+        // The gradient doesn't flow through the norms that have been clamped to `eps`, this
+        // keeps it finite for the zero vectors.
+        let local_grad = |data_el: f32, norm_el: f32| {
+            if norm_el <= eps {
+                0.
+            } else if (p - 1.).abs() < f32::EPSILON {
+                sign(data_el)
+            } else if (p - 2.).abs() < f32::EPSILON {
+                data_el / norm_el
+            } else {
+                sign(data_el) * (data_el.abs() / norm_el).powf(p - 1.)
+            }
+        };
+
+        let zip = Zip::from(&mut *op_grad)
+            .and(&*data)
+            .and_broadcast(&*grad)
+            .and_broadcast(&*norm);
+        if self.diff_operand.can_overwrite() {
+            zip.for_each(|op_grad_el, &data_el, &grad_el, &norm_el| {
+                *op_grad_el = grad_el * local_grad(data_el, norm_el)
+            });
+            self.diff_operand.set_overwrite(false);
+        } else {
+            zip.for_each(|op_grad_el, &data_el, &grad_el, &norm_el| {
+                *op_grad_el += grad_el * local_grad(data_el, norm_el)
+            });
+        }
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Debug for NormBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NormBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Display for NormBackward<T, U>
+where
+    T: Gradient,
+    U: Data<Dim = T::Dim>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
@@ -0,0 +1,303 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, Norm, NormBackward, Overwrite, Tensor,
+};
+
+mod forward {
+    use super::{assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, Norm, Tensor};
+
+    #[test]
+    fn creation() {
+        let input = new_input((2, 3), vec![1., -2., 2., 0., 0., 0.]);
+        let node = Norm::new(input, 2., &[1], 0.);
+
+        assert_eq!(*node.data(), Tensor::from_elem((2, 1), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((2, 1), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((2, 3), vec![1., -2., 2., 0., 0., 0.]);
+        let node = Norm::new(input, 2., &[1], 0.);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((2, 3), vec![1., -2., 2., 0., 0., 0.]);
+        let node = Norm::new(input.clone(), 2., &[1], 0.);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((2, 1), vec![3., 0.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data * &Tensor::from_elem(1, 2.);
+        }
+        assert_almost_equals(
+            &*input.data(),
+            &new_tensor((2, 3), vec![2., -4., 4., 0., 0., 0.]),
+        );
+
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((2, 1), vec![3., 0.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((2, 1), vec![6., 0.]));
+    }
+
+    #[test]
+    fn forward_p() {
+        let input = new_input((2, 3), vec![1., -2., 2., 0., 0., 0.]);
+
+        let node = Norm::new(input.clone(), 1., &[1], 0.);
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((2, 1), vec![5., 0.]));
+
+        let node = Norm::new(input, 3., &[1], 0.);
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((2, 1), vec![2.571281, 0.]));
+    }
+
+    #[test]
+    fn forward_axes() {
+        let input = new_input((2, 3), vec![1., -2., 2., 0., 0., 0.]);
+
+        let node = Norm::new(input.clone(), 2., &[0], 0.);
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((1, 3), vec![1., 2., 2.]));
+
+        let node = Norm::new(input, 2., &[0, 1], 0.);
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((1, 1), vec![3.]));
+    }
+
+    #[test]
+    fn forward_eps() {
+        let input = new_input((2, 3), vec![1., -2., 2., 0., 0., 0.]);
+        let node = Norm::new(input, 2., &[1], 1e-6);
+
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((2, 1), vec![3., 1e-6]));
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((2, 3), vec![1., -2., 2., 0., 0., 0.]);
+        let node = Norm::new(input, 2., &[1], 0.);
+
+        let output = "Norm { data: [[0.0],\n [0.0]], shape=[2, 1], strides=[1, 1], layout=CFcf (0xf), const ndim=2, p: 2.0, axes: [1], eps: 0.0, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((2, 3), vec![1., -2., 2., 0., 0., 0.]);
+        let node = Norm::new(input, 2., &[1], 0.);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Forward,
+        Gradient, Norm, NormBackward, Overwrite, Tensor,
+    };
+    use std::rc::Rc;
+
+    fn new_norm(p: f32, data: Vec<f32>) -> Rc<Norm<crate::variable::Input<ndarray::Ix2>>> {
+        let norm = Rc::new(Norm::new(new_input((2, 3), data), p, &[1], 0.));
+        norm.forward();
+        norm
+    }
+
+    #[test]
+    fn creation() {
+        let input = new_input((2, 3), vec![1., -2., 2., 0., 0., 0.]);
+        let node = NormBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            input.clone(),
+            Rc::new(Norm::new(input, 2., &[1], 0.)),
+        );
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((2, 1), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((2, 1), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let input = new_input((2, 3), vec![1., -2., 2., 0., 0., 0.]);
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = NormBackward::new(
+            diff.clone(),
+            input.clone(),
+            Rc::new(Norm::new(input, 2., &[1], 0.)),
+        );
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        let data = vec![1., -2., 2., 0., 0., 0.];
+        let norm = new_norm(2., data.clone());
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = NormBackward::new(diff.clone(), new_input((2, 3), data), norm);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((2, 1), vec![1.; 2]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((2, 1), vec![1.; 2]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![0.33333, -0.66667, 0.66667, 0., 0., 0.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![0.66667, -1.33333, 1.33333, 0., 0., 0.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![0.33333, -0.66667, 0.66667, 0., 0., 0.]),
+        );
+    }
+
+    #[test]
+    fn backward_p() {
+        let data = vec![1., -2., 2., 0., 0., 0.];
+
+        let expected = [
+            (1., vec![1., -1., 1., 0., 0., 0.]),
+            (2., vec![0.33333, -0.66667, 0.66667, 0., 0., 0.]),
+            (3., vec![0.151252, -0.605007, 0.605007, 0., 0., 0.]),
+        ];
+        for (p, expected) in expected {
+            let diff = new_backward_input((2, 3), vec![0.; 6]);
+            let node = NormBackward::new(
+                diff.clone(),
+                new_input((2, 3), data.clone()),
+                new_norm(p, data.clone()),
+            );
+            *node.gradient_mut() = new_tensor((2, 1), vec![1.; 2]);
+            node.backward();
+
+            assert_almost_equals(&*diff.gradient(), &new_tensor((2, 3), expected));
+        }
+    }
+
+    #[test]
+    fn backward_zero_vector() {
+        for p in [1., 2., 3.] {
+            let data = vec![0.; 6];
+            let diff = new_backward_input((2, 3), vec![0.; 6]);
+            let norm = Rc::new(Norm::new(new_input((2, 3), data.clone()), p, &[1], 1e-12));
+            norm.forward();
+            let node = NormBackward::new(diff.clone(), new_input((2, 3), data), norm);
+            *node.gradient_mut() = new_tensor((2, 1), vec![1.; 2]);
+            node.backward();
+
+            assert_eq!(*diff.gradient(), Tensor::from_elem((2, 3), 0.));
+        }
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((2, 3), vec![1., -2., 2., 0., 0., 0.]);
+        let node = NormBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            input.clone(),
+            Rc::new(Norm::new(input, 2., &[1], 0.)),
+        );
+
+        let output = "NormBackward { gradient: Some([[0.0],\n [0.0]], shape=[2, 1], strides=[1, 1], layout=CFcf (0xf), const ndim=2), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((2, 3), vec![1., -2., 2., 0., 0., 0.]);
+        let node = NormBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            input.clone(),
+            Rc::new(Norm::new(input, 2., &[1], 0.)),
+        );
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // NormBackward
+        let input = new_input((2, 3), vec![0.; 6]);
+        let node = NormBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            input.clone(),
+            Rc::new(Norm::new(input, 2., &[1], 0.)),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
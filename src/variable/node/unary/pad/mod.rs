@@ -0,0 +1,328 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite, Summary,
+    Tensor,
+};
+use ndarray::{Dimension, IntoDimension};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+/// The way the border added by a padding is filled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PadMode {
+    /// The border is filled with a constant value.
+    Constant(f32),
+    /// The border is the reflection of the elements next to it, the edge excluded.
+    Reflect,
+    /// The border is filled with the elements on the edge.
+    Replicate,
+}
+
+impl PadMode {
+    /// Returns the position of the operand's element that ends up at `position` along an axis of
+    /// length `len` padded with `before` elements, or `None` if it's filled with a constant.
+    fn source(&self, position: usize, before: usize, len: usize) -> Option<usize> {
+        let position = position as isize - before as isize;
+        let last = len as isize - 1;
+        if (0..=last).contains(&position) {
+            return Some(position as usize);
+        }
+
+        match self {
+            PadMode::Constant(_) => None,
+            PadMode::Reflect if position < 0 => Some(-position as usize),
+            PadMode::Reflect => Some((2 * last - position) as usize),
+            PadMode::Replicate => Some(position.clamp(0, last) as usize),
+        }
+    }
+}
+
+/// Computes the shape of `shape` padded by `padding`.
+///
+/// # Panics
+///
+/// If `padding` doesn't have an entry for each axis of `shape`, or if it's too large for `mode`.
+fn padded_shape<D: Dimension>(shape: &D, padding: &[(usize, usize)], mode: PadMode) -> D {
+    assert_eq!(
+        padding.len(),
+        shape.ndim(),
+        "error: padding must have an entry for each of the {} dimensions, found {}.",
+        shape.ndim(),
+        padding.len()
+    );
+
+    let mut padded = shape.clone();
+    for (axis, (len, &(before, after))) in shape.slice().iter().zip(padding).enumerate() {
+        match mode {
+            PadMode::Constant(_) => {}
+            PadMode::Reflect => assert!(
+                before < *len && after < *len,
+                "error: reflective padding of ({}, {}) is too large for axis {} of length {}.",
+                before,
+                after,
+                axis,
+                len
+            ),
+            PadMode::Replicate => assert!(
+                *len > 0 || before + after == 0,
+                "error: cannot replicate the edges of the empty axis {}.",
+                axis
+            ),
+        }
+        padded[axis] = len + before + after;
+    }
+
+    padded
+}
+
+/// Returns the index of the element of an operand of shape `shape` that ends up at `index` once
+/// padded, or `None` if the element at `index` is filled with a constant.
+fn source_index<D: Dimension>(
+    index: D::Pattern,
+    shape: &D,
+    padding: &[(usize, usize)],
+    mode: PadMode,
+) -> Option<D> {
+    let mut index = index.into_dimension();
+    for (axis, &(before, _)) in padding.iter().enumerate() {
+        index[axis] = mode.source(index[axis], before, shape[axis])?;
+    }
+
+    Some(index)
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Pad ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct Pad<T: ?Sized>
+where
+    T: Data,
+{
+    operand: Rc<T>,
+    data: RefCell<Tensor<T::Dim>>,
+    padding: Vec<(usize, usize)>,
+    mode: PadMode,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized> Pad<T>
+where
+    T: Data,
+{
+    pub fn new(operand: Rc<T>, padding: &[(usize, usize)], mode: PadMode) -> Self {
+        let shape = padded_shape(&operand.data().raw_dim(), padding, mode);
+
+        Self {
+            operand,
+            data: RefCell::new(Tensor::zeros(shape)),
+            padding: padding.to_vec(),
+            mode,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized> Cache for Pad<T>
+where
+    T: Data,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized> Forward for Pad<T>
+where
+    T: Data,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let (mut data, operand_data) = (self.data.borrow_mut(), self.operand.data());
+        let shape = operand_data.raw_dim();
+        let value = match self.mode {
+            PadMode::Constant(value) => value,
+            _ => 0.,
+        };
+
+        for (index, data_el) in data.indexed_iter_mut() {
+            *data_el = match source_index(index, &shape, &self.padding, self.mode) {
+                Some(source) => operand_data[source],
+                None => value,
+            };
+        }
+    }
+}
+
+impl<T: ?Sized> Data for Pad<T>
+where
+    T: Data,
+{
+    type Dim = T::Dim;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> Debug for Pad<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pad")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("padding", &self.padding)
+            .field("mode", &self.mode)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for Pad<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ PadBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct PadBackward<T: ?Sized>
+where
+    T: Gradient,
+{
+    gradient: RefCell<Option<Tensor<T::Dim>>>,
+    shape: T::Dim,
+    overwrite: Cell<bool>,
+    operand: Rc<T>,
+    padding: Vec<(usize, usize)>,
+    mode: PadMode,
+}
+
+impl<T: ?Sized> PadBackward<T>
+where
+    T: Gradient,
+{
+    pub fn new(operand: Rc<T>, padding: &[(usize, usize)], mode: PadMode) -> Self {
+        let shape = padded_shape(&operand.gradient().raw_dim(), padding, mode);
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape.clone()))),
+            shape,
+            overwrite: Cell::new(true),
+            operand,
+            padding: padding.to_vec(),
+            mode,
+        }
+    }
+}
+
+impl<T: ?Sized> Gradient for PadBackward<T>
+where
+    T: Gradient,
+{
+    type Dim = T::Dim;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized> Overwrite for PadBackward<T>
+where
+    T: Gradient,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized> Backward for PadBackward<T>
+where
+    T: Gradient,
+{
+    fn backward(&self) {
+        let (mut operand_gradient, gradient) = (self.operand.gradient_mut(), self.gradient());
+        let shape = operand_gradient.raw_dim();
+
+        // Several elements of the border may come from the same element of the operand, so
+        // their gradients are accumulated.
+        if self.operand.can_overwrite() {
+            operand_gradient.fill(0.);
+            self.operand.set_overwrite(false);
+        }
+
+        for (index, gradient_el) in gradient.indexed_iter() {
+            if let Some(source) = source_index(index, &shape, &self.padding, self.mode) {
+                operand_gradient[source] += gradient_el;
+            }
+        }
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<T: ?Sized> Debug for PadBackward<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PadBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("padding", &self.padding)
+            .field("mode", &self.mode)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for PadBackward<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
@@ -0,0 +1,332 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, Overwrite, Pad, PadBackward, PadMode, Tensor,
+};
+
+mod forward {
+    use super::{
+        assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, Pad, PadMode, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Pad::new(input, &[(1, 0), (0, 2)], PadMode::Constant(0.5));
+
+        assert_eq!(*node.data(), Tensor::from_elem((3, 5), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((3, 5), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Pad::new(input, &[(1, 0), (0, 2)], PadMode::Constant(0.5));
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: padding must have an entry for each of the 2 dimensions, found 1."
+    )]
+    fn fail() {
+        Pad::new(
+            new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]),
+            &[(1, 1)],
+            PadMode::Replicate,
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: reflective padding of (0, 2) is too large for axis 0 of length 2."
+    )]
+    fn fail_reflect() {
+        Pad::new(
+            new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]),
+            &[(0, 2), (1, 1)],
+            PadMode::Reflect,
+        );
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Pad::new(input.clone(), &[(1, 0), (0, 2)], PadMode::Constant(0.5));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (3, 5),
+                vec![
+                    0.5, 0.5, 0.5, 0.5, 0.5, 1., 2., 3., 0.5, 0.5, 4., 5., 6., 0.5, 0.5,
+                ],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(
+            &*input.data(),
+            &new_tensor((2, 3), vec![2., 3., 4., 5., 6., 7.]),
+        );
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (3, 5),
+                vec![
+                    0.5, 0.5, 0.5, 0.5, 0.5, 1., 2., 3., 0.5, 0.5, 4., 5., 6., 0.5, 0.5,
+                ],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (3, 5),
+                vec![
+                    0.5, 0.5, 0.5, 0.5, 0.5, 2., 3., 4., 0.5, 0.5, 5., 6., 7., 0.5, 0.5,
+                ],
+            ),
+        );
+    }
+
+    #[test]
+    fn forward_reflect() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Pad::new(input, &[(1, 1), (2, 1)], PadMode::Reflect);
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (4, 6),
+                vec![
+                    6., 5., 4., 5., 6., 5., 3., 2., 1., 2., 3., 2., 6., 5., 4., 5., 6., 5., 3., 2.,
+                    1., 2., 3., 2.,
+                ],
+            ),
+        );
+    }
+
+    #[test]
+    fn forward_replicate() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Pad::new(input, &[(1, 1), (2, 1)], PadMode::Replicate);
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (4, 6),
+                vec![
+                    1., 1., 1., 2., 3., 3., 1., 1., 1., 2., 3., 3., 4., 4., 4., 5., 6., 6., 4., 4.,
+                    4., 5., 6., 6.,
+                ],
+            ),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input(3, vec![1., 2., 3.]);
+        let node = Pad::new(input, &[(1, 0)], PadMode::Constant(0.));
+
+        let output = "Pad { data: [0.0, 0.0, 0.0, 0.0], shape=[4], strides=[1], layout=CFcf (0xf), const ndim=1, padding: [(1, 0)], mode: Constant(0.0), computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input(3, vec![1., 2., 3.]);
+        let node = Pad::new(input, &[(1, 0)], PadMode::Constant(0.));
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_tensor, Backward, Gradient, Overwrite,
+        PadBackward, PadMode, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = PadBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            &[(1, 1), (2, 1)],
+            PadMode::Reflect,
+        );
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((4, 6), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((4, 6), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    #[should_panic(expected = "error: cannot replicate the edges of the empty axis 1.")]
+    fn fail() {
+        PadBackward::new(
+            new_backward_input((2, 0), vec![]),
+            &[(0, 0), (1, 0)],
+            PadMode::Replicate,
+        );
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = PadBackward::new(diff.clone(), &[(1, 1), (2, 1)], PadMode::Reflect);
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        // The stale gradient of the operand must be discarded on overwrite.
+        let diff = new_backward_input((2, 3), vec![5.; 6]);
+        let node = PadBackward::new(diff.clone(), &[(1, 0), (0, 2)], PadMode::Constant(0.5));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((3, 5), (1..=15).map(|el| el as f32).collect());
+        assert_almost_equals(
+            &*node.gradient(),
+            &new_tensor((3, 5), (1..=15).map(|el| el as f32).collect()),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![6., 7., 8., 11., 12., 13.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![12., 14., 16., 22., 24., 26.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![6., 7., 8., 11., 12., 13.]),
+        );
+    }
+
+    #[test]
+    fn backward_reflect() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = PadBackward::new(diff.clone(), &[(1, 1), (2, 1)], PadMode::Reflect);
+
+        *node.gradient_mut() = new_tensor((4, 6), vec![1.; 24]);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![2., 6., 4., 2., 6., 4.]),
+        );
+    }
+
+    #[test]
+    fn backward_replicate() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = PadBackward::new(diff.clone(), &[(1, 1), (2, 1)], PadMode::Replicate);
+
+        *node.gradient_mut() = new_tensor((4, 6), vec![1.; 24]);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![6., 2., 4., 6., 2., 4.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let diff = new_backward_input(3, vec![0.; 3]);
+        let node = PadBackward::new(diff, &[(1, 0)], PadMode::Constant(0.));
+
+        let output = "PadBackward { gradient: Some([0.0, 0.0, 0.0, 0.0], shape=[4], strides=[1], layout=CFcf (0xf), const ndim=1), padding: [(1, 0)], mode: Constant(0.0), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let diff = new_backward_input(3, vec![0.; 3]);
+        let node = PadBackward::new(diff, &[(1, 0)], PadMode::Constant(0.));
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // PadBackward
+        let node = PadBackward::new(
+            new_backward_input((2, 3), vec![0.; 6]),
+            &[(1, 0), (0, 2)],
+            PadMode::Constant(0.),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
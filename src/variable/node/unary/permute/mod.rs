@@ -0,0 +1,267 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, push_gradient, Backward, Cache, Data, Forward, Gradient,
+    Overwrite, Summary, Tensor,
+};
+use ndarray::Dimension;
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Permute ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct Permute<T: ?Sized>
+where
+    T: Data,
+{
+    operand: Rc<T>,
+    data: RefCell<Tensor<T::Dim>>,
+    axes: T::Dim,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized> Permute<T>
+where
+    T: Data,
+{
+    pub fn new(operand: Rc<T>, axes: T::Dim) -> Self {
+        check_permutation(&axes, operand.data().ndim());
+        let data = Tensor::zeros(operand.data().view().permuted_axes(axes.clone()).raw_dim());
+
+        Self {
+            operand,
+            data: RefCell::new(data),
+            axes,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized> Cache for Permute<T>
+where
+    T: Data,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized> Forward for Permute<T>
+where
+    T: Data,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        self.data
+            .borrow_mut()
+            .assign(&self.operand.data().view().permuted_axes(self.axes.clone()));
+    }
+}
+
+impl<T: ?Sized> Data for Permute<T>
+where
+    T: Data,
+{
+    type Dim = T::Dim;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> Debug for Permute<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Permute")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("axes", &self.axes.slice())
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for Permute<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ PermuteBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct PermuteBackward<T: ?Sized>
+where
+    T: Gradient,
+{
+    gradient: RefCell<Option<Tensor<T::Dim>>>,
+    shape: T::Dim,
+    overwrite: Cell<bool>,
+    operand: Rc<T>,
+    axes: T::Dim,
+}
+
+impl<T: ?Sized> PermuteBackward<T>
+where
+    T: Gradient,
+{
+    pub fn new(operand: Rc<T>, axes: T::Dim) -> Self {
+        check_permutation(&axes, operand.gradient().ndim());
+        let gradient = Tensor::zeros(
+            operand
+                .gradient()
+                .view()
+                .permuted_axes(axes.clone())
+                .raw_dim(),
+        );
+        let shape = gradient.raw_dim();
+
+        Self {
+            gradient: RefCell::new(Some(gradient)),
+            shape,
+            overwrite: Cell::new(true),
+            operand,
+            axes,
+        }
+    }
+}
+
+impl<T: ?Sized> Gradient for PermuteBackward<T>
+where
+    T: Gradient,
+{
+    type Dim = T::Dim;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized> Overwrite for PermuteBackward<T>
+where
+    T: Gradient,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized> Backward for PermuteBackward<T>
+where
+    T: Gradient,
+{
+    fn backward(&self) {
+        // Undoes the permutation: the axis moved to position `i` goes back to `self.axes[i]`.
+        let mut inverse = self.axes.clone();
+        for (i, axis) in self.axes.slice().iter().enumerate() {
+            inverse.slice_mut()[*axis] = i;
+        }
+
+        push_gradient(
+            &*self.operand,
+            self.gradient().view().permuted_axes(inverse),
+        );
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<T: ?Sized> Debug for PermuteBackward<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PermuteBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("axes", &self.axes.slice())
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for PermuteBackward<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+/// Checks that `axes` contains each one of the `ndim` axes of a variable exactly once.
+fn check_permutation<D: Dimension>(axes: &D, ndim: usize) {
+    let mut seen = vec![false; ndim];
+    let is_permutation = axes.ndim() == ndim
+        && axes
+            .slice()
+            .iter()
+            .all(|&axis| axis < ndim && !std::mem::replace(&mut seen[axis], true));
+
+    assert!(
+        is_permutation,
+        "error: {:?} is not a permutation of the axes of a variable with {} dimensions, each axis must appear exactly once.",
+        axes.slice(),
+        ndim
+    );
+}
+
+/// Returns the permutation of `ndim` axes swapping `a` and `b`.
+pub(crate) fn swap_permutation<D: Dimension>(ndim: usize, a: usize, b: usize) -> D {
+    assert!(
+        a < ndim && b < ndim,
+        "error: cannot swap axes {} and {} of a variable with {} dimensions.",
+        a,
+        b,
+        ndim
+    );
+
+    let mut axes = D::zeros(ndim);
+    for (i, axis) in axes.slice_mut().iter_mut().enumerate() {
+        *axis = i;
+    }
+    axes.slice_mut().swap(a, b);
+    axes
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
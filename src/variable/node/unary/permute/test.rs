@@ -0,0 +1,243 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, Overwrite, Permute, PermuteBackward, Tensor,
+};
+use ndarray::Ix3;
+
+mod forward {
+    use super::{
+        assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, Ix3, Permute, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((2, 1, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Permute::new(input, Ix3(2, 0, 1));
+
+        assert_eq!(*node.data(), Tensor::from_elem((3, 2, 1), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((3, 2, 1), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((2, 1, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Permute::new(input, Ix3(2, 0, 1));
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: [0, 0, 1] is not a permutation of the axes of a variable with 3 dimensions, each axis must appear exactly once."
+    )]
+    fn fail() {
+        Permute::new(
+            new_input((2, 1, 3), vec![1., 2., 3., 4., 5., 6.]),
+            Ix3(0, 0, 1),
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: [0, 3, 1] is not a permutation of the axes of a variable with 3 dimensions, each axis must appear exactly once."
+    )]
+    fn fail_out_of_bounds() {
+        Permute::new(
+            new_input((2, 1, 3), vec![1., 2., 3., 4., 5., 6.]),
+            Ix3(0, 3, 1),
+        );
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((2, 1, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Permute::new(input.clone(), Ix3(2, 0, 1));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 2, 1), vec![1., 4., 2., 5., 3., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(
+            &*input.data(),
+            &new_tensor((2, 1, 3), vec![2., 3., 4., 5., 6., 7.]),
+        );
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 2, 1), vec![1., 4., 2., 5., 3., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 2, 1), vec![2., 5., 3., 6., 4., 7.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((2, 1, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Permute::new(input, Ix3(2, 0, 1));
+
+        let output = "Permute { data: [[[0.0],\n  [0.0]],\n\n [[0.0],\n  [0.0]],\n\n [[0.0],\n  [0.0]]], shape=[3, 2, 1], strides=[2, 1, 1], layout=Cc (0x5), const ndim=3, axes: [2, 0, 1], computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((2, 1, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Permute::new(input, Ix3(2, 0, 1));
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_tensor, Backward, Gradient, Ix3, Overwrite,
+        PermuteBackward, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = PermuteBackward::new(new_backward_input((2, 1, 3), vec![0.; 6]), Ix3(2, 0, 1));
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((3, 2, 1), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((3, 2, 1), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: [1, 1, 0] is not a permutation of the axes of a variable with 3 dimensions, each axis must appear exactly once."
+    )]
+    fn fail() {
+        PermuteBackward::new(new_backward_input((2, 1, 3), vec![0.; 6]), Ix3(1, 1, 0));
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((2, 1, 3), vec![0.; 6]);
+        let node = PermuteBackward::new(diff.clone(), Ix3(2, 0, 1));
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        let diff = new_backward_input((2, 1, 3), vec![0.; 6]);
+        let node = PermuteBackward::new(diff.clone(), Ix3(2, 0, 1));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((3, 2, 1), vec![1., 2., 3., 4., 5., 6.]);
+        assert_almost_equals(
+            &*node.gradient(),
+            &new_tensor((3, 2, 1), vec![1., 2., 3., 4., 5., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 1, 3), vec![1., 3., 5., 2., 4., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 1, 3), vec![2., 6., 10., 4., 8., 12.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 1, 3), vec![1., 3., 5., 2., 4., 6.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let diff = new_backward_input((2, 1, 3), vec![0.; 6]);
+        let node = PermuteBackward::new(diff, Ix3(2, 0, 1));
+
+        let output = "PermuteBackward { gradient: Some([[[0.0],\n  [0.0]],\n\n [[0.0],\n  [0.0]],\n\n [[0.0],\n  [0.0]]], shape=[3, 2, 1], strides=[2, 1, 1], layout=Cc (0x5), const ndim=3), axes: [2, 0, 1], overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let diff = new_backward_input((2, 1, 3), vec![0.; 6]);
+        let node = PermuteBackward::new(diff, Ix3(2, 0, 1));
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // PermuteBackward
+        let node = PermuteBackward::new(new_backward_input((2, 1, 3), vec![0.; 6]), Ix3(2, 0, 1));
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
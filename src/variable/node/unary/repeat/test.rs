@@ -0,0 +1,228 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, Overwrite, Repeat, RepeatBackward, Tensor,
+};
+
+mod forward {
+    use super::{
+        assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, Repeat, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Repeat::new(input, 2, 1);
+
+        assert_eq!(*node.data(), Tensor::from_elem((2, 6), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((2, 6), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Repeat::new(input, 2, 1);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic(expected = "error: axis 2 is out of bounds for a variable with 2 dimensions.")]
+    fn fail() {
+        Repeat::new(new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]), 2, 2);
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Repeat::new(input.clone(), 2, 1);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((2, 6), vec![1., 2., 3., 1., 2., 3., 4., 5., 6., 4., 5., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(
+            &*input.data(),
+            &new_tensor((2, 3), vec![2., 3., 4., 5., 6., 7.]),
+        );
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((2, 6), vec![1., 2., 3., 1., 2., 3., 4., 5., 6., 4., 5., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((2, 6), vec![2., 3., 4., 2., 3., 4., 5., 6., 7., 5., 6., 7.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input(2, vec![1., 2.]);
+        let node = Repeat::new(input, 2, 0);
+
+        let output = "Repeat { data: [0.0, 0.0, 0.0, 0.0], shape=[4], strides=[1], layout=CFcf (0xf), const ndim=1, n: 2, axis: 0, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input(2, vec![1., 2.]);
+        let node = Repeat::new(input, 2, 0);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_tensor, Backward, Gradient, Overwrite,
+        RepeatBackward, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = RepeatBackward::new(new_backward_input((2, 3), vec![0.; 6]), 3, 0);
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((6, 3), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((6, 3), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = RepeatBackward::new(diff.clone(), 2, 1);
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        // The stale gradient of the operand must be discarded on overwrite.
+        let diff = new_backward_input((2, 3), vec![5.; 6]);
+        let node = RepeatBackward::new(diff.clone(), 2, 1);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((2, 6), (1..=12).map(|el| el as f32).collect());
+        assert_almost_equals(
+            &*node.gradient(),
+            &new_tensor((2, 6), (1..=12).map(|el| el as f32).collect()),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![5., 7., 9., 17., 19., 21.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![10., 14., 18., 34., 38., 42.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![5., 7., 9., 17., 19., 21.]),
+        );
+    }
+
+    #[test]
+    fn backward_no_repetitions() {
+        let diff = new_backward_input((2, 3), vec![5.; 6]);
+        let node = RepeatBackward::new(diff.clone(), 0, 0);
+
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor((2, 3), vec![0.; 6]));
+    }
+
+    #[test]
+    fn debug() {
+        let diff = new_backward_input(2, vec![0.; 2]);
+        let node = RepeatBackward::new(diff, 2, 0);
+
+        let output = "RepeatBackward { gradient: Some([0.0, 0.0, 0.0, 0.0], shape=[4], strides=[1], layout=CFcf (0xf), const ndim=1), n: 2, axis: 0, overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let diff = new_backward_input(2, vec![0.; 2]);
+        let node = RepeatBackward::new(diff, 2, 0);
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // RepeatBackward
+        let node = RepeatBackward::new(new_backward_input((2, 3), vec![0.; 6]), 2, 1);
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
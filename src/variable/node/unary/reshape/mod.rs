@@ -0,0 +1,317 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, push_gradient, Backward, Cache, Data, Forward, Gradient,
+    Overwrite, Summary, Tensor,
+};
+use ndarray::{Dimension, IxDyn};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+/// Replaces the entry of `shape` equal to `usize::MAX`, if any, with the length that makes `shape`
+/// hold exactly `len` elements.
+///
+/// # Panics
+///
+/// If more than one entry is to be inferred or if `len` elements can't be arranged in `shape`.
+fn infer_shape<D: Dimension>(mut shape: D, len: usize) -> D {
+    let inferred: Vec<usize> = shape
+        .slice()
+        .iter()
+        .enumerate()
+        .filter(|(_, axis_len)| **axis_len == usize::MAX)
+        .map(|(axis, _)| axis)
+        .collect();
+    assert!(
+        inferred.len() <= 1,
+        "error: cannot infer more than one dimension of shape {}.",
+        format_shape(shape.slice())
+    );
+
+    if let Some(&axis) = inferred.first() {
+        let known: usize = shape
+            .slice()
+            .iter()
+            .filter(|axis_len| **axis_len != usize::MAX)
+            .product();
+        assert!(
+            known != 0 && len.is_multiple_of(known),
+            "error: cannot reshape {} elements into shape {}.",
+            len,
+            format_shape(shape.slice())
+        );
+        shape[axis] = len / known;
+    }
+    assert_eq!(
+        shape.size(),
+        len,
+        "error: cannot reshape {} elements into shape {}.",
+        len,
+        format_shape(shape.slice())
+    );
+
+    shape
+}
+
+/// Returns `shape` with the axes from `start_axis` to `end_axis`, both included, collapsed into a
+/// single one.
+///
+/// # Panics
+///
+/// If `start_axis` is greater than `end_axis` or if `end_axis` is out of bounds.
+pub(crate) fn flatten_shape(shape: &[usize], start_axis: usize, end_axis: usize) -> IxDyn {
+    assert!(
+        start_axis <= end_axis && end_axis < shape.len(),
+        "error: cannot flatten axes {} to {} of a variable with {} dimensions.",
+        start_axis,
+        end_axis,
+        shape.len()
+    );
+
+    let collapsed = shape[start_axis..=end_axis].iter().product();
+    let flattened: Vec<usize> = shape[..start_axis]
+        .iter()
+        .copied()
+        .chain(std::iter::once(collapsed))
+        .chain(shape[end_axis + 1..].iter().copied())
+        .collect();
+    IxDyn(&flattened)
+}
+
+/// Formats `shape` writing the inferred entry as `-1`.
+fn format_shape(shape: &[usize]) -> String {
+    let axes: Vec<String> = shape
+        .iter()
+        .map(|axis_len| match axis_len {
+            &usize::MAX => "-1".to_string(),
+            axis_len => axis_len.to_string(),
+        })
+        .collect();
+    format!("[{}]", axes.join(", "))
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Reshape ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct Reshape<T: ?Sized, D>
+where
+    T: Data,
+    D: Dimension,
+{
+    operand: Rc<T>,
+    data: RefCell<Tensor<D>>,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized, D> Reshape<T, D>
+where
+    T: Data,
+    D: Dimension,
+{
+    pub fn new(operand: Rc<T>, shape: D) -> Self {
+        let shape = infer_shape(shape, operand.data().len());
+        let data = Tensor::zeros(shape);
+
+        Self {
+            operand,
+            data: RefCell::new(data),
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized, D> Cache for Reshape<T, D>
+where
+    T: Data,
+    D: Dimension,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized, D> Forward for Reshape<T, D>
+where
+    T: Data,
+    D: Dimension,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        self.data
+            .borrow_mut()
+            .iter_mut()
+            .zip(self.operand.data().iter())
+            .for_each(|(data_el, operand_data_el)| *data_el = *operand_data_el);
+    }
+}
+
+impl<T: ?Sized, D> Data for Reshape<T, D>
+where
+    T: Data,
+    D: Dimension,
+{
+    type Dim = D;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized, D> Debug for Reshape<T, D>
+where
+    T: Data,
+    D: Dimension,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Reshape")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, D> Display for Reshape<T, D>
+where
+    T: Data,
+    D: Dimension,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ ReshapeBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct ReshapeBackward<T: ?Sized, D>
+where
+    T: Gradient,
+    D: Dimension,
+{
+    gradient: RefCell<Option<Tensor<D>>>,
+    shape: D,
+    overwrite: Cell<bool>,
+    operand: Rc<T>,
+    operand_shape: T::Dim,
+}
+
+impl<T: ?Sized, D> ReshapeBackward<T, D>
+where
+    T: Gradient,
+    D: Dimension,
+{
+    pub fn new(operand: Rc<T>, shape: D) -> Self {
+        let operand_shape = operand.gradient().raw_dim();
+        let shape = infer_shape(shape, operand_shape.size());
+        let gradient = Tensor::zeros(shape.clone());
+
+        Self {
+            gradient: RefCell::new(Some(gradient)),
+            shape,
+            overwrite: Cell::new(true),
+            operand,
+            operand_shape,
+        }
+    }
+}
+
+impl<T: ?Sized, D> Gradient for ReshapeBackward<T, D>
+where
+    T: Gradient,
+    D: Dimension,
+{
+    type Dim = D;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized, D> Overwrite for ReshapeBackward<T, D>
+where
+    T: Gradient,
+    D: Dimension,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized, D> Backward for ReshapeBackward<T, D>
+where
+    T: Gradient,
+    D: Dimension,
+{
+    fn backward(&self) {
+        let gradient = self.gradient();
+        let gradient = gradient
+            .as_standard_layout()
+            .into_shape(self.operand_shape.clone())
+            .unwrap();
+
+        push_gradient(&*self.operand, &gradient);
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<T: ?Sized, D> Debug for ReshapeBackward<T, D>
+where
+    T: Gradient,
+    D: Dimension,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReshapeBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, D> Display for ReshapeBackward<T, D>
+where
+    T: Gradient,
+    D: Dimension,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
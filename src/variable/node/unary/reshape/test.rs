@@ -0,0 +1,269 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, Overwrite, Reshape, ReshapeBackward, Tensor,
+};
+use ndarray::{Ix2, ShapeBuilder};
+
+mod forward {
+    use super::{
+        assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, Ix2, Reshape,
+        ShapeBuilder, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Reshape::new(input, Ix2(3, 2));
+
+        assert_eq!(*node.data(), Tensor::from_elem((3, 2), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((3, 2), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Reshape::new(input, Ix2(3, 2));
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic(expected = "error: cannot reshape 6 elements into shape [4, 2].")]
+    fn fail() {
+        Reshape::new(new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]), Ix2(4, 2));
+    }
+
+    #[test]
+    fn creation_inferred() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+
+        let node = Reshape::new(input.clone(), Ix2(usize::MAX, 2));
+        assert_eq!(*node.data(), Tensor::from_elem((3, 2), 0.));
+
+        let node = Reshape::new(input, Ix2(1, usize::MAX));
+        assert_eq!(*node.data(), Tensor::from_elem((1, 6), 0.));
+    }
+
+    #[test]
+    #[should_panic(expected = "error: cannot reshape 6 elements into shape [-1, 4].")]
+    fn fail_inferred() {
+        Reshape::new(
+            new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]),
+            Ix2(usize::MAX, 4),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "error: cannot infer more than one dimension of shape [-1, -1].")]
+    fn fail_two_inferred() {
+        Reshape::new(
+            new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]),
+            Ix2(usize::MAX, usize::MAX),
+        );
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Reshape::new(input.clone(), Ix2(3, 2));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(
+            &*input.data(),
+            &new_tensor((2, 3), vec![2., 3., 4., 5., 6., 7.]),
+        );
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 2), vec![2., 3., 4., 5., 6., 7.]),
+        );
+    }
+
+    #[test]
+    fn forward_non_standard_layout() {
+        let input = new_input((2, 3).f(), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Reshape::new(input, Ix2(3, 2));
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 2), vec![1., 3., 5., 2., 4., 6.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Reshape::new(input, Ix2(3, 2));
+
+        let output = "Reshape { data: [[0.0, 0.0],\n [0.0, 0.0],\n [0.0, 0.0]], shape=[3, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((2, 3), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Reshape::new(input, Ix2(3, 2));
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_tensor, Backward, Gradient, Ix2, Overwrite,
+        ReshapeBackward, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = ReshapeBackward::new(new_backward_input((2, 3), vec![0.; 6]), Ix2(3, 2));
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((3, 2), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((3, 2), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn creation_inferred() {
+        let node =
+            ReshapeBackward::new(new_backward_input((2, 3), vec![0.; 6]), Ix2(usize::MAX, 3));
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((2, 3), 0.));
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = ReshapeBackward::new(diff.clone(), Ix2(3, 2));
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = ReshapeBackward::new(diff.clone(), Ix2(3, 2));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        assert_almost_equals(
+            &*node.gradient(),
+            &new_tensor((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![1., 2., 3., 4., 5., 6.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![2., 4., 6., 8., 10., 12.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((2, 3), vec![1., 2., 3., 4., 5., 6.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = ReshapeBackward::new(diff, Ix2(3, 2));
+
+        let output = "ReshapeBackward { gradient: Some([[0.0, 0.0],\n [0.0, 0.0],\n [0.0, 0.0]], shape=[3, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let diff = new_backward_input((2, 3), vec![0.; 6]);
+        let node = ReshapeBackward::new(diff, Ix2(3, 2));
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // ReshapeBackward
+        let node = ReshapeBackward::new(new_backward_input((2, 3), vec![0.; 6]), Ix2(3, 2));
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
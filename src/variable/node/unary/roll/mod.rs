@@ -0,0 +1,266 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite, Summary,
+    Tensor,
+};
+use ndarray::{Axis, Dimension, Slice};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Roll ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct Roll<T: ?Sized>
+where
+    T: Data,
+{
+    operand: Rc<T>,
+    data: RefCell<Tensor<T::Dim>>,
+    shift: isize,
+    axis: usize,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized> Roll<T>
+where
+    T: Data,
+{
+    pub fn new(operand: Rc<T>, shift: isize, axis: usize) -> Self {
+        let shape = operand.data().raw_dim();
+        check_axis(shape.ndim(), axis);
+
+        Self {
+            operand,
+            data: RefCell::new(Tensor::zeros(shape)),
+            shift,
+            axis,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized> Cache for Roll<T>
+where
+    T: Data,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized> Forward for Roll<T>
+where
+    T: Data,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let (mut data, operand_data) = (self.data.borrow_mut(), self.operand.data());
+        let axis = Axis(self.axis);
+        let len = data.len_of(axis);
+        let offset = roll_offset(self.shift, len);
+
+        data.slice_axis_mut(axis, Slice::from(offset..))
+            .assign(&operand_data.slice_axis(axis, Slice::from(..len - offset)));
+        data.slice_axis_mut(axis, Slice::from(..offset))
+            .assign(&operand_data.slice_axis(axis, Slice::from(len - offset..)));
+    }
+}
+
+impl<T: ?Sized> Data for Roll<T>
+where
+    T: Data,
+{
+    type Dim = T::Dim;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> Debug for Roll<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Roll")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("shift", &self.shift)
+            .field("axis", &self.axis)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for Roll<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ RollBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct RollBackward<T: ?Sized>
+where
+    T: Gradient,
+{
+    gradient: RefCell<Option<Tensor<T::Dim>>>,
+    shape: T::Dim,
+    overwrite: Cell<bool>,
+    operand: Rc<T>,
+    shift: isize,
+    axis: usize,
+}
+
+impl<T: ?Sized> RollBackward<T>
+where
+    T: Gradient,
+{
+    pub fn new(operand: Rc<T>, shift: isize, axis: usize) -> Self {
+        let shape = operand.gradient().raw_dim();
+        check_axis(shape.ndim(), axis);
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape.clone()))),
+            shape,
+            overwrite: Cell::new(true),
+            operand,
+            shift,
+            axis,
+        }
+    }
+}
+
+impl<T: ?Sized> Gradient for RollBackward<T>
+where
+    T: Gradient,
+{
+    type Dim = T::Dim;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized> Overwrite for RollBackward<T>
+where
+    T: Gradient,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized> Backward for RollBackward<T>
+where
+    T: Gradient,
+{
+    fn backward(&self) {
+        let (mut operand_gradient, gradient) = (self.operand.gradient_mut(), self.gradient());
+        let axis = Axis(self.axis);
+        let len = gradient.len_of(axis);
+        let offset = roll_offset(self.shift, len);
+
+        // Rolls the gradient back by the opposite shift.
+        let (mut head, mut tail) = operand_gradient.view_mut().split_at(axis, len - offset);
+        let (head_gradient, tail_gradient) = (
+            gradient.slice_axis(axis, Slice::from(offset..)),
+            gradient.slice_axis(axis, Slice::from(..offset)),
+        );
+        if self.operand.can_overwrite() {
+            head.assign(&head_gradient);
+            tail.assign(&tail_gradient);
+            self.operand.set_overwrite(false);
+        } else {
+            head += &head_gradient;
+            tail += &tail_gradient;
+        }
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<T: ?Sized> Debug for RollBackward<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RollBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("shift", &self.shift)
+            .field("axis", &self.axis)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for RollBackward<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+/// Returns the position at which the first element of an axis of length `len` ends up once
+/// rolled by `shift`.
+fn roll_offset(shift: isize, len: usize) -> usize {
+    if len == 0 {
+        0
+    } else {
+        shift.rem_euclid(len as isize) as usize
+    }
+}
+
+/// Checks that `axis` is in bounds for a variable with `ndim` dimensions.
+fn check_axis(ndim: usize, axis: usize) {
+    assert!(
+        axis < ndim,
+        "error: axis {} is out of bounds for a variable with {} dimensions.",
+        axis,
+        ndim
+    );
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
@@ -1,7 +1,8 @@
 #[cfg(test)]
 use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
 use super::{
-    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite, Tensor,
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite, Summary,
+    Tensor,
 };
 use ndarray::Zip;
 use std::{
@@ -10,6 +11,25 @@ use std::{
     rc::Rc,
 };
 
+/// Computes *log(1 + exp(beta * x)) / beta*, reverting to the identity when *beta * x* exceeds
+/// `threshold`.
+fn softplus(x: f32, beta: f32, threshold: f32) -> f32 {
+    if beta * x > threshold {
+        x
+    } else {
+        (beta * x).exp().ln_1p() / beta
+    }
+}
+
+/// Computes the derivative of [`softplus`], that is *sigmoid(beta * x)*.
+fn softplus_derivative(x: f32, beta: f32, threshold: f32) -> f32 {
+    if beta * x > threshold {
+        1.
+    } else {
+        1. / (1. + (-beta * x).exp())
+    }
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ SoftPlus ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -19,6 +39,8 @@ where
 {
     operand: Rc<T>,
     data: RefCell<Tensor<T::Dim>>,
+    beta: f32,
+    threshold: f32,
     computed: Cell<bool>,
 }
 
@@ -26,12 +48,14 @@ impl<T: ?Sized> SoftPlus<T>
 where
     T: Data,
 {
-    pub fn new(operand: Rc<T>) -> Self {
+    pub fn new(operand: Rc<T>, beta: f32, threshold: f32) -> Self {
         let data = RefCell::new(Tensor::zeros(operand.data().raw_dim()));
 
         Self {
             operand,
             data,
+            beta,
+            threshold,
             computed: Cell::new(false),
         }
     }
@@ -60,9 +84,10 @@ where
         }
 
         self.computed.set(true);
+        let (beta, threshold) = (self.beta, self.threshold);
         Zip::from(&mut *self.data.borrow_mut())
             .and(&*self.operand.data())
-            .for_each(|v, o| *v = (1.0 + o.exp()).ln());
+            .for_each(|v, o| *v = softplus(*o, beta, threshold));
     }
 }
 
@@ -87,7 +112,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("SoftPlus")
-            .field("data", &self.data.borrow())
+            .field("data", &Summary(&self.data.borrow()))
             .field("computed", &self.computed.get())
             .finish()
     }
@@ -98,7 +123,7 @@ where
     T: Data,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", &self.data.borrow())
+        write!(f, "{}", Summary(&self.data.borrow()))
     }
 }
 
@@ -115,6 +140,8 @@ where
     overwrite: Cell<bool>,
     diff_operand: Rc<T>,
     no_diff_operand: Rc<U>,
+    beta: f32,
+    threshold: f32,
 }
 
 impl<T: ?Sized, U: ?Sized> SoftPlusBackward<T, U>
@@ -122,7 +149,7 @@ where
     T: Gradient,
     U: Data<Dim = T::Dim>,
 {
-    pub fn new(diff_operand: Rc<T>, no_diff_operand: Rc<U>) -> Self {
+    pub fn new(diff_operand: Rc<T>, no_diff_operand: Rc<U>, beta: f32, threshold: f32) -> Self {
         let shape = diff_operand.gradient().raw_dim();
 
         Self {
@@ -131,6 +158,8 @@ where
             overwrite: Cell::new(true),
             diff_operand,
             no_diff_operand,
+            beta,
+            threshold,
         }
     }
 }
@@ -174,16 +203,17 @@ where
         let mut op_grad = self.diff_operand.gradient_mut();
         let op_data = self.no_diff_operand.data();
         let grad = self.gradient();
+        let (beta, threshold) = (self.beta, self.threshold);
 
         let zip = Zip::from(&mut *op_grad).and(&*grad).and(&*op_data);
         if self.diff_operand.can_overwrite() {
             zip.for_each(|op_grad_el, grad_el, op_data_el| {
-                *op_grad_el = grad_el / (1.0 + (-*op_data_el).exp())
+                *op_grad_el = grad_el * softplus_derivative(*op_data_el, beta, threshold)
             });
             self.diff_operand.set_overwrite(false);
         } else {
             zip.for_each(|op_grad_el, grad_el, op_data_el| {
-                *op_grad_el += grad_el / (1.0 + (-*op_data_el).exp())
+                *op_grad_el += grad_el * softplus_derivative(*op_data_el, beta, threshold)
             });
         }
     }
@@ -204,7 +234,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("SoftPlusBackward")
-            .field("gradient", &self.gradient.borrow())
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
             .field("overwrite", &self.overwrite.get())
             .finish()
     }
@@ -217,7 +247,7 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match &*self.gradient.borrow() {
-            Some(gradient) => write!(f, "{}", &gradient),
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
             None => write!(f, "None"),
         }
     }
@@ -0,0 +1,306 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite, Summary,
+    Tensor,
+};
+use crate::nn::loss::Reduction;
+use ndarray::{Array, ArrayView1, Axis, Ix2, IxDyn, Zip};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ SparseCrossEntropyLoss ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct SparseCrossEntropyLoss<T: ?Sized>
+where
+    T: Data<Dim = Ix2>,
+{
+    operand: Rc<T>,
+    target: Vec<usize>,
+    data: RefCell<Tensor<IxDyn>>,
+    reduction: Reduction,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized> SparseCrossEntropyLoss<T>
+where
+    T: Data<Dim = Ix2>,
+{
+    pub(crate) fn new(operand: Rc<T>, target: Vec<usize>, reduction: Reduction) -> Self {
+        check_target(&operand.data().raw_dim(), &target);
+        let data = Tensor::zeros(reduction.shape(target.len()));
+
+        Self {
+            operand,
+            target,
+            data: RefCell::new(data),
+            reduction,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized> Data for SparseCrossEntropyLoss<T>
+where
+    T: Data<Dim = Ix2>,
+{
+    type Dim = IxDyn;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> Cache for SparseCrossEntropyLoss<T>
+where
+    T: Data<Dim = Ix2>,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized> Forward for SparseCrossEntropyLoss<T>
+where
+    T: Data<Dim = Ix2>,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let (mut loss_data, operand_data) = (self.data.borrow_mut(), self.operand.data());
+
+        // Only the log-probability of the target class of each sample is needed.
+        let loss = Array::from_iter(
+            operand_data
+                .rows()
+                .into_iter()
+                .zip(&self.target)
+                .map(|(logits, target)| log_sum_exp(logits) - logits[*target]),
+        );
+        *loss_data = self
+            .reduction
+            .reduce(loss, operand_data.len_of(Axis(0)) as f32);
+    }
+}
+
+impl<T: ?Sized> Debug for SparseCrossEntropyLoss<T>
+where
+    T: Data<Dim = Ix2>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SparseCrossEntropyLoss")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("target", &self.target)
+            .field("reduction", &self.reduction)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for SparseCrossEntropyLoss<T>
+where
+    T: Data<Dim = Ix2>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ SparseCrossEntropyLossBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct SparseCrossEntropyLossBackward<T: ?Sized, U: ?Sized>
+where
+    T: Gradient<Dim = Ix2>,
+    U: Data<Dim = Ix2>,
+{
+    gradient: RefCell<Option<Tensor<IxDyn>>>,
+    overwrite: Cell<bool>,
+    diff_operand: Rc<T>,
+    operand: Rc<U>,
+    target: Vec<usize>,
+    reduction: Reduction,
+}
+
+impl<T: ?Sized, U: ?Sized> SparseCrossEntropyLossBackward<T, U>
+where
+    T: Gradient<Dim = Ix2>,
+    U: Data<Dim = Ix2>,
+{
+    pub(crate) fn new(
+        diff_operand: Rc<T>,
+        operand: Rc<U>,
+        target: Vec<usize>,
+        reduction: Reduction,
+    ) -> Self {
+        check_target(&diff_operand.gradient().raw_dim(), &target);
+        let gradient = Tensor::zeros(reduction.shape(target.len()));
+
+        Self {
+            gradient: RefCell::new(Some(gradient)),
+            overwrite: Cell::new(true),
+            diff_operand,
+            operand,
+            target,
+            reduction,
+        }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Gradient for SparseCrossEntropyLossBackward<T, U>
+where
+    T: Gradient<Dim = Ix2>,
+    U: Data<Dim = Ix2>,
+{
+    type Dim = IxDyn;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Overwrite for SparseCrossEntropyLossBackward<T, U>
+where
+    T: Gradient<Dim = Ix2>,
+    U: Data<Dim = Ix2>,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Backward for SparseCrossEntropyLossBackward<T, U>
+where
+    T: Gradient<Dim = Ix2>,
+    U: Data<Dim = Ix2>,
+{
+    fn backward(&self) {
+        let (mut operand_gradient, gradient, operand_data) = (
+            self.diff_operand.gradient_mut(),
+            self.gradient(),
+            self.operand.data(),
+        );
+        let n = self.reduction.scale(operand_data.len_of(Axis(0)) as f32);
+        let gradient = gradient.broadcast(self.target.len()).unwrap();
+
+        // The derivative wrt the logits is the softmax minus the one-hot target, the latter
+        // being subtracted from the target class alone.
+        let overwrite = self.diff_operand.can_overwrite();
+        operand_gradient
+            .rows_mut()
+            .into_iter()
+            .zip(operand_data.rows())
+            .zip(&self.target)
+            .zip(gradient)
+            .for_each(|(((mut op_grad, logits), target), grad)| {
+                let grad = grad / n;
+                let log_sum_exp = log_sum_exp(logits);
+                Zip::from(&mut op_grad)
+                    .and(&logits)
+                    .for_each(|op_grad_el, logit| {
+                        let derivative = grad * (logit - log_sum_exp).exp();
+                        if overwrite {
+                            *op_grad_el = derivative;
+                        } else {
+                            *op_grad_el += derivative;
+                        }
+                    });
+                op_grad[*target] -= grad;
+            });
+        if overwrite {
+            self.diff_operand.set_overwrite(false);
+        }
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        let shape = self.reduction.shape(self.target.len());
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(shape));
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Debug for SparseCrossEntropyLossBackward<T, U>
+where
+    T: Gradient<Dim = Ix2>,
+    U: Data<Dim = Ix2>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SparseCrossEntropyLossBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("target", &self.target)
+            .field("reduction", &self.reduction)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Display for SparseCrossEntropyLossBackward<T, U>
+where
+    T: Gradient<Dim = Ix2>,
+    U: Data<Dim = Ix2>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+/// Checks that there is exactly one target for each sample of the logits of shape *(N, C)* and
+/// that each of them is one of the C classes.
+fn check_target(shape: &Ix2, target: &[usize]) {
+    let (samples, classes) = (shape[0], shape[1]);
+    assert_eq!(
+        target.len(),
+        samples,
+        "error: {} targets were given for a batch of {} samples.",
+        target.len(),
+        samples
+    );
+    if let Some(class) = target.iter().find(|class| **class >= classes) {
+        panic!(
+            "error: target class {} is out of range for {} classes.",
+            class, classes
+        );
+    }
+}
+
+/// Computes the logarithm of the sum of the exponentials of `logits`, shifting them by their
+/// maximum for numerical stability.
+fn log_sum_exp(logits: ArrayView1<f32>) -> f32 {
+    let max = logits.fold(f32::MIN, |x, y| x.max(*y));
+    logits.fold(0., |acc, el| acc + (el - max).exp()).ln() + max
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
@@ -0,0 +1,151 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Data, Forward,
+    Gradient, Overwrite, Reduction, SparseCrossEntropyLoss, SparseCrossEntropyLossBackward,
+};
+use ndarray::arr0;
+
+#[test]
+fn mean() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input = new_input((3, 3), vec![1., 2., 0.5, 0.3, -1., 2., -0.5, 0.2, 0.1]);
+    let loss = SparseCrossEntropyLoss::new(input.clone(), vec![1, 0, 2], Reduction::Mean);
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(1.116484).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input((3, 3), vec![0.; 9]);
+    let loss_backward = SparseCrossEntropyLossBackward::new(
+        input_diff.clone(),
+        input,
+        vec![1, 0, 2],
+        Reduction::Mean,
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_eq!(input_diff.gradient().shape(), &[3, 3]);
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (3, 3),
+            vec![
+                0.077075, -0.123823, 0.046748, -0.283925, 0.013465, 0.270459, 0.068929, 0.138807,
+                -0.207736,
+            ],
+        ),
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ 2nd Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (3, 3),
+            vec![
+                0.15415, -0.247646, 0.093496, -0.56785, 0.02693, 0.540918, 0.137858, 0.277614,
+                -0.415472,
+            ],
+        ),
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Overwrite ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    input_diff.set_overwrite(true);
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (3, 3),
+            vec![
+                0.077075, -0.123823, 0.046748, -0.283925, 0.013465, 0.270459, 0.068929, 0.138807,
+                -0.207736,
+            ],
+        ),
+    );
+}
+
+#[test]
+fn sum() {
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Forward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input = new_input((3, 3), vec![1., 2., 0.5, 0.3, -1., 2., -0.5, 0.2, 0.1]);
+    let loss = SparseCrossEntropyLoss::new(input.clone(), vec![1, 0, 2], Reduction::Sum);
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(3.349451).into_dyn());
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Backward Pass ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let input_diff = new_backward_input((3, 3), vec![0.; 9]);
+    let loss_backward = SparseCrossEntropyLossBackward::new(
+        input_diff.clone(),
+        input,
+        vec![1, 0, 2],
+        Reduction::Sum,
+    );
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    loss_backward.backward();
+    assert_almost_equals(
+        &*input_diff.gradient(),
+        &new_tensor(
+            (3, 3),
+            vec![
+                0.231224, -0.371468, 0.140244, -0.851775, 0.040396, 0.811378, 0.206788, 0.41642,
+                -0.623208,
+            ],
+        ),
+    );
+}
+
+#[test]
+fn large_logits() {
+    let input = new_input((1, 3), vec![1000., 0., -1000.]);
+    let loss = SparseCrossEntropyLoss::new(input.clone(), vec![0], Reduction::Sum);
+
+    loss.forward();
+    assert_almost_equals(&*loss.data(), &arr0(0.).into_dyn());
+
+    let input_diff = new_backward_input((1, 3), vec![0.; 3]);
+    let loss_backward =
+        SparseCrossEntropyLossBackward::new(input_diff.clone(), input, vec![0], Reduction::Sum);
+    *loss_backward.gradient_mut() = arr0(1.).into_dyn();
+
+    loss_backward.backward();
+    assert_almost_equals(&*input_diff.gradient(), &new_tensor((1, 3), vec![0.; 3]));
+}
+
+#[test]
+#[should_panic(expected = "error: 2 targets were given for a batch of 3 samples.")]
+fn wrong_number_of_targets() {
+    let input = new_input((3, 3), vec![0.; 9]);
+    SparseCrossEntropyLoss::new(input, vec![1, 0], Reduction::Mean);
+}
+
+#[test]
+#[should_panic(expected = "error: target class 3 is out of range for 3 classes.")]
+fn target_out_of_range() {
+    let input_diff = new_backward_input((3, 3), vec![0.; 9]);
+    let input = new_input((3, 3), vec![0.; 9]);
+    SparseCrossEntropyLossBackward::new(input_diff, input, vec![1, 3, 0], Reduction::Mean);
+}
+
+#[test]
+fn debug() {
+    let input = new_input((1, 2), vec![0.; 2]);
+    let loss = SparseCrossEntropyLoss::new(input.clone(), vec![1], Reduction::Mean);
+
+    let output = "SparseCrossEntropyLoss { data: 0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0, target: [1], reduction: Mean, computed: false }";
+    assert_eq!(output, format!("{:?}", loss));
+
+    let input_diff = new_backward_input((1, 2), vec![0.; 2]);
+    let loss_backward =
+        SparseCrossEntropyLossBackward::new(input_diff, input, vec![1], Reduction::Sum);
+
+    let output = "SparseCrossEntropyLossBackward { gradient: Some(0.0, shape=[], strides=[], layout=CFcf (0xf), dynamic ndim=0), target: [1], reduction: Sum, overwrite: true }";
+    assert_eq!(output, format!("{:?}", loss_backward));
+}
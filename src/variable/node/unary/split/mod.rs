@@ -0,0 +1,286 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite, Summary,
+    Tensor,
+};
+use ndarray::{Axis, Dimension, Slice, Zip};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Split ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct Split<T: ?Sized>
+where
+    T: Data,
+{
+    operand: Rc<T>,
+    data: RefCell<Tensor<T::Dim>>,
+    axis: usize,
+    split_no: usize,
+    start: usize,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized> Split<T>
+where
+    T: Data,
+{
+    pub fn new(operand: Rc<T>, axis: usize, sizes: &[usize], split_no: usize) -> Self {
+        let mut shape = operand.data().raw_dim();
+        let start = split_start(shape.slice(), axis, sizes, split_no);
+        shape[axis] = sizes[split_no];
+
+        Self {
+            operand,
+            data: RefCell::new(Tensor::zeros(shape)),
+            axis,
+            split_no,
+            start,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized> Cache for Split<T>
+where
+    T: Data,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized> Forward for Split<T>
+where
+    T: Data,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        let mut data = self.data.borrow_mut();
+        let slice = Slice::from(self.start..self.start + data.len_of(Axis(self.axis)));
+        data.assign(&self.operand.data().slice_axis(Axis(self.axis), slice));
+    }
+}
+
+impl<T: ?Sized> Data for Split<T>
+where
+    T: Data,
+{
+    type Dim = T::Dim;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> Debug for Split<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Split")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("axis", &self.axis)
+            .field("split_no", &self.split_no)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for Split<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ SplitBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct SplitBackward<T: ?Sized>
+where
+    T: Gradient,
+{
+    gradient: RefCell<Option<Tensor<T::Dim>>>,
+    shape: T::Dim,
+    overwrite: Cell<bool>,
+    operand: Rc<T>,
+    axis: usize,
+    split_no: usize,
+    start: usize,
+}
+
+impl<T: ?Sized> SplitBackward<T>
+where
+    T: Gradient,
+{
+    pub fn new(operand: Rc<T>, axis: usize, sizes: &[usize], split_no: usize) -> Self {
+        let mut shape = operand.gradient().raw_dim();
+        let start = split_start(shape.slice(), axis, sizes, split_no);
+        shape[axis] = sizes[split_no];
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape.clone()))),
+            shape,
+            overwrite: Cell::new(true),
+            operand,
+            axis,
+            split_no,
+            start,
+        }
+    }
+}
+
+impl<T: ?Sized> Gradient for SplitBackward<T>
+where
+    T: Gradient,
+{
+    type Dim = T::Dim;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized> Overwrite for SplitBackward<T>
+where
+    T: Gradient,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized> Backward for SplitBackward<T>
+where
+    T: Gradient,
+{
+    fn backward(&self) {
+        let (mut operand_gradient, gradient) = (self.operand.gradient_mut(), self.gradient());
+        let slice = Slice::from(self.start..self.start + self.shape[self.axis]);
+
+        // The other pieces of the split may not be part of the graph, so the whole gradient of
+        // the operand is reset rather than just the slice of this piece.
+        if self.operand.can_overwrite() {
+            operand_gradient.fill(0.);
+            self.operand.set_overwrite(false);
+        }
+
+        Zip::from(operand_gradient.slice_axis_mut(Axis(self.axis), slice))
+            .and(&*gradient)
+            .for_each(|operand_gradient_el, gradient_el| *operand_gradient_el += gradient_el);
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<T: ?Sized> Debug for SplitBackward<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SplitBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("axis", &self.axis)
+            .field("split_no", &self.split_no)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for SplitBackward<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+/// Returns the position along `axis` at which the piece number `split_no` of a split in pieces of
+/// length `sizes` starts.
+///
+/// # Panics
+///
+/// If `axis` is out of bounds or if `sizes` don't add up to its length.
+fn split_start(shape: &[usize], axis: usize, sizes: &[usize], split_no: usize) -> usize {
+    assert!(
+        axis < shape.len(),
+        "error: axis {} is out of bounds for a variable with {} dimensions.",
+        axis,
+        shape.len()
+    );
+    assert_eq!(
+        sizes.iter().sum::<usize>(),
+        shape[axis],
+        "error: split sizes {:?} don't add up to the length {} of axis {}.",
+        sizes,
+        shape[axis],
+        axis
+    );
+
+    sizes[..split_no].iter().sum()
+}
+
+/// Returns the sizes of `chunks` pieces dividing `axis` as evenly as possible, the first ones
+/// being one element longer than the others when its length isn't divisible by `chunks`.
+///
+/// # Panics
+///
+/// If `axis` is out of bounds or if `chunks` is zero.
+pub(crate) fn chunk_sizes(shape: &[usize], axis: usize, chunks: usize) -> Vec<usize> {
+    assert!(
+        axis < shape.len(),
+        "error: axis {} is out of bounds for a variable with {} dimensions.",
+        axis,
+        shape.len()
+    );
+    assert!(chunks > 0, "error: cannot split a variable into 0 chunks.");
+
+    let len = shape[axis];
+    (0..chunks)
+        .map(|i| len / chunks + usize::from(i < len % chunks))
+        .collect()
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
@@ -0,0 +1,227 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, Overwrite, Split, SplitBackward, Tensor,
+};
+
+mod forward {
+    use super::{assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, Split, Tensor};
+
+    #[test]
+    fn creation() {
+        let input = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Split::new(input, 0, &[1, 2], 1);
+
+        assert_eq!(*node.data(), Tensor::from_elem((2, 2), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((2, 2), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Split::new(input, 0, &[1, 2], 1);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic(expected = "error: split sizes [1, 1] don't add up to the length 3 of axis 0.")]
+    fn fail() {
+        Split::new(
+            new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+            0,
+            &[1, 1],
+            1,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "error: axis 2 is out of bounds for a variable with 2 dimensions.")]
+    fn fail_axis() {
+        Split::new(
+            new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]),
+            2,
+            &[1, 2],
+            0,
+        );
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Split::new(input.clone(), 0, &[1, 2], 1);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((2, 2), vec![3., 4., 5., 6.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(
+            &*input.data(),
+            &new_tensor((3, 2), vec![2., 3., 4., 5., 6., 7.]),
+        );
+
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((2, 2), vec![3., 4., 5., 6.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((2, 2), vec![4., 5., 6., 7.]));
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Split::new(input, 0, &[1, 2], 1);
+
+        let output = "Split { data: [[0.0, 0.0],\n [0.0, 0.0]], shape=[2, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2, axis: 0, split_no: 1, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((3, 2), vec![1., 2., 3., 4., 5., 6.]);
+        let node = Split::new(input, 0, &[1, 2], 1);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_tensor, Backward, Gradient, Overwrite,
+        SplitBackward, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = SplitBackward::new(new_backward_input((3, 2), vec![0.; 6]), 1, &[1, 1], 0);
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((3, 1), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((3, 1), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    #[should_panic(expected = "error: split sizes [3] don't add up to the length 2 of axis 1.")]
+    fn fail() {
+        SplitBackward::new(new_backward_input((3, 2), vec![0.; 6]), 1, &[3], 0);
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((3, 2), vec![0.; 6]);
+        let node = SplitBackward::new(diff.clone(), 0, &[1, 2], 1);
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        // The stale gradient of the operand must be discarded on overwrite.
+        let diff = new_backward_input((3, 2), vec![5.; 6]);
+        let node = SplitBackward::new(diff.clone(), 0, &[1, 2], 1);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((2, 2), vec![1., 2., 3., 4.]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((2, 2), vec![1., 2., 3., 4.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 2), vec![0., 0., 1., 2., 3., 4.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 2), vec![0., 0., 2., 4., 6., 8.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 2), vec![0., 0., 1., 2., 3., 4.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let diff = new_backward_input((3, 2), vec![0.; 6]);
+        let node = SplitBackward::new(diff, 0, &[1, 2], 1);
+
+        let output = "SplitBackward { gradient: Some([[0.0, 0.0],\n [0.0, 0.0]], shape=[2, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2), axis: 0, split_no: 1, overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let diff = new_backward_input((3, 2), vec![0.; 6]);
+        let node = SplitBackward::new(diff, 0, &[1, 2], 1);
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // SplitBackward
+        let node = SplitBackward::new(new_backward_input((3, 2), vec![0.; 6]), 0, &[1, 2], 1);
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
@@ -0,0 +1,256 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, push_gradient, Backward, Cache, Data, Forward, Gradient,
+    Overwrite, Summary, Tensor,
+};
+use ndarray::{Axis, Dimension, RemoveAxis};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Squeeze ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct Squeeze<T: ?Sized>
+where
+    T: Data,
+    T::Dim: RemoveAxis,
+{
+    operand: Rc<T>,
+    data: RefCell<Tensor<<T::Dim as Dimension>::Smaller>>,
+    axis: usize,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized> Squeeze<T>
+where
+    T: Data,
+    T::Dim: RemoveAxis,
+{
+    pub fn new(operand: Rc<T>, axis: usize) -> Self {
+        let shape = operand.data().raw_dim();
+        check_squeezable(shape.slice(), axis);
+        let data = RefCell::new(Tensor::zeros(shape.remove_axis(Axis(axis))));
+
+        Self {
+            operand,
+            data,
+            axis,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized> Cache for Squeeze<T>
+where
+    T: Data,
+    T::Dim: RemoveAxis,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized> Forward for Squeeze<T>
+where
+    T: Data,
+    T::Dim: RemoveAxis,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        self.data
+            .borrow_mut()
+            .assign(&self.operand.data().index_axis(Axis(self.axis), 0));
+    }
+}
+
+impl<T: ?Sized> Data for Squeeze<T>
+where
+    T: Data,
+    T::Dim: RemoveAxis,
+{
+    type Dim = <T::Dim as Dimension>::Smaller;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> Debug for Squeeze<T>
+where
+    T: Data,
+    T::Dim: RemoveAxis,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Squeeze")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("axis", &self.axis)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for Squeeze<T>
+where
+    T: Data,
+    T::Dim: RemoveAxis,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ SqueezeBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct SqueezeBackward<T: ?Sized>
+where
+    T: Gradient,
+    T::Dim: RemoveAxis,
+{
+    gradient: RefCell<Option<Tensor<<T::Dim as Dimension>::Smaller>>>,
+    shape: <T::Dim as Dimension>::Smaller,
+    overwrite: Cell<bool>,
+    operand: Rc<T>,
+    axis: usize,
+}
+
+impl<T: ?Sized> SqueezeBackward<T>
+where
+    T: Gradient,
+    T::Dim: RemoveAxis,
+{
+    pub fn new(operand: Rc<T>, axis: usize) -> Self {
+        let shape = operand.gradient().raw_dim();
+        check_squeezable(shape.slice(), axis);
+        let gradient = Tensor::zeros(shape.remove_axis(Axis(axis)));
+        let shape = gradient.raw_dim();
+
+        Self {
+            gradient: RefCell::new(Some(gradient)),
+            shape,
+            overwrite: Cell::new(true),
+            operand,
+            axis,
+        }
+    }
+}
+
+impl<T: ?Sized> Gradient for SqueezeBackward<T>
+where
+    T: Gradient,
+    T::Dim: RemoveAxis,
+{
+    type Dim = <T::Dim as Dimension>::Smaller;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized> Overwrite for SqueezeBackward<T>
+where
+    T: Gradient,
+    T::Dim: RemoveAxis,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized> Backward for SqueezeBackward<T>
+where
+    T: Gradient,
+    T::Dim: RemoveAxis,
+{
+    fn backward(&self) {
+        push_gradient(
+            &*self.operand,
+            self.gradient()
+                .view()
+                .insert_axis(Axis(self.axis))
+                .into_dimensionality::<T::Dim>()
+                .unwrap(),
+        );
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<T: ?Sized> Debug for SqueezeBackward<T>
+where
+    T: Gradient,
+    T::Dim: RemoveAxis,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SqueezeBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("axis", &self.axis)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for SqueezeBackward<T>
+where
+    T: Gradient,
+    T::Dim: RemoveAxis,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+/// Checks that `axis` exists in `shape` and has length one.
+fn check_squeezable(shape: &[usize], axis: usize) {
+    assert!(
+        axis < shape.len(),
+        "error: axis {} is out of bounds for a variable with {} dimensions.",
+        axis,
+        shape.len()
+    );
+    assert!(
+        shape[axis] == 1,
+        "error: cannot squeeze axis {} of length {}, only axes of length one can be squeezed.",
+        axis,
+        shape[axis]
+    );
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
@@ -0,0 +1,258 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, Overwrite, Squeeze, SqueezeBackward, Tensor,
+};
+
+mod forward {
+    use super::{
+        assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, Squeeze, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((1, 3, 1), vec![1., 2., 3.]);
+        let node = Squeeze::new(input, 0);
+
+        assert_eq!(*node.data(), Tensor::from_elem((3, 1), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((3, 1), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((1, 3, 1), vec![1., 2., 3.]);
+        let node = Squeeze::new(input, 0);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: cannot squeeze axis 1 of length 3, only axes of length one can be squeezed."
+    )]
+    fn fail_middle() {
+        Squeeze::new(new_input((1, 3, 1), vec![1., 2., 3.]), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "error: axis 3 is out of bounds for a variable with 3 dimensions.")]
+    fn fail_out_of_bounds() {
+        Squeeze::new(new_input((1, 3, 1), vec![1., 2., 3.]), 3);
+    }
+
+    #[test]
+    fn forward_first() {
+        let input = new_input((1, 3, 1), vec![1., 2., 3.]);
+        let node = Squeeze::new(input.clone(), 0);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((3, 1), vec![1., 2., 3.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(&*input.data(), &new_tensor((1, 3, 1), vec![2., 3., 4.]));
+
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((3, 1), vec![1., 2., 3.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((3, 1), vec![2., 3., 4.]));
+    }
+
+    #[test]
+    fn forward_last() {
+        let input = new_input((1, 3, 1), vec![1., 2., 3.]);
+        let node = Squeeze::new(input.clone(), 2);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((1, 3), vec![1., 2., 3.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(&*input.data(), &new_tensor((1, 3, 1), vec![2., 3., 4.]));
+
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((1, 3), vec![1., 2., 3.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(&*node.data(), &new_tensor((1, 3), vec![2., 3., 4.]));
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((1, 3, 1), vec![1., 2., 3.]);
+        let node = Squeeze::new(input, 2);
+
+        let output = "Squeeze { data: [[0.0, 0.0, 0.0]], shape=[1, 3], strides=[3, 1], layout=CFcf (0xf), const ndim=2, axis: 2, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((1, 3, 1), vec![1., 2., 3.]);
+        let node = Squeeze::new(input, 2);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_tensor, Backward, Gradient, Overwrite,
+        SqueezeBackward, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = SqueezeBackward::new(new_backward_input((1, 3, 1), vec![0.; 3]), 0);
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((3, 1), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((3, 1), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "error: cannot squeeze axis 1 of length 3, only axes of length one can be squeezed."
+    )]
+    fn fail_middle() {
+        SqueezeBackward::new(new_backward_input((1, 3, 1), vec![0.; 3]), 1);
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((1, 3, 1), vec![0.; 3]);
+        let node = SqueezeBackward::new(diff.clone(), 0);
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward_first() {
+        let diff = new_backward_input((1, 3, 1), vec![0.; 3]);
+        let node = SqueezeBackward::new(diff.clone(), 0);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((3, 1), vec![1., 2., 3.]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((3, 1), vec![1., 2., 3.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor((1, 3, 1), vec![1., 2., 3.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor((1, 3, 1), vec![2., 4., 6.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor((1, 3, 1), vec![1., 2., 3.]));
+    }
+
+    #[test]
+    fn backward_last() {
+        let diff = new_backward_input((1, 3, 1), vec![0.; 3]);
+        let node = SqueezeBackward::new(diff.clone(), 2);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((1, 3), vec![1., 2., 3.]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((1, 3), vec![1., 2., 3.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor((1, 3, 1), vec![1., 2., 3.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor((1, 3, 1), vec![2., 4., 6.]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(&*diff.gradient(), &new_tensor((1, 3, 1), vec![1., 2., 3.]));
+    }
+
+    #[test]
+    fn debug() {
+        let diff = new_backward_input((1, 3, 1), vec![0.; 3]);
+        let node = SqueezeBackward::new(diff, 2);
+
+        let output = "SqueezeBackward { gradient: Some([[0.0, 0.0, 0.0]], shape=[1, 3], strides=[3, 1], layout=CFcf (0xf), const ndim=2), axis: 2, overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let diff = new_backward_input((1, 3, 1), vec![0.; 3]);
+        let node = SqueezeBackward::new(diff, 2);
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // SqueezeBackward
+        let node = SqueezeBackward::new(new_backward_input((1, 3, 1), vec![0.; 3]), 0);
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
@@ -0,0 +1,284 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, Overwrite, Rc, Swish, SwishBackward, Tensor,
+};
+
+mod forward {
+    use super::{assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, Swish, Tensor};
+
+    #[test]
+    fn creation() {
+        let input = new_input((3, 3), vec![-3., -2., -1., 0., 1., 2., 3., 4., 5.]);
+        let node = Swish::new(input);
+
+        assert_eq!(*node.data(), Tensor::from_elem((3, 3), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((3, 3), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((3, 3), vec![-3., -2., -1., 0., 1., 2., 3., 4., 5.]);
+        let node = Swish::new(input);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((3, 3), vec![-3., -2., -1., 0., 1., 2., 3., 4., 5.]);
+        let node = Swish::new(input.clone());
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (3, 3),
+                vec![
+                    -0.142278, -0.238406, -0.268941, 0., 0.731059, 1.761594, 2.857722, 3.928055,
+                    4.966536,
+                ],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(
+            &*input.data(),
+            &new_tensor((3, 3), vec![-2., -1., 0., 1., 2., 3., 4., 5., 6.]),
+        );
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (3, 3),
+                vec![
+                    -0.142278, -0.238406, -0.268941, 0., 0.731059, 1.761594, 2.857722, 3.928055,
+                    4.966536,
+                ],
+            ),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor(
+                (3, 3),
+                vec![
+                    -0.238406, -0.268941, 0., 0.731059, 1.761594, 2.857722, 3.928055, 4.966536,
+                    5.985164,
+                ],
+            ),
+        );
+    }
+
+    #[test]
+    fn forward_zero() {
+        let node = Swish::new(new_input(1, vec![0.]));
+        node.forward();
+
+        assert_eq!(*node.data(), new_tensor(1, vec![0.]));
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((3, 3), vec![1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+        let node = Swish::new(input);
+
+        let output = "Swish { data: [[0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0]], shape=[3, 3], strides=[3, 1], layout=Cc (0x5), const ndim=2, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((3, 3), vec![1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+        let node = Swish::new(input);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Data, Forward,
+        Gradient, Overwrite, Rc, Swish, SwishBackward, Tensor,
+    };
+
+    #[test]
+    fn creation() {
+        let node = SwishBackward::new(
+            new_backward_input(3, vec![0.; 3]),
+            Rc::new(Swish::new(new_input(3, vec![1., 2., 3.]))),
+        );
+
+        assert_eq!(*node.gradient(), Tensor::from_elem(3, 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem(3, 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input(3, vec![0.; 3]);
+        let swish = Rc::new(Swish::new(new_input(3, vec![1., 2., 3.])));
+        swish.forward();
+        let node = SwishBackward::new(diff.clone(), swish);
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        let diff = new_backward_input(3, vec![0.; 3]);
+        let swish = Rc::new(Swish::new(new_input(3, vec![1., 2., 3.])));
+        swish.forward();
+        let node = SwishBackward::new(diff.clone(), swish);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor(3, vec![1.; 3]);
+        assert_almost_equals(&*node.gradient(), &new_tensor(3, vec![1.; 3]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(3, vec![0.927671, 1.090784, 1.088104]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(3, vec![1.855342, 2.181568, 2.176208]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor(3, vec![0.927671, 1.090784, 1.088104]),
+        );
+    }
+
+    #[test]
+    fn backward_zero() {
+        let diff = new_backward_input(1, vec![0.]);
+        let swish = Rc::new(Swish::new(new_input(1, vec![0.])));
+        swish.forward();
+        let node = SwishBackward::new(diff.clone(), swish);
+        *node.gradient_mut() = new_tensor(1, vec![1.]);
+        node.backward();
+
+        assert_eq!(*diff.gradient(), new_tensor(1, vec![0.5]));
+    }
+
+    #[test]
+    fn backward_numeric() {
+        let data = vec![-3., -1.5, -0.5, 0., 0.25, 1., 2.5];
+        let diff = new_backward_input(7, vec![0.; 7]);
+        let swish = Rc::new(Swish::new(new_input(7, data.clone())));
+        swish.forward();
+        let node = SwishBackward::new(diff.clone(), swish);
+        *node.gradient_mut() = new_tensor(7, vec![1.; 7]);
+        node.backward();
+
+        // Central finite differences of the forward computation.
+        let h = 1e-2;
+        let swish = |x: f32| {
+            let node = Swish::new(new_input(1, vec![x]));
+            node.forward();
+            let value = node.data()[0];
+            value
+        };
+        let numeric = data
+            .iter()
+            .map(|&x| (swish(x + h) - swish(x - h)) / (2. * h));
+
+        assert!(diff
+            .gradient()
+            .iter()
+            .zip(numeric)
+            .all(|(analytic, numeric)| (analytic - numeric).abs() < 1e-2));
+    }
+
+    #[test]
+    fn debug() {
+        let diff = new_backward_input(3, vec![0.; 3]);
+        let node = SwishBackward::new(diff, Rc::new(Swish::new(new_input(3, vec![1., 2., 3.]))));
+
+        let output = "SwishBackward { gradient: Some([0.0, 0.0, 0.0], shape=[3], strides=[1], layout=CFcf (0xf), const ndim=1), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let diff = new_backward_input(3, vec![0.; 3]);
+        let node = SwishBackward::new(diff, Rc::new(Swish::new(new_input(3, vec![1., 2., 3.]))));
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // SwishBackward
+        let node = SwishBackward::new(
+            new_backward_input((3, 3), vec![0.; 9]),
+            Rc::new(Swish::new(new_input((3, 3), vec![0.; 9]))),
+        );
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
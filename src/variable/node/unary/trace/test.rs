@@ -0,0 +1,209 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, Overwrite, Tensor, Trace, TraceBackward,
+};
+use ndarray::arr0;
+
+mod forward {
+    use super::{
+        arr0, assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, Tensor, Trace,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((3, 4), (1..=12).map(|el| el as f32).collect());
+        let node = Trace::new(input);
+
+        assert_eq!(*node.data(), arr0(0.));
+        assert_eq!(*node.data_mut(), arr0(0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((3, 4), (1..=12).map(|el| el as f32).collect());
+        let node = Trace::new(input);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((3, 4), (1..=12).map(|el| el as f32).collect());
+        let node = Trace::new(input.clone());
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(&*node.data(), &arr0(18.));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(
+            &*input.data(),
+            &new_tensor((3, 4), (2..=13).map(|el| el as f32).collect()),
+        );
+
+        node.forward();
+        assert_almost_equals(&*node.data(), &arr0(18.));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(&*node.data(), &arr0(21.));
+    }
+
+    #[test]
+    fn forward_tall() {
+        let input = new_input((4, 3), (1..=12).map(|el| el as f32).collect());
+        let node = Trace::new(input);
+
+        node.forward();
+        assert_almost_equals(&*node.data(), &arr0(15.));
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((3, 4), (1..=12).map(|el| el as f32).collect());
+        let node = Trace::new(input);
+
+        let output = "Trace { data: 0.0, shape=[], strides=[], layout=CFcf (0xf), const ndim=0, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((3, 4), (1..=12).map(|el| el as f32).collect());
+        let node = Trace::new(input);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        arr0, assert_almost_equals, new_backward_input, new_tensor, Backward, Gradient, Overwrite,
+        TraceBackward,
+    };
+
+    #[test]
+    fn creation() {
+        let node = TraceBackward::new(new_backward_input((3, 4), vec![0.; 12]));
+
+        assert_eq!(*node.gradient(), arr0(0.));
+        assert_eq!(*node.gradient_mut(), arr0(0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((3, 4), vec![0.; 12]);
+        let node = TraceBackward::new(diff.clone());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        // The entries off the diagonal are cleared when the gradient is overwritten.
+        let diff = new_backward_input((3, 4), vec![5.; 12]);
+        let node = TraceBackward::new(diff.clone());
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = arr0(2.);
+        assert_almost_equals(&*node.gradient(), &arr0(2.));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 4), vec![2., 0., 0., 0., 0., 2., 0., 0., 0., 0., 2., 0.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 4), vec![4., 0., 0., 0., 0., 4., 0., 0., 0., 0., 4., 0.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 4), vec![2., 0., 0., 0., 0., 2., 0., 0., 0., 0., 2., 0.]),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let node = TraceBackward::new(new_backward_input((3, 4), vec![0.; 12]));
+
+        let output = "TraceBackward { gradient: Some(0.0, shape=[], strides=[], layout=CFcf (0xf), const ndim=0), overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let node = TraceBackward::new(new_backward_input((3, 4), vec![0.; 12]));
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // TraceBackward
+        let node = TraceBackward::new(new_backward_input((3, 4), vec![0.; 12]));
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), arr0(0.));
+    }
+}
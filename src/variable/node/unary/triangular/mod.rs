@@ -0,0 +1,274 @@
+#[cfg(test)]
+use super::{assert_almost_equals, new_backward_input, new_input, new_tensor};
+use super::{
+    expect_tensor, expect_tensor_mut, Backward, Cache, Data, Forward, Gradient, Overwrite, Summary,
+    Tensor,
+};
+use ndarray::{Array2, Dimension, Zip};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+/// The triangle of a matrix that is kept by a triangular masking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Triangle {
+    /// The elements on and above the diagonal.
+    Upper,
+    /// The elements on and below the diagonal.
+    Lower,
+}
+
+/// Computes the mask of the elements of the last two axes of an array of shape `shape` that are
+/// kept. A positive `offset` selects a diagonal above the main one, a negative `offset` one below
+/// it.
+fn triangular_mask<D: Dimension>(shape: &D, triangle: Triangle, offset: isize) -> Array2<bool> {
+    let ndim = shape.ndim();
+    assert!(
+        ndim >= 2,
+        "error: triangular masking needs at least two axes, found {}.",
+        ndim
+    );
+
+    Array2::from_shape_fn((shape[ndim - 2], shape[ndim - 1]), |(row, col)| {
+        let distance = col as isize - row as isize;
+        match triangle {
+            Triangle::Upper => distance >= offset,
+            Triangle::Lower => distance <= offset,
+        }
+    })
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Triangular ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct Triangular<T: ?Sized>
+where
+    T: Data,
+{
+    operand: Rc<T>,
+    data: RefCell<Tensor<T::Dim>>,
+    triangle: Triangle,
+    offset: isize,
+    mask: Array2<bool>,
+    computed: Cell<bool>,
+}
+
+impl<T: ?Sized> Triangular<T>
+where
+    T: Data,
+{
+    pub fn new(operand: Rc<T>, triangle: Triangle, offset: isize) -> Self {
+        let shape = operand.data().raw_dim();
+        let mask = triangular_mask(&shape, triangle, offset);
+        let data = RefCell::new(Tensor::zeros(shape));
+
+        Self {
+            operand,
+            data,
+            triangle,
+            offset,
+            mask,
+            computed: Cell::new(false),
+        }
+    }
+}
+
+impl<T: ?Sized> Cache for Triangular<T>
+where
+    T: Data,
+{
+    fn was_computed(&self) -> bool {
+        self.computed.get()
+    }
+
+    fn reset_computation(&self) {
+        self.computed.set(false);
+    }
+}
+
+impl<T: ?Sized> Forward for Triangular<T>
+where
+    T: Data,
+{
+    fn forward(&self) {
+        if self.was_computed() {
+            return;
+        }
+
+        self.computed.set(true);
+        Zip::from(&mut *self.data.borrow_mut())
+            .and(&*self.operand.data())
+            .and_broadcast(&self.mask)
+            .for_each(|v, &o, &kept| *v = if kept { o } else { 0. });
+    }
+}
+
+impl<T: ?Sized> Data for Triangular<T>
+where
+    T: Data,
+{
+    type Dim = T::Dim;
+
+    fn data(&self) -> Ref<Tensor<Self::Dim>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        self.data.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> Debug for Triangular<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Triangular")
+            .field("data", &Summary(&self.data.borrow()))
+            .field("triangle", &self.triangle)
+            .field("offset", &self.offset)
+            .field("computed", &self.computed.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for Triangular<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", Summary(&self.data.borrow()))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ TriangularBackward ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+pub struct TriangularBackward<T: ?Sized>
+where
+    T: Gradient,
+{
+    gradient: RefCell<Option<Tensor<T::Dim>>>,
+    shape: T::Dim,
+    overwrite: Cell<bool>,
+    operand: Rc<T>,
+    triangle: Triangle,
+    offset: isize,
+    mask: Array2<bool>,
+}
+
+impl<T: ?Sized> TriangularBackward<T>
+where
+    T: Gradient,
+{
+    pub fn new(operand: Rc<T>, triangle: Triangle, offset: isize) -> Self {
+        let shape = operand.gradient().raw_dim();
+        let mask = triangular_mask(&shape, triangle, offset);
+
+        Self {
+            gradient: RefCell::new(Some(Tensor::zeros(shape.clone()))),
+            shape,
+            overwrite: Cell::new(true),
+            operand,
+            triangle,
+            offset,
+            mask,
+        }
+    }
+}
+
+impl<T: ?Sized> Gradient for TriangularBackward<T>
+where
+    T: Gradient,
+{
+    type Dim = T::Dim;
+
+    fn gradient(&self) -> Ref<Tensor<Self::Dim>> {
+        expect_tensor(&self.gradient)
+    }
+
+    fn gradient_mut(&self) -> RefMut<Tensor<Self::Dim>> {
+        expect_tensor_mut(&self.gradient)
+    }
+}
+
+impl<T: ?Sized> Overwrite for TriangularBackward<T>
+where
+    T: Gradient,
+{
+    fn can_overwrite(&self) -> bool {
+        self.overwrite.get()
+    }
+
+    fn set_overwrite(&self, state: bool) {
+        self.overwrite.set(state);
+    }
+}
+
+impl<T: ?Sized> Backward for TriangularBackward<T>
+where
+    T: Gradient,
+{
+    fn backward(&self) {
+        let mut op_grad = self.operand.gradient_mut();
+        let grad = self.gradient();
+
+        let zip = Zip::from(&mut *op_grad)
+            .and(&*grad)
+            .and_broadcast(&self.mask);
+        if self.operand.can_overwrite() {
+            zip.for_each(|op_grad_el, &grad_el, &kept| {
+                *op_grad_el = if kept { grad_el } else { 0. }
+            });
+            self.operand.set_overwrite(false);
+        } else {
+            zip.for_each(|op_grad_el, &grad_el, &kept| {
+                if kept {
+                    *op_grad_el += grad_el
+                }
+            });
+        }
+    }
+
+    fn no_grad(&self) {
+        *self.gradient.borrow_mut() = None;
+    }
+
+    fn with_grad(&self) {
+        *self.gradient.borrow_mut() = Some(Tensor::zeros(self.shape.clone()));
+    }
+}
+
+impl<T: ?Sized> Debug for TriangularBackward<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TriangularBackward")
+            .field("gradient", &self.gradient.borrow().as_ref().map(Summary))
+            .field("triangle", &self.triangle)
+            .field("offset", &self.offset)
+            .field("overwrite", &self.overwrite.get())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for TriangularBackward<T>
+where
+    T: Gradient,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &*self.gradient.borrow() {
+            Some(gradient) => write!(f, "{}", Summary(gradient)),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
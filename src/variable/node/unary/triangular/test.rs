@@ -0,0 +1,281 @@
+use super::{
+    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
+    Forward, Gradient, Overwrite, Tensor, Triangle, Triangular, TriangularBackward,
+};
+
+mod forward {
+    use super::{
+        assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, Tensor, Triangle,
+        Triangular,
+    };
+
+    #[test]
+    fn creation() {
+        let input = new_input((3, 3), vec![1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+        let node = Triangular::new(input, Triangle::Upper, 0);
+
+        assert_eq!(*node.data(), Tensor::from_elem((3, 3), 0.));
+        assert_eq!(*node.data_mut(), Tensor::from_elem((3, 3), 0.));
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    #[should_panic]
+    fn creation_fail() {
+        Triangular::new(new_input(3, vec![1., 2., 3.]), Triangle::Upper, 0);
+    }
+
+    #[test]
+    fn computation_was_computed_transition() {
+        let input = new_input((3, 3), vec![1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+        let node = Triangular::new(input, Triangle::Upper, 0);
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.forward();
+        assert!(node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+
+        node.reset_computation();
+        assert!(!node.was_computed());
+    }
+
+    #[test]
+    fn forward() {
+        let input = new_input((3, 3), vec![1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+        let node = Triangular::new(input.clone(), Triangle::Upper, 0);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 3), vec![1., 2., 3., 0., 5., 6., 0., 0., 9.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        {
+            let mut data = input.data_mut();
+            *data = &*data + &Tensor::from_elem(1, 1.);
+        }
+        assert_almost_equals(
+            &*input.data(),
+            &new_tensor((3, 3), vec![2., 3., 4., 5., 6., 7., 8., 9., 10.]),
+        );
+
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 3), vec![1., 2., 3., 0., 5., 6., 0., 0., 9.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.reset_computation();
+        node.forward();
+        assert_almost_equals(
+            &*node.data(),
+            &new_tensor((3, 3), vec![2., 3., 4., 0., 6., 7., 0., 0., 10.]),
+        );
+    }
+
+    #[test]
+    fn forward_offset() {
+        let data = vec![1., 2., 3., 4., 5., 6., 7., 8., 9.];
+        let triangular = |triangle, offset| {
+            let node = Triangular::new(new_input((3, 3), data.clone()), triangle, offset);
+            node.forward();
+            let data = node.data().clone();
+            data
+        };
+
+        assert_eq!(
+            triangular(Triangle::Upper, 1),
+            new_tensor((3, 3), vec![0., 2., 3., 0., 0., 6., 0., 0., 0.])
+        );
+        assert_eq!(
+            triangular(Triangle::Upper, -1),
+            new_tensor((3, 3), vec![1., 2., 3., 4., 5., 6., 0., 8., 9.])
+        );
+        assert_eq!(
+            triangular(Triangle::Lower, 1),
+            new_tensor((3, 3), vec![1., 2., 0., 4., 5., 6., 7., 8., 9.])
+        );
+        assert_eq!(
+            triangular(Triangle::Lower, -1),
+            new_tensor((3, 3), vec![0., 0., 0., 4., 0., 0., 7., 8., 0.])
+        );
+    }
+
+    #[test]
+    fn forward_batched() {
+        let input = new_input((2, 2, 3), (1..=12).map(|el| el as f32).collect());
+        let node = Triangular::new(input, Triangle::Lower, 0);
+
+        node.forward();
+        assert_eq!(
+            *node.data(),
+            new_tensor(
+                (2, 2, 3),
+                vec![1., 0., 0., 4., 5., 0., 7., 0., 0., 10., 11., 0.]
+            )
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let input = new_input((2, 2), vec![1., 2., 3., 4.]);
+        let node = Triangular::new(input, Triangle::Upper, 0);
+
+        let output = "Triangular { data: [[0.0, 0.0],\n [0.0, 0.0]], shape=[2, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2, triangle: Upper, offset: 0, computed: false }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let input = new_input((2, 2), vec![1., 2., 3., 4.]);
+        let node = Triangular::new(input, Triangle::Upper, 0);
+
+        assert_eq!(format!("{}", node.data()), format!("{}", node));
+    }
+}
+
+mod backward {
+    use super::{
+        assert_almost_equals, new_backward_input, new_tensor, Backward, Gradient, Overwrite,
+        Tensor, Triangle, TriangularBackward,
+    };
+
+    #[test]
+    fn creation() {
+        let node =
+            TriangularBackward::new(new_backward_input((3, 3), vec![0.; 9]), Triangle::Lower, 0);
+
+        assert_eq!(*node.gradient(), Tensor::from_elem((3, 3), 0.));
+        assert_eq!(*node.gradient_mut(), Tensor::from_elem((3, 3), 0.));
+        assert!(node.can_overwrite());
+    }
+
+    #[test]
+    fn computation_state_transition() {
+        let diff = new_backward_input((3, 3), vec![0.; 9]);
+        let node = TriangularBackward::new(diff.clone(), Triangle::Lower, 0);
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        diff.set_overwrite(true);
+        assert!(node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.set_overwrite(false);
+        assert!(!node.can_overwrite());
+        assert!(diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+
+        node.backward();
+        assert!(!node.can_overwrite());
+        assert!(!diff.can_overwrite());
+    }
+
+    #[test]
+    fn backward() {
+        let diff = new_backward_input((3, 3), vec![0.; 9]);
+        let node = TriangularBackward::new(diff.clone(), Triangle::Lower, 1);
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        *node.gradient_mut() = new_tensor((3, 3), vec![1.; 9]);
+        assert_almost_equals(&*node.gradient(), &new_tensor((3, 3), vec![1.; 9]));
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 3), vec![1., 1., 0., 1., 1., 1., 1., 1., 1.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 3), vec![2., 2., 0., 2., 2., 2., 2., 2., 2.]),
+        );
+
+        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        diff.set_overwrite(true);
+        node.backward();
+        assert_almost_equals(
+            &*diff.gradient(),
+            &new_tensor((3, 3), vec![1., 1., 0., 1., 1., 1., 1., 1., 1.]),
+        );
+    }
+
+    #[test]
+    fn backward_masked_positions() {
+        let diff = new_backward_input((2, 3, 3), vec![0.; 18]);
+        let node = TriangularBackward::new(diff.clone(), Triangle::Upper, -1);
+
+        // The seed gradient is non-zero everywhere, masked positions included.
+        *node.gradient_mut() = new_tensor((2, 3, 3), (1..=18).map(|el| el as f32).collect());
+        node.backward();
+
+        assert_eq!(
+            *diff.gradient(),
+            new_tensor(
+                (2, 3, 3),
+                vec![
+                    1., 2., 3., 4., 5., 6., 0., 8., 9., 10., 11., 12., 13., 14., 15., 0., 17., 18.,
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let diff = new_backward_input((2, 2), vec![0.; 4]);
+        let node = TriangularBackward::new(diff, Triangle::Lower, 0);
+
+        let output = "TriangularBackward { gradient: Some([[0.0, 0.0],\n [0.0, 0.0]], shape=[2, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2), triangle: Lower, offset: 0, overwrite: true }";
+
+        assert_eq!(output, format!("{:?}", node));
+    }
+
+    #[test]
+    fn display() {
+        let diff = new_backward_input((2, 2), vec![0.; 4]);
+        let node = TriangularBackward::new(diff, Triangle::Lower, 0);
+
+        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+    }
+
+    #[test]
+    fn no_grad() {
+        // TriangularBackward
+        let node =
+            TriangularBackward::new(new_backward_input((3, 3), vec![0.; 9]), Triangle::Lower, 0);
+
+        node.no_grad();
+        assert!(node.gradient.borrow().is_none());
+
+        node.with_grad();
+        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+    }
+}
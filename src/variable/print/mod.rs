@@ -0,0 +1,293 @@
+use super::Tensor;
+use ndarray::{ArrayViewD, Axis, Dimension};
+use std::{
+    cell::Cell,
+    fmt::{self, Debug, Display},
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Print Options ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Options controlling how variables and their gradients are printed.
+///
+/// A tensor holding more than `threshold` elements is summarized: only the first and the last
+/// `edge_items` entries of each axis are printed, the rest being replaced by `...`, and its shape
+/// and data type are appended to the output. `precision` sets the number of decimal digits of each
+/// element, `None` leaving the choice to the standard formatting of `f32`.
+///
+/// Print options are kept per thread, see [`set_print_options`] and [`with_print_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrintOptions {
+    /// Number of decimal digits of each element.
+    pub precision: Option<usize>,
+    /// Number of entries printed at the beginning and at the end of each summarized axis.
+    pub edge_items: usize,
+    /// Number of elements above which a tensor is summarized.
+    pub threshold: usize,
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self {
+            precision: None,
+            edge_items: 3,
+            threshold: 1000,
+        }
+    }
+}
+
+thread_local! {
+    static PRINT_OPTIONS: Cell<PrintOptions> = Cell::new(PrintOptions::default());
+}
+
+/// Sets the print options of the current thread.
+///
+/// # Arguments
+///
+/// * `precision` - number of decimal digits of each element, `None` for the shortest exact
+/// representation.
+///
+/// * `edge_items` - number of entries printed at the beginning and at the end of each summarized
+/// axis.
+///
+/// * `threshold` - number of elements above which a tensor is summarized.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "blas")]
+/// # extern crate blas_src;
+/// neuronika::set_print_options(Some(2), 2, 10);
+///
+/// let x = neuronika::full(20, 0.5);
+/// assert_eq!(
+///     format!("{}", x),
+///     "[0.50, 0.50, ..., 0.50, 0.50], shape=[20], dtype=f32"
+/// );
+/// ```
+pub fn set_print_options(precision: Option<usize>, edge_items: usize, threshold: usize) {
+    PRINT_OPTIONS.with(|options| {
+        options.set(PrintOptions {
+            precision,
+            edge_items,
+            threshold,
+        })
+    });
+}
+
+/// Returns the print options of the current thread.
+pub fn print_options() -> PrintOptions {
+    PRINT_OPTIONS.with(Cell::get)
+}
+
+/// Runs `f` with the given print options, restoring the previous ones afterwards.
+///
+/// The previous options are restored even if `f` panics.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "blas")]
+/// # extern crate blas_src;
+/// use neuronika::PrintOptions;
+///
+/// let x = neuronika::full(3, 1. / 3.);
+/// let options = PrintOptions {
+///     precision: Some(3),
+///     ..PrintOptions::default()
+/// };
+///
+/// let printed = neuronika::with_print_options(options, || format!("{}", x));
+/// assert_eq!(printed, "[0.333, 0.333, 0.333]");
+/// assert_eq!(neuronika::print_options(), PrintOptions::default());
+/// ```
+pub fn with_print_options<R>(options: PrintOptions, f: impl FnOnce() -> R) -> R {
+    struct Restore(PrintOptions);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            PRINT_OPTIONS.with(|options| options.set(self.0));
+        }
+    }
+
+    let _restore = Restore(PRINT_OPTIONS.with(|current| current.replace(options)));
+    f()
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Summary ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Formats a tensor according to the print options of the current thread.
+///
+/// Tensors that are not summarized are printed exactly as **ndarray** does. The alternate flag,
+/// `{:#}`, disables summarization.
+pub(crate) struct Summary<'a, D: Dimension>(pub(crate) &'a Tensor<D>);
+
+impl<'a, D: Dimension> Summary<'a, D> {
+    fn is_summarized(&self, f: &fmt::Formatter<'_>, options: &PrintOptions) -> bool {
+        !f.alternate() && self.0.len() > options.threshold
+    }
+}
+
+impl<'a, D: Dimension> Display for Summary<'a, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let options = print_options();
+        let summarized = self.is_summarized(f, &options);
+        let edge_items = if summarized {
+            Some(options.edge_items)
+        } else {
+            None
+        };
+
+        format_view(
+            self.0.view().into_dyn(),
+            f,
+            edge_items,
+            options.precision,
+            0,
+        )?;
+        if summarized {
+            write!(f, ", shape={:?}, dtype=f32", self.0.shape())?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, D: Dimension> Debug for Summary<'a, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_summarized(f, &print_options()) {
+            write!(f, "shape={:?}, dtype=f32", self.0.shape())
+        } else {
+            Debug::fmt(self.0, f)
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Shaped Display ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Formats the shape of a variable and whether its data was computed, without printing the data
+/// itself, e.g. `Tensor[f32; shape=[3, 4], computed=true]`.
+///
+/// See [`Var::shape_info()`](crate::Var::shape_info()) and
+/// [`VarDiff::shape_info()`](crate::VarDiff::shape_info()).
+#[derive(Debug, Clone, Copy)]
+pub struct ShapedDisplay<'a> {
+    shape: &'a [usize],
+    computed: bool,
+}
+
+impl<'a> ShapedDisplay<'a> {
+    pub(crate) fn new(shape: &'a [usize], computed: bool) -> Self {
+        Self { shape, computed }
+    }
+}
+
+impl<'a> Display for ShapedDisplay<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Tensor[f32; shape={:?}, computed={}]",
+            self.shape, self.computed
+        )
+    }
+}
+
+/// Formats `view` recursively, mirroring **ndarray**'s layout. When `edge_items` is `Some`, axes
+/// longer than twice its value are elided.
+fn format_view(
+    view: ArrayViewD<f32>,
+    f: &mut fmt::Formatter<'_>,
+    edge_items: Option<usize>,
+    precision: Option<usize>,
+    depth: usize,
+) -> fmt::Result {
+    if view.is_empty() {
+        return write!(f, "{}{}", "[".repeat(view.ndim()), "]".repeat(view.ndim()));
+    }
+
+    match view.shape() {
+        [] => format_element(view.iter().next().unwrap(), f, precision),
+        &[len] => {
+            f.write_str("[")?;
+            format_with_elision(f, len, edge_items, ", ", &mut |f, index| {
+                format_element(&view[index], f, precision)
+            })?;
+            f.write_str("]")
+        }
+        shape => {
+            let separator = format!(
+                ",\n{}{}",
+                "\n".repeat(shape.len() - 2),
+                " ".repeat(depth + 1)
+            );
+
+            f.write_str("[")?;
+            format_with_elision(f, shape[0], edge_items, &separator, &mut |f, index| {
+                format_view(
+                    view.index_axis(Axis(0), index),
+                    f,
+                    edge_items,
+                    precision,
+                    depth + 1,
+                )
+            })?;
+            f.write_str("]")
+        }
+    }
+}
+
+/// Formats a single element. A precision given to the formatter takes priority over the one set in
+/// the print options.
+fn format_element(
+    element: &f32,
+    f: &mut fmt::Formatter<'_>,
+    precision: Option<usize>,
+) -> fmt::Result {
+    match precision {
+        Some(precision) if f.precision().is_none() => write!(f, "{:.*}", precision, element),
+        _ => Display::fmt(element, f),
+    }
+}
+
+/// Formats `length` items separated by `separator`, replacing the middle ones with `...` when there
+/// are more than twice `edge_items`.
+fn format_with_elision(
+    f: &mut fmt::Formatter<'_>,
+    length: usize,
+    edge_items: Option<usize>,
+    separator: &str,
+    format_item: &mut dyn FnMut(&mut fmt::Formatter<'_>, usize) -> fmt::Result,
+) -> fmt::Result {
+    let (head, tail) = match edge_items {
+        Some(edge_items) if length > 2 * edge_items => (edge_items, length - edge_items),
+        _ => (length, length),
+    };
+
+    for index in 0..head {
+        if index > 0 {
+            f.write_str(separator)?;
+        }
+        format_item(f, index)?;
+    }
+    if head < tail {
+        if head > 0 {
+            f.write_str(separator)?;
+        }
+        f.write_str("...")?;
+    }
+    for index in tail..length {
+        f.write_str(separator)?;
+        format_item(f, index)?;
+    }
+    Ok(())
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[cfg(test)]
+mod test;
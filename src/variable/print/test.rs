@@ -0,0 +1,176 @@
+use super::{
+    print_options, set_print_options, with_print_options, PrintOptions, ShapedDisplay, Summary,
+    Tensor,
+};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+#[test]
+fn default_options() {
+    let options = PrintOptions::default();
+
+    assert_eq!(options.precision, None);
+    assert_eq!(options.edge_items, 3);
+    assert_eq!(options.threshold, 1000);
+    assert_eq!(print_options(), options);
+}
+
+#[test]
+fn small_tensors_match_ndarray() {
+    let scalar = Tensor::from_elem((), 3.5);
+    let vector = Tensor::from_shape_fn(5, |i| i as f32 / 2.);
+    let matrix = Tensor::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as f32);
+    let cube = Tensor::from_shape_fn((2, 3, 4), |(i, j, k)| (i * 12 + j * 4 + k) as f32);
+    let empty = Tensor::<ndarray::Ix2>::zeros((0, 3));
+
+    assert_eq!(format!("{}", Summary(&scalar)), format!("{}", scalar));
+    assert_eq!(format!("{}", Summary(&vector)), format!("{}", vector));
+    assert_eq!(format!("{}", Summary(&matrix)), format!("{}", matrix));
+    assert_eq!(format!("{}", Summary(&cube)), format!("{}", cube));
+    assert_eq!(format!("{}", Summary(&empty)), format!("{}", empty));
+    assert_eq!(format!("{:.2}", Summary(&vector)), format!("{:.2}", vector));
+    assert_eq!(format!("{:?}", Summary(&matrix)), format!("{:?}", matrix));
+}
+
+#[test]
+fn vector_snapshot() {
+    let vector = Tensor::from_shape_fn(1000, |i| i as f32);
+    let options = PrintOptions {
+        precision: Some(1),
+        edge_items: 3,
+        threshold: 999,
+    };
+
+    let output = "[0.0, 1.0, 2.0, ..., 997.0, 998.0, 999.0], shape=[1000], dtype=f32";
+
+    assert_eq!(
+        output,
+        with_print_options(options, || format!("{}", Summary(&vector)))
+    );
+}
+
+#[test]
+fn matrix_snapshot() {
+    let matrix = Tensor::from_shape_fn((100, 100), |(i, j)| (i * 100 + j) as f32);
+
+    let output = "[[0, 1, 2, ..., 97, 98, 99],\n [100, 101, 102, ..., 197, 198, 199],\n [200, 201, 202, ..., 297, 298, 299],\n ...,\n [9700, 9701, 9702, ..., 9797, 9798, 9799],\n [9800, 9801, 9802, ..., 9897, 9898, 9899],\n [9900, 9901, 9902, ..., 9997, 9998, 9999]], shape=[100, 100], dtype=f32";
+
+    assert_eq!(output, format!("{}", Summary(&matrix)));
+}
+
+#[test]
+fn debug_above_threshold() {
+    let matrix = Tensor::zeros((100, 100));
+
+    assert_eq!(
+        "shape=[100, 100], dtype=f32",
+        format!("{:?}", Summary(&matrix))
+    );
+}
+
+#[test]
+fn alternate_disables_summarization() {
+    let vector = Tensor::from_shape_fn(1001, |i| i as f32);
+
+    assert_eq!(format!("{:#}", Summary(&vector)), format!("{:#}", vector));
+}
+
+#[test]
+fn formatter_precision_wins() {
+    let vector = Tensor::from_elem(3, 1. / 3.);
+    let options = PrintOptions {
+        precision: Some(4),
+        ..PrintOptions::default()
+    };
+
+    with_print_options(options, || {
+        assert_eq!("[0.3333, 0.3333, 0.3333]", format!("{}", Summary(&vector)));
+        assert_eq!("[0.33, 0.33, 0.33]", format!("{:.2}", Summary(&vector)));
+    });
+}
+
+#[test]
+fn variable_display() {
+    let var = super::super::Input::new(Tensor::from_elem(20, 0.5));
+    let options = PrintOptions {
+        precision: Some(2),
+        edge_items: 1,
+        threshold: 10,
+    };
+
+    with_print_options(options, || {
+        assert_eq!(
+            "[0.50, ..., 0.50], shape=[20], dtype=f32",
+            format!("{}", var)
+        );
+        assert_eq!(
+            "Var { node: Input { data: shape=[20], dtype=f32 }, past: 0 }",
+            format!("{:?}", var)
+        );
+    });
+}
+
+#[test]
+fn shaped_display() {
+    assert_eq!(
+        "Tensor[f32; shape=[3, 4], computed=true]",
+        format!("{}", ShapedDisplay::new(&[3, 4], true))
+    );
+    assert_eq!(
+        "Tensor[f32; shape=[], computed=false]",
+        format!("{}", ShapedDisplay::new(&[], false))
+    );
+}
+
+#[test]
+fn variable_shape_info() {
+    let var = crate::ones((3, 4)) * 2.;
+    assert_eq!(
+        "Tensor[f32; shape=[3, 4], computed=false]",
+        var.shape_info()
+    );
+
+    var.forward();
+    assert_eq!("Tensor[f32; shape=[3, 4], computed=true]", var.shape_info());
+
+    let var_diff = crate::ones((2, 5)).requires_grad().sum();
+    assert_eq!(
+        "Tensor[f32; shape=[], computed=false]",
+        var_diff.shape_info()
+    );
+}
+
+#[test]
+fn scoped_options() {
+    let options = PrintOptions {
+        precision: Some(2),
+        edge_items: 1,
+        threshold: 10,
+    };
+
+    with_print_options(options, || assert_eq!(print_options(), options));
+    assert_eq!(print_options(), PrintOptions::default());
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        with_print_options(options, || panic!("error: unwinding."))
+    }));
+    assert!(result.is_err());
+    assert_eq!(print_options(), PrintOptions::default());
+}
+
+#[test]
+fn options_are_per_thread() {
+    let vector = Tensor::from_shape_fn(20, |i| i as f32);
+    let shared = vector.clone();
+
+    let summarized = std::thread::spawn(move || {
+        set_print_options(None, 2, 10);
+        assert_eq!(print_options().threshold, 10);
+        format!("{}", Summary(&shared))
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!("[0, 1, ..., 18, 19], shape=[20], dtype=f32", summarized);
+    assert_eq!(print_options(), PrintOptions::default());
+    assert_eq!(format!("{}", vector), format!("{}", Summary(&vector)));
+}
@@ -19,6 +19,102 @@ fn grad_mut() {
     assert_eq!(*x.grad(), ndarray::array![[1., 1.,], [1., 1.,]]);
 }
 
+#[test]
+fn try_grad() {
+    let w = crate::ones((2, 2)).requires_grad();
+    let y = w.clone() * 3.;
+    let loss = y.clone().sum();
+    assert!(w.try_grad().is_none());
+
+    loss.forward();
+    loss.backward(1.);
+    assert_eq!(*w.try_grad().unwrap(), ndarray::array![[3., 3.], [3., 3.]]);
+    assert_eq!(*y.try_grad().unwrap(), ndarray::array![[1., 1.], [1., 1.]]);
+    assert_eq!(loss.try_grad().unwrap()[()], 1.);
+
+    // The gradients of the intermediate results are stale after a new forward pass.
+    loss.forward();
+    assert!(y.try_grad().is_none());
+
+    loss.no_grad();
+    assert!(loss.try_grad().is_none());
+}
+
+#[test]
+fn zero_grad() {
+    let w = crate::ones((2, 2)).requires_grad();
+    let loss = (w.clone() * 3.).sum();
+    loss.forward();
+    loss.backward(1.);
+    assert!(w.try_grad().is_some());
+
+    w.zero_grad();
+    assert_eq!(
+        *w.try_grad().unwrap(),
+        ndarray::Array2::<f32>::zeros((2, 2))
+    );
+
+    // The gradient is accumulated again from zero.
+    loss.forward();
+    loss.backward(1.);
+    assert_eq!(*w.grad(), ndarray::array![[3., 3.], [3., 3.]]);
+}
+
+#[test]
+fn set_grad() {
+    let w = crate::ones(2).requires_grad();
+    let y = w.clone() * 3.;
+    let loss = y.clone().sum();
+    loss.forward();
+
+    // The backward pass accumulates onto the injected gradient.
+    w.set_grad(ndarray::array![1., 1.]);
+    assert_eq!(*w.try_grad().unwrap(), ndarray::array![1., 1.]);
+
+    loss.backward(1.);
+    assert_eq!(*w.grad(), ndarray::array![4., 4.]);
+}
+
+#[test]
+#[should_panic(expected = "error: cannot set a gradient of shape [3] to a variable of shape [2].")]
+fn set_grad_wrong_shape() {
+    crate::ones(2)
+        .requires_grad()
+        .set_grad(ndarray::array![1., 1., 1.]);
+}
+
+#[test]
+fn shape() {
+    let var = crate::ones((3, 4)).mm(crate::ones((4, 2)));
+    assert_eq!(
+        var.shape(),
+        ndarray::Dimension::slice(&var.data().raw_dim())
+    );
+    assert_eq!(var.shape(), &[3, 2]);
+
+    let var_diff = crate::ones((3, 4))
+        .requires_grad()
+        .mean_axes(&[1])
+        .into_dyn();
+    assert_eq!(
+        var_diff.shape(),
+        ndarray::Dimension::slice(&var_diff.data().raw_dim())
+    );
+    assert_eq!(var_diff.shape(), &[3, 1]);
+}
+
+#[test]
+fn shape_after_replacement() {
+    let var = crate::zeros((2, 3));
+    *var.data_mut() = ndarray::Array::zeros((4, 5));
+    assert_eq!(var.shape(), &[4, 5]);
+    assert_eq!(var.shape_info(), "Tensor[f32; shape=[4, 5], computed=true]");
+
+    let var_diff = crate::zeros(3).requires_grad();
+    *var_diff.data_mut() = ndarray::Array::zeros(7);
+    assert_eq!(var_diff.shape(), &[7]);
+}
+
 #[test]
 fn add_scalar() {
     // Var - f32
@@ -188,6 +284,122 @@ fn parameters_test() {
     assert_eq!(w.parameters().len(), 3);
 }
 
+#[test]
+fn freeze() {
+    let linear = crate::nn::Linear::new(3, 2);
+    let input = crate::rand((4, 3));
+
+    linear.weight.freeze();
+    assert!(linear.weight.is_frozen());
+    assert!(!linear.bias.as_ref().unwrap().is_frozen());
+
+    let output = linear.forward(input).sum();
+    output.forward();
+    output.backward(1.);
+
+    assert!(linear.weight.grad().iter().all(|grad| *grad == 0.));
+    assert_eq!(
+        *linear.bias.as_ref().unwrap().grad(),
+        ndarray::array![4., 4.]
+    );
+
+    // Once unfrozen the weight receives its gradient again.
+    linear.weight.unfreeze();
+    assert!(!linear.weight.is_frozen());
+    output.backward(1.);
+    assert!(linear.weight.grad().iter().any(|grad| *grad != 0.));
+}
+
+#[test]
+fn no_grad() {
+    assert!(crate::is_grad_enabled());
+    {
+        let _guard = crate::no_grad();
+        assert!(!crate::is_grad_enabled());
+        {
+            let _nested = crate::no_grad();
+            assert!(!crate::is_grad_enabled());
+        }
+        // The nested guard restores the previous state.
+        assert!(!crate::is_grad_enabled());
+    }
+    assert!(crate::is_grad_enabled());
+}
+
+#[test]
+fn no_grad_backward() {
+    let (x, y, output) = {
+        let _guard = crate::no_grad();
+
+        let x = crate::ones((2, 2)).requires_grad();
+        let y = crate::full((2, 2), 3.).requires_grad();
+        let output = (x.clone() * y.clone()).sum();
+        output.forward();
+        output.backward(1.);
+
+        assert!(x.is_frozen() && y.is_frozen());
+        assert!(output.parameters().is_empty());
+        (x, y, output)
+    };
+
+    assert_eq!(*output.data(), ndarray::arr0(12.));
+    assert!(x.grad().iter().all(|grad| *grad == 0.));
+    assert!(y.grad().iter().all(|grad| *grad == 0.));
+
+    // Leaves created once the guard is dropped are tracked again.
+    let z = crate::ones((2, 2)).requires_grad();
+    let output = (z.clone() * x).sum();
+    output.forward();
+    output.backward(1.);
+
+    assert_eq!(output.parameters().len(), 1);
+    assert_eq!(*z.grad(), ndarray::Array::from_elem((2, 2), 1.));
+}
+
+#[test]
+fn detach() {
+    let x = crate::from_ndarray(ndarray::array![1., 2., 3.]).requires_grad();
+    let detached = x.clone().exp().detach();
+    let y = (detached.clone() + x.clone()).sum();
+    y.forward();
+    y.backward(1.);
+
+    assert_eq!(*detached.data(), x.data().mapv(f32::exp));
+    assert!(detached.past.is_empty());
+    // The detached path doesn't contribute to the gradient, that would otherwise be 1 + exp(x).
+    assert_eq!(*x.grad(), ndarray::arr1(&[1., 1., 1.]));
+    assert_eq!(y.parameters().len(), 1);
+}
+
+#[test]
+fn detach_var() {
+    let x = crate::ones(3);
+    let y = (x.clone() * 2.).detach();
+
+    // The copy isn't affected by later changes to the ancestors.
+    x.data_mut().fill(5.);
+    assert_eq!(*y.data(), ndarray::arr1(&[2., 2., 2.]));
+    assert!(y.past.is_empty());
+}
+
+#[test]
+fn requires_grad() {
+    let x = crate::from_ndarray(ndarray::array![1., 2., 3.]);
+    let y = (x.clone() + 1.).requires_grad();
+    let z = (y.clone() * y.clone()).sum();
+    z.forward();
+    z.backward(1.);
+
+    assert_eq!(*z.data(), ndarray::arr0(29.));
+    assert_eq!(*y.grad(), ndarray::arr1(&[4., 6., 8.]));
+    assert_eq!(z.parameters().len(), 1);
+
+    // The data is copied, so the original variable can be used in a separate graph.
+    let leaf = x.clone().requires_grad();
+    leaf.data_mut().fill(0.);
+    assert_eq!(*x.data(), ndarray::arr1(&[1., 2., 3.]));
+}
+
 #[test]
 fn sum() {
     let input = crate::ones((2, 2));
@@ -224,6 +436,26 @@ fn mean_diff() {
     assert_eq!(mean.past.parameters.len(), 1);
 }
 
+#[test]
+fn mean_axes() {
+    let input = crate::ones((2, 3, 2));
+    let mean_axes = input.mean_axes(&[1, 2]);
+
+    assert_eq!(mean_axes.past.len(), 1);
+    assert!(mean_axes.past.changeables.is_empty());
+    assert_eq!(mean_axes.data().shape(), &[2, 1, 1]);
+}
+
+#[test]
+fn mean_axes_diff() {
+    let input = crate::ones((2, 3, 2)).requires_grad();
+    let mean_axes = input.mean_axes(&[1, 2]);
+
+    assert_eq!(mean_axes.past.len(), 1);
+    assert_eq!(mean_axes.past.parameters.len(), 1);
+    assert_eq!(mean_axes.grad().shape(), &[2, 1, 1]);
+}
+
 #[test]
 fn pow() {
     let input = crate::ones((2, 2));
@@ -299,7 +531,7 @@ fn leaky_relu_diff() {
 #[test]
 fn softplus() {
     let input = crate::ones((2, 2));
-    let softplus = input.softplus();
+    let softplus = input.softplus(1., 20.);
 
     assert_eq!(softplus.past.len(), 1);
     assert!(softplus.past.changeables.is_empty());
@@ -308,12 +540,39 @@ fn softplus() {
 #[test]
 fn softplus_diff() {
     let input = crate::ones((2, 2)).requires_grad();
-    let softplus = input.softplus();
+    let softplus = input.softplus(1., 20.);
 
     assert_eq!(softplus.past.len(), 1);
     assert_eq!(softplus.past.parameters.len(), 1);
 }
 
+#[test]
+fn gelu() {
+    let input = crate::ones((2, 2));
+    let gelu = input.gelu();
+
+    assert_eq!(gelu.past.len(), 1);
+    assert!(gelu.past.changeables.is_empty());
+}
+
+#[test]
+fn gelu_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let gelu = input.gelu();
+
+    assert_eq!(gelu.past.len(), 1);
+    assert_eq!(gelu.past.parameters.len(), 1);
+}
+
+#[test]
+fn gelu_diff_backward() {
+    let data = ndarray::array![-3., -1.5, -0.5, 0., 0.25, 1., 2.5];
+    let input = crate::from_ndarray(data).requires_grad();
+    let loss = input.clone().gelu().into_dyn().sum();
+
+    super::check_gradient(&input, &loss);
+}
+
 #[test]
 fn sigmoid() {
     let input = crate::ones((2, 2));
@@ -324,180 +583,1493 @@ fn sigmoid() {
 }
 
 #[test]
-fn sigmoid_diff() {
-    let input = crate::ones((2, 2)).requires_grad();
-    let sigmoid = input.sigmoid();
+fn sigmoid_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let sigmoid = input.sigmoid();
+
+    assert_eq!(sigmoid.past.len(), 1);
+    assert_eq!(sigmoid.past.parameters.len(), 1);
+}
+
+#[test]
+fn swish() {
+    let input = crate::ones((2, 2));
+    let swish = input.swish();
+
+    assert_eq!(swish.past.len(), 1);
+    assert!(swish.past.changeables.is_empty());
+}
+
+#[test]
+fn swish_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let swish = input.swish();
+
+    assert_eq!(swish.past.len(), 1);
+    assert_eq!(swish.past.parameters.len(), 1);
+}
+
+#[test]
+fn mish() {
+    let input = crate::ones((2, 2));
+    let mish = input.mish();
+
+    assert_eq!(mish.past.len(), 1);
+    assert!(mish.past.changeables.is_empty());
+}
+
+#[test]
+fn mish_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let mish = input.mish();
+
+    assert_eq!(mish.past.len(), 1);
+    assert_eq!(mish.past.parameters.len(), 1);
+}
+
+#[test]
+fn tanh() {
+    let input = crate::ones((2, 2));
+    let tanh = input.tanh();
+
+    assert_eq!(tanh.past.len(), 1);
+    assert!(tanh.past.changeables.is_empty());
+}
+
+#[test]
+fn tanh_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let tanh = input.tanh();
+
+    assert_eq!(tanh.past.len(), 1);
+    assert_eq!(tanh.past.parameters.len(), 1);
+}
+
+#[test]
+fn ln() {
+    let input = crate::ones((2, 2));
+    let ln = input.ln();
+
+    assert_eq!(ln.past.len(), 1);
+    assert!(ln.past.changeables.is_empty());
+}
+
+#[test]
+fn ln_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let ln = input.ln();
+
+    assert_eq!(ln.past.len(), 1);
+    assert_eq!(ln.past.parameters.len(), 1);
+}
+
+#[test]
+fn exp() {
+    let input = crate::ones((2, 2));
+    let exp = input.exp();
+
+    assert_eq!(exp.past.len(), 1);
+    assert!(exp.past.changeables.is_empty());
+}
+
+#[test]
+fn exp_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let exp = input.exp();
+
+    assert_eq!(exp.past.len(), 1);
+    assert_eq!(exp.past.parameters.len(), 1);
+}
+
+#[test]
+fn softmax() {
+    let input = crate::ones((2, 2));
+    let softmax = input.softmax(1);
+
+    assert_eq!(softmax.past.len(), 1);
+    assert!(softmax.past.changeables.is_empty());
+}
+
+#[test]
+fn softmax_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let softmax = input.softmax(1);
+
+    assert_eq!(softmax.past.len(), 1);
+    assert_eq!(softmax.past.parameters.len(), 1);
+}
+
+#[test]
+fn log_softmax() {
+    let input = crate::ones((2, 2));
+    let log_softmax = input.log_softmax(1);
+
+    assert_eq!(log_softmax.past.len(), 1);
+    assert!(log_softmax.past.changeables.is_empty());
+}
+
+#[test]
+fn log_softmax_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let log_softmax = input.log_softmax(1);
+
+    assert_eq!(log_softmax.past.len(), 1);
+    assert_eq!(log_softmax.past.parameters.len(), 1);
+}
+
+#[test]
+fn log_sum_exp() {
+    let input = crate::from_ndarray(ndarray::array![0., 1., 2.]);
+    let log_sum_exp = input.log_sum_exp(0);
+
+    assert_eq!(log_sum_exp.past.len(), 1);
+    assert!(log_sum_exp.past.changeables.is_empty());
+
+    log_sum_exp.forward();
+    let expected = (1_f32 + 1_f32.exp() + 2_f32.exp()).ln();
+    assert!((log_sum_exp.data()[0] - expected).abs() < 1e-6);
+}
+
+#[test]
+fn log_sum_exp_diff() {
+    let input = crate::from_ndarray(ndarray::array![0., 1., 2.]).requires_grad();
+    let log_sum_exp = input.clone().log_sum_exp(0);
+
+    assert_eq!(log_sum_exp.past.len(), 1);
+    assert_eq!(log_sum_exp.past.parameters.len(), 1);
+
+    // The gradient of the log-sum-exp is the softmax.
+    let sum = log_sum_exp.sum();
+    sum.forward();
+    sum.backward(1.);
+
+    let softmax = crate::from_ndarray(ndarray::array![0., 1., 2.]).softmax(0);
+    softmax.forward();
+    assert!(input
+        .grad()
+        .iter()
+        .zip(softmax.data().iter())
+        .all(|(l, r)| (l - r).abs() < 1e-6));
+}
+
+#[test]
+fn norm() {
+    let input = crate::ones((2, 2));
+    let norm = input.norm(2., &[1]);
+
+    assert_eq!(norm.past.len(), 1);
+    assert!(norm.past.changeables.is_empty());
+}
+
+#[test]
+fn norm_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let norm = input.norm(2., &[1]);
+
+    assert_eq!(norm.past.len(), 1);
+    assert_eq!(norm.past.parameters.len(), 1);
+}
+
+#[test]
+fn norm_diff_backward() {
+    let data = ndarray::array![[1., -2., 2.], [0.5, 1.5, -1.]];
+    for p in [1., 2., 3.] {
+        let input = crate::from_ndarray(data.clone()).requires_grad();
+        let loss = input.clone().norm(p, &[1]).sum();
+
+        super::check_gradient(&input, &loss);
+    }
+}
+
+#[test]
+fn normalize() {
+    let input = crate::ones((2, 2));
+    let normalized = input.normalize(2., &[1], 1e-12);
+
+    assert_eq!(normalized.past.len(), 2);
+    assert!(normalized.past.changeables.is_empty());
+}
+
+#[test]
+fn normalize_diff() {
+    let input = crate::from_ndarray(ndarray::array![[3., 4.], [0., 0.]]).requires_grad();
+    let normalized = input.clone().normalize(2., &[1], 1e-12);
+
+    assert_eq!(normalized.past.len(), 2);
+    assert_eq!(normalized.past.parameters.len(), 1);
+
+    normalized.forward();
+    assert_eq!(*normalized.data(), ndarray::array![[0.6, 0.8], [0., 0.]]);
+
+    normalized.backward(1.);
+    assert!(input.grad().iter().all(|grad| grad.is_finite()));
+}
+
+#[test]
+fn normalize_unit_rows() {
+    let input = crate::from_ndarray(ndarray::array![[3., 4., 0.], [1., 2., 2.], [-2., 0., 0.]]);
+    let normalized = input.normalize(2., &[1], 1e-12);
+
+    normalized.forward();
+    assert!(normalized
+        .data()
+        .rows()
+        .into_iter()
+        .all(|row| (row.dot(&row).sqrt() - 1.).abs() < 1e-6));
+}
+
+#[test]
+fn normalize_gradient() {
+    // For a row x of norm n the Jacobian is (I - y * y^T) / n, with y = x / n.
+    let input = crate::from_ndarray(ndarray::array![[1., 2., 2.], [0., 3., 4.]]).requires_grad();
+    let weights = crate::from_ndarray(ndarray::array![[1., 0., -1.], [2., 1., 0.5]]);
+    let loss = (input.clone().normalize(2., &[1], 1e-12) * weights).sum();
+
+    loss.forward();
+    loss.backward(1.);
+
+    let expected = ndarray::array![
+        [10. / 27., 2. / 27., -7. / 27.],
+        [2. / 5., 0.4 / 5., -0.3 / 5.]
+    ];
+    assert!(input
+        .grad()
+        .iter()
+        .zip(expected.iter())
+        .all(|(l, r)| (l - r).abs() < 1e-6));
+}
+
+#[test]
+fn normalize_near_zero() {
+    // The norm of the second row is clamped by eps, which is then the only divisor.
+    let input = crate::from_ndarray(ndarray::array![[3., 4.], [1e-7, 0.]]).requires_grad();
+    let weights = crate::from_ndarray(ndarray::array![[1., 1.], [1., -2.]]);
+    let normalized = input.clone().normalize(2., &[1], 1e-6);
+    let loss = (normalized.clone() * weights).sum();
+
+    loss.forward();
+    assert!(normalized
+        .data()
+        .iter()
+        .zip(ndarray::array![[0.6, 0.8], [0.1, 0.]].iter())
+        .all(|(l, r)| (l - r).abs() < 1e-6));
+
+    loss.backward(1.);
+    let grad = input.grad();
+    assert!((grad[[1, 0]] - 1e6).abs() < 1.);
+    assert!((grad[[1, 1]] + 2e6).abs() < 1.);
+}
+
+#[test]
+fn diag() {
+    let input = crate::ones((2, 3));
+    let diag = input.diag(0);
+
+    assert_eq!(diag.past.len(), 1);
+    assert!(diag.past.changeables.is_empty());
+    diag.forward();
+    assert_eq!(diag.data().shape(), &[2]);
+}
+
+#[test]
+fn diag_diff() {
+    let input = crate::ones((2, 3)).requires_grad();
+    let diag = input.clone().diag(1);
+
+    assert_eq!(diag.past.len(), 1);
+    assert_eq!(diag.past.parameters.len(), 1);
+
+    let sum = diag.sum();
+    sum.forward();
+    sum.backward(1.);
+    assert_eq!(*input.grad(), ndarray::array![[0., 1., 0.], [0., 0., 1.]]);
+}
+
+#[test]
+fn diag_embed() {
+    let input = crate::ones(3);
+    let diag_embed = input.diag_embed(-1);
+
+    assert_eq!(diag_embed.past.len(), 1);
+    assert!(diag_embed.past.changeables.is_empty());
+    diag_embed.forward();
+    assert_eq!(diag_embed.data().shape(), &[4, 4]);
+}
+
+#[test]
+fn diag_embed_diff() {
+    let input = crate::ones(2).requires_grad();
+    let diag_embed = input.clone().diag_embed(0);
+
+    assert_eq!(diag_embed.past.len(), 1);
+    assert_eq!(diag_embed.past.parameters.len(), 1);
+
+    // Only the diagonal of the incoming gradient reaches the vector.
+    let weight = crate::from_ndarray(ndarray::array![[1., 2.], [3., 4.]]);
+    let sum = (diag_embed * weight).sum();
+    sum.forward();
+    sum.backward(1.);
+    assert_eq!(*input.grad(), ndarray::array![1., 4.]);
+}
+
+#[test]
+fn diag_round_trip() {
+    let input = crate::from_ndarray(ndarray::array![1., -2., 3.]).requires_grad();
+    let round_trip = input.clone().diag_embed(1).diag(1);
+
+    round_trip.forward();
+    assert_eq!(*round_trip.data(), ndarray::array![1., -2., 3.]);
+
+    let weight = crate::from_ndarray(ndarray::array![2., 3., 4.]);
+    let sum = (round_trip * weight).sum();
+    sum.forward();
+    sum.backward(1.);
+    assert_eq!(*input.grad(), ndarray::array![2., 3., 4.]);
+
+    // The other way around the elements off the diagonal are lost.
+    let matrix = crate::from_ndarray(ndarray::array![[1., 2.], [3., 4.]]);
+    let round_trip = matrix.clone().diag(0).diag_embed(0);
+
+    round_trip.forward();
+    assert_eq!(*round_trip.data(), ndarray::array![[1., 0.], [0., 4.]]);
+    assert_ne!(*round_trip.data(), *matrix.data());
+}
+
+#[test]
+fn trace() {
+    let input = crate::ones((3, 2));
+    let trace = input.trace();
+
+    assert_eq!(trace.past.len(), 1);
+    assert!(trace.past.changeables.is_empty());
+    trace.forward();
+    assert_eq!(trace.data()[()], 2.);
+}
+
+#[test]
+fn trace_diff() {
+    let input = crate::ones((3, 2)).requires_grad();
+    let trace = input.clone().trace();
+
+    assert_eq!(trace.past.len(), 1);
+    assert_eq!(trace.past.parameters.len(), 1);
+
+    trace.forward();
+    trace.backward(1.);
+    assert_eq!(*input.grad(), ndarray::array![[1., 0.], [0., 1.], [0., 0.]]);
+}
+
+#[test]
+fn trace_identity() {
+    let input = crate::from_ndarray(ndarray::Array2::eye(5)).requires_grad();
+    let trace = input.clone().trace();
+
+    trace.forward();
+    trace.backward(1.);
+    assert!((trace.data()[()] - 5.).abs() <= f32::EPSILON);
+    assert_eq!(*input.grad(), ndarray::Array2::<f32>::eye(5));
+
+    // The gradient of the trace is the identity scaled by the incoming gradient.
+    let input = crate::rand((4, 4)).requires_grad();
+    let trace = input.clone().trace();
+
+    trace.forward();
+    trace.backward(3.);
+    assert_eq!(*input.grad(), ndarray::Array2::<f32>::eye(4) * 3.);
+}
+
+#[test]
+fn triu() {
+    let input = crate::ones((2, 3, 3));
+    let triu = input.triu(1);
+
+    assert_eq!(triu.past.len(), 1);
+    assert!(triu.past.changeables.is_empty());
+}
+
+#[test]
+fn triu_diff() {
+    let input = crate::ones((2, 3, 3)).requires_grad();
+    let triu = input.triu(1);
+
+    assert_eq!(triu.past.len(), 1);
+    assert_eq!(triu.past.parameters.len(), 1);
+}
+
+#[test]
+fn tril() {
+    let input = crate::ones((3, 3));
+    let tril = input.tril(-1);
+
+    assert_eq!(tril.past.len(), 1);
+    assert!(tril.past.changeables.is_empty());
+}
+
+#[test]
+fn tril_diff() {
+    let input = crate::ones((3, 3)).requires_grad();
+    let tril = input.tril(-1);
+
+    assert_eq!(tril.past.len(), 1);
+    assert_eq!(tril.past.parameters.len(), 1);
+}
+
+#[test]
+fn cumsum() {
+    let input = crate::ones((2, 3, 2));
+    let cumsum = input.cumsum(1);
+
+    assert_eq!(cumsum.past.len(), 1);
+    assert!(cumsum.past.changeables.is_empty());
+}
+
+#[test]
+fn cumsum_diff() {
+    let input = crate::ones((2, 3, 2)).requires_grad();
+    let cumsum = input.cumsum(1);
+
+    assert_eq!(cumsum.past.len(), 1);
+    assert_eq!(cumsum.past.parameters.len(), 1);
+}
+
+#[test]
+fn cumsum_values() {
+    let cumsum = crate::from_ndarray(ndarray::array![1., 2., 3.]).cumsum(0);
+
+    cumsum.forward();
+    assert_eq!(*cumsum.data(), ndarray::array![1., 3., 6.]);
+}
+
+#[test]
+fn cumsum_last_output_gradient() {
+    // Every input contributes to the last output, only the first one to the first output.
+    let input = crate::from_ndarray(ndarray::array![1., 2., 3.]).requires_grad();
+    let last = (input.clone().cumsum(0) * crate::from_ndarray(ndarray::array![0., 0., 1.])).sum();
+
+    last.forward();
+    last.backward(1.);
+    assert_eq!(*input.grad(), ndarray::array![1., 1., 1.]);
+
+    let input = crate::from_ndarray(ndarray::array![1., 2., 3.]).requires_grad();
+    let first = (input.clone().cumsum(0) * crate::from_ndarray(ndarray::array![1., 0., 0.])).sum();
+
+    first.forward();
+    first.backward(1.);
+    assert_eq!(*input.grad(), ndarray::array![1., 0., 0.]);
+}
+
+#[test]
+fn cumprod() {
+    let input = crate::ones((2, 3, 2));
+    let cumprod = input.cumprod(1);
+
+    assert_eq!(cumprod.past.len(), 1);
+    assert!(cumprod.past.changeables.is_empty());
+}
+
+#[test]
+fn cumprod_diff() {
+    let input = crate::from_ndarray(ndarray::array![[2., 0., 3.]]).requires_grad();
+    let cumprod = input.clone().cumprod(1);
+
+    assert_eq!(cumprod.past.len(), 1);
+    assert_eq!(cumprod.past.parameters.len(), 1);
+
+    let loss = cumprod.sum();
+    loss.forward();
+    loss.backward(1.);
+    assert_eq!(*input.grad(), ndarray::array![[1., 8., 0.]]);
+}
+
+#[test]
+fn t() {
+    let input = crate::ones((2, 2));
+    let t = input.t();
+
+    assert_eq!(t.past.len(), 1);
+    assert!(t.past.changeables.is_empty());
+}
+
+#[test]
+fn t_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let t = input.t();
+
+    assert_eq!(t.past.len(), 1);
+    assert_eq!(t.past.parameters.len(), 1);
+}
+
+#[test]
+fn dropout() {
+    let input = crate::ones((2, 2));
+    let dropout = input.dropout(0.5);
+
+    assert_eq!(dropout.past.len(), 1);
+    assert_eq!(dropout.past.changeables.len(), 1);
+}
+
+#[test]
+fn dropout_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let dropout = input.dropout(0.5);
+
+    assert_eq!(dropout.past.len(), 1);
+    assert_eq!(dropout.past.parameters.len(), 1);
+}
+
+#[test]
+fn chunks() {
+    let input = crate::ones((2, 2));
+    let chunks = input.chunks((1, 1));
+
+    assert_eq!(chunks[0].past.len(), 1);
+    assert_eq!(chunks[1].past.len(), 1);
+
+    assert!(chunks[0].past.changeables.is_empty());
+    assert!(chunks[1].past.changeables.is_empty());
+}
+
+#[test]
+fn chunks_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let chunks = input.chunks((1, 1));
+
+    assert_eq!(chunks[0].past.len(), 1);
+    assert_eq!(chunks[1].past.len(), 1);
+
+    assert_eq!(chunks[0].past.parameters.len(), 1);
+    assert_eq!(chunks[1].past.parameters.len(), 1);
+}
+
+#[test]
+fn unsqueeze() {
+    let input = crate::ones((2, 2));
+    let unsqueeze = input.unsqueeze(0);
+
+    assert_eq!(unsqueeze.past.len(), 1);
+    assert!(unsqueeze.past.changeables.is_empty());
+}
+
+#[test]
+fn unsqueeze_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let unsqueeze = input.unsqueeze(0);
+
+    assert_eq!(unsqueeze.past.len(), 1);
+    assert_eq!(unsqueeze.past.parameters.len(), 1);
+}
+
+#[test]
+fn narrow() {
+    let data = ndarray::Array::range(0., 24., 1.)
+        .into_shape((2, 4, 3))
+        .unwrap();
+    let narrow = crate::from_ndarray(data.clone()).narrow(1, 1, 2);
+
+    assert_eq!(narrow.past.len(), 1);
+    assert!(narrow.past.changeables.is_empty());
+
+    narrow.forward();
+    assert_eq!(*narrow.data(), data.slice(ndarray::s![.., 1..3, ..]));
+}
+
+#[test]
+fn narrow_diff() {
+    let input = crate::ones((2, 4, 3)).requires_grad();
+    let narrow = input.clone().narrow(1, 1, 2);
+
+    assert_eq!(narrow.past.len(), 1);
+    assert_eq!(narrow.past.parameters.len(), 1);
+
+    narrow.forward();
+    narrow.backward(1.);
+
+    let mut expected = ndarray::Array::zeros((2, 4, 3));
+    expected.slice_mut(ndarray::s![.., 1..3, ..]).fill(1.);
+    assert_eq!(*input.grad(), expected);
+}
+
+#[test]
+fn narrow_accumulation() {
+    // The two slices overlap on the third row of axis 1.
+    let input = crate::ones((2, 4, 3)).requires_grad();
+    let loss = input.clone().narrow(1, 0, 3).sum() + input.clone().narrow(1, 2, 2).sum() * 2.;
+
+    loss.forward();
+    loss.backward(1.);
+
+    let mut expected = ndarray::Array::zeros((2, 4, 3));
+    expected.slice_mut(ndarray::s![.., 0..2, ..]).fill(1.);
+    expected.slice_mut(ndarray::s![.., 2, ..]).fill(3.);
+    expected.slice_mut(ndarray::s![.., 3, ..]).fill(2.);
+    assert_eq!(*input.grad(), expected);
+}
+
+#[test]
+#[should_panic(expected = "error: cannot narrow axis 1 of length 4 to 3 elements starting at 2.")]
+fn narrow_out_of_range() {
+    crate::ones((2, 4, 3)).narrow(1, 2, 3);
+}
+
+#[test]
+fn pad_constant() {
+    let data = ndarray::array![[1., 2., 3.], [4., 5., 6.]];
+    let input = crate::from_ndarray(data.into_shape((1, 1, 2, 3)).unwrap()).requires_grad();
+    let pad = input.clone().pad(
+        &[(0, 0), (0, 0), (1, 0), (2, 1)],
+        crate::PadMode::Constant(-1.),
+    );
+
+    assert_eq!(pad.past.len(), 1);
+    assert_eq!(pad.past.parameters.len(), 1);
+
+    pad.forward();
+    assert_eq!(
+        pad.data().clone().into_shape((3, 6)).unwrap(),
+        ndarray::array![
+            [-1., -1., -1., -1., -1., -1.],
+            [-1., -1., 1., 2., 3., -1.],
+            [-1., -1., 4., 5., 6., -1.]
+        ]
+    );
+
+    pad.backward(1.);
+    assert_eq!(*input.grad(), ndarray::Array::<f32, _>::ones((1, 1, 2, 3)));
+}
+
+#[test]
+fn pad_reflect() {
+    let data = ndarray::array![[1., 2., 3.], [4., 5., 6.]];
+    let input = crate::from_ndarray(data.into_shape((1, 1, 2, 3)).unwrap()).requires_grad();
+    let pad = input
+        .clone()
+        .pad(&[(0, 0), (0, 0), (1, 0), (2, 1)], crate::PadMode::Reflect);
+
+    pad.forward();
+    assert_eq!(
+        pad.data().clone().into_shape((3, 6)).unwrap(),
+        ndarray::array![
+            [6., 5., 4., 5., 6., 5.],
+            [3., 2., 1., 2., 3., 2.],
+            [6., 5., 4., 5., 6., 5.]
+        ]
+    );
+
+    // Each element of the input receives the gradient of every copy of it.
+    pad.backward(1.);
+    assert_eq!(
+        input.grad().clone().into_shape((2, 3)).unwrap(),
+        ndarray::array![[1., 3., 2.], [2., 6., 4.]]
+    );
+}
+
+#[test]
+fn pad_replicate() {
+    let data = ndarray::array![[1., 2., 3.], [4., 5., 6.]];
+    let input = crate::from_ndarray(data.into_shape((1, 1, 2, 3)).unwrap()).requires_grad();
+    let pad = input
+        .clone()
+        .pad(&[(0, 0), (0, 0), (1, 0), (2, 1)], crate::PadMode::Replicate);
+
+    pad.forward();
+    assert_eq!(
+        pad.data().clone().into_shape((3, 6)).unwrap(),
+        ndarray::array![
+            [1., 1., 1., 2., 3., 3.],
+            [1., 1., 1., 2., 3., 3.],
+            [4., 4., 4., 5., 6., 6.]
+        ]
+    );
+
+    pad.backward(1.);
+    assert_eq!(
+        input.grad().clone().into_shape((2, 3)).unwrap(),
+        ndarray::array![[6., 2., 4.], [3., 1., 2.]]
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "error: reflective padding of (3, 0) is too large for axis 1 of length 3."
+)]
+fn pad_reflect_too_large() {
+    crate::ones((2, 3)).pad(&[(0, 0), (3, 0)], crate::PadMode::Reflect);
+}
+
+#[test]
+fn split() {
+    let data = ndarray::Array::range(0., 24., 1.)
+        .into_shape((6, 4))
+        .unwrap();
+    let pieces = crate::from_ndarray(data.clone()).split(&[2, 4], 0);
+
+    assert_eq!(pieces.len(), 2);
+    for piece in &pieces {
+        assert_eq!(piece.past.len(), 1);
+        assert!(piece.past.changeables.is_empty());
+        piece.forward();
+    }
+    assert_eq!(*pieces[0].data(), data.slice(ndarray::s![0..2, ..]));
+    assert_eq!(*pieces[1].data(), data.slice(ndarray::s![2..6, ..]));
+}
+
+#[test]
+fn split_diff() {
+    let input = crate::ones((6, 4)).requires_grad();
+    let mut pieces = input.clone().split(&[2, 4], 0);
+
+    assert_eq!(pieces[0].data().shape(), &[2, 4]);
+    assert_eq!(pieces[1].data().shape(), &[4, 4]);
+
+    let second = pieces.pop().unwrap();
+    let first = pieces.pop().unwrap();
+    let loss = first.sum() + second.sum() * 2.;
+    loss.forward();
+    loss.backward(1.);
+
+    let mut expected = ndarray::Array::ones((6, 4));
+    expected.slice_mut(ndarray::s![2..6, ..]).fill(2.);
+    assert_eq!(*input.grad(), expected);
+}
+
+#[test]
+#[should_panic(expected = "error: split sizes [2, 3] don't add up to the length 6 of axis 0.")]
+fn split_wrong_sizes() {
+    crate::ones((6, 4)).split(&[2, 3], 0);
+}
+
+#[test]
+fn split_sub_expressions() {
+    let data = ndarray::Array::range(0., 24., 1.)
+        .into_shape((6, 4))
+        .unwrap();
+    let input = crate::from_ndarray(data).requires_grad();
+    let pieces = input.clone().split(&[2, 3, 1], 0);
+
+    assert_eq!(pieces[0].data().shape(), &[2, 4]);
+    assert_eq!(pieces[1].data().shape(), &[3, 4]);
+    assert_eq!(pieces[2].data().shape(), &[1, 4]);
+
+    let loss = (pieces[0].clone() * 2.).sum()
+        + (pieces[1].clone() * pieces[1].clone()).sum()
+        + pieces[2].clone().exp().sum();
+    loss.forward();
+    loss.backward(1.);
+
+    let gradients: Vec<_> = pieces.iter().map(|piece| piece.grad().clone()).collect();
+    let views: Vec<_> = gradients.iter().map(|gradient| gradient.view()).collect();
+    assert_eq!(
+        *input.grad(),
+        ndarray::concatenate(ndarray::Axis(0), &views).unwrap()
+    );
+}
+
+#[test]
+fn chunk() {
+    let data = ndarray::Array::range(0., 14., 1.)
+        .into_shape((7, 2))
+        .unwrap();
+    let chunks = crate::from_ndarray(data.clone()).chunk(3, 0);
+
+    assert_eq!(chunks.len(), 3);
+    for chunk in &chunks {
+        chunk.forward();
+    }
+    assert_eq!(*chunks[0].data(), data.slice(ndarray::s![0..3, ..]));
+    assert_eq!(*chunks[1].data(), data.slice(ndarray::s![3..5, ..]));
+    assert_eq!(*chunks[2].data(), data.slice(ndarray::s![5..7, ..]));
+}
+
+#[test]
+fn chunk_diff() {
+    // Only the middle chunk takes part in the loss, the other ones contribute zeros.
+    let input = crate::ones((7, 2)).requires_grad();
+    let mut chunks = input.clone().chunk(3, 0);
+    let loss = chunks.remove(1).sum();
+
+    loss.forward();
+    loss.backward(1.);
+
+    let mut expected = ndarray::Array::zeros((7, 2));
+    expected.slice_mut(ndarray::s![3..5, ..]).fill(1.);
+    assert_eq!(*input.grad(), expected);
+}
+
+#[test]
+#[should_panic(expected = "error: cannot split a variable into 0 chunks.")]
+fn chunk_zero() {
+    crate::ones((7, 2)).chunk(0, 0);
+}
+
+#[test]
+fn flip() {
+    let flip = crate::from_ndarray(ndarray::array![[1., 2., 3.], [4., 5., 6.]]).flip(&[1]);
+
+    assert_eq!(flip.past.len(), 1);
+    assert!(flip.past.changeables.is_empty());
+
+    flip.forward();
+    assert_eq!(*flip.data(), ndarray::array![[3., 2., 1.], [6., 5., 4.]]);
+}
+
+#[test]
+fn flip_round_trip() {
+    let data = ndarray::Array::range(0., 24., 1.)
+        .into_shape((2, 3, 4))
+        .unwrap();
+    let weights = ndarray::Array::range(1., 25., 1.)
+        .into_shape((2, 3, 4))
+        .unwrap();
+    let input = crate::from_ndarray(data.clone()).requires_grad();
+    let round_trip = input.clone().flip(&[0, 2]).flip(&[2, 0]);
+    let loss = (round_trip.clone() * crate::from_ndarray(weights.clone())).sum();
+
+    loss.forward();
+    assert_eq!(*round_trip.data(), data);
+
+    loss.backward(1.);
+    assert_eq!(*input.grad(), weights);
+}
+
+#[test]
+fn roll() {
+    let roll = crate::from_ndarray(ndarray::array![[1., 2., 3.], [4., 5., 6.]]).roll(1, 1);
+
+    assert_eq!(roll.past.len(), 1);
+    assert!(roll.past.changeables.is_empty());
+
+    roll.forward();
+    assert_eq!(*roll.data(), ndarray::array![[3., 1., 2.], [6., 4., 5.]]);
+}
+
+#[test]
+fn roll_round_trip() {
+    let data = ndarray::Array::range(0., 24., 1.)
+        .into_shape((2, 3, 4))
+        .unwrap();
+    let weights = ndarray::Array::range(1., 25., 1.)
+        .into_shape((2, 3, 4))
+        .unwrap();
+    let input = crate::from_ndarray(data.clone()).requires_grad();
+    let round_trip = input.clone().roll(3, 2).roll(-3, 2);
+    let loss = (round_trip.clone() * crate::from_ndarray(weights.clone())).sum();
+
+    loss.forward();
+    assert_eq!(*round_trip.data(), data);
+
+    loss.backward(1.);
+    assert_eq!(*input.grad(), weights);
+}
+
+#[test]
+fn repeat() {
+    let repeat = crate::from_ndarray(ndarray::array![[1., 2., 3.], [4., 5., 6.]]).repeat(4, 0);
+
+    assert_eq!(repeat.past.len(), 1);
+    assert!(repeat.past.changeables.is_empty());
+
+    repeat.forward();
+    assert_eq!(repeat.data().shape(), &[8, 3]);
+    assert_eq!(
+        *repeat.data(),
+        ndarray::array![
+            [1., 2., 3.],
+            [4., 5., 6.],
+            [1., 2., 3.],
+            [4., 5., 6.],
+            [1., 2., 3.],
+            [4., 5., 6.],
+            [1., 2., 3.],
+            [4., 5., 6.]
+        ]
+    );
+}
+
+#[test]
+fn repeat_diff() {
+    let input = crate::ones((2, 3)).requires_grad();
+    let repeat = input.clone().repeat(4, 0);
+    let weights = ndarray::Array::range(1., 25., 1.)
+        .into_shape((8, 3))
+        .unwrap();
+    let loss = (repeat.clone() * crate::from_ndarray(weights)).sum();
+
+    assert_eq!(repeat.past.len(), 1);
+    assert_eq!(repeat.past.parameters.len(), 1);
+
+    loss.forward();
+    loss.backward(1.);
+    // Each row collects the gradient of its 4 copies.
+    assert_eq!(
+        *input.grad(),
+        ndarray::array![[40., 44., 48.], [52., 56., 60.]]
+    );
+}
+
+#[test]
+fn index_select() {
+    let data = ndarray::array![[1., 2.], [3., 4.], [5., 6.]];
+    let index_select = crate::from_ndarray(data).index_select(0, vec![2, 0, 2]);
+
+    assert_eq!(index_select.past.len(), 1);
+    assert!(index_select.past.changeables.is_empty());
+
+    index_select.forward();
+    assert_eq!(
+        *index_select.data(),
+        ndarray::array![[5., 6.], [1., 2.], [5., 6.]]
+    );
+}
+
+#[test]
+fn index_select_diff() {
+    // An embedding-style lookup, the rows picked more than once accumulate their gradients.
+    let embeddings = crate::ones((4, 3)).requires_grad();
+    let lookup = embeddings.clone().index_select(0, vec![1, 3, 1, 1]);
+
+    assert_eq!(lookup.past.len(), 1);
+    assert_eq!(lookup.past.parameters.len(), 1);
+
+    lookup.forward();
+    lookup.backward(1.);
+
+    assert_eq!(
+        *embeddings.grad(),
+        ndarray::array![[0., 0., 0.], [3., 3., 3.], [0., 0., 0.], [1., 1., 1.]]
+    );
+}
+
+#[test]
+#[should_panic(expected = "error: index 4 is out of bounds for axis 0 of length 4.")]
+fn index_select_out_of_bounds() {
+    crate::ones((4, 3)).index_select(0, vec![1, 4]);
+}
+
+#[test]
+fn gather() {
+    let data = ndarray::array![[1., 2., 3.], [4., 5., 6.]];
+    let gather = crate::from_ndarray(data).gather(1, ndarray::array![[2, 2], [0, 1]]);
+
+    assert_eq!(gather.past.len(), 1);
+    assert!(gather.past.changeables.is_empty());
+
+    gather.forward();
+    assert_eq!(*gather.data(), ndarray::array![[3., 3.], [4., 5.]]);
+}
+
+#[test]
+fn gather_diff() {
+    let input = crate::ones((2, 3)).requires_grad();
+    let gather = input.clone().gather(1, ndarray::array![[2, 2], [0, 1]]);
+
+    assert_eq!(gather.past.len(), 1);
+    assert_eq!(gather.past.parameters.len(), 1);
+
+    gather.forward();
+    gather.backward(1.);
+
+    assert_eq!(*input.grad(), ndarray::array![[0., 0., 2.], [1., 1., 0.]]);
+}
+
+#[test]
+#[should_panic(expected = "error: index 3 is out of bounds for axis 1 of length 3.")]
+fn gather_out_of_bounds() {
+    crate::ones((2, 3)).gather(1, ndarray::array![[0], [3]]);
+}
+
+#[test]
+fn gather_rows() {
+    let data = ndarray::Array::range(0., 12., 1.)
+        .into_shape((3, 4))
+        .unwrap();
+    let input = crate::from_ndarray(data).requires_grad();
+    let index = ndarray::array![[0, 0, 0, 0], [2, 2, 2, 2]];
+    let gather = input.clone().gather(0, index);
+
+    let weights = crate::from_ndarray(ndarray::array![[1., 2., 3., 4.], [5., 6., 7., 8.]]);
+    let loss = (gather.clone() * weights).sum();
+    loss.forward();
+    loss.backward(1.);
+
+    assert_eq!(
+        *gather.data(),
+        ndarray::array![[0., 1., 2., 3.], [8., 9., 10., 11.]]
+    );
+    assert_eq!(
+        *input.grad(),
+        ndarray::array![[1., 2., 3., 4.], [0., 0., 0., 0.], [5., 6., 7., 8.]]
+    );
+}
+
+#[test]
+fn scatter_add() {
+    let destination = crate::ones((2, 3));
+    let src = crate::from_ndarray(ndarray::array![[1., 2.], [3., 4.]]);
+    let scatter_add = destination.scatter_add(1, ndarray::array![[2, 2], [0, 1]], src);
+
+    assert_eq!(scatter_add.past.len(), 1);
+    assert!(scatter_add.past.changeables.is_empty());
+
+    scatter_add.forward();
+    assert_eq!(
+        *scatter_add.data(),
+        ndarray::array![[1., 1., 4.], [4., 5., 1.]]
+    );
+}
+
+#[test]
+fn scatter_add_diff() {
+    // Segment sum, rows 0 and 2 of the source fall into the same segment.
+    let destination = crate::zeros((2, 2)).requires_grad();
+    let src = crate::from_ndarray(ndarray::array![[1., 2.], [3., 4.], [5., 6.]]).requires_grad();
+    let index = ndarray::array![[0, 1], [1, 1], [0, 0]];
+    let scatter_add = destination.clone().scatter_add(0, index, src.clone());
+
+    assert_eq!(scatter_add.past.len(), 1);
+    assert_eq!(scatter_add.past.parameters.len(), 2);
+
+    let weights = crate::from_ndarray(ndarray::array![[1., 2.], [3., 4.]]);
+    let loss = (scatter_add.clone() * weights).sum();
+    loss.forward();
+    loss.backward(1.);
+
+    assert_eq!(*scatter_add.data(), ndarray::array![[6., 6.], [3., 6.]]);
+    assert_eq!(*destination.grad(), ndarray::array![[1., 2.], [3., 4.]]);
+    assert_eq!(*src.grad(), ndarray::array![[1., 4.], [3., 4.], [1., 2.]]);
+}
+
+#[test]
+fn scatter_add_mixed() {
+    let index = ndarray::array![[0, 1], [1, 1], [0, 0]];
+    let src = crate::from_ndarray(ndarray::array![[1., 2.], [3., 4.], [5., 6.]]);
+
+    // Differentiable destination.
+    let destination = crate::zeros((2, 2)).requires_grad();
+    let scatter_add = destination
+        .clone()
+        .scatter_add(0, index.clone(), src.clone());
+
+    assert_eq!(scatter_add.past.len(), 1);
+    assert_eq!(scatter_add.past.parameters.len(), 1);
+
+    scatter_add.forward();
+    scatter_add.backward(1.);
+    assert_eq!(*scatter_add.data(), ndarray::array![[6., 6.], [3., 6.]]);
+    assert_eq!(*destination.grad(), ndarray::array![[1., 1.], [1., 1.]]);
+
+    // Differentiable source.
+    let src = src.requires_grad();
+    let scatter_add = crate::zeros((2, 2)).scatter_add(0, index, src.clone());
+
+    assert_eq!(scatter_add.past.len(), 1);
+    assert_eq!(scatter_add.past.parameters.len(), 1);
+
+    scatter_add.forward();
+    scatter_add.backward(1.);
+    assert_eq!(*scatter_add.data(), ndarray::array![[6., 6.], [3., 6.]]);
+    assert_eq!(*src.grad(), ndarray::array![[1., 1.], [1., 1.], [1., 1.]]);
+}
+
+#[test]
+fn scatter_add_into_zeros() {
+    let src = crate::from_ndarray(ndarray::array![1., 2., 3.]).requires_grad();
+    let index = ndarray::array![0, 0, 1];
+    let scatter_add = crate::scatter_add(src.clone(), index.clone(), 2, 0);
+
+    assert_eq!(scatter_add.past.len(), 1);
+    assert_eq!(scatter_add.past.parameters.len(), 1);
+
+    let upstream = ndarray::array![4., 5.];
+    let loss = (scatter_add.clone() * crate::from_ndarray(upstream.clone())).sum();
+    loss.forward();
+    loss.backward(1.);
+
+    assert_eq!(*scatter_add.data(), ndarray::array![3., 3.]);
+    assert_eq!(*src.grad(), index.map(|i| upstream[*i]));
+}
+
+#[test]
+#[should_panic(expected = "error: index 3 is out of bounds for axis 1 of length 3.")]
+fn scatter_add_out_of_bounds() {
+    crate::ones((2, 3)).scatter_add(1, ndarray::array![[0], [3]], crate::ones((2, 1)));
+}
+
+#[test]
+fn masked_fill() {
+    let input = crate::from_ndarray(ndarray::array![[1., 2., 3.], [4., 5., 6.]]);
+    let mask = crate::from_ndarray(ndarray::array![0., 1., 0.]);
+    let masked_fill = input.masked_fill(mask, -1.);
+
+    assert_eq!(masked_fill.past.len(), 1);
+    assert!(masked_fill.past.changeables.is_empty());
+
+    masked_fill.forward();
+    assert_eq!(
+        *masked_fill.data(),
+        ndarray::array![[1., -1., 3.], [4., -1., 6.]]
+    );
+}
+
+#[test]
+fn masked_fill_softmax() {
+    let input = crate::from_ndarray(ndarray::array![[1., 2., 3.], [4., 5., 6.]]).requires_grad();
+    let mask = crate::from_ndarray(ndarray::array![[0., 1., 0.], [1., 1., 0.]]);
+    let attention = input
+        .clone()
+        .masked_fill(mask, f32::NEG_INFINITY)
+        .softmax(1);
+
+    assert_eq!(attention.past.len(), 2);
+    assert_eq!(attention.past.parameters.len(), 1);
+
+    let weights = crate::from_ndarray(ndarray::array![[1., 2., 3.], [4., 5., 6.]]);
+    let loss = (attention.clone() * weights).sum();
+    loss.forward();
+    loss.backward(1.);
+
+    // The masked elements get exactly zero probability and zero gradient.
+    let attention = attention.data();
+    assert_eq!(attention[[0, 1]], 0.);
+    assert_eq!(attention[[1, 0]], 0.);
+    assert_eq!(attention[[1, 1]], 0.);
+    assert_eq!(attention[[1, 2]], 1.);
+
+    let grad = input.grad();
+    assert!(grad.iter().all(|grad_el| grad_el.is_finite()));
+    assert_eq!(grad[[0, 1]], 0.);
+    assert_eq!(grad[[1, 0]], 0.);
+    assert_eq!(grad[[1, 1]], 0.);
+    assert!(grad[[0, 0]] < 0. && grad[[0, 2]] > 0.);
+}
+
+#[test]
+#[should_panic(
+    expected = "error: cannot broadcast a mask of shape [2] to a variable of shape [2, 3]."
+)]
+fn masked_fill_fail() {
+    crate::ones((2, 3)).masked_fill(crate::ones(2), 0.);
+}
+
+#[test]
+fn masked_select() {
+    let input = crate::from_ndarray(ndarray::array![[1., 2., 3.], [4., 5., 6.]]);
+    let mask = crate::from_ndarray(ndarray::array![[1., 0., 1.], [0., 0., 1.]]);
+    let masked_select = input.masked_select(mask);
+
+    assert_eq!(masked_select.past.len(), 1);
+    assert!(masked_select.past.changeables.is_empty());
+
+    masked_select.forward();
+    assert_eq!(*masked_select.data(), ndarray::array![1., 3., 6.]);
+}
+
+#[test]
+fn masked_select_diff() {
+    let input = crate::from_ndarray(ndarray::array![[1., 2., 3.], [4., 5., 6.]]).requires_grad();
+    let mask = crate::from_ndarray(ndarray::array![1., 0., 1.]);
+    let masked_select = input.clone().masked_select(mask);
+
+    assert_eq!(masked_select.past.len(), 1);
+    assert_eq!(masked_select.past.parameters.len(), 1);
+
+    masked_select.forward();
+    masked_select.backward(1.);
+
+    assert_eq!(*masked_select.data(), ndarray::array![1., 3., 4., 6.]);
+    assert_eq!(*input.grad(), ndarray::array![[1., 0., 1.], [1., 0., 1.]]);
+}
+
+#[test]
+fn where_() {
+    let condition = crate::from_ndarray(ndarray::array![[1.], [0.]]);
+    let lhs = crate::from_ndarray(ndarray::array![1., 2., 3.]);
+    let rhs = crate::full((), -1.);
+    let selected = crate::where_(condition, lhs, rhs);
+
+    assert_eq!(selected.past.len(), 1);
+    assert!(selected.past.changeables.is_empty());
+
+    selected.forward();
+    assert_eq!(
+        *selected.data(),
+        ndarray::array![[1., 2., 3.], [-1., -1., -1.]]
+    );
+}
+
+#[test]
+fn where_nan() {
+    let condition = crate::from_ndarray(ndarray::array![[1., 0.], [0., 1.]]);
+    let lhs = crate::from_ndarray(ndarray::array![[1., f32::NAN], [f32::NAN, 4.]]).requires_grad();
+    let rhs = crate::from_ndarray(ndarray::array![[f32::NAN, 2.], [3., f32::NAN]]).requires_grad();
+    let selected = crate::where_(condition, lhs.clone(), rhs.clone());
+
+    assert_eq!(selected.past.len(), 1);
+    assert_eq!(selected.past.parameters.len(), 2);
+
+    selected.forward();
+    selected.backward(1.);
+
+    assert_eq!(*selected.data(), ndarray::array![[1., 2.], [3., 4.]]);
+    assert_eq!(*lhs.grad(), ndarray::array![[1., 0.], [0., 1.]]);
+    assert_eq!(*rhs.grad(), ndarray::array![[0., 1.], [1., 0.]]);
+}
+
+#[test]
+fn where_mixed() {
+    let condition = crate::from_ndarray(ndarray::array![[1., 0., 1.], [0., 1., 0.]]);
+    let lhs = crate::from_ndarray(ndarray::array![1., 2., 3.]).requires_grad();
+    let rhs = crate::zeros((2, 1));
+    let selected = crate::where_(condition.clone(), lhs.clone(), rhs.clone());
+
+    assert_eq!(selected.past.len(), 1);
+    assert_eq!(selected.past.parameters.len(), 1);
+
+    selected.forward();
+    selected.backward(1.);
+
+    assert_eq!(
+        *selected.data(),
+        ndarray::array![[1., 0., 3.], [0., 2., 0.]]
+    );
+    assert_eq!(*lhs.grad(), ndarray::array![1., 1., 1.]);
+
+    let rhs = rhs.requires_grad();
+    let selected = crate::where_(condition, crate::ones(3), rhs.clone());
+
+    assert_eq!(selected.past.len(), 1);
+    assert_eq!(selected.past.parameters.len(), 1);
+
+    selected.forward();
+    selected.backward(1.);
+
+    assert_eq!(
+        *selected.data(),
+        ndarray::array![[1., 0., 1.], [0., 1., 0.]]
+    );
+    assert_eq!(*rhs.grad(), ndarray::array![[1.], [2.]]);
+}
+
+#[test]
+fn where_custom_activation() {
+    // A leaky ReLU, both branches come from the same variable and their gradients add up.
+    let input = crate::from_ndarray(ndarray::array![-2., -0.5, 0.5, 3.]).requires_grad();
+    let positive = crate::from_ndarray(input.data().mapv(|el| f32::from(u8::from(el > 0.))));
+    let selected = crate::where_(positive, input.clone(), input.clone() * 0.01);
+    let loss = selected.clone().sum();
 
-    assert_eq!(sigmoid.past.len(), 1);
-    assert_eq!(sigmoid.past.parameters.len(), 1);
+    loss.forward();
+    assert!(selected
+        .data()
+        .iter()
+        .zip(ndarray::array![-0.02, -0.005, 0.5, 3.].iter())
+        .all(|(l, r)| (l - r).abs() < 1e-6));
+
+    loss.backward(1.);
+    assert!(input
+        .grad()
+        .iter()
+        .zip(ndarray::array![0.01, 0.01, 1., 1.].iter())
+        .all(|(l, r)| (l - r).abs() < 1e-6));
 }
 
 #[test]
-fn tanh() {
-    let input = crate::ones((2, 2));
-    let tanh = input.tanh();
-
-    assert_eq!(tanh.past.len(), 1);
-    assert!(tanh.past.changeables.is_empty());
+#[should_panic(expected = "error: the two tensors have incompatible shape.")]
+fn where_fail() {
+    let condition = crate::ones(2);
+    crate::where_(condition, crate::ones(3), crate::zeros(3));
 }
 
 #[test]
-fn tanh_diff() {
-    let input = crate::ones((2, 2)).requires_grad();
-    let tanh = input.tanh();
+fn squeeze() {
+    let input = crate::ones((1, 3, 1));
+    let squeeze = input.squeeze(2);
 
-    assert_eq!(tanh.past.len(), 1);
-    assert_eq!(tanh.past.parameters.len(), 1);
+    assert_eq!(squeeze.past.len(), 1);
+    assert!(squeeze.past.changeables.is_empty());
+    assert_eq!(squeeze.data().shape(), &[1, 3]);
 }
 
 #[test]
-fn ln() {
-    let input = crate::ones((2, 2));
-    let ln = input.ln();
+fn squeeze_diff() {
+    let input = crate::ones((1, 3, 1)).requires_grad();
+    let squeeze = input.squeeze(0);
 
-    assert_eq!(ln.past.len(), 1);
-    assert!(ln.past.changeables.is_empty());
+    assert_eq!(squeeze.past.len(), 1);
+    assert_eq!(squeeze.past.parameters.len(), 1);
+    assert_eq!(squeeze.data().shape(), &[3, 1]);
 }
 
 #[test]
-fn ln_diff() {
-    let input = crate::ones((2, 2)).requires_grad();
-    let ln = input.ln();
+fn unsqueeze_squeeze_round_trip() {
+    let data = ndarray::array![[1., 2., 3.], [4., 5., 6.]];
 
-    assert_eq!(ln.past.len(), 1);
-    assert_eq!(ln.past.parameters.len(), 1);
-}
+    for axis in 0..3 {
+        let input = crate::from_ndarray(data.clone()).requires_grad();
+        let round_trip = input.clone().unsqueeze(axis).squeeze(axis);
 
-#[test]
-fn exp() {
-    let input = crate::ones((2, 2));
-    let exp = input.exp();
+        round_trip.forward();
+        assert_eq!(*round_trip.data(), data);
 
-    assert_eq!(exp.past.len(), 1);
-    assert!(exp.past.changeables.is_empty());
+        round_trip.backward(1.);
+        assert_eq!(*input.grad(), ndarray::Array::<f32, _>::ones((2, 3)));
+    }
 }
 
 #[test]
-fn exp_diff() {
-    let input = crate::ones((2, 2)).requires_grad();
-    let exp = input.exp();
-
-    assert_eq!(exp.past.len(), 1);
-    assert_eq!(exp.past.parameters.len(), 1);
+#[should_panic(
+    expected = "error: cannot squeeze axis 1 of length 3, only axes of length one can be squeezed."
+)]
+fn squeeze_non_unit_axis() {
+    crate::ones((1, 3, 1)).squeeze(1);
 }
 
 #[test]
-fn softmax() {
-    let input = crate::ones((2, 2));
-    let softmax = input.softmax(1);
+fn reshape() {
+    let input = crate::ones((2, 3));
+    let reshape = input.reshape((3, 2));
 
-    assert_eq!(softmax.past.len(), 1);
-    assert!(softmax.past.changeables.is_empty());
+    assert_eq!(reshape.past.len(), 1);
+    assert!(reshape.past.changeables.is_empty());
+    assert_eq!(reshape.data().shape(), &[3, 2]);
+
+    let reshape = crate::ones((2, 3, 4)).reshape(vec![usize::MAX, 4]);
+    assert_eq!(reshape.data().shape(), &[6, 4]);
 }
 
 #[test]
-fn softmax_diff() {
-    let input = crate::ones((2, 2)).requires_grad();
-    let softmax = input.softmax(1);
+fn reshape_diff() {
+    let input = crate::ones((2, 3)).requires_grad();
+    let reshape = input.clone().reshape((usize::MAX, 2));
 
-    assert_eq!(softmax.past.len(), 1);
-    assert_eq!(softmax.past.parameters.len(), 1);
+    assert_eq!(reshape.past.len(), 1);
+    assert_eq!(reshape.past.parameters.len(), 1);
+    assert_eq!(reshape.data().shape(), &[3, 2]);
+
+    let loss = (reshape * crate::from_ndarray(ndarray::array![[1., 2.], [3., 4.], [5., 6.]])).sum();
+    loss.forward();
+    loss.backward(1.);
+    assert_eq!(*input.grad(), ndarray::array![[1., 2., 3.], [4., 5., 6.]]);
 }
 
 #[test]
-fn log_softmax() {
-    let input = crate::ones((2, 2));
-    let log_softmax = input.log_softmax(1);
-
-    assert_eq!(log_softmax.past.len(), 1);
-    assert!(log_softmax.past.changeables.is_empty());
+#[should_panic(expected = "error: cannot reshape 6 elements into shape [-1, 4].")]
+fn reshape_incompatible() {
+    crate::ones((2, 3)).reshape((usize::MAX, 4));
 }
 
 #[test]
-fn log_softmax_diff() {
-    let input = crate::ones((2, 2)).requires_grad();
-    let log_softmax = input.log_softmax(1);
+fn permute() {
+    let data = ndarray::Array::range(0., 120., 1.)
+        .into_shape((2, 3, 4, 5))
+        .unwrap();
+    let input = crate::from_ndarray(data.clone());
+    let permute = input.clone().permute([0, 2, 1, 3]);
 
-    assert_eq!(log_softmax.past.len(), 1);
-    assert_eq!(log_softmax.past.parameters.len(), 1);
-}
+    assert_eq!(permute.past.len(), 1);
+    assert!(permute.past.changeables.is_empty());
 
-#[test]
-fn t() {
-    let input = crate::ones((2, 2));
-    let t = input.t();
+    permute.forward();
+    assert_eq!(*permute.data(), data.view().permuted_axes([0, 2, 1, 3]));
+    assert!(permute.data().is_standard_layout());
 
-    assert_eq!(t.past.len(), 1);
-    assert!(t.past.changeables.is_empty());
+    let swap_axes = input.swap_axes(3, 1);
+    swap_axes.forward();
+    assert_eq!(*swap_axes.data(), data.view().permuted_axes([0, 3, 2, 1]));
 }
 
 #[test]
-fn t_diff() {
-    let input = crate::ones((2, 2)).requires_grad();
-    let t = input.t();
+fn permute_diff() {
+    let data = ndarray::Array::range(0., 120., 1.)
+        .into_shape((2, 3, 4, 5))
+        .unwrap();
+    let input = crate::from_ndarray(data.clone()).requires_grad();
+    let permute = input.clone().permute([3, 0, 2, 1]);
 
-    assert_eq!(t.past.len(), 1);
-    assert_eq!(t.past.parameters.len(), 1);
-}
+    assert_eq!(permute.past.len(), 1);
+    assert_eq!(permute.past.parameters.len(), 1);
+    assert_eq!(permute.data().shape(), &[5, 2, 4, 3]);
 
-#[test]
-fn dropout() {
-    let input = crate::ones((2, 2));
-    let dropout = input.dropout(0.5);
+    // Each element of the permuted variable is weighted by its position in the permuted layout,
+    // the gradient holds the same weights laid out as the input.
+    let weights = ndarray::Array::range(0., 120., 1.)
+        .into_shape((5, 2, 4, 3))
+        .unwrap();
+    let loss = (permute * crate::from_ndarray(weights.clone())).sum();
+    loss.forward();
+    loss.backward(1.);
+    assert_eq!(*input.grad(), weights.view().permuted_axes([1, 3, 2, 0]));
 
-    assert_eq!(dropout.past.len(), 1);
-    assert_eq!(dropout.past.changeables.len(), 1);
+    input.grad_mut().fill(0.);
+    let swap_axes = input.clone().swap_axes(0, 2);
+    assert_eq!(swap_axes.data().shape(), &[4, 3, 2, 5]);
+
+    let loss = (swap_axes.clone() * swap_axes).sum();
+    loss.forward();
+    loss.backward(1.);
+    assert_eq!(*input.grad(), &data * 2.);
 }
 
 #[test]
-fn dropout_diff() {
-    let input = crate::ones((2, 2)).requires_grad();
-    let dropout = input.dropout(0.5);
+#[should_panic(
+    expected = "error: [0, 2, 2, 3] is not a permutation of the axes of a variable with 4 dimensions, each axis must appear exactly once."
+)]
+fn permute_invalid() {
+    crate::ones((2, 3, 4, 5)).permute([0, 2, 2, 3]);
+}
 
-    assert_eq!(dropout.past.len(), 1);
-    assert_eq!(dropout.past.parameters.len(), 1);
+#[test]
+#[should_panic(expected = "error: cannot swap axes 1 and 4 of a variable with 4 dimensions.")]
+fn swap_axes_out_of_bounds() {
+    crate::ones((2, 3, 4, 5)).requires_grad().swap_axes(1, 4);
 }
 
 #[test]
-fn chunks() {
-    let input = crate::ones((2, 2));
-    let chunks = input.chunks((1, 1));
+fn flatten() {
+    let input = crate::ones((2, 3, 4, 5));
+    let flatten = input.clone().flatten();
 
-    assert_eq!(chunks[0].past.len(), 1);
-    assert_eq!(chunks[1].past.len(), 1);
+    assert_eq!(flatten.past.len(), 1);
+    assert!(flatten.past.changeables.is_empty());
+    assert_eq!(flatten.data().shape(), &[2, 60]);
 
-    assert!(chunks[0].past.changeables.is_empty());
-    assert!(chunks[1].past.changeables.is_empty());
+    let flatten = input.clone().flatten_range(1, 2);
+    assert_eq!(flatten.data().shape(), &[2, 12, 5]);
+
+    let flatten = input.flatten_range(0, 3);
+    assert_eq!(flatten.data().shape(), &[120]);
 }
 
 #[test]
-fn chunks_diff() {
-    let input = crate::ones((2, 2)).requires_grad();
-    let chunks = input.chunks((1, 1));
+fn flatten_diff() {
+    let data = ndarray::Array::range(0., 120., 1.)
+        .into_shape((2, 3, 4, 5))
+        .unwrap();
+    let input = crate::from_ndarray(data.clone()).requires_grad();
+    let flatten = input.clone().flatten();
 
-    assert_eq!(chunks[0].past.len(), 1);
-    assert_eq!(chunks[1].past.len(), 1);
+    assert_eq!(flatten.past.len(), 1);
+    assert_eq!(flatten.past.parameters.len(), 1);
 
-    assert_eq!(chunks[0].past.parameters.len(), 1);
-    assert_eq!(chunks[1].past.parameters.len(), 1);
-}
+    // The gradient of the squared sum is twice the input, reshaped back to its original shape.
+    let loss = (flatten.clone() * flatten).sum();
+    loss.forward();
+    loss.backward(1.);
+    assert_eq!(*input.grad(), &data * 2.);
 
-#[test]
-fn unsqueeze() {
-    let input = crate::ones((2, 2));
-    let unsqueeze = input.unsqueeze(0);
+    input.grad_mut().fill(0.);
+    let flatten = input.clone().flatten_range(1, 2);
+    assert_eq!(flatten.data().shape(), &[2, 12, 5]);
 
-    assert_eq!(unsqueeze.past.len(), 1);
-    assert!(unsqueeze.past.changeables.is_empty());
+    let loss = (flatten.clone() * flatten).sum();
+    loss.forward();
+    loss.backward(1.);
+    assert_eq!(*input.grad(), &data * 2.);
 }
 
 #[test]
-fn unsqueeze_diff() {
-    let input = crate::ones((2, 2)).requires_grad();
-    let unsqueeze = input.unsqueeze(0);
-
-    assert_eq!(unsqueeze.past.len(), 1);
-    assert_eq!(unsqueeze.past.parameters.len(), 1);
+#[should_panic(expected = "error: cannot flatten axes 2 to 4 of a variable with 4 dimensions.")]
+fn flatten_range_out_of_bounds() {
+    crate::ones((2, 3, 4, 5)).flatten_range(2, 4);
 }
 
 #[test]
@@ -612,6 +2184,60 @@ fn multi_stack_diff() {
     assert_eq!(d.past.parameters.len(), 3);
 }
 
+#[test]
+fn multi_stack_backward() {
+    let a = crate::ones((2, 3)).requires_grad().into_dyn();
+    let b = crate::ones((2, 3)).requires_grad().into_dyn();
+    let c = crate::ones((2, 3)).requires_grad().into_dyn();
+
+    let d = crate::VarDiff::stack(&[a.clone(), b.clone(), c.clone()], 0);
+    let weights = crate::from_ndarray(ndarray::array![[[1.]], [[2.]], [[3.]]]);
+    let loss = (d.clone() * weights).sum();
+    loss.forward();
+    loss.backward(1.);
+
+    assert_eq!(d.data().shape(), &[3, 2, 3]);
+    assert_eq!(*a.grad(), ndarray::Array::from_elem((2, 3), 1.));
+    assert_eq!(*b.grad(), ndarray::Array::from_elem((2, 3), 2.));
+    assert_eq!(*c.grad(), ndarray::Array::from_elem((2, 3), 3.));
+}
+
+#[test]
+fn multi_stack_five_matrices() {
+    for axis in 0..3 {
+        let tensors: Vec<_> = (0..5)
+            .map(|i| {
+                ndarray::Array::range(6. * i as f32, 6. * (i + 1) as f32, 1.)
+                    .into_shape((2, 3))
+                    .unwrap()
+            })
+            .collect();
+        let variables: Vec<_> = tensors
+            .iter()
+            .map(|tensor| {
+                crate::from_ndarray(tensor.clone())
+                    .requires_grad()
+                    .into_dyn()
+            })
+            .collect();
+
+        let stacked = crate::VarDiff::stack(&variables, axis);
+        let views: Vec<_> = tensors.iter().map(|tensor| tensor.view()).collect();
+        let expected = ndarray::stack(ndarray::Axis(axis), &views).unwrap();
+        let weights = expected.mapv(|el| el * 2. + 1.);
+        let loss = (stacked.clone() * crate::from_ndarray(weights.clone())).sum();
+
+        loss.forward();
+        assert_eq!(stacked.data().shape(), expected.shape());
+        assert_eq!(*stacked.data(), expected);
+
+        loss.backward(1.);
+        for (i, variable) in variables.iter().enumerate() {
+            assert_eq!(*variable.grad(), weights.index_axis(ndarray::Axis(axis), i));
+        }
+    }
+}
+
 #[test]
 fn neg() {
     let input = crate::ones((2, 2));
@@ -800,6 +2426,409 @@ fn vv_diff() {
     assert_eq!(vv.past.parameters.len(), 2);
 }
 
+#[test]
+fn outer() {
+    let lhs = crate::ones(3);
+    let rhs = crate::zeros(2);
+    let outer = lhs.outer(rhs);
+
+    assert_eq!(outer.past.len(), 1);
+    assert!(outer.past.changeables.is_empty());
+}
+
+#[test]
+fn outer_diff() {
+    let lhs = crate::ones(3);
+    let rhs = crate::zeros(2).requires_grad();
+    let outer = lhs.outer(rhs);
+
+    assert_eq!(outer.past.len(), 1);
+    assert_eq!(outer.past.parameters.len(), 1);
+
+    let lhs = crate::ones(3).requires_grad();
+    let rhs = crate::zeros(2);
+    let outer = lhs.outer(rhs);
+
+    assert_eq!(outer.past.len(), 1);
+    assert_eq!(outer.past.parameters.len(), 1);
+
+    let lhs = crate::ones(3).requires_grad();
+    let rhs = crate::zeros(2).requires_grad();
+    let outer = lhs.outer(rhs);
+
+    assert_eq!(outer.past.len(), 1);
+    assert_eq!(outer.past.parameters.len(), 2);
+}
+
+#[test]
+fn outer_matches_unsqueeze() {
+    let (lhs_data, rhs_data) = (crate::rand(3).data().clone(), crate::rand(2).data().clone());
+    let close = |lhs: &ndarray::Array1<f32>, rhs: &ndarray::Array1<f32>| {
+        lhs.iter()
+            .zip(rhs.iter())
+            .all(|(l, r)| (l - r).abs() < 1e-6)
+    };
+    let weights = crate::from_ndarray(ndarray::array![[1., -2.], [3., 0.5], [-1., 4.]]);
+
+    let (lhs, rhs) = (
+        crate::from_ndarray(lhs_data.clone()).requires_grad(),
+        crate::from_ndarray(rhs_data.clone()).requires_grad(),
+    );
+    let outer = lhs.clone().outer(rhs.clone());
+    let loss = (outer.clone() * weights.clone()).sum();
+    loss.forward();
+    loss.backward(1.);
+
+    let (unsqueezed_lhs, unsqueezed_rhs) = (
+        crate::from_ndarray(lhs_data).requires_grad(),
+        crate::from_ndarray(rhs_data.clone()).requires_grad(),
+    );
+    let composed = unsqueezed_lhs.clone().unsqueeze(1) * unsqueezed_rhs.clone().unsqueeze(0);
+    let composed_loss = (composed.clone() * weights.clone()).sum();
+    composed_loss.forward();
+    composed_loss.backward(1.);
+
+    assert_eq!(*outer.data(), *composed.data());
+    assert!(close(&lhs.grad(), &unsqueezed_lhs.grad()));
+    assert!(close(&rhs.grad(), &unsqueezed_rhs.grad()));
+
+    // Only one of the operands is differentiable.
+    let lhs = crate::from_ndarray(lhs.data().clone());
+    let rhs = crate::from_ndarray(rhs_data).requires_grad();
+    let loss = (lhs.outer(rhs.clone()) * weights).sum();
+    loss.forward();
+    loss.backward(1.);
+
+    assert!(close(&rhs.grad(), &unsqueezed_rhs.grad()));
+}
+
+#[test]
+fn outer_gradient() {
+    // The gradients are the matrix-vector products of the upstream gradient with the operands.
+    let lhs = crate::from_ndarray(ndarray::array![1., 2., 3., 4.]).requires_grad();
+    let rhs = crate::from_ndarray(ndarray::array![-1., 0.5, 2.]).requires_grad();
+    let weights = ndarray::Array::range(1., 13., 1.)
+        .into_shape((4, 3))
+        .unwrap();
+    let outer = lhs.clone().outer(rhs.clone());
+    let loss = (outer.clone() * crate::from_ndarray(weights.clone())).sum();
+
+    loss.forward();
+    assert_eq!(outer.data().shape(), &[4, 3]);
+    assert_eq!(
+        *outer.data(),
+        ndarray::array![[-1., 0.5, 2.], [-2., 1., 4.], [-3., 1.5, 6.], [-4., 2., 8.]]
+    );
+
+    loss.backward(1.);
+    assert_eq!(*lhs.grad(), weights.dot(&*rhs.data()));
+    assert_eq!(*rhs.grad(), weights.t().dot(&*lhs.data()));
+}
+
+#[test]
+fn bmm() {
+    let lhs = crate::ones((4, 3, 5));
+    let rhs = crate::zeros((4, 5, 2));
+    let bmm = lhs.bmm(rhs);
+
+    assert_eq!(bmm.past.len(), 1);
+    assert!(bmm.past.changeables.is_empty());
+    assert_eq!(bmm.data().shape(), &[4, 3, 2]);
+}
+
+#[test]
+fn bmm_diff() {
+    let lhs = crate::ones((4, 3, 5));
+    let rhs = crate::zeros((4, 5, 2)).requires_grad();
+    let bmm = lhs.bmm(rhs);
+
+    assert_eq!(bmm.past.len(), 1);
+    assert_eq!(bmm.past.parameters.len(), 1);
+
+    let lhs = crate::ones((4, 3, 5)).requires_grad();
+    let rhs = crate::zeros((4, 5, 2));
+    let bmm = lhs.bmm(rhs);
+
+    assert_eq!(bmm.past.len(), 1);
+    assert_eq!(bmm.past.parameters.len(), 1);
+
+    let lhs = crate::ones((4, 3, 5)).requires_grad();
+    let rhs = crate::zeros((4, 5, 2)).requires_grad();
+    let bmm = lhs.bmm(rhs);
+
+    assert_eq!(bmm.past.len(), 1);
+    assert_eq!(bmm.past.parameters.len(), 2);
+}
+
+#[test]
+fn bmm_matches_mm() {
+    use ndarray::Axis;
+
+    let (lhs_data, rhs_data) = (
+        crate::rand((4, 3, 5)).data().clone(),
+        crate::rand((4, 5, 2)).data().clone(),
+    );
+    let close = |lhs: &[f32], rhs: &[f32]| {
+        lhs.iter()
+            .zip(rhs.iter())
+            .all(|(l, r)| (l - r).abs() < 1e-5)
+    };
+
+    let lhs = crate::from_ndarray(lhs_data.clone()).requires_grad();
+    let rhs = crate::from_ndarray(rhs_data.clone()).requires_grad();
+    let bmm = lhs.clone().bmm(rhs.clone());
+    let loss = (bmm.clone() * bmm.clone()).sum();
+    loss.forward();
+    loss.backward(1.);
+    assert_eq!(lhs.grad().shape(), &[4, 3, 5]);
+    assert_eq!(rhs.grad().shape(), &[4, 5, 2]);
+
+    for batch in 0..4 {
+        let lhs_batch =
+            crate::from_ndarray(lhs_data.index_axis(Axis(0), batch).to_owned()).requires_grad();
+        let rhs_batch =
+            crate::from_ndarray(rhs_data.index_axis(Axis(0), batch).to_owned()).requires_grad();
+        let mm = lhs_batch.clone().mm(rhs_batch.clone());
+        let loss = (mm.clone() * mm.clone()).sum();
+        loss.forward();
+        loss.backward(1.);
+
+        assert!(close(
+            bmm.data().index_axis(Axis(0), batch).as_slice().unwrap(),
+            mm.data().as_slice().unwrap()
+        ));
+        assert!(close(
+            lhs.grad().index_axis(Axis(0), batch).as_slice().unwrap(),
+            lhs_batch.grad().as_slice().unwrap()
+        ));
+        assert!(close(
+            rhs.grad().index_axis(Axis(0), batch).as_slice().unwrap(),
+            rhs_batch.grad().as_slice().unwrap()
+        ));
+    }
+}
+
+#[test]
+fn bmm_broadcast() {
+    use ndarray::Axis;
+
+    let (lhs_data, rhs_data) = (
+        crate::rand((3, 5)).data().clone(),
+        crate::rand((4, 5, 2)).data().clone(),
+    );
+    let close = |lhs: &[f32], rhs: &[f32]| {
+        lhs.iter()
+            .zip(rhs.iter())
+            .all(|(l, r)| (l - r).abs() < 1e-5)
+    };
+
+    let lhs = crate::from_ndarray(lhs_data.clone()).requires_grad();
+    let rhs = crate::from_ndarray(rhs_data.clone());
+    let bmm = lhs.clone().bmm(rhs);
+    assert_eq!(bmm.past.parameters.len(), 1);
+    bmm.forward();
+    bmm.backward(1.);
+    assert_eq!(bmm.data().shape(), &[4, 3, 2]);
+
+    let mut lhs_grad = ndarray::Array2::<f32>::zeros((3, 5));
+    for batch in 0..4 {
+        let lhs_batch = crate::from_ndarray(lhs_data.clone()).requires_grad();
+        let rhs_batch = crate::from_ndarray(rhs_data.index_axis(Axis(0), batch).to_owned());
+        let mm = lhs_batch.clone().mm(rhs_batch);
+        mm.forward();
+        mm.backward(1.);
+
+        assert!(close(
+            bmm.data().index_axis(Axis(0), batch).as_slice().unwrap(),
+            mm.data().as_slice().unwrap()
+        ));
+        lhs_grad += &*lhs_batch.grad();
+    }
+    assert!(close(
+        lhs.grad().as_slice().unwrap(),
+        lhs_grad.as_slice().unwrap()
+    ));
+}
+
+#[test]
+fn kron() {
+    let lhs = crate::ones((2, 3));
+    let rhs = crate::zeros((3, 2));
+    let kron = lhs.kron(rhs);
+
+    assert_eq!(kron.past.len(), 1);
+    assert!(kron.past.changeables.is_empty());
+    assert_eq!(kron.data().shape(), &[6, 6]);
+}
+
+#[test]
+fn kron_diff() {
+    let lhs = crate::ones((2, 3));
+    let rhs = crate::zeros((3, 2)).requires_grad();
+    let kron = lhs.kron(rhs);
+
+    assert_eq!(kron.past.len(), 1);
+    assert_eq!(kron.past.parameters.len(), 1);
+
+    let lhs = crate::ones((2, 3)).requires_grad();
+    let rhs = crate::zeros((3, 2));
+    let kron = lhs.kron(rhs);
+
+    assert_eq!(kron.past.len(), 1);
+    assert_eq!(kron.past.parameters.len(), 1);
+
+    let lhs = crate::ones((2, 3)).requires_grad();
+    let rhs = crate::full((3, 2), 2.).requires_grad();
+    let kron = lhs.clone().kron(rhs.clone());
+
+    assert_eq!(kron.past.len(), 1);
+    assert_eq!(kron.past.parameters.len(), 2);
+
+    let loss = kron.sum();
+    loss.forward();
+    loss.backward(1.);
+    assert_eq!(*lhs.grad(), ndarray::Array2::from_elem((2, 3), 12.));
+    assert_eq!(*rhs.grad(), ndarray::Array2::from_elem((3, 2), 6.));
+}
+
+#[test]
+fn kron_diff_backward() {
+    let lhs = crate::from_ndarray(ndarray::array![[0.5, -1., 2.], [1.5, 0.25, -0.75]]);
+    let rhs = crate::from_ndarray(ndarray::array![[1., -0.5], [2., 0.3], [-1.2, 0.8]]);
+    let (lhs, rhs) = (lhs.requires_grad(), rhs.requires_grad());
+    let weights = ndarray::Array::from_shape_fn((6, 6), |(i, j)| (i * 6 + j) as f32 / 10. - 1.);
+    let loss = (lhs.clone().kron(rhs.clone()) * crate::from_ndarray(weights)).sum();
+
+    super::check_gradient(&lhs, &loss);
+    super::check_gradient(&rhs, &loss);
+}
+
+#[test]
+fn tensordot() {
+    let lhs = crate::ones((2, 3, 4));
+    let rhs = crate::zeros((4, 5));
+    let tensordot = lhs.tensordot(rhs, &[2], &[0]);
+
+    assert_eq!(tensordot.past.len(), 6);
+    assert!(tensordot.past.changeables.is_empty());
+    assert_eq!(tensordot.data().shape(), &[2, 3, 5]);
+}
+
+#[test]
+fn tensordot_forward() {
+    let lhs = ndarray::Array::range(0., 24., 1.)
+        .into_shape((2, 3, 4))
+        .unwrap();
+    let rhs = ndarray::Array::range(0., 20., 1.)
+        .into_shape((4, 5))
+        .unwrap();
+    let tensordot = crate::tensordot(
+        crate::from_ndarray(lhs.clone()),
+        crate::from_ndarray(rhs.clone()),
+        &[2],
+        &[0],
+    );
+    tensordot.forward();
+
+    let target = lhs
+        .into_shape((6, 4))
+        .unwrap()
+        .dot(&rhs)
+        .into_shape((2, 3, 5))
+        .unwrap()
+        .into_dyn();
+    assert_eq!(*tensordot.data(), target);
+
+    let lhs = ndarray::Array::range(0., 24., 1.)
+        .into_shape((2, 3, 4))
+        .unwrap();
+    let rhs = ndarray::Array::range(0., 6., 1.)
+        .into_shape((3, 2))
+        .unwrap();
+    let tensordot = crate::from_ndarray(lhs.clone()).tensordot(
+        crate::from_ndarray(rhs.clone()),
+        &[1, 0],
+        &[0, 1],
+    );
+    tensordot.forward();
+
+    let target = ndarray::Array::from_shape_fn(4, |k| {
+        let mut sum = 0.;
+        for i in 0..2 {
+            for j in 0..3 {
+                sum += lhs[[i, j, k]] * rhs[[j, i]];
+            }
+        }
+        sum
+    });
+    assert_eq!(*tensordot.data(), target.into_dyn());
+}
+
+#[test]
+fn tensordot_diff() {
+    let lhs = crate::ones((2, 3, 4));
+    let rhs = crate::zeros((4, 5)).requires_grad();
+    let tensordot = lhs.tensordot(rhs, &[2], &[0]);
+
+    assert_eq!(tensordot.past.len(), 4);
+    assert_eq!(tensordot.past.parameters.len(), 1);
+
+    let lhs = crate::ones((2, 3, 4)).requires_grad();
+    let rhs = crate::zeros((4, 5));
+    let tensordot = lhs.tensordot(rhs, &[2], &[0]);
+
+    assert_eq!(tensordot.past.len(), 4);
+    assert_eq!(tensordot.past.parameters.len(), 1);
+
+    let lhs = crate::from_ndarray(
+        ndarray::Array::range(0., 24., 1.)
+            .into_shape((2, 3, 4))
+            .unwrap(),
+    )
+    .requires_grad();
+    let rhs = crate::from_ndarray(
+        ndarray::Array::range(0., 20., 1.)
+            .into_shape((4, 5))
+            .unwrap(),
+    )
+    .requires_grad();
+    let tensordot = lhs.clone().tensordot(rhs.clone(), &[2], &[0]);
+
+    assert_eq!(tensordot.past.len(), 6);
+    assert_eq!(tensordot.past.parameters.len(), 2);
+
+    let loss = tensordot.sum();
+    loss.forward();
+    loss.backward(1.);
+
+    // The gradient of the sum with respect to lhs[i, j, k] is the sum of the k-th row of rhs.
+    let lhs_grad = ndarray::Array::from_shape_fn((2, 3, 4), |(_, _, k)| rhs.data().row(k).sum());
+    // The gradient of the sum with respect to rhs[k, l] is the sum of lhs[.., .., k].
+    let rhs_grad = ndarray::Array::from_shape_fn((4, 5), |(k, _)| {
+        lhs.data().index_axis(ndarray::Axis(2), k).sum()
+    });
+    assert_eq!(*lhs.grad(), lhs_grad);
+    assert_eq!(*rhs.grad(), rhs_grad);
+}
+
+#[test]
+#[should_panic(expected = "error: tensordot axes must have the same length, got 2 and 1.")]
+fn tensordot_mismatched_axes() {
+    let lhs = crate::ones((2, 3, 4));
+    let rhs = crate::ones((4, 5));
+
+    lhs.tensordot(rhs, &[1, 2], &[0]);
+}
+
+#[test]
+#[should_panic(expected = "error: cannot contract axis 1 of length 3 with axis 0 of length 4.")]
+fn tensordot_mismatched_lengths() {
+    let lhs = crate::ones((2, 3, 4));
+    let rhs = crate::ones((4, 5));
+
+    lhs.tensordot(rhs, &[1], &[0]);
+}
+
 #[test]
 fn vm() {
     let lhs = crate::ones(2);
@@ -1048,11 +3077,7 @@ fn max_pooling() {
     use crate::MaxPooling;
 
     let input = crate::ones((4, 2, 6, 6));
-    let max_pool = super::Var::max_pool(
-        input,
-        &[2, 2],
-        &[2, 2],
-    );
+    let max_pool = super::Var::max_pool(input, &[2, 2], &[2, 2], &[0, 0]);
 
     assert_eq!(max_pool.past.len(), 1);
     assert!(max_pool.past.changeables.is_empty());
@@ -1066,8 +3091,147 @@ fn max_pooling_diff() {
         crate::ones((4, 2, 6, 6)).requires_grad(),
         &[2, 2],
         &[2, 2],
+        &[0, 0],
     );
 
     assert_eq!(max_pool.past.len(), 1);
     assert_eq!(max_pool.past.parameters.len(), 1)
 }
+
+#[test]
+fn any_var() {
+    use super::AnyVar;
+    use ndarray::{Ix0, Ix1, Ix2, Ix3, Ix4, Ix5, Ix6, IxDyn};
+
+    fn round_trip<D: super::Rank>(any: AnyVar, shape: &[usize]) {
+        assert_eq!(any.shape(), shape);
+        assert_eq!(any.ndim(), shape.len());
+        assert!((any.sum_all() - shape.iter().product::<usize>() as f32).abs() < f32::EPSILON);
+
+        let var = any.downcast::<D>().unwrap();
+        assert_eq!(var.data().shape(), shape);
+        assert!(var.past.is_empty());
+    }
+
+    round_trip::<Ix0>(crate::ones(()).into(), &[]);
+    round_trip::<Ix1>(crate::ones(2).into(), &[2]);
+    round_trip::<Ix2>(crate::ones((2, 3)).into(), &[2, 3]);
+    round_trip::<Ix3>(crate::ones((2, 3, 1)).into(), &[2, 3, 1]);
+    round_trip::<Ix4>(crate::ones((2, 3, 1, 2)).into(), &[2, 3, 1, 2]);
+    round_trip::<Ix5>(crate::ones((2, 3, 1, 2, 1)).into(), &[2, 3, 1, 2, 1]);
+    round_trip::<Ix6>(crate::ones((2, 3, 1, 2, 1, 2)).into(), &[2, 3, 1, 2, 1, 2]);
+    round_trip::<IxDyn>(
+        crate::ones(vec![2, 3, 1, 2, 1, 2, 2]).into(),
+        &[2, 3, 1, 2, 1, 2, 2],
+    );
+
+    // Downcasting to the wrong dimensionality gives the variable back.
+    let any: AnyVar = crate::ones((2, 2)).into();
+    let any = any.downcast::<Ix1>().err().unwrap();
+    assert!(any.downcast::<Ix2>().is_ok());
+
+    let any: AnyVar = (crate::ones(3) * 2.).sum().into();
+    any.forward();
+    assert_eq!(any.item(), Some(6.));
+    assert_eq!(AnyVar::from(crate::ones(3)).item(), None);
+}
+
+#[test]
+fn any_var_diff() {
+    use super::AnyVarDiff;
+    use ndarray::{Ix0, Ix1, Ix2, Ix3, Ix4, Ix5, Ix6, IxDyn};
+
+    fn round_trip<D: super::Rank>(any: AnyVarDiff, shape: &[usize]) {
+        assert_eq!(any.shape(), shape);
+        assert_eq!(any.parameters().len(), 1);
+
+        let var = any.downcast::<D>().unwrap();
+        assert_eq!(var.data().shape(), shape);
+        assert!(var.past.is_empty());
+        assert_eq!(var.past.parameters.len(), 1);
+    }
+
+    round_trip::<Ix0>(crate::ones(()).requires_grad().into(), &[]);
+    round_trip::<Ix1>(crate::ones(2).requires_grad().into(), &[2]);
+    round_trip::<Ix2>(crate::ones((2, 3)).requires_grad().into(), &[2, 3]);
+    round_trip::<Ix3>(crate::ones((2, 3, 1)).requires_grad().into(), &[2, 3, 1]);
+    round_trip::<Ix4>(
+        crate::ones((2, 3, 1, 2)).requires_grad().into(),
+        &[2, 3, 1, 2],
+    );
+    round_trip::<Ix5>(
+        crate::ones((2, 3, 1, 2, 1)).requires_grad().into(),
+        &[2, 3, 1, 2, 1],
+    );
+    round_trip::<Ix6>(
+        crate::ones((2, 3, 1, 2, 1, 2)).requires_grad().into(),
+        &[2, 3, 1, 2, 1, 2],
+    );
+    round_trip::<IxDyn>(
+        crate::ones(vec![2, 3, 1, 2, 1, 2, 2])
+            .requires_grad()
+            .into(),
+        &[2, 3, 1, 2, 1, 2, 2],
+    );
+
+    let any: AnyVarDiff = crate::ones((2, 2)).requires_grad().into();
+    let any = any.downcast::<Ix3>().err().unwrap();
+    assert!(any.downcast::<Ix2>().is_ok());
+}
+
+#[test]
+fn any_var_diff_mixed() {
+    use super::AnyVarDiff;
+
+    // A shared routine that only relies on the rank-agnostic interface.
+    fn log(outputs: &[AnyVarDiff]) -> Vec<(Vec<usize>, f32)> {
+        outputs
+            .iter()
+            .map(|output| {
+                output.forward();
+                output.backward(1.);
+                (output.shape(), output.sum_all())
+            })
+            .collect()
+    }
+
+    let x = crate::ones((2, 2)).requires_grad();
+    let outputs: Vec<AnyVarDiff> = vec![
+        x.clone().sum().into(),
+        (x.clone() * 2.).into(),
+        (crate::ones(3).requires_grad() + 1.).into(),
+        (crate::ones((1, 2, 2, 1)).requires_grad() * 3.).into(),
+    ];
+
+    assert_eq!(
+        log(&outputs),
+        vec![
+            (vec![], 4.),
+            (vec![2, 2], 8.),
+            (vec![3], 6.),
+            (vec![1, 2, 2, 1], 12.),
+        ]
+    );
+    assert_eq!(outputs[0].item(), Some(4.));
+    // The gradients of the two outputs sharing `x` are accumulated.
+    assert_eq!(*x.grad(), ndarray::array![[3., 3.], [3., 3.]]);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn any_var_serialize() {
+    use super::{AnyVar, AnyVarDiff};
+
+    let any: AnyVar = crate::full((2, 1, 3), 2.).into();
+    let serialized = bincode::serialize(&any).unwrap();
+    let deserialized: AnyVar = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(deserialized.to_array(), any.to_array());
+    assert!(deserialized.downcast::<ndarray::Ix3>().is_ok());
+
+    let any: AnyVarDiff = crate::full(4, 2.).requires_grad().into();
+    let serialized = bincode::serialize(&any).unwrap();
+    let deserialized: AnyVarDiff = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(deserialized.to_array(), any.to_array());
+    assert_eq!(deserialized.parameters().len(), 1);
+    assert!(deserialized.downcast::<ndarray::Ix1>().is_ok());
+}
@@ -1,17 +1,25 @@
 use super::{
-    Addition, AdditionBackwardUnary, Cat, Changeable, Chunk, Concatenate, ConcatenateBackwardRight,
-    Data, Division, DivisionBackwardRight, Dropout, Eval, Exp, Forward, Gradient, Input,
-    InputBackward, LeakyReLU, LogSoftmax, Logn, MatMatMul, MatMatMulT, MatVecMul, MatrixMatrixMul,
+    chunk_sizes, flatten_shape, is_grad_enabled, next_operation_id, swap_permutation, Addition,
+    AdditionBackwardUnary, BatchNorm, BatchedMatMatMul, BatchedMatMul, BatchedMatMulBackwardRight,
+    Broadcasted, Cat, Changeable, Chunk, Concatenate, ConcatenateBackwardRight, Contraction,
+    CumProd, CumSum, Data, DiagEmbed, Diagonal, Division, DivisionBackwardRight, DotDim, Dropout,
+    Eval, Exp, Flip, Forward, Gather, Gradient, IndexSelect, Input, InputBackward, Kron,
+    KronBackwardRight, KroneckerProduct, LayerNorm, LeakyReLU, LogSoftmax, LogSumExp, Logn,
+    MaskedFill, MaskedSelect, MatMatMul, MatMatMulT, MatVecMul, MatrixMatrixMul,
     MatrixMatrixMulBackwardRight, MatrixMatrixMulT, MatrixMatrixMulTBackwardRight, MatrixVectorMul,
-    MatrixVectorMulBackwardRight, Mean, MultiConcatenate, MultiStack, Multiplication,
-    MultiplicationBackwardUnary, Negation, Overwrite, Power, RawParam, ReLU, Sigmoid, SoftPlus,
-    Softmax, Sqrt, Stack, StackBackwardRight, Subtraction, SubtractionBackwardRight, Sum, TanH,
-    Tensor, Transpose, Unsqueeze, VarDiff, VarDiffHistory, VarHistory, VecMatMul, VecVecMul,
-    VectorMatrixMul, VectorMatrixMulBackwardRight, VectorVectorMul, VectorVectorMulBackwardUnary,
-    OPERATIONS_COUNTER,
+    MatrixVectorMulBackwardRight, Mean, MeanAxes, Mish, MultiConcatenate, MultiStack,
+    Multiplication, MultiplicationBackwardUnary, Narrow, Negation, Norm, Outer, OuterBackwardRight,
+    OuterProduct, Overwrite, Pad, PadMode, Permute, Power, RawParam, ReLU, Repeat, Reshape, Roll,
+    ScatterAdd, ScatterAddition, ScatterAdditionBackwardRight, Select, SelectBackwardRight,
+    ShapedDisplay, SiLU, Sigmoid, SoftPlus, Softmax, Split, Sqrt, Squeeze, Stack,
+    StackBackwardRight, Subtraction, SubtractionBackwardRight, Sum, Swish, TanH, Tensor, TensorDot,
+    Trace, Transpose, Triangle, Triangular, Unsqueeze, VarDiff, VarDiffHistory, VarHistory,
+    VecMatMul, VecVecMul, VectorMatrixMul, VectorMatrixMulBackwardRight, VectorVectorMul,
+    VectorVectorMulBackwardUnary, Where, GELU,
 };
 use ndarray::{
-    concatenate, stack, Axis, DimMax, Dimension, IntoDimension, Ix0, Ix1, Ix2, RemoveAxis,
+    concatenate, stack, Array, Axis, DimMax, Dimension, IntoDimension, Ix0, Ix1, Ix2, Ix3, IxDyn,
+    RemoveAxis,
 };
 #[cfg(feature = "serialize")]
 use serde::{
@@ -19,6 +27,7 @@ use serde::{
     ser::{Serialize, Serializer},
 };
 use std::{
+    any::type_name,
     cell::{Cell, Ref, RefMut},
     collections::HashSet,
     fmt::{Debug, Display},
@@ -53,57 +62,15 @@ where
     }
 }
 
-impl<D: Dimension> Var<Input<D>> {
-    /// Promotes `self` to a differentiable variable. A subsequent call to [`.backward()`]
-    /// will compute its gradient.
-    ///
-    /// [`.backward()`]: VarDiff::backward()
-    ///
-    /// # Examples
-    ///
-    /// This is the preferred usage.
-    ///
-    ///```
-    /// use neuronika;
-    ///
-    /// let x = neuronika::ones(5).requires_grad();
-    ///```
-    ///
-    /// This is also permitted, however, one should be aware of the difference between `x_diff` and
-    /// `x`.
-    ///
-    ///```
-    /// use neuronika;
-    ///
-    /// let x = neuronika::ones(5);
-    /// let y = x.clone() + neuronika::ones(1);
-    ///
-    /// let x_diff = x.requires_grad();
-    ///```
-    pub fn requires_grad(self) -> VarDiff<Input<D>, InputBackward<D>> {
-        debug_assert!(self.past.is_empty(), "error: the variable is not a leaf.");
-        let node = Rc::new(self.node.differentiable());
-        let mut gradient = node.gradient_mut();
-        let mut parameters = HashSet::new();
-        parameters.insert(RawParam::new(
-            self.node.data_mut().as_mut_ptr(),
-            gradient.as_mut_ptr(),
-            gradient.shape().to_vec(),
-        ));
-
-        VarDiff {
-            var: self,
-            node: node.clone(),
-            past: VarDiffHistory::new(parameters),
-        }
-    }
-}
-
 impl<T: Data + Forward> Var<T> {
     /// Creates a new variable from a node.
     pub(crate) fn from(node: T, mut past: VarHistory) -> Self {
         let node = Rc::new(node);
-        past.append_forward(unsafe { OPERATIONS_COUNTER.next() }, node.clone());
+        let id = next_operation_id();
+        past.append_forward(id, node.clone());
+
+        let shape = node.data().shape().into();
+        past.append_node(id, type_name::<T>(), shape, node.clone());
 
         Var { node, past }
     }
@@ -116,13 +83,16 @@ where
     /// Creates a new variable from a changeable node.
     pub(crate) fn from_changeable(node: T, mut past: VarHistory) -> Self {
         let node = Rc::new(node);
-        let id = unsafe { OPERATIONS_COUNTER.next() };
+        let id = next_operation_id();
         past.append_forward(id, node.clone());
         past.append_changeable(Changeable {
             id,
             node: node.clone(),
         });
 
+        let shape = node.data().shape().into();
+        past.append_node(id, type_name::<T>(), shape, node.clone());
+
         Var { node, past }
     }
 }
@@ -182,6 +152,77 @@ where
         }
     }
 
+    /// Returns a new leaf variable holding a copy of the data of `self`, which is computed first.
+    ///
+    /// The result shares neither the data nor the history of `self`, so further computations on
+    /// the ancestors of `self` don't affect it.
+    pub fn detach(&self) -> Var<Input<T::Dim>> {
+        self.forward();
+
+        Input::new(self.data().clone())
+    }
+
+    /// Promotes `self` to a differentiable variable. A subsequent call to [`.backward()`]
+    /// will compute its gradient.
+    ///
+    /// `self` is computed first and the differentiable variable is a new leaf holding a copy of
+    /// its data, so that `self` can still be used in a separate graph. This is the reverse of
+    /// [`.detach()`](VarDiff::detach()).
+    ///
+    /// If the gradient tracking is disabled by [`no_grad()`](crate::no_grad()), the
+    /// differentiable variable is created frozen and is not registered as a parameter.
+    ///
+    /// [`.backward()`]: VarDiff::backward()
+    ///
+    /// # Examples
+    ///
+    /// This is the preferred usage.
+    ///
+    ///```
+    /// use neuronika;
+    ///
+    /// let x = neuronika::ones(5).requires_grad();
+    ///```
+    ///
+    /// Any variable can be promoted, its data is copied.
+    ///
+    ///```
+    /// let x = neuronika::ones(3);
+    /// let y = (x.clone() * 2.).requires_grad();
+    /// let z = (y.clone() * 3.).sum();
+    ///
+    /// z.forward();
+    /// z.backward(1.);
+    ///
+    /// assert_eq!(*y.data(), ndarray::arr1(&[2., 2., 2.]));
+    /// assert_eq!(*y.grad(), ndarray::arr1(&[3., 3., 3.]));
+    ///
+    /// // The promoted variable doesn't share the data of `x`.
+    /// x.data_mut().fill(0.);
+    /// assert_eq!(*y.data(), ndarray::arr1(&[2., 2., 2.]));
+    ///```
+    pub fn requires_grad(self) -> VarDiff<Input<T::Dim>, InputBackward<T::Dim>> {
+        let leaf = self.detach();
+        let node = Rc::new(leaf.node.differentiable());
+        let mut parameters = HashSet::new();
+        if is_grad_enabled() {
+            let mut gradient = node.gradient_mut();
+            parameters.insert(RawParam::new(
+                leaf.node.data_mut().as_mut_ptr(),
+                gradient.as_mut_ptr(),
+                gradient.shape().to_vec(),
+            ));
+        } else {
+            node.freeze();
+        }
+
+        VarDiff {
+            var: leaf,
+            node: node.clone(),
+            past: VarDiffHistory::new(parameters),
+        }
+    }
+
     /// This has effect only on certain **ancestor** variables of `self`. It sets such variables
     /// in training mode.
     ///    
@@ -238,6 +279,25 @@ where
     {
         VecVecMul::vv(self, rhs)
     }
+
+    /// Vector-vector outer product.
+    ///
+    /// If `self` is *m* and `rhs` is *n* the output will be *(m, n)*.
+    pub fn outer<Rhs>(self, rhs: Rhs) -> <Self as OuterProduct<Rhs>>::Output
+    where
+        Self: OuterProduct<Rhs>,
+    {
+        OuterProduct::outer(self, rhs)
+    }
+
+    /// Returns a square matrix variable having the vector variable `self` as its diagonal with
+    /// the given `offset` and zeros elsewhere.
+    ///
+    /// A positive `offset` places it above the main diagonal, a negative `offset` below it. If
+    /// `self` is *n* the output will be *(n + |offset|, n + |offset|)*.
+    pub fn diag_embed(self, offset: isize) -> Var<DiagEmbed<T>> {
+        Var::from(DiagEmbed::new(self.node, offset), self.past)
+    }
 }
 
 impl<T: Data<Dim = Ix2> + 'static> Var<T> {
@@ -273,14 +333,59 @@ impl<T: Data<Dim = Ix2> + 'static> Var<T> {
     {
         MatVecMul::mv(self, rhs)
     }
+
+    /// Returns a variable with the diagonal of the matrix variable `self`.
+    ///
+    /// A positive `offset` selects a diagonal above the main one, a negative `offset` one below
+    /// it. If `self` is *(n, m)* the main diagonal has length *min(n, m)*.
+    pub fn diag(self, offset: isize) -> Var<Diagonal<T>> {
+        Var::from(Diagonal::new(self.node, offset), self.past)
+    }
+
+    /// Returns the sum of the elements on the main diagonal of the matrix variable `self`.
+    pub fn trace(self) -> Var<Trace<T>> {
+        Var::from(Trace::new(self.node), self.past)
+    }
+
+    /// Computes the Kronecker product between the matrix variables `self` and `rhs`.
+    ///
+    /// If `self` is *(m, n)* and `rhs` is *(p, q)* the output will be *(m·p, n·q)*.
+    pub fn kron<Rhs>(self, rhs: Rhs) -> <Self as KroneckerProduct<Rhs>>::Output
+    where
+        Self: KroneckerProduct<Rhs>,
+    {
+        KroneckerProduct::kron(self, rhs)
+    }
+}
+
+impl<T: ?Sized> Var<T>
+where
+    T: Data + 'static,
+    T::Dim: DotDim<Ix3, Output = Ix3>,
+{
+    /// Performs a batched matrix multiplication between `self` and the three-dimensional
+    /// variable `rhs`. If `self` is *(b, n, m)* and `rhs` is *(b, m, o)* the output will be
+    /// *(b, n, o)*. If `self` is a matrix of shape *(n, m)* it is broadcast along the batch axis.
+    ///
+    /// # Panics
+    ///
+    /// If `self` and `rhs` are both three-dimensional and have a different number of batches.
+    pub fn bmm<Rhs>(self, rhs: Rhs) -> <Self as BatchedMatMatMul<Rhs>>::Output
+    where
+        Self: BatchedMatMatMul<Rhs>,
+    {
+        BatchedMatMatMul::bmm(self, rhs)
+    }
 }
 
 impl<T: Data + 'static> Var<T> {
     pub(crate) fn new(node: T) -> Self {
-        Self {
-            node: Rc::new(node),
-            past: VarHistory::new(),
-        }
+        let node = Rc::new(node);
+        let shape = node.data().shape().into();
+        let mut past = VarHistory::new();
+        past.append_node(next_operation_id(), type_name::<T>(), shape, node.clone());
+
+        Self { node, past }
     }
 }
 
@@ -304,6 +409,20 @@ where
         self.node.data_mut()
     }
 
+    /// Returns the shape of the data inside `self`.
+    ///
+    /// The shape is read from the data, so it reflects any replacement made through
+    /// [`.data_mut()`](Var::data_mut()).
+    pub fn shape(&self) -> Vec<usize> {
+        self.data().shape().to_vec()
+    }
+
+    /// Returns a short description of `self` holding its shape and whether its data was computed,
+    /// e.g. `Tensor[f32; shape=[3, 4], computed=true]`.
+    pub fn shape_info(&self) -> String {
+        ShapedDisplay::new(&self.shape(), self.node.was_computed()).to_string()
+    }
+
     /// Returns the sum of all elements in `self`.
     pub fn sum(self) -> Var<Sum<T>> {
         Var::from(Sum::new(self.node), self.past)
@@ -314,6 +433,44 @@ where
         Var::from(Mean::new(self.node), self.past)
     }
 
+    /// Returns the mean of the elements of `self` along `axes`. The reduced axes are kept with
+    /// length one, so that the result can be broadcast against `self`.
+    pub fn mean_axes(self, axes: &[usize]) -> Var<MeanAxes<T>> {
+        Var::from(MeanAxes::new(self.node, axes), self.past)
+    }
+
+    /// Returns a variable with the upper triangle of the matrices held by `self` and zeros
+    /// elsewhere. The matrices span the last two axes, so batches of matrices are supported.
+    ///
+    /// The elements on and above the diagonal of index `offset` are kept. A positive `offset`
+    /// selects a diagonal above the main one, a negative `offset` one below it.
+    ///
+    /// # Panics
+    ///
+    /// If `self` has less than two axes.
+    pub fn triu(self, offset: isize) -> Var<Triangular<T>> {
+        Var::from(
+            Triangular::new(self.node, Triangle::Upper, offset),
+            self.past,
+        )
+    }
+
+    /// Returns a variable with the lower triangle of the matrices held by `self` and zeros
+    /// elsewhere. The matrices span the last two axes, so batches of matrices are supported.
+    ///
+    /// The elements on and below the diagonal of index `offset` are kept. A positive `offset`
+    /// selects a diagonal above the main one, a negative `offset` one below it.
+    ///
+    /// # Panics
+    ///
+    /// If `self` has less than two axes.
+    pub fn tril(self, offset: isize) -> Var<Triangular<T>> {
+        Var::from(
+            Triangular::new(self.node, Triangle::Lower, offset),
+            self.past,
+        )
+    }
+
     /// Takes the power of each element in `self` with exponent `exp` and returns a variable with the
     /// result.
     pub fn pow(self, exp: i32) -> Var<Power<T>> {
@@ -343,9 +500,22 @@ where
 
     /// Applies the *softplus* element-wise and returns a variable with the result.
     ///
-    /// *Softplus(x) = log(1 + exp(x))*
-    pub fn softplus(self) -> Var<SoftPlus<T>> {
-        Var::from(SoftPlus::new(self.node), self.past)
+    /// *Softplus(x) = log(1 + exp(beta * x)) / beta*
+    ///
+    /// For numerical stability the function reverts to the identity when *beta * x* is above
+    /// `threshold`. Common values are `beta = 1.0` and `threshold = 20.0`.
+    pub fn softplus(self, beta: f32, threshold: f32) -> Var<SoftPlus<T>> {
+        Var::from(SoftPlus::new(self.node, beta, threshold), self.past)
+    }
+
+    /// Applies the *Gaussian error linear unit* element-wise and returns a variable with the
+    /// result.
+    ///
+    /// The tanh approximation is used.
+    ///
+    /// *GELU(x) = 0.5 * x * (1 + tanh(√(2 / π) * (x + 0.044715 * x³)))*
+    pub fn gelu(self) -> Var<GELU<T>> {
+        Var::from(GELU::new(self.node), self.past)
     }
 
     /// Applies the *sigmoid* element-wise and returns a variable with the result.
@@ -353,6 +523,27 @@ where
         Var::from(Sigmoid::new(self.node), self.past)
     }
 
+    /// Applies the *swish* element-wise and returns a variable with the result.
+    ///
+    /// *Swish(x) = x * sigmoid(x)*
+    pub fn swish(self) -> Var<Swish<T>> {
+        Var::from(Swish::new(self.node), self.past)
+    }
+
+    /// Applies the *sigmoid linear unit* element-wise and returns a variable with the result.
+    ///
+    /// This is the same as [`.swish()`](Var::swish()).
+    pub fn silu(self) -> Var<SiLU<T>> {
+        self.swish()
+    }
+
+    /// Applies the *mish* element-wise and returns a variable with the result.
+    ///
+    /// *Mish(x) = x * tanh(softplus(x))*
+    pub fn mish(self) -> Var<Mish<T>> {
+        Var::from(Mish::new(self.node), self.past)
+    }
+
     /// Applies the *tanh* element-wise and returns a variable with the result.
     pub fn tanh(self) -> Var<TanH<T>> {
         Var::from(TanH::new(self.node), self.past)
@@ -390,6 +581,57 @@ where
         Var::from(LogSoftmax::new(self.node, axis), self.past)
     }
 
+    /// Computes the logarithm of the sum of the exponentials of `self` along `axis`, subtracting
+    /// the maximum of each lane first so that the exponentials never overflow.
+    ///
+    /// The reduced axis is kept with length one, so that the result can be broadcasted against
+    /// `self`.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is out of bounds.
+    pub fn log_sum_exp(self, axis: usize) -> Var<LogSumExp<T>> {
+        Var::from(LogSumExp::new(self.node, axis), self.past)
+    }
+
+    /// Computes the *Lp norm* of `self` along `axes` and returns a variable with the result.
+    ///
+    /// The reduced axes are kept with length one, so that the result can be broadcasted against
+    /// `self`. The *L1* and the *L2* norms, i.e. `p` equal to 1 and to 2, are computed through
+    /// specialized fast paths.
+    pub fn norm(self, p: f32, axes: &[usize]) -> Var<Norm<T>> {
+        self.clamped_norm(p, axes, 0.)
+    }
+
+    /// Computes the *Lp norm* of `self` along `axes` clamping it to be at least `eps`.
+    pub(crate) fn clamped_norm(self, p: f32, axes: &[usize], eps: f32) -> Var<Norm<T>> {
+        Var::from(Norm::new(self.node, p, axes, eps), self.past)
+    }
+
+    /// Divides `self` by its *Lp norm* computed along `axes` and returns a variable with the
+    /// result.
+    ///
+    /// The norm is clamped to be at least `eps` in order to avoid divisions by zero.
+    ///
+    /// See also [`.norm()`].
+    ///
+    /// [`.norm()`]: Var::norm()
+    pub fn normalize(self, p: f32, axes: &[usize], eps: f32) -> Var<Division<T, Norm<T>>> {
+        self.clone() / self.clamped_norm(p, axes, eps)
+    }
+
+    /// Computes the *cumulative sum* of the elements of `self` along `axis` and returns a
+    /// variable with the result.
+    pub fn cumsum(self, axis: usize) -> Var<CumSum<T>> {
+        Var::from(CumSum::new(self.node, axis), self.past)
+    }
+
+    /// Computes the *cumulative product* of the elements of `self` along `axis` and returns a
+    /// variable with the result.
+    pub fn cumprod(self, axis: usize) -> Var<CumProd<T>> {
+        Var::from(CumProd::new(self.node, axis), self.past)
+    }
+
     /// Returns a variable equivalent to `self` with its dimensions reversed.
     pub fn t(self) -> Var<Transpose<T>> {
         Var::from(Transpose::new(self.node), self.past)
@@ -422,6 +664,38 @@ where
         Var::from_changeable(Dropout::new(self.node, p, status), self.past)
     }
 
+    /// Creates a new batch normalization variable, updating the running statistics in training
+    /// mode. This method is used in the `BatchNorm` component of the `nn` module.
+    pub(crate) fn batch_norm_with_status(
+        self,
+        running_mean: &Var<Input<Ix1>>,
+        running_var: &Var<Input<Ix1>>,
+        momentum: f32,
+        eps: f32,
+        status: Rc<Cell<bool>>,
+    ) -> Var<BatchNorm<T>>
+    where
+        T::Dim: RemoveAxis,
+    {
+        let node = BatchNorm::new(
+            self.node,
+            running_mean.node.clone(),
+            running_var.node.clone(),
+            momentum,
+            eps,
+            status,
+        );
+
+        Var::from_changeable(node, self.past)
+    }
+
+    /// Creates a new layer normalization variable, normalizing `self` over its last
+    /// `normalized_ndim` axes. This method is used in the `LayerNorm` component of the `nn`
+    /// module.
+    pub(crate) fn layer_norm(self, normalized_ndim: usize, eps: f32) -> Var<LayerNorm<T>> {
+        Var::from(LayerNorm::new(self.node, normalized_ndim, eps), self.past)
+    }
+
     /// Splits `self` into a certain number of chunks of size `chunk_size` **skipping** the
     /// remainder along each dimension that doesn’t fit evenly.
     pub fn chunks<E: IntoDimension<Dim = T::Dim>>(self, chunk_size: E) -> Vec<Var<Chunk<T>>> {
@@ -439,11 +713,286 @@ where
             .collect()
     }
 
+    /// Splits `self` along `axis` into consecutive pieces of length `sizes`.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is out of bounds or if `sizes` don't add up to its length.
+    pub fn split(self, sizes: &[usize], axis: usize) -> Vec<Var<Split<T>>> {
+        (0..sizes.len())
+            .map(|i| {
+                Var::from(
+                    Split::new(self.node.clone(), axis, sizes, i),
+                    self.past.clone(),
+                )
+            })
+            .collect()
+    }
+
+    /// Splits `self` along `axis` into `chunks` consecutive pieces as even as possible. When the
+    /// length of `axis` isn't divisible by `chunks`, the first pieces are one element longer.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is out of bounds or if `chunks` is zero.
+    pub fn chunk(self, chunks: usize, axis: usize) -> Vec<Var<Split<T>>> {
+        let sizes = chunk_sizes(self.node.data().shape(), axis, chunks);
+        self.split(&sizes, axis)
+    }
+
     /// Returns a new variable with a dimension of size one inserted at the position specified by
     /// `axis`.
     pub fn unsqueeze(self, axis: usize) -> Var<Unsqueeze<T>> {
         Var::from(Unsqueeze::new(self.node, axis), self.past)
     }
+
+    /// Returns a variable with the `len` elements of `self` along `axis` starting from `start`.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is out of bounds or if the range doesn't fit in it.
+    pub fn narrow(self, axis: usize, start: usize, len: usize) -> Var<Narrow<T>> {
+        Var::from(Narrow::new(self.node, axis, start, len), self.past)
+    }
+
+    /// Returns a variable with `self` padded by `padding`, which holds the number of elements added
+    /// before and after each axis. The border is filled according to `mode`.
+    ///
+    /// # Panics
+    ///
+    /// If `padding` doesn't have an entry for each axis of `self`, or if a reflective padding is
+    /// not smaller than the axis it's applied to.
+    pub fn pad(self, padding: &[(usize, usize)], mode: PadMode) -> Var<Pad<T>> {
+        Var::from(Pad::new(self.node, padding, mode), self.past)
+    }
+
+    /// Returns a variable with the elements of `self` in reverse order along `axes`.
+    ///
+    /// # Panics
+    ///
+    /// If any of `axes` is out of bounds or repeated.
+    pub fn flip(self, axes: &[usize]) -> Var<Flip<T>> {
+        Var::from(Flip::new(self.node, axes), self.past)
+    }
+
+    /// Returns a variable with the elements of `self` circularly shifted by `shift` positions
+    /// along `axis`. Elements shifted beyond the last position are re-introduced at the first one.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is out of bounds.
+    pub fn roll(self, shift: isize, axis: usize) -> Var<Roll<T>> {
+        Var::from(Roll::new(self.node, shift, axis), self.past)
+    }
+
+    /// Returns a variable made of `n` copies of `self` laid one after the other along `axis`.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is out of bounds.
+    pub fn repeat(self, n: usize, axis: usize) -> Var<Repeat<T>> {
+        Var::from(Repeat::new(self.node, n, axis), self.past)
+    }
+
+    /// Returns a variable with the sub-tensors of `self` at `indices` along `axis`, in the same
+    /// order as `indices`. Indices can be repeated.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is out of bounds or if any of `indices` is out of bounds for it.
+    pub fn index_select(self, axis: usize, indices: Vec<usize>) -> Var<IndexSelect<T>> {
+        Var::from(IndexSelect::new(self.node, axis, indices), self.past)
+    }
+
+    /// Returns a variable with the elements of `self` gathered along `axis` at the positions
+    /// specified by `index`.
+    ///
+    /// The result has the same shape as `index`, with each of its elements taken from the position
+    /// of `self` obtained by replacing the coordinate along `axis` with the corresponding element
+    /// of `index`. For a two-dimensional variable and `axis` equal to one, this is
+    /// `result[i][j] = self[i][index[i][j]]`.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is out of bounds, if `index` is larger than `self` along any other axis or if any
+    /// of its elements is out of bounds for `axis`.
+    pub fn gather(self, axis: usize, index: Array<usize, T::Dim>) -> Var<Gather<T>> {
+        Var::from(Gather::new(self.node, axis, index), self.past)
+    }
+
+    /// Returns a variable with the elements of `src` added to the ones of `self` along
+    /// `axis`, at the positions specified by `index`.
+    ///
+    /// This is the counterpart of `.gather()`: each element of `src` is added to the position of
+    /// `self` obtained by replacing its coordinate along `axis` with the corresponding element of
+    /// `index`, which must have the same shape as `src`. Elements scattered to the same position
+    /// add up. For a two-dimensional variable and `axis` equal to one, this is
+    /// `result[i][index[i][j]] += src[i][j]`.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is out of bounds, if `index` and `src` have different shapes, if `index` is
+    /// larger than `self` along any other axis or if any of its elements is out of bounds for
+    /// `axis`.
+    pub fn scatter_add<Rhs>(
+        self,
+        axis: usize,
+        index: Array<usize, T::Dim>,
+        src: Rhs,
+    ) -> <Self as ScatterAdd<Rhs>>::Output
+    where
+        Self: ScatterAdd<Rhs, Dim = T::Dim>,
+    {
+        ScatterAdd::scatter_add(self, axis, index, src)
+    }
+
+    /// Returns a variable equal to `self` except at the positions where `mask` is nonzero, which
+    /// are set to `value`.
+    ///
+    /// The mask, usually made of zeros and ones, must be broadcastable to the shape of `self`. It's
+    /// read every time the result is evaluated, so it may be the result of other operations.
+    ///
+    /// # Panics
+    ///
+    /// If `mask` cannot be broadcast to the shape of `self`.
+    pub fn masked_fill<M: ?Sized>(mut self, mask: Var<M>, value: f32) -> Var<MaskedFill<T, M>>
+    where
+        M: Data + 'static,
+    {
+        self.past.merge(mask.past);
+        Var::from(MaskedFill::new(self.node, mask.node, value), self.past)
+    }
+
+    /// Returns a one-dimensional variable with the elements of `self` at the positions where
+    /// `mask` is nonzero, in row-major order.
+    ///
+    /// The mask must be broadcastable to the shape of `self`. The length of the result is the
+    /// number of elements selected by `mask` when this method is called, the mask may change
+    /// afterwards as long as it selects the same number of elements.
+    ///
+    /// # Panics
+    ///
+    /// If `mask` cannot be broadcast to the shape of `self`.
+    pub fn masked_select<M: ?Sized>(mut self, mask: Var<M>) -> Var<MaskedSelect<T, M>>
+    where
+        M: Data + 'static,
+    {
+        self.past.merge(mask.past);
+        Var::from(MaskedSelect::new(self.node, mask.node), self.past)
+    }
+
+    /// Returns a new variable with the dimension of size one at the position specified by `axis`
+    /// removed.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is out of bounds or if its length is not one.
+    pub fn squeeze(self, axis: usize) -> Var<Squeeze<T>>
+    where
+        T::Dim: RemoveAxis,
+    {
+        Var::from(Squeeze::new(self.node, axis), self.past)
+    }
+
+    /// Returns a variable with the axes of `self` permuted according to `axes`.
+    ///
+    /// The *i*-th axis of the result is the axis `axes[i]` of `self`.
+    ///
+    /// # Panics
+    ///
+    /// If `axes` is not a permutation of the axes of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "blas")]
+    /// # extern crate blas_src;
+    /// let x = neuronika::zeros((2, 3, 4, 5)).permute([0, 2, 1, 3]);
+    ///
+    /// assert_eq!(x.data().shape(), &[2, 4, 3, 5]);
+    /// ```
+    pub fn permute<Ax: IntoDimension<Dim = T::Dim>>(self, axes: Ax) -> Var<Permute<T>> {
+        Var::from(Permute::new(self.node, axes.into_dimension()), self.past)
+    }
+
+    /// Returns a variable with the axes `a` and `b` of `self` swapped.
+    ///
+    /// # Panics
+    ///
+    /// If either `a` or `b` is out of bounds.
+    pub fn swap_axes(self, a: usize, b: usize) -> Var<Permute<T>> {
+        let axes: T::Dim = swap_permutation(self.data().ndim(), a, b);
+        self.permute(axes)
+    }
+
+    /// Returns a variable with the elements of `self` arranged in the given `shape`.
+    ///
+    /// The elements are read and placed in row-major order. At most one entry of `shape` can be
+    /// `usize::MAX`, in which case its length is inferred from the number of elements of `self`.
+    ///
+    /// # Panics
+    ///
+    /// If the number of elements of `self` doesn't match the one of `shape` or if more than one
+    /// entry of `shape` is to be inferred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "blas")]
+    /// # extern crate blas_src;
+    /// let x = neuronika::range(0., 6., 1.).reshape((usize::MAX, 2));
+    /// x.forward();
+    ///
+    /// assert_eq!(*x.data(), ndarray::array![[0., 1.], [2., 3.], [4., 5.]]);
+    /// ```
+    pub fn reshape<Sh: IntoDimension>(self, shape: Sh) -> Var<Reshape<T, Sh::Dim>> {
+        Var::from(Reshape::new(self.node, shape.into_dimension()), self.past)
+    }
+
+    /// Returns a matrix variable with the first axis of `self` kept and all the others collapsed
+    /// into a single one.
+    ///
+    /// If `self` is *(n, c, h, w)* the output will be *(n, c·h·w)*.
+    ///
+    /// # Panics
+    ///
+    /// If `self` is zero-dimensional.
+    pub fn flatten(self) -> Var<Reshape<T, Ix2>> {
+        let shape = self.data().shape().to_vec();
+        assert!(
+            !shape.is_empty(),
+            "error: cannot flatten a zero-dimensional variable."
+        );
+        let shape = Ix2(shape[0], shape[1..].iter().product());
+
+        self.reshape(shape)
+    }
+
+    /// Returns a variable with the axes of `self` from `start_axis` to `end_axis`, both included,
+    /// collapsed into a single one.
+    ///
+    /// # Panics
+    ///
+    /// If `start_axis` is greater than `end_axis` or if `end_axis` is out of bounds.
+    pub fn flatten_range(self, start_axis: usize, end_axis: usize) -> Var<Reshape<T, IxDyn>> {
+        let shape = flatten_shape(self.data().shape(), start_axis, end_axis);
+        self.reshape(shape)
+    }
+
+    /// Contracts `self` and `rhs` over the axes `lhs_axes` of `self` and `rhs_axes` of `rhs`.
+    ///
+    /// See [`TensorDot`] for more details.
+    pub fn tensordot<Rhs>(
+        self,
+        rhs: Rhs,
+        lhs_axes: &[usize],
+        rhs_axes: &[usize],
+    ) -> <Self as TensorDot<Rhs>>::Output
+    where
+        Self: TensorDot<Rhs>,
+    {
+        TensorDot::tensordot(self, rhs, lhs_axes, rhs_axes)
+    }
 }
 
 impl<D> Var<dyn Data<Dim = D>>
@@ -953,7 +1502,146 @@ where
     }
 }
 
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Outer ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<F1: ?Sized, F2: ?Sized> OuterProduct<Var<F2>> for Var<F1>
+where
+    F1: Data<Dim = Ix1> + 'static,
+    F2: Data<Dim = Ix1> + 'static,
+{
+    type Output = Var<Outer<F1, F2>>;
+
+    fn outer(mut self, rhs: Var<F2>) -> Self::Output {
+        self.past.merge(rhs.past);
+        Var::from(Outer::new(self.node, rhs.node), self.past)
+    }
+}
+
+impl<F1: ?Sized, F2: ?Sized, B2: ?Sized> OuterProduct<VarDiff<F2, B2>> for Var<F1>
+where
+    F1: Data<Dim = Ix1> + 'static,
+    F2: Data<Dim = Ix1> + 'static,
+    B2: Gradient<Dim = Ix1> + Overwrite + 'static,
+{
+    type Output = VarDiff<Outer<F1, F2>, OuterBackwardRight<F1, B2>>;
+
+    fn outer(self, rhs: VarDiff<F2, B2>) -> Self::Output {
+        let node = OuterBackwardRight::new(self.node.clone(), rhs.node);
+        VarDiff::from(node, rhs.past, self.outer(rhs.var))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Batched Multiplication ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<F1: ?Sized, F2: ?Sized> BatchedMatMatMul<Var<F2>> for Var<F1>
+where
+    F1: Data + 'static,
+    F1::Dim: DotDim<Ix3, Output = Ix3>,
+    F2: Data<Dim = Ix3> + 'static,
+{
+    type Output = Var<BatchedMatMul<F1, F2>>;
+
+    fn bmm(mut self, rhs: Var<F2>) -> Self::Output {
+        self.past.merge(rhs.past);
+        Var::from(BatchedMatMul::new(self.node, rhs.node), self.past)
+    }
+}
+
+impl<F1: ?Sized, F2: ?Sized, B2: ?Sized> BatchedMatMatMul<VarDiff<F2, B2>> for Var<F1>
+where
+    F1: Data + 'static,
+    F1::Dim: DotDim<Ix3, Output = Ix3>,
+    F2: Data<Dim = Ix3> + 'static,
+    B2: Gradient<Dim = Ix3> + Overwrite + 'static,
+{
+    type Output = VarDiff<BatchedMatMul<F1, F2>, BatchedMatMulBackwardRight<F1, B2>>;
+
+    fn bmm(self, rhs: VarDiff<F2, B2>) -> Self::Output {
+        let node = BatchedMatMulBackwardRight::new(self.node.clone(), rhs.node);
+        VarDiff::from(node, rhs.past, self.bmm(rhs.var))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Kron ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<F1: ?Sized, F2: ?Sized> KroneckerProduct<Var<F2>> for Var<F1>
+where
+    F1: Data<Dim = Ix2> + 'static,
+    F2: Data<Dim = Ix2> + 'static,
+{
+    type Output = Var<Kron<F1, F2>>;
+
+    fn kron(mut self, rhs: Var<F2>) -> Self::Output {
+        self.past.merge(rhs.past);
+        Var::from(Kron::new(self.node, rhs.node), self.past)
+    }
+}
+
+impl<F1: ?Sized, F2: ?Sized, B2: ?Sized> KroneckerProduct<VarDiff<F2, B2>> for Var<F1>
+where
+    F1: Data<Dim = Ix2> + 'static,
+    F2: Data<Dim = Ix2> + 'static,
+    B2: Gradient<Dim = Ix2> + Overwrite + 'static,
+{
+    type Output = VarDiff<Kron<F1, F2>, KronBackwardRight<F1, B2>>;
+
+    fn kron(self, rhs: VarDiff<F2, B2>) -> Self::Output {
+        let node = KronBackwardRight::new(self.node.clone(), rhs.node);
+        VarDiff::from(node, rhs.past, self.kron(rhs.var))
+    }
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tensordot ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<F1: ?Sized, F2: ?Sized> TensorDot<Var<F2>> for Var<F1>
+where
+    F1: Data + 'static,
+    F2: Data + 'static,
+{
+    type Output = Var<dyn Data<Dim = IxDyn>>;
+
+    fn tensordot(self, rhs: Var<F2>, lhs_axes: &[usize], rhs_axes: &[usize]) -> Self::Output {
+        let contraction =
+            Contraction::new(self.data().shape(), rhs.data().shape(), lhs_axes, rhs_axes);
+        let lhs = self
+            .permute(contraction.lhs_permutation::<F1::Dim>())
+            .reshape(contraction.lhs_shape);
+        let rhs = rhs
+            .permute(contraction.rhs_permutation::<F2::Dim>())
+            .reshape(contraction.rhs_shape);
+
+        lhs.mm(rhs).reshape(contraction.shape).into_dyn()
+    }
+}
+
+impl<F1: ?Sized, F2: ?Sized, B2: ?Sized> TensorDot<VarDiff<F2, B2>> for Var<F1>
+where
+    F1: Data + 'static,
+    F2: Data + 'static,
+    B2: Gradient<Dim = F2::Dim> + Overwrite + 'static,
+{
+    type Output = VarDiff<dyn Data<Dim = IxDyn>, dyn Gradient<Dim = IxDyn>>;
+
+    fn tensordot(
+        self,
+        rhs: VarDiff<F2, B2>,
+        lhs_axes: &[usize],
+        rhs_axes: &[usize],
+    ) -> Self::Output {
+        let contraction =
+            Contraction::new(self.data().shape(), rhs.data().shape(), lhs_axes, rhs_axes);
+        let lhs = self
+            .permute(contraction.lhs_permutation::<F1::Dim>())
+            .reshape(contraction.lhs_shape);
+        let rhs = rhs
+            .permute(contraction.rhs_permutation::<F2::Dim>())
+            .reshape(contraction.rhs_shape);
+
+        lhs.mm(rhs).reshape(contraction.shape).into_dyn()
+    }
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Cat and Stack traits implementations ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
@@ -1024,6 +1712,98 @@ where
     }
 }
 
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ ScatterAdd trait implementations ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<F1: ?Sized, F2: ?Sized> ScatterAdd<Var<F2>> for Var<F1>
+where
+    F1: Data + 'static,
+    F2: Data<Dim = F1::Dim> + 'static,
+{
+    type Output = Var<ScatterAddition<F1, F2>>;
+    type Dim = F1::Dim;
+
+    fn scatter_add(
+        mut self,
+        axis: usize,
+        index: Array<usize, F1::Dim>,
+        src: Var<F2>,
+    ) -> Self::Output {
+        self.past.merge(src.past);
+        Var::from(
+            ScatterAddition::new(self.node, src.node, axis, index),
+            self.past,
+        )
+    }
+}
+
+impl<F1: ?Sized, F2: ?Sized, B2: ?Sized> ScatterAdd<VarDiff<F2, B2>> for Var<F1>
+where
+    F1: Data + 'static,
+    F2: Data<Dim = F1::Dim> + 'static,
+    B2: Gradient<Dim = F1::Dim> + Overwrite + 'static,
+{
+    type Output = VarDiff<ScatterAddition<F1, F2>, ScatterAdditionBackwardRight<B2>>;
+    type Dim = F1::Dim;
+
+    fn scatter_add(
+        self,
+        axis: usize,
+        index: Array<usize, F1::Dim>,
+        src: VarDiff<F2, B2>,
+    ) -> Self::Output {
+        let node =
+            ScatterAdditionBackwardRight::new(self.node.clone(), src.node, axis, index.clone());
+        VarDiff::from(
+            node,
+            src.past,
+            ScatterAdd::scatter_add(self, axis, index, src.var),
+        )
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Where trait implementations ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<C: ?Sized, F1: ?Sized, F2: ?Sized> Where<Var<C>, Var<F2>> for Var<F1>
+where
+    C: Data + 'static,
+    F1: Data + 'static,
+    F2: Data + 'static,
+    C::Dim: DimMax<F1::Dim>,
+    Broadcasted<C::Dim, F1::Dim>: DimMax<F2::Dim>,
+{
+    type Output = Var<Select<C, F1, F2>>;
+
+    fn where_(mut self, condition: Var<C>, other: Var<F2>) -> Self::Output {
+        self.past.merge(condition.past);
+        self.past.merge(other.past);
+        Var::from(
+            Select::new(condition.node, self.node, other.node),
+            self.past,
+        )
+    }
+}
+
+impl<C: ?Sized, F1: ?Sized, F2: ?Sized, B2: ?Sized> Where<Var<C>, VarDiff<F2, B2>> for Var<F1>
+where
+    C: Data + 'static,
+    F1: Data + 'static,
+    F2: Data + 'static,
+    B2: Gradient + Overwrite + 'static,
+    C::Dim: DimMax<F1::Dim>,
+    Broadcasted<C::Dim, F1::Dim>: DimMax<F2::Dim> + DimMax<B2::Dim>,
+{
+    type Output = VarDiff<Select<C, F1, F2>, SelectBackwardRight<C, F1, B2>>;
+
+    fn where_(self, condition: Var<C>, other: VarDiff<F2, B2>) -> Self::Output {
+        let node = SelectBackwardRight::new(condition.node.clone(), self.node.clone(), other.node);
+        VarDiff::from(node, other.past, self.where_(condition, other.var))
+    }
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Debug ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 impl<T: ?Sized> Debug for Var<T>
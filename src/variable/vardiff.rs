@@ -1,24 +1,36 @@
 use super::{
-    Addition, AdditionBackward, AdditionBackwardUnary, Backward, Cat, Chunk, ChunkBackward,
-    Concatenate, ConcatenateBackward, ConcatenateBackwardLeft, Data, Division, DivisionBackward,
-    DivisionBackwardLeft, DivisionBackwardRight, Dropout, DropoutBackward, Exp, ExpBackward,
-    Forward, Gradient, Input, LeakyReLU, LeakyReLUBackward, LogSoftmax, LogSoftmaxBackward, Logn,
-    LognBackward, MatMatMul, MatMatMulT, MatVecMul, MatrixMatrixMul, MatrixMatrixMulBackward,
-    MatrixMatrixMulBackwardLeft, MatrixMatrixMulT, MatrixMatrixMulTBackward,
-    MatrixMatrixMulTBackwardLeft, MatrixVectorMul, MatrixVectorMulBackward,
-    MatrixVectorMulBackwardLeft, Mean, MeanBackward, MultiConcatenate, MultiConcatenateBackward,
-    MultiStack, MultiStackBackward, Multiplication, MultiplicationBackward,
-    MultiplicationBackwardUnary, Negation, NegationBackward, Overwrite, Param, Power,
-    PowerBackward, RawParam, ReLU, ReLUBackward, Sigmoid, SigmoidBackward, SoftPlus,
-    SoftPlusBackward, Softmax, SoftmaxBackward, Sqrt, SqrtBackward, Stack, StackBackward,
-    StackBackwardLeft, Subtraction, SubtractionBackward, SubtractionBackwardLeft,
-    SubtractionBackwardRight, Sum, SumBackward, TanH, TanHBackward, Tensor, Transpose,
-    TransposeBackward, Unsqueeze, UnsqueezeBackward, Var, VarDiffHistory, VecMatMul, VecVecMul,
-    VectorMatrixMul, VectorMatrixMulBackward, VectorMatrixMulBackwardLeft, VectorVectorMul,
-    VectorVectorMulBackward, VectorVectorMulBackwardUnary, OPERATIONS_COUNTER,
+    chunk_sizes, flatten_shape, next_operation_id, swap_permutation, Addition, AdditionBackward,
+    AdditionBackwardUnary, Backward, BatchNorm, BatchNormBackward, BatchedMatMatMul, BatchedMatMul,
+    BatchedMatMulBackward, BatchedMatMulBackwardLeft, Broadcasted, Cat, Chunk, ChunkBackward,
+    Concatenate, ConcatenateBackward, ConcatenateBackwardLeft, Contraction, CumProd,
+    CumProdBackward, CumSum, CumSumBackward, Data, DiagEmbed, DiagEmbedBackward, Diagonal,
+    DiagonalBackward, Division, DivisionBackward, DivisionBackwardLeft, DivisionBackwardRight,
+    DotDim, Dropout, DropoutBackward, Exp, ExpBackward, Flip, FlipBackward, Forward, GELUBackward,
+    Gather, GatherBackward, Gradient, IndexSelect, IndexSelectBackward, Input, InputBackward, Kron,
+    KronBackward, KronBackwardLeft, KroneckerProduct, LayerNorm, LayerNormBackward, LeakyReLU,
+    LeakyReLUBackward, LogSoftmax, LogSoftmaxBackward, LogSumExp, LogSumExpBackward, Logn,
+    LognBackward, MaskedFill, MaskedFillBackward, MaskedSelect, MaskedSelectBackward, MatMatMul,
+    MatMatMulT, MatVecMul, MatrixMatrixMul, MatrixMatrixMulBackward, MatrixMatrixMulBackwardLeft,
+    MatrixMatrixMulT, MatrixMatrixMulTBackward, MatrixMatrixMulTBackwardLeft, MatrixVectorMul,
+    MatrixVectorMulBackward, MatrixVectorMulBackwardLeft, Mean, MeanAxes, MeanAxesBackward,
+    MeanBackward, Mish, MishBackward, MultiConcatenate, MultiConcatenateBackward, MultiStack,
+    MultiStackBackward, Multiplication, MultiplicationBackward, MultiplicationBackwardUnary,
+    Narrow, NarrowBackward, Negation, NegationBackward, Norm, NormBackward, Outer, OuterBackward,
+    OuterBackwardLeft, OuterProduct, Overwrite, Pad, PadBackward, PadMode, Param, Permute,
+    PermuteBackward, Power, PowerBackward, RawParam, ReLU, ReLUBackward, Repeat, RepeatBackward,
+    Reshape, ReshapeBackward, Roll, RollBackward, ScatterAdd, ScatterAddition,
+    ScatterAdditionBackward, ScatterAdditionBackwardLeft, Select, SelectBackward,
+    SelectBackwardLeft, SiLU, SiLUBackward, Sigmoid, SigmoidBackward, SoftPlus, SoftPlusBackward,
+    Softmax, SoftmaxBackward, Split, SplitBackward, Sqrt, SqrtBackward, Squeeze, SqueezeBackward,
+    Stack, StackBackward, StackBackwardLeft, Subtraction, SubtractionBackward,
+    SubtractionBackwardLeft, SubtractionBackwardRight, Sum, SumBackward, Swish, SwishBackward,
+    TanH, TanHBackward, Tensor, TensorDot, Trace, TraceBackward, Transpose, TransposeBackward,
+    Triangle, Triangular, TriangularBackward, Unsqueeze, UnsqueezeBackward, Var, VarDiffHistory,
+    VecMatMul, VecVecMul, VectorMatrixMul, VectorMatrixMulBackward, VectorMatrixMulBackwardLeft,
+    VectorVectorMul, VectorVectorMulBackward, VectorVectorMulBackwardUnary, Where, GELU,
 };
 use crate::nn::Register;
-use ndarray::{DimMax, Dimension, IntoDimension, Ix0, Ix1, Ix2, RemoveAxis};
+use ndarray::{Array, DimMax, Dimension, IntoDimension, Ix0, Ix1, Ix2, Ix3, IxDyn, RemoveAxis};
 #[cfg(feature = "serialize")]
 use serde::{
     de::{Deserialize, Deserializer},
@@ -74,7 +86,7 @@ where
 {
     pub(crate) fn from(node: U, mut past: VarDiffHistory, var: Var<T>) -> VarDiff<T, U> {
         let node = Rc::new(node);
-        past.append_backward(unsafe { OPERATIONS_COUNTER.next() }, node.clone());
+        past.append_backward(next_operation_id(), node.clone());
 
         VarDiff { var, node, past }
     }
@@ -98,6 +110,53 @@ where
     }
 }
 
+impl<D: Dimension> VarDiff<Input<D>, InputBackward<D>> {
+    /// Freezes `self`, so that it stops accumulating gradients.
+    ///
+    /// The gradient of a frozen differentiable leaf is zeroed and stays so across any subsequent
+    /// call to [`.backward()`](VarDiff::backward()), while the rest of the graph is differentiated
+    /// as usual.
+    ///
+    /// Optimizers are not aware of freezing: they keep updating a frozen leaf whenever its update
+    /// doesn't depend on the gradient alone, as it happens with a penalty or a momentum. Frozen
+    /// leaves must thus be left out of the parameters given to the optimizer, and can be added
+    /// back once unfrozen, for instance with
+    /// [`SGD::add_param_group`](crate::optim::SGD::add_param_group).
+    ///
+    /// ```
+    /// use neuronika::optim::{SGD, L2};
+    ///
+    /// let w = neuronika::ones(3).requires_grad();
+    /// let b = neuronika::ones(3).requires_grad();
+    /// let y = (w.clone() * 2. + b.clone()).sum();
+    ///
+    /// w.freeze();
+    /// // Only b is optimized.
+    /// let optim = SGD::new(b.parameters(), 0.1, L2::new(0.1));
+    /// y.forward();
+    /// y.backward(1.);
+    ///
+    /// assert_eq!(*w.grad(), ndarray::arr1(&[0., 0., 0.]));
+    /// assert_eq!(*b.grad(), ndarray::arr1(&[1., 1., 1.]));
+    ///
+    /// optim.step();
+    /// assert_eq!(*w.data(), ndarray::arr1(&[1., 1., 1.]));
+    /// ```
+    pub fn freeze(&self) {
+        self.node.freeze();
+    }
+
+    /// Unfreezes `self`, so that it accumulates gradients again.
+    pub fn unfreeze(&self) {
+        self.node.unfreeze();
+    }
+
+    /// Returns `true` if `self` is frozen.
+    pub fn is_frozen(&self) -> bool {
+        self.node.is_frozen()
+    }
+}
+
 impl<T: ?Sized, U: ?Sized> VarDiff<T, U>
 where
     T: Data + 'static,
@@ -119,10 +178,29 @@ where
         self.var.node.data_mut()
     }
 
+    /// Returns the shape of the data inside `self`.
+    ///
+    /// The shape is read from the data, so it reflects any replacement made through
+    /// [`.data_mut()`](VarDiff::data_mut()).
+    pub fn shape(&self) -> Vec<usize> {
+        self.var.shape()
+    }
+
+    /// Returns a short description of `self` holding its shape and whether its data was computed,
+    /// e.g. `Tensor[f32; shape=[3, 4], computed=true]`.
+    pub fn shape_info(&self) -> String {
+        self.var.shape_info()
+    }
+
     /// Returns an immutable reference to the gradient inside `self`.
     ///
     /// At the differentiable variable's creation the gradient is filled with zeros. You can
-    /// populate it with a call to [`.backward()`](VarDiff::backward()).
+    /// populate it with a call to [`.backward()`](VarDiff::backward()). See also
+    /// [`.try_grad()`](VarDiff::try_grad()).
+    ///
+    /// # Panics
+    ///
+    /// If the gradient was de-allocated by [`.no_grad()`](VarDiff::no_grad()).
     pub fn grad(&self) -> Ref<Tensor<U::Dim>> {
         self.node.gradient()
     }
@@ -135,6 +213,64 @@ where
         self.node.gradient_mut()
     }
 
+    /// Returns an immutable reference to the gradient inside `self` if it has been computed.
+    ///
+    /// The gradient is computed by a call to [`.backward()`](VarDiff::backward()) reaching `self`
+    /// and is considered stale once the data of `self` is computed anew by a
+    /// [`.forward()`](VarDiff::forward()). A de-allocated gradient is never computed.
+    ///
+    /// ```
+    /// let w = neuronika::ones(3).requires_grad();
+    /// let loss = (w.clone() * 2.).sum();
+    /// assert!(w.try_grad().is_none());
+    ///
+    /// loss.forward();
+    /// loss.backward(1.);
+    /// assert_eq!(*w.try_grad().unwrap(), ndarray::array![2., 2., 2.]);
+    /// ```
+    pub fn try_grad(&self) -> Option<Ref<Tensor<U::Dim>>> {
+        if self.node.can_overwrite() {
+            None
+        } else {
+            Some(self.node.gradient())
+        }
+    }
+
+    /// Fills the gradient inside `self` with zeros, in-place.
+    ///
+    /// Only the gradient of `self` is affected, the optimizers' `.zero_grad()` should be used to
+    /// zero the gradients of all the parameters at once.
+    ///
+    /// # Panics
+    ///
+    /// If the gradient was de-allocated by [`.no_grad()`](VarDiff::no_grad()).
+    pub fn zero_grad(&self) {
+        self.node.gradient_mut().fill(0.);
+    }
+
+    /// Sets `grad` as the gradient of `self`.
+    ///
+    /// The gradient is considered computed, so that the contributions of the following backward
+    /// passes will be accumulated onto it. This is useful to inject gradients by hand, e.g. when
+    /// implementing a gradient reversal layer.
+    ///
+    /// # Panics
+    ///
+    /// If the shape of `grad` differs from the one of `self` or if the gradient was de-allocated
+    /// by [`.no_grad()`](VarDiff::no_grad()).
+    pub fn set_grad(&self, grad: Tensor<U::Dim>) {
+        let mut gradient = self.node.gradient_mut();
+        assert_eq!(
+            gradient.shape(),
+            grad.shape(),
+            "error: cannot set a gradient of shape {:?} to a variable of shape {:?}.",
+            grad.shape(),
+            gradient.shape()
+        );
+        *gradient = grad;
+        self.node.set_overwrite(false);
+    }
+
     /// Propagates the computations forwards and populates all the variables and differentiable
     /// variables from the leaves of the graph to `self`.   
     pub fn forward(&self) {
@@ -167,6 +303,28 @@ where
         }
     }
 
+    /// Returns a new leaf variable holding a copy of the data of `self`, which is computed first.
+    ///
+    /// The result has no connection with the computational graph of `self`, so it can be used as a
+    /// constant in a larger graph without back-propagating through the operations that produced
+    /// it. See also [`Var::detach()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let x = neuronika::full(3, 2.).requires_grad();
+    /// let y = (x.clone().pow(2).detach() + x.clone()).sum();
+    ///
+    /// y.forward();
+    /// y.backward(1.);
+    ///
+    /// assert_eq!(*y.data(), ndarray::arr0(18.));
+    /// assert_eq!(*x.grad(), ndarray::arr1(&[1., 1., 1.]));
+    /// ```
+    pub fn detach(&self) -> Var<Input<T::Dim>> {
+        self.var.detach()
+    }
+
     /// Back-propagates through the computational graph and populates the gradients of the
     /// differentiable leaves that are ancestors of `self`. Before back-propagating the gradient
     /// of `self` is seeded with `seed`, thus, the leaves' gradients will be scaled accordingly.
@@ -180,6 +338,7 @@ where
         debug_assert!(!self.past.is_empty());
 
         self.node.gradient_mut().fill(seed);
+        self.node.set_overwrite(false);
         self.past.prepare_buffer();
         let buffer = self.past.buffer();
         for node in buffer.iter().rev() {
@@ -221,6 +380,7 @@ where
         self.past.prepare_buffer();
         for node in self.past.buffer.borrow().iter() {
             node.no_grad();
+            node.set_overwrite(true);
         }
     }
 
@@ -281,6 +441,26 @@ where
     {
         VecVecMul::vv(self, rhs)
     }
+
+    /// Vector-vector outer product.
+    ///
+    /// If `self` is *m* and `rhs` is *n* the output will be *(m, n)*.
+    pub fn outer<Rhs>(self, rhs: Rhs) -> <Self as OuterProduct<Rhs>>::Output
+    where
+        Self: OuterProduct<Rhs>,
+    {
+        OuterProduct::outer(self, rhs)
+    }
+
+    /// Returns a differentiable square matrix variable having the vector variable `self` as its
+    /// diagonal with the given `offset` and zeros elsewhere.
+    ///
+    /// A positive `offset` places it above the main diagonal, a negative `offset` below it. If
+    /// `self` is *n* the output will be *(n + |offset|, n + |offset|)*.
+    pub fn diag_embed(self, offset: isize) -> VarDiff<DiagEmbed<T>, DiagEmbedBackward<U>> {
+        let node = DiagEmbedBackward::new(self.node, offset);
+        VarDiff::from(node, self.past, self.var.diag_embed(offset))
+    }
 }
 
 impl<T, U> VarDiff<T, U>
@@ -320,6 +500,52 @@ where
     {
         MatVecMul::mv(self, rhs)
     }
+
+    /// Returns a differentiable variable with the diagonal of the matrix variable `self`.
+    ///
+    /// A positive `offset` selects a diagonal above the main one, a negative `offset` one below
+    /// it. If `self` is *(n, m)* the main diagonal has length *min(n, m)*.
+    pub fn diag(self, offset: isize) -> VarDiff<Diagonal<T>, DiagonalBackward<U>> {
+        let node = DiagonalBackward::new(self.node, offset);
+        VarDiff::from(node, self.past, self.var.diag(offset))
+    }
+
+    /// Returns the sum of the elements on the main diagonal of the matrix variable `self`.
+    pub fn trace(self) -> VarDiff<Trace<T>, TraceBackward<U>> {
+        let node = TraceBackward::new(self.node);
+        VarDiff::from(node, self.past, self.var.trace())
+    }
+
+    /// Computes the Kronecker product between the matrix variables `self` and `rhs`.
+    ///
+    /// If `self` is *(m, n)* and `rhs` is *(p, q)* the output will be *(m·p, n·q)*.
+    pub fn kron<Rhs>(self, rhs: Rhs) -> <Self as KroneckerProduct<Rhs>>::Output
+    where
+        Self: KroneckerProduct<Rhs>,
+    {
+        KroneckerProduct::kron(self, rhs)
+    }
+}
+
+impl<T, U> VarDiff<T, U>
+where
+    T: Data + 'static,
+    T::Dim: DotDim<Ix3, Output = Ix3>,
+    U: Gradient<Dim = T::Dim> + 'static,
+{
+    /// Performs a batched matrix multiplication between `self` and the three-dimensional
+    /// variable `rhs`. If `self` is *(b, n, m)* and `rhs` is *(b, m, o)* the output will be
+    /// *(b, n, o)*. If `self` is a matrix of shape *(n, m)* it is broadcast along the batch axis.
+    ///
+    /// # Panics
+    ///
+    /// If `self` and `rhs` are both three-dimensional and have a different number of batches.
+    pub fn bmm<Rhs>(self, rhs: Rhs) -> <Self as BatchedMatMatMul<Rhs>>::Output
+    where
+        Self: BatchedMatMatMul<Rhs>,
+    {
+        BatchedMatMatMul::bmm(self, rhs)
+    }
 }
 
 impl<T: ?Sized, U: ?Sized> VarDiff<T, U>
@@ -379,6 +605,43 @@ where
         VarDiff::from(node, self.past, self.var.mean())
     }
 
+    /// Returns the mean of the elements of `self` along `axes`. The reduced axes are kept with
+    /// length one, so that the result can be broadcast against `self`.
+    pub fn mean_axes(self, axes: &[usize]) -> VarDiff<MeanAxes<T>, MeanAxesBackward<U>> {
+        let node = MeanAxesBackward::new(self.node, axes);
+        VarDiff::from(node, self.past, self.var.mean_axes(axes))
+    }
+
+    /// Returns a differentiable variable with the upper triangle of the matrices held by `self`
+    /// and zeros elsewhere. The matrices span the last two axes, so batches of matrices are
+    /// supported.
+    ///
+    /// The elements on and above the diagonal of index `offset` are kept. A positive `offset`
+    /// selects a diagonal above the main one, a negative `offset` one below it.
+    ///
+    /// # Panics
+    ///
+    /// If `self` has less than two axes.
+    pub fn triu(self, offset: isize) -> VarDiff<Triangular<T>, TriangularBackward<U>> {
+        let node = TriangularBackward::new(self.node, Triangle::Upper, offset);
+        VarDiff::from(node, self.past, self.var.triu(offset))
+    }
+
+    /// Returns a differentiable variable with the lower triangle of the matrices held by `self`
+    /// and zeros elsewhere. The matrices span the last two axes, so batches of matrices are
+    /// supported.
+    ///
+    /// The elements on and below the diagonal of index `offset` are kept. A positive `offset`
+    /// selects a diagonal above the main one, a negative `offset` one below it.
+    ///
+    /// # Panics
+    ///
+    /// If `self` has less than two axes.
+    pub fn tril(self, offset: isize) -> VarDiff<Triangular<T>, TriangularBackward<U>> {
+        let node = TriangularBackward::new(self.node, Triangle::Lower, offset);
+        VarDiff::from(node, self.past, self.var.tril(offset))
+    }
+
     /// Takes the power of each element in `self` with exponent `exp` and returns a differentiable
     /// variable with the result.
     pub fn pow(self, exp: i32) -> VarDiff<Power<T>, PowerBackward<U, T>> {
@@ -413,10 +676,28 @@ where
 
     /// Applies the *softplus* element-wise and returns a differentiable variable with the result.
     ///
-    /// *Softplus(x) = log(1 + exp(x))*
-    pub fn softplus(self) -> VarDiff<SoftPlus<T>, SoftPlusBackward<U, T>> {
-        let node = SoftPlusBackward::new(self.node, self.var.node.clone());
-        VarDiff::from(node, self.past, self.var.softplus())
+    /// *Softplus(x) = log(1 + exp(beta * x)) / beta*
+    ///
+    /// For numerical stability the function reverts to the identity when *beta * x* is above
+    /// `threshold`. Common values are `beta = 1.0` and `threshold = 20.0`.
+    pub fn softplus(
+        self,
+        beta: f32,
+        threshold: f32,
+    ) -> VarDiff<SoftPlus<T>, SoftPlusBackward<U, T>> {
+        let node = SoftPlusBackward::new(self.node, self.var.node.clone(), beta, threshold);
+        VarDiff::from(node, self.past, self.var.softplus(beta, threshold))
+    }
+
+    /// Applies the *Gaussian error linear unit* element-wise and returns a differentiable
+    /// variable with the result.
+    ///
+    /// The tanh approximation is used.
+    ///
+    /// *GELU(x) = 0.5 * x * (1 + tanh(√(2 / π) * (x + 0.044715 * x³)))*
+    pub fn gelu(self) -> VarDiff<GELU<T>, GELUBackward<U, T>> {
+        let node = GELUBackward::new(self.node, self.var.node.clone());
+        VarDiff::from(node, self.past, self.var.gelu())
     }
 
     /// Applies the *sigmoid* element-wise and returns a differentiable variable with the result.
@@ -426,6 +707,32 @@ where
         VarDiff::from(node, self.past, var)
     }
 
+    /// Applies the *swish* element-wise and returns a differentiable variable with the result.
+    ///
+    /// *Swish(x) = x * sigmoid(x)*
+    pub fn swish(self) -> VarDiff<Swish<T>, SwishBackward<U, T>> {
+        let var = self.var.swish();
+        let node = SwishBackward::new(self.node, var.node.clone());
+        VarDiff::from(node, self.past, var)
+    }
+
+    /// Applies the *sigmoid linear unit* element-wise and returns a differentiable variable with
+    /// the result.
+    ///
+    /// This is the same as [`.swish()`](VarDiff::swish()).
+    pub fn silu(self) -> VarDiff<SiLU<T>, SiLUBackward<U, T>> {
+        self.swish()
+    }
+
+    /// Applies the *mish* element-wise and returns a differentiable variable with the result.
+    ///
+    /// *Mish(x) = x * tanh(softplus(x))*
+    pub fn mish(self) -> VarDiff<Mish<T>, MishBackward<U, T>> {
+        let var = self.var.mish();
+        let node = MishBackward::new(self.node, var.node.clone());
+        VarDiff::from(node, self.past, var)
+    }
+
     /// Applies the *tanh* element-wise and returns a differentiable variable with the result.
     pub fn tanh(self) -> VarDiff<TanH<T>, TanHBackward<U, TanH<T>>> {
         let var = self.var.tanh();
@@ -477,6 +784,83 @@ where
         VarDiff::from(node, self.past, var)
     }
 
+    /// Computes the logarithm of the sum of the exponentials of `self` along `axis`, subtracting
+    /// the maximum of each lane first so that the exponentials never overflow, and returns a
+    /// differentiable variable with the result. Its gradient is the softmax of `self` along
+    /// `axis`.
+    ///
+    /// The reduced axis is kept with length one, so that the result can be broadcasted against
+    /// `self`.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is out of bounds.
+    pub fn log_sum_exp(self, axis: usize) -> VarDiff<LogSumExp<T>, LogSumExpBackward<U, T>> {
+        let var = self.var.log_sum_exp(axis);
+        let node = LogSumExpBackward::new(self.node, var.node.clone());
+        VarDiff::from(node, self.past, var)
+    }
+
+    /// Computes the *Lp norm* of `self` along `axes` and returns a differentiable variable with
+    /// the result.
+    ///
+    /// The reduced axes are kept with length one, so that the result can be broadcasted against
+    /// `self`. The *L1* and the *L2* norms, i.e. `p` equal to 1 and to 2, are computed through
+    /// specialized fast paths.
+    ///
+    /// The gradient of a zero norm is defined to be zero.
+    pub fn norm(self, p: f32, axes: &[usize]) -> VarDiff<Norm<T>, NormBackward<U, T>> {
+        self.clamped_norm(p, axes, 0.)
+    }
+
+    /// Computes the *Lp norm* of `self` along `axes` clamping it to be at least `eps`.
+    fn clamped_norm(
+        self,
+        p: f32,
+        axes: &[usize],
+        eps: f32,
+    ) -> VarDiff<Norm<T>, NormBackward<U, T>> {
+        let var = self.var.clone().clamped_norm(p, axes, eps);
+        let node = NormBackward::new(self.node, self.var.node, var.node.clone());
+        VarDiff::from(node, self.past, var)
+    }
+
+    /// Divides `self` by its *Lp norm* computed along `axes` and returns a differentiable
+    /// variable with the result.
+    ///
+    /// The norm is clamped to be at least `eps` in order to avoid divisions by zero, this is
+    /// useful, for instance, to compute the cosine similarity of embeddings.
+    ///
+    /// See also [`.norm()`].
+    ///
+    /// [`.norm()`]: VarDiff::norm()
+    #[allow(clippy::type_complexity)]
+    pub fn normalize(
+        self,
+        p: f32,
+        axes: &[usize],
+        eps: f32,
+    ) -> VarDiff<Division<T, Norm<T>>, DivisionBackward<T, U, Norm<T>, NormBackward<U, T>>> {
+        self.clone() / self.clamped_norm(p, axes, eps)
+    }
+
+    /// Computes the *cumulative sum* of the elements of `self` along `axis` and returns a
+    /// differentiable variable with the result.
+    pub fn cumsum(self, axis: usize) -> VarDiff<CumSum<T>, CumSumBackward<U>> {
+        let node = CumSumBackward::new(self.node, axis);
+        VarDiff::from(node, self.past, self.var.cumsum(axis))
+    }
+
+    /// Computes the *cumulative product* of the elements of `self` along `axis` and returns a
+    /// differentiable variable with the result.
+    ///
+    /// The gradient is well defined even when `self` contains zeros.
+    pub fn cumprod(self, axis: usize) -> VarDiff<CumProd<T>, CumProdBackward<U, T>> {
+        let var = self.var.clone().cumprod(axis);
+        let node = CumProdBackward::new(self.node, self.var.node, var.node.clone());
+        VarDiff::from(node, self.past, var)
+    }
+
     /// Returns a differentiable variable equivalent to `self` with its dimensions reversed.
     pub fn t(self) -> VarDiff<Transpose<T>, TransposeBackward<U>> {
         let node = TransposeBackward::new(self.node);
@@ -515,6 +899,38 @@ where
         VarDiff::from(node, self.past, var)
     }
 
+    /// Creates a new batch normalization differentiable variable sharing the status with its
+    /// internal var.
+    pub(crate) fn batch_norm_with_status(
+        self,
+        running_mean: &Var<Input<Ix1>>,
+        running_var: &Var<Input<Ix1>>,
+        momentum: f32,
+        eps: f32,
+        status: Rc<Cell<bool>>,
+    ) -> VarDiff<BatchNorm<T>, BatchNormBackward<U, T>>
+    where
+        T::Dim: RemoveAxis,
+    {
+        let var = self
+            .var
+            .batch_norm_with_status(running_mean, running_var, momentum, eps, status);
+        let node = BatchNormBackward::new(self.node, var.node.clone());
+        VarDiff::from(node, self.past, var)
+    }
+
+    /// Creates a new layer normalization differentiable variable, normalizing `self` over its last
+    /// `normalized_ndim` axes.
+    pub(crate) fn layer_norm(
+        self,
+        normalized_ndim: usize,
+        eps: f32,
+    ) -> VarDiff<LayerNorm<T>, LayerNormBackward<U, T>> {
+        let var = self.var.layer_norm(normalized_ndim, eps);
+        let node = LayerNormBackward::new(self.node, var.node.clone());
+        VarDiff::from(node, self.past, var)
+    }
+
     /// Splits `self` into a certain number of chunks of size `chunk_size` **skipping** the
     /// remainder along each dimension that doesn’t fit evenly.
     pub fn chunks<E>(self, chunk_size: E) -> Vec<VarDiff<Chunk<T>, ChunkBackward<U>>>
@@ -541,6 +957,42 @@ where
             .collect()
     }
 
+    /// Splits `self` along `axis` into consecutive differentiable pieces of length `sizes`.
+    ///
+    /// The gradients of the pieces are accumulated into the corresponding ranges of the gradient
+    /// of `self`.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is out of bounds or if `sizes` don't add up to its length.
+    pub fn split(self, sizes: &[usize], axis: usize) -> Vec<VarDiff<Split<T>, SplitBackward<U>>> {
+        self.var
+            .clone()
+            .split(sizes, axis)
+            .into_iter()
+            .enumerate()
+            .map(|(i, var)| {
+                VarDiff::from(
+                    SplitBackward::new(self.node.clone(), axis, sizes, i),
+                    self.past.clone(),
+                    var,
+                )
+            })
+            .collect()
+    }
+
+    /// Splits `self` along `axis` into `chunks` consecutive differentiable pieces as even as
+    /// possible. When the length of `axis` isn't divisible by `chunks`, the first pieces are one
+    /// element longer.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is out of bounds or if `chunks` is zero.
+    pub fn chunk(self, chunks: usize, axis: usize) -> Vec<VarDiff<Split<T>, SplitBackward<U>>> {
+        let sizes = chunk_sizes(self.var.node.data().shape(), axis, chunks);
+        self.split(&sizes, axis)
+    }
+
     /// Returns a new differentiable variable with a dimension of size one inserted at the position
     /// specified by `axis`.
     pub fn unsqueeze(self, axis: usize) -> VarDiff<Unsqueeze<T>, UnsqueezeBackward<U>> {
@@ -550,6 +1002,316 @@ where
             self.var.unsqueeze(axis),
         )
     }
+
+    /// Returns a differentiable variable with the `len` elements of `self` along `axis` starting
+    /// from `start`.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is out of bounds or if the range doesn't fit in it.
+    pub fn narrow(
+        self,
+        axis: usize,
+        start: usize,
+        len: usize,
+    ) -> VarDiff<Narrow<T>, NarrowBackward<U>> {
+        VarDiff::from(
+            NarrowBackward::new(self.node, axis, start, len),
+            self.past,
+            self.var.narrow(axis, start, len),
+        )
+    }
+
+    /// Returns a differentiable variable with `self` padded by `padding`, which holds the number
+    /// of elements added before and after each axis. The border is filled according to `mode`.
+    ///
+    /// The gradient of the elements copied into the border is accumulated onto the elements they
+    /// were copied from.
+    ///
+    /// # Panics
+    ///
+    /// If `padding` doesn't have an entry for each axis of `self`, or if a reflective padding is
+    /// not smaller than the axis it's applied to.
+    pub fn pad(self, padding: &[(usize, usize)], mode: PadMode) -> VarDiff<Pad<T>, PadBackward<U>> {
+        VarDiff::from(
+            PadBackward::new(self.node, padding, mode),
+            self.past,
+            self.var.pad(padding, mode),
+        )
+    }
+
+    /// Returns a differentiable variable with the elements of `self` in reverse order along
+    /// `axes`.
+    ///
+    /// # Panics
+    ///
+    /// If any of `axes` is out of bounds or repeated.
+    pub fn flip(self, axes: &[usize]) -> VarDiff<Flip<T>, FlipBackward<U>> {
+        VarDiff::from(
+            FlipBackward::new(self.node, axes),
+            self.past,
+            self.var.flip(axes),
+        )
+    }
+
+    /// Returns a differentiable variable with the elements of `self` circularly shifted by
+    /// `shift` positions along `axis`. Elements shifted beyond the last position are re-introduced
+    /// at the first one.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is out of bounds.
+    pub fn roll(self, shift: isize, axis: usize) -> VarDiff<Roll<T>, RollBackward<U>> {
+        VarDiff::from(
+            RollBackward::new(self.node, shift, axis),
+            self.past,
+            self.var.roll(shift, axis),
+        )
+    }
+
+    /// Returns a differentiable variable made of `n` copies of `self` laid one after the other
+    /// along `axis`. The gradients of the copies are summed up.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is out of bounds.
+    pub fn repeat(self, n: usize, axis: usize) -> VarDiff<Repeat<T>, RepeatBackward<U>> {
+        VarDiff::from(
+            RepeatBackward::new(self.node, n, axis),
+            self.past,
+            self.var.repeat(n, axis),
+        )
+    }
+
+    /// Returns a differentiable variable with the sub-tensors of `self` at `indices` along `axis`,
+    /// in the same order as `indices`. Indices can be repeated, in which case the gradients of
+    /// all their occurrences are accumulated.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is out of bounds or if any of `indices` is out of bounds for it.
+    pub fn index_select(
+        self,
+        axis: usize,
+        indices: Vec<usize>,
+    ) -> VarDiff<IndexSelect<T>, IndexSelectBackward<U>> {
+        VarDiff::from(
+            IndexSelectBackward::new(self.node, axis, indices.clone()),
+            self.past,
+            self.var.index_select(axis, indices),
+        )
+    }
+
+    /// Returns a differentiable variable with the elements of `self` gathered along `axis` at the
+    /// positions specified by `index`. See [`Var::gather()`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is out of bounds, if `index` is larger than `self` along any other axis or if any
+    /// of its elements is out of bounds for `axis`.
+    pub fn gather(
+        self,
+        axis: usize,
+        index: Array<usize, T::Dim>,
+    ) -> VarDiff<Gather<T>, GatherBackward<U>> {
+        VarDiff::from(
+            GatherBackward::new(self.node, axis, index.clone()),
+            self.past,
+            self.var.gather(axis, index),
+        )
+    }
+
+    /// Returns a new differentiable variable with the elements of `src` added to the ones of `self` along
+    /// `axis`, at the positions specified by `index`.
+    ///
+    /// This is the counterpart of `.gather()`: each element of `src` is added to the position of
+    /// `self` obtained by replacing its coordinate along `axis` with the corresponding element of
+    /// `index`, which must have the same shape as `src`. Elements scattered to the same position
+    /// add up. For a two-dimensional variable and `axis` equal to one, this is
+    /// `result[i][index[i][j]] += src[i][j]`.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is out of bounds, if `index` and `src` have different shapes, if `index` is
+    /// larger than `self` along any other axis or if any of its elements is out of bounds for
+    /// `axis`.
+    pub fn scatter_add<Rhs>(
+        self,
+        axis: usize,
+        index: Array<usize, T::Dim>,
+        src: Rhs,
+    ) -> <Self as ScatterAdd<Rhs>>::Output
+    where
+        Self: ScatterAdd<Rhs, Dim = T::Dim>,
+    {
+        ScatterAdd::scatter_add(self, axis, index, src)
+    }
+
+    /// Returns a new differentiable variable equal to `self` except at the positions where `mask`
+    /// is nonzero, which are set to `value`. The gradient at the filled positions is zero.
+    ///
+    /// The mask, usually made of zeros and ones, must be broadcastable to the shape of `self`. It's
+    /// read every time the result is evaluated, so it may be the result of other operations.
+    ///
+    /// # Panics
+    ///
+    /// If `mask` cannot be broadcast to the shape of `self`.
+    pub fn masked_fill<M: ?Sized>(
+        self,
+        mask: Var<M>,
+        value: f32,
+    ) -> VarDiff<MaskedFill<T, M>, MaskedFillBackward<U, M>>
+    where
+        M: Data + 'static,
+    {
+        VarDiff::from(
+            MaskedFillBackward::new(self.node, mask.node.clone()),
+            self.past,
+            self.var.masked_fill(mask, value),
+        )
+    }
+
+    /// Returns a new one-dimensional differentiable variable with the elements of `self` at the
+    /// positions where `mask` is nonzero, in row-major order.
+    ///
+    /// The mask must be broadcastable to the shape of `self`. The length of the result is the
+    /// number of elements selected by `mask` when this method is called, the mask may change
+    /// afterwards as long as it selects the same number of elements.
+    ///
+    /// # Panics
+    ///
+    /// If `mask` cannot be broadcast to the shape of `self`.
+    pub fn masked_select<M: ?Sized>(
+        self,
+        mask: Var<M>,
+    ) -> VarDiff<MaskedSelect<T, M>, MaskedSelectBackward<U, M>>
+    where
+        M: Data + 'static,
+    {
+        VarDiff::from(
+            MaskedSelectBackward::new(self.node, mask.node.clone()),
+            self.past,
+            self.var.masked_select(mask),
+        )
+    }
+
+    /// Returns a new differentiable variable with the dimension of size one at the position
+    /// specified by `axis` removed.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is out of bounds or if its length is not one.
+    pub fn squeeze(self, axis: usize) -> VarDiff<Squeeze<T>, SqueezeBackward<U>>
+    where
+        T::Dim: RemoveAxis,
+    {
+        VarDiff::from(
+            SqueezeBackward::new(self.node, axis),
+            self.past,
+            self.var.squeeze(axis),
+        )
+    }
+
+    /// Returns a differentiable variable with the axes of `self` permuted according to `axes`.
+    ///
+    /// The *i*-th axis of the result is the axis `axes[i]` of `self`.
+    ///
+    /// # Panics
+    ///
+    /// If `axes` is not a permutation of the axes of `self`.
+    pub fn permute<Ax: IntoDimension<Dim = T::Dim>>(
+        self,
+        axes: Ax,
+    ) -> VarDiff<Permute<T>, PermuteBackward<U>> {
+        let axes = axes.into_dimension();
+
+        VarDiff::from(
+            PermuteBackward::new(self.node, axes.clone()),
+            self.past,
+            self.var.permute(axes),
+        )
+    }
+
+    /// Returns a differentiable variable with the axes `a` and `b` of `self` swapped.
+    ///
+    /// # Panics
+    ///
+    /// If either `a` or `b` is out of bounds.
+    pub fn swap_axes(self, a: usize, b: usize) -> VarDiff<Permute<T>, PermuteBackward<U>> {
+        let axes: T::Dim = swap_permutation(self.data().ndim(), a, b);
+        self.permute(axes)
+    }
+
+    /// Returns a differentiable variable with the elements of `self` arranged in the given
+    /// `shape`.
+    ///
+    /// The elements are read and placed in row-major order. At most one entry of `shape` can be
+    /// `usize::MAX`, in which case its length is inferred from the number of elements of `self`.
+    ///
+    /// # Panics
+    ///
+    /// If the number of elements of `self` doesn't match the one of `shape` or if more than one
+    /// entry of `shape` is to be inferred.
+    pub fn reshape<Sh: IntoDimension>(
+        self,
+        shape: Sh,
+    ) -> VarDiff<Reshape<T, Sh::Dim>, ReshapeBackward<U, Sh::Dim>> {
+        let shape = shape.into_dimension();
+        VarDiff::from(
+            ReshapeBackward::new(self.node, shape.clone()),
+            self.past,
+            self.var.reshape(shape),
+        )
+    }
+
+    /// Returns a differentiable matrix variable with the first axis of `self` kept and all the
+    /// others collapsed into a single one.
+    ///
+    /// If `self` is *(n, c, h, w)* the output will be *(n, c·h·w)*.
+    ///
+    /// # Panics
+    ///
+    /// If `self` is zero-dimensional.
+    pub fn flatten(self) -> VarDiff<Reshape<T, Ix2>, ReshapeBackward<U, Ix2>> {
+        let shape = self.data().shape().to_vec();
+        assert!(
+            !shape.is_empty(),
+            "error: cannot flatten a zero-dimensional variable."
+        );
+        let shape = Ix2(shape[0], shape[1..].iter().product());
+
+        self.reshape(shape)
+    }
+
+    /// Returns a differentiable variable with the axes of `self` from `start_axis` to `end_axis`,
+    /// both included, collapsed into a single one.
+    ///
+    /// # Panics
+    ///
+    /// If `start_axis` is greater than `end_axis` or if `end_axis` is out of bounds.
+    pub fn flatten_range(
+        self,
+        start_axis: usize,
+        end_axis: usize,
+    ) -> VarDiff<Reshape<T, IxDyn>, ReshapeBackward<U, IxDyn>> {
+        let shape = flatten_shape(self.data().shape(), start_axis, end_axis);
+        self.reshape(shape)
+    }
+
+    /// Contracts `self` and `rhs` over the axes `lhs_axes` of `self` and `rhs_axes` of `rhs`.
+    ///
+    /// See [`TensorDot`] for more details.
+    pub fn tensordot<Rhs>(
+        self,
+        rhs: Rhs,
+        lhs_axes: &[usize],
+        rhs_axes: &[usize],
+    ) -> <Self as TensorDot<Rhs>>::Output
+    where
+        Self: TensorDot<Rhs>,
+    {
+        TensorDot::tensordot(self, rhs, lhs_axes, rhs_axes)
+    }
 }
 
 impl<D> VarDiff<dyn Data<Dim = D>, dyn Gradient<Dim = D>>
@@ -1138,6 +1900,174 @@ where
     }
 }
 
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Outer ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<F1: ?Sized, B1: ?Sized, F2: ?Sized> OuterProduct<Var<F2>> for VarDiff<F1, B1>
+where
+    F1: Data<Dim = Ix1> + 'static,
+    B1: Gradient<Dim = Ix1> + Overwrite + 'static,
+    F2: Data<Dim = Ix1> + 'static,
+{
+    type Output = VarDiff<Outer<F1, F2>, OuterBackwardLeft<B1, F2>>;
+
+    fn outer(self, rhs: Var<F2>) -> Self::Output {
+        let node = OuterBackwardLeft::new(self.node, rhs.node.clone());
+        VarDiff::from(node, self.past, self.var.outer(rhs))
+    }
+}
+
+impl<F1: ?Sized, B1: ?Sized, F2: ?Sized, B2: ?Sized> OuterProduct<VarDiff<F2, B2>>
+    for VarDiff<F1, B1>
+where
+    F1: Data<Dim = Ix1> + 'static,
+    B1: Gradient<Dim = Ix1> + Overwrite + 'static,
+    F2: Data<Dim = Ix1> + 'static,
+    B2: Gradient<Dim = Ix1> + Overwrite + 'static,
+{
+    type Output = VarDiff<Outer<F1, F2>, OuterBackward<F1, B1, F2, B2>>;
+
+    fn outer(mut self, rhs: VarDiff<F2, B2>) -> Self::Output {
+        self.past.merge(rhs.past);
+        let node = OuterBackward::new(
+            self.var.node.clone(),
+            self.node,
+            rhs.var.node.clone(),
+            rhs.node,
+        );
+        VarDiff::from(node, self.past, self.var.outer(rhs.var))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Batched Multiplication ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<F1: ?Sized, B1: ?Sized, F2: ?Sized> BatchedMatMatMul<Var<F2>> for VarDiff<F1, B1>
+where
+    F1: Data + 'static,
+    F1::Dim: DotDim<Ix3, Output = Ix3>,
+    B1: Gradient<Dim = F1::Dim> + Overwrite + 'static,
+    F2: Data<Dim = Ix3> + 'static,
+{
+    type Output = VarDiff<BatchedMatMul<F1, F2>, BatchedMatMulBackwardLeft<B1, F2>>;
+
+    fn bmm(self, rhs: Var<F2>) -> Self::Output {
+        let node = BatchedMatMulBackwardLeft::new(self.node, rhs.node.clone());
+        VarDiff::from(node, self.past, self.var.bmm(rhs))
+    }
+}
+
+impl<F1: ?Sized, B1: ?Sized, F2: ?Sized, B2: ?Sized> BatchedMatMatMul<VarDiff<F2, B2>>
+    for VarDiff<F1, B1>
+where
+    F1: Data + 'static,
+    F1::Dim: DotDim<Ix3, Output = Ix3>,
+    B1: Gradient<Dim = F1::Dim> + Overwrite + 'static,
+    F2: Data<Dim = Ix3> + 'static,
+    B2: Gradient<Dim = Ix3> + Overwrite + 'static,
+{
+    type Output = VarDiff<BatchedMatMul<F1, F2>, BatchedMatMulBackward<F1, B1, F2, B2>>;
+
+    fn bmm(mut self, rhs: VarDiff<F2, B2>) -> Self::Output {
+        self.past.merge(rhs.past);
+        let node = BatchedMatMulBackward::new(
+            self.var.node.clone(),
+            self.node,
+            rhs.var.node.clone(),
+            rhs.node,
+        );
+        VarDiff::from(node, self.past, self.var.bmm(rhs.var))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Kron ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<F1: ?Sized, B1: ?Sized, F2: ?Sized> KroneckerProduct<Var<F2>> for VarDiff<F1, B1>
+where
+    F1: Data<Dim = Ix2> + 'static,
+    B1: Gradient<Dim = Ix2> + Overwrite + 'static,
+    F2: Data<Dim = Ix2> + 'static,
+{
+    type Output = VarDiff<Kron<F1, F2>, KronBackwardLeft<B1, F2>>;
+
+    fn kron(self, rhs: Var<F2>) -> Self::Output {
+        let node = KronBackwardLeft::new(self.node, rhs.node.clone());
+        VarDiff::from(node, self.past, self.var.kron(rhs))
+    }
+}
+
+impl<F1: ?Sized, B1: ?Sized, F2: ?Sized, B2: ?Sized> KroneckerProduct<VarDiff<F2, B2>>
+    for VarDiff<F1, B1>
+where
+    F1: Data<Dim = Ix2> + 'static,
+    B1: Gradient<Dim = Ix2> + Overwrite + 'static,
+    F2: Data<Dim = Ix2> + 'static,
+    B2: Gradient<Dim = Ix2> + Overwrite + 'static,
+{
+    type Output = VarDiff<Kron<F1, F2>, KronBackward<F1, B1, F2, B2>>;
+
+    fn kron(mut self, rhs: VarDiff<F2, B2>) -> Self::Output {
+        self.past.merge(rhs.past);
+        let node = KronBackward::new(
+            self.var.node.clone(),
+            self.node,
+            rhs.var.node.clone(),
+            rhs.node,
+        );
+        VarDiff::from(node, self.past, self.var.kron(rhs.var))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tensordot ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<F1: ?Sized, B1: ?Sized, F2: ?Sized> TensorDot<Var<F2>> for VarDiff<F1, B1>
+where
+    F1: Data + 'static,
+    B1: Gradient<Dim = F1::Dim> + Overwrite + 'static,
+    F2: Data + 'static,
+{
+    type Output = VarDiff<dyn Data<Dim = IxDyn>, dyn Gradient<Dim = IxDyn>>;
+
+    fn tensordot(self, rhs: Var<F2>, lhs_axes: &[usize], rhs_axes: &[usize]) -> Self::Output {
+        let contraction =
+            Contraction::new(self.data().shape(), rhs.data().shape(), lhs_axes, rhs_axes);
+        let lhs = self
+            .permute(contraction.lhs_permutation::<F1::Dim>())
+            .reshape(contraction.lhs_shape);
+        let rhs = rhs
+            .permute(contraction.rhs_permutation::<F2::Dim>())
+            .reshape(contraction.rhs_shape);
+
+        lhs.mm(rhs).reshape(contraction.shape).into_dyn()
+    }
+}
+
+impl<F1: ?Sized, B1: ?Sized, F2: ?Sized, B2: ?Sized> TensorDot<VarDiff<F2, B2>> for VarDiff<F1, B1>
+where
+    F1: Data + 'static,
+    B1: Gradient<Dim = F1::Dim> + Overwrite + 'static,
+    F2: Data + 'static,
+    B2: Gradient<Dim = F2::Dim> + Overwrite + 'static,
+{
+    type Output = VarDiff<dyn Data<Dim = IxDyn>, dyn Gradient<Dim = IxDyn>>;
+
+    fn tensordot(
+        self,
+        rhs: VarDiff<F2, B2>,
+        lhs_axes: &[usize],
+        rhs_axes: &[usize],
+    ) -> Self::Output {
+        let contraction =
+            Contraction::new(self.data().shape(), rhs.data().shape(), lhs_axes, rhs_axes);
+        let lhs = self
+            .permute(contraction.lhs_permutation::<F1::Dim>())
+            .reshape(contraction.lhs_shape);
+        let rhs = rhs
+            .permute(contraction.rhs_permutation::<F2::Dim>())
+            .reshape(contraction.rhs_shape);
+
+        lhs.mm(rhs).reshape(contraction.shape).into_dyn()
+    }
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Cat and Stack traits implementations ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -1214,6 +2144,98 @@ where
     }
 }
 
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ ScatterAdd trait implementations ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<F1: ?Sized, B1: ?Sized, F2: ?Sized> ScatterAdd<Var<F2>> for VarDiff<F1, B1>
+where
+    F1: Data<Dim = B1::Dim> + 'static,
+    F2: Data<Dim = F1::Dim> + 'static,
+    B1: Gradient + 'static,
+{
+    type Output = VarDiff<ScatterAddition<F1, F2>, ScatterAdditionBackwardLeft<B1>>;
+    type Dim = F1::Dim;
+
+    fn scatter_add(self, axis: usize, index: Array<usize, F1::Dim>, src: Var<F2>) -> Self::Output {
+        let node = ScatterAdditionBackwardLeft::new(self.node, src.node.clone(), axis, &index);
+        VarDiff::from(
+            node,
+            self.past,
+            ScatterAdd::scatter_add(self.var, axis, index, src),
+        )
+    }
+}
+
+impl<F1: ?Sized, B1: ?Sized, F2: ?Sized, B2: ?Sized> ScatterAdd<VarDiff<F2, B2>> for VarDiff<F1, B1>
+where
+    F1: Data + 'static,
+    B1: Gradient<Dim = F1::Dim> + 'static,
+    F2: Data<Dim = F1::Dim> + 'static,
+    B2: Gradient<Dim = B1::Dim> + 'static,
+{
+    type Output = VarDiff<ScatterAddition<F1, F2>, ScatterAdditionBackward<B1, B2>>;
+    type Dim = F1::Dim;
+
+    fn scatter_add(
+        mut self,
+        axis: usize,
+        index: Array<usize, F1::Dim>,
+        src: VarDiff<F2, B2>,
+    ) -> Self::Output {
+        self.past.merge(src.past);
+        let node = ScatterAdditionBackward::new(self.node, src.node, axis, index.clone());
+        VarDiff::from(
+            node,
+            self.past,
+            ScatterAdd::scatter_add(self.var, axis, index, src.var),
+        )
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Where trait implementations ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<C: ?Sized, F1: ?Sized, B1: ?Sized, F2: ?Sized> Where<Var<C>, Var<F2>> for VarDiff<F1, B1>
+where
+    C: Data + 'static,
+    F1: Data + 'static,
+    B1: Gradient + 'static,
+    F2: Data + 'static,
+    C::Dim: DimMax<F1::Dim> + DimMax<B1::Dim>,
+    Broadcasted<C::Dim, F1::Dim>: DimMax<F2::Dim>,
+    Broadcasted<C::Dim, B1::Dim>: DimMax<F2::Dim>,
+{
+    type Output = VarDiff<Select<C, F1, F2>, SelectBackwardLeft<C, B1, F2>>;
+
+    fn where_(self, condition: Var<C>, other: Var<F2>) -> Self::Output {
+        let node = SelectBackwardLeft::new(condition.node.clone(), self.node, other.node.clone());
+        VarDiff::from(node, self.past, self.var.where_(condition, other))
+    }
+}
+
+impl<C: ?Sized, F1: ?Sized, B1: ?Sized, F2: ?Sized, B2: ?Sized> Where<Var<C>, VarDiff<F2, B2>>
+    for VarDiff<F1, B1>
+where
+    C: Data + 'static,
+    F1: Data + 'static,
+    B1: Gradient + 'static,
+    F2: Data + 'static,
+    B2: Gradient + 'static,
+    C::Dim: DimMax<F1::Dim> + DimMax<B1::Dim>,
+    Broadcasted<C::Dim, F1::Dim>: DimMax<F2::Dim>,
+    Broadcasted<C::Dim, B1::Dim>: DimMax<B2::Dim>,
+{
+    type Output = VarDiff<Select<C, F1, F2>, SelectBackward<C, B1, B2>>;
+
+    fn where_(mut self, condition: Var<C>, other: VarDiff<F2, B2>) -> Self::Output {
+        self.past.merge(other.past);
+        let node = SelectBackward::new(condition.node.clone(), self.node, other.node);
+        VarDiff::from(node, self.past, self.var.where_(condition, other.var))
+    }
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Register ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 impl<T: ?Sized, U: ?Sized> Register for VarDiff<T, U>